@@ -54,18 +54,18 @@ def affine_transform(
 def area(
     input: ArrowArrayExportable,
     *,
-    method: AreaMethod | AreaMethodT = AreaMethod.Euclidean,
+    method: AreaMethod | AreaMethodT | None = None,
 ) -> Array: ...
 @overload
 def area(
     input: ArrowStreamExportable,
     *,
-    method: AreaMethod | AreaMethodT = AreaMethod.Euclidean,
+    method: AreaMethod | AreaMethodT | None = None,
 ) -> ChunkedArray: ...
 def area(
     input: ArrowArrayExportable | ArrowStreamExportable,
     *,
-    method: AreaMethod | AreaMethodT = AreaMethod.Euclidean,
+    method: AreaMethod | AreaMethodT | None = None,
 ) -> Array | ChunkedArray:
     """
     Determine the area of an array of geometries
@@ -77,6 +77,8 @@ def area(
         method: The method to use for area calculation. One of "Ellipsoidal", "Euclidean", or
             "Spherical". Refer to the documentation on
             [AreaMethod][geoarrow.rust.compute.enums.AreaMethod] for more information.
+            Defaults to "Ellipsoidal" if the input has spherical edges and "Euclidean"
+            otherwise. "Euclidean" raises an error for input with spherical edges.
 
     Returns:
         Array or chunked array with area values.
@@ -122,6 +124,8 @@ def centroid(
     Args:
         input: input geometry array or chunked geometry array
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         Array or chunked array with centroid values.
     """
@@ -156,6 +160,8 @@ def chaikin_smoothing(
         input: input geometry array or chunked geometry array n_iterations: Number of
             iterations to use for smoothing.
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         Smoothed geometry array or chunked geometry array.
     """
@@ -179,6 +185,8 @@ def convex_hull(
     Args:
         input: input geometry array
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         Array with convex hull polygons.
     """
@@ -198,6 +206,9 @@ def densify(
 
     Note: `max_distance` must be greater than 0.
 
+    If the input has spherical edges, coordinates are interpolated along geodesics and
+    `max_distance` is in meters.
+
     Args:
         input: input geometry array
         max_distance: maximum distance between coordinates
@@ -269,7 +280,10 @@ def frechet_distance(
             - A [`ChunkedLineStringArray`][geoarrow.rust.core.ChunkedLineStringArray]
             - Any Python class that implements the Geo Interface, such as a [`shapely`
               LineString][shapely.LineString]
-            - Any GeoArrow array or chunked array of `LineString` type
+            - Any GeoArrow array or chunked array of `LineString` type, or of
+              geometry type with only `LineString` values
+
+    Raises an error for input with spherical edges, which are not supported.
 
     Returns:
         Array or chunked array with float distance values.
@@ -305,6 +319,42 @@ def geodesic_perimeter(
         Array with output values.
     """
 
+@overload
+def hausdorff_distance(
+    input: ArrowArrayExportable,
+    other: BroadcastGeometry,
+) -> Array: ...
+@overload
+def hausdorff_distance(
+    input: ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> ChunkedArray: ...
+def hausdorff_distance(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    other: BroadcastGeometry,
+) -> Array | ChunkedArray:
+    """
+    Determine the similarity between two arrays of geometries using the [Hausdorff
+    distance].
+
+    The Hausdorff distance is the greatest distance from any vertex of one geometry to
+    the nearest vertex of the other. Unlike the Fréchet distance, it ignores the order
+    of the vertices, and is defined between geometries of any type.
+
+    [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+
+    Args:
+        input: input geometry array or chunked geometry array
+        other: the geometry or geometry array to compare against, which must have the
+            same length and chunking as `input` if it is an array.
+
+    Raises an error for input with spherical edges, which are not supported.
+
+    Returns:
+        Array or chunked array with float distance values. The output is null where
+        either geometry is null or empty.
+    """
+
 @overload
 def is_empty(input: ArrowArrayExportable) -> Array: ...
 @overload
@@ -326,18 +376,18 @@ def is_empty(
 def length(
     input: ArrowArrayExportable,
     *,
-    method: LengthMethod | LengthMethodT = LengthMethod.Euclidean,
+    method: LengthMethod | LengthMethodT | None = None,
 ) -> Array: ...
 @overload
 def length(
     input: ArrowStreamExportable,
     *,
-    method: LengthMethod | LengthMethodT = LengthMethod.Euclidean,
+    method: LengthMethod | LengthMethodT | None = None,
 ) -> ChunkedArray: ...
 def length(
     input: ArrowArrayExportable | ArrowStreamExportable,
     *,
-    method: LengthMethod | LengthMethodT = LengthMethod.Euclidean,
+    method: LengthMethod | LengthMethodT | None = None,
 ) -> Array | ChunkedArray:
     """
     Calculation of the length of a Line
@@ -349,7 +399,9 @@ def length(
         method: The method to use for length calculation. One of "Ellipsoidal",
             "Euclidean", "Haversine", or "Vincenty". Refer to the documentation on
             [LengthMethod][geoarrow.rust.compute.enums.LengthMethod] for more
-            information. Defaults to LengthMethod.Euclidean.
+            information. Defaults to LengthMethod.Ellipsoidal if the input has spherical
+            edges and LengthMethod.Euclidean otherwise. LengthMethod.Euclidean raises an
+            error for input with spherical edges.
 
     Returns:
         Array or chunked array with length values.
@@ -394,6 +446,8 @@ def line_interpolate_point(
             - A numpy `ndarray` with `float64` data type.
             - An Arrow array or chunked array with `float64` data type.
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         PointArray or ChunkedPointArray with result values
     """
@@ -433,6 +487,8 @@ def line_locate_point(
             - Any Python class that implements the Geo Interface, such as a [`shapely` Point][shapely.Point]
             - Any GeoArrow array or chunked array of `Point` type
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         Array or chunked array with float fraction values.
     """
@@ -466,6 +522,8 @@ def polylabel(
             documentation](https://github.com/mapbox/polylabel/blob/07c112091b4c9ffeb412af33c575133168893b4a/README.md#how-the-algorithm-works)
             for more information
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         PointArray or ChunkedPointArray with result values
     """
@@ -474,18 +532,18 @@ def polylabel(
 def signed_area(
     input: ArrowArrayExportable,
     *,
-    method: AreaMethod | AreaMethodT = AreaMethod.Euclidean,
+    method: AreaMethod | AreaMethodT | None = None,
 ) -> Array: ...
 @overload
 def signed_area(
     input: ArrowStreamExportable,
     *,
-    method: AreaMethod | AreaMethodT = AreaMethod.Euclidean,
+    method: AreaMethod | AreaMethodT | None = None,
 ) -> ChunkedArray: ...
 def signed_area(
     input: ArrowArrayExportable | ArrowStreamExportable,
     *,
-    method: AreaMethod | AreaMethodT = AreaMethod.Euclidean,
+    method: AreaMethod | AreaMethodT | None = None,
 ) -> Array | ChunkedArray:
     """
     Signed area of a geometry array
@@ -497,6 +555,8 @@ def signed_area(
          method: The method to use for area calculation. One of "Ellipsoidal", "Euclidean", or
             "Spherical". Refer to the documentation on
             [AreaMethod][geoarrow.rust.compute.enums.AreaMethod] for more information.
+            Defaults to "Ellipsoidal" if the input has spherical edges and "Euclidean"
+            otherwise. "Euclidean" raises an error for input with spherical edges.
 
     Returns:
         Array or chunked array with area values.
@@ -576,6 +636,8 @@ def simplify(
             [SimplifyMethod][geoarrow.rust.compute.enums.SimplifyMethod] for more
             information. Defaults to SimplifyMethod.RDP.
 
+    Raises an error for input with spherical edges, which are not supported.
+
     Returns:
         Simplified geometry array.
     """
@@ -32,6 +32,7 @@ pub fn affine_transform(
     input: AnyNativeInput,
     transform: AffineTransform,
 ) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("affine_transform")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().affine_transform(&transform.0)?;
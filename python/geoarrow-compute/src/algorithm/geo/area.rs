@@ -26,12 +26,33 @@ impl<'a> FromPyObject<'a> for AreaMethod {
     }
 }
 
+impl AreaMethod {
+    /// The method to use for the input, which is ellipsoidal for spherical edges and Euclidean
+    /// otherwise if not given. Euclidean area is an error for spherical edges.
+    fn resolve(method: Option<Self>, input: &AnyNativeInput) -> PyGeoArrowResult<Self> {
+        match method {
+            None if input.is_spherical() => Ok(Self::Geodesic),
+            None => Ok(Self::Euclidean),
+            Some(Self::Euclidean) => {
+                input.require_planar("Euclidean area")?;
+                Ok(Self::Euclidean)
+            }
+            Some(method) => Ok(method),
+        }
+    }
+}
+
 #[pyfunction]
 #[pyo3(
-    signature = (input, *, method = AreaMethod::Euclidean),
-    text_signature = "(input, *, method = 'euclidean')")
+    signature = (input, *, method = None),
+    text_signature = "(input, *, method = None)")
 ]
-pub fn area(py: Python, input: AnyNativeInput, method: AreaMethod) -> PyGeoArrowResult<PyObject> {
+pub fn area(
+    py: Python,
+    input: AnyNativeInput,
+    method: Option<AreaMethod>,
+) -> PyGeoArrowResult<PyObject> {
+    let method = AreaMethod::resolve(method, &input)?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = match method {
@@ -58,14 +79,15 @@ pub fn area(py: Python, input: AnyNativeInput, method: AreaMethod) -> PyGeoArrow
 
 #[pyfunction]
 #[pyo3(
-    signature = (input, *, method = AreaMethod::Euclidean),
-    text_signature = "(input, *, method = 'euclidean')")
+    signature = (input, *, method = None),
+    text_signature = "(input, *, method = None)")
 ]
 pub fn signed_area(
     py: Python,
     input: AnyNativeInput,
-    method: AreaMethod,
+    method: Option<AreaMethod>,
 ) -> PyGeoArrowResult<PyObject> {
+    let method = AreaMethod::resolve(method, &input)?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = match method {
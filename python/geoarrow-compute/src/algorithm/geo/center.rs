@@ -8,6 +8,7 @@ use pyo3_geoarrow::PyGeoArrowResult;
 
 #[pyfunction]
 pub fn center(py: Python, input: AnyNativeInput) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("center")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().center()?;
@@ -8,6 +8,7 @@ use pyo3_geoarrow::PyGeoArrowResult;
 
 #[pyfunction]
 pub fn centroid(py: Python, input: AnyNativeInput) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("centroid")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().centroid()?;
@@ -10,6 +10,7 @@ pub fn chaikin_smoothing(
     input: AnyNativeInput,
     n_iterations: u32,
 ) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("chaikin_smoothing")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().chaikin_smoothing(n_iterations)?;
@@ -10,6 +10,7 @@ use pyo3_geoarrow::PyGeoArrowResult;
 
 #[pyfunction]
 pub fn convex_hull(py: Python, input: AnyNativeInput) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("convex_hull")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out: PolygonArray = arr.as_ref().convex_hull()?;
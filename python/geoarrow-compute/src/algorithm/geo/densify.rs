@@ -6,13 +6,22 @@ use pyo3_geoarrow::PyGeoArrowResult;
 
 #[pyfunction]
 pub fn densify(py: Python, input: AnyNativeInput, max_distance: f64) -> PyGeoArrowResult<PyObject> {
+    let spherical = input.is_spherical();
     match input {
         AnyNativeInput::Array(arr) => {
-            let out = arr.as_ref().densify(max_distance)?;
+            let out = if spherical {
+                arr.as_ref().densify_geodesic(max_distance)?
+            } else {
+                arr.as_ref().densify(max_distance)?
+            };
             return_geometry_array(py, out)
         }
         AnyNativeInput::Chunked(arr) => {
-            let out = arr.as_ref().densify(max_distance)?;
+            let out = if spherical {
+                arr.as_ref().densify_geodesic(max_distance)?
+            } else {
+                arr.as_ref().densify(max_distance)?
+            };
             return_chunked_geometry_array(py, out)
         }
     }
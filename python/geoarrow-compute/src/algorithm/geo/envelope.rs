@@ -8,6 +8,7 @@ use pyo3_geoarrow::PyGeoArrowResult;
 
 #[pyfunction]
 pub fn envelope(py: Python, input: AnyNativeInput) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("envelope")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().bounding_rect()?;
@@ -15,6 +15,8 @@ pub fn frechet_distance(
     input: AnyNativeInput,
     other: AnyNativeBroadcastInput,
 ) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("frechet_distance")?;
+    other.require_planar("frechet_distance")?;
     match (input, other) {
         (AnyNativeInput::Array(left), AnyNativeBroadcastInput::Array(right)) => {
             let result = FrechetDistance::frechet_distance(&left.as_ref(), &right.as_ref())?;
@@ -28,16 +28,33 @@ impl<'a> FromPyObject<'a> for LengthMethod {
     }
 }
 
+impl LengthMethod {
+    /// The method to use for the input, which is ellipsoidal for spherical edges and Euclidean
+    /// otherwise if not given. Euclidean length is an error for spherical edges.
+    fn resolve(method: Option<Self>, input: &AnyNativeInput) -> PyGeoArrowResult<Self> {
+        match method {
+            None if input.is_spherical() => Ok(Self::Geodesic),
+            None => Ok(Self::Euclidean),
+            Some(Self::Euclidean) => {
+                input.require_planar("Euclidean length")?;
+                Ok(Self::Euclidean)
+            }
+            Some(method) => Ok(method),
+        }
+    }
+}
+
 #[pyfunction]
 #[pyo3(
-    signature = (input, *, method = LengthMethod::Euclidean),
-    text_signature = "(input, *, method = 'euclidean')")
+    signature = (input, *, method = None),
+    text_signature = "(input, *, method = None)")
 ]
 pub fn length(
     py: Python,
     input: AnyNativeInput,
-    method: LengthMethod,
+    method: Option<LengthMethod>,
 ) -> PyGeoArrowResult<PyObject> {
+    let method = LengthMethod::resolve(method, &input)?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = match method {
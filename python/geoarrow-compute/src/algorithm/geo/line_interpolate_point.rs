@@ -15,6 +15,7 @@ pub fn line_interpolate_point(
     input: AnyNativeInput,
     fraction: AnyPrimitiveBroadcastInput<Float64Type>,
 ) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("line_interpolate_point")?;
     match (input, fraction) {
         (AnyNativeInput::Array(arr), AnyPrimitiveBroadcastInput::Array(fraction)) => {
             let out = arr.as_ref().line_interpolate_point(&fraction)?;
@@ -15,6 +15,8 @@ pub fn line_locate_point(
     input: AnyNativeInput,
     point: AnyNativeBroadcastInput,
 ) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("line_locate_point")?;
+    point.require_planar("line_locate_point")?;
     match (input, point) {
         (AnyNativeInput::Array(arr), AnyNativeBroadcastInput::Array(point)) => {
             let result = LineLocatePoint::line_locate_point(&arr.as_ref(), point.as_ref())?;
@@ -9,6 +9,7 @@ pub(crate) mod dimensions;
 pub(crate) mod envelope;
 pub(crate) mod frechet_distance;
 pub(crate) mod geodesic_area;
+pub(crate) mod hausdorff_distance;
 pub(crate) mod length;
 pub(crate) mod line_interpolate_point;
 pub(crate) mod line_locate_point;
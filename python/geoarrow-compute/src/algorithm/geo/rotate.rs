@@ -1,6 +1,6 @@
 use crate::ffi::from_python::AnyNativeInput;
 use crate::util::{return_chunked_geometry_array, return_geometry_array};
-use geoarrow::algorithm::geo::Rotate;
+use geoarrow::algorithm::geo::{edges, Rotate};
 use geoarrow::chunked_array::ChunkedNativeArrayDyn;
 use geoarrow::error::GeoArrowError;
 use pyo3::exceptions::{PyTypeError, PyValueError};
@@ -23,6 +23,8 @@ impl<'a> FromPyObject<'a> for Origin {
                 _ => Err(PyValueError::new_err("Unexpected origin method")),
             }
         } else if let Ok(geom) = ob.extract::<PyGeometry>() {
+            edges::require_planar(geom.as_ref(), "rotate")
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
             let point = geom
                 .inner()
                 .to_geo_point()
@@ -56,6 +58,7 @@ pub fn rotate(
     angle: f64,
     origin: Origin,
 ) -> PyGeoArrowResult<PyObject> {
+    geom.require_planar("rotate")?;
     match geom {
         AnyNativeInput::Array(arr) => {
             let out = match origin {
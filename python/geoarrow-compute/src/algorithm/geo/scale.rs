@@ -14,6 +14,7 @@ pub fn scale(
     xfact: f64,
     yfact: f64,
 ) -> PyGeoArrowResult<PyObject> {
+    geom.require_planar("scale")?;
     match geom {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().scale_xy(&xfact.into(), &yfact.into())?;
@@ -34,6 +34,7 @@ pub fn simplify(
     epsilon: f64,
     method: SimplifyMethod,
 ) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("simplify")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = match method {
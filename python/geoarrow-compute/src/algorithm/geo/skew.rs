@@ -9,6 +9,7 @@ use pyo3_geoarrow::PyGeoArrowResult;
 #[pyfunction]
 #[pyo3(signature = (geom, xs=0.0, ys=0.0))]
 pub fn skew(py: Python, geom: AnyNativeInput, xs: f64, ys: f64) -> PyGeoArrowResult<PyObject> {
+    geom.require_planar("skew")?;
     match geom {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().skew_xy(&xs.into(), &ys.into())?;
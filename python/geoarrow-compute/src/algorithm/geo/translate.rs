@@ -14,6 +14,7 @@ pub(crate) fn translate(
     xoff: f64,
     yoff: f64,
 ) -> PyGeoArrowResult<PyObject> {
+    geom.require_planar("translate")?;
     match geom {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().translate(&xoff.into(), &yoff.into())?;
@@ -9,6 +9,7 @@ use pyo3_geoarrow::{PyChunkedNativeArray, PyNativeArray};
 
 #[pyfunction]
 pub fn polylabel(py: Python, input: AnyNativeInput, tolerance: f64) -> PyGeoArrowResult<PyObject> {
+    input.require_planar("polylabel")?;
     match input {
         AnyNativeInput::Array(arr) => {
             let out = arr.as_ref().polylabel(tolerance)?;
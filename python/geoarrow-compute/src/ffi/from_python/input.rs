@@ -3,14 +3,15 @@ use arrow::compute::cast;
 use arrow::datatypes::{ArrowPrimitiveType, DataType, Float64Type};
 use arrow_array::{Array, PrimitiveArray};
 use arrow_buffer::ScalarBuffer;
-use geoarrow::chunked_array::ChunkedArray;
+use geoarrow::algorithm::geo::edges;
+use geoarrow::chunked_array::{ChunkedArray, ChunkedNativeArray};
 use numpy::PyReadonlyArray1;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::{PyAny, PyResult};
 use pyo3_arrow::input::AnyArray;
 use pyo3_arrow::PyArray;
-use pyo3_geoarrow::{PyChunkedNativeArray, PyGeometry, PyNativeArray};
+use pyo3_geoarrow::{PyChunkedNativeArray, PyGeoArrowResult, PyGeometry, PyNativeArray};
 
 pub enum AnyNativeInput {
     Array(PyNativeArray),
@@ -31,6 +32,34 @@ impl<'a> FromPyObject<'a> for AnyNativeInput {
     }
 }
 
+impl AnyNativeInput {
+    /// Whether the edges of any chunk of the input follow great circles.
+    pub fn is_spherical(&self) -> bool {
+        match self {
+            Self::Array(arr) => edges::is_spherical(arr.as_ref()),
+            Self::Chunked(arr) => arr
+                .as_ref()
+                .geometry_chunks()
+                .iter()
+                .any(|chunk| edges::is_spherical(chunk.as_ref())),
+        }
+    }
+
+    /// Return an error if the input has spherical edges, for an `operation` that is only
+    /// implemented for planar edges.
+    pub fn require_planar(&self, operation: &str) -> PyGeoArrowResult<()> {
+        match self {
+            Self::Array(arr) => edges::require_planar(arr.as_ref(), operation)?,
+            Self::Chunked(arr) => {
+                for chunk in arr.as_ref().geometry_chunks() {
+                    edges::require_planar(chunk.as_ref(), operation)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 pub enum AnyNativeBroadcastInput {
     Array(PyNativeArray),
     Chunked(PyChunkedNativeArray),
@@ -53,6 +82,23 @@ impl<'a> FromPyObject<'a> for AnyNativeBroadcastInput {
     }
 }
 
+impl AnyNativeBroadcastInput {
+    /// Return an error if the input has spherical edges, for an `operation` that is only
+    /// implemented for planar edges.
+    pub fn require_planar(&self, operation: &str) -> PyGeoArrowResult<()> {
+        match self {
+            Self::Array(arr) => edges::require_planar(arr.as_ref(), operation)?,
+            Self::Chunked(arr) => {
+                for chunk in arr.as_ref().geometry_chunks() {
+                    edges::require_planar(chunk.as_ref(), operation)?;
+                }
+            }
+            Self::Scalar(scalar) => edges::require_planar(scalar.as_ref(), operation)?,
+        }
+        Ok(())
+    }
+}
+
 pub enum AnyPrimitiveBroadcastInput<T: ArrowPrimitiveType> {
     Array(PrimitiveArray<T>),
     Chunked(ChunkedArray<PrimitiveArray<T>>),
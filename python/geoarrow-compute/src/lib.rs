@@ -77,6 +77,10 @@ fn _compute(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         crate::algorithm::geo::geodesic_area::geodesic_perimeter,
         m
     )?)?;
+    m.add_function(wrap_pyfunction!(
+        crate::algorithm::geo::hausdorff_distance::hausdorff_distance,
+        m
+    )?)?;
     m.add_function(wrap_pyfunction!(crate::algorithm::geo::length::length, m)?)?;
     m.add_function(wrap_pyfunction!(
         crate::algorithm::geo::line_interpolate_point::line_interpolate_point,
@@ -1,7 +1,10 @@
 from typing import List, Tuple
 
-from arro3.core.types import ArrayInput
-from geoarrow.rust.core.types import CRSInput
+import numpy as np
+from arro3.core.types import ArrayInput, ArrowArrayExportable
+from geoarrow.rust.core.enums import Edges
+from geoarrow.rust.core.types import CRSInput, EdgesT
+from numpy.typing import NDArray
 
 from ._rust import NativeArray
 
@@ -9,12 +12,14 @@ def points(
     coords: ArrayInput | Tuple[ArrayInput, ...] | List[ArrayInput],
     *,
     crs: CRSInput | None = None,
+    edges: Edges | EdgesT | None = None,
 ) -> NativeArray: ...
 def linestrings(
     coords: ArrayInput | Tuple[ArrayInput, ...] | List[ArrayInput],
     geom_offsets: ArrayInput,
     *,
     crs: CRSInput | None = None,
+    edges: Edges | EdgesT | None = None,
 ) -> NativeArray: ...
 def polygons(
     coords: ArrayInput | Tuple[ArrayInput, ...] | List[ArrayInput],
@@ -22,11 +27,13 @@ def polygons(
     ring_offsets: ArrayInput,
     *,
     crs: CRSInput | None = None,
+    edges: Edges | EdgesT | None = None,
 ) -> NativeArray: ...
 def multipoints(
     coords: ArrayInput | Tuple[ArrayInput, ...] | List[ArrayInput],
     *,
     crs: CRSInput | None = None,
+    edges: Edges | EdgesT | None = None,
 ) -> NativeArray: ...
 def multilinestrings(
     coords: ArrayInput | Tuple[ArrayInput, ...] | List[ArrayInput],
@@ -34,6 +41,7 @@ def multilinestrings(
     ring_offsets: ArrayInput,
     *,
     crs: CRSInput | None = None,
+    edges: Edges | EdgesT | None = None,
 ) -> NativeArray: ...
 def multipolygons(
     coords: ArrayInput | Tuple[ArrayInput, ...] | List[ArrayInput],
@@ -42,4 +50,27 @@ def multipolygons(
     ring_offsets: ArrayInput,
     *,
     crs: CRSInput | None = None,
+    edges: Edges | EdgesT | None = None,
 ) -> NativeArray: ...
+def buffers(
+    array: ArrowArrayExportable,
+) -> Tuple[
+    NDArray[np.float64] | Tuple[NDArray[np.float64], ...] | NDArray[np.int32], ...
+]:
+    """
+    Export the coordinates and offsets of a geometry array to NumPy.
+
+    The buffers are returned in the order the matching constructor accepts them, so that for
+    example `linestrings(*buffers(array))` recreates a LineString array. Interleaved
+    coordinates are returned as one array of shape `(N, 2)` or `(N, 3)`, and separated
+    coordinates as a tuple of one array per dimension. The buffers are copied.
+
+    Only Point, LineString, Polygon, MultiPoint, MultiLineString and MultiPolygon arrays
+    without null values are supported.
+
+    Args:
+        array: the geometry array to export.
+
+    Returns:
+        A tuple of the coordinates followed by the offsets of the array.
+    """
@@ -28,6 +28,7 @@ try:
 except ImportError:
     pass
 
+from geoarrow.rust.core._constructors import buffers as buffers
 from geoarrow.rust.core._constructors import linestrings as linestrings
 from geoarrow.rust.core._constructors import multilinestrings as multilinestrings
 from geoarrow.rust.core._constructors import multipoints as multipoints
@@ -36,8 +37,8 @@ from geoarrow.rust.core._constructors import points as points
 from geoarrow.rust.core._constructors import polygons as polygons
 from geoarrow.rust.core.types import CRSInput
 
-from .enums import CoordType, Dimension
-from .types import CoordTypeT, DimensionT
+from .enums import CoordType, Dimension, Edges
+from .types import CoordTypeT, DimensionT, EdgesT
 
 class Geometry:
     """
@@ -116,6 +117,24 @@ class NativeArray:
     @property
     def type(self) -> NativeType:
         """Get the geometry type of this array."""
+    @property
+    def edges(self) -> Edges:
+        """How the edges between coordinates are interpreted.
+
+        Edges are planar unless the array metadata declares them as spherical.
+        """
+    def set_edges(self, edges: Edges | EdgesT) -> NativeArray:
+        """Set how the edges between coordinates are interpreted.
+
+        Compute functions use spherical implementations for arrays with spherical edges
+        where they exist, and raise an error otherwise.
+
+        Args:
+            edges: The new edges.
+
+        Returns:
+            A new array with updated metadata, sharing the same geometries.
+        """
 
 class SerializedArray:
     """An immutable array of serialized geometries (WKB or WKT)."""
@@ -168,6 +187,9 @@ class ChunkedNativeArray:
 
         For example, you can call [`pyarrow.chunked_array()`][pyarrow.chunked_array] to
         convert this array into a pyarrow array, without copying memory.
+
+        If the consumer requests a different GeoArrow type, such as separated
+        coordinates, each chunk is cast as it is read.
         """
     def __eq__(self, other: object) -> bool: ...
     def __getitem__(self, key: int) -> Geometry:
@@ -176,12 +198,55 @@ class ChunkedNativeArray:
         """The number of rows."""
     def __repr__(self) -> str:
         """Text representation."""
+    def cast(self, to_type: NativeType) -> ChunkedNativeArray:
+        """Cast every chunk to another geometry type.
+
+        Args:
+            to_type: The geometry type to cast to.
+
+        Returns:
+            A new chunked array of the given type.
+        """
     def chunk(self, i: int) -> NativeArray:
         """Access a single underlying chunk."""
     def chunks(self) -> List[NativeArray]:
         """Convert to a list of single-chunked arrays."""
+    def combine_chunks(self) -> NativeArray:
+        """Concatenate all chunks into a single array, keeping the CRS metadata."""
     def num_chunks(self) -> int:
         """Number of underlying chunks."""
+    def rechunk(self, *, max_chunksize: int | None = None) -> ChunkedNativeArray:
+        """Rechunk into chunks of at most `max_chunksize` rows.
+
+        Args:
+            max_chunksize: The maximum number of rows per chunk. If `None`, all rows are
+                combined into a single chunk.
+
+        Returns:
+            A new chunked array.
+        """
+    def slice(self, offset: int = 0, length: int | None = None) -> ChunkedNativeArray:
+        """Compute a zero-copy slice of this array.
+
+        Args:
+            offset: The offset of the first row to include.
+            length: The number of rows to include. Defaults to all rows after `offset`.
+
+        Returns:
+            The sliced chunked array.
+        """
+    @classmethod
+    def from_arrays(cls, arrays: Sequence[NativeArray]) -> Self:
+        """Construct a chunked array from a list of geometry arrays.
+
+        Arrays of differing geometry types are cast to their common type.
+
+        Args:
+            arrays: The arrays to use as chunks. Must not be empty.
+
+        Returns:
+            Self
+        """
     @classmethod
     def from_arrow(cls, data: ArrowArrayExportable) -> Self:
         """Construct this object from existing Arrow data
@@ -200,6 +265,75 @@ class ChunkedNativeArray:
     @property
     def type(self) -> NativeType:
         """Get the geometry type of this array."""
+    @property
+    def edges(self) -> Edges:
+        """How the edges between coordinates are interpreted.
+
+        Edges are planar unless the array metadata declares them as spherical.
+        """
+    def set_edges(self, edges: Edges | EdgesT) -> ChunkedNativeArray:
+        """Set how the edges between coordinates are interpreted for every chunk.
+
+        Args:
+            edges: The new edges.
+
+        Returns:
+            A new chunked array with updated metadata, sharing the same geometries.
+        """
+
+class NativeArrayReader:
+    """A stream of geometry arrays that all have the same type.
+
+    The stream can only be consumed once.
+    """
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """
+        An implementation of the [Arrow PyCapsule
+        Interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+        This dunder method should not be called directly, but enables zero-copy data
+        transfer to other Python libraries that understand Arrow memory.
+
+        If the consumer requests a different GeoArrow type, such as separated
+        coordinates, each array is cast as it is read.
+        """
+    def __iter__(self) -> Self: ...
+    def __next__(self) -> NativeArray: ...
+    def __repr__(self) -> str:
+        """Text representation."""
+    @classmethod
+    def from_arrays(cls, type: NativeType, arrays: Sequence[NativeArray]) -> Self:
+        """Construct a reader from a list of arrays of the same type.
+
+        Args:
+            type: The geometry type of every array.
+            arrays: The arrays to yield from the reader.
+
+        Returns:
+            Self
+        """
+    @classmethod
+    def from_arrow(cls, data: ArrowStreamExportable) -> Self:
+        """Construct this object from an existing Arrow stream
+
+        Args:
+            input: Arrow stream to use for constructing this object
+
+        Returns:
+            Self
+        """
+    @classmethod
+    def from_arrow_pycapsule(cls, capsule: object) -> Self:
+        """Construct this object from a raw Arrow C Stream capsule."""
+    @property
+    def closed(self) -> bool:
+        """Returns `True` if this reader has already been consumed."""
+    def read_all(self) -> ChunkedNativeArray:
+        """Read all remaining arrays into a chunked array."""
+    def read_next_array(self) -> NativeArray:
+        """Read the next array from the stream."""
+    @property
+    def type(self) -> NativeType:
+        """Get the geometry type of this stream."""
 
 class NativeType:
     @overload
@@ -247,7 +381,8 @@ class NativeType:
             type: The string type of the geometry. One of `"point"`, `"linestring"`,
                 `"polygon"`, `"multipoint"`, `"multilinestring"`, `"multipolygon"`,
                 `"geometry"`, `"geometrycollection"`, `"box"`.
-            dimension: The coordinate dimension. Either "XY" or "XYZ". Defaults to None.
+            dimension: The coordinate dimension. One of "XY", "XYZ", "XYM" or "XYZM".
+                Defaults to None.
             coord_type: The coordinate type. Defaults to None.
         """
     def __arrow_c_schema__(self) -> object:
@@ -42,3 +42,23 @@ class Dimension(StrEnum):
     XYZ = auto()
     """Three dimensions, X, Y, and Z
     """
+
+    XYM = auto()
+    """Three dimensions, X, Y, and M
+    """
+
+    XYZM = auto()
+    """Four dimensions, X, Y, Z, and M
+    """
+
+
+class Edges(StrEnum):
+    """How edges between coordinates are interpreted."""
+
+    Planar = auto()
+    """Edges are straight lines in the coordinate space.
+    """
+
+    Spherical = auto()
+    """Edges follow the shortest path on the sphere (great circles).
+    """
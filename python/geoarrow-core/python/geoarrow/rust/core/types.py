@@ -14,6 +14,10 @@ CoordTypeT = Literal["interleaved", "separated"]
 """Acceptable coord_type strings.
 """
 
-DimensionT = Literal["XY", "XYZ", "xy", "xyz"]
+DimensionT = Literal["XY", "XYZ", "XYM", "XYZM", "xy", "xyz", "xym", "xyzm"]
 """Acceptable dimension strings.
 """
+
+EdgesT = Literal["planar", "spherical"]
+"""Acceptable edges strings.
+"""
@@ -1,34 +1,47 @@
 use std::sync::Arc;
 
+use arrow_buffer::OffsetBuffer;
 use geoarrow::array::metadata::ArrayMetadata;
 use geoarrow::array::{
-    LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, NativeArrayDyn,
-    PointArray, PolygonArray,
+    AsNativeArray, CoordBuffer, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, NativeArrayDyn, PointArray, PolygonArray,
 };
+use geoarrow::datatypes::NativeType;
+use geoarrow::ArrayBase;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3_geoarrow::{PyCoordBuffer, PyGeoArrowResult, PyNativeArray, PyOffsetBuffer, CRS};
+use pyo3::types::PyTuple;
+use pyo3_geoarrow::{PyCoordBuffer, PyEdges, PyGeoArrowResult, PyNativeArray, PyOffsetBuffer, CRS};
 
-fn create_array_metadata(crs: Option<CRS>) -> Arc<ArrayMetadata> {
-    Arc::new(crs.map(|inner| inner.into_inner()).unwrap_or_default())
+fn create_array_metadata(crs: Option<CRS>, edges: Option<PyEdges>) -> Arc<ArrayMetadata> {
+    Arc::new(ArrayMetadata {
+        edges: edges.and_then(Option::from),
+        ..crs.map(|inner| inner.into_inner()).unwrap_or_default()
+    })
 }
 
 #[pyfunction]
-#[pyo3(signature = (coords, *, crs = None))]
-pub fn points(coords: PyCoordBuffer, crs: Option<CRS>) -> PyGeoArrowResult<PyNativeArray> {
-    let metadata = create_array_metadata(crs);
+#[pyo3(signature = (coords, *, crs = None, edges = None))]
+pub fn points(
+    coords: PyCoordBuffer,
+    crs: Option<CRS>,
+    edges: Option<PyEdges>,
+) -> PyGeoArrowResult<PyNativeArray> {
+    let metadata = create_array_metadata(crs, edges);
     // TODO: remove const generic
     let array = PointArray::new(coords.into_inner(), None, metadata);
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
 
 #[pyfunction]
-#[pyo3(signature = (coords, geom_offsets, *, crs = None))]
+#[pyo3(signature = (coords, geom_offsets, *, crs = None, edges = None))]
 pub fn linestrings(
     coords: PyCoordBuffer,
     geom_offsets: PyOffsetBuffer,
     crs: Option<CRS>,
+    edges: Option<PyEdges>,
 ) -> PyGeoArrowResult<PyNativeArray> {
-    let metadata = create_array_metadata(crs);
+    let metadata = create_array_metadata(crs, edges);
     // TODO: remove const generic
     let array = LineStringArray::new(
         coords.into_inner(),
@@ -40,14 +53,15 @@ pub fn linestrings(
 }
 
 #[pyfunction]
-#[pyo3(signature = (coords, geom_offsets, ring_offsets, *, crs = None))]
+#[pyo3(signature = (coords, geom_offsets, ring_offsets, *, crs = None, edges = None))]
 pub fn polygons(
     coords: PyCoordBuffer,
     geom_offsets: PyOffsetBuffer,
     ring_offsets: PyOffsetBuffer,
     crs: Option<CRS>,
+    edges: Option<PyEdges>,
 ) -> PyGeoArrowResult<PyNativeArray> {
-    let metadata = create_array_metadata(crs);
+    let metadata = create_array_metadata(crs, edges);
     // TODO: remove const generic
     let array = PolygonArray::new(
         coords.into_inner(),
@@ -60,13 +74,14 @@ pub fn polygons(
 }
 
 #[pyfunction]
-#[pyo3(signature = (coords, geom_offsets, *, crs = None))]
+#[pyo3(signature = (coords, geom_offsets, *, crs = None, edges = None))]
 pub fn multipoints(
     coords: PyCoordBuffer,
     geom_offsets: PyOffsetBuffer,
     crs: Option<CRS>,
+    edges: Option<PyEdges>,
 ) -> PyGeoArrowResult<PyNativeArray> {
-    let metadata = create_array_metadata(crs);
+    let metadata = create_array_metadata(crs, edges);
     let array = MultiPointArray::new(
         coords.into_inner(),
         geom_offsets.into_inner(),
@@ -77,14 +92,15 @@ pub fn multipoints(
 }
 
 #[pyfunction]
-#[pyo3(signature = (coords, geom_offsets, ring_offsets, *, crs = None))]
+#[pyo3(signature = (coords, geom_offsets, ring_offsets, *, crs = None, edges = None))]
 pub fn multilinestrings(
     coords: PyCoordBuffer,
     geom_offsets: PyOffsetBuffer,
     ring_offsets: PyOffsetBuffer,
     crs: Option<CRS>,
+    edges: Option<PyEdges>,
 ) -> PyGeoArrowResult<PyNativeArray> {
-    let metadata = create_array_metadata(crs);
+    let metadata = create_array_metadata(crs, edges);
     let array = MultiLineStringArray::new(
         coords.into_inner(),
         geom_offsets.into_inner(),
@@ -96,15 +112,16 @@ pub fn multilinestrings(
 }
 
 #[pyfunction]
-#[pyo3(signature = (coords, geom_offsets, polygon_offsets, ring_offsets, *, crs = None))]
+#[pyo3(signature = (coords, geom_offsets, polygon_offsets, ring_offsets, *, crs = None, edges = None))]
 pub fn multipolygons(
     coords: PyCoordBuffer,
     geom_offsets: PyOffsetBuffer,
     polygon_offsets: PyOffsetBuffer,
     ring_offsets: PyOffsetBuffer,
     crs: Option<CRS>,
+    edges: Option<PyEdges>,
 ) -> PyGeoArrowResult<PyNativeArray> {
-    let metadata = create_array_metadata(crs);
+    let metadata = create_array_metadata(crs, edges);
     let array = MultiPolygonArray::new(
         coords.into_inner(),
         geom_offsets.into_inner(),
@@ -115,3 +132,70 @@ pub fn multipolygons(
     );
     Ok(PyNativeArray::new(NativeArrayDyn::new(Arc::new(array))))
 }
+
+/// Export the coordinates and offsets of a geometry array to NumPy, in the order the matching
+/// constructor accepts them.
+#[pyfunction]
+pub fn buffers<'py>(
+    py: Python<'py>,
+    array: PyNativeArray,
+) -> PyGeoArrowResult<Bound<'py, PyTuple>> {
+    let array = array.as_ref();
+    if array.null_count() != 0 {
+        return Err(PyValueError::new_err(format!(
+            "Cannot export buffers of an array with null values. Got {} nulls.",
+            array.null_count()
+        ))
+        .into());
+    }
+
+    let coords = |coords: &CoordBuffer| PyCoordBuffer::from(coords.clone());
+    let offsets = |offsets: &OffsetBuffer<i32>| PyOffsetBuffer::from(offsets.clone());
+
+    use NativeType::*;
+    let buffers = match array.data_type() {
+        Point(_, _) => (coords(array.as_point().coords()),).into_pyobject(py)?,
+        LineString(_, _) => {
+            let arr = array.as_line_string();
+            (coords(arr.coords()), offsets(arr.geom_offsets())).into_pyobject(py)?
+        }
+        Polygon(_, _) => {
+            let arr = array.as_polygon();
+            (
+                coords(arr.coords()),
+                offsets(arr.geom_offsets()),
+                offsets(arr.ring_offsets()),
+            )
+                .into_pyobject(py)?
+        }
+        MultiPoint(_, _) => {
+            let arr = array.as_multi_point();
+            (coords(arr.coords()), offsets(arr.geom_offsets())).into_pyobject(py)?
+        }
+        MultiLineString(_, _) => {
+            let arr = array.as_multi_line_string();
+            (
+                coords(arr.coords()),
+                offsets(arr.geom_offsets()),
+                offsets(arr.ring_offsets()),
+            )
+                .into_pyobject(py)?
+        }
+        MultiPolygon(_, _) => {
+            let arr = array.as_multi_polygon();
+            (
+                coords(arr.coords()),
+                offsets(arr.geom_offsets()),
+                offsets(arr.polygon_offsets()),
+                offsets(arr.ring_offsets()),
+            )
+                .into_pyobject(py)?
+        }
+        t => {
+            return Err(
+                PyValueError::new_err(format!("Cannot export buffers of {:?} arrays", t)).into(),
+            )
+        }
+    };
+    Ok(buffers)
+}
@@ -38,6 +38,7 @@ fn _rust(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<pyo3_geoarrow::PyGeometry>()?;
     m.add_class::<pyo3_geoarrow::PyNativeArray>()?;
     m.add_class::<pyo3_geoarrow::PyChunkedNativeArray>()?;
+    m.add_class::<pyo3_geoarrow::PyNativeArrayReader>()?;
     m.add_class::<pyo3_geoarrow::PyNativeType>()?;
 
     m.add_class::<pyo3_geoarrow::PySerializedArray>()?;
@@ -51,6 +52,7 @@ fn _rust(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(crate::constructors::multipoints, m)?)?;
     m.add_function(wrap_pyfunction!(crate::constructors::multilinestrings, m)?)?;
     m.add_function(wrap_pyfunction!(crate::constructors::multipolygons, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::constructors::buffers, m)?)?;
 
     // Top-level table functions
 
@@ -2,9 +2,10 @@ use std::sync::Arc;
 
 use crate::data_type::PySerializedType;
 use crate::error::{PyGeoArrowError, PyGeoArrowResult};
-use crate::{PyGeometry, PyNativeType};
+use crate::{PyEdges, PyGeometry, PyNativeType};
 use arrow::datatypes::Schema;
 use arrow_array::RecordBatch;
+use geoarrow::array::metadata::ArrayMetadata;
 use geoarrow::array::{NativeArrayDyn, SerializedArray, SerializedArrayDyn};
 use geoarrow::error::GeoArrowError;
 use geoarrow::scalar::GeometryScalar;
@@ -151,6 +152,19 @@ impl PyNativeArray {
     fn r#type(&self) -> PyNativeType {
         self.0.data_type().into()
     }
+
+    #[getter]
+    fn edges(&self, py: Python) -> PyResult<PyObject> {
+        PyEdges::from_metadata(&self.0.metadata()).to_python(py)
+    }
+
+    fn set_edges(&self, edges: PyEdges) -> Self {
+        let metadata = ArrayMetadata {
+            edges: edges.into(),
+            ..self.0.metadata().as_ref().clone()
+        };
+        self.0.with_metadata(Arc::new(metadata)).into()
+    }
 }
 
 impl From<NativeArrayDyn> for PyNativeArray {
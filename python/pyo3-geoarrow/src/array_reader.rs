@@ -0,0 +1,206 @@
+use std::sync::{Arc, Mutex};
+
+use arrow_schema::FieldRef;
+use geoarrow::array::metadata::ArrayMetadata;
+use geoarrow::array::NativeArrayDyn;
+use geoarrow::chunked_array::ChunkedNativeArrayDyn;
+use geoarrow::datatypes::NativeType;
+use geoarrow::io::{NativeArrayIterator, NativeArrayReader};
+use geoarrow::{ArrayBase, NativeArray};
+use pyo3::exceptions::{PyIOError, PyStopIteration, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyTuple, PyType};
+use pyo3_arrow::ffi::ArrayReader;
+use pyo3_arrow::PyArrayReader;
+
+use crate::array::PyNativeArray;
+use crate::chunked_array::PyChunkedNativeArray;
+use crate::error::{PyGeoArrowError, PyGeoArrowResult};
+use crate::ffi::to_python::to_native_stream_pycapsule;
+use crate::PyNativeType;
+
+/// A stream of geometry arrays that all have the same type.
+///
+/// The stream can only be consumed once.
+#[pyclass(
+    module = "geoarrow.rust.core._rust",
+    name = "NativeArrayReader",
+    subclass,
+    frozen
+)]
+pub struct PyNativeArrayReader {
+    reader: Mutex<Option<Box<dyn NativeArrayReader + Send>>>,
+    field: FieldRef,
+}
+
+impl PyNativeArrayReader {
+    /// Create a new reader, with the metadata to attach to the exported extension field.
+    pub fn new(reader: Box<dyn NativeArrayReader + Send>, metadata: Arc<ArrayMetadata>) -> Self {
+        let field = reader
+            .data_type()
+            .to_field_with_metadata("", true, &metadata);
+        Self {
+            reader: Mutex::new(Some(reader)),
+            field: Arc::new(field),
+        }
+    }
+
+    /// Import from a raw Arrow C Stream capsule
+    pub fn from_arrow_pycapsule(capsule: &Bound<PyCapsule>) -> PyGeoArrowResult<Self> {
+        PyArrayReader::from_arrow_pycapsule(capsule)?.try_into()
+    }
+
+    /// Consume this reader and take ownership of the underlying stream.
+    pub fn into_reader(self) -> PyGeoArrowResult<Box<dyn NativeArrayReader + Send>> {
+        self.take_reader()
+    }
+
+    fn take_reader(&self) -> PyGeoArrowResult<Box<dyn NativeArrayReader + Send>> {
+        self.reader
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or(PyIOError::new_err("Cannot read from closed stream.").into())
+    }
+
+    /// Export to a geoarrow.rust.core.NativeArrayReader.
+    ///
+    /// This requires that you depend on geoarrow-rust-core from your Python package.
+    pub fn to_geoarrow<'py>(&'py self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let geoarrow_mod = py.import(intern!(py, "geoarrow.rust.core"))?;
+        geoarrow_mod
+            .getattr(intern!(py, "NativeArrayReader"))?
+            .call_method1(
+                intern!(py, "from_arrow_pycapsule"),
+                PyTuple::new(py, vec![self.__arrow_c_stream__(py, None)?])?,
+            )
+    }
+}
+
+#[pymethods]
+impl PyNativeArrayReader {
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyCapsule>>,
+    ) -> PyGeoArrowResult<Bound<'py, PyCapsule>> {
+        let reader = self.take_reader()?;
+        to_native_stream_pycapsule(py, reader, self.field.clone(), requested_schema)
+    }
+
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&self) -> PyGeoArrowResult<PyNativeArray> {
+        self.read_next_array()
+    }
+
+    fn __repr__(&self) -> String {
+        "geoarrow.rust.core.NativeArrayReader".to_string()
+    }
+
+    #[classmethod]
+    fn from_arrow(_cls: &Bound<PyType>, data: &Bound<PyAny>) -> PyResult<Self> {
+        data.extract()
+    }
+
+    #[classmethod]
+    #[pyo3(name = "from_arrow_pycapsule")]
+    fn from_arrow_pycapsule_py(
+        _cls: &Bound<PyType>,
+        capsule: &Bound<PyCapsule>,
+    ) -> PyGeoArrowResult<Self> {
+        Self::from_arrow_pycapsule(capsule)
+    }
+
+    #[classmethod]
+    fn from_arrays(
+        _cls: &Bound<PyType>,
+        r#type: PyNativeType,
+        arrays: Vec<PyNativeArray>,
+    ) -> PyGeoArrowResult<Self> {
+        let data_type = r#type.into_inner();
+        let mut metadata = None;
+        let mut geo_arrays = Vec::with_capacity(arrays.len());
+        for array in arrays {
+            let array = array.into_inner().into_inner();
+            if array.data_type() != data_type {
+                return Err(PyValueError::new_err(format!(
+                    "Expected arrays of type {:?}, got {:?}",
+                    data_type,
+                    array.data_type()
+                ))
+                .into());
+            }
+            metadata.get_or_insert_with(|| array.metadata());
+            geo_arrays.push(Ok(array));
+        }
+
+        let reader = Box::new(NativeArrayIterator::new(geo_arrays, data_type));
+        Ok(Self::new(reader, metadata.unwrap_or_default()))
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.reader.lock().unwrap().is_none()
+    }
+
+    fn read_all(&self) -> PyGeoArrowResult<PyChunkedNativeArray> {
+        let reader = self.take_reader()?;
+        let arrays = reader.collect::<Result<Vec<_>, _>>()?;
+        let refs = arrays
+            .iter()
+            .map(|array| array.as_ref())
+            .collect::<Vec<_>>();
+        Ok(PyChunkedNativeArray::new(
+            ChunkedNativeArrayDyn::from_geoarrow_chunks(refs.as_slice())?.into_inner(),
+        ))
+    }
+
+    fn read_next_array(&self) -> PyGeoArrowResult<PyNativeArray> {
+        let mut inner = self.reader.lock().unwrap();
+        let reader = inner
+            .as_mut()
+            .ok_or(PyIOError::new_err("Cannot read from closed stream."))?;
+
+        match reader.next() {
+            Some(array) => Ok(array?.into()),
+            None => Err(PyStopIteration::new_err("").into()),
+        }
+    }
+
+    #[getter]
+    fn r#type(&self) -> PyGeoArrowResult<PyNativeType> {
+        Ok(NativeType::try_from(self.field.as_ref())?.into())
+    }
+}
+
+impl<'a> FromPyObject<'a> for PyNativeArrayReader {
+    fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
+        ob.extract::<PyArrayReader>()?
+            .try_into()
+            .map_err(PyErr::from)
+    }
+}
+
+impl TryFrom<PyArrayReader> for PyNativeArrayReader {
+    type Error = PyGeoArrowError;
+
+    fn try_from(value: PyArrayReader) -> Result<Self, Self::Error> {
+        let reader = value.into_reader()?;
+        let field = reader.field();
+        let data_type = NativeType::try_from(field.as_ref())?;
+
+        let array_field = field.clone();
+        let arrays = reader.map(move |array| -> geoarrow::error::Result<_> {
+            Ok(NativeArrayDyn::from_arrow_array(&array?, &array_field)?.into_inner())
+        });
+        Ok(Self {
+            reader: Mutex::new(Some(Box::new(NativeArrayIterator::new(arrays, data_type)))),
+            field,
+        })
+    }
+}
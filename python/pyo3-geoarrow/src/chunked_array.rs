@@ -1,20 +1,25 @@
 use std::sync::Arc;
 
+use arrow::compute::concat;
+use geoarrow::algorithm::native::Cast;
+use geoarrow::array::metadata::ArrayMetadata;
 use geoarrow::array::NativeArrayDyn;
 use geoarrow::chunked_array::{ChunkedNativeArray, ChunkedNativeArrayDyn};
+use geoarrow::io::NativeArrayIterator;
 use geoarrow::scalar::GeometryScalar;
-use pyo3::exceptions::PyIndexError;
+use geoarrow::{ArrayBase, NativeArray};
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyTuple, PyType};
-use pyo3_arrow::ffi::{to_stream_pycapsule, ArrayIterator};
 use pyo3_arrow::input::AnyArray;
 use pyo3_arrow::PyChunkedArray;
 
 use crate::array::PyNativeArray;
 use crate::error::{PyGeoArrowError, PyGeoArrowResult};
+use crate::ffi::to_python::to_native_stream_pycapsule;
 use crate::scalar::PyGeometry;
-use crate::PyNativeType;
+use crate::{PyEdges, PyNativeType};
 
 #[pyclass(
     module = "geoarrow.rust.core._rust",
@@ -34,6 +39,31 @@ impl PyChunkedNativeArray {
         self.0.as_ref()
     }
 
+    /// Create a chunked array from a list of geometry arrays.
+    ///
+    /// Arrays of differing types are cast to their common type.
+    pub fn from_arrays(arrays: Vec<PyNativeArray>) -> PyGeoArrowResult<Self> {
+        let refs = arrays
+            .iter()
+            .map(|array| array.as_ref())
+            .collect::<Vec<_>>();
+        Ok(Self(
+            ChunkedNativeArrayDyn::from_geoarrow_chunks(refs.as_slice())?.into_inner(),
+        ))
+    }
+
+    /// Concatenate all chunks into a single array, keeping the extension metadata.
+    pub fn combine_chunks(&self) -> PyGeoArrowResult<NativeArrayDyn> {
+        let field = self.0.extension_field();
+        let arrow_chunks = self.0.array_refs();
+        let refs = arrow_chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        let combined = concat(refs.as_slice())?;
+        Ok(NativeArrayDyn::from_arrow_array(&combined, &field)?)
+    }
+
     /// Import from a raw Arrow C Stream capsule
     pub fn from_arrow_pycapsule(capsule: &Bound<PyCapsule>) -> PyGeoArrowResult<Self> {
         PyChunkedArray::from_arrow_pycapsule(capsule)?.try_into()
@@ -60,12 +90,14 @@ impl PyChunkedNativeArray {
         &self,
         py: Python<'py>,
         requested_schema: Option<Bound<'py, PyCapsule>>,
-    ) -> PyResult<Bound<'py, PyCapsule>> {
+    ) -> PyGeoArrowResult<Bound<'py, PyCapsule>> {
         let field = self.0.extension_field();
-        let arrow_chunks = self.0.array_refs();
-
-        let array_reader = Box::new(ArrayIterator::new(arrow_chunks.into_iter().map(Ok), field));
-        Ok(to_stream_pycapsule(py, array_reader, requested_schema)?)
+        let chunks = self.0.geometry_chunks();
+        let reader = Box::new(NativeArrayIterator::new(
+            chunks.into_iter().map(Ok),
+            self.0.data_type(),
+        ));
+        to_native_stream_pycapsule(py, reader, field, requested_schema)
     }
 
     // /// Check for equality with other object.
@@ -145,10 +177,89 @@ impl PyChunkedNativeArray {
         Ok(out)
     }
 
+    #[classmethod]
+    #[pyo3(name = "from_arrays")]
+    fn from_arrays_py(_cls: &Bound<PyType>, arrays: Vec<PyNativeArray>) -> PyGeoArrowResult<Self> {
+        Self::from_arrays(arrays)
+    }
+
+    #[pyo3(name = "combine_chunks")]
+    fn combine_chunks_py(&self) -> PyGeoArrowResult<PyNativeArray> {
+        Ok(self.combine_chunks()?.into())
+    }
+
+    #[pyo3(signature = (*, max_chunksize=None))]
+    fn rechunk(&self, max_chunksize: Option<usize>) -> PyGeoArrowResult<Self> {
+        let combined = self.combine_chunks()?;
+        let max_chunksize = max_chunksize.unwrap_or(combined.len()).max(1);
+        if combined.is_empty() {
+            return Ok(Self(self.0.clone()));
+        }
+
+        let chunks = (0..combined.len())
+            .step_by(max_chunksize)
+            .map(|offset| combined.slice(offset, max_chunksize.min(combined.len() - offset)))
+            .collect::<Vec<_>>();
+        let refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(Self(
+            ChunkedNativeArrayDyn::from_geoarrow_chunks(refs.as_slice())?.into_inner(),
+        ))
+    }
+
+    #[pyo3(signature = (offset=0, length=None))]
+    fn slice(&self, offset: usize, length: Option<usize>) -> PyGeoArrowResult<Self> {
+        if offset > self.0.len() {
+            return Err(PyValueError::new_err("offset is greater than array length").into());
+        }
+        let length = length.unwrap_or(self.0.len() - offset);
+        if offset + length > self.0.len() {
+            return Err(
+                PyValueError::new_err("offset + length is greater than array length").into(),
+            );
+        }
+        Ok(Self(self.0.slice(offset, length)?))
+    }
+
+    fn cast(&self, to_type: PyNativeType) -> PyGeoArrowResult<Self> {
+        Ok(Self(self.0.as_ref().cast(to_type.into_inner())?))
+    }
+
     #[getter]
     fn r#type(&self) -> PyNativeType {
         self.0.data_type().into()
     }
+
+    #[getter]
+    fn edges(&self, py: Python) -> PyGeoArrowResult<PyObject> {
+        let metadata = ArrayMetadata::try_from(self.0.extension_field().as_ref())?;
+        Ok(PyEdges::from_metadata(&metadata).to_python(py)?)
+    }
+
+    fn set_edges(&self, edges: PyEdges) -> PyGeoArrowResult<Self> {
+        let edges = Option::from(edges);
+        let chunks = self
+            .0
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| {
+                let metadata = ArrayMetadata {
+                    edges: edges.clone(),
+                    ..chunk.metadata().as_ref().clone()
+                };
+                chunk.with_metadata(Arc::new(metadata))
+            })
+            .collect::<Vec<_>>();
+        let refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(Self(
+            ChunkedNativeArrayDyn::from_geoarrow_chunks(refs.as_slice())?.into_inner(),
+        ))
+    }
 }
 
 impl<'a> FromPyObject<'a> for PyChunkedNativeArray {
@@ -5,11 +5,17 @@ use arrow_buffer::ScalarBuffer;
 use arrow_schema::DataType;
 use geoarrow::array::{CoordBuffer, InterleavedCoordBuffer, SeparatedCoordBuffer};
 use geoarrow::datatypes::Dimension;
+use numpy::{PyArrayMethods, ToPyArray};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyList, PyTuple};
 use pyo3_arrow::PyArray;
 
+/// A coordinate buffer, extracted from Python and exported back to NumPy.
+///
+/// Coordinates can be passed as a single float64 array of shape `(N, 2)` or `(N, 3)`, or as a
+/// tuple or list of one float64 array per dimension. Any object implementing the Arrow PyCapsule
+/// Interface or the buffer protocol is accepted, so C-contiguous NumPy arrays are not copied.
 pub struct PyCoordBuffer(CoordBuffer);
 
 impl PyCoordBuffer {
@@ -18,10 +24,39 @@ impl PyCoordBuffer {
     }
 }
 
+impl From<CoordBuffer> for PyCoordBuffer {
+    fn from(value: CoordBuffer) -> Self {
+        Self(value)
+    }
+}
+
+/// Extract the values of one dimension of separated coordinates.
+fn extract_dim_values(ob: &Bound<'_, PyAny>, name: &str) -> PyResult<ScalarBuffer<f64>> {
+    let array = ob.extract::<PyArray>()?;
+    let array = array.array();
+
+    if !matches!(array.data_type(), DataType::Float64) {
+        return Err(PyValueError::new_err(format!(
+            "Expected {name} to be float64 data type, got {}",
+            array.data_type()
+        )));
+    }
+
+    let array = array.as_primitive::<Float64Type>();
+    if array.null_count() != 0 {
+        return Err(PyValueError::new_err(format!(
+            "Cannot construct point array with null values. The '{name}' array has {} null values",
+            array.null_count()
+        )));
+    }
+
+    Ok(array.values().clone())
+}
+
 impl<'py> FromPyObject<'py> for PyCoordBuffer {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         if ob.is_instance_of::<PyTuple>() || ob.is_instance_of::<PyList>() {
-            let arrays = ob.extract::<Vec<PyArray>>()?;
+            let arrays = ob.extract::<Vec<Bound<'py, PyAny>>>()?;
 
             if arrays.len() < 2 || arrays.len() > 3 {
                 return Err(PyValueError::new_err(format!(
@@ -30,83 +65,18 @@ impl<'py> FromPyObject<'py> for PyCoordBuffer {
                 )));
             }
 
-            let x = arrays[0].array();
-            let y = arrays[1].array();
-
-            if !matches!(x.data_type(), DataType::Float64) {
-                return Err(PyValueError::new_err(format!(
-                    "Expected x to be float64 data type, got {}",
-                    x.data_type()
-                )));
-            }
-
-            if !matches!(y.data_type(), DataType::Float64) {
-                return Err(PyValueError::new_err(format!(
-                    "Expected y to be float64 data type, got {}",
-                    y.data_type()
-                )));
-            }
-
-            let x = x.as_primitive::<Float64Type>();
-            let y = y.as_primitive::<Float64Type>();
-
-            if x.null_count() != 0 {
-                return Err(PyValueError::new_err(format!(
-                "Cannot construct point array with null values. The 'x' array has {} null values",
-                x.null_count()
-            )));
-            }
-
-            if y.null_count() != 0 {
-                return Err(PyValueError::new_err(format!(
-                "Cannot construct point array with null values. The 'y' array has {} null values",
-                y.null_count()
-            )));
-            }
-
-            let x = x.values();
-            let y = y.values();
-
-            if let Some(z) = arrays.get(2) {
-                if !matches!(z.field().data_type(), DataType::Float64) {
-                    return Err(PyValueError::new_err(format!(
-                        "Expected z to be float64 data type, got {}",
-                        z.field().data_type()
-                    )));
-                }
-
-                let z = z.array().as_primitive::<Float64Type>();
-
-                if z.null_count() != 0 {
-                    return Err(PyValueError::new_err(format!(
-                "Cannot construct point array with null values. The 'z' array has {} null values",
-                z.null_count()
-            )));
-                }
-
-                let z = z.values();
-
-                Ok(Self(
-                    SeparatedCoordBuffer::new(
-                        [x.clone(), y.clone(), z.clone(), ScalarBuffer::from(vec![])],
-                        Dimension::XYZ,
-                    )
-                    .into(),
-                ))
+            let dim = if arrays.len() == 3 {
+                Dimension::XYZ
             } else {
-                Ok(Self(
-                    SeparatedCoordBuffer::new(
-                        [
-                            x.clone(),
-                            y.clone(),
-                            ScalarBuffer::from(vec![]),
-                            ScalarBuffer::from(vec![]),
-                        ],
-                        Dimension::XY,
-                    )
-                    .into(),
-                ))
+                Dimension::XY
+            };
+            let mut buffers = [(); 4].map(|_| ScalarBuffer::from(Vec::<f64>::new()));
+            for (i, (array, name)) in arrays.iter().zip(["x", "y", "z"]).enumerate() {
+                buffers[i] = extract_dim_values(array, name)?;
             }
+            let coords = SeparatedCoordBuffer::try_new(buffers, dim)
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+            Ok(Self(coords.into()))
         } else {
             let coords = ob.extract::<PyArray>()?;
 
@@ -163,3 +133,30 @@ impl<'py> FromPyObject<'py> for PyCoordBuffer {
         }
     }
 }
+
+/// Copy the coordinates to NumPy: an array of shape `(N, D)` for interleaved coordinates, or a
+/// tuple of one array per dimension for separated coordinates.
+impl<'py> IntoPyObject<'py> for PyCoordBuffer {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        match self.0 {
+            CoordBuffer::Interleaved(cb) => {
+                let size = cb.dim().size();
+                let coords = cb.coords();
+                Ok(coords
+                    .to_pyarray(py)
+                    .reshape([coords.len() / size, size])?
+                    .into_any())
+            }
+            CoordBuffer::Separated(cb) => {
+                let arrays = cb.buffers()[..cb.dim().size()]
+                    .iter()
+                    .map(|buffer| buffer.to_pyarray(py));
+                Ok(PyTuple::new(py, arrays)?.into_any())
+            }
+        }
+    }
+}
@@ -128,6 +128,8 @@ impl PyNativeType {
         match self.0.dimension() {
             Some(Dimension::XY) => Ok(coord_type.getattr(intern!(py, "XY"))?.into()),
             Some(Dimension::XYZ) => Ok(coord_type.getattr(intern!(py, "XYZ"))?.into()),
+            Some(Dimension::XYM) => Ok(coord_type.getattr(intern!(py, "XYM"))?.into()),
+            Some(Dimension::XYZM) => Ok(coord_type.getattr(intern!(py, "XYZM"))?.into()),
             None => Ok(py.None()),
         }
     }
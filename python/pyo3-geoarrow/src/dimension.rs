@@ -7,6 +7,8 @@ use pyo3::prelude::*;
 pub enum PyDimension {
     XY,
     XYZ,
+    XYM,
+    XYZM,
 }
 
 impl<'a> FromPyObject<'a> for PyDimension {
@@ -15,6 +17,8 @@ impl<'a> FromPyObject<'a> for PyDimension {
         match s.to_lowercase().as_str() {
             "xy" => Ok(Self::XY),
             "xyz" => Ok(Self::XYZ),
+            "xym" => Ok(Self::XYM),
+            "xyzm" => Ok(Self::XYZM),
             _ => Err(PyValueError::new_err("Unexpected dimension")),
         }
     }
@@ -25,6 +29,8 @@ impl From<PyDimension> for Dimension {
         match value {
             PyDimension::XY => Self::XY,
             PyDimension::XYZ => Self::XYZ,
+            PyDimension::XYM => Self::XYM,
+            PyDimension::XYZM => Self::XYZM,
         }
     }
 }
@@ -0,0 +1,51 @@
+use geoarrow::array::metadata::{ArrayMetadata, Edges};
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+
+/// The interpretation of the edges between coordinates, stored in the array metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyEdges {
+    Planar,
+    Spherical,
+}
+
+impl PyEdges {
+    /// The edges declared by array metadata, where edges are planar if not declared.
+    pub fn from_metadata(metadata: &ArrayMetadata) -> Self {
+        match metadata.edges {
+            Some(Edges::Spherical) => Self::Spherical,
+            None => Self::Planar,
+        }
+    }
+
+    /// Export to a member of the `geoarrow.rust.core.enums.Edges` enum.
+    pub fn to_python(self, py: Python) -> PyResult<PyObject> {
+        let enums_mod = py.import(intern!(py, "geoarrow.rust.core.enums"))?;
+        let edges = enums_mod.getattr(intern!(py, "Edges"))?;
+        match self {
+            Self::Planar => Ok(edges.getattr(intern!(py, "Planar"))?.into()),
+            Self::Spherical => Ok(edges.getattr(intern!(py, "Spherical"))?.into()),
+        }
+    }
+}
+
+impl<'a> FromPyObject<'a> for PyEdges {
+    fn extract_bound(ob: &Bound<'a, PyAny>) -> PyResult<Self> {
+        let s: String = ob.extract()?;
+        match s.to_lowercase().as_str() {
+            "planar" => Ok(Self::Planar),
+            "spherical" => Ok(Self::Spherical),
+            _ => Err(PyValueError::new_err("Unexpected edges")),
+        }
+    }
+}
+
+impl From<PyEdges> for Option<Edges> {
+    fn from(value: PyEdges) -> Self {
+        match value {
+            PyEdges::Planar => None,
+            PyEdges::Spherical => Some(Edges::Spherical),
+        }
+    }
+}
@@ -1,3 +1,4 @@
 //! Arrow FFI via the C Data Interface and the Arrow PyCapsule Interface.
 
 pub mod from_python;
+pub(crate) mod to_python;
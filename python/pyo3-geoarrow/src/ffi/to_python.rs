@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use arrow_schema::{ArrowError, FieldRef};
+use geoarrow::algorithm::native::cast_reader;
+use geoarrow::array::metadata::ArrayMetadata;
+use geoarrow::datatypes::NativeType;
+use geoarrow::io::NativeArrayReader;
+use geoarrow::ArrayBase;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+use pyo3_arrow::ffi::{to_stream_pycapsule, ArrayIterator};
+use pyo3_arrow::PyField;
+
+use crate::error::PyGeoArrowResult;
+
+/// Parse a requested schema capsule into a GeoArrow native type.
+///
+/// Returns `None` if the requested schema has no GeoArrow extension type, in which case the
+/// consumer's request is handled as a plain Arrow cast.
+fn requested_native_type(capsule: &Bound<PyCapsule>) -> PyGeoArrowResult<Option<NativeType>> {
+    let field = PyField::from_arrow_pycapsule(capsule)?.into_inner();
+    match field.metadata().get("ARROW:extension:name") {
+        Some(name) if name.starts_with("geoarrow.") => {
+            Ok(NativeType::try_from(field.as_ref()).ok())
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Export a stream of native arrays to an Arrow C Stream capsule.
+///
+/// If the consumer requests a GeoArrow type, such as a different coordinate layout or geometry
+/// type, each array is cast lazily as it is pulled from the stream. Any other requested schema is
+/// passed on to the Arrow-level cast.
+pub(crate) fn to_native_stream_pycapsule<'py>(
+    py: Python<'py>,
+    reader: Box<dyn NativeArrayReader + Send>,
+    field: FieldRef,
+    requested_schema: Option<Bound<'py, PyCapsule>>,
+) -> PyGeoArrowResult<Bound<'py, PyCapsule>> {
+    let to_type = match &requested_schema {
+        Some(capsule) => requested_native_type(capsule)?,
+        None => None,
+    };
+
+    let (reader, field, requested_schema) = match to_type {
+        Some(to_type) if to_type != reader.data_type() => {
+            let metadata = ArrayMetadata::try_from(field.as_ref())?;
+            let field =
+                to_type.to_field_with_metadata(field.name(), field.is_nullable(), &metadata);
+            let reader: Box<dyn NativeArrayReader + Send> = Box::new(cast_reader(reader, to_type));
+            (reader, Arc::new(field), None)
+        }
+        Some(_) => (reader, field, None),
+        None => (reader, field, requested_schema),
+    };
+
+    let arrays = reader.map(|array| {
+        array
+            .map(|array| array.to_array_ref())
+            .map_err(|err| ArrowError::ExternalError(Box::new(err)))
+    });
+    let array_reader = Box::new(ArrayIterator::new(arrays, field));
+    Ok(to_stream_pycapsule(py, array_reader, requested_schema)?)
+}
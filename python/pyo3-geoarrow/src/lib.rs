@@ -1,22 +1,26 @@
 mod array;
+mod array_reader;
 mod chunked_array;
 mod coord_buffer;
 mod coord_type;
 mod crs;
 mod data_type;
 mod dimension;
+mod edges;
 mod error;
 mod ffi;
 mod offset_buffer;
 mod scalar;
 
 pub use array::{PyNativeArray, PySerializedArray};
+pub use array_reader::PyNativeArrayReader;
 pub use chunked_array::PyChunkedNativeArray;
 pub use coord_buffer::PyCoordBuffer;
 pub use coord_type::PyCoordType;
 pub use crs::{PyprojCRSTransform, CRS};
 pub use data_type::{PyNativeType, PySerializedType};
 pub use dimension::PyDimension;
+pub use edges::PyEdges;
 pub use error::{PyGeoArrowError, PyGeoArrowResult};
 pub use offset_buffer::PyOffsetBuffer;
 pub use scalar::PyGeometry;
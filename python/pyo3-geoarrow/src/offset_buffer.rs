@@ -3,12 +3,17 @@ use arrow::compute::cast;
 use arrow::datatypes::Int32Type;
 use arrow_buffer::OffsetBuffer;
 use arrow_schema::DataType;
+use numpy::ToPyArray;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3_arrow::PyArray;
 
 use crate::PyGeoArrowError;
 
+/// An offset buffer, extracted from Python and exported back to NumPy.
+///
+/// Any integer array implementing the Arrow PyCapsule Interface or the buffer protocol is
+/// accepted. int32 arrays are not copied, while other integer types are cast to int32.
 pub struct PyOffsetBuffer(OffsetBuffer<i32>);
 
 impl PyOffsetBuffer {
@@ -17,6 +22,12 @@ impl PyOffsetBuffer {
     }
 }
 
+impl From<OffsetBuffer<i32>> for PyOffsetBuffer {
+    fn from(value: OffsetBuffer<i32>) -> Self {
+        Self(value)
+    }
+}
+
 impl<'py> FromPyObject<'py> for PyOffsetBuffer {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let ob = ob.extract::<PyArray>()?;
@@ -27,7 +38,45 @@ impl<'py> FromPyObject<'py> for PyOffsetBuffer {
             )));
         }
         let offsets = cast(ob.as_ref(), &DataType::Int32).map_err(PyGeoArrowError::from)?;
-        let offsets = offsets.as_ref().as_primitive::<Int32Type>();
-        Ok(Self(OffsetBuffer::new(offsets.values().clone())))
+        // Values that overflow int32 are cast to null
+        if offsets.null_count() != 0 {
+            return Err(PyValueError::new_err(
+                "Cannot construct offset buffer with values that overflow int32.",
+            ));
+        }
+        let offsets = offsets.as_ref().as_primitive::<Int32Type>().values();
+
+        if offsets.is_empty() {
+            return Err(PyValueError::new_err(
+                "Offsets must contain at least one value.",
+            ));
+        }
+        if offsets[0] < 0 {
+            return Err(PyValueError::new_err(format!(
+                "Offsets must be non-negative, got {} as the first offset.",
+                offsets[0]
+            )));
+        }
+        if let Some(i) = offsets.windows(2).position(|w| w[0] > w[1]) {
+            return Err(PyValueError::new_err(format!(
+                "Offsets must be monotonically increasing, got {} after {} at index {}.",
+                offsets[i + 1],
+                offsets[i],
+                i + 1
+            )));
+        }
+
+        Ok(Self(OffsetBuffer::new(offsets.clone())))
+    }
+}
+
+/// Copy the offsets to a one-dimensional int32 NumPy array.
+impl<'py> IntoPyObject<'py> for PyOffsetBuffer {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        Ok(self.0.inner().to_pyarray(py).into_any())
     }
 }
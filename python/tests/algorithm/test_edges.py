@@ -0,0 +1,64 @@
+import geoarrow.rust.compute as grc
+import numpy as np
+import pyarrow as pa
+import pytest
+from geoarrow.rust.core import ChunkedNativeArray, linestrings
+from geoarrow.rust.core.enums import Edges
+
+
+def equator_line(**kwargs):
+    coords = np.array([[0.0, 0.0], [1.0, 0.0]])
+    geom_offsets = np.array([0, 2], dtype=np.int32)
+    return linestrings(coords, geom_offsets, **kwargs)
+
+
+def test_edges_metadata():
+    planar = equator_line()
+    assert planar.edges == Edges.Planar
+
+    spherical = equator_line(edges="spherical")
+    assert spherical.edges == Edges.Spherical
+    assert spherical.set_edges(Edges.Planar).edges == Edges.Planar
+    assert planar.set_edges("spherical").edges == Edges.Spherical
+
+    chunked = ChunkedNativeArray.from_arrays([planar, planar])
+    assert chunked.edges == Edges.Planar
+    assert chunked.set_edges("spherical").edges == Edges.Spherical
+
+
+def test_spherical_dispatch():
+    planar = equator_line()
+    spherical = planar.set_edges("spherical")
+
+    assert pa.array(grc.length(planar))[0].as_py() == 1.0
+    # The geodesic length in meters of one degree along the equator
+    geodesic = pa.array(grc.length(spherical))[0].as_py()
+    assert geodesic == pytest.approx(111_319.49, abs=0.01)
+    assert pa.array(grc.length(spherical, method="haversine"))[0].as_py() > 1
+
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.length(spherical, method="euclidean")
+
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.simplify(spherical, 0.1)
+
+    chunked = ChunkedNativeArray.from_arrays([spherical])
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.centroid(chunked)
+
+
+def test_spherical_arguments():
+    planar = equator_line()
+    spherical = planar.set_edges("spherical")
+
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.frechet_distance(planar, spherical)
+
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.hausdorff_distance(planar, spherical)
+
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.envelope(spherical)
+
+    with pytest.raises(Exception, match="spherical edges"):
+        grc.translate(spherical, 1.0, 1.0)
@@ -1,7 +1,8 @@
 import numpy as np
 import pyarrow as pa
 import pytest
-from geoarrow.rust.core import linestrings, points
+from arro3.core import Array
+from geoarrow.rust.core import buffers, linestrings, points, polygons
 
 
 def test_points_2d():
@@ -33,7 +34,6 @@ def test_points_2d():
     assert np.allclose(coords, coords_retour2)
 
 
-@pytest.mark.skip("Come back to this once we remove the const generic from array types")
 def test_points_3d():
     coords = np.random.rand(10, 3)
     point_arr = points(coords)
@@ -42,6 +42,21 @@ def test_points_3d():
     assert point_arr[0][1].as_py() == coords[0, 1]
     assert point_arr[0][2].as_py() == coords[0, 2]
 
+    x, y, z = (np.ascontiguousarray(coords[:, i]) for i in range(3))
+    point_arr2 = pa.array(points((x, y, z)))
+    assert point_arr2[0][2].as_py() == coords[0, 2]
+
+
+def test_points_dtype_and_length():
+    with pytest.raises(ValueError, match="float64"):
+        points(np.arange(20, dtype=np.int64).reshape(10, 2))
+
+    with pytest.raises(ValueError, match="float64"):
+        points((np.arange(10, dtype=np.float32), np.random.rand(10)))
+
+    with pytest.raises(ValueError):
+        points((np.random.rand(10), np.random.rand(9)))
+
 
 def test_linestrings():
     coords = np.random.rand(10, 2)
@@ -54,3 +69,54 @@ def test_linestrings():
     assert len(geom_arr[2]) == 4
 
     assert np.allclose(coords, geom_arr.values.values.to_numpy().reshape(-1, 2))
+
+
+def test_linestrings_int64_offsets():
+    coords = np.random.rand(10, 2)
+    geom_offsets = np.array([0, 2, 6, 10], dtype=np.int64)
+    geom_arr = pa.array(linestrings(coords, geom_offsets))
+    assert len(geom_arr) == 3
+
+
+def test_invalid_offsets():
+    coords = np.random.rand(10, 2)
+
+    with pytest.raises(ValueError, match="monotonically increasing"):
+        linestrings(coords, np.array([0, 6, 2, 10], dtype=np.int32))
+
+    with pytest.raises(ValueError, match="non-negative"):
+        linestrings(coords, np.array([-1, 2, 10], dtype=np.int32))
+
+    with pytest.raises(ValueError, match="overflow"):
+        linestrings(coords, np.array([0, 2**40], dtype=np.int64))
+
+
+def test_buffers_roundtrip():
+    coords = np.random.rand(10, 2)
+    geom_offsets = np.array([0, 2, 6, 10], dtype=np.int32)
+    ring_offsets = np.array([0, 1, 3, 5, 10], dtype=np.int32)
+
+    coords_retour, geom_offsets_retour = buffers(linestrings(coords, geom_offsets))
+    assert np.array_equal(coords, coords_retour)
+    assert np.array_equal(geom_offsets, geom_offsets_retour)
+    assert geom_offsets_retour.dtype == np.int32
+
+    arr = polygons(coords, geom_offsets, ring_offsets)
+    assert pa.array(polygons(*buffers(arr))) == pa.array(arr)
+
+
+def test_buffers_separated():
+    x, y, z = (np.random.rand(10) for _ in range(3))
+    (coords,) = buffers(points((x, y, z)))
+    assert isinstance(coords, tuple)
+    assert all(np.array_equal(a, b) for a, b in zip(coords, (x, y, z)))
+
+
+def test_buffers_nulls():
+    arr = pa.array(points(np.random.rand(3, 2)))
+    field = pa.field("", arr.type, metadata={"ARROW:extension:name": "geoarrow.point"})
+    arr = pa.FixedSizeListArray.from_arrays(
+        arr.values, 2, mask=pa.array([False, True, False])
+    )
+    with pytest.raises(ValueError, match="null values"):
+        buffers(Array(arr, field))
@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use geoarrow::algorithm::geo::Area;
+use geoarrow::algorithm::geo::{Area, Centroid};
 use geoarrow::array::{AsChunkedNativeArray, MultiPolygonArray};
 use geoarrow::io::flatgeobuf::FlatGeobufReaderBuilder;
 use geoarrow::table::Table;
@@ -32,6 +32,12 @@ fn criterion_benchmark(c: &mut Criterion) {
             criterion::black_box(criterion::black_box(&data).signed_area());
         });
     });
+
+    c.bench_function("centroid", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(criterion::black_box(&data).centroid());
+        });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,608 @@
+//! Operations that are implemented both natively with [`geo`] and with GEOS, where the
+//! implementation is selected at runtime with a [`Backend`].
+//!
+//! The GEOS backend requires the `geos` feature. It gives exact parity with other GEOS-based
+//! tools such as PostGIS and Shapely, at the cost of converting every geometry to GEOS.
+//!
+//! [`buffer`], [`overlay`] and [`overlay_scalar`] don't check that their input is valid. To check
+//! it once and reuse the result in later steps, [`validate`] the array into a [`ValidatedArray`]
+//! and call [`buffer_validated`] or [`overlay_validated`] instead. These run the same operation
+//! as the unvalidated functions; they skip no work, and only return an error up front, naming the
+//! first invalid row, instead of undefined output.
+
+use std::sync::Arc;
+
+use arrow_array::BooleanArray;
+use arrow_buffer::BooleanBuffer;
+use geo::dimensions::HasDimensions;
+use geo::BooleanOps;
+use geo_traits::GeometryTrait;
+
+use crate::algorithm::geo::validation::check;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::{GeometryArray, GeometryBuilder};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::NativeArray;
+
+/// The library used to compute an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// The [`geo`] crate.
+    #[default]
+    Geo,
+
+    /// The GEOS library, through the [`geos`](https://docs.rs/geos) crate. Requires the `geos`
+    /// feature.
+    Geos,
+}
+
+/// The set operation computed by [`overlay`] and [`overlay_scalar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayOp {
+    /// The area in both geometries.
+    Intersection,
+
+    /// The area in either geometry.
+    Union,
+
+    /// The area in the left geometry but not in the right geometry.
+    Difference,
+
+    /// The area in exactly one of the geometries.
+    SymDifference,
+}
+
+#[cfg(not(feature = "geos"))]
+fn geos_disabled() -> GeoArrowError {
+    GeoArrowError::General("The GEOS backend requires the geos feature".to_string())
+}
+
+fn unsupported_by_geo(operation: &str) -> GeoArrowError {
+    GeoArrowError::NotYetImplemented(format!(
+        "{operation} is not supported by the geo backend, use Backend::Geos"
+    ))
+}
+
+/// Compute a buffer of `width` around every geometry, with `quadsegs` segments per quarter
+/// circle.
+///
+/// The input isn't validated, so invalid geometries give undefined output. Use
+/// [`buffer_validated`] to check the input against a [`ValidityCache`] first.
+///
+/// Only [`Backend::Geos`] is supported for now.
+pub fn buffer(
+    array: &dyn NativeArray,
+    width: f64,
+    quadsegs: i32,
+    backend: Backend,
+) -> Result<GeometryArray> {
+    match backend {
+        Backend::Geo => Err(unsupported_by_geo("buffer")),
+        #[cfg(feature = "geos")]
+        Backend::Geos => {
+            use geos::Geom;
+
+            geos_unary(array, |geom| geom.buffer(width, quadsegs))
+        }
+        #[cfg(not(feature = "geos"))]
+        Backend::Geos => {
+            let _ = (array, width, quadsegs);
+            Err(geos_disabled())
+        }
+    }
+}
+
+/// Repair invalid geometries. See [`MakeValid`][crate::algorithm::geos::MakeValid].
+///
+/// Only [`Backend::Geos`] is supported for now.
+pub fn make_valid(array: &dyn NativeArray, backend: Backend) -> Result<GeometryArray> {
+    match backend {
+        Backend::Geo => Err(unsupported_by_geo("make_valid")),
+        #[cfg(feature = "geos")]
+        Backend::Geos => {
+            use crate::algorithm::geos::MakeValid;
+
+            array.make_valid()
+        }
+        #[cfg(not(feature = "geos"))]
+        Backend::Geos => {
+            let _ = array;
+            Err(geos_disabled())
+        }
+    }
+}
+
+/// Whether each geometry of an array is valid, computed once with [`validate`] and reused by the
+/// kernels that require valid input.
+///
+/// Null geometries are considered valid. The cache can also be built from validity flags computed
+/// elsewhere, e.g. by a previous step of a pipeline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidityCache {
+    valid: BooleanBuffer,
+}
+
+impl ValidityCache {
+    /// Create a new cache from one flag per geometry, which is `true` where the geometry is valid.
+    pub fn new(valid: BooleanBuffer) -> Self {
+        Self { valid }
+    }
+
+    /// The number of geometries in the cache.
+    pub fn len(&self) -> usize {
+        self.valid.len()
+    }
+
+    /// Whether the cache has no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.valid.is_empty()
+    }
+
+    /// Whether the geometry at index `i` is valid.
+    pub fn is_valid(&self, i: usize) -> bool {
+        self.valid.value(i)
+    }
+
+    /// Whether every geometry is valid.
+    pub fn all_valid(&self) -> bool {
+        self.valid.count_set_bits() == self.valid.len()
+    }
+
+    /// The indices of the invalid geometries.
+    pub fn invalid_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        self.valid
+            .iter()
+            .enumerate()
+            .filter_map(|(i, valid)| (!valid).then_some(i))
+    }
+
+    /// Return an error naming the first invalid row, if any.
+    fn check(&self, operation: &str, input: &str) -> Result<()> {
+        match self.invalid_rows().next() {
+            None => Ok(()),
+            Some(row) => Err(GeoArrowError::General(format!(
+                "{operation} requires valid geometries, but row {row} of the {input} is invalid \
+                ({} invalid rows in total)",
+                self.valid.len() - self.valid.count_set_bits()
+            ))),
+        }
+    }
+}
+
+impl From<&BooleanArray> for ValidityCache {
+    /// Create a cache from the output of an `is_valid` kernel, where nulls are considered valid.
+    fn from(value: &BooleanArray) -> Self {
+        Self::new(value.iter().map(|valid| valid.unwrap_or(true)).collect())
+    }
+}
+
+/// Check the validity of every geometry of an array, so that it can be reused by later steps.
+///
+/// The geo backend uses the same checks as
+/// [`is_valid_detail`][crate::algorithm::geo::is_valid_detail], which may differ from GEOS for
+/// some edge cases.
+pub fn validate(array: &dyn NativeArray, backend: Backend) -> Result<ValidityCache> {
+    match backend {
+        Backend::Geo => Ok(ValidityCache::new(
+            iter_geometries(array)
+                .map(|geom| geom.map_or(true, |geom| check(&geometry_to_geo(&geom)).is_none()))
+                .collect(),
+        )),
+        #[cfg(feature = "geos")]
+        Backend::Geos => {
+            use crate::algorithm::geos::IsValid;
+
+            Ok(ValidityCache::from(&array.is_valid()?))
+        }
+        #[cfg(not(feature = "geos"))]
+        Backend::Geos => {
+            let _ = array;
+            Err(geos_disabled())
+        }
+    }
+}
+
+/// An array with a [`ValidityCache`] attached.
+///
+/// Kernels that require valid input, like [`buffer_validated`] and [`overlay_validated`], consult
+/// the cache instead of validating the geometries again, and give an error naming the invalid row.
+#[derive(Debug, Clone)]
+pub struct ValidatedArray {
+    array: Arc<dyn NativeArray>,
+    validity: ValidityCache,
+}
+
+impl ValidatedArray {
+    /// Attach a cache to an array.
+    ///
+    /// Returns an error if the cache and the array have different lengths.
+    pub fn try_new(array: Arc<dyn NativeArray>, validity: ValidityCache) -> Result<Self> {
+        if array.len() != validity.len() {
+            return Err(GeoArrowError::General(format!(
+                "Validity cache has length {} but array has length {}",
+                validity.len(),
+                array.len()
+            )));
+        }
+        Ok(Self { array, validity })
+    }
+
+    /// Validate an array with [`validate`] and attach the result.
+    pub fn validate(array: Arc<dyn NativeArray>, backend: Backend) -> Result<Self> {
+        let validity = validate(array.as_ref(), backend)?;
+        Ok(Self { array, validity })
+    }
+
+    /// The underlying array.
+    pub fn array(&self) -> &Arc<dyn NativeArray> {
+        &self.array
+    }
+
+    /// The attached validity cache.
+    pub fn validity(&self) -> &ValidityCache {
+        &self.validity
+    }
+
+    /// Whether each geometry is valid, read from the cache. Null geometries are null.
+    pub fn is_valid(&self) -> BooleanArray {
+        BooleanArray::new(self.validity.valid.clone(), self.array.nulls().cloned())
+    }
+
+    /// Consume this, returning the array and its cache.
+    pub fn into_inner(self) -> (Arc<dyn NativeArray>, ValidityCache) {
+        (self.array, self.validity)
+    }
+}
+
+/// Compute a buffer of every geometry of a validated array. See [`buffer`].
+///
+/// Returns an error naming the first invalid row, without computing any buffers, if the cache has
+/// any invalid geometries.
+pub fn buffer_validated(
+    array: &ValidatedArray,
+    width: f64,
+    quadsegs: i32,
+    backend: Backend,
+) -> Result<GeometryArray> {
+    array.validity.check("buffer", "input")?;
+    buffer(array.array.as_ref(), width, quadsegs, backend)
+}
+
+/// Compute a set operation between two validated arrays. See [`overlay`].
+///
+/// Returns an error naming the first invalid row, without computing any overlays, if either cache
+/// has any invalid geometries.
+pub fn overlay_validated(
+    left: &ValidatedArray,
+    right: &ValidatedArray,
+    op: OverlayOp,
+    backend: Backend,
+) -> Result<GeometryArray> {
+    left.validity.check("overlay", "left input")?;
+    right.validity.check("overlay", "right input")?;
+    overlay(left.array.as_ref(), right.array.as_ref(), op, backend)
+}
+
+/// Compute a set operation between the geometries at the same index of two arrays.
+///
+/// The output is null where either input is null. The geo backend supports polygons and
+/// multi polygons only, and always returns multi polygons. Empty geometries of any type are
+/// treated as empty multi polygons.
+///
+/// The inputs aren't validated. Use [`overlay_validated`] to check them against a
+/// [`ValidityCache`] first.
+pub fn overlay(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    op: OverlayOp,
+    backend: Backend,
+) -> Result<GeometryArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    match backend {
+        Backend::Geo => geo_overlay(left, right, op),
+        #[cfg(feature = "geos")]
+        Backend::Geos => geos_overlay(left, right, op),
+        #[cfg(not(feature = "geos"))]
+        Backend::Geos => Err(geos_disabled()),
+    }
+}
+
+/// Compute a set operation between every geometry of an array and a single geometry, e.g. to
+/// clip a layer to a study area.
+///
+/// The output is null where the array is null. See [`overlay`] for the geometries supported by
+/// each backend. As there, the inputs aren't validated.
+pub fn overlay_scalar(
+    array: &dyn NativeArray,
+    geometry: &impl GeometryTrait<T = f64>,
+    op: OverlayOp,
+    backend: Backend,
+) -> Result<GeometryArray> {
+    match backend {
+        Backend::Geo => {
+            let right = to_multi_polygon(geometry_to_geo(geometry))?;
+            let mut builder =
+                GeometryBuilder::new_with_options(array.coord_type(), array.metadata(), false);
+            for geom in iter_geometries(array) {
+                match geom {
+                    Some(geom) => {
+                        let left = to_multi_polygon(geometry_to_geo(&geom))?;
+                        builder.push_multi_polygon(Some(&geo_overlay_op(&left, &right, op)))?;
+                    }
+                    None => builder.push_null(),
+                }
+            }
+            Ok(builder.finish())
+        }
+        #[cfg(feature = "geos")]
+        Backend::Geos => {
+            use crate::io::geos::scalar::to_geos_geometry;
+            use crate::io::geos::{from_geos_vec, to_geos_vec};
+
+            let right = match geometry_to_geo(geometry) {
+                geo::Geometry::Rect(rect) => to_geos_geometry(&rect.to_polygon())?,
+                right => to_geos_geometry(&right)?,
+            };
+            let geoms = to_geos_vec(array)?
+                .iter()
+                .map(|geom| {
+                    geom.as_ref()
+                        .map(|geom| geos_overlay_op(geom, &right, op))
+                        .transpose()
+                })
+                .collect::<std::result::Result<Vec<_>, geos::Error>>()?;
+            from_geos_vec(geoms, array.coord_type(), array.metadata())
+        }
+        #[cfg(not(feature = "geos"))]
+        Backend::Geos => {
+            let _ = (array, geometry, op);
+            Err(geos_disabled())
+        }
+    }
+}
+
+pub(crate) fn to_multi_polygon(geom: geo::Geometry) -> Result<geo::MultiPolygon> {
+    match geom {
+        geo::Geometry::Polygon(polygon) => Ok(polygon.into()),
+        geo::Geometry::MultiPolygon(multi_polygon) => Ok(multi_polygon),
+        geo::Geometry::Rect(rect) => Ok(rect.to_polygon().into()),
+        geom if geom.is_empty() => Ok(geo::MultiPolygon::new(vec![])),
+        _ => Err(GeoArrowError::IncorrectType(
+            "The geo backend only supports overlay of polygonal geometries".into(),
+        )),
+    }
+}
+
+fn geo_overlay(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    op: OverlayOp,
+) -> Result<GeometryArray> {
+    let mut builder = GeometryBuilder::new_with_options(left.coord_type(), left.metadata(), false);
+    for (left_geom, right_geom) in iter_geometries(left).zip(iter_geometries(right)) {
+        let (Some(left_geom), Some(right_geom)) = (left_geom, right_geom) else {
+            builder.push_null();
+            continue;
+        };
+        let left_geom = to_multi_polygon(geometry_to_geo(&left_geom))?;
+        let right_geom = to_multi_polygon(geometry_to_geo(&right_geom))?;
+        builder.push_multi_polygon(Some(&geo_overlay_op(&left_geom, &right_geom, op)))?;
+    }
+    Ok(builder.finish())
+}
+
+fn geo_overlay_op(
+    left: &geo::MultiPolygon,
+    right: &geo::MultiPolygon,
+    op: OverlayOp,
+) -> geo::MultiPolygon {
+    match op {
+        OverlayOp::Intersection => left.intersection(right),
+        OverlayOp::Union => left.union(right),
+        OverlayOp::Difference => left.difference(right),
+        OverlayOp::SymDifference => left.xor(right),
+    }
+}
+
+#[cfg(feature = "geos")]
+fn geos_unary<F>(array: &dyn NativeArray, op: F) -> Result<GeometryArray>
+where
+    F: Fn(&geos::Geometry) -> std::result::Result<geos::Geometry, geos::Error>,
+{
+    use crate::io::geos::{from_geos_vec, to_geos_vec};
+
+    let geoms = to_geos_vec(array)?
+        .iter()
+        .map(|geom| geom.as_ref().map(&op).transpose())
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    from_geos_vec(geoms, array.coord_type(), array.metadata())
+}
+
+#[cfg(feature = "geos")]
+fn geos_overlay(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    op: OverlayOp,
+) -> Result<GeometryArray> {
+    use crate::io::geos::{from_geos_vec, to_geos_vec};
+    use geos::Geom;
+
+    let geoms = to_geos_vec(left)?
+        .iter()
+        .zip(to_geos_vec(right)?.iter())
+        .map(|(left_geom, right_geom)| {
+            let (Some(left_geom), Some(right_geom)) = (left_geom, right_geom) else {
+                return Ok(None);
+            };
+            geos_overlay_op(left_geom, right_geom, op).map(Some)
+        })
+        .collect::<std::result::Result<Vec<_>, geos::Error>>()?;
+    from_geos_vec(geoms, left.coord_type(), left.metadata())
+}
+
+#[cfg(feature = "geos")]
+fn geos_overlay_op(
+    left: &geos::Geometry,
+    right: &geos::Geometry,
+    op: OverlayOp,
+) -> std::result::Result<geos::Geometry, geos::Error> {
+    use geos::Geom;
+
+    match op {
+        OverlayOp::Intersection => left.intersection(right),
+        OverlayOp::Union => left.union(right),
+        OverlayOp::Difference => left.difference(right),
+        OverlayOp::SymDifference => left.sym_difference(right),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use crate::test::point;
+    use crate::trait_::ArrayAccessor;
+    use arrow_array::Array;
+    use geo::{polygon, Area};
+
+    fn squares() -> (PolygonArray, PolygonArray) {
+        let left = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let right = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+        (
+            (vec![left].as_slice(), Dimension::XY).into(),
+            (vec![right].as_slice(), Dimension::XY).into(),
+        )
+    }
+
+    #[test]
+    fn overlay_geo() {
+        let (left, right) = squares();
+        let result = overlay(&left, &right, OverlayOp::Intersection, Backend::Geo).unwrap();
+        assert_eq!(result.value_as_geo(0).unsigned_area(), 1.);
+
+        let result = overlay(&left, &right, OverlayOp::Union, Backend::Geo).unwrap();
+        assert_eq!(result.value_as_geo(0).unsigned_area(), 7.);
+
+        let points = point::point_array();
+        assert!(buffer(&points, 1., 8, Backend::Geo).is_err());
+    }
+
+    #[test]
+    fn overlay_keeps_metadata() {
+        let (left, right) = squares();
+        let metadata = Arc::new(ArrayMetadata::from_authority_code("EPSG:3857".to_string()));
+        let left = left.with_metadata(metadata.clone());
+        let result = overlay(left.as_ref(), &right, OverlayOp::Union, Backend::Geo).unwrap();
+        assert_eq!(result.metadata(), metadata);
+
+        let study_area = right.value_as_geo(0);
+        let result =
+            overlay_scalar(left.as_ref(), &study_area, OverlayOp::Union, Backend::Geo).unwrap();
+        assert_eq!(result.metadata(), metadata);
+    }
+
+    #[test]
+    fn overlay_scalar_and_empties() {
+        let (left, right) = squares();
+        let study_area = right.value_as_geo(0);
+        let result =
+            overlay_scalar(&left, &study_area, OverlayOp::Difference, Backend::Geo).unwrap();
+        assert_eq!(result.value_as_geo(0).unsigned_area(), 3.);
+
+        let empty = geo::GeometryCollection::<f64>::default();
+        let result = overlay_scalar(&left, &empty, OverlayOp::Intersection, Backend::Geo).unwrap();
+        assert_eq!(result.value_as_geo(0).unsigned_area(), 0.);
+
+        let points = point::point_array();
+        assert!(overlay_scalar(&points, &study_area, OverlayOp::Union, Backend::Geo).is_err());
+    }
+
+    #[test]
+    fn overlay_with_validity_cache() {
+        let (left, right) = squares();
+        let left = Arc::new(left) as Arc<dyn NativeArray>;
+        let right = Arc::new(right) as Arc<dyn NativeArray>;
+
+        let valid = ValidityCache::new(BooleanBuffer::from(vec![true]));
+        assert!(valid.all_valid());
+        let left = ValidatedArray::try_new(left, valid.clone()).unwrap();
+        let invalid = ValidityCache::from(&BooleanArray::from(vec![Some(false)]));
+        assert_eq!(invalid.invalid_rows().collect::<Vec<_>>(), vec![0]);
+        let right = ValidatedArray::try_new(right, invalid).unwrap();
+
+        let result = overlay_validated(&left, &left, OverlayOp::Union, Backend::Geo).unwrap();
+        assert_eq!(result.value_as_geo(0).unsigned_area(), 4.);
+
+        let err = overlay_validated(&left, &right, OverlayOp::Union, Backend::Geo).unwrap_err();
+        assert!(err.to_string().contains("row 0 of the right input"));
+        assert!(!right.is_valid().value(0));
+
+        let (array, _) = left.into_inner();
+        let long = ValidityCache::new(BooleanBuffer::from(vec![true, true]));
+        assert!(ValidatedArray::try_new(array, long).is_err());
+    }
+
+    #[test]
+    fn validate_geo() {
+        let bowtie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        let (square, _) = squares();
+        let array: PolygonArray = (
+            vec![Some(square.value_as_geo(0)), None, Some(bowtie)],
+            Dimension::XY,
+        )
+            .into();
+
+        let validated = ValidatedArray::validate(Arc::new(array), Backend::Geo).unwrap();
+        assert_eq!(
+            validated.validity().invalid_rows().collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert!(validated.is_valid().is_null(1));
+        let err =
+            overlay_validated(&validated, &validated, OverlayOp::Union, Backend::Geo).unwrap_err();
+        assert!(err.to_string().contains("row 2"));
+    }
+
+    #[cfg(feature = "geos")]
+    #[test]
+    fn validate_geos() {
+        let bowtie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        let (square, _) = squares();
+        let array: PolygonArray = (
+            vec![Some(square.value_as_geo(0)), None, Some(bowtie)],
+            Dimension::XY,
+        )
+            .into();
+
+        let validated = ValidatedArray::validate(Arc::new(array), Backend::Geos).unwrap();
+        assert_eq!(
+            validated.validity().invalid_rows().collect::<Vec<_>>(),
+            vec![2]
+        );
+        assert!(validated.is_valid().is_null(1));
+        let err = buffer_validated(&validated, 1., 8, Backend::Geos).unwrap_err();
+        assert!(err.to_string().contains("row 2"));
+    }
+
+    #[cfg(feature = "geos")]
+    #[test]
+    fn overlay_geos() {
+        let (left, right) = squares();
+        let result = overlay(&left, &right, OverlayOp::SymDifference, Backend::Geos).unwrap();
+        assert_eq!(result.value_as_geo(0).unsigned_area(), 6.);
+
+        let points = point::point_array();
+        let buffered = buffer(&points, 1., 8, Backend::Geos).unwrap();
+        assert!(buffered.value_as_geo(0).unsigned_area() > 3.);
+    }
+}
@@ -4,7 +4,7 @@ use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
-use crate::trait_::ArrayAccessor;
+use crate::trait_::{ArrayAccessor, GeometryArraySelfMethods};
 use crate::NativeArray;
 use geo::AffineOps as _AffineOps;
 use geo::AffineTransform;
@@ -15,6 +15,11 @@ use geo::AffineTransform;
 /// Multiple transformations can be composed in order to be efficiently applied in a single
 /// operation. See [`AffineTransform`] for more on how to build up a transformation.
 ///
+/// The transform is two-dimensional and only changes x and y values. Point, line string, polygon
+/// and multi-geometry arrays keep their Z and M values unchanged, so that e.g. a rotation turns
+/// geometries around a vertical axis. Rect, geometry collection, mixed and geometry arrays are
+/// transformed through [`geo`] and their output is two-dimensional.
+///
 /// If you are not composing operations, traits that leverage this same machinery exist which might
 /// be more readable. See: [`Scale`](crate::algorithm::geo::Scale),
 /// [`Translate`](crate::algorithm::geo::Translate), [`Rotate`](crate::algorithm::geo::Rotate), and
@@ -31,30 +36,6 @@ pub trait AffineOps<Rhs> {
 // │ Implementations for RHS scalars │
 // └─────────────────────────────────┘
 
-impl AffineOps<&AffineTransform> for PointArray {
-    type Output = Self;
-
-    fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
-        let output_geoms: Vec<Option<geo::Point>> = self
-            .iter_geo()
-            .map(|maybe_g| {
-                maybe_g.map(|mut geom| {
-                    geom.affine_transform_mut(transform);
-                    geom
-                })
-            })
-            .collect();
-
-        PointBuilder::from_nullable_points(
-            output_geoms.iter().map(|x| x.as_ref()),
-            Dimension::XY,
-            self.coord_type(),
-            self.metadata().clone(),
-        )
-        .finish()
-    }
-}
-
 impl AffineOps<&AffineTransform> for RectArray {
     type Output = Self;
 
@@ -152,65 +133,26 @@ impl AffineOps<&AffineTransform> for MixedGeometryArray {
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $builder_type:ty, $method:ident, $geo_type:ty) => {
+/// Implementation that transforms the coordinate buffer directly. Z values are kept.
+macro_rules! coords_impl {
+    ($type:ty) => {
         impl AffineOps<&AffineTransform> for $type {
             type Output = Self;
 
             fn affine_transform(&self, transform: &AffineTransform) -> Self::Output {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| {
-                        maybe_g.map(|mut geom| {
-                            geom.affine_transform_mut(transform);
-                            geom
-                        })
-                    })
-                    .collect();
-
-                <$builder_type>::$method(
-                    output_geoms.as_slice(),
-                    Dimension::XY,
-                    self.coord_type(),
-                    self.metadata().clone(),
-                )
-                .finish()
+                let coords = self.coords().affine_transform(transform);
+                self.clone().with_coords(coords)
             }
         }
     };
 }
 
-iter_geo_impl!(
-    LineStringArray,
-    LineStringBuilder,
-    from_nullable_line_strings,
-    geo::LineString
-);
-iter_geo_impl!(
-    PolygonArray,
-    PolygonBuilder,
-    from_nullable_polygons,
-    geo::Polygon
-);
-iter_geo_impl!(
-    MultiPointArray,
-    MultiPointBuilder,
-    from_nullable_multi_points,
-    geo::MultiPoint
-);
-iter_geo_impl!(
-    MultiLineStringArray,
-    MultiLineStringBuilder,
-    from_nullable_multi_line_strings,
-    geo::MultiLineString
-);
-iter_geo_impl!(
-    MultiPolygonArray,
-    MultiPolygonBuilder,
-    from_nullable_multi_polygons,
-    geo::MultiPolygon
-);
+coords_impl!(PointArray);
+coords_impl!(LineStringArray);
+coords_impl!(PolygonArray);
+coords_impl!(MultiPointArray);
+coords_impl!(MultiLineStringArray);
+coords_impl!(MultiPolygonArray);
 
 // iter_geo_impl!(LineStringArray, LineStringBuilder, push_line_string);
 // iter_geo_impl!(PolygonArray, PolygonBuilder, push_polygon);
@@ -500,3 +442,22 @@ impl AffineOps<&[AffineTransform]> for &dyn NativeArray {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::ForceDimension;
+    use crate::test::point;
+
+    #[test]
+    fn keeps_z() {
+        let array = point::point_array().force_3d(5.);
+        let transformed = array.affine_transform(&AffineTransform::translate(1., 2.));
+        assert_eq!(transformed.dimension(), Dimension::XYZ);
+        assert!(transformed.coords().z_values().all(|z| z == 5.));
+        assert_eq!(
+            transformed.value_as_geo(0),
+            point::p0().affine_transform(&AffineTransform::translate(1., 2.))
+        );
+    }
+}
@@ -88,13 +88,41 @@ macro_rules! iter_geo_impl {
     };
 }
 
-iter_geo_impl!(PolygonArray);
-iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(RectArray);
 iter_geo_impl!(GeometryArray);
 
+// Polygon areas are summed directly over the coordinate buffers.
+impl Area for PolygonArray {
+    type Output = Float64Array;
+
+    fn signed_area(&self) -> Self::Output {
+        Float64Array::new(self.raw_signed_areas().into(), self.nulls().cloned())
+    }
+
+    fn unsigned_area(&self) -> Self::Output {
+        let areas = self
+            .raw_signed_areas()
+            .into_iter()
+            .map(f64::abs)
+            .collect::<Vec<_>>();
+        Float64Array::new(areas.into(), self.nulls().cloned())
+    }
+}
+
+impl Area for MultiPolygonArray {
+    type Output = Float64Array;
+
+    fn signed_area(&self) -> Self::Output {
+        Float64Array::new(self.raw_signed_areas().into(), self.nulls().cloned())
+    }
+
+    fn unsigned_area(&self) -> Self::Output {
+        Float64Array::new(self.raw_unsigned_areas().into(), self.nulls().cloned())
+    }
+}
+
 impl Area for &dyn NativeArray {
     type Output = Result<Float64Array>;
 
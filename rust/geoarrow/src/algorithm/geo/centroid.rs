@@ -1,3 +1,5 @@
+use crate::algorithm::geo::empty::{points_with_options, prepare_geometry};
+use crate::algorithm::geo::EmptyGeometryOptions;
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray, ChunkedPointArray};
 use crate::datatypes::{Dimension, NativeType};
@@ -14,6 +16,11 @@ use geo::algorithm::centroid::Centroid as GeoCentroid;
 /// The geometric centroid of a convex object always lies in the object.
 /// A non-convex object might have a centroid that _is outside the object itself_.
 ///
+/// The centroid of an empty geometry is undefined, and is null by default. The centroid of an
+/// empty point is the empty point itself. Use
+/// [`centroid_with_options`][Self::centroid_with_options] to choose how empty geometries are
+/// handled.
+///
 /// # Examples
 ///
 /// ```
@@ -64,6 +71,14 @@ pub trait Centroid {
     /// );
     /// ```
     fn centroid(&self) -> Self::Output;
+
+    /// Calculate the centroid, handling empty geometries and empty parts according to `options`.
+    ///
+    /// The default implementation ignores `options` and calls [`centroid`][Self::centroid].
+    fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
+        let _ = options;
+        self.centroid()
+    }
 }
 
 impl Centroid for PointArray {
@@ -72,21 +87,9 @@ impl Centroid for PointArray {
     fn centroid(&self) -> Self::Output {
         self.clone()
     }
-}
 
-impl Centroid for RectArray {
-    type Output = PointArray;
-
-    fn centroid(&self) -> Self::Output {
-        let mut output_array = PointBuilder::with_capacity_and_options(
-            Dimension::XY,
-            self.len(),
-            self.coord_type(),
-            self.metadata().clone(),
-        );
-        self.iter_geo()
-            .for_each(|maybe_g| output_array.push_point(maybe_g.map(|g| g.centroid()).as_ref()));
-        output_array.into()
+    fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
+        points_with_options(self, options)
     }
 }
 
@@ -97,14 +100,23 @@ macro_rules! iter_geo_impl {
             type Output = PointArray;
 
             fn centroid(&self) -> Self::Output {
+                self.centroid_with_options(&Default::default())
+            }
+
+            fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
                 let mut output_array = PointBuilder::with_capacity_and_options(
                     Dimension::XY,
                     self.len(),
                     self.coord_type(),
                     self.metadata().clone(),
                 );
-                self.iter_geo().for_each(|maybe_g| {
-                    output_array.push_point(maybe_g.and_then(|g| g.centroid()).as_ref())
+                self.iter_geo().for_each(|maybe_g| match maybe_g {
+                    Some(g) => output_array.push_point(
+                        prepare_geometry(g, options)
+                            .and_then(|g| g.centroid())
+                            .as_ref(),
+                    ),
+                    None => output_array.push_null(),
                 });
                 output_array.into()
             }
@@ -113,30 +125,76 @@ macro_rules! iter_geo_impl {
 }
 
 iter_geo_impl!(LineStringArray);
-iter_geo_impl!(PolygonArray);
 iter_geo_impl!(MultiPointArray);
 iter_geo_impl!(MultiLineStringArray);
-iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(RectArray);
+
+/// Implementation that computes area-weighted centroids over the coordinate buffers, and falls
+/// back to geo for geometries without area or with empty parts.
+macro_rules! raw_polygon_impl {
+    ($type:ty) => {
+        impl Centroid for $type {
+            type Output = PointArray;
+
+            fn centroid(&self) -> Self::Output {
+                self.centroid_with_options(&Default::default())
+            }
+
+            fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
+                let mut output_array = PointBuilder::with_capacity_and_options(
+                    Dimension::XY,
+                    self.len(),
+                    self.coord_type(),
+                    self.metadata().clone(),
+                );
+                for (index, centroid) in self.raw_centroids().into_iter().enumerate() {
+                    if let Some((x, y)) = centroid {
+                        output_array.push_point(Some(&geo::Point::new(x, y)));
+                        continue;
+                    }
+                    match self.get_as_geo(index) {
+                        Some(g) => output_array.push_point(
+                            prepare_geometry(g, options)
+                                .and_then(|g| g.centroid())
+                                .as_ref(),
+                        ),
+                        None => output_array.push_null(),
+                    }
+                }
+                output_array.into()
+            }
+        }
+    };
+}
+
+raw_polygon_impl!(PolygonArray);
+raw_polygon_impl!(MultiPolygonArray);
 
 impl Centroid for &dyn NativeArray {
     type Output = Result<PointArray>;
 
     fn centroid(&self) -> Self::Output {
+        self.centroid_with_options(&Default::default())
+    }
+
+    fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, _) => self.as_point().centroid(),
-            LineString(_, _) => self.as_line_string().centroid(),
-            Polygon(_, _) => self.as_polygon().centroid(),
-            MultiPoint(_, _) => self.as_multi_point().centroid(),
-            MultiLineString(_, _) => self.as_multi_line_string().centroid(),
-            MultiPolygon(_, _) => self.as_multi_polygon().centroid(),
-            GeometryCollection(_, _) => self.as_geometry_collection().centroid(),
-            Rect(_) => self.as_rect().centroid(),
-            Geometry(_) => self.as_geometry().centroid(),
+            Point(_, _) => self.as_point().centroid_with_options(options),
+            LineString(_, _) => self.as_line_string().centroid_with_options(options),
+            Polygon(_, _) => self.as_polygon().centroid_with_options(options),
+            MultiPoint(_, _) => self.as_multi_point().centroid_with_options(options),
+            MultiLineString(_, _) => self.as_multi_line_string().centroid_with_options(options),
+            MultiPolygon(_, _) => self.as_multi_polygon().centroid_with_options(options),
+            GeometryCollection(_, _) => {
+                self.as_geometry_collection().centroid_with_options(options)
+            }
+            Rect(_) => self.as_rect().centroid_with_options(options),
+            Geometry(_) => self.as_geometry().centroid_with_options(options),
         };
         Ok(result)
     }
@@ -146,7 +204,12 @@ impl<G: NativeArray> Centroid for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedPointArray>;
 
     fn centroid(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().centroid())?.try_into()
+        self.centroid_with_options(&Default::default())
+    }
+
+    fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().centroid_with_options(options))?
+            .try_into()
     }
 }
 
@@ -154,18 +217,24 @@ impl Centroid for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedPointArray>;
 
     fn centroid(&self) -> Self::Output {
+        self.centroid_with_options(&Default::default())
+    }
+
+    fn centroid_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, _) => self.as_point().centroid(),
-            LineString(_, _) => self.as_line_string().centroid(),
-            Polygon(_, _) => self.as_polygon().centroid(),
-            MultiPoint(_, _) => self.as_multi_point().centroid(),
-            MultiLineString(_, _) => self.as_multi_line_string().centroid(),
-            MultiPolygon(_, _) => self.as_multi_polygon().centroid(),
-            GeometryCollection(_, _) => self.as_geometry_collection().centroid(),
-            Rect(_) => self.as_rect().centroid(),
-            Geometry(_) => self.as_geometry().centroid(),
+            Point(_, _) => self.as_point().centroid_with_options(options),
+            LineString(_, _) => self.as_line_string().centroid_with_options(options),
+            Polygon(_, _) => self.as_polygon().centroid_with_options(options),
+            MultiPoint(_, _) => self.as_multi_point().centroid_with_options(options),
+            MultiLineString(_, _) => self.as_multi_line_string().centroid_with_options(options),
+            MultiPolygon(_, _) => self.as_multi_polygon().centroid_with_options(options),
+            GeometryCollection(_, _) => {
+                self.as_geometry_collection().centroid_with_options(options)
+            }
+            Rect(_) => self.as_rect().centroid_with_options(options),
+            Geometry(_) => self.as_geometry().centroid_with_options(options),
         }
     }
 }
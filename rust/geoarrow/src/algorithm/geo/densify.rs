@@ -6,8 +6,8 @@ use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::ArrayAccessor;
 use crate::NativeArray;
-use geo::Euclidean;
 use geo::{CoordFloat, Densify as _Densify};
+use geo::{Distance, Euclidean, Geodesic, InterpolatePoint, Point};
 use num_traits::FromPrimitive;
 
 /// Return a new linear geometry containing both existing and new interpolated coordinates with
@@ -18,18 +18,55 @@ pub trait Densify {
     type Output;
 
     fn densify(&self, max_distance: f64) -> Self::Output;
+
+    /// Densify geometries in longitude and latitude along geodesics on the WGS84 ellipsoid, with
+    /// a maximum distance of `max_distance` meters between coordinates.
+    ///
+    /// Inserted coordinates are on the geodesic between the existing coordinates, so that the
+    /// densified geometry follows the same path on the ellipsoid when its edges are interpreted
+    /// as straight lines by planar consumers.
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output;
+}
+
+/// Densify with a metric space of [`geo`], which determines the path between coordinates.
+trait DensifyIn {
+    type Output;
+
+    fn densify_in<MetricSpace>(&self, max_distance: f64) -> Self::Output
+    where
+        MetricSpace: Distance<f64, Point, Point> + InterpolatePoint<f64>;
+}
+
+/// Implement [`Densify`] with the Euclidean and geodesic metric spaces of [`DensifyIn`].
+macro_rules! impl_densify {
+    ($type:ty) => {
+        impl Densify for $type {
+            type Output = <$type as DensifyIn>::Output;
+
+            fn densify(&self, max_distance: f64) -> Self::Output {
+                self.densify_in::<Euclidean>(max_distance)
+            }
+
+            fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+                self.densify_in::<Geodesic>(max_distance)
+            }
+        }
+    };
 }
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
     ($type:ty, $builder_type:ty, $method:ident, $geo_type:ty) => {
-        impl Densify for $type {
+        impl DensifyIn for $type {
             type Output = $type;
 
-            fn densify(&self, max_distance: f64) -> Self::Output {
+            fn densify_in<MetricSpace>(&self, max_distance: f64) -> Self::Output
+            where
+                MetricSpace: Distance<f64, Point, Point> + InterpolatePoint<f64>,
+            {
                 let output_geoms: Vec<Option<$geo_type>> = self
                     .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.densify::<Euclidean>(max_distance)))
+                    .map(|maybe_g| maybe_g.map(|geom| geom.densify::<MetricSpace>(max_distance)))
                     .collect();
 
                 <$builder_type>::$method(
@@ -41,6 +78,8 @@ macro_rules! iter_geo_impl {
                 .finish()
             }
         }
+
+        impl_densify!($type);
     };
 }
 
@@ -117,14 +156,18 @@ impl<F: geo::CoordFloat + FromPrimitive> geo::Densify<F> for GeometryDensifyWrap
     }
 }
 
-impl Densify for GeometryArray {
+impl DensifyIn for GeometryArray {
     type Output = Result<Self>;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
+    fn densify_in<MetricSpace>(&self, max_distance: f64) -> Self::Output
+    where
+        MetricSpace: Distance<f64, Point, Point> + InterpolatePoint<f64>,
+    {
         let output_geoms: Vec<Option<geo::Geometry>> = self
             .iter_geo()
             .map(|maybe_g| {
-                maybe_g.map(|geom| GeometryDensifyWrapper(&geom).densify::<Euclidean>(max_distance))
+                maybe_g
+                    .map(|geom| GeometryDensifyWrapper(&geom).densify::<MetricSpace>(max_distance))
             })
             .collect();
 
@@ -138,24 +181,40 @@ impl Densify for GeometryArray {
     }
 }
 
-impl Densify for &dyn NativeArray {
+impl_densify!(GeometryArray);
+
+impl DensifyIn for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
+    fn densify_in<MetricSpace>(&self, max_distance: f64) -> Self::Output
+    where
+        MetricSpace: Distance<f64, Point, Point> + InterpolatePoint<f64>,
+    {
         use NativeType::*;
 
         let result: Arc<dyn NativeArray> = match self.data_type() {
-            LineString(_, _) => Arc::new(self.as_line_string().densify(max_distance)),
-            Polygon(_, _) => Arc::new(self.as_polygon().densify(max_distance)),
-            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().densify(max_distance)),
-            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().densify(max_distance)),
-            Geometry(_) => Arc::new(self.as_geometry().densify(max_distance)?),
+            LineString(_, _) => Arc::new(
+                self.as_line_string()
+                    .densify_in::<MetricSpace>(max_distance),
+            ),
+            Polygon(_, _) => Arc::new(self.as_polygon().densify_in::<MetricSpace>(max_distance)),
+            MultiLineString(_, _) => Arc::new(
+                self.as_multi_line_string()
+                    .densify_in::<MetricSpace>(max_distance),
+            ),
+            MultiPolygon(_, _) => Arc::new(
+                self.as_multi_polygon()
+                    .densify_in::<MetricSpace>(max_distance),
+            ),
+            Geometry(_) => Arc::new(self.as_geometry().densify_in::<MetricSpace>(max_distance)?),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
         Ok(result)
     }
 }
 
+impl_densify!(&dyn NativeArray);
+
 macro_rules! impl_chunked {
     ($struct_name:ty) => {
         impl Densify for $struct_name {
@@ -166,6 +225,12 @@ macro_rules! impl_chunked {
                     .try_into()
                     .unwrap()
             }
+
+            fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+                self.map(|chunk| chunk.densify_geodesic(max_distance))
+                    .try_into()
+                    .unwrap()
+            }
         }
     };
 }
@@ -190,4 +255,50 @@ impl Densify for &dyn ChunkedNativeArray {
         };
         Ok(result)
     }
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
+            LineString(_, _) => Arc::new(self.as_line_string().densify_geodesic(max_distance)),
+            Polygon(_, _) => Arc::new(self.as_polygon().densify_geodesic(max_distance)),
+            MultiLineString(_, _) => {
+                Arc::new(self.as_multi_line_string().densify_geodesic(max_distance))
+            }
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().densify_geodesic(max_distance)),
+            _ => return Err(GeoArrowError::IncorrectType("".into())),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+    use geo::Length;
+
+    #[test]
+    fn densify_geodesic() {
+        // A segment along a parallel, whose geodesic bulges toward the pole
+        let line_string = geo::line_string![(x: 0., y: 60.), (x: 40., y: 60.)];
+        let array: LineStringArray = (vec![line_string.clone()].as_slice(), Dimension::XY).into();
+
+        let planar = array.densify(10.).value_as_geo(0);
+        assert!(planar.0.iter().all(|coord| coord.y == 60.));
+
+        let geodesic = array.densify_geodesic(100_000.).value_as_geo(0);
+        assert!(geodesic.0.len() > 2);
+        assert!(geodesic.0[1..geodesic.0.len() - 1]
+            .iter()
+            .all(|coord| coord.y > 60.));
+        assert_relative_eq!(
+            geodesic.length::<Geodesic>(),
+            line_string.length::<Geodesic>(),
+            max_relative = 1e-9
+        );
+        assert!(geodesic
+            .lines()
+            .all(|line| Geodesic::distance(line.start_point(), line.end_point()) <= 100_000.));
+    }
 }
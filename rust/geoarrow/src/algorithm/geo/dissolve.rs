@@ -0,0 +1,538 @@
+use std::collections::hash_map::{DefaultHasher, Entry};
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use arrow::row::{OwnedRow, RowConverter, SortField};
+use arrow_array::cast::AsArray;
+use arrow_array::{make_array, Array, ArrayRef, BinaryArray, RecordBatch, RecordBatchReader};
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use geo::{BooleanOps, CoordsIter};
+
+use crate::algorithm::backend::to_multi_polygon;
+use crate::algorithm::geo::unary_union::cascaded_union;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{
+    CoordType, GeometryArray, GeometryBuilder, MultiPolygonBuilder, NativeArrayDyn, WKBArray,
+};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::io::wkb::to_wkb;
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
+use crate::trait_::{ArrayAccessor, IntoArrow, NativeScalar};
+use crate::{ArrayBase, NativeArray};
+
+/// Used to give every spill directory of the process a distinct name.
+static SPILL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Options for [`StreamingDissolve`].
+#[derive(Debug, Clone)]
+pub struct DissolveOptions {
+    /// The approximate number of bytes that partial unions can take in memory before they are
+    /// spilled to disk.
+    ///
+    /// Defaults to 256 MiB.
+    pub memory_limit: usize,
+
+    /// The directory to write spill files to. A temporary subdirectory is created in it, and
+    /// removed when the dissolve is finished or dropped.
+    ///
+    /// Defaults to [`std::env::temp_dir`].
+    pub spill_dir: Option<PathBuf>,
+
+    /// The number of files that spilled partial unions are split into by key.
+    ///
+    /// At the end, the partial unions of one file at a time are loaded and merged, so more
+    /// partitions lower the peak memory use when spilling. Defaults to 16.
+    pub num_partitions: usize,
+}
+
+impl Default for DissolveOptions {
+    fn default() -> Self {
+        Self {
+            memory_limit: 256 * 1024 * 1024,
+            spill_dir: None,
+            num_partitions: 16,
+        }
+    }
+}
+
+/// Spill files of partial unions, one Arrow IPC stream per partition.
+struct Spill {
+    dir: PathBuf,
+    paths: Vec<PathBuf>,
+    writers: Vec<StreamWriter<BufWriter<File>>>,
+}
+
+impl Spill {
+    fn try_new(options: &DissolveOptions) -> Result<Self> {
+        let dir = options
+            .spill_dir
+            .clone()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(format!(
+                "geoarrow-dissolve-{}-{}",
+                std::process::id(),
+                SPILL_COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+        std::fs::create_dir_all(&dir)?;
+
+        let schema = spill_schema();
+        let mut paths = Vec::with_capacity(options.num_partitions);
+        let mut writers = Vec::with_capacity(options.num_partitions);
+        for partition in 0..options.num_partitions {
+            let path = dir.join(format!("partition-{partition}.arrows"));
+            writers.push(StreamWriter::try_new(
+                BufWriter::new(File::create(&path)?),
+                &schema,
+            )?);
+            paths.push(path);
+        }
+        Ok(Self {
+            dir,
+            paths,
+            writers,
+        })
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Spilled partial unions have the row-encoded key and the WKB geometry of each group.
+fn spill_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("key", DataType::Binary, false),
+        Field::new("geometry", DataType::Binary, false),
+    ])
+}
+
+fn partition(key: &[u8], num_partitions: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    (hasher.finish() % num_partitions as u64) as usize
+}
+
+/// An estimate of the memory used by a multi polygon.
+fn estimated_size(multi_polygon: &geo::MultiPolygon) -> usize {
+    multi_polygon.coords_count() * std::mem::size_of::<geo::Coord>()
+}
+
+/// A group-by dissolve of record batches that don't need to fit in memory at once.
+///
+/// Batches are pushed with [`push_batch`][Self::push_batch], and the union of the geometries of
+/// each key seen so far is kept. When these partial unions take more than
+/// [`DissolveOptions::memory_limit`], they are spilled to disk as WKB, and merged by partition of
+/// keys in [`finish`][Self::finish].
+///
+/// Geometries must be polygons or multi polygons. The union is computed with [`geo`], in 2D:
+/// Z values are dropped. Null geometries are skipped, and a null key is a group of its own.
+pub struct StreamingDissolve {
+    key_index: usize,
+    geometry_index: usize,
+    geometry_field: Arc<Field>,
+    output_schema: SchemaRef,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+    converter: RowConverter,
+    partials: HashMap<OwnedRow, geo::MultiPolygon>,
+    memory_used: usize,
+    options: DissolveOptions,
+    spill: Option<Spill>,
+}
+
+impl StreamingDissolve {
+    /// Create a dissolve of batches of `schema`, grouped by the column at `key_index`.
+    ///
+    /// The geometry column is `geometry_index`, or the only geometry column of `schema` if
+    /// `None`.
+    pub fn try_new(
+        schema: &Schema,
+        key_index: usize,
+        geometry_index: Option<usize>,
+        options: DissolveOptions,
+    ) -> Result<Self> {
+        let geometry_index = if let Some(index) = geometry_index {
+            index
+        } else {
+            let geom_indices = schema.geometry_columns();
+            if geom_indices.len() != 1 {
+                return Err(GeoArrowError::General(
+                    "`geometry_index` must be provided when multiple geometry columns exist."
+                        .to_string(),
+                ));
+            }
+            geom_indices[0]
+        };
+        if options.num_partitions == 0 {
+            return Err(GeoArrowError::General(
+                "num_partitions must be at least 1".to_string(),
+            ));
+        }
+
+        let key_field = schema.field(key_index);
+        let geometry_field = Arc::new(schema.field(geometry_index).clone());
+        let coord_type = NativeType::try_from(geometry_field.as_ref())?.coord_type();
+        let metadata = Arc::new(ArrayMetadata::try_from(geometry_field.as_ref())?);
+        let output_geometry_field =
+            MultiPolygonBuilder::new_with_options(Dimension::XY, coord_type, metadata.clone())
+                .finish()
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name(geometry_field.name());
+        let output_schema = Arc::new(Schema::new(vec![key_field.clone(), output_geometry_field]));
+
+        Ok(Self {
+            key_index,
+            geometry_index,
+            geometry_field,
+            output_schema,
+            coord_type,
+            metadata,
+            converter: RowConverter::new(vec![SortField::new(key_field.data_type().clone())])?,
+            partials: HashMap::new(),
+            memory_used: 0,
+            options,
+            spill: None,
+        })
+    }
+
+    /// The schema of the output of [`finish`][Self::finish]: the key column, followed by a
+    /// multi polygon column with the name of the input geometry column.
+    pub fn output_schema(&self) -> SchemaRef {
+        self.output_schema.clone()
+    }
+
+    /// Add the geometries of a batch to the unions of their keys.
+    pub fn push_batch(&mut self, batch: &RecordBatch) -> Result<()> {
+        let keys = self
+            .converter
+            .convert_columns(&[batch.column(self.key_index).clone()])?;
+        let geometry = NativeArrayDyn::from_arrow_array(
+            batch.column(self.geometry_index),
+            &self.geometry_field,
+        )?
+        .into_inner();
+
+        let mut groups: HashMap<_, Vec<geo::MultiPolygon>> = HashMap::new();
+        for (key, geom) in keys.iter().zip(iter_geometries(geometry.as_ref())) {
+            if let Some(geom) = geom {
+                groups
+                    .entry(key)
+                    .or_default()
+                    .push(to_multi_polygon(geometry_to_geo(&geom))?);
+            }
+        }
+        for (key, multi_polygons) in groups {
+            self.merge(key.owned(), cascaded_union(multi_polygons));
+        }
+
+        if self.memory_used > self.options.memory_limit {
+            self.spill_partials()?;
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, key: OwnedRow, multi_polygon: geo::MultiPolygon) {
+        let key_size = key.row().as_ref().len();
+        match self.partials.entry(key) {
+            Entry::Occupied(mut entry) => {
+                let union = entry.get().union(&multi_polygon);
+                self.memory_used =
+                    self.memory_used - estimated_size(entry.get()) + estimated_size(&union);
+                entry.insert(union);
+            }
+            Entry::Vacant(entry) => {
+                self.memory_used += key_size + estimated_size(&multi_polygon);
+                entry.insert(multi_polygon);
+            }
+        }
+    }
+
+    fn spill_partials(&mut self) -> Result<()> {
+        if self.spill.is_none() {
+            self.spill = Some(Spill::try_new(&self.options)?);
+        }
+        let spill = self.spill.as_mut().unwrap();
+
+        let num_partitions = self.options.num_partitions;
+        let mut partitions: Vec<(Vec<OwnedRow>, Vec<geo::MultiPolygon>)> =
+            vec![(vec![], vec![]); num_partitions];
+        for (key, multi_polygon) in self.partials.drain() {
+            let (keys, multi_polygons) =
+                &mut partitions[partition(key.row().as_ref(), num_partitions)];
+            keys.push(key);
+            multi_polygons.push(multi_polygon);
+        }
+        self.memory_used = 0;
+
+        let schema = Arc::new(spill_schema());
+        for ((keys, multi_polygons), writer) in partitions.into_iter().zip(spill.writers.iter_mut())
+        {
+            if keys.is_empty() {
+                continue;
+            }
+            let keys = BinaryArray::from_iter_values(keys.iter().map(|key| key.row()));
+            let geometry = MultiPolygonBuilder::from_multi_polygons(
+                &multi_polygons,
+                Dimension::XY,
+                CoordType::Interleaved,
+                Default::default(),
+            )
+            .finish();
+            let wkb = to_wkb::<i32>(&geometry).into_arrow();
+            writer.write(&RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(keys), Arc::new(wkb)],
+            )?)?;
+        }
+        Ok(())
+    }
+
+    fn output_batch(&self, partials: HashMap<OwnedRow, geo::MultiPolygon>) -> Result<RecordBatch> {
+        let (keys, multi_polygons): (Vec<_>, Vec<_>) = partials.into_iter().unzip();
+        let mut key_columns = self
+            .converter
+            .convert_rows(keys.iter().map(|key| key.row()))?;
+        let geometry = MultiPolygonBuilder::from_multi_polygons(
+            &multi_polygons,
+            Dimension::XY,
+            self.coord_type,
+            self.metadata.clone(),
+        )
+        .finish();
+        Ok(RecordBatch::try_new(
+            self.output_schema.clone(),
+            vec![key_columns.remove(0), geometry.into_array_ref()],
+        )?)
+    }
+
+    /// Merge the partial unions and return the dissolved geometry of every key.
+    ///
+    /// The output has one batch, or one batch per partition of keys if partial unions were
+    /// spilled. The order of keys is unspecified.
+    pub fn finish(mut self) -> Result<Table> {
+        let Some(mut spill) = self.spill.take() else {
+            let partials = std::mem::take(&mut self.partials);
+            let batch = self.output_batch(partials)?;
+            return Table::try_new(vec![batch], self.output_schema.clone());
+        };
+
+        let num_partitions = self.options.num_partitions;
+        let mut in_memory = vec![HashMap::new(); num_partitions];
+        for (key, multi_polygon) in self.partials.drain() {
+            in_memory[partition(key.row().as_ref(), num_partitions)].insert(key, multi_polygon);
+        }
+        for writer in spill.writers.iter_mut() {
+            writer.finish()?;
+        }
+
+        let mut batches = vec![];
+        for (path, partials) in spill.paths.iter().zip(in_memory) {
+            self.partials = partials;
+            let reader = StreamReader::try_new(BufReader::new(File::open(path)?), None)?;
+            for spilled in reader {
+                let spilled = spilled?;
+                let keys = spilled.column(0).as_binary::<i32>();
+                let wkb = WKBArray::new(
+                    spilled.column(1).as_binary::<i32>().clone(),
+                    Default::default(),
+                );
+                for i in 0..spilled.num_rows() {
+                    let key = self.converter.parser().parse(keys.value(i)).owned();
+                    self.merge(key, to_multi_polygon(wkb.value(i).to_geo())?);
+                }
+            }
+            if !self.partials.is_empty() {
+                let partials = std::mem::take(&mut self.partials);
+                batches.push(self.output_batch(partials)?);
+            }
+        }
+        Table::try_new(batches, self.output_schema.clone())
+    }
+}
+
+/// Dissolve the batches of `reader` by the column at `key_index`, without holding all of them in
+/// memory. See [`StreamingDissolve`].
+pub fn dissolve_stream(
+    reader: Box<dyn RecordBatchReader>,
+    key_index: usize,
+    geometry_index: Option<usize>,
+    options: DissolveOptions,
+) -> Result<Table> {
+    let mut dissolve =
+        StreamingDissolve::try_new(&reader.schema(), key_index, geometry_index, options)?;
+    for batch in reader {
+        dissolve.push_batch(&batch?)?;
+    }
+    dissolve.finish()
+}
+
+/// Union the geometries of an array by key, returning the distinct keys and the union of the
+/// geometries of each key.
+///
+/// `keys` must have the same length as `geometry`, and may have any type supported by Arrow's
+/// row format, such as primitive and string types. Keys are returned in the order of their first
+/// row. A null key is a group of its own, null geometries are skipped, and the union of a key
+/// with only null geometries is an empty multi polygon.
+///
+/// Geometries must be polygons or multi polygons. The unions are computed as in
+/// [`unary_union`][crate::algorithm::geo::unary_union], and returned as multi polygons with the
+/// coordinate type and metadata of `geometry`. Use [`StreamingDissolve`] for data that doesn't
+/// fit in memory.
+pub fn dissolve_by_key(
+    geometry: &dyn NativeArray,
+    keys: &dyn Array,
+) -> Result<(ArrayRef, GeometryArray)> {
+    if keys.len() != geometry.len() {
+        return Err(GeoArrowError::General(format!(
+            "Expected {} keys, one per geometry, got {}",
+            geometry.len(),
+            keys.len()
+        )));
+    }
+    let converter = RowConverter::new(vec![SortField::new(keys.data_type().clone())])?;
+    let rows = converter.convert_columns(&[make_array(keys.to_data())])?;
+
+    let mut group_indices = HashMap::new();
+    let mut group_keys = vec![];
+    let mut groups: Vec<Vec<geo::MultiPolygon>> = vec![];
+    for (key, geom) in rows.iter().zip(iter_geometries(geometry)) {
+        let group = *group_indices.entry(key).or_insert_with(|| {
+            group_keys.push(key);
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        if let Some(geom) = geom {
+            groups[group].push(to_multi_polygon(geometry_to_geo(&geom))?);
+        }
+    }
+
+    let mut key_columns = converter.convert_rows(group_keys)?;
+    let mut builder =
+        GeometryBuilder::new_with_options(geometry.coord_type(), geometry.metadata(), false);
+    for multi_polygons in groups {
+        builder.push_multi_polygon(Some(&cascaded_union(multi_polygons)))?;
+    }
+    Ok((key_columns.remove(0), builder.finish()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{Array, StringArray};
+    use geo::{polygon, Area};
+
+    use super::*;
+    use crate::array::{AsChunkedNativeArray, PolygonArray};
+
+    fn batch(cells: Vec<Option<&str>>, offsets: Vec<f64>) -> RecordBatch {
+        let polygons = offsets
+            .into_iter()
+            .map(|x| polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)])
+            .collect::<Vec<_>>();
+        let array: PolygonArray = (polygons.as_slice(), Dimension::XY).into();
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("key", DataType::Utf8, true)),
+            array.extension_field(),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![Arc::new(StringArray::from(cells)), array.into_array_ref()],
+        )
+        .unwrap()
+    }
+
+    /// The area of the dissolved geometry of every key, sorted by key.
+    fn areas(table: &Table) -> Vec<(Option<String>, f64)> {
+        let geometry = table.geometry_column(None).unwrap();
+        let mut areas = vec![];
+        for (batch, chunk) in table
+            .batches()
+            .iter()
+            .zip(geometry.as_ref().as_multi_polygon().chunks())
+        {
+            let keys = batch.column(0).as_string::<i32>();
+            for i in 0..batch.num_rows() {
+                let key = keys.is_valid(i).then(|| keys.value(i).to_string());
+                areas.push((key, chunk.value_as_geo(i).unsigned_area()));
+            }
+        }
+        areas.sort_by(|a, b| a.0.cmp(&b.0));
+        areas
+    }
+
+    fn dissolve(options: DissolveOptions) -> Table {
+        let batches = vec![
+            batch(vec![Some("a"), Some("b"), Some("a")], vec![0., 10., 1.]),
+            batch(vec![Some("a"), None], vec![0.5, 20.]),
+            batch(vec![Some("b"), Some("c")], vec![11., 30.]),
+        ];
+        let schema = batches[0].schema();
+        let reader = arrow_array::RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+        dissolve_stream(Box::new(reader), 0, None, options).unwrap()
+    }
+
+    #[test]
+    fn dissolve_in_memory() {
+        let table = dissolve(Default::default());
+        assert_eq!(table.batches().len(), 1);
+        assert_eq!(
+            areas(&table),
+            vec![
+                (None, 1.),
+                (Some("a".to_string()), 2.),
+                (Some("b".to_string()), 2.),
+                (Some("c".to_string()), 1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn by_key() {
+        let batch = batch(
+            vec![Some("b"), Some("a"), None, Some("b")],
+            vec![0., 10., 20., 0.5],
+        );
+        let geometry =
+            NativeArrayDyn::from_arrow_array(batch.column(1), batch.schema_ref().field(1))
+                .unwrap()
+                .into_inner();
+        let (keys, unions) = dissolve_by_key(geometry.as_ref(), batch.column(0)).unwrap();
+        assert_eq!(
+            keys.as_string::<i32>().iter().collect::<Vec<_>>(),
+            vec![Some("b"), Some("a"), None]
+        );
+        let areas = unions
+            .iter_geo()
+            .map(|geom| geom.unwrap().unsigned_area())
+            .collect::<Vec<_>>();
+        assert_eq!(areas, vec![1.5, 1., 1.]);
+
+        assert!(dissolve_by_key(geometry.as_ref(), &StringArray::from(vec!["a"])).is_err());
+    }
+
+    #[test]
+    fn dissolve_with_spill() {
+        let options = DissolveOptions {
+            memory_limit: 0,
+            num_partitions: 3,
+            ..Default::default()
+        };
+        let table = dissolve(options);
+        assert_eq!(table.len(), 4);
+        assert_eq!(areas(&table), areas(&dissolve(Default::default())));
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{FixedSizeListArray, Float64Array, RecordBatch, UInt32Array};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Field, Schema};
+use geo::{BoundingRect, Distance, Euclidean};
+
+use crate::algorithm::geo_index::SpatialIndexBuilder;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{ArrayAccessor, NativeScalar};
+use crate::NativeArray;
+
+/// The number of geometries of each input that are converted to [`geo`] geometries at a time.
+const TILE_SIZE: usize = 1024;
+
+/// The maximum number of values in the output of [`distance_matrix`], which is 2 GiB of
+/// `Float64` values.
+pub const MAX_DISTANCE_MATRIX_VALUES: usize = 1 << 28;
+
+/// Convert a range of an array to [`geo`] geometries.
+fn to_geo_geometries(
+    array: &dyn NativeArray,
+    offset: usize,
+    length: usize,
+) -> Vec<Option<geo::Geometry>> {
+    let array = array.slice(offset, length);
+
+    macro_rules! impl_to_geo {
+        ($cast_func:ident) => {
+            array
+                .as_ref()
+                .$cast_func()
+                .iter()
+                .map(|geom| geom.map(|geom| geom.to_geo_geometry()))
+                .collect()
+        };
+    }
+
+    use NativeType::*;
+
+    match array.data_type() {
+        Point(_, _) => impl_to_geo!(as_point),
+        LineString(_, _) => impl_to_geo!(as_line_string),
+        Polygon(_, _) => impl_to_geo!(as_polygon),
+        MultiPoint(_, _) => impl_to_geo!(as_multi_point),
+        MultiLineString(_, _) => impl_to_geo!(as_multi_line_string),
+        MultiPolygon(_, _) => impl_to_geo!(as_multi_polygon),
+        GeometryCollection(_, _) => impl_to_geo!(as_geometry_collection),
+        Rect(_) => impl_to_geo!(as_rect),
+        Geometry(_) => impl_to_geo!(as_geometry),
+    }
+}
+
+/// Compute the Euclidean distance between every geometry of `left` and every geometry of
+/// `right`.
+///
+/// Returns a dense matrix with one row per geometry of `left`, as a [`FixedSizeListArray`] of
+/// `Float64` values with a list size of `right.len()`. Rows for null geometries of `left` are
+/// null, and distances to null geometries of `right` are null.
+///
+/// The inputs are processed in tiles, so that only a bounded number of geometries are converted
+/// at a time. The output itself still has `left.len() * right.len()` values, and an error is
+/// returned when that is more than [`MAX_DISTANCE_MATRIX_VALUES`]; use [`within_distance_pairs`]
+/// when only nearby pairs are of interest.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::Array;
+/// use geoarrow::algorithm::geo::distance_matrix;
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+///
+/// let points = vec![geo::point!(x: 0., y: 0.), geo::point!(x: 3., y: 4.)];
+/// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+///
+/// let matrix = distance_matrix(&array, &array).unwrap();
+/// assert_eq!(matrix.len(), 2);
+/// assert_eq!(matrix.value_length(), 2);
+/// ```
+pub fn distance_matrix(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+) -> Result<FixedSizeListArray> {
+    let (num_rows, num_cols) = (left.len(), right.len());
+    let list_size = i32::try_from(num_cols)
+        .map_err(|_| GeoArrowError::General("Too many geometries in right array".to_string()))?;
+
+    let num_values = num_rows
+        .checked_mul(num_cols)
+        .filter(|num_values| *num_values <= MAX_DISTANCE_MATRIX_VALUES)
+        .ok_or_else(|| {
+            GeoArrowError::General(format!(
+                "Distance matrix of {num_rows} by {num_cols} values is larger than the maximum of {MAX_DISTANCE_MATRIX_VALUES}"
+            ))
+        })?;
+
+    let mut values = vec![0.; num_values];
+    let mut value_validity = vec![true; num_values];
+
+    for row_start in (0..num_rows).step_by(TILE_SIZE) {
+        let row_len = TILE_SIZE.min(num_rows - row_start);
+        let left_tile = to_geo_geometries(left, row_start, row_len);
+
+        for col_start in (0..num_cols).step_by(TILE_SIZE) {
+            let col_len = TILE_SIZE.min(num_cols - col_start);
+            let right_tile = to_geo_geometries(right, col_start, col_len);
+
+            for (i, left_geom) in left_tile.iter().enumerate() {
+                let row_offset = (row_start + i) * num_cols + col_start;
+                for (j, right_geom) in right_tile.iter().enumerate() {
+                    match (left_geom, right_geom) {
+                        (Some(left_geom), Some(right_geom)) => {
+                            values[row_offset + j] = Euclidean::distance(left_geom, right_geom)
+                        }
+                        _ => value_validity[row_offset + j] = false,
+                    }
+                }
+            }
+        }
+    }
+
+    let values = Float64Array::new(values.into(), Some(NullBuffer::from(value_validity)));
+    let field = Arc::new(Field::new("item", DataType::Float64, true));
+    Ok(FixedSizeListArray::try_new(
+        field,
+        list_size,
+        Arc::new(values),
+        left.nulls().cloned(),
+    )?)
+}
+
+/// A row index as a `UInt32` index of the output of [`within_distance_pairs`].
+fn output_index(row: usize) -> Result<u32> {
+    u32::try_from(row).map_err(|_| {
+        GeoArrowError::General(format!(
+            "Row index {row} does not fit in the UInt32 index columns"
+        ))
+    })
+}
+
+/// Find all pairs of geometries of `left` and `right` that are within `distance` of each other.
+///
+/// Returns a sparse matrix of coordinate triplets as a [`RecordBatch`] with the columns
+/// `left_index` and `right_index` (`UInt32`), and `distance` (`Float64`), sorted by
+/// `left_index` and then `right_index`. Null and empty geometries are never part of a pair.
+/// Returns an error if a pair is found at a row index that doesn't fit in a `u32`.
+///
+/// A spatial index over `right` is used to find candidate pairs, so that the memory use scales
+/// with the number of pairs found rather than with the size of the full matrix.
+pub fn within_distance_pairs(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    distance: f64,
+) -> Result<RecordBatch> {
+    if distance.is_nan() || distance < 0. {
+        return Err(GeoArrowError::General(
+            "distance must be a non-negative number".to_string(),
+        ));
+    }
+
+    let mut index_builder = SpatialIndexBuilder::new();
+    index_builder.push_array(right)?;
+    let index = index_builder.finish()?;
+
+    let mut left_indices = vec![];
+    let mut right_indices = vec![];
+    let mut distances = vec![];
+
+    for row_start in (0..left.len()).step_by(TILE_SIZE) {
+        let row_len = TILE_SIZE.min(left.len() - row_start);
+        let left_tile = to_geo_geometries(left, row_start, row_len);
+
+        // Find the candidates of every geometry in the tile with the index
+        let candidates = left_tile
+            .iter()
+            .map(|geom| {
+                let Some(rect) = geom.as_ref().and_then(|geom| geom.bounding_rect()) else {
+                    return vec![];
+                };
+                index.search(
+                    rect.min().x - distance,
+                    rect.min().y - distance,
+                    rect.max().x + distance,
+                    rect.max().y + distance,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Only convert the right geometries that are candidates of this tile
+        let mut right_geoms: HashMap<u64, geo::Geometry> = HashMap::new();
+        for row in candidates.iter().flatten() {
+            if !right_geoms.contains_key(row) {
+                if let Some(geom) = to_geo_geometries(right, *row as usize, 1).pop().flatten() {
+                    right_geoms.insert(*row, geom);
+                }
+            }
+        }
+
+        for (i, (left_geom, rows)) in left_tile.iter().zip(candidates).enumerate() {
+            let Some(left_geom) = left_geom else {
+                continue;
+            };
+            for row in rows {
+                let Some(right_geom) = right_geoms.get(&row) else {
+                    continue;
+                };
+                let pair_distance = Euclidean::distance(left_geom, right_geom);
+                if pair_distance <= distance {
+                    left_indices.push(output_index(row_start + i)?);
+                    right_indices.push(output_index(row as usize)?);
+                    distances.push(pair_distance);
+                }
+            }
+        }
+    }
+
+    let schema = Schema::new(vec![
+        Field::new("left_index", DataType::UInt32, false),
+        Field::new("right_index", DataType::UInt32, false),
+        Field::new("distance", DataType::Float64, false),
+    ]);
+    Ok(RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(UInt32Array::from(left_indices)),
+            Arc::new(UInt32Array::from(right_indices)),
+            Arc::new(Float64Array::from(distances)),
+        ],
+    )?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::datatypes::Dimension;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, UInt32Type};
+    use arrow_array::Array;
+
+    fn points() -> PointArray {
+        let points = vec![
+            geo::point!(x: 0., y: 0.),
+            geo::point!(x: 3., y: 4.),
+            geo::point!(x: 100., y: 100.),
+        ];
+        (points.as_slice(), Dimension::XY).into()
+    }
+
+    #[test]
+    fn dense_matrix() {
+        let array = points();
+        let matrix = distance_matrix(&array, &array).unwrap();
+        assert_eq!(matrix.len(), 3);
+
+        let row = matrix.value(0);
+        let row = row.as_primitive::<Float64Type>();
+        assert_eq!(row.value(0), 0.);
+        assert_eq!(row.value(1), 5.);
+    }
+
+    #[test]
+    fn sparse_pairs() {
+        let array = points();
+        let pairs = within_distance_pairs(&array, &array, 5.).unwrap();
+
+        let left = pairs.column(0).as_primitive::<UInt32Type>();
+        let right = pairs.column(1).as_primitive::<UInt32Type>();
+        let distances = pairs.column(2).as_primitive::<Float64Type>();
+        assert_eq!(left.values().to_vec(), vec![0, 0, 1, 1, 2]);
+        assert_eq!(right.values().to_vec(), vec![0, 1, 0, 1, 2]);
+        assert_eq!(distances.value(1), 5.);
+    }
+
+    #[test]
+    fn too_large_matrix() {
+        let points = vec![geo::point!(x: 0., y: 0.); 1 << 15];
+        let array: PointArray = (points.as_slice(), Dimension::XY).into();
+        assert!(distance_matrix(&array, &array).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! Kernels that respect the [`Edges`] of an array.
+//!
+//! The kernels of this module are planar: they interpret the edges between two coordinates as
+//! straight lines. For arrays whose metadata declares [`Edges::Spherical`], where edges follow
+//! great circles, the functions here use a spherical or ellipsoidal implementation where one
+//! exists, and otherwise return an error instead of a silently wrong planar result.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use geoarrow::algorithm::geo::edges;
+//! use geoarrow::array::metadata::{ArrayMetadata, Edges};
+//! use geoarrow::array::LineStringArray;
+//! use geoarrow::datatypes::Dimension;
+//! use geoarrow::NativeArray;
+//!
+//! let line_string = geo::line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+//! let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+//! let planar = edges::length(&array).unwrap();
+//! assert_eq!(planar.value(0), 1.);
+//!
+//! let metadata = ArrayMetadata::default().with_edges(Edges::Spherical);
+//! let spherical = array.with_metadata(Arc::new(metadata));
+//! // The geodesic length in meters of one degree along the equator
+//! let geodesic = edges::length(spherical.as_ref()).unwrap();
+//! assert!((geodesic.value(0) - 111_319.49).abs() < 0.01);
+//!
+//! assert!(edges::intersects(spherical.as_ref(), spherical.as_ref()).is_err());
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::{BooleanArray, Float64Array};
+
+use crate::algorithm::geo::{Area, Densify, EuclideanLength, GeodesicArea, GeodesicLength};
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::metadata::Edges;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::{ArrayBase, NativeArray};
+
+/// Whether the edges of an array follow great circles.
+pub fn is_spherical(array: &dyn NativeArray) -> bool {
+    matches!(array.metadata().edges, Some(Edges::Spherical))
+}
+
+/// Return an error if the edges of `array` are spherical, for an `operation` that is only
+/// implemented for planar edges.
+pub fn require_planar(array: &dyn NativeArray, operation: &str) -> Result<()> {
+    if is_spherical(array) {
+        return Err(GeoArrowError::NotYetImplemented(format!(
+            "{operation} is only implemented for planar edges, but the array has spherical edges. \
+            Project the geometries to a planar CRS, or remove the edges metadata to treat them as \
+            planar."
+        )));
+    }
+    Ok(())
+}
+
+/// The unsigned area of every geometry.
+///
+/// This is the planar area in the units of the coordinates for planar edges, and the geodesic
+/// area in square meters for spherical edges.
+pub fn area(array: &dyn NativeArray) -> Result<Float64Array> {
+    if is_spherical(array) {
+        array.geodesic_area_unsigned()
+    } else {
+        array.unsigned_area()
+    }
+}
+
+/// The length of every geometry.
+///
+/// This is the planar length in the units of the coordinates for planar edges, and the geodesic
+/// length in meters for spherical edges.
+pub fn length(array: &dyn NativeArray) -> Result<Float64Array> {
+    if is_spherical(array) {
+        array.geodesic_length()
+    } else {
+        array.euclidean_length()
+    }
+}
+
+/// Add vertices to the edges of every geometry so that no edge is longer than `max_distance`.
+///
+/// For planar edges, `max_distance` is in the units of the coordinates. For spherical edges, it
+/// is in meters, and the vertices are added along geodesics.
+pub fn densify(array: &dyn NativeArray, max_distance: f64) -> Result<Arc<dyn NativeArray>> {
+    if is_spherical(array) {
+        array.densify_geodesic(max_distance)
+    } else {
+        array.densify(max_distance)
+    }
+}
+
+/// Whether the geometries at the same index of two arrays intersect.
+///
+/// The output is null where either input is null. Only planar edges are supported.
+pub fn intersects(left: &dyn NativeArray, right: &dyn NativeArray) -> Result<BooleanArray> {
+    require_planar(left, "intersects")?;
+    require_planar(right, "intersects")?;
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    Ok(iter_geometries(left)
+        .zip(iter_geometries(right))
+        .map(|(left_geom, right_geom)| {
+            let (left_geom, right_geom) = (left_geom?, right_geom?);
+            Some(geo::Intersects::intersects(
+                &geometry_to_geo(&left_geom),
+                &geometry_to_geo(&right_geom),
+            ))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use crate::test::polygon::p_array;
+
+    #[test]
+    fn spherical_dispatch() {
+        let planar = p_array();
+        let spherical = planar.with_metadata(Arc::new(
+            ArrayMetadata::default().with_edges(Edges::Spherical),
+        ));
+
+        assert_eq!(area(&planar).unwrap(), planar.unsigned_area());
+        assert_eq!(
+            area(spherical.as_ref()).unwrap(),
+            spherical.as_ref().geodesic_area_unsigned().unwrap()
+        );
+
+        assert!(intersects(&planar, &planar).unwrap().value(0));
+        let err = intersects(&planar, spherical.as_ref()).unwrap_err();
+        assert!(err.to_string().contains("spherical edges"));
+    }
+}
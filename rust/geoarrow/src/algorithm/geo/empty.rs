@@ -0,0 +1,277 @@
+use geo::dimensions::HasDimensions as _;
+use geo::{Geometry, GeometryCollection, MultiLineString, MultiPoint, MultiPolygon, Polygon};
+
+use crate::array::{PointArray, PointBuilder};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Options for kernels whose result is undefined for empty geometries, such as
+/// [`Centroid`][super::Centroid], [`InteriorPoint`][super::InteriorPoint] and
+/// [`EuclideanDistance`][super::EuclideanDistance].
+///
+/// Empty points are stored with NaN coordinates and are considered empty here, even though
+/// [`geo`] treats them as regular points.
+///
+/// The default options pass geometries to the [`geo`] algorithms unchanged, so the centroid and
+/// interior point of empty geometries other than points are null, and the distance to an empty
+/// geometry is 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmptyGeometryOptions {
+    /// Ignore empty parts of multi-geometries and geometry collections, and empty interior rings
+    /// of polygons, computing the result from the remaining parts only.
+    ///
+    /// If `false`, geometries are passed to the kernel with their empty parts, whose NaN
+    /// coordinates may make the result NaN.
+    pub skip_empty: bool,
+
+    /// Return null for empty geometries.
+    ///
+    /// If `false`, empty geometries are passed to the kernel, which returns null points for most
+    /// of them, an empty point for an empty point, and a distance of 0.
+    pub empty_as_null: bool,
+}
+
+fn is_empty_point(point: &geo::Point) -> bool {
+    point.x().is_nan() || point.y().is_nan()
+}
+
+/// Whether a geometry has no coordinates, counting points with NaN coordinates as empty.
+pub(crate) fn is_empty(geom: &Geometry) -> bool {
+    match geom {
+        Geometry::Point(point) => is_empty_point(point),
+        Geometry::MultiPoint(multi_point) => multi_point.iter().all(is_empty_point),
+        Geometry::GeometryCollection(collection) => collection.iter().all(is_empty),
+        geom => geom.is_empty(),
+    }
+}
+
+fn remove_empty_rings(polygon: Polygon) -> Polygon {
+    let (exterior, interiors) = polygon.into_inner();
+    let interiors = interiors
+        .into_iter()
+        .filter(|ring| !ring.0.is_empty())
+        .collect();
+    Polygon::new(exterior, interiors)
+}
+
+/// Remove all empty parts of multi-geometries and collections, and empty interior rings of
+/// polygons.
+fn remove_empty_parts(geom: Geometry) -> Geometry {
+    match geom {
+        Geometry::Polygon(polygon) => Geometry::Polygon(remove_empty_rings(polygon)),
+        Geometry::MultiPoint(multi_point) => Geometry::MultiPoint(MultiPoint::new(
+            multi_point
+                .into_iter()
+                .filter(|point| !is_empty_point(point))
+                .collect(),
+        )),
+        Geometry::MultiLineString(multi_line_string) => {
+            Geometry::MultiLineString(MultiLineString::new(
+                multi_line_string
+                    .into_iter()
+                    .filter(|line| !line.0.is_empty())
+                    .collect(),
+            ))
+        }
+        Geometry::MultiPolygon(multi_polygon) => Geometry::MultiPolygon(MultiPolygon::new(
+            multi_polygon
+                .into_iter()
+                .filter(|polygon| !polygon.exterior().0.is_empty())
+                .map(remove_empty_rings)
+                .collect(),
+        )),
+        Geometry::GeometryCollection(collection) => {
+            Geometry::GeometryCollection(GeometryCollection::new_from(
+                collection
+                    .into_iter()
+                    .map(remove_empty_parts)
+                    .filter(|geom| !is_empty(geom))
+                    .collect(),
+            ))
+        }
+        geom => geom,
+    }
+}
+
+/// Apply the empty geometry options to an input geometry.
+///
+/// Returns `None` if the output of a kernel on this geometry should be null.
+pub(crate) fn prepare_geometry(
+    geom: impl Into<Geometry>,
+    options: &EmptyGeometryOptions,
+) -> Option<Geometry> {
+    let geom = geom.into();
+    let geom = if options.skip_empty {
+        remove_empty_parts(geom)
+    } else {
+        geom
+    };
+    if options.empty_as_null && is_empty(&geom) {
+        None
+    } else {
+        Some(geom)
+    }
+}
+
+/// The centroid or interior point of each point, which is the point itself.
+///
+/// The array is returned unchanged, keeping its dimension and coordinate type, unless empty
+/// points are replaced by nulls.
+pub(crate) fn points_with_options(
+    array: &PointArray,
+    options: &EmptyGeometryOptions,
+) -> PointArray {
+    if !options.empty_as_null {
+        return array.clone();
+    }
+
+    let mut output_array = PointBuilder::with_capacity_and_options(
+        array.dimension(),
+        array.len(),
+        array.coord_type(),
+        array.metadata(),
+    );
+    for index in 0..array.len() {
+        match array.get(index) {
+            Some(point) if !array.value_as_geo(index).x().is_nan() => {
+                output_array.push_point(Some(&point))
+            }
+            _ => output_array.push_null(),
+        }
+    }
+    output_array.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::{Centroid, EuclideanDistance, InteriorPoint};
+    use crate::array::{LineStringArray, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::test::empty::{empty_arrays, multi_point_with_empty_part};
+    use crate::test::point::point_z_array;
+    use crate::trait_::ArrayAccessor;
+    use arrow_array::Array;
+    use geo::{line_string, point, polygon};
+
+    const EMPTY_AS_NULL: EmptyGeometryOptions = EmptyGeometryOptions {
+        skip_empty: true,
+        empty_as_null: true,
+    };
+
+    #[test]
+    fn point_kernel_expectations() {
+        for (name, array) in empty_arrays() {
+            let array = array.as_ref();
+            let results = [
+                (
+                    "centroid",
+                    array.centroid_with_options(&EMPTY_AS_NULL).unwrap(),
+                ),
+                (
+                    "interior_point",
+                    array.interior_point_with_options(&EMPTY_AS_NULL).unwrap(),
+                ),
+            ];
+            for (kernel, result) in results {
+                assert!(result.is_null(0), "{kernel} of empty {name} should be null");
+            }
+
+            // By default, only the centroid of an empty point is an empty point
+            let results = [
+                ("centroid", array.centroid().unwrap()),
+                ("interior_point", array.interior_point().unwrap()),
+            ];
+            for (kernel, result) in results {
+                if name == "Point" {
+                    assert!(
+                        result.get_as_geo(0).unwrap().x().is_nan(),
+                        "{kernel} of empty {name} should be empty"
+                    );
+                } else {
+                    assert!(result.is_null(0), "{kernel} of empty {name} should be null");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distance_expectations() {
+        let points: PointArray = (vec![point!(x: 0., y: 0.)].as_slice(), Dimension::XY).into();
+        let empty: LineStringArray = (
+            vec![Some(geo::LineString::<f64>::new(vec![]))],
+            Dimension::XY,
+        )
+            .into();
+
+        assert_eq!(points.euclidean_distance(&empty).value(0), 0.);
+        let distance = points.euclidean_distance_with_options(&empty, &EMPTY_AS_NULL);
+        assert!(distance.is_null(0));
+    }
+
+    #[test]
+    fn skip_empty_parts() {
+        let array = multi_point_with_empty_part();
+        assert_eq!(
+            array.centroid_with_options(&EMPTY_AS_NULL).get_as_geo(0),
+            Some(point!(x: 1., y: 2.))
+        );
+        assert!(array.centroid().is_valid(0));
+        assert_eq!(array.len(), 1);
+    }
+
+    #[test]
+    fn prepare_skips_empty_parts() {
+        let multi_point =
+            MultiPoint::new(vec![point!(x: f64::NAN, y: f64::NAN), point!(x: 1., y: 2.)]);
+
+        let skipped = prepare_geometry(multi_point.clone(), &EMPTY_AS_NULL).unwrap();
+        assert_eq!(
+            skipped,
+            Geometry::MultiPoint(MultiPoint::new(vec![point!(x: 1., y: 2.)]))
+        );
+
+        let kept = prepare_geometry(multi_point.clone(), &Default::default()).unwrap();
+        assert_eq!(kept, Geometry::MultiPoint(multi_point));
+    }
+
+    #[test]
+    fn prepare_empty_geometries() {
+        let options = EmptyGeometryOptions::default();
+        assert!(prepare_geometry(point!(x: f64::NAN, y: f64::NAN), &options).is_none());
+        assert!(prepare_geometry(geo::LineString::<f64>::new(vec![]), &options).is_none());
+        assert!(prepare_geometry(MultiPolygon::<f64>::new(vec![]), &options).is_none());
+        assert!(prepare_geometry(GeometryCollection::new_from(vec![]), &options).is_none());
+        assert!(prepare_geometry(line_string![(x: 0., y: 0.)], &options).is_some());
+        assert!(prepare_geometry(
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            &options
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn indexed_intersects_empty_scalar() {
+        use crate::algorithm::geo::intersects::IntersectsLineString;
+        use crate::indexed::array::IndexedPolygonArray;
+        use crate::test::polygon::p_array;
+
+        let indexed = IndexedPolygonArray::new(p_array());
+        let result = indexed.intersects(&geo::LineString::<f64>::new(vec![]));
+        assert_eq!(result.len(), indexed.len());
+        assert_eq!(result.true_count(), 0);
+    }
+
+    #[test]
+    fn point_centroid_keeps_dimension() {
+        let array = point_z_array();
+        let centroid = array.centroid();
+        assert_eq!(centroid.dimension(), Dimension::XYZ);
+        assert_eq!(centroid.coord_type(), array.coord_type());
+        assert_eq!(centroid, array);
+
+        let interior_point = array.interior_point_with_options(&EMPTY_AS_NULL);
+        assert_eq!(interior_point.dimension(), Dimension::XYZ);
+        assert_eq!(interior_point.value_as_geo(0), array.value_as_geo(0));
+    }
+}
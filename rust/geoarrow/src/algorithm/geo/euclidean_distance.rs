@@ -1,3 +1,5 @@
+use crate::algorithm::geo::empty::prepare_geometry;
+use crate::algorithm::geo::EmptyGeometryOptions;
 use crate::array::*;
 use crate::scalar::*;
 use crate::trait_::ArrayAccessor;
@@ -15,7 +17,9 @@ pub trait EuclideanDistance<Rhs> {
     ///
     /// If a `Point` lies on a `LineString`, the distance is `0.0`
     ///
-    /// The distance between a `Point` and an empty `LineString` is `0.0`
+    /// The distance to an empty geometry is undefined, and is 0 by default. Use
+    /// [`euclidean_distance_with_options`][Self::euclidean_distance_with_options] to choose how
+    /// empty geometries are handled.
     ///
     /// # Examples
     ///
@@ -85,37 +89,39 @@ pub trait EuclideanDistance<Rhs> {
     /// assert_relative_eq!(distance, 1.1313708498984762);
     /// ```
     fn euclidean_distance(&self, rhs: &Rhs) -> Float64Array;
+
+    /// Returns the distance between two geometries, handling empty geometries and empty parts
+    /// according to `options`.
+    ///
+    /// The default implementation ignores `options` and calls
+    /// [`euclidean_distance`][Self::euclidean_distance].
+    fn euclidean_distance_with_options(
+        &self,
+        rhs: &Rhs,
+        options: &EmptyGeometryOptions,
+    ) -> Float64Array {
+        let _ = options;
+        self.euclidean_distance(rhs)
+    }
 }
 
 // ┌────────────────────────────────┐
 // │ Implementations for RHS arrays │
 // └────────────────────────────────┘
 
-// Note: this implementation is outside the macro because it is not generic over O
-impl EuclideanDistance<PointArray> for PointArray {
-    /// Minimum distance between two Points
-    fn euclidean_distance(&self, other: &PointArray) -> Float64Array {
-        assert_eq!(self.len(), other.len());
-        let mut output_array = Float64Builder::with_capacity(self.len());
-
-        self.iter_geo()
-            .zip(other.iter_geo())
-            .for_each(|(first, second)| match (first, second) {
-                (Some(first), Some(second)) => {
-                    output_array.append_value(Euclidean::distance(&first, &second))
-                }
-                _ => output_array.append_null(),
-            });
-
-        output_array.finish()
-    }
-}
-
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a> EuclideanDistance<$second> for $first {
             fn euclidean_distance(&self, other: &$second) -> Float64Array {
+                self.euclidean_distance_with_options(other, &Default::default())
+            }
+
+            fn euclidean_distance_with_options(
+                &self,
+                other: &$second,
+                options: &EmptyGeometryOptions,
+            ) -> Float64Array {
                 assert_eq!(self.len(), other.len());
                 let mut output_array = Float64Builder::with_capacity(self.len());
 
@@ -123,7 +129,10 @@ macro_rules! iter_geo_impl {
                     .zip(other.iter_geo())
                     .for_each(|(first, second)| match (first, second) {
                         (Some(first), Some(second)) => {
-                            output_array.append_value(Euclidean::distance(&first, &second))
+                            let distance = prepare_geometry(first, options)
+                                .zip(prepare_geometry(second, options))
+                                .map(|(first, second)| Euclidean::distance(&first, &second));
+                            output_array.append_option(distance)
                         }
                         _ => output_array.append_null(),
                     });
@@ -135,6 +144,7 @@ macro_rules! iter_geo_impl {
 }
 
 // Implementations on PointArray
+iter_geo_impl!(PointArray, PointArray);
 iter_geo_impl!(PointArray, LineStringArray);
 iter_geo_impl!(PointArray, PolygonArray);
 iter_geo_impl!(PointArray, MultiPointArray);
@@ -185,31 +195,28 @@ iter_geo_impl!(MultiPolygonArray, PointArray);
 // │ Implementations for RHS scalars │
 // └─────────────────────────────────┘
 
-// Note: this implementation is outside the macro because it is not generic over O
-impl<'a> EuclideanDistance<Point<'a>> for PointArray {
-    /// Minimum distance between two Points
-    fn euclidean_distance(&self, other: &Point<'a>) -> Float64Array {
-        let mut output_array = Float64Builder::with_capacity(self.len());
-
-        self.iter_geo().for_each(|maybe_point| {
-            let output = maybe_point.map(|point| Euclidean::distance(&point, &other.to_geo()));
-            output_array.append_option(output)
-        });
-
-        output_array.finish()
-    }
-}
-
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl_scalar {
     ($first:ty, $second:ty) => {
         impl<'a> EuclideanDistance<$second> for $first {
             fn euclidean_distance(&self, other: &$second) -> Float64Array {
+                self.euclidean_distance_with_options(other, &Default::default())
+            }
+
+            fn euclidean_distance_with_options(
+                &self,
+                other: &$second,
+                options: &EmptyGeometryOptions,
+            ) -> Float64Array {
                 let mut output_array = Float64Builder::with_capacity(self.len());
-                let other_geo = other.to_geo();
+                let other_geo = prepare_geometry(other.to_geo(), options);
 
                 self.iter_geo().for_each(|maybe_geom| {
-                    let output = maybe_geom.map(|geom| Euclidean::distance(&geom, &other_geo));
+                    let output = maybe_geom.and_then(|geom| {
+                        prepare_geometry(geom, options)
+                            .zip(other_geo.as_ref())
+                            .map(|(geom, other_geo)| Euclidean::distance(&geom, other_geo))
+                    });
                     output_array.append_option(output)
                 });
 
@@ -220,6 +227,7 @@ macro_rules! iter_geo_impl_scalar {
 }
 
 // Implementations on PointArray
+iter_geo_impl_scalar!(PointArray, Point<'a>);
 iter_geo_impl_scalar!(PointArray, LineString<'a>);
 iter_geo_impl_scalar!(PointArray, Polygon<'a>);
 iter_geo_impl_scalar!(PointArray, MultiPoint<'a>);
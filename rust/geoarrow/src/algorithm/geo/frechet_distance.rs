@@ -1,7 +1,7 @@
+use crate::algorithm::geo::utils::{as_chunked_line_string_array, as_line_string_array};
 use crate::algorithm::native::{Binary, MapChunks, Unary};
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedLineStringArray, ChunkedNativeArray};
-use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
 use crate::trait_::NativeScalar;
 use crate::NativeArray;
@@ -16,6 +16,9 @@ use geo_traits::LineStringTrait;
 
 /// Determine the similarity between two arrays of `LineStrings` using the [Frechet distance].
 ///
+/// Dynamically-typed arrays may be line string arrays, or geometry arrays whose values are all
+/// line strings.
+///
 /// Based on [Computing Discrete Frechet Distance] by T. Eiter and H. Mannila.
 ///
 /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
@@ -51,16 +54,10 @@ impl FrechetDistance for &dyn NativeArray {
     type Output = Result<Float64Array>;
 
     fn frechet_distance(&self, rhs: &Self) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        let result = match (self.data_type(), rhs.data_type()) {
-            (LineString(_, XY), LineString(_, XY)) => {
-                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_line_string())
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(FrechetDistance::frechet_distance(
+            &as_line_string_array(*self)?,
+            &as_line_string_array(*rhs)?,
+        ))
     }
 }
 
@@ -68,16 +65,10 @@ impl FrechetDistance for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn frechet_distance(&self, rhs: &Self) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        let result = match (self.data_type(), rhs.data_type()) {
-            (LineString(_, XY), LineString(_, XY)) => {
-                FrechetDistance::frechet_distance(self.as_line_string(), rhs.as_line_string())
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(FrechetDistance::frechet_distance(
+            &as_chunked_line_string_array(*self)?,
+            &as_chunked_line_string_array(*rhs)?,
+        ))
     }
 }
 
@@ -115,16 +106,10 @@ impl<G: LineStringTrait<T = f64>> FrechetDistanceLineString<G> for &dyn NativeAr
     type Output = Result<Float64Array>;
 
     fn frechet_distance(&self, rhs: &G) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        let result = match self.data_type() {
-            LineString(_, XY) => {
-                FrechetDistanceLineString::frechet_distance(self.as_line_string(), rhs)
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(FrechetDistanceLineString::frechet_distance(
+            &as_line_string_array(*self)?,
+            rhs,
+        ))
     }
 }
 
@@ -132,16 +117,10 @@ impl<G: LineStringTrait<T = f64>> FrechetDistanceLineString<G> for &dyn ChunkedN
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn frechet_distance(&self, rhs: &G) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
         let rhs = rhs.to_line_string();
-        let result = match self.data_type() {
-            LineString(_, XY) => {
-                FrechetDistanceLineString::frechet_distance(self.as_line_string(), &rhs)
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(FrechetDistanceLineString::frechet_distance(
+            &as_chunked_line_string_array(*self)?,
+            &rhs,
+        ))
     }
 }
@@ -1,8 +1,8 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedNativeArray};
-use crate::datatypes::{Dimension, NativeType};
-use crate::error::{GeoArrowError, Result};
+use crate::datatypes::NativeType;
+use crate::error::Result;
 use crate::trait_::ArrayAccessor;
 use crate::NativeArray;
 use arrow_array::builder::Float64Builder;
@@ -298,98 +298,100 @@ iter_geo_impl!(PolygonArray);
 iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
+iter_geo_impl!(RectArray);
+iter_geo_impl!(GeometryArray);
 
 impl GeodesicArea for &dyn NativeArray {
     type OutputSingle = Result<Float64Array>;
     type OutputDouble = Result<(Float64Array, Float64Array)>;
 
     fn geodesic_area_signed(&self) -> Self::OutputSingle {
-        use Dimension::*;
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_area_signed(),
-            LineString(_, XY) => self.as_line_string().geodesic_area_signed(),
-            Polygon(_, XY) => self.as_polygon().geodesic_area_signed(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_area_signed(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_area_signed(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_area_signed(),
-            GeometryCollection(_, XY) => self.as_geometry_collection().geodesic_area_signed(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            Point(_, _) => self.as_point().geodesic_area_signed(),
+            LineString(_, _) => self.as_line_string().geodesic_area_signed(),
+            Polygon(_, _) => self.as_polygon().geodesic_area_signed(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_area_signed(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_area_signed(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_area_signed(),
+            GeometryCollection(_, _) => self.as_geometry_collection().geodesic_area_signed(),
+            Rect(_) => self.as_rect().geodesic_area_signed(),
+            Geometry(_) => self.as_geometry().geodesic_area_signed(),
         };
         Ok(result)
     }
 
     fn geodesic_area_unsigned(&self) -> Self::OutputSingle {
-        use Dimension::*;
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_area_unsigned(),
-            LineString(_, XY) => self.as_line_string().geodesic_area_unsigned(),
-            Polygon(_, XY) => self.as_polygon().geodesic_area_unsigned(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_area_unsigned(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_area_unsigned(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_area_unsigned(),
-            GeometryCollection(_, XY) => self.as_geometry_collection().geodesic_area_unsigned(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            Point(_, _) => self.as_point().geodesic_area_unsigned(),
+            LineString(_, _) => self.as_line_string().geodesic_area_unsigned(),
+            Polygon(_, _) => self.as_polygon().geodesic_area_unsigned(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_area_unsigned(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_area_unsigned(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_area_unsigned(),
+            GeometryCollection(_, _) => self.as_geometry_collection().geodesic_area_unsigned(),
+            Rect(_) => self.as_rect().geodesic_area_unsigned(),
+            Geometry(_) => self.as_geometry().geodesic_area_unsigned(),
         };
         Ok(result)
     }
 
     fn geodesic_perimeter(&self) -> Self::OutputSingle {
-        use Dimension::*;
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_perimeter(),
-            LineString(_, XY) => self.as_line_string().geodesic_perimeter(),
-            Polygon(_, XY) => self.as_polygon().geodesic_perimeter(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_perimeter(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_perimeter(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_perimeter(),
-            GeometryCollection(_, XY) => self.as_geometry_collection().geodesic_perimeter(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            Point(_, _) => self.as_point().geodesic_perimeter(),
+            LineString(_, _) => self.as_line_string().geodesic_perimeter(),
+            Polygon(_, _) => self.as_polygon().geodesic_perimeter(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_perimeter(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_perimeter(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_perimeter(),
+            GeometryCollection(_, _) => self.as_geometry_collection().geodesic_perimeter(),
+            Rect(_) => self.as_rect().geodesic_perimeter(),
+            Geometry(_) => self.as_geometry().geodesic_perimeter(),
         };
         Ok(result)
     }
 
     fn geodesic_perimeter_area_signed(&self) -> Self::OutputDouble {
-        use Dimension::*;
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_perimeter_area_signed(),
-            LineString(_, XY) => self.as_line_string().geodesic_perimeter_area_signed(),
-            Polygon(_, XY) => self.as_polygon().geodesic_perimeter_area_signed(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_perimeter_area_signed(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_perimeter_area_signed(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_perimeter_area_signed(),
-            GeometryCollection(_, XY) => self
+            Point(_, _) => self.as_point().geodesic_perimeter_area_signed(),
+            LineString(_, _) => self.as_line_string().geodesic_perimeter_area_signed(),
+            Polygon(_, _) => self.as_polygon().geodesic_perimeter_area_signed(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_perimeter_area_signed(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_perimeter_area_signed(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_perimeter_area_signed(),
+            GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .geodesic_perimeter_area_signed(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            Rect(_) => self.as_rect().geodesic_perimeter_area_signed(),
+            Geometry(_) => self.as_geometry().geodesic_perimeter_area_signed(),
         };
         Ok(result)
     }
 
     fn geodesic_perimeter_area_unsigned(&self) -> Self::OutputDouble {
-        use Dimension::*;
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_perimeter_area_unsigned(),
-            LineString(_, XY) => self.as_line_string().geodesic_perimeter_area_unsigned(),
-            Polygon(_, XY) => self.as_polygon().geodesic_perimeter_area_unsigned(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_perimeter_area_unsigned(),
-            MultiLineString(_, XY) => self
+            Point(_, _) => self.as_point().geodesic_perimeter_area_unsigned(),
+            LineString(_, _) => self.as_line_string().geodesic_perimeter_area_unsigned(),
+            Polygon(_, _) => self.as_polygon().geodesic_perimeter_area_unsigned(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_perimeter_area_unsigned(),
+            MultiLineString(_, _) => self
                 .as_multi_line_string()
                 .geodesic_perimeter_area_unsigned(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_perimeter_area_unsigned(),
-            GeometryCollection(_, XY) => self
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_perimeter_area_unsigned(),
+            GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .geodesic_perimeter_area_unsigned(),
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
+            Rect(_) => self.as_rect().geodesic_perimeter_area_unsigned(),
+            Geometry(_) => self.as_geometry().geodesic_perimeter_area_unsigned(),
         };
         Ok(result)
     }
@@ -436,88 +438,123 @@ impl GeodesicArea for &dyn ChunkedNativeArray {
     type OutputDouble = Result<(ChunkedArray<Float64Array>, ChunkedArray<Float64Array>)>;
 
     fn geodesic_area_signed(&self) -> Self::OutputSingle {
-        use Dimension::*;
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_area_signed(),
-            LineString(_, XY) => self.as_line_string().geodesic_area_signed(),
-            Polygon(_, XY) => self.as_polygon().geodesic_area_signed(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_area_signed(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_area_signed(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_area_signed(),
-            GeometryCollection(_, XY) => self.as_geometry_collection().geodesic_area_signed(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            Point(_, _) => self.as_point().geodesic_area_signed(),
+            LineString(_, _) => self.as_line_string().geodesic_area_signed(),
+            Polygon(_, _) => self.as_polygon().geodesic_area_signed(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_area_signed(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_area_signed(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_area_signed(),
+            GeometryCollection(_, _) => self.as_geometry_collection().geodesic_area_signed(),
+            Rect(_) => self.as_rect().geodesic_area_signed(),
+            Geometry(_) => self.as_geometry().geodesic_area_signed(),
         }
     }
 
     fn geodesic_area_unsigned(&self) -> Self::OutputSingle {
-        use Dimension::*;
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_area_unsigned(),
-            LineString(_, XY) => self.as_line_string().geodesic_area_unsigned(),
-            Polygon(_, XY) => self.as_polygon().geodesic_area_unsigned(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_area_unsigned(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_area_unsigned(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_area_unsigned(),
-            GeometryCollection(_, XY) => self.as_geometry_collection().geodesic_area_unsigned(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            Point(_, _) => self.as_point().geodesic_area_unsigned(),
+            LineString(_, _) => self.as_line_string().geodesic_area_unsigned(),
+            Polygon(_, _) => self.as_polygon().geodesic_area_unsigned(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_area_unsigned(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_area_unsigned(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_area_unsigned(),
+            GeometryCollection(_, _) => self.as_geometry_collection().geodesic_area_unsigned(),
+            Rect(_) => self.as_rect().geodesic_area_unsigned(),
+            Geometry(_) => self.as_geometry().geodesic_area_unsigned(),
         }
     }
 
     fn geodesic_perimeter(&self) -> Self::OutputSingle {
-        use Dimension::*;
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_perimeter(),
-            LineString(_, XY) => self.as_line_string().geodesic_perimeter(),
-            Polygon(_, XY) => self.as_polygon().geodesic_perimeter(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_perimeter(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_perimeter(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_perimeter(),
-            GeometryCollection(_, XY) => self.as_geometry_collection().geodesic_perimeter(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            Point(_, _) => self.as_point().geodesic_perimeter(),
+            LineString(_, _) => self.as_line_string().geodesic_perimeter(),
+            Polygon(_, _) => self.as_polygon().geodesic_perimeter(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_perimeter(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_perimeter(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_perimeter(),
+            GeometryCollection(_, _) => self.as_geometry_collection().geodesic_perimeter(),
+            Rect(_) => self.as_rect().geodesic_perimeter(),
+            Geometry(_) => self.as_geometry().geodesic_perimeter(),
         }
     }
 
     fn geodesic_perimeter_area_signed(&self) -> Self::OutputDouble {
-        use Dimension::*;
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_perimeter_area_signed(),
-            LineString(_, XY) => self.as_line_string().geodesic_perimeter_area_signed(),
-            Polygon(_, XY) => self.as_polygon().geodesic_perimeter_area_signed(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_perimeter_area_signed(),
-            MultiLineString(_, XY) => self.as_multi_line_string().geodesic_perimeter_area_signed(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_perimeter_area_signed(),
-            GeometryCollection(_, XY) => self
+            Point(_, _) => self.as_point().geodesic_perimeter_area_signed(),
+            LineString(_, _) => self.as_line_string().geodesic_perimeter_area_signed(),
+            Polygon(_, _) => self.as_polygon().geodesic_perimeter_area_signed(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_perimeter_area_signed(),
+            MultiLineString(_, _) => self.as_multi_line_string().geodesic_perimeter_area_signed(),
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_perimeter_area_signed(),
+            GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .geodesic_perimeter_area_signed(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            Rect(_) => self.as_rect().geodesic_perimeter_area_signed(),
+            Geometry(_) => self.as_geometry().geodesic_perimeter_area_signed(),
         }
     }
 
     fn geodesic_perimeter_area_unsigned(&self) -> Self::OutputDouble {
-        use Dimension::*;
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, XY) => self.as_point().geodesic_perimeter_area_unsigned(),
-            LineString(_, XY) => self.as_line_string().geodesic_perimeter_area_unsigned(),
-            Polygon(_, XY) => self.as_polygon().geodesic_perimeter_area_unsigned(),
-            MultiPoint(_, XY) => self.as_multi_point().geodesic_perimeter_area_unsigned(),
-            MultiLineString(_, XY) => self
+            Point(_, _) => self.as_point().geodesic_perimeter_area_unsigned(),
+            LineString(_, _) => self.as_line_string().geodesic_perimeter_area_unsigned(),
+            Polygon(_, _) => self.as_polygon().geodesic_perimeter_area_unsigned(),
+            MultiPoint(_, _) => self.as_multi_point().geodesic_perimeter_area_unsigned(),
+            MultiLineString(_, _) => self
                 .as_multi_line_string()
                 .geodesic_perimeter_area_unsigned(),
-            MultiPolygon(_, XY) => self.as_multi_polygon().geodesic_perimeter_area_unsigned(),
-            GeometryCollection(_, XY) => self
+            MultiPolygon(_, _) => self.as_multi_polygon().geodesic_perimeter_area_unsigned(),
+            GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .geodesic_perimeter_area_unsigned(),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
+            Rect(_) => self.as_rect().geodesic_perimeter_area_unsigned(),
+            Geometry(_) => self.as_geometry().geodesic_perimeter_area_unsigned(),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use approx::assert_relative_eq;
+    use arrow_array::Array;
+    use geo::{polygon, Rect};
+
+    #[test]
+    fn geometry_and_rect_arrays() {
+        let rect = Rect::new((0., 0.), (1., 1.));
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let polygon_array: PolygonArray = (vec![polygon.clone()].as_slice(), Dimension::XY).into();
+        let expected = polygon_array.geodesic_perimeter_area_unsigned();
+
+        let rect_array: RectArray = (vec![rect].as_slice(), Dimension::XY).into();
+        let geometries = vec![Some(geo::Geometry::Polygon(polygon)), None];
+        let geometry_array: GeometryArray = GeometryBuilder::from_nullable_geometries(
+            &geometries,
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+
+        for array in [&rect_array as &dyn NativeArray, &geometry_array] {
+            let (perimeter, area) = array.geodesic_perimeter_area_unsigned().unwrap();
+            assert_relative_eq!(perimeter.value(0), expected.0.value(0));
+            assert_relative_eq!(area.value(0), expected.1.value(0));
+        }
+        assert!(geometry_array.geodesic_area_unsigned().is_null(1));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::algorithm::native::kernel::{binary, binary_chunked, unary, unary_chunked};
+use crate::chunked_array::{ChunkedArray, ChunkedNativeArray};
+use crate::error::Result;
+use crate::io::geo::geometry_to_geo;
+use crate::scalar::Geometry;
+use crate::NativeArray;
+use arrow_array::builder::Float64Builder;
+use arrow_array::Float64Array;
+use geo::dimensions::HasDimensions;
+use geo::HausdorffDistance as _HausdorffDistance;
+use geo_traits::GeometryTrait;
+
+// ┌────────────────────────────────┐
+// │ Implementations for RHS arrays │
+// └────────────────────────────────┘
+
+/// Determine the similarity between the geometries at the same index of two arrays using the
+/// [Hausdorff distance].
+///
+/// The Hausdorff distance is the greatest distance from any vertex of one geometry to the nearest
+/// vertex of the other. Unlike the [Fréchet distance][super::FrechetDistance], it ignores the
+/// order of the vertices, and is defined between geometries of any type.
+///
+/// The output is null where either geometry is null or empty. Returns an error if the arrays have
+/// different lengths, or the chunked arrays have different chunk lengths.
+///
+/// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::HausdorffDistance;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::NativeArray;
+///
+/// let points: PointArray = (vec![geo::point!(x: 0., y: 0.)].as_slice(), Dimension::XY).into();
+/// let line_string = geo::line_string![(x: 0., y: 0.), (x: 3., y: 4.)];
+/// let line_strings: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+///
+/// let distance = HausdorffDistance::hausdorff_distance(
+///     &points.as_ref(),
+///     &line_strings.as_ref(),
+/// )
+/// .unwrap();
+/// assert_eq!(distance.value(0), 5.);
+/// ```
+pub trait HausdorffDistance<Rhs = Self> {
+    type Output;
+
+    fn hausdorff_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl HausdorffDistance for &dyn NativeArray {
+    type Output = Result<Float64Array>;
+
+    fn hausdorff_distance(&self, rhs: &Self) -> Self::Output {
+        binary::<Float64Builder, _>(*self, *rhs, |left, right| {
+            Ok(distance(&geometry_to_geo(left), &geometry_to_geo(right)))
+        })
+    }
+}
+
+impl HausdorffDistance for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn hausdorff_distance(&self, rhs: &Self) -> Self::Output {
+        binary_chunked::<Float64Builder, _>(*self, *rhs, |left, right| {
+            Ok(distance(&geometry_to_geo(left), &geometry_to_geo(right)))
+        })
+    }
+}
+
+// ┌─────────────────────────────────┐
+// │ Implementations for RHS scalars │
+// └─────────────────────────────────┘
+
+pub trait HausdorffDistanceScalar<Rhs> {
+    type Output;
+
+    fn hausdorff_distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<G: GeometryTrait<T = f64>> HausdorffDistanceScalar<G> for &dyn NativeArray {
+    type Output = Result<Float64Array>;
+
+    fn hausdorff_distance(&self, rhs: &G) -> Self::Output {
+        let rhs = geometry_to_geo(rhs);
+        unary::<Float64Builder, _>(*self, |geom: &Geometry<'_>| {
+            Ok(distance(&geometry_to_geo(geom), &rhs))
+        })
+    }
+}
+
+impl<G: GeometryTrait<T = f64>> HausdorffDistanceScalar<G> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedArray<Float64Array>>;
+
+    fn hausdorff_distance(&self, rhs: &G) -> Self::Output {
+        let rhs = geometry_to_geo(rhs);
+        unary_chunked::<Float64Builder, _>(*self, |geom: &Geometry<'_>| {
+            Ok(distance(&geometry_to_geo(geom), &rhs))
+        })
+    }
+}
+
+/// The Hausdorff distance, or `None` if either geometry is empty.
+fn distance(left: &geo::Geometry, right: &geo::Geometry) -> Option<f64> {
+    if left.is_empty() || right.is_empty() {
+        return None;
+    }
+    Some(left.hausdorff_distance(right))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PolygonArray};
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::datatypes::Dimension;
+    use crate::test::point;
+    use crate::ArrayBase;
+    use arrow_array::Array;
+    use geo::{line_string, polygon};
+
+    #[test]
+    fn hausdorff_pairwise() {
+        let line_strings: LineStringArray = (
+            vec![
+                Some(line_string![(x: 0., y: 0.), (x: 2., y: 0.)]),
+                Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+                None,
+            ],
+            Dimension::XY,
+        )
+            .into();
+        let polygons: PolygonArray = (
+            vec![
+                Some(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 0., y: 1.)]),
+                Some(geo::Polygon::new(geo::LineString::new(vec![]), vec![])),
+                Some(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]),
+            ],
+            Dimension::XY,
+        )
+            .into();
+
+        let output =
+            HausdorffDistance::hausdorff_distance(&line_strings.as_ref(), &polygons.as_ref())
+                .unwrap();
+        assert_eq!(output.value(0), 1.);
+        assert!(output.is_null(1));
+        assert!(output.is_null(2));
+
+        let points = point::point_array();
+        assert!(
+            HausdorffDistance::hausdorff_distance(&points.as_ref(), &polygons.as_ref()).is_ok()
+        );
+        let short: LineStringArray =
+            (vec![line_string![(x: 0., y: 0.)]].as_slice(), Dimension::XY).into();
+        assert!(HausdorffDistance::hausdorff_distance(&points.as_ref(), &short.as_ref()).is_err());
+    }
+
+    #[test]
+    fn hausdorff_scalar() {
+        let points = point::point_array();
+        let origin = geo::point!(x: 0., y: 0.);
+        let output =
+            HausdorffDistanceScalar::hausdorff_distance(&points.as_ref(), &origin).unwrap();
+        assert_eq!(output.len(), points.len());
+        assert_eq!(output.value(0), 1.);
+
+        let chunked = ChunkedGeometryArray::new(vec![points.clone(), points]);
+        let output =
+            HausdorffDistanceScalar::hausdorff_distance(&chunked.as_ref(), &origin).unwrap();
+        assert_eq!(output.len(), 6);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::algorithm::geo::empty::{points_with_options, prepare_geometry};
+use crate::algorithm::geo::EmptyGeometryOptions;
 use crate::array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::Result;
@@ -23,10 +25,24 @@ use geo::algorithm::interior_point::InteriorPoint as _;
 /// described above; for all others, the interior point closest to the collection's centroid is
 /// used).
 ///
+/// The interior point of an empty geometry is undefined, and is null by default. The interior
+/// point of an empty point is the empty point itself. Use
+/// [`interior_point_with_options`][Self::interior_point_with_options] to choose how empty
+/// geometries are handled.
 pub trait InteriorPoint {
     type Output;
 
     fn interior_point(&self) -> Self::Output;
+
+    /// Calculate the interior point, handling empty geometries and empty parts according to
+    /// `options`.
+    ///
+    /// The default implementation ignores `options` and calls
+    /// [`interior_point`][Self::interior_point].
+    fn interior_point_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
+        let _ = options;
+        self.interior_point()
+    }
 }
 
 impl InteriorPoint for PointArray {
@@ -35,22 +51,9 @@ impl InteriorPoint for PointArray {
     fn interior_point(&self) -> Self::Output {
         self.clone()
     }
-}
-
-impl InteriorPoint for RectArray {
-    type Output = PointArray;
 
-    fn interior_point(&self) -> Self::Output {
-        let mut output_array = PointBuilder::with_capacity_and_options(
-            Dimension::XY,
-            self.len(),
-            self.coord_type(),
-            self.metadata().clone(),
-        );
-        self.iter_geo().for_each(|maybe_g| {
-            output_array.push_point(maybe_g.map(|g| g.interior_point()).as_ref())
-        });
-        output_array.into()
+    fn interior_point_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
+        points_with_options(self, options)
     }
 }
 
@@ -61,14 +64,23 @@ macro_rules! iter_geo_impl {
             type Output = PointArray;
 
             fn interior_point(&self) -> Self::Output {
+                self.interior_point_with_options(&Default::default())
+            }
+
+            fn interior_point_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
                 let mut output_array = PointBuilder::with_capacity_and_options(
                     Dimension::XY,
                     self.len(),
                     self.coord_type(),
                     self.metadata().clone(),
                 );
-                self.iter_geo().for_each(|maybe_g| {
-                    output_array.push_point(maybe_g.and_then(|g| g.interior_point()).as_ref())
+                self.iter_geo().for_each(|maybe_g| match maybe_g {
+                    Some(g) => output_array.push_point(
+                        prepare_geometry(g, options)
+                            .and_then(|g| g.interior_point())
+                            .as_ref(),
+                    ),
+                    None => output_array.push_null(),
                 });
                 output_array.into()
             }
@@ -84,23 +96,32 @@ iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(RectArray);
 
 impl InteriorPoint for &dyn NativeArray {
     type Output = Result<PointArray>;
 
     fn interior_point(&self) -> Self::Output {
+        self.interior_point_with_options(&Default::default())
+    }
+
+    fn interior_point_with_options(&self, options: &EmptyGeometryOptions) -> Self::Output {
         use NativeType::*;
 
         let result = match self.data_type() {
-            Point(_, _) => self.as_point().interior_point(),
-            LineString(_, _) => self.as_line_string().interior_point(),
-            Polygon(_, _) => self.as_polygon().interior_point(),
-            MultiPoint(_, _) => self.as_multi_point().interior_point(),
-            MultiLineString(_, _) => self.as_multi_line_string().interior_point(),
-            MultiPolygon(_, _) => self.as_multi_polygon().interior_point(),
-            GeometryCollection(_, _) => self.as_geometry_collection().interior_point(),
-            Rect(_) => self.as_rect().interior_point(),
-            Geometry(_) => self.as_geometry().interior_point(),
+            Point(_, _) => self.as_point().interior_point_with_options(options),
+            LineString(_, _) => self.as_line_string().interior_point_with_options(options),
+            Polygon(_, _) => self.as_polygon().interior_point_with_options(options),
+            MultiPoint(_, _) => self.as_multi_point().interior_point_with_options(options),
+            MultiLineString(_, _) => self
+                .as_multi_line_string()
+                .interior_point_with_options(options),
+            MultiPolygon(_, _) => self.as_multi_polygon().interior_point_with_options(options),
+            GeometryCollection(_, _) => self
+                .as_geometry_collection()
+                .interior_point_with_options(options),
+            Rect(_) => self.as_rect().interior_point_with_options(options),
+            Geometry(_) => self.as_geometry().interior_point_with_options(options),
         };
         Ok(result)
     }
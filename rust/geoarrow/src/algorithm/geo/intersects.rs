@@ -224,9 +224,7 @@ impl<G: LineStringTrait<T = f64>> IntersectsLineString<G> for IndexedPointArray
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = rhs.to_line_string();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -237,7 +235,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = rhs.to_line_string();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -298,9 +296,7 @@ impl<G: PolygonTrait<T = f64>> IntersectsPolygon<G> for IndexedPointArray {
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = rhs.to_polygon();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -311,7 +307,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = rhs.to_polygon();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -372,9 +368,7 @@ impl<G: MultiPointTrait<T = f64>> IntersectsMultiPoint<G> for IndexedPointArray
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = rhs.to_multi_point();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -385,7 +379,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = rhs.to_multi_point();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -446,9 +440,7 @@ impl<G: MultiLineStringTrait<T = f64>> IntersectsMultiLineString<G> for IndexedP
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = rhs.to_multi_line_string();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -459,7 +451,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = rhs.to_multi_line_string();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -520,9 +512,7 @@ impl<G: MultiPolygonTrait<T = f64>> IntersectsMultiPolygon<G> for IndexedPointAr
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = rhs.to_multi_polygon();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -533,7 +523,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = rhs.to_multi_polygon();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -594,9 +584,7 @@ impl<G: GeometryTrait<T = f64>> IntersectsGeometry<G> for IndexedPointArray {
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = geometry_to_geo(rhs);
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -607,7 +595,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = geometry_to_geo(rhs);
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -668,9 +656,7 @@ impl<G: GeometryCollectionTrait<T = f64>> IntersectsGeometryCollection<G> for In
 
     fn intersects(&self, rhs: &G) -> Self::Output {
         let rhs = geometry_collection_to_geo(rhs);
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
-            geom.to_geo().intersects(&rhs)
-        })
+        self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
     }
 }
 
@@ -681,7 +667,7 @@ macro_rules! impl_intersects {
 
             fn intersects(&self, rhs: &G) -> Self::Output {
                 let rhs = geometry_collection_to_geo(rhs);
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                self.unary_boolean_or_empty(rhs.bounding_rect(), |geom| {
                     geom.to_geo().intersects(&rhs)
                 })
             }
@@ -0,0 +1,141 @@
+use arrow_array::Float64Array;
+use geo::{Euclidean, Geodesic, Haversine, Length, VincentyLength};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::metadata::Edges;
+use crate::error::Result;
+use crate::io::geo::geometry_to_geo;
+use crate::{ArrayBase, NativeArray};
+
+/// The measure of [`length`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LengthMethod {
+    /// [`Geodesic`][Self::Geodesic] for arrays in a geographic CRS or with spherical edges, and
+    /// [`Euclidean`][Self::Euclidean] otherwise.
+    #[default]
+    Auto,
+
+    /// The planar length, in the units of the coordinates.
+    Euclidean,
+
+    /// The length on a sphere with the mean radius of the earth, in meters.
+    Haversine,
+
+    /// The length on the WGS 84 ellipsoid by the method of Karney (2013), in meters.
+    Geodesic,
+
+    /// The length on the WGS 84 ellipsoid by the method of Vincenty, in meters. It's an error if
+    /// it fails to converge, such as for nearly antipodal points.
+    Vincenty,
+}
+
+impl LengthMethod {
+    /// Resolve [`Auto`][Self::Auto] for an array.
+    fn resolve(self, array: &dyn NativeArray) -> Self {
+        if self != Self::Auto {
+            return self;
+        }
+        let metadata = array.metadata();
+        if metadata.is_geographic() || metadata.edges == Some(Edges::Spherical) {
+            Self::Geodesic
+        } else {
+            Self::Euclidean
+        }
+    }
+}
+
+/// The length of the linear parts of a geometry.
+fn geometry_length(geometry: &geo::Geometry, method: LengthMethod) -> Result<f64> {
+    macro_rules! measure {
+        ($geom:expr) => {
+            match method {
+                LengthMethod::Auto | LengthMethod::Euclidean => $geom.length::<Euclidean>(),
+                LengthMethod::Haversine => $geom.length::<Haversine>(),
+                LengthMethod::Geodesic => $geom.length::<Geodesic>(),
+                LengthMethod::Vincenty => $geom.vincenty_length()?,
+            }
+        };
+    }
+
+    let length = match geometry {
+        geo::Geometry::Line(line) => measure!(line),
+        geo::Geometry::LineString(line_string) => measure!(line_string),
+        geo::Geometry::MultiLineString(multi_line_string) => measure!(multi_line_string),
+        geo::Geometry::GeometryCollection(collection) => {
+            let mut length = 0.;
+            for geometry in collection {
+                length += geometry_length(geometry, method)?;
+            }
+            length
+        }
+        // Points and areas have no length, as in PostGIS' `ST_Length`
+        _ => 0.,
+    };
+    Ok(length)
+}
+
+/// The length of every geometry of an array.
+///
+/// The length of points, polygons and their multi geometries is 0, and that of a geometry
+/// collection is the sum of the lengths of its geometries. With [`LengthMethod::Auto`], arrays
+/// whose CRS is geographic, per [`ArrayMetadata::is_geographic`], or whose edges are spherical are
+/// measured as geodesics in meters, and other arrays as planar lengths in the units of their
+/// coordinates. The output is null where the input is null.
+///
+/// [`ArrayMetadata::is_geographic`]: crate::array::metadata::ArrayMetadata::is_geographic
+pub fn length(array: &dyn NativeArray, method: LengthMethod) -> Result<Float64Array> {
+    let method = method.resolve(array);
+    iter_geometries(array)
+        .map(|geometry| {
+            geometry
+                .map(|geometry| geometry_length(&geometry_to_geo(&geometry), method))
+                .transpose()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use geo::line_string;
+
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use crate::array::LineStringArray;
+    use crate::datatypes::Dimension;
+    use crate::test::{point, polygon};
+
+    #[test]
+    fn methods() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let array: LineStringArray = (vec![Some(line_string), None], Dimension::XY).into();
+
+        let euclidean = length(&array, LengthMethod::Euclidean).unwrap();
+        assert_eq!(euclidean, Float64Array::from(vec![Some(1.), None]));
+        assert_eq!(length(&array, LengthMethod::Auto).unwrap(), euclidean);
+
+        // One degree along the equator
+        let geodesic = length(&array, LengthMethod::Geodesic).unwrap();
+        assert!((geodesic.value(0) - 111_319.49).abs() < 0.01);
+        let haversine = length(&array, LengthMethod::Haversine).unwrap();
+        assert!((haversine.value(0) - 111_195.08).abs() < 0.01);
+        let vincenty = length(&array, LengthMethod::Vincenty).unwrap();
+        assert!((vincenty.value(0) - geodesic.value(0)).abs() < 0.01);
+
+        let wgs84 = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let geographic = array.with_metadata(Arc::new(wgs84));
+        assert_eq!(
+            length(geographic.as_ref(), LengthMethod::Auto).unwrap(),
+            geodesic
+        );
+    }
+
+    #[test]
+    fn non_linear() {
+        let points = length(&point::point_array(), LengthMethod::Auto).unwrap();
+        assert_eq!(points.values().iter().sum::<f64>(), 0.);
+        let polygons = length(&polygon::p_array(), LengthMethod::Auto).unwrap();
+        assert_eq!(polygons.values().iter().sum::<f64>(), 0.);
+    }
+}
@@ -1,9 +1,10 @@
+use crate::algorithm::geo::utils::{as_chunked_line_string_array, as_line_string_array};
 use crate::algorithm::native::MapChunks;
 use crate::array::LineStringArray;
 use crate::array::*;
 use crate::chunked_array::{ChunkedLineStringArray, ChunkedNativeArray, ChunkedPointArray};
-use crate::datatypes::{Dimension, NativeType};
-use crate::error::{GeoArrowError, Result};
+use crate::datatypes::Dimension;
+use crate::error::Result;
 use crate::trait_::ArrayAccessor;
 use crate::NativeArray;
 use arrow_array::Float64Array;
@@ -19,6 +20,9 @@ use geo::LineInterpolatePoint as _LineInterpolatePoint;
 ///  If either the fraction is NaN, or any coordinates of the line are not
 ///  finite, returns `None`.
 ///
+/// Dynamically-typed arrays may be line string arrays, or geometry arrays whose values are all
+/// line strings.
+///
 /// # Examples
 ///
 /// ```
@@ -70,13 +74,7 @@ impl LineInterpolatePoint<&Float64Array> for &dyn NativeArray {
     type Output = Result<PointArray>;
 
     fn line_interpolate_point(&self, fraction: &Float64Array) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        match self.data_type() {
-            LineString(_, XY) => Ok(self.as_line_string().line_interpolate_point(fraction)),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
-        }
+        Ok(as_line_string_array(*self)?.line_interpolate_point(fraction))
     }
 }
 
@@ -94,13 +92,7 @@ impl LineInterpolatePoint<&[Float64Array]> for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedPointArray>;
 
     fn line_interpolate_point(&self, fraction: &[Float64Array]) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        match self.data_type() {
-            LineString(_, XY) => Ok(self.as_line_string().line_interpolate_point(fraction)),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
-        }
+        Ok(as_chunked_line_string_array(*self)?.line_interpolate_point(fraction))
     }
 }
 
@@ -130,13 +122,7 @@ impl LineInterpolatePoint<f64> for &dyn NativeArray {
     type Output = Result<PointArray>;
 
     fn line_interpolate_point(&self, fraction: f64) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        match self.data_type() {
-            LineString(_, XY) => Ok(self.as_line_string().line_interpolate_point(fraction)),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
-        }
+        Ok(as_line_string_array(*self)?.line_interpolate_point(fraction))
     }
 }
 
@@ -152,12 +138,57 @@ impl LineInterpolatePoint<f64> for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedPointArray>;
 
     fn line_interpolate_point(&self, fraction: f64) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
+        Ok(as_chunked_line_string_array(*self)?.line_interpolate_point(fraction))
+    }
+}
 
-        match self.data_type() {
-            LineString(_, XY) => Ok(self.as_line_string().line_interpolate_point(fraction)),
-            _ => Err(GeoArrowError::IncorrectType("".into())),
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::linestring::{ls0, ls1};
+    use crate::test::polygon::p_array;
+    use crate::ArrayBase;
+
+    #[test]
+    fn interpolate_scalar() {
+        let array: LineStringArray = (vec![Some(ls0()), None, Some(ls1())], Dimension::XY).into();
+        let out = array.line_interpolate_point(0.5);
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.value_as_geo(0), geo::point!(x: 0.5, y: 1.5));
+        assert!(out.is_null(1));
+        assert_eq!(out.value_as_geo(2), geo::point!(x: 4., y: 5.));
+    }
+
+    #[test]
+    fn interpolate_array() {
+        let array: LineStringArray = (vec![ls0(), ls1(), ls1()].as_slice(), Dimension::XY).into();
+        let fractions = Float64Array::from(vec![Some(0.), Some(2.), None]);
+        let out = array.line_interpolate_point(&fractions);
+        assert_eq!(out.value_as_geo(0), geo::point!(x: 0., y: 1.));
+        assert_eq!(out.value_as_geo(1), geo::point!(x: 5., y: 6.));
+        assert!(out.is_null(2));
+    }
+
+    #[test]
+    fn interpolate_geometry_array() {
+        let geoms = vec![Some(geo::Geometry::LineString(ls0())), None];
+        let array = GeometryBuilder::from_nullable_geometries(
+            &geoms,
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+        let out = (&array as &dyn NativeArray)
+            .line_interpolate_point(0.5)
+            .unwrap();
+        assert_eq!(out.value_as_geo(0), geo::point!(x: 0.5, y: 1.5));
+        assert!(out.is_null(1));
+
+        let polygons = p_array();
+        assert!((&polygons as &dyn NativeArray)
+            .line_interpolate_point(0.5)
+            .is_err());
     }
 }
@@ -1,8 +1,10 @@
+use crate::algorithm::geo::utils::{
+    as_chunked_line_string_array, as_chunked_point_array, as_line_string_array, as_point_array,
+};
 use crate::algorithm::native::MapChunks;
-use crate::array::{AsChunkedNativeArray, AsNativeArray, LineStringArray, PointArray};
+use crate::array::{LineStringArray, PointArray};
 use crate::chunked_array::{ChunkedArray, ChunkedLineStringArray, ChunkedNativeArray};
-use crate::datatypes::{Dimension, NativeType};
-use crate::error::{GeoArrowError, Result};
+use crate::error::Result;
 use crate::trait_::ArrayAccessor;
 use crate::{ArrayBase, NativeArray};
 use arrow_array::builder::Float64Builder;
@@ -19,6 +21,9 @@ use geo_traits::PointTrait;
 ///
 /// If either the point's coordinates or any coordinates of the line
 /// are not finite, returns `None`.
+///
+/// Dynamically-typed arrays may also be geometry arrays whose values are all line strings or all
+/// points respectively.
 pub trait LineLocatePoint<Rhs> {
     type Output;
 
@@ -52,16 +57,10 @@ impl LineLocatePoint<&dyn NativeArray> for &dyn NativeArray {
     type Output = Result<Float64Array>;
 
     fn line_locate_point(&self, rhs: &dyn NativeArray) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        let result = match (self.data_type(), rhs.data_type()) {
-            (LineString(_, XY), Point(_, XY)) => {
-                LineLocatePoint::line_locate_point(self.as_line_string(), rhs.as_point())
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(LineLocatePoint::line_locate_point(
+            &as_line_string_array(*self)?,
+            &as_point_array(rhs)?,
+        ))
     }
 }
 
@@ -80,16 +79,10 @@ impl LineLocatePoint<&dyn ChunkedNativeArray> for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn line_locate_point(&self, rhs: &dyn ChunkedNativeArray) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        let result = match (self.data_type(), rhs.data_type()) {
-            (LineString(_, XY), Point(_, XY)) => {
-                LineLocatePoint::line_locate_point(self.as_line_string(), &rhs.as_point().chunks)
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(LineLocatePoint::line_locate_point(
+            &as_chunked_line_string_array(*self)?,
+            as_chunked_point_array(rhs)?.chunks.as_slice(),
+        ))
     }
 }
 
@@ -114,6 +107,8 @@ impl<G: PointTrait<T = f64>> LineLocatePointScalar<G> for LineStringArray {
                 } else {
                     output_array.append_value(f64::NAN)
                 }
+            } else {
+                output_array.append_null()
             }
         });
 
@@ -125,16 +120,10 @@ impl<G: PointTrait<T = f64>> LineLocatePointScalar<G> for &dyn NativeArray {
     type Output = Result<Float64Array>;
 
     fn line_locate_point(&self, rhs: G) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
-
-        let result = match self.data_type() {
-            LineString(_, XY) => {
-                LineLocatePointScalar::line_locate_point(self.as_line_string(), rhs)
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+        Ok(LineLocatePointScalar::line_locate_point(
+            &as_line_string_array(*self)?,
+            rhs,
+        ))
     }
 }
 
@@ -152,15 +141,63 @@ impl<G: PointTrait<T = f64>> LineLocatePointScalar<G> for &dyn ChunkedNativeArra
     type Output = Result<ChunkedArray<Float64Array>>;
 
     fn line_locate_point(&self, rhs: G) -> Self::Output {
-        use Dimension::*;
-        use NativeType::*;
+        Ok(LineLocatePointScalar::line_locate_point(
+            &as_chunked_line_string_array(*self)?,
+            rhs,
+        ))
+    }
+}
 
-        let result = match self.data_type() {
-            LineString(_, XY) => {
-                LineLocatePointScalar::line_locate_point(self.as_line_string(), rhs)
-            }
-            _ => return Err(GeoArrowError::IncorrectType("".into())),
-        };
-        Ok(result)
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::GeometryBuilder;
+    use crate::datatypes::Dimension;
+    use crate::test::linestring::{ls0, ls1};
+
+    #[test]
+    fn locate_scalar() {
+        let array: LineStringArray = (vec![Some(ls0()), None, Some(ls1())], Dimension::XY).into();
+        let out = LineLocatePointScalar::line_locate_point(&array, geo::point!(x: 0.5, y: 1.5));
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            vec![Some(0.5), None, Some(0.)]
+        );
+    }
+
+    #[test]
+    fn locate_array() {
+        let array: LineStringArray = (vec![ls0(), ls1()].as_slice(), Dimension::XY).into();
+        let points: PointArray =
+            (vec![Some(geo::point!(x: 1., y: 2.)), None], Dimension::XY).into();
+        let out = LineLocatePoint::line_locate_point(&array, &points);
+        assert_eq!(out.iter().collect::<Vec<_>>(), vec![Some(1.), None]);
+    }
+
+    #[test]
+    fn locate_geometry_array() {
+        let geoms = vec![Some(geo::Geometry::LineString(ls0()))];
+        let array = GeometryBuilder::from_nullable_geometries(
+            &geoms,
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+        let points: PointArray = (vec![geo::point!(x: 0., y: 1.)].as_slice(), Dimension::XY).into();
+        let out = LineLocatePoint::line_locate_point(
+            &(&array as &dyn NativeArray),
+            &points as &dyn NativeArray,
+        )
+        .unwrap();
+        assert_eq!(out.value(0), 0.);
+
+        // Points aren't line strings
+        assert!(LineLocatePoint::line_locate_point(
+            &(&points as &dyn NativeArray),
+            &points as &dyn NativeArray,
+        )
+        .is_err());
     }
 }
@@ -0,0 +1,318 @@
+use crate::algorithm::geo::utils::{as_chunked_line_string_array, as_line_string_array};
+use crate::algorithm::native::MapChunks;
+use crate::array::{LineStringArray, LineStringBuilder};
+use crate::chunked_array::{ChunkedLineStringArray, ChunkedNativeArray};
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+use arrow_array::Float64Array;
+use geo::{Euclidean, Length};
+
+/// Returns the part of each line string between two fractions of its length.
+///
+/// The fractions are clamped to the range `[0, 1]`. The end points of the substring are
+/// interpolated along the segments they fall on, and the vertices of the line string between them
+/// are kept. If the start fraction is greater than the end fraction, the substring is reversed.
+///
+/// If either fraction is NaN, or any coordinates of the line are not finite, the output is an
+/// empty line string. The output is null where the line string or either fraction is null.
+///
+/// Only two-dimensional line strings are supported. This returns an error for arrays with Z or M
+/// values, as they would be dropped from the output.
+///
+/// Dynamically-typed arrays may be line string arrays, or geometry arrays whose values are all
+/// line strings.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::LineSubstring;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let line_string = geo::line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+/// let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+///
+/// let substring = array.line_substring(0.25, 0.75).unwrap();
+/// assert_eq!(
+///     substring.value_as_geo(0),
+///     geo::line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.)]
+/// );
+/// ```
+pub trait LineSubstring<Rhs> {
+    type Output;
+
+    fn line_substring(&self, start_fraction: Rhs, end_fraction: Rhs) -> Self::Output;
+}
+
+/// The substrings are computed on two-dimensional geo line strings, so Z and M values would be
+/// lost.
+fn check_dimension(array: &LineStringArray) -> Result<()> {
+    if array.dimension() != Dimension::XY {
+        return Err(GeoArrowError::IncorrectType(
+            "line_substring only supports two-dimensional line strings".into(),
+        ));
+    }
+    Ok(())
+}
+
+impl LineSubstring<f64> for LineStringArray {
+    type Output = Result<LineStringArray>;
+
+    fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> Self::Output {
+        check_dimension(self)?;
+        let output_geoms: Vec<Option<geo::LineString>> = self
+            .iter_geo()
+            .map(|maybe_line_string| {
+                maybe_line_string.map(|line_string| {
+                    substring(&line_string, start_fraction, end_fraction).unwrap_or_default()
+                })
+            })
+            .collect();
+
+        Ok(LineStringBuilder::from_nullable_line_strings(
+            output_geoms.as_slice(),
+            Dimension::XY,
+            self.coord_type(),
+            self.metadata(),
+        )
+        .finish())
+    }
+}
+
+impl LineSubstring<&Float64Array> for LineStringArray {
+    type Output = Result<LineStringArray>;
+
+    fn line_substring(
+        &self,
+        start_fraction: &Float64Array,
+        end_fraction: &Float64Array,
+    ) -> Self::Output {
+        check_dimension(self)?;
+        let output_geoms: Vec<Option<geo::LineString>> = self
+            .iter_geo()
+            .zip(start_fraction)
+            .zip(end_fraction)
+            .map(|((maybe_line_string, start), end)| {
+                let (line_string, start, end) = (maybe_line_string?, start?, end?);
+                Some(substring(&line_string, start, end).unwrap_or_default())
+            })
+            .collect();
+
+        Ok(LineStringBuilder::from_nullable_line_strings(
+            output_geoms.as_slice(),
+            Dimension::XY,
+            self.coord_type(),
+            self.metadata(),
+        )
+        .finish())
+    }
+}
+
+impl LineSubstring<f64> for &dyn NativeArray {
+    type Output = Result<LineStringArray>;
+
+    fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> Self::Output {
+        as_line_string_array(*self)?.line_substring(start_fraction, end_fraction)
+    }
+}
+
+impl LineSubstring<&Float64Array> for &dyn NativeArray {
+    type Output = Result<LineStringArray>;
+
+    fn line_substring(
+        &self,
+        start_fraction: &Float64Array,
+        end_fraction: &Float64Array,
+    ) -> Self::Output {
+        as_line_string_array(*self)?.line_substring(start_fraction, end_fraction)
+    }
+}
+
+impl LineSubstring<f64> for ChunkedLineStringArray {
+    type Output = Result<ChunkedLineStringArray>;
+
+    fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> Self::Output {
+        Ok(ChunkedLineStringArray::new(self.try_map(|chunk| {
+            chunk.line_substring(start_fraction, end_fraction)
+        })?))
+    }
+}
+
+impl LineSubstring<&[Float64Array]> for ChunkedLineStringArray {
+    type Output = Result<ChunkedLineStringArray>;
+
+    fn line_substring(
+        &self,
+        start_fraction: &[Float64Array],
+        end_fraction: &[Float64Array],
+    ) -> Self::Output {
+        let fractions = start_fraction.iter().zip(end_fraction).collect::<Vec<_>>();
+        Ok(ChunkedLineStringArray::new(self.try_binary_map(
+            fractions.as_slice(),
+            |(chunk, (start, end))| chunk.line_substring(*start, *end),
+        )?))
+    }
+}
+
+impl LineSubstring<f64> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedLineStringArray>;
+
+    fn line_substring(&self, start_fraction: f64, end_fraction: f64) -> Self::Output {
+        as_chunked_line_string_array(*self)?.line_substring(start_fraction, end_fraction)
+    }
+}
+
+impl LineSubstring<&[Float64Array]> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedLineStringArray>;
+
+    fn line_substring(
+        &self,
+        start_fraction: &[Float64Array],
+        end_fraction: &[Float64Array],
+    ) -> Self::Output {
+        as_chunked_line_string_array(*self)?.line_substring(start_fraction, end_fraction)
+    }
+}
+
+/// The part of a line string between two fractions of its length, or `None` if the line string
+/// is empty, either fraction is NaN or the length isn't finite.
+fn substring(line_string: &geo::LineString, start: f64, end: f64) -> Option<geo::LineString> {
+    if start.is_nan() || end.is_nan() {
+        return None;
+    }
+    if start > end {
+        let mut reversed = substring(line_string, end, start)?;
+        reversed.0.reverse();
+        return Some(reversed);
+    }
+    let last = *line_string.0.last()?;
+    let length = line_string.length::<Euclidean>();
+    if !length.is_finite() {
+        return None;
+    }
+    let start = start.clamp(0., 1.) * length;
+    let end = end.clamp(0., 1.) * length;
+
+    let mut coords = vec![];
+    let mut cumulative = 0.;
+    for line in line_string.lines() {
+        let segment_length = line.dx().hypot(line.dy());
+        let offset = cumulative;
+        let along = move |distance: f64| {
+            if segment_length > 0. {
+                line.start + line.delta() * ((distance - offset) / segment_length)
+            } else {
+                line.start
+            }
+        };
+        cumulative += segment_length;
+
+        if coords.is_empty() {
+            if start > cumulative {
+                continue;
+            }
+            coords.push(along(start));
+        }
+        if end <= cumulative {
+            coords.push(along(end));
+            return Some(geo::LineString::new(coords));
+        }
+        // The start may fall on the end of this segment, which is then already pushed
+        if coords.last() != Some(&line.end) {
+            coords.push(line.end);
+        }
+    }
+
+    // Rounding can leave the fractions just beyond the summed segment lengths
+    if coords.is_empty() {
+        coords.push(last);
+    }
+    if coords.len() < 2 {
+        coords.push(last);
+    }
+    Some(geo::LineString::new(coords))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::ForceDimension;
+    use crate::test::linestring;
+    use geo::line_string;
+
+    fn array() -> LineStringArray {
+        let line_strings = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)]),
+            None,
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)]),
+        ];
+        (line_strings, Dimension::XY).into()
+    }
+
+    #[test]
+    fn substring_scalar() {
+        let out = array().line_substring(0.5, 1.).unwrap();
+        assert_eq!(out.len(), 3);
+        assert_eq!(
+            out.value_as_geo(0),
+            line_string![(x: 2., y: 0.), (x: 2., y: 2.)]
+        );
+        assert!(out.is_null(1));
+        assert_eq!(
+            out.value_as_geo(2),
+            line_string![(x: 2., y: 0.), (x: 4., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn substring_array() {
+        let start = Float64Array::from(vec![Some(0.75), Some(0.), None]);
+        let end = Float64Array::from(vec![Some(0.25), Some(1.), Some(1.)]);
+        let out = array().line_substring(&start, &end).unwrap();
+        // Reversed, as the start is after the end
+        assert_eq!(
+            out.value_as_geo(0),
+            line_string![(x: 2., y: 1.), (x: 2., y: 0.), (x: 1., y: 0.)]
+        );
+        assert!(out.is_null(1));
+        assert!(out.is_null(2));
+    }
+
+    #[test]
+    fn substring_edge_cases() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(substring(&line_string, -1., 2.), Some(line_string.clone()));
+        assert_eq!(
+            substring(&line_string, 1., 1.),
+            Some(line_string![(x: 4., y: 0.), (x: 4., y: 0.)])
+        );
+        assert_eq!(substring(&line_string, f64::NAN, 1.), None);
+        assert_eq!(substring(&geo::LineString::new(vec![]), 0., 1.), None);
+    }
+
+    #[test]
+    fn substring_from_vertex() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        assert_eq!(
+            substring(&line_string, 0.5, 0.75),
+            Some(line_string![(x: 2., y: 0.), (x: 2., y: 1.)])
+        );
+        assert_eq!(
+            substring(&line_string, 0.5, 0.5),
+            Some(line_string![(x: 2., y: 0.), (x: 2., y: 0.)])
+        );
+        assert_eq!(
+            substring(&line_string, 0.75, 0.5),
+            Some(line_string![(x: 2., y: 1.), (x: 2., y: 0.)])
+        );
+    }
+
+    #[test]
+    fn reject_z() {
+        let array = linestring::ls_array().force_3d(1.);
+        assert!(array.line_substring(0., 1.).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Apply a closure to each geometry of an array, collecting the results into a
+/// [`GeometryArray`].
+///
+/// Each non-null geometry is converted to a [`geo::Geometry`] and passed to the closure. Null
+/// inputs are not passed to the closure and stay null in the output. The closure may also return
+/// `Ok(None)` to produce a null output. An error returned by the closure is propagated.
+///
+/// The output keeps the coordinate type and metadata of the input, and each output geometry keeps
+/// the type returned by the closure. Chunked arrays are mapped chunk by chunk, in parallel when the
+/// `rayon` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, BoundingRect, Geometry};
+/// use geoarrow::algorithm::geo::MapGeometries;
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let points = vec![point!(x: 1., y: 2.), point!(x: -3., y: 4.)];
+/// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+///
+/// // Keep only points with a positive x coordinate, as their bounding rectangle
+/// let output = array
+///     .map_geometries(|geom| {
+///         let rect = geom.bounding_rect().unwrap();
+///         Ok((rect.min().x > 0.).then(|| Geometry::Rect(rect)))
+///     })
+///     .unwrap();
+/// assert!(output.get(0).is_some());
+/// assert!(output.get(1).is_none());
+/// ```
+pub trait MapGeometries {
+    type Output;
+
+    fn map_geometries<F>(&self, op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<Option<geo::Geometry>> + Sync + Send;
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl MapGeometries for $type {
+            type Output = Result<GeometryArray>;
+
+            fn map_geometries<F>(&self, op: F) -> Self::Output
+            where
+                F: Fn(geo::Geometry) -> Result<Option<geo::Geometry>> + Sync + Send,
+            {
+                let output_geoms = self
+                    .iter_geo()
+                    .map(|maybe_g| match maybe_g {
+                        Some(geom) => op(geom.into()),
+                        None => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Don't prefer multi geometries so that each output keeps the type returned by
+                // the closure.
+                Ok(GeometryBuilder::from_nullable_geometries(
+                    output_geoms.as_slice(),
+                    self.coord_type(),
+                    self.metadata(),
+                    false,
+                )?
+                .finish())
+            }
+        }
+    };
+}
+
+iter_geo_impl!(PointArray);
+iter_geo_impl!(LineStringArray);
+iter_geo_impl!(PolygonArray);
+iter_geo_impl!(MultiPointArray);
+iter_geo_impl!(MultiLineStringArray);
+iter_geo_impl!(MultiPolygonArray);
+iter_geo_impl!(MixedGeometryArray);
+iter_geo_impl!(GeometryCollectionArray);
+iter_geo_impl!(RectArray);
+iter_geo_impl!(GeometryArray);
+
+impl MapGeometries for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn map_geometries<F>(&self, op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<Option<geo::Geometry>> + Sync + Send,
+    {
+        use NativeType::*;
+
+        match self.data_type() {
+            Point(_, _) => self.as_point().map_geometries(op),
+            LineString(_, _) => self.as_line_string().map_geometries(op),
+            Polygon(_, _) => self.as_polygon().map_geometries(op),
+            MultiPoint(_, _) => self.as_multi_point().map_geometries(op),
+            MultiLineString(_, _) => self.as_multi_line_string().map_geometries(op),
+            MultiPolygon(_, _) => self.as_multi_polygon().map_geometries(op),
+            GeometryCollection(_, _) => self.as_geometry_collection().map_geometries(op),
+            Rect(_) => self.as_rect().map_geometries(op),
+            Geometry(_) => self.as_geometry().map_geometries(op),
+        }
+    }
+}
+
+impl<G: NativeArray> MapGeometries for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn map_geometries<F>(&self, op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<Option<geo::Geometry>> + Sync + Send,
+    {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.as_ref().map_geometries(&op))?,
+        ))
+    }
+}
+
+impl MapGeometries for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn map_geometries<F>(&self, op: F) -> Self::Output
+    where
+        F: Fn(geo::Geometry) -> Result<Option<geo::Geometry>> + Sync + Send,
+    {
+        use NativeType::*;
+
+        match self.data_type() {
+            Point(_, _) => self.as_point().map_geometries(op),
+            LineString(_, _) => self.as_line_string().map_geometries(op),
+            Polygon(_, _) => self.as_polygon().map_geometries(op),
+            MultiPoint(_, _) => self.as_multi_point().map_geometries(op),
+            MultiLineString(_, _) => self.as_multi_line_string().map_geometries(op),
+            MultiPolygon(_, _) => self.as_multi_polygon().map_geometries(op),
+            GeometryCollection(_, _) => self.as_geometry_collection().map_geometries(op),
+            Rect(_) => self.as_rect().map_geometries(op),
+            Geometry(_) => self.as_geometry().map_geometries(op),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::GeoArrowError;
+    use crate::test::{linestring, point};
+    use crate::ArrayBase;
+    use geo::{Centroid, Geometry};
+
+    #[test]
+    fn map_centroid() {
+        let arr = linestring::ls_array();
+        let output = arr
+            .map_geometries(|geom| Ok(geom.centroid().map(Geometry::Point)))
+            .unwrap();
+
+        assert_eq!(output.len(), arr.len());
+        let expected = Geometry::Point(linestring::ls0().centroid().unwrap());
+        assert_eq!(output.value_as_geo(0), expected);
+    }
+
+    #[test]
+    fn map_to_null() {
+        let arr = point::point_array();
+        let output = (&arr as &dyn NativeArray)
+            .map_geometries(|_| Ok(None))
+            .unwrap();
+
+        assert_eq!(output.len(), arr.len());
+        assert!(output.iter().all(|geom| geom.is_none()));
+    }
+
+    #[test]
+    fn map_error() {
+        let arr = point::point_array();
+        let result = arr.map_geometries(|_| Err(GeoArrowError::General("fail".to_string())));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn map_chunked() {
+        let arr = point::point_array();
+        let chunked = ChunkedGeometryArray::new(vec![arr.clone(), arr]);
+        let output = chunked.map_geometries(|geom| Ok(Some(geom))).unwrap();
+        assert_eq!(output.chunks().len(), 2);
+        assert_eq!(output.len(), chunked.len());
+    }
+}
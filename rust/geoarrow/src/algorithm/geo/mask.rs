@@ -0,0 +1,326 @@
+//! Boolean masks of the geometries of an array that are within an area or distance, which can be
+//! passed directly to Arrow's [`filter`][arrow::compute::filter].
+
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::BooleanArray;
+use arrow_buffer::BooleanBufferBuilder;
+use geo::{Distance, Euclidean, Within};
+use geo_traits::{CoordTrait, GeometryTrait, RectTrait};
+
+use crate::algorithm::geo_index::SpatialIndex;
+use crate::algorithm::native::bounding_rect::bounding_rect_geometry;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::scalar::Geometry;
+use crate::NativeArray;
+
+/// A bounding box as `[min_x, min_y, max_x, max_y]`.
+pub(super) type Bbox = [f64; 4];
+
+pub(super) fn to_bbox((min, max): ([f64; 2], [f64; 2])) -> Bbox {
+    [min[0], min[1], max[0], max[1]]
+}
+
+pub(super) fn is_empty(bbox: &Bbox) -> bool {
+    bbox[0] > bbox[2] || bbox[1] > bbox[3]
+}
+
+/// Whether `inner` is inside `outer`, boundary included.
+pub(super) fn bbox_contains(outer: &Bbox, inner: &Bbox) -> bool {
+    !is_empty(inner)
+        && inner[0] >= outer[0]
+        && inner[1] >= outer[1]
+        && inner[2] <= outer[2]
+        && inner[3] <= outer[3]
+}
+
+pub(super) fn bbox_intersects(left: &Bbox, right: &Bbox) -> bool {
+    left[0] <= right[2] && left[2] >= right[0] && left[1] <= right[3] && left[3] >= right[1]
+}
+
+/// Compute a mask by testing the geometries whose bounding box intersects `search_bbox` with
+/// `predicate`, which gets the geometry and its bounding box. All other geometries are `false`,
+/// and null geometries are null.
+///
+/// With an index, only the geometries it returns for `search_bbox` are visited.
+fn mask<F>(
+    array: &dyn NativeArray,
+    search_bbox: Bbox,
+    index: Option<&SpatialIndex>,
+    predicate: F,
+) -> Result<BooleanArray>
+where
+    F: Fn(&Geometry<'_>, &Bbox) -> bool,
+{
+    let candidates = match index {
+        Some(index) => {
+            if index.num_rows() != array.len() as u64 {
+                return Err(GeoArrowError::General(format!(
+                    "Spatial index has {} rows but the array has {}",
+                    index.num_rows(),
+                    array.len()
+                )));
+            }
+            let mut candidates = BooleanBufferBuilder::new(array.len());
+            candidates.append_n(array.len(), false);
+            let [min_x, min_y, max_x, max_y] = search_bbox;
+            for row in index.search(min_x, min_y, max_x, max_y) {
+                candidates.set_bit(row as usize, true);
+            }
+            Some(candidates.finish())
+        }
+        None => None,
+    };
+
+    let mut output = BooleanBuilder::with_capacity(array.len());
+    for (row, geom) in iter_geometries(array).enumerate() {
+        let Some(geom) = geom else {
+            output.append_null();
+            continue;
+        };
+        if candidates
+            .as_ref()
+            .is_some_and(|candidates| !candidates.value(row))
+        {
+            output.append_value(false);
+            continue;
+        }
+        let bbox = to_bbox(bounding_rect_geometry(&geom));
+        output.append_value(bbox_intersects(&bbox, &search_bbox) && predicate(&geom, &bbox));
+    }
+    Ok(output.finish())
+}
+
+/// Whether each geometry is inside a rectangle, boundary included.
+///
+/// This only compares bounding boxes, so it's exact and never converts geometries. Empty
+/// geometries are `false` and null geometries are null.
+///
+/// If `index` is given, it must index the rows of `array`, and only the geometries it returns are
+/// tested.
+pub fn within_bbox(
+    array: &dyn NativeArray,
+    rect: &impl RectTrait<T = f64>,
+    index: Option<&SpatialIndex>,
+) -> Result<BooleanArray> {
+    let rect = [
+        rect.min().x(),
+        rect.min().y(),
+        rect.max().x(),
+        rect.max().y(),
+    ];
+    mask(array, rect, index, |_, bbox| bbox_contains(&rect, bbox))
+}
+
+/// Whether each geometry is within a geometry, as defined by [`Within`].
+///
+/// Geometries whose bounding box isn't inside the bounding box of `geometry` are `false` without
+/// running the predicate. Null geometries are null.
+///
+/// If `index` is given, it must index the rows of `array`, and only the geometries it returns are
+/// tested.
+pub fn within_geometry(
+    array: &dyn NativeArray,
+    geometry: &impl GeometryTrait<T = f64>,
+    index: Option<&SpatialIndex>,
+) -> Result<BooleanArray> {
+    let search_bbox = to_bbox(bounding_rect_geometry(geometry));
+    let geometry = geometry_to_geo(geometry);
+    mask(array, search_bbox, index, |geom, bbox| {
+        bbox_contains(&search_bbox, bbox) && geometry_to_geo(geom).is_within(&geometry)
+    })
+}
+
+/// Whether the Euclidean distance of each geometry to a geometry is at most `distance`.
+///
+/// Geometries whose bounding box is further than `distance` from the bounding box of `geometry`
+/// are `false` without computing the distance. Null geometries are null.
+///
+/// If `index` is given, it must index the rows of `array`, and only the geometries it returns are
+/// tested.
+pub fn within_distance(
+    array: &dyn NativeArray,
+    geometry: &impl GeometryTrait<T = f64>,
+    distance: f64,
+    index: Option<&SpatialIndex>,
+) -> Result<BooleanArray> {
+    let [min_x, min_y, max_x, max_y] = to_bbox(bounding_rect_geometry(geometry));
+    let search_bbox = [
+        min_x - distance,
+        min_y - distance,
+        max_x + distance,
+        max_y + distance,
+    ];
+    let geometry = geometry_to_geo(geometry);
+    mask(array, search_bbox, index, |geom, _| {
+        Euclidean::distance(&geometry_to_geo(geom), &geometry) <= distance
+    })
+}
+
+/// The smallest and largest Euclidean distances between points of two bounding boxes.
+fn bbox_distance_range(left: &Bbox, right: &Bbox) -> (f64, f64) {
+    let dx_min = (right[0] - left[2]).max(left[0] - right[2]).max(0.);
+    let dy_min = (right[1] - left[3]).max(left[1] - right[3]).max(0.);
+    let dx_max = (right[2] - left[0]).max(left[2] - right[0]);
+    let dy_max = (right[3] - left[1]).max(left[3] - right[1]);
+    (dx_min.hypot(dy_min), dx_max.hypot(dy_max))
+}
+
+/// Whether the Euclidean distance between the geometries at the same index of two arrays is at
+/// most `distance`.
+///
+/// This is much cheaper than comparing the output of
+/// [`EuclideanDistance`][crate::algorithm::geo::EuclideanDistance] for filtering: pairs whose
+/// bounding boxes are further apart than `distance` are `false`, and pairs whose bounding boxes
+/// are entirely within `distance` of each other are `true`, without computing the distance.
+///
+/// The output is null where either geometry is null or empty, as their distance is undefined.
+pub fn euclidean_dwithin(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    distance: f64,
+) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let mut output = BooleanBuilder::with_capacity(left.len());
+    for (left_geom, right_geom) in iter_geometries(left).zip(iter_geometries(right)) {
+        let (Some(left_geom), Some(right_geom)) = (left_geom, right_geom) else {
+            output.append_null();
+            continue;
+        };
+        let left_bbox = to_bbox(bounding_rect_geometry(&left_geom));
+        let right_bbox = to_bbox(bounding_rect_geometry(&right_geom));
+        if is_empty(&left_bbox) || is_empty(&right_bbox) {
+            output.append_null();
+            continue;
+        }
+        let (min_distance, max_distance) = bbox_distance_range(&left_bbox, &right_bbox);
+        let within = if min_distance > distance {
+            false
+        } else if max_distance <= distance {
+            true
+        } else {
+            Euclidean::distance(&geometry_to_geo(&left_geom), &geometry_to_geo(&right_geom))
+                <= distance
+        };
+        output.append_value(within);
+    }
+    Ok(output.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::compute::filter;
+
+    use super::*;
+    use crate::algorithm::geo_index::SpatialIndexBuilder;
+    use crate::array::{LineStringArray, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::ArrayBase;
+
+    fn points() -> PointArray {
+        let points = vec![
+            Some(geo::point!(x: 0., y: 0.)),
+            Some(geo::point!(x: 1., y: 1.)),
+            None,
+            Some(geo::point!(x: 5., y: 5.)),
+            Some(geo::point!(x: 2., y: 0.5)),
+        ];
+        (points, Dimension::XY).into()
+    }
+
+    fn index(array: &dyn NativeArray) -> SpatialIndex {
+        let mut builder = SpatialIndexBuilder::new();
+        builder.push_array(array).unwrap();
+        builder.finish().unwrap()
+    }
+
+    fn index_of_len(len: usize) -> SpatialIndex {
+        let array: PointArray = (
+            vec![geo::point!(x: 0., y: 0.); len].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        index(&array)
+    }
+
+    #[test]
+    fn bbox() {
+        let array = points();
+        let rect = geo::Rect::new(geo::coord! { x: 0., y: 0. }, geo::coord! { x: 2., y: 2. });
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), None, Some(false), Some(true)]);
+        assert_eq!(within_bbox(&array, &rect, None).unwrap(), expected);
+        assert_eq!(
+            within_bbox(&array, &rect, Some(&index(&array))).unwrap(),
+            expected
+        );
+
+        let filtered = filter(&array.to_array_ref(), &expected).unwrap();
+        assert_eq!(filtered.len(), 3);
+    }
+
+    #[test]
+    fn geometry_and_distance() {
+        let array = points();
+        let index = index(&array);
+        let polygon = geo::polygon![
+            (x: -1., y: -1.),
+            (x: 3., y: -1.),
+            (x: 3., y: 3.),
+            (x: -1., y: 3.),
+            (x: -1., y: -1.),
+        ];
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), None, Some(false), Some(true)]);
+        assert_eq!(within_geometry(&array, &polygon, None).unwrap(), expected);
+        assert_eq!(
+            within_geometry(&array, &polygon, Some(&index)).unwrap(),
+            expected
+        );
+
+        let origin = geo::point!(x: 0., y: 0.);
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(true), None, Some(false), Some(false)]);
+        assert_eq!(
+            within_distance(&array, &origin, 1.5, None).unwrap(),
+            expected
+        );
+        assert_eq!(
+            within_distance(&array, &origin, 1.5, Some(&index)).unwrap(),
+            expected
+        );
+
+        let other = index_of_len(2);
+        assert!(within_distance(&array, &origin, 1.5, Some(&other)).is_err());
+    }
+
+    #[test]
+    fn dwithin() {
+        let left = points();
+        let right: LineStringArray = (
+            vec![
+                Some(geo::line_string![(x: 0., y: 1.), (x: 10., y: 1.)]),
+                Some(geo::line_string![(x: 0., y: 0.), (x: 0.5, y: 0.5)]),
+                Some(geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+                Some(geo::line_string![(x: 0., y: 0.), (x: 0., y: 10.)]),
+                None,
+            ],
+            Dimension::XY,
+        )
+            .into();
+        assert_eq!(
+            euclidean_dwithin(&left, &right, 1.).unwrap(),
+            BooleanArray::from(vec![Some(true), Some(true), None, Some(false), None])
+        );
+        let short: PointArray = (vec![geo::point!(x: 0., y: 0.)].as_slice(), Dimension::XY).into();
+        assert!(euclidean_dwithin(&left, &short, 1.).is_err());
+    }
+}
@@ -0,0 +1,394 @@
+use std::sync::Arc;
+
+use arrow::compute::{filter, filter_record_batch};
+use arrow_array::{BooleanArray, RecordBatch, RecordBatchIterator};
+use arrow_schema::ArrowError;
+use geo::dimensions::{Dimensions, HasDimensions};
+use geo::{Area, Euclidean, Length};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::*;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::io::RecordBatchReader;
+use crate::schema::GeoSchemaExt;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Size thresholds used to generalize data for smaller scales, such as before rendering.
+///
+/// Each threshold is ignored when `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MinSizeOptions {
+    /// Polygonal geometries with a smaller area are dropped.
+    pub min_area: Option<f64>,
+
+    /// Linear geometries with a smaller length are dropped.
+    pub min_length: Option<f64>,
+
+    /// Interior rings with a smaller area are removed from polygons.
+    pub min_hole_area: Option<f64>,
+}
+
+/// The length of the lines of a geometry.
+fn length(geom: &geo::Geometry) -> f64 {
+    match geom {
+        geo::Geometry::Line(line) => line.length::<Euclidean>(),
+        geo::Geometry::LineString(line_string) => line_string.length::<Euclidean>(),
+        geo::Geometry::MultiLineString(multi_line_string) => {
+            multi_line_string.length::<Euclidean>()
+        }
+        geo::Geometry::GeometryCollection(collection) => collection.iter().map(length).sum(),
+        _ => 0.,
+    }
+}
+
+/// Whether a geometry is at least as large as the area threshold if it's polygonal, or as the
+/// length threshold if it's linear.
+fn is_above_min_size(geom: &geo::Geometry, options: &MinSizeOptions) -> bool {
+    match geom.dimensions() {
+        Dimensions::TwoDimensional => options
+            .min_area
+            .map_or(true, |min_area| geom.unsigned_area() >= min_area),
+        Dimensions::OneDimensional => options
+            .min_length
+            .map_or(true, |min_length| length(geom) >= min_length),
+        _ => true,
+    }
+}
+
+fn mask(
+    array: &dyn NativeArray,
+    options: &MinSizeOptions,
+    null_value: Option<bool>,
+) -> BooleanArray {
+    iter_geometries(array)
+        .map(|geom| match geom {
+            Some(geom) => Some(is_above_min_size(&geometry_to_geo(&geom), options)),
+            None => null_value,
+        })
+        .collect()
+}
+
+/// Flag the geometries that are at least as large as the thresholds of `options`.
+///
+/// The area threshold applies to polygonal geometries, and the length threshold to linear
+/// geometries. Points, multi points and empty geometries are always kept, as are geometry
+/// collections of both polygons and lines that are large enough by area. The output is null where
+/// the input is null. [`MinSizeOptions::min_hole_area`] is ignored.
+pub fn min_size_mask(array: &dyn NativeArray, options: &MinSizeOptions) -> BooleanArray {
+    mask(array, options, None)
+}
+
+/// The area of a ring, regardless of its orientation.
+fn ring_area(ring: &geo::LineString) -> f64 {
+    ring.lines()
+        .map(|line| line.determinant())
+        .sum::<f64>()
+        .abs()
+        / 2.
+}
+
+fn remove_polygon_holes(polygon: geo::Polygon, min_hole_area: f64) -> geo::Polygon {
+    let (exterior, interiors) = polygon.into_inner();
+    let interiors = interiors
+        .into_iter()
+        .filter(|ring| ring_area(ring) >= min_hole_area)
+        .collect();
+    geo::Polygon::new(exterior, interiors)
+}
+
+fn remove_multi_polygon_holes(
+    multi_polygon: geo::MultiPolygon,
+    min_hole_area: f64,
+) -> geo::MultiPolygon {
+    multi_polygon
+        .into_iter()
+        .map(|polygon| remove_polygon_holes(polygon, min_hole_area))
+        .collect()
+}
+
+fn remove_geometry_holes(geom: geo::Geometry, min_hole_area: f64) -> geo::Geometry {
+    match geom {
+        geo::Geometry::Polygon(polygon) => remove_polygon_holes(polygon, min_hole_area).into(),
+        geo::Geometry::MultiPolygon(multi_polygon) => {
+            remove_multi_polygon_holes(multi_polygon, min_hole_area).into()
+        }
+        geo::Geometry::GeometryCollection(collection) => collection
+            .into_iter()
+            .map(|geom| remove_geometry_holes(geom, min_hole_area))
+            .collect::<geo::GeometryCollection>()
+            .into(),
+        geom => geom,
+    }
+}
+
+/// Remove the interior rings of polygons whose area is smaller than `min_hole_area`.
+///
+/// The output has the type, coordinate type and metadata of the input. Arrays without polygons
+/// are returned as is. Only 2D polygonal arrays are supported, because holes are removed with
+/// [`geo`].
+pub fn remove_small_holes(
+    array: &dyn NativeArray,
+    min_hole_area: f64,
+) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
+
+    let result: Arc<dyn NativeArray> = match array.data_type() {
+        Point(_, _) | LineString(_, _) | MultiPoint(_, _) | MultiLineString(_, _) | Rect(_) => {
+            array.slice(0, array.len())
+        }
+        Polygon(coord_type, Dimension::XY) => {
+            let polygons = array
+                .as_polygon()
+                .iter_geo()
+                .map(|polygon| polygon.map(|polygon| remove_polygon_holes(polygon, min_hole_area)))
+                .collect::<Vec<_>>();
+            Arc::new(
+                PolygonBuilder::from_nullable_polygons(
+                    &polygons,
+                    Dimension::XY,
+                    coord_type,
+                    array.metadata(),
+                )
+                .finish(),
+            )
+        }
+        MultiPolygon(coord_type, Dimension::XY) => {
+            let multi_polygons = array
+                .as_multi_polygon()
+                .iter_geo()
+                .map(|multi_polygon| {
+                    multi_polygon.map(|multi_polygon| {
+                        remove_multi_polygon_holes(multi_polygon, min_hole_area)
+                    })
+                })
+                .collect::<Vec<_>>();
+            Arc::new(
+                MultiPolygonBuilder::from_nullable_multi_polygons(
+                    &multi_polygons,
+                    Dimension::XY,
+                    coord_type,
+                    array.metadata(),
+                )
+                .finish(),
+            )
+        }
+        GeometryCollection(coord_type, Dimension::XY) => {
+            let geoms = array
+                .as_geometry_collection()
+                .iter_geo()
+                .map(|collection| {
+                    collection
+                        .map(|collection| remove_geometry_holes(collection.into(), min_hole_area))
+                })
+                .collect::<Vec<_>>();
+            Arc::new(
+                GeometryCollectionBuilder::from_nullable_geometries(
+                    &geoms,
+                    Dimension::XY,
+                    coord_type,
+                    array.metadata(),
+                    false,
+                )?
+                .finish(),
+            )
+        }
+        Geometry(coord_type) => {
+            let geometry_array = array.as_geometry();
+            if geometry_array.has_dimension(Dimension::XYZ)
+                || geometry_array.has_geometry_collections(Dimension::XYZ)
+            {
+                return Err(GeoArrowError::IncorrectType(
+                    "remove_small_holes only supports 2D polygons".into(),
+                ));
+            }
+            let geoms = geometry_array
+                .iter_geo()
+                .map(|geom| geom.map(|geom| remove_geometry_holes(geom, min_hole_area)))
+                .collect::<Vec<_>>();
+            Arc::new(
+                GeometryBuilder::from_nullable_geometries(
+                    &geoms,
+                    coord_type,
+                    array.metadata(),
+                    false,
+                )?
+                .finish(),
+            )
+        }
+        _ => {
+            return Err(GeoArrowError::IncorrectType(
+                "remove_small_holes only supports 2D polygons".into(),
+            ))
+        }
+    };
+    Ok(result)
+}
+
+fn apply_hole_threshold(
+    array: &dyn NativeArray,
+    options: &MinSizeOptions,
+) -> Result<Arc<dyn NativeArray>> {
+    match options.min_hole_area {
+        Some(min_hole_area) => remove_small_holes(array, min_hole_area),
+        None => Ok(array.slice(0, array.len())),
+    }
+}
+
+/// Remove small holes and drop small geometries, as set by `options`.
+///
+/// Holes are removed first, so that the area threshold applies to the area of the remaining
+/// polygons. See [`min_size_mask`] for the geometries the thresholds apply to. Null geometries are
+/// kept.
+pub fn filter_min_size(
+    array: &dyn NativeArray,
+    options: &MinSizeOptions,
+) -> Result<Arc<dyn NativeArray>> {
+    let array = apply_hole_threshold(array, options)?;
+    let filtered = filter(
+        &array.to_array_ref(),
+        &mask(array.as_ref(), options, Some(true)),
+    )?;
+    Ok(NativeArrayDyn::from_arrow_array(&filtered, &array.extension_field())?.into_inner())
+}
+
+/// Remove small holes from the geometry column at `geometry_index` of a batch, and drop the rows
+/// of small geometries. See [`filter_min_size`].
+pub fn filter_min_size_batch(
+    batch: &RecordBatch,
+    geometry_index: usize,
+    options: &MinSizeOptions,
+) -> Result<RecordBatch> {
+    let array = NativeArrayDyn::from_arrow_array(
+        batch.column(geometry_index),
+        batch.schema_ref().field(geometry_index),
+    )?
+    .into_inner();
+    let array = apply_hole_threshold(array.as_ref(), options)?;
+    let keep = mask(array.as_ref(), options, Some(true));
+
+    let mut columns = batch.columns().to_vec();
+    columns[geometry_index] = array.to_array_ref();
+    let batch = RecordBatch::try_new(batch.schema(), columns)?;
+    Ok(filter_record_batch(&batch, &keep)?)
+}
+
+/// Lazily apply [`filter_min_size_batch`] to a stream of record batches.
+///
+/// This is meant as a pre-filter for writers that take a record batch reader, so that small
+/// geometries are dropped while writing smaller-scale products. The geometry column is
+/// `geometry_index`, or the only geometry column of the reader if `None`.
+pub fn filter_min_size_reader(
+    reader: RecordBatchReader,
+    geometry_index: Option<usize>,
+    options: MinSizeOptions,
+) -> Result<RecordBatchReader> {
+    let schema = reader.schema();
+    let geometry_index = if let Some(index) = geometry_index {
+        index
+    } else {
+        let geom_indices = schema.as_ref().geometry_columns();
+        if geom_indices.len() != 1 {
+            return Err(GeoArrowError::General(
+                "`geometry_index` must be provided when multiple geometry columns exist."
+                    .to_string(),
+            ));
+        }
+        geom_indices[0]
+    };
+
+    let batches = reader.into_inner().map(move |batch| {
+        filter_min_size_batch(&batch?, geometry_index, &options)
+            .map_err(|err| ArrowError::ExternalError(Box::new(err)))
+    });
+    Ok(RecordBatchReader::new(Box::new(RecordBatchIterator::new(
+        batches, schema,
+    ))))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use geo::{line_string, polygon};
+
+    use super::*;
+    use crate::table::Table;
+    use crate::test::point;
+
+    fn polygons() -> PolygonArray {
+        let small = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let with_holes = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                [(x: 3., y: 3.), (x: 8., y: 3.), (x: 8., y: 8.), (x: 3., y: 8.)],
+            ],
+        );
+        (vec![small, with_holes].as_slice(), Dimension::XY).into()
+    }
+
+    #[test]
+    fn mask_polygons_and_lines() {
+        let options = MinSizeOptions {
+            min_area: Some(2.),
+            min_length: Some(2.),
+            ..Default::default()
+        };
+        assert_eq!(
+            min_size_mask(&polygons(), &options),
+            BooleanArray::from(vec![false, true])
+        );
+
+        let lines: LineStringArray = (
+            vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 0., y: 0.), (x: 3., y: 0.)],
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        assert_eq!(
+            min_size_mask(&lines, &options),
+            BooleanArray::from(vec![false, true])
+        );
+
+        let points = point::point_array();
+        assert_eq!(min_size_mask(&points, &options).true_count(), points.len());
+    }
+
+    #[test]
+    fn remove_holes_then_filter() {
+        let options = MinSizeOptions {
+            min_area: Some(2.),
+            min_hole_area: Some(2.),
+            ..Default::default()
+        };
+        let filtered = filter_min_size(&polygons(), &options).unwrap();
+        let filtered_ref = filtered.as_ref();
+        let filtered = filtered_ref.as_polygon();
+        assert_eq!(filtered.len(), 1);
+        let polygon = filtered.value_as_geo(0);
+        assert_eq!(polygon.interiors().len(), 1);
+        assert_eq!(polygon.unsigned_area(), 75.);
+    }
+
+    #[test]
+    fn reader_pre_filter() {
+        let array = polygons();
+        let schema = Arc::new(arrow_schema::Schema::new(vec![array.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.into_array_ref()]).unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+
+        let options = MinSizeOptions {
+            min_area: Some(2.),
+            ..Default::default()
+        };
+        let reader = filter_min_size_reader(table.into(), None, options).unwrap();
+        let filtered = Table::try_from(reader).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.batches()[0].column(0).len(), 1);
+    }
+}
@@ -54,6 +54,21 @@ pub use densify::Densify;
 mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Dissolve the geometries of record batches by key, spilling partial unions to disk.
+mod dissolve;
+pub use dissolve::{dissolve_by_key, dissolve_stream, DissolveOptions, StreamingDissolve};
+
+/// Calculate dense or sparse matrices of the Euclidean distances between two arrays.
+mod distance_matrix;
+pub use distance_matrix::{distance_matrix, within_distance_pairs, MAX_DISTANCE_MATRIX_VALUES};
+
+/// Kernels that use spherical implementations, or fail, for arrays with spherical edges.
+pub mod edges;
+
+/// Options for kernels whose result is undefined for empty geometries.
+mod empty;
+pub use empty::EmptyGeometryOptions;
+
 /// Calculate the length of a planar length of a
 /// [`LineStringArray`][crate::array::LineStringArray].
 mod euclidean_length;
@@ -74,10 +89,18 @@ pub use geodesic_area::GeodesicArea;
 mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
 
+/// Calculate the Hausdorff distance between the geometries of two arrays.
+mod hausdorff_distance;
+pub use hausdorff_distance::{HausdorffDistance, HausdorffDistanceScalar};
+
 /// Calculate the Haversine length of a Line.
 mod haversine_length;
 pub use haversine_length::HaversineLength;
 
+/// Calculate the length of geometries with a planar or ellipsoidal method.
+mod length;
+pub use length::{length, LengthMethod};
+
 /// Calculation of interior points.
 mod interior_point;
 pub use interior_point::InteriorPoint;
@@ -94,10 +117,42 @@ pub use line_interpolate_point::LineInterpolatePoint;
 mod line_locate_point;
 pub use line_locate_point::{LineLocatePoint, LineLocatePointScalar};
 
+/// Extract the portion of a `LineStringArray` between two fractions of its length.
+mod line_substring;
+pub use line_substring::LineSubstring;
+
+/// Apply a closure to each geometry of an array.
+mod map_geometries;
+pub use map_geometries::MapGeometries;
+
+/// Boolean masks of the geometries within a bounding box, a geometry or a distance of a geometry.
+mod mask;
+pub use mask::{euclidean_dwithin, within_bbox, within_distance, within_geometry};
+
+/// Drop geometries and remove holes below an area or length threshold.
+mod min_size;
+pub use min_size::{
+    filter_min_size, filter_min_size_batch, filter_min_size_reader, min_size_mask,
+    remove_small_holes, MinSizeOptions,
+};
+
 /// Calculate the minimum rotated rectangle of a `Geometry`.
 mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
 
+/// Find the closest points and shortest lines between the geometries of two arrays.
+mod nearest_points;
+pub use nearest_points::{
+    nearest_points, nearest_points_scalar, shortest_line, shortest_line_scalar,
+};
+
+/// Evaluate DE-9IM spatial predicates and patterns between geometries.
+mod relate;
+pub use relate::{
+    relate_pattern, relate_pattern_prepared, relate_pattern_scalar, relate_predicate,
+    relate_predicate_prepared, relate_predicate_scalar, PreparedGeometry, SpatialPredicate,
+};
+
 /// Remove (consecutive) repeated points
 mod remove_repeated_points;
 pub use remove_repeated_points::RemoveRepeatedPoints;
@@ -110,6 +165,14 @@ pub use rotate::Rotate;
 mod scale;
 pub use scale::Scale;
 
+/// Reduce the precision of geometries by snapping their coordinates to a grid.
+mod set_precision;
+pub use set_precision::set_precision;
+
+/// Find the paths shared by pairs of lineal geometries.
+mod shared_paths;
+pub use shared_paths::shared_paths;
+
 /// Simplify geometries using the Ramer-Douglas-Peucker algorithm.
 mod simplify;
 pub use simplify::Simplify;
@@ -126,10 +189,30 @@ pub use simplify_vw_preserve::SimplifyVwPreserve;
 mod skew;
 pub use skew::Skew;
 
+/// Snap geometries to the vertices of reference geometries within a tolerance.
+mod snap;
+pub use snap::snap;
+
+/// Split geometries by blade geometries.
+mod split;
+pub use split::split;
+
+/// Assign features to web mercator tiles, clipping them to the tile bounds.
+mod tile_cut;
+pub use tile_cut::tile_cut;
+
 /// Translate geometries along the given offsets.
 mod translate;
 pub use translate::Translate;
 
+/// Dissolve all the geometries of an array into one geometry.
+mod unary_union;
+pub use unary_union::{unary_union, UnaryUnionAccumulator};
+
+/// Check the validity of geometries, with the reason and location of each problem.
+pub(crate) mod validation;
+pub use validation::{is_valid_detail, InvalidReason};
+
 /// Calculate the Vincenty length of a [`LineStringArray`][crate::array::LineStringArray].
 mod vincenty_length;
 pub use vincenty_length::VincentyLength;
@@ -0,0 +1,395 @@
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{BoundingRect, Coord, Intersects, Line};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::{LineStringArray, LineStringBuilder, PointArray, PointBuilder};
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::{ArrayBase, NativeArray};
+
+/// Find the closest pair of points between the geometries at the same index of two arrays.
+///
+/// Returns a point on each geometry of `left` and the point on the geometry of `right` that is
+/// nearest to it. Where the geometries intersect, both points are the same point of their
+/// intersection. The output is null where either geometry is null or empty.
+///
+/// Returns an error if the arrays have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::nearest_points;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let points: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+/// let line_string = geo::line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+/// let line_strings: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+///
+/// let (left, right) = nearest_points(&points, &line_strings).unwrap();
+/// assert_eq!(left.value_as_geo(0), geo::point!(x: 1., y: 2.));
+/// assert_eq!(right.value_as_geo(0), geo::point!(x: 1., y: 0.));
+/// ```
+pub fn nearest_points(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+) -> Result<(PointArray, PointArray)> {
+    let pairs = pairwise(left, right)?;
+    Ok(points_output(&pairs, left, right))
+}
+
+/// Find the closest pair of points between each geometry of an array and a single geometry.
+///
+/// See [`nearest_points`]. The parts of `right` are prepared once and the bounding boxes of its
+/// segments are used to skip those that can't be closer than the nearest pair found so far, so
+/// this is much faster than broadcasting `right` to an array.
+pub fn nearest_points_scalar(
+    left: &dyn NativeArray,
+    right: &geo::Geometry,
+) -> Result<(PointArray, PointArray)> {
+    let pairs = broadcast(left, right);
+    Ok(points_output(&pairs, left, left))
+}
+
+/// Find the shortest line between the geometries at the same index of two arrays.
+///
+/// Each line string has two coordinates: the points returned by [`nearest_points`], from `left`
+/// to `right`. Where the geometries intersect, the line has zero length. The output is null where
+/// either geometry is null or empty.
+///
+/// Returns an error if the arrays have different lengths.
+pub fn shortest_line(left: &dyn NativeArray, right: &dyn NativeArray) -> Result<LineStringArray> {
+    let pairs = pairwise(left, right)?;
+    Ok(line_output(&pairs, left))
+}
+
+/// Find the shortest line between each geometry of an array and a single geometry.
+///
+/// See [`shortest_line`] and [`nearest_points_scalar`].
+pub fn shortest_line_scalar(
+    left: &dyn NativeArray,
+    right: &geo::Geometry,
+) -> Result<LineStringArray> {
+    let pairs = broadcast(left, right);
+    Ok(line_output(&pairs, left))
+}
+
+/// The segments and polygons of a geometry, with its bounding box.
+struct Parts {
+    /// The segments of the geometry, where points are zero-length segments
+    lines: Vec<Line>,
+    polygons: Vec<(geo::Polygon, geo::Rect)>,
+    rect: geo::Rect,
+}
+
+impl Parts {
+    /// Returns `None` for empty geometries.
+    fn new(geom: &geo::Geometry) -> Option<Self> {
+        let rect = geom.bounding_rect()?;
+        let mut parts = Self {
+            lines: vec![],
+            polygons: vec![],
+            rect,
+        };
+        parts.push(geom);
+        (!parts.lines.is_empty()).then_some(parts)
+    }
+
+    fn push(&mut self, geom: &geo::Geometry) {
+        match geom {
+            geo::Geometry::Point(point) => self.lines.push(Line::new(point.0, point.0)),
+            geo::Geometry::Line(line) => self.lines.push(*line),
+            geo::Geometry::LineString(line_string) => self.push_line_string(line_string),
+            geo::Geometry::Polygon(polygon) => self.push_polygon(polygon),
+            geo::Geometry::MultiPoint(multi_point) => self
+                .lines
+                .extend(multi_point.iter().map(|point| Line::new(point.0, point.0))),
+            geo::Geometry::MultiLineString(multi_line_string) => multi_line_string
+                .iter()
+                .for_each(|line_string| self.push_line_string(line_string)),
+            geo::Geometry::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .for_each(|polygon| self.push_polygon(polygon)),
+            geo::Geometry::GeometryCollection(collection) => {
+                collection.iter().for_each(|geom| self.push(geom))
+            }
+            geo::Geometry::Rect(rect) => self.push_polygon(&rect.to_polygon()),
+            geo::Geometry::Triangle(triangle) => self.push_polygon(&triangle.to_polygon()),
+        }
+    }
+
+    fn push_line_string(&mut self, line_string: &geo::LineString) {
+        match line_string.0.as_slice() {
+            [coord] => self.lines.push(Line::new(*coord, *coord)),
+            _ => self.lines.extend(line_string.lines()),
+        }
+    }
+
+    fn push_polygon(&mut self, polygon: &geo::Polygon) {
+        let Some(rect) = polygon.bounding_rect() else {
+            return;
+        };
+        self.push_line_string(polygon.exterior());
+        polygon
+            .interiors()
+            .iter()
+            .for_each(|interior| self.push_line_string(interior));
+        self.polygons.push((polygon.clone(), rect));
+    }
+
+    /// A vertex of this geometry that lies inside a polygon of `other`, if any.
+    fn vertex_within(&self, other: &Self) -> Option<Coord> {
+        self.lines.iter().map(|line| line.start).find(|coord| {
+            other
+                .polygons
+                .iter()
+                .any(|(polygon, rect)| rect.intersects(coord) && polygon.intersects(coord))
+        })
+    }
+}
+
+/// The closest pair of points between two geometries.
+fn nearest(left: &Parts, right: &Parts) -> (Coord, Coord) {
+    // If a geometry has a vertex inside a polygon of the other and no boundaries cross, the
+    // geometries only intersect in the polygon interior.
+    if let Some(coord) = left
+        .vertex_within(right)
+        .or_else(|| right.vertex_within(left))
+    {
+        return (coord, coord);
+    }
+
+    let mut best = (f64::INFINITY, left.lines[0].start, right.lines[0].start);
+    for right_line in &right.lines {
+        let right_rect = right_line.bounding_rect();
+        if rect_distance(&right_rect, &left.rect) >= best.0 {
+            continue;
+        }
+        for left_line in &left.lines {
+            if rect_distance(&left_line.bounding_rect(), &right_rect) >= best.0 {
+                continue;
+            }
+            let candidate = segment_nearest(left_line, right_line);
+            if candidate.0 < best.0 {
+                best = candidate;
+                if best.0 == 0. {
+                    return (best.1, best.2);
+                }
+            }
+        }
+    }
+    (best.1, best.2)
+}
+
+/// The distance between two segments and their closest pair of points.
+fn segment_nearest(left: &Line, right: &Line) -> (f64, Coord, Coord) {
+    let degenerate = left.start == left.end || right.start == right.end;
+    if !degenerate {
+        match line_intersection(*left, *right) {
+            Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                return (0., intersection, intersection)
+            }
+            Some(LineIntersection::Collinear { intersection }) => {
+                return (0., intersection.start, intersection.start)
+            }
+            None => {}
+        }
+    }
+
+    [
+        (left.start, project(left.start, right)),
+        (left.end, project(left.end, right)),
+        (project(right.start, left), right.start),
+        (project(right.end, left), right.end),
+    ]
+    .into_iter()
+    .map(|(a, b)| ((a.x - b.x).hypot(a.y - b.y), a, b))
+    .fold(
+        (f64::INFINITY, left.start, right.start),
+        |best, candidate| {
+            if candidate.0 < best.0 {
+                candidate
+            } else {
+                best
+            }
+        },
+    )
+}
+
+/// The point of a segment nearest to a coordinate.
+fn project(coord: Coord, line: &Line) -> Coord {
+    let delta = line.delta();
+    let length_squared = delta.x * delta.x + delta.y * delta.y;
+    if length_squared == 0. {
+        return line.start;
+    }
+    let t =
+        ((coord.x - line.start.x) * delta.x + (coord.y - line.start.y) * delta.y) / length_squared;
+    line.start + delta * t.clamp(0., 1.)
+}
+
+/// The distance between two bounding boxes, which is zero if they overlap.
+fn rect_distance(left: &geo::Rect, right: &geo::Rect) -> f64 {
+    let dx = (left.min().x - right.max().x)
+        .max(right.min().x - left.max().x)
+        .max(0.);
+    let dy = (left.min().y - right.max().y)
+        .max(right.min().y - left.max().y)
+        .max(0.);
+    dx.hypot(dy)
+}
+
+fn pairwise(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+) -> Result<Vec<Option<(Coord, Coord)>>> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    Ok(iter_geometries(left)
+        .zip(iter_geometries(right))
+        .map(|(left_geom, right_geom)| {
+            let left_parts = Parts::new(&geometry_to_geo(&left_geom?))?;
+            let right_parts = Parts::new(&geometry_to_geo(&right_geom?))?;
+            Some(nearest(&left_parts, &right_parts))
+        })
+        .collect())
+}
+
+fn broadcast(left: &dyn NativeArray, right: &geo::Geometry) -> Vec<Option<(Coord, Coord)>> {
+    let Some(right_parts) = Parts::new(right) else {
+        return vec![None; left.len()];
+    };
+    iter_geometries(left)
+        .map(|left_geom| {
+            let left_parts = Parts::new(&geometry_to_geo(&left_geom?))?;
+            Some(nearest(&left_parts, &right_parts))
+        })
+        .collect()
+}
+
+fn points_output(
+    pairs: &[Option<(Coord, Coord)>],
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+) -> (PointArray, PointArray) {
+    let left_points = pairs
+        .iter()
+        .map(|pair| pair.map(|(coord, _)| geo::Point(coord)))
+        .collect::<Vec<_>>();
+    let right_points = pairs
+        .iter()
+        .map(|pair| pair.map(|(_, coord)| geo::Point(coord)))
+        .collect::<Vec<_>>();
+
+    let left_points = PointBuilder::from_nullable_points(
+        left_points.iter().map(|point| point.as_ref()),
+        Dimension::XY,
+        left.coord_type(),
+        left.metadata(),
+    )
+    .finish();
+    let right_points = PointBuilder::from_nullable_points(
+        right_points.iter().map(|point| point.as_ref()),
+        Dimension::XY,
+        right.coord_type(),
+        right.metadata(),
+    )
+    .finish();
+    (left_points, right_points)
+}
+
+fn line_output(pairs: &[Option<(Coord, Coord)>], left: &dyn NativeArray) -> LineStringArray {
+    let lines = pairs
+        .iter()
+        .map(|pair| pair.map(|(start, end)| geo::LineString::new(vec![start, end])))
+        .collect::<Vec<_>>();
+    LineStringBuilder::from_nullable_line_strings(
+        lines.as_slice(),
+        Dimension::XY,
+        left.coord_type(),
+        left.metadata(),
+    )
+    .finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::trait_::ArrayAccessor;
+    use geo::{line_string, point, polygon};
+
+    fn square() -> geo::Polygon {
+        polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)]
+    }
+
+    #[test]
+    fn nearest_points_pairwise() {
+        let points: PointArray = (
+            vec![Some(point!(x: 6., y: 2.)), Some(point!(x: 1., y: 1.)), None],
+            Dimension::XY,
+        )
+            .into();
+        let polygons: PolygonArray = (
+            vec![Some(square()), Some(square()), Some(square())],
+            Dimension::XY,
+        )
+            .into();
+
+        let (left, right) = nearest_points(&points, &polygons).unwrap();
+        assert_eq!(right.value_as_geo(0), point!(x: 4., y: 2.));
+        // The point is inside the polygon
+        assert_eq!(left.value_as_geo(1), point!(x: 1., y: 1.));
+        assert_eq!(right.value_as_geo(1), point!(x: 1., y: 1.));
+        assert!(left.is_null(2));
+        assert!(right.is_null(2));
+
+        let line = shortest_line(&polygons, &points).unwrap();
+        assert_eq!(
+            line.value_as_geo(0),
+            line_string![(x: 4., y: 2.), (x: 6., y: 2.)]
+        );
+    }
+
+    #[test]
+    fn shortest_line_broadcast() {
+        let line_strings: LineStringArray = (
+            vec![
+                line_string![(x: 0., y: 5.), (x: 4., y: 7.)],
+                line_string![(x: -1., y: 2.), (x: 5., y: 2.)],
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let right = geo::Geometry::Polygon(square());
+
+        let lines = shortest_line_scalar(&line_strings, &right).unwrap();
+        assert_eq!(
+            lines.value_as_geo(0),
+            line_string![(x: 0., y: 5.), (x: 0., y: 4.)]
+        );
+        // Crossing geometries give a zero-length line
+        let crossing = lines.value_as_geo(1);
+        assert_eq!(crossing.0[0], crossing.0[1]);
+
+        let empty = geo::Geometry::MultiPoint(geo::MultiPoint::new(vec![]));
+        let (left, _) = nearest_points_scalar(&line_strings, &empty).unwrap();
+        assert_eq!(left.null_count(), 2);
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let points: PointArray = (vec![point!(x: 0., y: 0.)].as_slice(), Dimension::XY).into();
+        let polygons: PolygonArray = (vec![Some(square()), None], Dimension::XY).into();
+        assert!(nearest_points(&points, &polygons).is_err());
+    }
+}
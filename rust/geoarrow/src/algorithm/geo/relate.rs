@@ -0,0 +1,364 @@
+//! Element-wise [DE-9IM] predicates between the geometries of two arrays, or of an array and a
+//! single geometry.
+//!
+//! [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::BooleanArray;
+use geo::relate::IntersectionMatrix;
+use geo::Relate;
+use geo_traits::GeometryTrait;
+
+use crate::algorithm::geo::mask::{bbox_contains, bbox_intersects, to_bbox, Bbox};
+use crate::algorithm::native::bounding_rect::bounding_rect_geometry;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::NativeArray;
+
+/// A named spatial predicate, as defined by the OGC Simple Features specification and
+/// implemented by PostGIS' functions of the same names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialPredicate {
+    /// The geometries have at least one point in common. `ST_Intersects`.
+    Intersects,
+
+    /// The geometries have no point in common. `ST_Disjoint`.
+    Disjoint,
+
+    /// No point of the right geometry is outside the left one, and their interiors intersect.
+    /// `ST_Contains`.
+    Contains,
+
+    /// No point of the left geometry is outside the right one, and their interiors intersect.
+    /// `ST_Within`.
+    Within,
+
+    /// No point of the right geometry is outside the left one. `ST_Covers`.
+    Covers,
+
+    /// No point of the left geometry is outside the right one. `ST_CoveredBy`.
+    CoveredBy,
+
+    /// The geometries have a point in common, but their interiors don't intersect.
+    /// `ST_Touches`.
+    Touches,
+
+    /// The geometries have some, but not all, interior points in common, and the dimension of
+    /// their intersection is less than that of one of them. `ST_Crosses`.
+    Crosses,
+
+    /// The geometries have the same dimension, and some, but not all, points in common.
+    /// `ST_Overlaps`.
+    Overlaps,
+
+    /// The geometries are topologically equal: they have the same points. `ST_Equals`.
+    EqualsTopo,
+}
+
+impl SpatialPredicate {
+    fn evaluate(&self, matrix: &IntersectionMatrix) -> bool {
+        match self {
+            Self::Intersects => matrix.is_intersects(),
+            Self::Disjoint => matrix.is_disjoint(),
+            Self::Contains => matrix.is_contains(),
+            Self::Within => matrix.is_within(),
+            Self::Covers => matrix.is_covers(),
+            Self::CoveredBy => matrix.is_coveredby(),
+            Self::Touches => matrix.is_touches(),
+            Self::Crosses => matrix.is_crosses(),
+            Self::Overlaps => matrix.is_overlaps(),
+            Self::EqualsTopo => matrix.is_equal_topo(),
+        }
+    }
+
+    /// The result of the predicate if the bounding boxes of the geometries rule it out, without
+    /// computing the intersection matrix.
+    fn bbox_result(&self, left: &Bbox, right: &Bbox) -> Option<bool> {
+        if !bbox_intersects(left, right) {
+            return Some(*self == Self::Disjoint);
+        }
+        let possible = match self {
+            Self::Contains | Self::Covers => bbox_contains(left, right),
+            Self::Within | Self::CoveredBy => bbox_contains(right, left),
+            Self::EqualsTopo => left == right,
+            _ => true,
+        };
+        (!possible).then_some(false)
+    }
+}
+
+/// A spatial predicate or a DE-9IM pattern to evaluate.
+enum Relation<'a> {
+    Predicate(SpatialPredicate),
+    Pattern(&'a str),
+}
+
+impl Relation<'_> {
+    fn evaluate(&self, left: &geo::Geometry, right: &impl Relate<f64>) -> Result<bool> {
+        let matrix = left.relate(right);
+        match self {
+            Self::Predicate(predicate) => Ok(predicate.evaluate(&matrix)),
+            Self::Pattern(pattern) => matrix
+                .matches(pattern)
+                .map_err(|_| GeoArrowError::General(format!("Invalid DE-9IM pattern: {pattern}"))),
+        }
+    }
+
+    fn bbox_result(&self, left: &Bbox, right: &Bbox) -> Option<bool> {
+        match self {
+            Self::Predicate(predicate) => predicate.bbox_result(left, right),
+            Self::Pattern(_) => None,
+        }
+    }
+}
+
+fn relate_arrays(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    relation: Relation,
+) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let mut output = BooleanBuilder::with_capacity(left.len());
+    for (left_geom, right_geom) in iter_geometries(left).zip(iter_geometries(right)) {
+        let (Some(left_geom), Some(right_geom)) = (left_geom, right_geom) else {
+            output.append_null();
+            continue;
+        };
+        let left_bbox = to_bbox(bounding_rect_geometry(&left_geom));
+        let right_bbox = to_bbox(bounding_rect_geometry(&right_geom));
+        let result = match relation.bbox_result(&left_bbox, &right_bbox) {
+            Some(result) => result,
+            None => {
+                relation.evaluate(&geometry_to_geo(&left_geom), &geometry_to_geo(&right_geom))?
+            }
+        };
+        output.append_value(result);
+    }
+    Ok(output.finish())
+}
+
+fn relate_array_prepared(
+    left: &dyn NativeArray,
+    right: &PreparedGeometry,
+    relation: Relation,
+) -> Result<BooleanArray> {
+    let mut output = BooleanBuilder::with_capacity(left.len());
+    for left_geom in iter_geometries(left) {
+        let Some(left_geom) = left_geom else {
+            output.append_null();
+            continue;
+        };
+        let left_bbox = to_bbox(bounding_rect_geometry(&left_geom));
+        let result = match relation.bbox_result(&left_bbox, &right.bbox) {
+            Some(result) => result,
+            None => relation.evaluate(&geometry_to_geo(&left_geom), &right.geometry)?,
+        };
+        output.append_value(result);
+    }
+    Ok(output.finish())
+}
+
+/// A geometry prepared for evaluating spatial predicates against many geometries.
+///
+/// Preparing a geometry computes its topology graph, with the index of its edges, once instead of
+/// for every geometry it's compared with. Prefer it to the scalar kernels when testing a large
+/// geometry, such as a polygon with many vertices, against several arrays or chunks.
+pub struct PreparedGeometry {
+    geometry: geo::PreparedGeometry<'static>,
+    bbox: Bbox,
+}
+
+impl PreparedGeometry {
+    /// Prepare a geometry, such as a [`GeometryScalar`][crate::scalar::GeometryScalar].
+    pub fn new(geometry: &impl GeometryTrait<T = f64>) -> Self {
+        Self {
+            geometry: geo::PreparedGeometry::from(geometry_to_geo(geometry)),
+            bbox: to_bbox(bounding_rect_geometry(geometry)),
+        }
+    }
+}
+
+/// Evaluate a spatial predicate between the geometries at the same index of two arrays.
+///
+/// Pairs that the bounding boxes of the geometries decide, e.g. geometries whose bounding boxes
+/// don't intersect for [`SpatialPredicate::Intersects`], are computed without the intersection
+/// matrix. The output is null where either geometry is null.
+pub fn relate_predicate(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    predicate: SpatialPredicate,
+) -> Result<BooleanArray> {
+    relate_arrays(left, right, Relation::Predicate(predicate))
+}
+
+/// Evaluate a spatial predicate between every geometry of an array, on the left, and a single
+/// geometry, on the right. See [`relate_predicate`].
+pub fn relate_predicate_scalar(
+    left: &dyn NativeArray,
+    right: &impl GeometryTrait<T = f64>,
+    predicate: SpatialPredicate,
+) -> Result<BooleanArray> {
+    relate_array_prepared(
+        left,
+        &PreparedGeometry::new(right),
+        Relation::Predicate(predicate),
+    )
+}
+
+/// Evaluate a spatial predicate between every geometry of an array, on the left, and a prepared
+/// geometry, on the right. See [`relate_predicate`].
+pub fn relate_predicate_prepared(
+    left: &dyn NativeArray,
+    right: &PreparedGeometry,
+    predicate: SpatialPredicate,
+) -> Result<BooleanArray> {
+    relate_array_prepared(left, right, Relation::Predicate(predicate))
+}
+
+/// Whether the DE-9IM intersection matrix of the geometries at the same index of two arrays
+/// matches a pattern such as `"T*F**F***"`, the equivalent of PostGIS' `ST_Relate` with a
+/// pattern.
+///
+/// It's an error if the pattern isn't nine characters of `T`, `F`, `*`, `0`, `1` and `2`. The
+/// output is null where either geometry is null.
+pub fn relate_pattern(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    pattern: &str,
+) -> Result<BooleanArray> {
+    relate_arrays(left, right, Relation::Pattern(pattern))
+}
+
+/// Whether the DE-9IM intersection matrix of every geometry of an array and a single geometry
+/// matches a pattern. See [`relate_pattern`].
+pub fn relate_pattern_scalar(
+    left: &dyn NativeArray,
+    right: &impl GeometryTrait<T = f64>,
+    pattern: &str,
+) -> Result<BooleanArray> {
+    relate_array_prepared(
+        left,
+        &PreparedGeometry::new(right),
+        Relation::Pattern(pattern),
+    )
+}
+
+/// Whether the DE-9IM intersection matrix of every geometry of an array and a prepared geometry
+/// matches a pattern. See [`relate_pattern`].
+pub fn relate_pattern_prepared(
+    left: &dyn NativeArray,
+    right: &PreparedGeometry,
+    pattern: &str,
+) -> Result<BooleanArray> {
+    relate_array_prepared(left, right, Relation::Pattern(pattern))
+}
+
+#[cfg(test)]
+mod test {
+    use geo::polygon;
+
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+
+    fn square(x: f64, y: f64, size: f64) -> geo::Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    fn polygons() -> PolygonArray {
+        let polygons = vec![
+            Some(square(1., 1., 1.)),
+            Some(square(4., 0., 1.)),
+            Some(square(3., 1., 2.)),
+            Some(square(10., 10., 1.)),
+            Some(square(0., 0., 4.)),
+            None,
+        ];
+        (polygons, Dimension::XY).into()
+    }
+
+    #[test]
+    fn predicates_against_scalar() {
+        let array = polygons();
+        let area = square(0., 0., 4.);
+        let cases = [
+            (SpatialPredicate::Within, [true, false, false, false, true]),
+            (
+                SpatialPredicate::CoveredBy,
+                [true, false, false, false, true],
+            ),
+            (
+                SpatialPredicate::Touches,
+                [false, true, false, false, false],
+            ),
+            (
+                SpatialPredicate::Overlaps,
+                [false, false, true, false, false],
+            ),
+            (
+                SpatialPredicate::Disjoint,
+                [false, false, false, true, false],
+            ),
+            (
+                SpatialPredicate::EqualsTopo,
+                [false, false, false, false, true],
+            ),
+        ];
+        for (predicate, expected) in cases {
+            let mut expected = expected.map(Some).to_vec();
+            expected.push(None);
+            assert_eq!(
+                relate_predicate_scalar(&array, &area, predicate).unwrap(),
+                BooleanArray::from(expected),
+                "{predicate:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn prepared_geometry() {
+        let area = PreparedGeometry::new(&square(0., 0., 4.));
+        for chunk in [polygons().slice(0, 3), polygons().slice(3, 3)] {
+            assert_eq!(
+                relate_predicate_prepared(&chunk, &area, SpatialPredicate::Intersects).unwrap(),
+                relate_predicate_scalar(&chunk, &square(0., 0., 4.), SpatialPredicate::Intersects)
+                    .unwrap(),
+            );
+        }
+        assert_eq!(
+            relate_pattern_prepared(&polygons(), &area, "T*F**F***").unwrap(),
+            relate_predicate_prepared(&polygons(), &area, SpatialPredicate::Within).unwrap(),
+        );
+    }
+
+    #[test]
+    fn predicates_between_arrays() {
+        let left = polygons();
+        let right: PolygonArray = (vec![Some(square(0., 0., 4.)); 6], Dimension::XY).into();
+        assert_eq!(
+            relate_predicate(&right, &left, SpatialPredicate::Contains).unwrap(),
+            relate_predicate(&left, &right, SpatialPredicate::Within).unwrap(),
+        );
+        assert_eq!(
+            relate_pattern(&left, &right, "T*F**F***").unwrap(),
+            relate_predicate(&left, &right, SpatialPredicate::Within).unwrap(),
+        );
+        assert!(relate_pattern(&left, &right, "invalid").is_err());
+        assert!(
+            relate_predicate(&left, &polygons().slice(0, 1), SpatialPredicate::Covers).is_err()
+        );
+    }
+}
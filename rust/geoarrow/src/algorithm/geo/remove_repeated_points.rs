@@ -66,7 +66,53 @@ iter_geo_impl!(
 );
 iter_geo_impl!(MultiPolygonArray, MultiPolygonBuilder, push_multi_polygon);
 // iter_geo_impl!(MixedGeometryArray, MixedGeometryBuilder, push_geometry);
-// iter_geo_impl!(GeometryCollectionArray, geo::GeometryCollection);
+
+impl RemoveRepeatedPoints for GeometryCollectionArray {
+    type Output = Result<Self>;
+
+    fn remove_repeated_points(&self) -> Self::Output {
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.remove_repeated_points()))
+            .collect();
+
+        Ok(GeometryCollectionBuilder::from_nullable_geometries(
+            output_geoms.as_slice(),
+            self.dimension(),
+            self.coord_type(),
+            self.metadata().clone(),
+            false,
+        )?
+        .finish())
+    }
+}
+
+impl RemoveRepeatedPoints for GeometryArray {
+    type Output = Result<Self>;
+
+    fn remove_repeated_points(&self) -> Self::Output {
+        // The geometries are converted to two-dimensional geo geometries, so Z values would be
+        // lost
+        if self.has_dimension(Dimension::XYZ) {
+            return Err(GeoArrowError::IncorrectType(
+                "remove_repeated_points only supports two-dimensional geometries".into(),
+            ));
+        }
+
+        let output_geoms: Vec<Option<geo::Geometry>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.remove_repeated_points()))
+            .collect();
+
+        Ok(GeometryBuilder::from_nullable_geometries(
+            output_geoms.as_slice(),
+            self.coord_type(),
+            self.metadata().clone(),
+            false,
+        )?
+        .finish())
+    }
+}
 
 impl RemoveRepeatedPoints for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
@@ -85,7 +131,12 @@ impl RemoveRepeatedPoints for &dyn NativeArray {
             }
             MultiPolygon(_, XY) => Arc::new(self.as_multi_polygon().remove_repeated_points()),
             // Mixed(_, XY) => self.as_mixed().remove_repeated_points(),
-            // GeometryCollection(_, XY) => self.as_geometry_collection().remove_repeated_points(),
+            GeometryCollection(_, XY) => {
+                Arc::new(self.as_geometry_collection().remove_repeated_points()?)
+            }
+            Geometry(_) if !self.as_geometry().has_dimension(XYZ) => {
+                Arc::new(self.as_geometry().remove_repeated_points()?)
+            }
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
         Ok(result)
@@ -143,3 +194,40 @@ impl RemoveRepeatedPoints for &dyn ChunkedNativeArray {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use geo::line_string;
+
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn geometry_array() {
+        let line_string: geo::Geometry =
+            line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 1.)].into();
+        let array = GeometryBuilder::from_nullable_geometries(
+            &[Some(line_string), None],
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+
+        let output = array.remove_repeated_points().unwrap();
+        assert_eq!(
+            output.value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into()
+        );
+        assert!(output.get(1).is_none());
+
+        // Z values would be lost
+        let points = point::point_z_array();
+        let mut builder = GeometryBuilder::new();
+        builder.push_point(Some(&points.value(0))).unwrap();
+        let array = builder.finish();
+        assert!(array.remove_repeated_points().is_err());
+        assert!(array.as_ref().remove_repeated_points().is_err());
+    }
+}
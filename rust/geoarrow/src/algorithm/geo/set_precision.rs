@@ -0,0 +1,324 @@
+use geo::{Area, Coord, LineString};
+
+use crate::algorithm::geo::validation::check;
+use crate::algorithm::native::kernel::{has_z_or_m, iter_geometries};
+use crate::array::{GeometryArray, GeometryBuilder};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::NativeArray;
+
+/// Reduce the precision of every geometry of an array by snapping its coordinates to a grid of
+/// `grid_size`.
+///
+/// Each coordinate is rounded to the nearest multiple of `grid_size`. Then consecutive repeated
+/// coordinates are removed, as are spikes, where a line string or ring goes back along itself.
+/// Parts that collapse are removed: line strings left with fewer than two distinct points, and
+/// polygon rings left with no area. A polygon whose exterior ring collapses is removed along with
+/// its holes. A grid size of zero keeps the coordinates as they are, but still removes repeated
+/// coordinates, spikes and collapsed parts. Rects and triangles are reduced as polygons.
+///
+/// Collapsed segments elsewhere are kept: a line string that overlaps itself other than at a
+/// spike, or rings that end up sharing an edge, are not merged.
+///
+/// Snapping can make a valid geometry invalid, e.g. when two rings end up crossing. If
+/// `validate` is true, each reduced geometry is checked as by
+/// [`is_valid_detail`][super::is_valid_detail], and an error is returned for the first invalid
+/// one.
+///
+/// The output is null where the geometry is null or collapses entirely. Returns an error if
+/// `grid_size` is negative or NaN, or if the array has Z or M values, which are not supported
+/// yet.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::set_precision;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let line_string = geo::line_string![(x: 0.1, y: 0.2), (x: 0.4, y: 0.1), (x: 2.3, y: 0.9)];
+/// let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+///
+/// let reduced = set_precision(&array, 1., false).unwrap();
+/// assert_eq!(
+///     reduced.value_as_geo(0),
+///     geo::Geometry::LineString(geo::line_string![(x: 0., y: 0.), (x: 2., y: 1.)])
+/// );
+/// ```
+pub fn set_precision(
+    array: &dyn NativeArray,
+    grid_size: f64,
+    validate: bool,
+) -> Result<GeometryArray> {
+    if grid_size.is_nan() || grid_size < 0. {
+        return Err(GeoArrowError::General(format!(
+            "Grid size must be a non-negative number, got {grid_size}"
+        )));
+    }
+    if has_z_or_m(array) {
+        return Err(GeoArrowError::NotYetImplemented(
+            "set_precision with Z or M values".to_string(),
+        ));
+    }
+
+    let mut builder =
+        GeometryBuilder::new_with_options(array.coord_type(), array.metadata(), false);
+    for (i, geom) in iter_geometries(array).enumerate() {
+        let reduced = geom.and_then(|geom| reduce(&geometry_to_geo(&geom), grid_size));
+        if validate {
+            if let Some((reason, location)) = reduced.as_ref().and_then(check) {
+                return Err(GeoArrowError::General(format!(
+                    "Geometry at index {i} is invalid after reducing precision: {reason} at \
+                    ({}, {})",
+                    location.x, location.y
+                )));
+            }
+        }
+        builder.push_geometry(reduced.as_ref())?;
+    }
+    Ok(builder.finish())
+}
+
+/// Snap a coordinate to the grid, or keep it if the grid size is zero.
+fn snap_coord(coord: Coord, grid_size: f64) -> Coord {
+    if grid_size == 0. {
+        return coord;
+    }
+    Coord {
+        x: (coord.x / grid_size).round() * grid_size,
+        y: (coord.y / grid_size).round() * grid_size,
+    }
+}
+
+/// Whether the path from `a` through `b` to `c` goes back along itself at `b`.
+fn is_spike(a: Coord, b: Coord, c: Coord) -> bool {
+    let (ab, bc) = (b - a, c - b);
+    ab.x * bc.y - ab.y * bc.x == 0. && ab.x * bc.x + ab.y * bc.y < 0.
+}
+
+/// Snap the coordinates of a line string to the grid and remove repeated coordinates and spikes.
+fn snap_coords(line_string: &LineString, grid_size: f64) -> Vec<Coord> {
+    let mut coords: Vec<Coord> = Vec::with_capacity(line_string.0.len());
+    for coord in line_string.coords() {
+        let coord = snap_coord(*coord, grid_size);
+        if coords.last() == Some(&coord) {
+            continue;
+        }
+        coords.push(coord);
+        // Removing a spike can leave a repeated coordinate or another spike behind it
+        while let [.., a, b, c] = coords[..] {
+            if !is_spike(a, b, c) {
+                break;
+            }
+            coords.remove(coords.len() - 2);
+            if a == c {
+                coords.pop();
+            }
+        }
+    }
+    coords
+}
+
+/// Reduce a line string, or `None` if it collapses to a point.
+fn reduce_line_string(line_string: &LineString, grid_size: f64) -> Option<LineString> {
+    let coords = snap_coords(line_string, grid_size);
+    (coords.len() >= 2).then(|| LineString::new(coords))
+}
+
+/// Reduce a ring, or `None` if it collapses to a point or line.
+fn reduce_ring(ring: &LineString, grid_size: f64) -> Option<LineString> {
+    let mut coords = snap_coords(ring, grid_size);
+    // Remove the closing coordinate, and the repeated coordinates and spikes around it
+    loop {
+        let n = coords.len();
+        if n < 3 {
+            return None;
+        }
+        if coords[0] == coords[n - 1] || is_spike(coords[n - 2], coords[n - 1], coords[0]) {
+            coords.pop();
+        } else if is_spike(coords[n - 1], coords[0], coords[1]) {
+            coords.remove(0);
+        } else {
+            break;
+        }
+    }
+    coords.push(coords[0]);
+    let ring = LineString::new(coords);
+    let collapsed = geo::Polygon::new(ring.clone(), vec![]).unsigned_area() == 0.;
+    (!collapsed).then_some(ring)
+}
+
+/// Reduce a polygon, or `None` if its exterior ring collapses.
+fn reduce_polygon(polygon: &geo::Polygon, grid_size: f64) -> Option<geo::Polygon> {
+    let exterior = reduce_ring(polygon.exterior(), grid_size)?;
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter_map(|ring| reduce_ring(ring, grid_size))
+        .collect();
+    Some(geo::Polygon::new(exterior, interiors))
+}
+
+/// Reduce a geometry, or `None` if it collapses entirely.
+fn reduce(geom: &geo::Geometry, grid_size: f64) -> Option<geo::Geometry> {
+    match geom {
+        geo::Geometry::Point(point) => Some(geo::Point(snap_coord(point.0, grid_size)).into()),
+        geo::Geometry::MultiPoint(multi_point) => Some(
+            geo::MultiPoint::new(
+                multi_point
+                    .iter()
+                    .map(|point| geo::Point(snap_coord(point.0, grid_size)))
+                    .collect(),
+            )
+            .into(),
+        ),
+        geo::Geometry::Line(line) => {
+            reduce_line_string(&LineString::new(vec![line.start, line.end]), grid_size)
+                .map(Into::into)
+        }
+        geo::Geometry::LineString(line_string) => {
+            reduce_line_string(line_string, grid_size).map(Into::into)
+        }
+        geo::Geometry::MultiLineString(multi_line_string) => {
+            let line_strings = multi_line_string
+                .iter()
+                .filter_map(|line_string| reduce_line_string(line_string, grid_size))
+                .collect::<Vec<_>>();
+            (!line_strings.is_empty()).then(|| geo::MultiLineString::new(line_strings).into())
+        }
+        geo::Geometry::Polygon(polygon) => reduce_polygon(polygon, grid_size).map(Into::into),
+        geo::Geometry::MultiPolygon(multi_polygon) => {
+            let polygons = multi_polygon
+                .iter()
+                .filter_map(|polygon| reduce_polygon(polygon, grid_size))
+                .collect::<Vec<_>>();
+            (!polygons.is_empty()).then(|| geo::MultiPolygon::new(polygons).into())
+        }
+        geo::Geometry::Rect(rect) => reduce_polygon(&rect.to_polygon(), grid_size).map(Into::into),
+        geo::Geometry::Triangle(triangle) => {
+            reduce_polygon(&triangle.to_polygon(), grid_size).map(Into::into)
+        }
+        geo::Geometry::GeometryCollection(collection) => {
+            let geoms = collection
+                .iter()
+                .filter_map(|geom| reduce(geom, grid_size))
+                .collect::<Vec<_>>();
+            (!geoms.is_empty()).then(|| geo::GeometryCollection::from(geoms).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo::{line_string, polygon};
+
+    use super::*;
+    use crate::array::{MultiPolygonArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn snap_to_grid() {
+        let polygon = polygon!(
+            exterior: [(x: 0.1, y: 0.1), (x: 10.2, y: 0.), (x: 9.9, y: 10.4), (x: 0., y: 9.8)],
+            interiors: [
+                [(x: 2., y: 2.), (x: 2.2, y: 2.1), (x: 2.1, y: 2.2)],
+                [(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.)],
+            ],
+        );
+        let tiny = polygon![(x: 20., y: 20.), (x: 20.2, y: 20.), (x: 20.2, y: 20.2)];
+        let array: PolygonArray = (vec![Some(polygon), None, Some(tiny)], Dimension::XY).into();
+
+        let reduced = set_precision(&array, 1., true).unwrap();
+        // The small hole collapses, and the larger one is kept
+        let expected = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.)],
+            ],
+        );
+        assert_eq!(reduced.value_as_geo(0), geo::Geometry::Polygon(expected));
+        assert!(reduced.get(1).is_none());
+        assert!(reduced.get(2).is_none());
+    }
+
+    #[test]
+    fn remove_collapsed_parts() {
+        let lines = geo::Geometry::MultiLineString(geo::MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 0.1, y: 0.1)],
+            line_string![(x: 0., y: 0.), (x: 0.1, y: 0.1), (x: 3., y: 0.)],
+        ]));
+        assert_eq!(
+            reduce(&lines, 1.),
+            Some(
+                geo::MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 3., y: 0.)]])
+                    .into()
+            )
+        );
+
+        // Zero keeps coordinates, but still drops repeated ones
+        let line = geo::Geometry::LineString(
+            line_string![(x: 0.5, y: 0.5), (x: 0.5, y: 0.5), (x: 1., y: 1.)],
+        );
+        assert_eq!(
+            reduce(&line, 0.),
+            Some(line_string![(x: 0.5, y: 0.5), (x: 1., y: 1.)].into())
+        );
+    }
+
+    #[test]
+    fn remove_spikes() {
+        // A line string that goes back along itself
+        let line = geo::Geometry::LineString(
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+        );
+        assert_eq!(
+            reduce(&line, 0.),
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)].into())
+        );
+
+        // A square with a spike in its exterior ring, and at the closing coordinate
+        let square = polygon![
+            (x: 0., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let expected = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        assert_eq!(reduce(&square.into(), 0.), Some(expected.into()));
+        let closing_spike = polygon![
+            (x: -1., y: 0.), (x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let reduced = reduce(&closing_spike.into(), 0.).unwrap();
+        assert_eq!(reduced.unsigned_area(), 4.);
+        let geo::Geometry::Polygon(reduced) = reduced else {
+            panic!("Expected a polygon");
+        };
+        assert_eq!(reduced.exterior().0.len(), 5);
+
+        // A ring that collapses to a spike
+        let spike = polygon![(x: 0., y: 0.), (x: 1., y: 0.1), (x: 2., y: 0.)];
+        assert_eq!(reduce(&spike.into(), 1.), None);
+    }
+
+    #[test]
+    fn reject_z() {
+        let array = crate::test::point::point_z_array();
+        assert!(set_precision(&array, 1., false).is_err());
+    }
+
+    #[test]
+    fn validate() {
+        // Two disjoint polygons that snap onto a shared edge
+        let multi_polygon = geo::MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 2.4, y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2.4, y: 2.)],
+        ]);
+        let array: MultiPolygonArray = (vec![multi_polygon].as_slice(), Dimension::XY).into();
+        assert!(set_precision(&array, 1., false).is_ok());
+        assert!(set_precision(&array, 1., true).is_err());
+
+        assert!(set_precision(&array, -1., false).is_err());
+        assert!(set_precision(&array, f64::NAN, false).is_err());
+    }
+}
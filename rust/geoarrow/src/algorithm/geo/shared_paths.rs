@@ -0,0 +1,218 @@
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{Coord, Line};
+
+use crate::algorithm::geo::utils::binary_geometry_map;
+use crate::array::GeometryArray;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+
+/// Find the paths shared by the lineal geometries at the same index of two arrays.
+///
+/// Each output geometry is a geometry collection of two multi line strings: the shared paths
+/// that run in the same direction in both geometries, then those that run in opposite
+/// directions. Both are in the order and direction of the paths along the geometry of `left`.
+/// Paths that touch at a point aren't shared.
+///
+/// The output is null where either geometry is null. Returns an error if the arrays have
+/// different lengths, or a geometry isn't a line string or multi line string.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::shared_paths;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let left = geo::line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+/// let left: LineStringArray = (vec![left].as_slice(), Dimension::XY).into();
+/// let right = geo::line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.)];
+/// let right: LineStringArray = (vec![right].as_slice(), Dimension::XY).into();
+///
+/// let paths = shared_paths(&left, &right).unwrap();
+/// let expected = geo::GeometryCollection::from(vec![
+///     geo::Geometry::from(geo::MultiLineString::new(vec![
+///         geo::line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+///     ])),
+///     geo::Geometry::from(geo::MultiLineString::new(vec![])),
+/// ]);
+/// assert_eq!(paths.value_as_geo(0), geo::Geometry::GeometryCollection(expected));
+/// ```
+pub fn shared_paths(left: &dyn NativeArray, right: &dyn NativeArray) -> Result<GeometryArray> {
+    binary_geometry_map(left, right, |_, left, right| {
+        let (forward, backward) = paths(&lines(&left)?, &lines(&right)?);
+        Ok(Some(geo::Geometry::GeometryCollection(
+            geo::GeometryCollection::new_from(vec![forward.into(), backward.into()]),
+        )))
+    })
+}
+
+/// The line strings of a lineal geometry.
+fn lines(geom: &geo::Geometry) -> Result<Vec<geo::LineString>> {
+    match geom {
+        geo::Geometry::Line(line) => Ok(vec![geo::LineString::new(vec![line.start, line.end])]),
+        geo::Geometry::LineString(line_string) => Ok(vec![line_string.clone()]),
+        geo::Geometry::MultiLineString(multi_line_string) => Ok(multi_line_string.0.clone()),
+        _ => Err(GeoArrowError::General(
+            "Shared paths are only defined for line strings and multi line strings".to_string(),
+        )),
+    }
+}
+
+/// The shared paths in the same and opposite directions.
+fn paths(
+    left: &[geo::LineString],
+    right: &[geo::LineString],
+) -> (geo::MultiLineString, geo::MultiLineString) {
+    let right_lines = right
+        .iter()
+        .flat_map(|line_string| line_string.lines())
+        .filter(|line| line.start != line.end)
+        .collect::<Vec<_>>();
+
+    let mut forward = vec![];
+    let mut backward = vec![];
+    for line_string in left {
+        // The path being extended, and whether it runs forward
+        let mut current: Option<(Vec<Coord>, bool)> = None;
+        for line in line_string.lines() {
+            if line.start == line.end {
+                continue;
+            }
+            for (overlap, is_forward) in overlaps(&line, &right_lines) {
+                match &mut current {
+                    Some((coords, direction))
+                        if *direction == is_forward && coords.last() == Some(&overlap.start) =>
+                    {
+                        coords.push(overlap.end)
+                    }
+                    _ => {
+                        if let Some((coords, direction)) = current.take() {
+                            push_path(coords, direction, &mut forward, &mut backward);
+                        }
+                        current = Some((vec![overlap.start, overlap.end], is_forward));
+                    }
+                }
+            }
+        }
+        if let Some((coords, direction)) = current {
+            push_path(coords, direction, &mut forward, &mut backward);
+        }
+    }
+    (
+        geo::MultiLineString::new(forward),
+        geo::MultiLineString::new(backward),
+    )
+}
+
+fn push_path(
+    coords: Vec<Coord>,
+    is_forward: bool,
+    forward: &mut Vec<geo::LineString>,
+    backward: &mut Vec<geo::LineString>,
+) {
+    let path = geo::LineString::new(coords);
+    if is_forward {
+        forward.push(path);
+    } else {
+        backward.push(path);
+    }
+}
+
+/// The parts of a segment that overlap the other segments, in the direction of the segment and
+/// sorted along it, with whether each overlapping segment runs in the same direction.
+fn overlaps(line: &Line, others: &[Line]) -> Vec<(Line, bool)> {
+    let delta = line.delta();
+    let position =
+        |coord: Coord| (coord.x - line.start.x) * delta.x + (coord.y - line.start.y) * delta.y;
+
+    let mut overlaps = others
+        .iter()
+        .filter_map(|other| match line_intersection(*line, *other) {
+            Some(LineIntersection::Collinear { intersection })
+                if intersection.start != intersection.end =>
+            {
+                let overlap = if position(intersection.start) <= position(intersection.end) {
+                    intersection
+                } else {
+                    Line::new(intersection.end, intersection.start)
+                };
+                let other_delta = other.delta();
+                let is_forward = delta.x * other_delta.x + delta.y * other_delta.y > 0.;
+                Some((overlap, is_forward))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    overlaps.sort_by(|a, b| position(a.0.start).total_cmp(&position(b.0.start)));
+    overlaps.dedup_by(|a, b| a.0 == b.0);
+    overlaps
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+    use geo::{line_string, point};
+
+    #[test]
+    fn shared_paths_directions() {
+        let left =
+            vec![line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)]];
+        let right = vec![
+            line_string![(x: 1., y: 0.), (x: 3., y: 0.)],
+            line_string![(x: 4., y: 3.), (x: 4., y: 1.)],
+            // Touching at a point isn't shared
+            line_string![(x: 0., y: -1.), (x: 0., y: 1.)],
+        ];
+        let (forward, backward) = paths(&left, &right);
+        assert_eq!(
+            forward,
+            geo::MultiLineString::new(vec![
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)]
+            ])
+        );
+        assert_eq!(
+            backward,
+            geo::MultiLineString::new(vec![line_string![(x: 4., y: 1.), (x: 4., y: 3.)]])
+        );
+    }
+
+    #[test]
+    fn shared_paths_arrays() {
+        let left: LineStringArray = (
+            vec![Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)]), None],
+            Dimension::XY,
+        )
+            .into();
+        let right: LineStringArray = (
+            vec![
+                Some(line_string![(x: 3., y: 0.), (x: 1., y: 0.)]),
+                Some(line_string![(x: 3., y: 0.), (x: 1., y: 0.)]),
+            ],
+            Dimension::XY,
+        )
+            .into();
+        let output = shared_paths(&left, &right).unwrap();
+        assert_eq!(output.len(), 2);
+        let expected = geo::GeometryCollection::new_from(vec![
+            geo::MultiLineString::new(vec![]).into(),
+            geo::MultiLineString::new(vec![line_string![(x: 1., y: 0.), (x: 3., y: 0.)]]).into(),
+        ]);
+        assert_eq!(
+            output.value_as_geo(0),
+            geo::Geometry::GeometryCollection(expected)
+        );
+        assert!(output.is_null(1));
+
+        let points: PointArray = (
+            vec![point!(x: 0., y: 0.), point!(x: 1., y: 0.)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        assert!(shared_paths(&left, &points).is_err());
+    }
+}
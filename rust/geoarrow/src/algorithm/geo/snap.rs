@@ -0,0 +1,296 @@
+use arrow_array::types::Float64Type;
+use geo::{Coord, Line};
+
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::utils::binary_geometry_map;
+use crate::array::GeometryArray;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+
+/// Snap the vertices and segments of each geometry of `array` to the vertices of the reference
+/// geometry at the same index of `reference`.
+///
+/// Each vertex within `tolerance` of a reference vertex is moved onto the nearest one. Then,
+/// reference vertices within `tolerance` of a segment, but not of its end points, are inserted
+/// into the segment in order along it. Polygon rings stay closed, and rects and triangles are
+/// snapped as polygons.
+///
+/// The output is null where either geometry or the tolerance is null. Returns an error if the
+/// arrays have different lengths, or a tolerance is negative or NaN.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::snap;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let line_string = geo::line_string![(x: 0., y: 0.), (x: 4., y: 0.1)];
+/// let line_strings: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+/// let reference: PointArray = (vec![geo::point!(x: 4., y: 0.)].as_slice(), Dimension::XY).into();
+///
+/// let snapped = snap(&line_strings, &reference, &0.5.into()).unwrap();
+/// assert_eq!(
+///     snapped.value_as_geo(0),
+///     geo::Geometry::LineString(geo::line_string![(x: 0., y: 0.), (x: 4., y: 0.)])
+/// );
+/// ```
+pub fn snap(
+    array: &dyn NativeArray,
+    reference: &dyn NativeArray,
+    tolerance: &BroadcastablePrimitive<Float64Type>,
+) -> Result<GeometryArray> {
+    let tolerances = tolerance.into_iter().take(array.len()).collect::<Vec<_>>();
+    binary_geometry_map(array, reference, |i, geom, reference| {
+        let Some(tolerance) = tolerances.get(i).copied().flatten() else {
+            return Ok(None);
+        };
+        if tolerance.is_nan() || tolerance < 0. {
+            return Err(GeoArrowError::General(format!(
+                "Snapping tolerance must be a non-negative number, got {tolerance}"
+            )));
+        }
+        let snapper = Snapper::new(&reference, tolerance);
+        Ok(Some(snapper.snap(&geom)))
+    })
+}
+
+/// The vertices of a reference geometry, to which other geometries are snapped.
+struct Snapper {
+    vertices: Vec<Coord>,
+    tolerance: f64,
+}
+
+impl Snapper {
+    fn new(reference: &geo::Geometry, tolerance: f64) -> Self {
+        let mut vertices = vec![];
+        push_vertices(reference, &mut vertices);
+        vertices.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        vertices.dedup();
+        Self {
+            vertices,
+            tolerance,
+        }
+    }
+
+    fn snap(&self, geom: &geo::Geometry) -> geo::Geometry {
+        match geom {
+            geo::Geometry::Point(point) => geo::Point(self.snap_vertex(point.0)).into(),
+            geo::Geometry::MultiPoint(multi_point) => multi_point
+                .iter()
+                .map(|point| geo::Point(self.snap_vertex(point.0)))
+                .collect::<geo::MultiPoint>()
+                .into(),
+            geo::Geometry::Line(line) => self
+                .snap_line_string(&geo::LineString::new(vec![line.start, line.end]))
+                .into(),
+            geo::Geometry::LineString(line_string) => self.snap_line_string(line_string).into(),
+            geo::Geometry::MultiLineString(multi_line_string) => geo::MultiLineString::new(
+                multi_line_string
+                    .iter()
+                    .map(|line_string| self.snap_line_string(line_string))
+                    .collect(),
+            )
+            .into(),
+            geo::Geometry::Polygon(polygon) => self.snap_polygon(polygon).into(),
+            geo::Geometry::MultiPolygon(multi_polygon) => geo::MultiPolygon::new(
+                multi_polygon
+                    .iter()
+                    .map(|polygon| self.snap_polygon(polygon))
+                    .collect(),
+            )
+            .into(),
+            geo::Geometry::Rect(rect) => self.snap_polygon(&rect.to_polygon()).into(),
+            geo::Geometry::Triangle(triangle) => self.snap_polygon(&triangle.to_polygon()).into(),
+            geo::Geometry::GeometryCollection(collection) => geo::GeometryCollection::new_from(
+                collection.iter().map(|geom| self.snap(geom)).collect(),
+            )
+            .into(),
+        }
+    }
+
+    /// The nearest reference vertex within the tolerance, or the vertex itself.
+    fn snap_vertex(&self, coord: Coord) -> Coord {
+        self.vertices
+            .iter()
+            .map(|vertex| (distance(*vertex, coord), *vertex))
+            .filter(|(distance, _)| *distance <= self.tolerance)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map_or(coord, |(_, vertex)| vertex)
+    }
+
+    fn snap_line_string(&self, line_string: &geo::LineString) -> geo::LineString {
+        let vertices = line_string
+            .0
+            .iter()
+            .map(|coord| self.snap_vertex(*coord))
+            .collect::<Vec<_>>();
+
+        let mut coords: Vec<Coord> = Vec::with_capacity(vertices.len());
+        for pair in vertices.windows(2) {
+            let line = Line::new(pair[0], pair[1]);
+            coords.push(line.start);
+            coords.extend(self.segment_vertices(&line));
+        }
+        coords.extend(vertices.last());
+        coords.dedup();
+
+        // Keep collapsed line strings valid
+        if coords.len() == 1 && vertices.len() > 1 {
+            coords.push(coords[0]);
+        }
+        geo::LineString::new(coords)
+    }
+
+    fn snap_polygon(&self, polygon: &geo::Polygon) -> geo::Polygon {
+        let snap_ring = |ring: &geo::LineString| {
+            let mut ring = self.snap_line_string(ring);
+            ring.close();
+            ring
+        };
+        geo::Polygon::new(
+            snap_ring(polygon.exterior()),
+            polygon.interiors().iter().map(snap_ring).collect(),
+        )
+    }
+
+    /// The reference vertices within the tolerance of the interior of a segment, sorted along it.
+    fn segment_vertices(&self, line: &Line) -> Vec<Coord> {
+        let delta = line.delta();
+        let length_squared = delta.x * delta.x + delta.y * delta.y;
+        if length_squared == 0. {
+            return vec![];
+        }
+
+        let mut vertices = self
+            .vertices
+            .iter()
+            .filter(|vertex| {
+                distance(**vertex, line.start) > self.tolerance
+                    && distance(**vertex, line.end) > self.tolerance
+            })
+            .filter_map(|vertex| {
+                let t = ((vertex.x - line.start.x) * delta.x + (vertex.y - line.start.y) * delta.y)
+                    / length_squared;
+                let projected = line.start + delta * t;
+                (t > 0. && t < 1. && distance(*vertex, projected) <= self.tolerance)
+                    .then_some((t, *vertex))
+            })
+            .collect::<Vec<_>>();
+        vertices.sort_by(|a, b| a.0.total_cmp(&b.0));
+        vertices.into_iter().map(|(_, vertex)| vertex).collect()
+    }
+}
+
+fn distance(a: Coord, b: Coord) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+fn push_vertices(geom: &geo::Geometry, vertices: &mut Vec<Coord>) {
+    match geom {
+        geo::Geometry::Point(point) => vertices.push(point.0),
+        geo::Geometry::MultiPoint(multi_point) => {
+            vertices.extend(multi_point.iter().map(|point| point.0))
+        }
+        geo::Geometry::Line(line) => vertices.extend([line.start, line.end]),
+        geo::Geometry::LineString(line_string) => vertices.extend(line_string.coords()),
+        geo::Geometry::MultiLineString(multi_line_string) => {
+            vertices.extend(multi_line_string.iter().flat_map(|ls| ls.coords()))
+        }
+        geo::Geometry::Polygon(polygon) => push_polygon_vertices(polygon, vertices),
+        geo::Geometry::MultiPolygon(multi_polygon) => multi_polygon
+            .iter()
+            .for_each(|polygon| push_polygon_vertices(polygon, vertices)),
+        geo::Geometry::Rect(rect) => push_polygon_vertices(&rect.to_polygon(), vertices),
+        geo::Geometry::Triangle(triangle) => {
+            push_polygon_vertices(&triangle.to_polygon(), vertices)
+        }
+        geo::Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .for_each(|geom| push_vertices(geom, vertices)),
+    }
+}
+
+fn push_polygon_vertices(polygon: &geo::Polygon, vertices: &mut Vec<Coord>) {
+    vertices.extend(polygon.exterior().coords());
+    polygon
+        .interiors()
+        .iter()
+        .for_each(|interior| vertices.extend(interior.coords()));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+    use arrow_array::Float64Array;
+    use geo::{line_string, point, polygon};
+
+    #[test]
+    fn snap_vertices_and_segments() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let reference: geo::Geometry =
+            line_string![(x: 0.1, y: 0.1), (x: 5., y: 0.2), (x: 20., y: 20.)].into();
+        let snapped = Snapper::new(&reference, 0.5).snap(&line_string.into());
+        assert_eq!(
+            snapped,
+            geo::Geometry::LineString(
+                line_string![(x: 0.1, y: 0.1), (x: 5., y: 0.2), (x: 10., y: 0.)]
+            )
+        );
+
+        // Zero tolerance leaves the geometry unchanged
+        let polygon: geo::Geometry =
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)].into();
+        assert_eq!(Snapper::new(&reference, 0.).snap(&polygon), polygon);
+    }
+
+    #[test]
+    fn snap_polygon_stays_closed() {
+        let polygon: geo::Geometry =
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)].into();
+        let reference: geo::Geometry = point!(x: 0.2, y: -0.1).into();
+        let geo::Geometry::Polygon(snapped) = Snapper::new(&reference, 0.5).snap(&polygon) else {
+            panic!("expected a polygon");
+        };
+        assert!(snapped.exterior().is_closed());
+        assert_eq!(snapped.exterior().0[0], geo::coord! { x: 0.2, y: -0.1 });
+    }
+
+    #[test]
+    fn snap_arrays() {
+        let line_strings: LineStringArray = (
+            vec![
+                line_string![(x: 0., y: 0.), (x: 4., y: 0.1)],
+                line_string![(x: 0., y: 0.), (x: 4., y: 0.1)],
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let reference: PolygonArray = (
+            vec![
+                polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.)],
+                polygon![(x: 4., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.)],
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+
+        let tolerance = BroadcastablePrimitive::Array(Float64Array::from(vec![Some(0.5), None]));
+        let output = snap(&line_strings, &reference, &tolerance).unwrap();
+        assert_eq!(output.len(), 2);
+        assert_eq!(
+            output.value_as_geo(0),
+            geo::Geometry::LineString(line_string![(x: 0., y: 0.), (x: 4., y: 0.)])
+        );
+        assert!(output.is_null(1));
+
+        assert!(snap(&line_strings, &reference, &(-1.).into()).is_err());
+    }
+}
@@ -0,0 +1,523 @@
+use std::collections::{HashMap, HashSet};
+
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{Area, Centroid, Contains, Coord, InteriorPoint, Intersects, Line};
+
+use crate::algorithm::geo::utils::binary_geometry_map;
+use crate::array::GeometryArray;
+use crate::error::Result;
+use crate::NativeArray;
+
+/// Split each geometry of `array` by the blade geometry at the same index of `blade`.
+///
+/// Line strings are split wherever they touch a blade point or cross a blade line, or polygon
+/// boundary. Polygons are split into the faces formed by the blade lines that cross them; the
+/// parts of a blade that end inside a polygon don't split it. Each output geometry is a
+/// geometry collection of the parts, in the order of the input. Points and geometries that a
+/// blade doesn't touch are returned as a collection of the unchanged input.
+///
+/// The output is null where either geometry is null. Returns an error if the arrays have
+/// different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::split;
+/// use geoarrow::array::{LineStringArray, PointArray};
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let line_string = geo::line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+/// let line_strings: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+/// let blades: PointArray = (vec![geo::point!(x: 1., y: 0.)].as_slice(), Dimension::XY).into();
+///
+/// let parts = split(&line_strings, &blades).unwrap();
+/// let expected = geo::GeometryCollection::from(vec![
+///     geo::Geometry::from(geo::line_string![(x: 0., y: 0.), (x: 1., y: 0.)]),
+///     geo::Geometry::from(geo::line_string![(x: 1., y: 0.), (x: 4., y: 0.)]),
+/// ]);
+/// assert_eq!(parts.value_as_geo(0), geo::Geometry::GeometryCollection(expected));
+/// ```
+pub fn split(array: &dyn NativeArray, blade: &dyn NativeArray) -> Result<GeometryArray> {
+    binary_geometry_map(array, blade, |_, geom, blade| {
+        Ok(Some(geo::Geometry::GeometryCollection(split_geometry(
+            &geom, &blade,
+        ))))
+    })
+}
+
+fn split_geometry(geom: &geo::Geometry, blade: &geo::Geometry) -> geo::GeometryCollection {
+    let mut blade_parts = Blade::default();
+    blade_parts.push(blade);
+    let mut parts = vec![];
+    push_parts(geom, &blade_parts, &mut parts);
+    geo::GeometryCollection::new_from(parts)
+}
+
+fn push_parts(geom: &geo::Geometry, blade: &Blade, parts: &mut Vec<geo::Geometry>) {
+    match geom {
+        geo::Geometry::Line(line) => {
+            let line_string = geo::LineString::new(vec![line.start, line.end]);
+            parts.extend(
+                split_line_string(&line_string, blade)
+                    .into_iter()
+                    .map(Into::into),
+            )
+        }
+        geo::Geometry::LineString(line_string) => parts.extend(
+            split_line_string(line_string, blade)
+                .into_iter()
+                .map(Into::into),
+        ),
+        geo::Geometry::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string {
+                parts.extend(
+                    split_line_string(line_string, blade)
+                        .into_iter()
+                        .map(Into::into),
+                )
+            }
+        }
+        geo::Geometry::Polygon(polygon) => {
+            parts.extend(split_polygon(polygon, blade).into_iter().map(Into::into))
+        }
+        geo::Geometry::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon {
+                parts.extend(split_polygon(polygon, blade).into_iter().map(Into::into))
+            }
+        }
+        geo::Geometry::Rect(rect) => parts.extend(
+            split_polygon(&rect.to_polygon(), blade)
+                .into_iter()
+                .map(Into::into),
+        ),
+        geo::Geometry::Triangle(triangle) => parts.extend(
+            split_polygon(&triangle.to_polygon(), blade)
+                .into_iter()
+                .map(Into::into),
+        ),
+        geo::Geometry::GeometryCollection(collection) => {
+            for geom in collection {
+                push_parts(geom, blade, parts);
+            }
+        }
+        geo::Geometry::Point(_) | geo::Geometry::MultiPoint(_) => parts.push(geom.clone()),
+    }
+}
+
+/// The points and segments of a blade geometry, where the boundaries of polygons are segments.
+#[derive(Default)]
+struct Blade {
+    points: Vec<Coord>,
+    lines: Vec<Line>,
+}
+
+impl Blade {
+    fn push(&mut self, geom: &geo::Geometry) {
+        match geom {
+            geo::Geometry::Point(point) => self.points.push(point.0),
+            geo::Geometry::MultiPoint(multi_point) => {
+                self.points.extend(multi_point.iter().map(|point| point.0))
+            }
+            geo::Geometry::Line(line) => self.push_lines([*line]),
+            geo::Geometry::LineString(line_string) => self.push_lines(line_string.lines()),
+            geo::Geometry::MultiLineString(multi_line_string) => multi_line_string
+                .iter()
+                .for_each(|line_string| self.push_lines(line_string.lines())),
+            geo::Geometry::Polygon(polygon) => self.push_polygon(polygon),
+            geo::Geometry::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .for_each(|polygon| self.push_polygon(polygon)),
+            geo::Geometry::Rect(rect) => self.push_polygon(&rect.to_polygon()),
+            geo::Geometry::Triangle(triangle) => self.push_polygon(&triangle.to_polygon()),
+            geo::Geometry::GeometryCollection(collection) => {
+                collection.iter().for_each(|geom| self.push(geom))
+            }
+        }
+    }
+
+    fn push_lines(&mut self, lines: impl IntoIterator<Item = Line>) {
+        self.lines
+            .extend(lines.into_iter().filter(|line| line.start != line.end));
+    }
+
+    fn push_polygon(&mut self, polygon: &geo::Polygon) {
+        self.push_lines(polygon.exterior().lines());
+        polygon
+            .interiors()
+            .iter()
+            .for_each(|interior| self.push_lines(interior.lines()));
+    }
+
+    /// The coordinates where a segment touches the blade.
+    fn cuts(&self, line: &Line) -> Vec<Coord> {
+        let mut cuts = self
+            .points
+            .iter()
+            .filter(|point| line.intersects(*point))
+            .copied()
+            .collect::<Vec<_>>();
+        for blade_line in &self.lines {
+            match line_intersection(*line, *blade_line) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => cuts.push(intersection),
+                Some(LineIntersection::Collinear { intersection }) => {
+                    cuts.extend([intersection.start, intersection.end])
+                }
+                None => {}
+            }
+        }
+        cuts
+    }
+}
+
+/// The position of a coordinate along a segment, from 0 at its start to 1 at its end.
+fn position(line: &Line, coord: Coord) -> f64 {
+    if coord == line.start {
+        return 0.;
+    }
+    if coord == line.end {
+        return 1.;
+    }
+    let delta = line.delta();
+    let length_squared = delta.x * delta.x + delta.y * delta.y;
+    (((coord.x - line.start.x) * delta.x + (coord.y - line.start.y) * delta.y) / length_squared)
+        .clamp(0., 1.)
+}
+
+/// The cuts of a segment sorted along it, without duplicates.
+fn sorted_cuts(line: &Line, cuts: Vec<Coord>) -> Vec<(f64, Coord)> {
+    let mut cuts = cuts
+        .into_iter()
+        .map(|coord| (position(line, coord), coord))
+        .collect::<Vec<_>>();
+    cuts.sort_by(|a, b| a.0.total_cmp(&b.0));
+    cuts.dedup_by(|a, b| a.0 == b.0 || a.1 == b.1);
+    cuts
+}
+
+fn split_line_string(line_string: &geo::LineString, blade: &Blade) -> Vec<geo::LineString> {
+    fn finish(pieces: &mut Vec<geo::LineString>, current: &mut Vec<Coord>, start: Coord) {
+        let piece = std::mem::replace(current, vec![start]);
+        if piece.len() >= 2 && piece.iter().any(|coord| *coord != piece[0]) {
+            pieces.push(geo::LineString::new(piece));
+        }
+    }
+
+    let Some(first) = line_string.0.first() else {
+        return vec![];
+    };
+    let mut pieces = vec![];
+    let mut current = vec![*first];
+    for line in line_string.lines() {
+        if line.start == line.end {
+            continue;
+        }
+        for (t, coord) in sorted_cuts(&line, blade.cuts(&line)) {
+            if t <= 0. {
+                finish(&mut pieces, &mut current, line.start);
+            } else if t >= 1. {
+                current.push(line.end);
+                finish(&mut pieces, &mut current, line.end);
+            } else {
+                current.push(coord);
+                finish(&mut pieces, &mut current, coord);
+            }
+        }
+        if current.last() != Some(&line.end) {
+            current.push(line.end);
+        }
+    }
+    finish(&mut pieces, &mut current, *first);
+
+    if pieces.is_empty() {
+        vec![line_string.clone()]
+    } else {
+        pieces
+    }
+}
+
+/// A key for the nodes of a planar graph, so that equal coordinates are the same node.
+fn node_key(coord: Coord) -> (u64, u64) {
+    ((coord.x + 0.).to_bits(), (coord.y + 0.).to_bits())
+}
+
+/// Split a polygon into the faces of the planar graph formed by its rings and the parts of the
+/// blade lines inside it.
+fn split_polygon(polygon: &geo::Polygon, blade: &Blade) -> Vec<geo::Polygon> {
+    if blade.lines.is_empty() {
+        return vec![polygon.clone()];
+    }
+
+    let ring_lines = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.lines())
+        .filter(|line| line.start != line.end)
+        .collect::<Vec<_>>();
+
+    // Node the rings and the blade against each other, and the blade against itself
+    let mut ring_cuts = vec![vec![]; ring_lines.len()];
+    let mut blade_cuts = vec![vec![]; blade.lines.len()];
+    for (i, ring_line) in ring_lines.iter().enumerate() {
+        for (j, blade_line) in blade.lines.iter().enumerate() {
+            let coords = match line_intersection(*ring_line, *blade_line) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => vec![intersection],
+                Some(LineIntersection::Collinear { intersection }) => {
+                    vec![intersection.start, intersection.end]
+                }
+                None => continue,
+            };
+            ring_cuts[i].extend(coords.iter().copied());
+            blade_cuts[j].extend(coords);
+        }
+    }
+    for i in 0..blade.lines.len() {
+        for j in i + 1..blade.lines.len() {
+            if let Some(LineIntersection::SinglePoint { intersection, .. }) =
+                line_intersection(blade.lines[i], blade.lines[j])
+            {
+                blade_cuts[i].push(intersection);
+                blade_cuts[j].push(intersection);
+            }
+        }
+    }
+
+    let mut graph = Graph::default();
+    for (line, cuts) in ring_lines.iter().zip(ring_cuts) {
+        graph.add_noded_line(line, cuts, |_| true);
+    }
+    let mut has_blade_edges = false;
+    for (line, cuts) in blade.lines.iter().zip(blade_cuts) {
+        graph.add_noded_line(line, cuts, |edge| {
+            let inside = polygon.contains(&edge.centroid());
+            has_blade_edges |= inside;
+            inside
+        });
+    }
+    if !has_blade_edges {
+        return vec![polygon.clone()];
+    }
+    graph.prune_dangles();
+
+    let (faces, shells): (Vec<_>, Vec<_>) = graph
+        .rings()
+        .into_iter()
+        .map(geo::LineString::new)
+        .partition(|ring| geo::Polygon::new(ring.clone(), vec![]).signed_area() > 0.);
+    let mut faces = faces
+        .into_iter()
+        .map(|ring| geo::Polygon::new(ring, vec![]))
+        .collect::<Vec<_>>();
+
+    // Rings that aren't connected to the exterior of a face are its holes
+    for shell in shells {
+        let vertex = geo::Point(shell.0[0]);
+        let containing = faces
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| face.contains(&vertex))
+            .min_by(|(_, a), (_, b)| a.unsigned_area().total_cmp(&b.unsigned_area()))
+            .map(|(i, _)| i);
+        if let Some(i) = containing {
+            faces[i].interiors_push(shell);
+        }
+    }
+
+    faces.retain(|face| {
+        face.interior_point()
+            .is_some_and(|point| polygon.contains(&point))
+    });
+    // Blade lines that don't separate any part of the polygon, such as those ending inside it,
+    // leave it unchanged
+    if faces.len() <= 1 {
+        vec![polygon.clone()]
+    } else {
+        faces
+    }
+}
+
+/// An undirected planar graph, whose edges are deduplicated.
+#[derive(Default)]
+struct Graph {
+    nodes: Vec<Coord>,
+    node_ids: HashMap<(u64, u64), usize>,
+    neighbors: Vec<Vec<usize>>,
+    edges: HashSet<(usize, usize)>,
+}
+
+impl Graph {
+    fn node(&mut self, coord: Coord) -> usize {
+        *self.node_ids.entry(node_key(coord)).or_insert_with(|| {
+            self.nodes.push(coord);
+            self.neighbors.push(vec![]);
+            self.nodes.len() - 1
+        })
+    }
+
+    /// Add the pieces of a segment between its cuts, keeping those accepted by `keep`.
+    fn add_noded_line(
+        &mut self,
+        line: &Line,
+        cuts: Vec<Coord>,
+        mut keep: impl FnMut(&Line) -> bool,
+    ) {
+        let mut coords = vec![line.start];
+        coords.extend(sorted_cuts(line, cuts).into_iter().map(|(_, coord)| coord));
+        coords.push(line.end);
+        for pair in coords.windows(2) {
+            let edge = Line::new(pair[0], pair[1]);
+            if edge.start == edge.end || !keep(&edge) {
+                continue;
+            }
+            let (a, b) = (self.node(edge.start), self.node(edge.end));
+            if a != b && self.edges.insert((a.min(b), a.max(b))) {
+                self.neighbors[a].push(b);
+                self.neighbors[b].push(a);
+            }
+        }
+    }
+
+    /// Remove edges with a node that isn't connected to any other edge, until there are none.
+    fn prune_dangles(&mut self) {
+        let mut stack = (0..self.nodes.len())
+            .filter(|node| self.neighbors[*node].len() == 1)
+            .collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            let Some(&other) = self.neighbors[node].first() else {
+                continue;
+            };
+            if self.neighbors[node].len() != 1 {
+                continue;
+            }
+            self.neighbors[node].clear();
+            self.neighbors[other].retain(|n| *n != node);
+            self.edges.remove(&(node.min(other), node.max(other)));
+            if self.neighbors[other].len() == 1 {
+                stack.push(other);
+            }
+        }
+    }
+
+    /// Trace the rings of the graph, keeping each face to the left of its directed edges.
+    ///
+    /// Bounded faces are counterclockwise, while the outer boundary of each connected component is
+    /// clockwise.
+    fn rings(&mut self) -> Vec<Vec<Coord>> {
+        let nodes = &self.nodes;
+        for (node, neighbors) in self.neighbors.iter_mut().enumerate() {
+            let origin = nodes[node];
+            neighbors.sort_by(|a, b| {
+                let angle = |n: &usize| (nodes[*n].y - origin.y).atan2(nodes[*n].x - origin.x);
+                angle(a).total_cmp(&angle(b))
+            });
+        }
+
+        let mut visited = HashSet::new();
+        let mut rings = vec![];
+        for start in 0..self.nodes.len() {
+            for &next in &self.neighbors[start] {
+                if visited.contains(&(start, next)) {
+                    continue;
+                }
+                let mut ring = vec![self.nodes[start]];
+                let (mut from, mut to) = (start, next);
+                while visited.insert((from, to)) {
+                    ring.push(self.nodes[to]);
+                    // The next edge is the one clockwise from the edge we arrived on
+                    let neighbors = &self.neighbors[to];
+                    let index = neighbors.iter().position(|n| *n == from).unwrap();
+                    let next = neighbors[(index + neighbors.len() - 1) % neighbors.len()];
+                    (from, to) = (to, next);
+                }
+                if ring.len() >= 4 {
+                    rings.push(ring);
+                }
+            }
+        }
+        rings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon};
+
+    fn collection(geoms: Vec<geo::Geometry>) -> geo::GeometryCollection {
+        geo::GeometryCollection::new_from(geoms)
+    }
+
+    #[test]
+    fn split_line_string_by_line() {
+        let line_string: geo::Geometry = line_string![(x: 0., y: 0.), (x: 4., y: 0.)].into();
+        let blade: geo::Geometry = line_string![(x: 1., y: -1.), (x: 1., y: 1.)].into();
+        assert_eq!(
+            split_geometry(&line_string, &blade),
+            collection(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)].into(),
+                line_string![(x: 1., y: 0.), (x: 4., y: 0.)].into(),
+            ])
+        );
+
+        // Splitting at a vertex
+        let line_string: geo::Geometry =
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)].into();
+        let blade: geo::Geometry = point!(x: 2., y: 0.).into();
+        assert_eq!(
+            split_geometry(&line_string, &blade),
+            collection(vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 0.)].into(),
+                line_string![(x: 2., y: 0.), (x: 2., y: 2.)].into(),
+            ])
+        );
+
+        // A blade that doesn't touch the line
+        let blade: geo::Geometry = point!(x: 5., y: 5.).into();
+        assert_eq!(
+            split_geometry(&line_string, &blade),
+            collection(vec![line_string.clone()])
+        );
+    }
+
+    #[test]
+    fn split_polygon_by_line() {
+        let square: geo::Geometry =
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)].into();
+        let blade: geo::Geometry = line_string![(x: 1., y: -1.), (x: 1., y: 5.)].into();
+        let parts = split_geometry(&square, &blade);
+        assert_eq!(parts.len(), 2);
+        let mut areas = parts
+            .iter()
+            .map(|part| part.unsigned_area())
+            .collect::<Vec<_>>();
+        areas.sort_by(f64::total_cmp);
+        assert_eq!(areas, vec![4., 12.]);
+
+        // A blade ending inside the polygon doesn't split it
+        let blade: geo::Geometry = line_string![(x: 1., y: -1.), (x: 1., y: 2.)].into();
+        assert_eq!(
+            split_geometry(&square, &blade),
+            collection(vec![square.clone()])
+        );
+    }
+
+    #[test]
+    fn split_polygon_with_hole() {
+        let polygon: geo::Geometry = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 6., y: 6.), (x: 8., y: 6.), (x: 8., y: 8.), (x: 6., y: 8.)]],
+        )
+        .into();
+        let blade: geo::Geometry = line_string![(x: 2., y: -1.), (x: 2., y: 11.)].into();
+        let parts = split_geometry(&polygon, &blade);
+        assert_eq!(parts.len(), 2);
+        let total = parts.iter().map(|part| part.unsigned_area()).sum::<f64>();
+        assert_eq!(total, polygon.unsigned_area());
+
+        // Cutting through the hole
+        let blade: geo::Geometry = line_string![(x: 7., y: -1.), (x: 7., y: 11.)].into();
+        let parts = split_geometry(&polygon, &blade);
+        assert_eq!(parts.len(), 2);
+        let total = parts.iter().map(|part| part.unsigned_area()).sum::<f64>();
+        assert_eq!(total, polygon.unsigned_area());
+    }
+}
@@ -0,0 +1,454 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use arrow::compute::take;
+use arrow_array::builder::{UInt32Builder, UInt8Builder};
+use arrow_array::{ArrayRef, RecordBatch, UInt32Array};
+use arrow_schema::{DataType, Field, SchemaBuilder};
+use geo::coordinate_position::CoordPos;
+use geo::dimensions::{Dimensions, HasDimensions};
+use geo::{BooleanOps, BoundingRect, Contains, CoordsIter, Intersects, Relate};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::algorithm::tile::{
+    buffered_tile_bounds, mercator_x, mercator_y, Tile, MAX_ZOOM, TILE_SIZE,
+};
+use crate::array::GeometryBuilder;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
+use crate::ArrayBase;
+
+/// The maximum number of tiles that a single feature may be assigned to at one zoom level.
+const MAX_TILES_PER_FEATURE: u64 = 1 << 20;
+
+/// The tiles at zoom level `z` whose buffered bounds may intersect `rect`, as the inclusive
+/// ranges of their columns and rows.
+fn candidate_tiles(
+    rect: &geo::Rect,
+    z: u8,
+    buffer: f64,
+) -> Result<(RangeInclusive<u32>, RangeInclusive<u32>)> {
+    let n = 2f64.powi(z.into());
+    let buffer = buffer / TILE_SIZE;
+    let max = (1u64 << z) - 1;
+    let index = |position: f64| (position * n).floor().clamp(0., max as f64) as u32;
+
+    let columns =
+        index(mercator_x(rect.min().x) - buffer / n)..=index(mercator_x(rect.max().x) + buffer / n);
+    let rows =
+        index(mercator_y(rect.max().y) - buffer / n)..=index(mercator_y(rect.min().y) + buffer / n);
+
+    let count =
+        (columns.end() - columns.start() + 1) as u64 * (rows.end() - rows.start() + 1) as u64;
+    if count > MAX_TILES_PER_FEATURE {
+        return Err(GeoArrowError::General(format!(
+            "A feature covers {count} tiles at zoom level {z}, more than the maximum of \
+            {MAX_TILES_PER_FEATURE}"
+        )));
+    }
+    Ok((columns, rows))
+}
+
+/// Whether a geometry overlaps the interior of a tile.
+///
+/// Geometries that only touch the edges of a tile don't overlap it. Points on an edge are in the
+/// tile to their south-east, as with [`Tile::containing`], so that each point is in one tile.
+fn overlaps_tile(geom: &geo::Geometry, tile: Tile) -> Result<bool> {
+    match geom {
+        geo::Geometry::GeometryCollection(collection) => {
+            for part in collection {
+                if overlaps_tile(part, tile)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        _ if geom.dimensions() == Dimensions::ZeroDimensional => {
+            for coord in geom.coords_iter() {
+                if Tile::containing(coord.x, coord.y, tile.z)? == tile {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        _ => {
+            let matrix = geom.relate(&tile.bounds().to_polygon());
+            Ok(matrix.get(CoordPos::Inside, CoordPos::Inside) != Dimensions::Empty)
+        }
+    }
+}
+
+/// Clip a geometry to a rectangle, or `None` if nothing of it is left.
+///
+/// Geometries inside the rectangle are returned as is. Otherwise polygons are clipped to polygons
+/// or multi polygons, lines to line strings or multi line strings, and points are kept if they're
+/// inside the rectangle.
+fn clip(geom: &geo::Geometry, rect: &geo::Rect) -> Option<geo::Geometry> {
+    let bounds = geom.bounding_rect()?;
+    if rect.contains(&bounds) {
+        return Some(geom.clone());
+    }
+    if !rect.intersects(&bounds) {
+        return None;
+    }
+
+    let clip_polygon = rect.to_polygon();
+    let clip_lines = |multi_line_string: geo::MultiLineString| {
+        let mut clipped = clip_polygon.clip(&multi_line_string, false);
+        match clipped.0.len() {
+            0 => None,
+            1 => Some(geo::Geometry::LineString(clipped.0.remove(0))),
+            _ => Some(geo::Geometry::MultiLineString(clipped)),
+        }
+    };
+    let clip_polygons = |multi_polygon: geo::MultiPolygon| {
+        let mut clipped = multi_polygon.intersection(&clip_polygon);
+        match clipped.0.len() {
+            0 => None,
+            1 => Some(geo::Geometry::Polygon(clipped.0.remove(0))),
+            _ => Some(geo::Geometry::MultiPolygon(clipped)),
+        }
+    };
+
+    match geom {
+        geo::Geometry::Point(point) => rect.intersects(point).then(|| geom.clone()),
+        geo::Geometry::MultiPoint(multi_point) => {
+            let points = multi_point
+                .iter()
+                .filter(|point| rect.intersects(*point))
+                .copied()
+                .collect::<Vec<_>>();
+            (!points.is_empty()).then(|| geo::MultiPoint::new(points).into())
+        }
+        geo::Geometry::Line(line) => clip_lines(geo::MultiLineString::new(vec![
+            geo::LineString::new(vec![line.start, line.end]),
+        ])),
+        geo::Geometry::LineString(line_string) => {
+            clip_lines(geo::MultiLineString::new(vec![line_string.clone()]))
+        }
+        geo::Geometry::MultiLineString(multi_line_string) => clip_lines(multi_line_string.clone()),
+        geo::Geometry::Polygon(polygon) => {
+            clip_polygons(geo::MultiPolygon::new(vec![polygon.clone()]))
+        }
+        geo::Geometry::MultiPolygon(multi_polygon) => clip_polygons(multi_polygon.clone()),
+        geo::Geometry::Rect(geom_rect) => {
+            clip_polygons(geo::MultiPolygon::new(vec![geom_rect.to_polygon()]))
+        }
+        geo::Geometry::Triangle(triangle) => {
+            clip_polygons(geo::MultiPolygon::new(vec![triangle.to_polygon()]))
+        }
+        geo::Geometry::GeometryCollection(collection) => {
+            let parts = collection
+                .iter()
+                .filter_map(|geom| clip(geom, rect))
+                .collect::<Vec<_>>();
+            (!parts.is_empty()).then(|| geo::GeometryCollection::from(parts).into())
+        }
+    }
+}
+
+/// Assign each feature of a table to the web mercator tiles it intersects, at every zoom level of
+/// `zoom_range`.
+///
+/// The output has a row for each pair of feature and tile, with the zoom level, column and row
+/// of the tile in the `z` (UInt8), `x` and `y` (UInt32) columns, followed by the columns of the
+/// input. Tile columns and rows count from the north-west. Rows are in the order of the input
+/// features, then of zoom level, then of tile row and column.
+///
+/// If `buffer` is `Some`, each geometry is clipped to the bounds of its tile expanded by that
+/// many pixels of a 256 pixel tile, and features are assigned to the tiles whose buffered bounds
+/// they intersect. Otherwise geometries are kept whole, and features are assigned to the tiles
+/// whose interior they overlap; a feature that only touches a tile along its edges isn't in it.
+/// Either way, the geometry column of the output is a geometry array with the name and metadata
+/// of the input column.
+///
+/// The table must have a single geometry column, in longitude and latitude. Geometries are
+/// clipped in two dimensions, and features with null or empty geometries are dropped. Returns an
+/// error if the zoom range goes beyond zoom level 30, or a feature would be assigned to more
+/// than 2<sup>20</sup> tiles at one zoom level.
+pub fn tile_cut(
+    table: &Table,
+    zoom_range: RangeInclusive<u8>,
+    buffer: Option<f64>,
+) -> Result<Table> {
+    if *zoom_range.end() > MAX_ZOOM {
+        return Err(GeoArrowError::General(format!(
+            "Tile zoom levels must be at most {MAX_ZOOM}, got {}",
+            zoom_range.end()
+        )));
+    }
+    if buffer.is_some_and(|buffer| buffer.is_nan() || buffer < 0.) {
+        return Err(GeoArrowError::General(
+            "Tile buffer must be a non-negative number of pixels".to_string(),
+        ));
+    }
+
+    let geometry_indices = table.schema().as_ref().geometry_columns();
+    let [geometry_index] = geometry_indices.as_slice() else {
+        return Err(GeoArrowError::General(format!(
+            "tile_cut requires a table with a single geometry column, found {}",
+            geometry_indices.len()
+        )));
+    };
+    let geometry_index = *geometry_index;
+    let geometry_column = table.geometry_column(Some(geometry_index))?;
+    let input_field = table.schema().field(geometry_index);
+
+    let mut output_field = None;
+    let mut output_batches = vec![];
+    for (batch, chunk) in table
+        .batches()
+        .iter()
+        .zip(geometry_column.geometry_chunks())
+    {
+        let mut z_builder = UInt8Builder::new();
+        let mut x_builder = UInt32Builder::new();
+        let mut y_builder = UInt32Builder::new();
+        let mut indices = vec![];
+        let mut geometry_builder =
+            GeometryBuilder::new_with_options(chunk.coord_type(), chunk.metadata(), false);
+
+        for (row, geom) in iter_geometries(chunk.as_ref()).enumerate() {
+            let Some(geom) = geom else {
+                continue;
+            };
+            let geom = geometry_to_geo(&geom);
+            let Some(rect) = geom.bounding_rect() else {
+                continue;
+            };
+            for z in zoom_range.clone() {
+                let (columns, rows) = candidate_tiles(&rect, z, buffer.unwrap_or(0.))?;
+                let mut tiles = vec![];
+                for y in rows {
+                    for x in columns.clone() {
+                        let tile_geom = match buffer {
+                            Some(buffer) => clip(&geom, &buffered_tile_bounds(z, x, y, buffer)),
+                            None => overlaps_tile(&geom, Tile { z, x, y })?.then(|| geom.clone()),
+                        };
+                        if let Some(tile_geom) = tile_geom {
+                            tiles.push((x, y, tile_geom));
+                        }
+                    }
+                }
+                // A geometry that lies along tile edges overlaps no tile, so it goes in the tile
+                // containing its north-west corner
+                if buffer.is_none() && tiles.is_empty() {
+                    let tile = Tile::containing(rect.min().x, rect.max().y, z)?;
+                    tiles.push((tile.x, tile.y, geom.clone()));
+                }
+                for (x, y, tile_geom) in tiles {
+                    z_builder.append_value(z);
+                    x_builder.append_value(x);
+                    y_builder.append_value(y);
+                    indices.push(row as u32);
+                    geometry_builder.push_geometry(Some(&tile_geom))?;
+                }
+            }
+        }
+
+        let geometry_array = geometry_builder.finish();
+        let geometry_field = output_field.get_or_insert_with(|| {
+            geometry_array
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name(input_field.name())
+                .with_nullable(input_field.is_nullable())
+        });
+
+        let indices = UInt32Array::from(indices);
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(z_builder.finish()),
+            Arc::new(x_builder.finish()),
+            Arc::new(y_builder.finish()),
+        ];
+        for (i, column) in batch.columns().iter().enumerate() {
+            if i == geometry_index {
+                columns.push(geometry_array.to_array_ref());
+            } else {
+                columns.push(take(column, &indices, None)?);
+            }
+        }
+        let schema = output_schema(table, geometry_index, geometry_field.clone());
+        output_batches.push(RecordBatch::try_new(schema.into(), columns)?);
+    }
+
+    let geometry_field = match output_field {
+        Some(field) => field,
+        None => GeometryBuilder::new_with_options(
+            geometry_column.data_type().coord_type(),
+            Default::default(),
+            false,
+        )
+        .finish()
+        .extension_field()
+        .as_ref()
+        .clone()
+        .with_name(input_field.name())
+        .with_nullable(input_field.is_nullable()),
+    };
+    let schema = output_schema(table, geometry_index, geometry_field);
+    Table::try_new(output_batches, schema.into())
+}
+
+/// The schema of the output of [`tile_cut`]: the tile columns, then the input columns with the
+/// geometry column replaced.
+fn output_schema(
+    table: &Table,
+    geometry_index: usize,
+    geometry_field: Field,
+) -> arrow_schema::Schema {
+    let mut builder = SchemaBuilder::new();
+    builder.push(Field::new("z", DataType::UInt8, false));
+    builder.push(Field::new("x", DataType::UInt32, false));
+    builder.push(Field::new("y", DataType::UInt32, false));
+    for (i, field) in table.schema().fields().iter().enumerate() {
+        if i == geometry_index {
+            builder.push(geometry_field.clone());
+        } else {
+            builder.push(field.clone());
+        }
+    }
+    builder
+        .metadata_mut()
+        .extend(table.schema().metadata().clone());
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, UInt32Type, UInt8Type};
+    use arrow_array::Int32Array;
+    use arrow_schema::Schema;
+    use geo::{polygon, Area};
+
+    use super::*;
+    use crate::array::{CoordType, GeometryArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::trait_::{ArrayAccessor, GeometryArraySelfMethods};
+    use crate::NativeArray;
+
+    fn table() -> Table {
+        table_with_coord_type(CoordType::Interleaved)
+    }
+
+    fn table_with_coord_type(coord_type: CoordType) -> Table {
+        // A square across the prime meridian and the equator, and one in the north-west
+        let polygons: PolygonArray = (
+            vec![
+                Some(polygon![(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)]),
+                None,
+                Some(polygon![(x: -100., y: 40.), (x: -99., y: 40.), (x: -99., y: 41.), (x: -100., y: 41.)]),
+            ],
+            Dimension::XY,
+        )
+            .into();
+        let polygons = polygons.into_coord_type(coord_type);
+        let ids = Int32Array::from(vec![1, 2, 3]);
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            polygons.extension_field(),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(ids), polygons.into_array_ref()],
+        )
+        .unwrap();
+        Table::try_new(vec![batch], schema).unwrap()
+    }
+
+    #[test]
+    fn assign_tiles() {
+        let output = tile_cut(&table(), 0..=1, None).unwrap();
+        let batch = &output.batches()[0];
+        // The first square is in all four tiles at zoom 1, the second only in the north-west
+        assert_eq!(batch.num_rows(), 1 + 4 + 1 + 1);
+
+        let z = batch.column(0).as_primitive::<UInt8Type>();
+        let x = batch.column(1).as_primitive::<UInt32Type>();
+        let y = batch.column(2).as_primitive::<UInt32Type>();
+        let ids = batch.column(3).as_primitive::<Int32Type>();
+        let rows = (0..batch.num_rows())
+            .map(|i| (ids.value(i), z.value(i), x.value(i), y.value(i)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (1, 0, 0, 0),
+                (1, 1, 0, 0),
+                (1, 1, 1, 0),
+                (1, 1, 0, 1),
+                (1, 1, 1, 1),
+                (3, 0, 0, 0),
+                (3, 1, 0, 0),
+            ]
+        );
+        assert_eq!(output.schema().field(4).name(), "geometry");
+    }
+
+    #[test]
+    fn clip_to_tiles() {
+        let output = tile_cut(&table(), 1..=1, Some(0.)).unwrap();
+        let batch = &output.batches()[0];
+        assert_eq!(batch.num_rows(), 5);
+
+        let geometry =
+            GeometryArray::try_from((batch.column(4).as_ref(), output.schema().field(4))).unwrap();
+        // Each quarter of the first square has a quarter of its area
+        for i in 0..4 {
+            assert!((geometry.value_as_geo(i).unsigned_area() - 100.).abs() < 1e-9);
+        }
+
+        // A buffer extends the clipped geometries across the tile edges
+        let output = tile_cut(&table(), 1..=1, Some(16.)).unwrap();
+        let batch = &output.batches()[0];
+        let geometry =
+            GeometryArray::try_from((batch.column(4).as_ref(), output.schema().field(4))).unwrap();
+        assert!(geometry.value_as_geo(0).unsigned_area() > 100.);
+
+        assert!(tile_cut(&table(), 0..=31, None).is_err());
+        assert!(tile_cut(&table(), 0..=1, Some(-1.)).is_err());
+    }
+
+    #[test]
+    fn skip_tiles_touched_on_edges() {
+        // The north-east tile at zoom 1 starts at the prime meridian and the equator
+        let polygon: geo::Geometry =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into();
+        let north_east = Tile { z: 1, x: 1, y: 0 };
+        assert!(overlaps_tile(&polygon, north_east).unwrap());
+        assert!(!overlaps_tile(&polygon, Tile { z: 1, x: 0, y: 0 }).unwrap());
+        assert!(!overlaps_tile(&polygon, Tile { z: 1, x: 1, y: 1 }).unwrap());
+
+        // A point on the edges is only in the tile to its south-east
+        let point: geo::Geometry = geo::point!(x: 0., y: 0.).into();
+        assert!(overlaps_tile(&point, Tile { z: 1, x: 1, y: 1 }).unwrap());
+        assert!(!overlaps_tile(&point, north_east).unwrap());
+    }
+
+    #[test]
+    fn keep_coord_type() {
+        let output = tile_cut(&table_with_coord_type(CoordType::Separated), 0..=0, None).unwrap();
+        let geometry = GeometryArray::try_from((
+            output.batches()[0].column(4).as_ref(),
+            output.schema().field(4),
+        ))
+        .unwrap();
+        assert_eq!(geometry.coord_type(), CoordType::Separated);
+    }
+
+    #[test]
+    fn clip_geometries() {
+        let rect = geo::Rect::new(geo::coord! { x: 0., y: 0. }, geo::coord! { x: 1., y: 1. });
+        let line: geo::Geometry = geo::line_string![(x: -1., y: 0.5), (x: 2., y: 0.5)].into();
+        assert_eq!(
+            clip(&line, &rect),
+            Some(geo::line_string![(x: 0., y: 0.5), (x: 1., y: 0.5)].into())
+        );
+        let point: geo::Geometry = geo::point!(x: 2., y: 2.).into();
+        assert_eq!(clip(&point, &rect), None);
+    }
+}
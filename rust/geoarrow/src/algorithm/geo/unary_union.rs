@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+
+use geo::{BooleanOps, BoundingRect};
+
+use crate::algorithm::backend::to_multi_polygon;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::chunked_array::ChunkedNativeArray;
+use crate::error::Result;
+use crate::io::geo::geometry_to_geo;
+use crate::NativeArray;
+
+/// Union multi polygons with a cascaded union.
+///
+/// The multi polygons are sorted by the center of their bounding boxes and unioned pairwise, then
+/// the pairs are unioned pairwise, and so on. Neighbors are merged first, which keeps the
+/// intermediate geometries small, unlike adding the geometries to a single union one at a time.
+///
+/// Only polygons are supported, as [`BooleanOps`] is only implemented for polygonal geometries.
+/// Callers convert their input with [`to_multi_polygon`], which errors on points and lines.
+pub(crate) fn cascaded_union(mut multi_polygons: Vec<geo::MultiPolygon>) -> geo::MultiPolygon {
+    let center = |multi_polygon: &geo::MultiPolygon| {
+        multi_polygon
+            .bounding_rect()
+            .map(|rect| rect.center().x_y())
+            .unwrap_or((f64::INFINITY, f64::INFINITY))
+    };
+    multi_polygons.sort_by(|left, right| {
+        center(left)
+            .partial_cmp(&center(right))
+            .unwrap_or(Ordering::Equal)
+    });
+
+    while multi_polygons.len() > 1 {
+        let mut iter = multi_polygons.into_iter();
+        let mut unions = Vec::with_capacity(iter.len().div_ceil(2));
+        while let Some(left) = iter.next() {
+            unions.push(match iter.next() {
+                Some(right) => left.union(&right),
+                None => left,
+            });
+        }
+        multi_polygons = unions;
+    }
+    multi_polygons
+        .pop()
+        .unwrap_or_else(|| geo::MultiPolygon::new(vec![]))
+}
+
+/// The multi polygons of an array, skipping nulls.
+fn multi_polygons(array: &dyn NativeArray) -> Result<Vec<geo::MultiPolygon>> {
+    iter_geometries(array)
+        .flatten()
+        .map(|geom| to_multi_polygon(geometry_to_geo(&geom)))
+        .collect()
+}
+
+/// Dissolve all the geometries of an array into one geometry, the equivalent of PostGIS'
+/// `ST_Union` aggregate.
+///
+/// Only polygonal geometries are supported: polygons, multi polygons and rects. Points and lines
+/// return an error, and empty geometries are ignored. The union is computed with [`geo`] in 2D,
+/// as a cascaded union. Null geometries are skipped, and the output
+/// is an empty multi polygon if there are no geometries.
+pub fn unary_union(array: &dyn NativeArray) -> Result<geo::Geometry> {
+    Ok(cascaded_union(multi_polygons(array)?).into())
+}
+
+/// Computes [`unary_union`] of arrays pushed one at a time, such as the chunks of a chunked array
+/// or of a stream.
+///
+/// The union of each chunk is merged with the unions of previous chunks as in a binary counter,
+/// so chunks are merged with chunks of similar size and only a logarithmic number of partial
+/// unions is kept.
+///
+/// Like [`unary_union`], only polygonal geometries are supported.
+#[derive(Debug, Clone, Default)]
+pub struct UnaryUnionAccumulator {
+    /// The partial union of `2^i` chunks at index `i`, if any.
+    levels: Vec<Option<geo::MultiPolygon>>,
+}
+
+impl UnaryUnionAccumulator {
+    /// Create a new, empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the geometries of an array to the union.
+    pub fn push_array(&mut self, array: &dyn NativeArray) -> Result<()> {
+        let mut carry = cascaded_union(multi_polygons(array)?);
+        for level in self.levels.iter_mut() {
+            match level.take() {
+                Some(partial) => carry = partial.union(&carry),
+                None => {
+                    *level = Some(carry);
+                    return Ok(());
+                }
+            }
+        }
+        self.levels.push(Some(carry));
+        Ok(())
+    }
+
+    /// Add the geometries of every chunk of a chunked array to the union.
+    pub fn push_chunked(&mut self, array: &dyn ChunkedNativeArray) -> Result<()> {
+        for chunk in array.geometry_chunks() {
+            self.push_array(chunk.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Merge the partial unions and return the union of every geometry pushed.
+    pub fn finish(self) -> geo::Geometry {
+        cascaded_union(self.levels.into_iter().flatten().collect()).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo::{polygon, Area};
+
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use crate::test::point;
+
+    fn squares(offsets: &[f64]) -> PolygonArray {
+        let polygons = offsets
+            .iter()
+            .map(|&x| {
+                Some(polygon![(x: x, y: 0.), (x: x + 1., y: 0.), (x: x + 1., y: 1.), (x: x, y: 1.)])
+            })
+            .chain([None])
+            .collect::<Vec<_>>();
+        (polygons, Dimension::XY).into()
+    }
+
+    #[test]
+    fn union_of_array() {
+        // Overlapping squares form one polygon, the separate square another
+        let array = squares(&[0., 0.5, 1., 5.]);
+        let geo::Geometry::MultiPolygon(union) = unary_union(&array).unwrap() else {
+            panic!("expected a multi polygon");
+        };
+        assert_eq!(union.0.len(), 2);
+        assert_eq!(union.unsigned_area(), 3.);
+
+        assert!(unary_union(&point::point_array()).is_err());
+    }
+
+    #[test]
+    fn accumulator() {
+        let mut accumulator = UnaryUnionAccumulator::new();
+        for i in 0..5 {
+            accumulator
+                .push_array(&squares(&[i as f64 * 2., i as f64 * 2. + 1.]))
+                .unwrap();
+        }
+        let union = accumulator.finish();
+        assert_eq!(union.unsigned_area(), 10.);
+        assert_eq!(UnaryUnionAccumulator::new().finish().unsigned_area(), 0.);
+    }
+}
@@ -1,7 +1,118 @@
 use arrow_array::Float64Array;
 use arrow_buffer::NullBuffer;
 
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::algorithm::native::Cast;
+use crate::array::{
+    AsChunkedNativeArray, AsNativeArray, GeometryArray, GeometryBuilder, LineStringArray,
+    PointArray,
+};
+use crate::chunked_array::{ChunkedLineStringArray, ChunkedNativeArray, ChunkedPointArray};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::NativeArray;
+
 pub(crate) fn zeroes(len: usize, nulls: Option<&NullBuffer>) -> Float64Array {
     let values = vec![0.0f64; len];
     Float64Array::new(values.into(), nulls.cloned())
 }
+
+/// Downcast an array to a [`LineStringArray`], casting geometry arrays that only contain line
+/// strings.
+pub(crate) fn as_line_string_array(array: &dyn NativeArray) -> Result<LineStringArray> {
+    match array.data_type() {
+        NativeType::LineString(_, _) => Ok(array.as_line_string().clone()),
+        NativeType::Geometry(coord_type) => Ok(array
+            .cast(NativeType::LineString(coord_type, Dimension::XY))?
+            .as_line_string()
+            .clone()),
+        data_type => Err(GeoArrowError::IncorrectType(
+            format!("Expected a line string array, got {data_type}").into(),
+        )),
+    }
+}
+
+/// Downcast an array to a [`PointArray`], casting geometry arrays that only contain points.
+pub(crate) fn as_point_array(array: &dyn NativeArray) -> Result<PointArray> {
+    match array.data_type() {
+        NativeType::Point(_, _) => Ok(array.as_point().clone()),
+        NativeType::Geometry(coord_type) => Ok(array
+            .cast(NativeType::Point(coord_type, Dimension::XY))?
+            .as_point()
+            .clone()),
+        data_type => Err(GeoArrowError::IncorrectType(
+            format!("Expected a point array, got {data_type}").into(),
+        )),
+    }
+}
+
+/// Downcast a chunked array to a [`ChunkedLineStringArray`], casting geometry arrays that only
+/// contain line strings.
+pub(crate) fn as_chunked_line_string_array(
+    array: &dyn ChunkedNativeArray,
+) -> Result<ChunkedLineStringArray> {
+    match array.data_type() {
+        NativeType::LineString(_, _) => Ok(array.as_line_string().clone()),
+        NativeType::Geometry(coord_type) => Ok(array
+            .cast(NativeType::LineString(coord_type, Dimension::XY))?
+            .as_line_string()
+            .clone()),
+        data_type => Err(GeoArrowError::IncorrectType(
+            format!("Expected a line string array, got {data_type}").into(),
+        )),
+    }
+}
+
+/// Downcast a chunked array to a [`ChunkedPointArray`], casting geometry arrays that only contain
+/// points.
+pub(crate) fn as_chunked_point_array(array: &dyn ChunkedNativeArray) -> Result<ChunkedPointArray> {
+    match array.data_type() {
+        NativeType::Point(_, _) => Ok(array.as_point().clone()),
+        NativeType::Geometry(coord_type) => Ok(array
+            .cast(NativeType::Point(coord_type, Dimension::XY))?
+            .as_point()
+            .clone()),
+        data_type => Err(GeoArrowError::IncorrectType(
+            format!("Expected a point array, got {data_type}").into(),
+        )),
+    }
+}
+
+/// Apply a closure to every pair of geometries at the same index of two arrays, collecting the
+/// output in a [`GeometryArray`].
+///
+/// The closure is passed the row index along with both geometries. The output is null where
+/// either input is null or the closure returns `None`. Returns an error if the arrays have
+/// different lengths.
+pub(crate) fn binary_geometry_map<F>(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    op: F,
+) -> Result<GeometryArray>
+where
+    F: Fn(usize, geo::Geometry, geo::Geometry) -> Result<Option<geo::Geometry>>,
+{
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let mut builder = GeometryBuilder::new_with_options(left.coord_type(), left.metadata(), false);
+    for (i, (left_geom, right_geom)) in iter_geometries(left)
+        .zip(iter_geometries(right))
+        .enumerate()
+    {
+        match (left_geom, right_geom) {
+            (Some(left_geom), Some(right_geom)) => {
+                let output = op(i, geometry_to_geo(&left_geom), geometry_to_geo(&right_geom))?;
+                builder.push_geometry(output.as_ref())?;
+            }
+            _ => builder.push_null(),
+        }
+    }
+    Ok(builder.finish())
+}
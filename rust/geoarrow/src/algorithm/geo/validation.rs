@@ -0,0 +1,492 @@
+use std::fmt;
+use std::sync::Arc;
+
+use arrow_array::builder::{BooleanBuilder, StringBuilder};
+use arrow_array::{ArrayRef, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Field, Fields};
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{BoundingRect, Contains, Coord, Intersects, Line};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::PointBuilder;
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::io::geo::geometry_to_geo;
+use crate::{ArrayBase, NativeArray};
+
+/// The reason that a geometry is invalid, as returned by [`is_valid_detail`].
+///
+/// The messages match those of GEOS, so that they can be compared with the output of PostGIS
+/// `ST_IsValidDetail`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidReason {
+    /// A coordinate is infinite or NaN.
+    InvalidCoordinate,
+
+    /// A line string has fewer than two distinct points, or a ring fewer than three.
+    TooFewPoints,
+
+    /// A polygon ring intersects itself.
+    RingSelfIntersection,
+
+    /// The rings of a polygon, or the polygons of a multi polygon, cross or overlap.
+    SelfIntersection,
+
+    /// A polygon hole isn't inside the exterior ring.
+    HoleOutsideShell,
+
+    /// A polygon hole is inside another hole.
+    NestedHoles,
+
+    /// A polygon of a multi polygon is inside another.
+    NestedShells,
+}
+
+impl fmt::Display for InvalidReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            InvalidReason::InvalidCoordinate => "Invalid Coordinate",
+            InvalidReason::TooFewPoints => "Too few points in geometry component",
+            InvalidReason::RingSelfIntersection => "Ring Self-intersection",
+            InvalidReason::SelfIntersection => "Self-intersection",
+            InvalidReason::HoleOutsideShell => "Hole lies outside shell",
+            InvalidReason::NestedHoles => "Holes are nested",
+            InvalidReason::NestedShells => "Nested shells",
+        };
+        f.write_str(reason)
+    }
+}
+
+/// A problem found in a geometry, and where it is.
+pub(crate) type Problem = (InvalidReason, Coord);
+
+/// Check the validity of every geometry of an array, returning why and where each invalid
+/// geometry is invalid.
+///
+/// Each row of the output is a struct of:
+///
+/// - `is_valid`: whether the geometry is valid.
+/// - `reason`: the [`InvalidReason`] of the first problem found, or null if the geometry is
+///   valid.
+/// - `location`: a point at the problem, or null if the geometry is valid.
+///
+/// This is similar to PostGIS `ST_IsValidDetail`. The checks follow the OGC rules, except that
+/// polygons whose interior is disconnected by holes touching at several points are considered
+/// valid. Empty geometries are valid. The output is null where the geometry is null.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::cast::AsArray;
+/// use geoarrow::algorithm::geo::is_valid_detail;
+/// use geoarrow::array::PolygonArray;
+/// use geoarrow::datatypes::Dimension;
+///
+/// // A bowtie, whose ring crosses itself at (1, 1)
+/// let polygon = geo::polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+///
+/// let detail = is_valid_detail(&array).unwrap();
+/// assert!(!detail.column(0).as_boolean().value(0));
+/// assert_eq!(detail.column(1).as_string::<i32>().value(0), "Ring Self-intersection");
+/// ```
+pub fn is_valid_detail(array: &dyn NativeArray) -> Result<StructArray> {
+    let mut is_valid = BooleanBuilder::with_capacity(array.len());
+    let mut reasons = StringBuilder::with_capacity(array.len(), 0);
+    let mut locations: Vec<Option<geo::Point>> = Vec::with_capacity(array.len());
+    let mut nulls = Vec::with_capacity(array.len());
+
+    for geom in iter_geometries(array) {
+        let problem = geom.as_ref().and_then(|geom| check(&geometry_to_geo(geom)));
+        is_valid.append_value(problem.is_none());
+        reasons.append_option(problem.map(|(reason, _)| reason.to_string()));
+        locations.push(problem.map(|(_, location)| geo::Point(location)));
+        nulls.push(geom.is_some());
+    }
+
+    let locations = PointBuilder::from_nullable_points(
+        locations.iter().map(Option::as_ref),
+        Dimension::XY,
+        Default::default(),
+        Default::default(),
+    )
+    .finish();
+
+    let location_field = locations
+        .extension_field()
+        .as_ref()
+        .clone()
+        .with_name("location")
+        .with_nullable(true);
+    let fields = Fields::from(vec![
+        Field::new("is_valid", DataType::Boolean, false),
+        Field::new("reason", DataType::Utf8, true),
+        location_field,
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(is_valid.finish()),
+        Arc::new(reasons.finish()),
+        locations.into_array_ref(),
+    ];
+    Ok(StructArray::try_new(
+        fields,
+        columns,
+        Some(NullBuffer::from(nulls)),
+    )?)
+}
+
+/// The first problem found in a geometry, if any.
+pub(crate) fn check(geom: &geo::Geometry) -> Option<Problem> {
+    match geom {
+        geo::Geometry::Point(point) => check_point(point),
+        geo::Geometry::MultiPoint(multi_point) => multi_point.iter().find_map(check_point),
+        geo::Geometry::Line(line) => {
+            check_line_string(&geo::LineString::new(vec![line.start, line.end]))
+        }
+        geo::Geometry::LineString(line_string) => check_line_string(line_string),
+        geo::Geometry::MultiLineString(multi_line_string) => {
+            multi_line_string.iter().find_map(check_line_string)
+        }
+        geo::Geometry::Polygon(polygon) => check_polygon(polygon),
+        geo::Geometry::MultiPolygon(multi_polygon) => check_multi_polygon(multi_polygon),
+        geo::Geometry::Rect(rect) => check_polygon(&rect.to_polygon()),
+        geo::Geometry::Triangle(triangle) => check_polygon(&triangle.to_polygon()),
+        geo::Geometry::GeometryCollection(collection) => collection.iter().find_map(check),
+    }
+}
+
+fn check_point(point: &geo::Point) -> Option<Problem> {
+    // Empty points are stored with NaN coordinates
+    if point.x().is_nan() && point.y().is_nan() {
+        return None;
+    }
+    check_coords(&[point.0])
+}
+
+fn check_coords(coords: &[Coord]) -> Option<Problem> {
+    coords
+        .iter()
+        .find(|coord| !coord.x.is_finite() || !coord.y.is_finite())
+        .map(|coord| (InvalidReason::InvalidCoordinate, *coord))
+}
+
+/// The number of distinct points of a line string, ignoring repeated consecutive points.
+fn distinct_points(coords: &[Coord]) -> usize {
+    let mut points = coords.to_vec();
+    points.dedup();
+    points.len()
+}
+
+fn check_line_string(line_string: &geo::LineString) -> Option<Problem> {
+    let coords = &line_string.0;
+    if coords.is_empty() {
+        return None;
+    }
+    check_coords(coords).or_else(|| {
+        (distinct_points(coords) < 2).then_some((InvalidReason::TooFewPoints, coords[0]))
+    })
+}
+
+fn check_ring(ring: &geo::LineString) -> Option<Problem> {
+    let coords = &ring.0;
+    check_coords(coords)
+        .or_else(|| {
+            // A closed ring repeats its first point, so needs four points
+            (distinct_points(coords) < 4).then_some((InvalidReason::TooFewPoints, coords[0]))
+        })
+        .or_else(|| ring_self_intersection(ring))
+}
+
+/// A point where a ring touches or crosses itself, other than at the shared end points of
+/// consecutive segments.
+fn ring_self_intersection(ring: &geo::LineString) -> Option<Problem> {
+    let lines = ring
+        .lines()
+        .filter(|line| line.start != line.end)
+        .collect::<Vec<_>>();
+    let last = lines.len() - 1;
+    for i in 0..lines.len() {
+        for j in i + 1..lines.len() {
+            let adjacent = j == i + 1 || (i == 0 && j == last);
+            match intersection(&lines[i], &lines[j]) {
+                Some(LineIntersection::Collinear { intersection }) => {
+                    return Some((InvalidReason::RingSelfIntersection, intersection.start))
+                }
+                Some(LineIntersection::SinglePoint { intersection, .. }) if !adjacent => {
+                    return Some((InvalidReason::RingSelfIntersection, intersection))
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// The intersection of two segments, skipping the computation if their bounding boxes are
+/// disjoint.
+fn intersection(a: &Line, b: &Line) -> Option<LineIntersection<f64>> {
+    if !a.bounding_rect().intersects(&b.bounding_rect()) {
+        return None;
+    }
+    line_intersection(*a, *b)
+}
+
+/// A point where two rings cross or share a segment. Rings may touch at points.
+fn rings_intersection(a: &geo::LineString, b: &geo::LineString) -> Option<Problem> {
+    for line_a in a.lines() {
+        for line_b in b.lines() {
+            match intersection(&line_a, &line_b) {
+                Some(LineIntersection::Collinear { intersection }) => {
+                    return Some((InvalidReason::SelfIntersection, intersection.start))
+                }
+                Some(LineIntersection::SinglePoint {
+                    intersection,
+                    is_proper: true,
+                }) => return Some((InvalidReason::SelfIntersection, intersection)),
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// A vertex of `ring` that is inside the area enclosed by `other`.
+///
+/// As the rings don't cross, `ring` is inside `other` if any of its vertices not on `other` is.
+fn ring_inside(ring: &geo::LineString, other: &geo::LineString) -> Option<Coord> {
+    let vertex = *ring.0.iter().find(|coord| !other.intersects(**coord))?;
+    geo::Polygon::new(other.clone(), vec![])
+        .contains(&vertex)
+        .then_some(vertex)
+}
+
+fn check_polygon(polygon: &geo::Polygon) -> Option<Problem> {
+    let exterior = polygon.exterior();
+    // Empty holes don't affect the polygon
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter(|interior| !interior.0.is_empty())
+        .collect::<Vec<_>>();
+    if exterior.0.is_empty() {
+        return interiors
+            .first()
+            .map(|interior| (InvalidReason::HoleOutsideShell, interior.0[0]));
+    }
+    let rings = std::iter::once(exterior)
+        .chain(interiors.iter().copied())
+        .collect::<Vec<_>>();
+
+    if let Some(problem) = rings.iter().find_map(|ring| check_ring(ring)) {
+        return Some(problem);
+    }
+    for i in 0..rings.len() {
+        for j in i + 1..rings.len() {
+            if let Some(problem) = rings_intersection(rings[i], rings[j]) {
+                return Some(problem);
+            }
+        }
+    }
+    for interior in &interiors {
+        if ring_inside(interior, exterior).is_none() {
+            let vertex = interior
+                .0
+                .iter()
+                .find(|coord| !exterior.intersects(**coord))
+                .unwrap_or(&interior.0[0]);
+            return Some((InvalidReason::HoleOutsideShell, *vertex));
+        }
+    }
+    for (i, interior) in interiors.iter().enumerate() {
+        for (j, other) in interiors.iter().enumerate() {
+            if i != j {
+                if let Some(vertex) = ring_inside(interior, other) {
+                    return Some((InvalidReason::NestedHoles, vertex));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn check_multi_polygon(multi_polygon: &geo::MultiPolygon) -> Option<Problem> {
+    if let Some(problem) = multi_polygon.iter().find_map(check_polygon) {
+        return Some(problem);
+    }
+    let polygons = multi_polygon
+        .iter()
+        .filter(|polygon| !polygon.exterior().0.is_empty())
+        .collect::<Vec<_>>();
+    for i in 0..polygons.len() {
+        for j in i + 1..polygons.len() {
+            let (a, b) = (polygons[i], polygons[j]);
+            let rect_a = a.bounding_rect();
+            let rect_b = b.bounding_rect();
+            if rect_a
+                .zip(rect_b)
+                .is_some_and(|(ra, rb)| !ra.intersects(&rb))
+            {
+                continue;
+            }
+            let rings_a = std::iter::once(a.exterior()).chain(a.interiors());
+            for ring_a in rings_a {
+                let rings_b = std::iter::once(b.exterior()).chain(b.interiors());
+                for ring_b in rings_b {
+                    if let Some(problem) = rings_intersection(ring_a, ring_b) {
+                        return Some(problem);
+                    }
+                }
+            }
+            // A shell inside another polygon, and not in one of its holes
+            for (inner, outer) in [(a, b), (b, a)] {
+                let vertex = inner
+                    .exterior()
+                    .0
+                    .iter()
+                    .find(|coord| !outer.exterior().intersects(**coord));
+                if let Some(vertex) = vertex {
+                    if outer.contains(*vertex) {
+                        return Some((InvalidReason::NestedShells, *vertex));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MultiPolygonArray, PolygonArray};
+    use crate::trait_::ArrayAccessor;
+    use arrow_array::cast::AsArray;
+    use arrow_array::Array;
+    use geo::{coord, line_string, point, polygon};
+
+    fn reason(geom: impl Into<geo::Geometry>) -> Option<InvalidReason> {
+        check(&geom.into()).map(|(reason, _)| reason)
+    }
+
+    #[test]
+    fn valid_geometries() {
+        assert_eq!(reason(point!(x: 1., y: 2.)), None);
+        assert_eq!(reason(point!(x: f64::NAN, y: f64::NAN)), None);
+        assert_eq!(reason(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]), None);
+        assert_eq!(
+            reason(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                interiors: [
+                    [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+                    // Touching the exterior at a point
+                    [(x: 5., y: 0.), (x: 6., y: 1.), (x: 4., y: 1.)],
+                ],
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid_geometries() {
+        assert_eq!(
+            check(&point!(x: f64::INFINITY, y: 0.).into()),
+            Some((
+                InvalidReason::InvalidCoordinate,
+                coord! { x: f64::INFINITY, y: 0. }
+            ))
+        );
+        assert_eq!(
+            reason(line_string![(x: 1., y: 1.), (x: 1., y: 1.)]),
+            Some(InvalidReason::TooFewPoints)
+        );
+        assert_eq!(
+            reason(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)]),
+            Some(InvalidReason::TooFewPoints)
+        );
+        assert_eq!(
+            check(&polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)].into()),
+            Some((InvalidReason::RingSelfIntersection, coord! { x: 1., y: 1. }))
+        );
+
+        let exterior = [(0., 0.), (10., 0.), (10., 10.), (0., 10.)];
+        let with_hole =
+            |hole: Vec<(f64, f64)>| geo::Polygon::new(exterior.to_vec().into(), vec![hole.into()]);
+        assert_eq!(
+            reason(with_hole(vec![(8., 5.), (12., 5.), (12., 6.), (8., 6.)])),
+            Some(InvalidReason::SelfIntersection)
+        );
+        assert_eq!(
+            reason(with_hole(vec![(20., 20.), (21., 20.), (21., 21.)])),
+            Some(InvalidReason::HoleOutsideShell)
+        );
+
+        let nested = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)],
+                [(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.)],
+            ],
+        );
+        assert_eq!(reason(nested), Some(InvalidReason::NestedHoles));
+    }
+
+    #[test]
+    fn invalid_multi_polygons() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let overlapping =
+            polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)];
+        let inner = polygon![(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.)];
+        let disjoint = polygon![(x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.)];
+
+        assert_eq!(
+            reason(geo::MultiPolygon::new(vec![square.clone(), disjoint])),
+            None
+        );
+        assert_eq!(
+            reason(geo::MultiPolygon::new(vec![square.clone(), overlapping])),
+            Some(InvalidReason::SelfIntersection)
+        );
+        assert_eq!(
+            reason(geo::MultiPolygon::new(vec![square, inner])),
+            Some(InvalidReason::NestedShells)
+        );
+    }
+
+    #[test]
+    fn detail_array() {
+        let polygons: PolygonArray = (
+            vec![
+                Some(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]),
+                None,
+                Some(polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)]),
+            ],
+            Dimension::XY,
+        )
+            .into();
+        let detail = is_valid_detail(&polygons).unwrap();
+        assert_eq!(detail.len(), 3);
+        assert!(detail.is_null(1));
+
+        let is_valid = detail.column(0).as_boolean();
+        assert!(is_valid.value(0));
+        assert!(!is_valid.value(2));
+
+        let reasons = detail.column(1).as_string::<i32>();
+        assert!(reasons.is_null(0));
+        assert_eq!(reasons.value(2), "Ring Self-intersection");
+
+        let locations =
+            crate::array::PointArray::try_from((detail.column(2).as_ref(), Dimension::XY)).unwrap();
+        assert!(locations.is_null(0));
+        assert_eq!(locations.value_as_geo(2), point!(x: 1., y: 1.));
+
+        let multi_polygons: MultiPolygonArray = (
+            vec![geo::MultiPolygon::new(vec![])].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let detail = is_valid_detail(&multi_polygons).unwrap();
+        assert!(detail.column(0).as_boolean().value(0));
+    }
+}
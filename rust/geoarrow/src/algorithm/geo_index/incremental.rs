@@ -0,0 +1,212 @@
+use geo_index::rtree::sort::HilbertSort;
+use geo_index::rtree::{RTreeBuilder, RTreeIndex, RTreeRef};
+use geo_traits::{CoordTrait, RectTrait};
+
+use crate::algorithm::native::BoundingRectArray;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// The default number of items per node of the R-tree.
+pub const DEFAULT_NODE_SIZE: usize = 16;
+
+/// Build a [`SpatialIndex`] incrementally from a stream of arrays.
+///
+/// Only the bounding box of each geometry is kept while batches are pushed, so the geometries
+/// themselves don't need to stay in memory. The R-tree is bulk-loaded once in
+/// [`finish`][Self::finish].
+///
+/// Null and empty geometries are not inserted into the tree, but still count towards the row
+/// indices returned by [`SpatialIndex::search`].
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo_index::SpatialIndexBuilder;
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+///
+/// let batch: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+///
+/// let mut builder = SpatialIndexBuilder::new();
+/// builder.push_array(&batch).unwrap();
+/// builder.push_array(&batch).unwrap();
+/// let index = builder.finish().unwrap();
+/// assert_eq!(index.search(0., 0., 5., 5.), vec![0, 1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpatialIndexBuilder {
+    node_size: usize,
+    boxes: Vec<[f64; 4]>,
+    row_ids: Vec<u64>,
+    num_rows: u64,
+}
+
+impl SpatialIndexBuilder {
+    /// Create a new builder with the default node size.
+    pub fn new() -> Self {
+        Self::with_node_size(DEFAULT_NODE_SIZE)
+    }
+
+    /// Create a new builder with the given number of items per node.
+    pub fn with_node_size(node_size: usize) -> Self {
+        Self {
+            node_size,
+            boxes: vec![],
+            row_ids: vec![],
+            num_rows: 0,
+        }
+    }
+
+    /// The number of rows pushed so far.
+    pub fn num_rows(&self) -> u64 {
+        self.num_rows
+    }
+
+    /// Add the geometries of an array to the index.
+    ///
+    /// Rows are numbered in the order they are pushed, continuing from the previous array.
+    pub fn push_array(&mut self, array: &dyn NativeArray) -> Result<()> {
+        let rects = array.bounding_rect()?;
+        for (row_idx, rect) in rects.iter().enumerate() {
+            if let Some(rect) = rect {
+                let (min, max) = (rect.min(), rect.max());
+                // Empty geometries have an inverted bounding box
+                if min.x() <= max.x() && min.y() <= max.y() {
+                    self.boxes.push([min.x(), min.y(), max.x(), max.y()]);
+                    self.row_ids.push(self.num_rows + row_idx as u64);
+                }
+            }
+        }
+        self.num_rows += array.len() as u64;
+        Ok(())
+    }
+
+    /// Bulk-load the R-tree from all pushed geometries.
+    pub fn finish(self) -> Result<SpatialIndex> {
+        let tree = if self.boxes.is_empty() {
+            vec![]
+        } else {
+            let mut builder = RTreeBuilder::new_with_node_size(self.boxes.len(), self.node_size);
+            for [min_x, min_y, max_x, max_y] in self.boxes {
+                builder.add(min_x, min_y, max_x, max_y);
+            }
+            builder.finish::<HilbertSort>().into_inner()
+        };
+        SpatialIndex::try_new(tree, self.row_ids, self.num_rows)
+    }
+}
+
+impl Default for SpatialIndexBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A static R-tree over the rows of a dataset, which may span many arrays.
+///
+/// Create one with a [`SpatialIndexBuilder`], or load a persisted index with
+/// [`read_sidecar_index`][super::read_sidecar_index].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialIndex {
+    /// The serialized R-tree. Empty if no geometries were indexed.
+    tree: Vec<u8>,
+    /// The row index of each item in the tree.
+    row_ids: Vec<u64>,
+    num_rows: u64,
+}
+
+impl SpatialIndex {
+    pub(crate) fn try_new(tree: Vec<u8>, row_ids: Vec<u64>, num_rows: u64) -> Result<Self> {
+        let index = Self {
+            tree,
+            row_ids,
+            num_rows,
+        };
+        let num_items = index.tree_ref()?.map_or(0, |tree| tree.num_items());
+        if num_items != index.row_ids.len() {
+            return Err(GeoArrowError::General(format!(
+                "Spatial index has {} items but {} row ids",
+                num_items,
+                index.row_ids.len()
+            )));
+        }
+        Ok(index)
+    }
+
+    fn tree_ref(&self) -> Result<Option<RTreeRef<'_, f64>>> {
+        if self.tree.is_empty() {
+            return Ok(None);
+        }
+        let tree = RTreeRef::try_new(&self.tree)
+            .map_err(|err| GeoArrowError::General(format!("Invalid spatial index: {err}")))?;
+        Ok(Some(tree))
+    }
+
+    /// The serialized R-tree.
+    pub(crate) fn tree(&self) -> &[u8] {
+        &self.tree
+    }
+
+    /// The row index of each item in the tree.
+    pub(crate) fn row_ids(&self) -> &[u64] {
+        &self.row_ids
+    }
+
+    /// The number of rows of the indexed dataset, including null and empty geometries.
+    pub fn num_rows(&self) -> u64 {
+        self.num_rows
+    }
+
+    /// Returns the sorted row indices of all geometries whose bounding box intersects the given
+    /// box.
+    pub fn search(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<u64> {
+        // The tree was validated on construction
+        let Some(tree) = self.tree_ref().unwrap() else {
+            return vec![];
+        };
+        let mut rows: Vec<u64> = tree
+            .search(min_x, min_y, max_x, max_y)
+            .into_iter()
+            .map(|item_idx| self.row_ids[item_idx])
+            .collect();
+        rows.sort_unstable();
+        rows
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointBuilder;
+    use crate::datatypes::Dimension;
+    use crate::test::{linestring, point};
+
+    #[test]
+    fn incremental_matches_row_numbers() {
+        let mut builder = SpatialIndexBuilder::with_node_size(4);
+        builder.push_array(&point::point_array()).unwrap();
+
+        let mut nulls = PointBuilder::new(Dimension::XY);
+        nulls.push_null();
+        nulls.push_empty();
+        builder.push_array(&nulls.finish()).unwrap();
+
+        builder.push_array(&linestring::ls_array()).unwrap();
+        let index = builder.finish().unwrap();
+
+        assert_eq!(index.num_rows(), 7);
+        // Every indexed geometry is found by a search over everything
+        assert_eq!(
+            index.search(f64::MIN, f64::MIN, f64::MAX, f64::MAX),
+            vec![0, 1, 2, 5, 6]
+        );
+    }
+
+    #[test]
+    fn empty_index() {
+        let index = SpatialIndexBuilder::new().finish().unwrap();
+        assert_eq!(index.num_rows(), 0);
+        assert!(index.search(0., 0., 1., 1.).is_empty());
+    }
+}
@@ -1,3 +1,10 @@
+mod incremental;
 pub mod rtree;
+mod sidecar;
 
+pub use incremental::{SpatialIndex, SpatialIndexBuilder, DEFAULT_NODE_SIZE};
 pub use rtree::RTree;
+pub use sidecar::{
+    load_or_build_sidecar_index, read_sidecar_index, sidecar_path, write_sidecar_index,
+    SIDECAR_EXTENSION,
+};
@@ -0,0 +1,224 @@
+//! Persist a [`SpatialIndex`] as a sidecar file next to the dataset it indexes.
+//!
+//! The sidecar stores a hash of the contents of the data file. When the data file changes, the
+//! stored index no longer matches and [`read_sidecar_index`] returns `None`, so that the index can
+//! be rebuilt.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::algorithm::geo_index::SpatialIndex;
+use crate::error::{GeoArrowError, Result};
+
+/// Magic bytes at the start of every sidecar index file.
+const MAGIC: &[u8; 8] = b"GARTREE\0";
+
+/// The version of the sidecar file layout.
+const VERSION: u32 = 1;
+
+/// The extension appended to the data file name to get the sidecar file name.
+pub const SIDECAR_EXTENSION: &str = "rtree";
+
+/// The path of the sidecar index file for a data file, e.g. `data.parquet.rtree` for
+/// `data.parquet`.
+pub fn sidecar_path(data_path: impl AsRef<Path>) -> PathBuf {
+    let mut path = data_path.as_ref().as_os_str().to_owned();
+    path.push(".");
+    path.push(SIDECAR_EXTENSION);
+    path.into()
+}
+
+/// Hash the contents of a file with 64-bit FNV-1a.
+///
+/// FNV-1a is used because its output is stable across platforms and releases.
+fn hash_file(path: &Path) -> Result<u64> {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut buf = vec![0; 64 * 1024];
+    let mut hash = OFFSET_BASIS;
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        for byte in &buf[..n] {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    Ok(hash)
+}
+
+/// Write a spatial index of the data file at `data_path` to its sidecar file.
+///
+/// Any existing sidecar file is overwritten.
+pub fn write_sidecar_index(data_path: impl AsRef<Path>, index: &SpatialIndex) -> Result<()> {
+    let data_path = data_path.as_ref();
+    let hash = hash_file(data_path)?;
+
+    let mut writer = BufWriter::new(File::create(sidecar_path(data_path))?);
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&hash.to_le_bytes())?;
+    writer.write_all(&index.num_rows().to_le_bytes())?;
+    writer.write_all(&(index.row_ids().len() as u64).to_le_bytes())?;
+    for row_id in index.row_ids() {
+        writer.write_all(&row_id.to_le_bytes())?;
+    }
+    writer.write_all(&(index.tree().len() as u64).to_le_bytes())?;
+    writer.write_all(index.tree())?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// Take `len` bytes from the `remaining` bytes of a sidecar file, so that corrupt lengths error
+/// before anything is allocated for them.
+fn take_bytes(remaining: &mut u64, len: Option<u64>, what: &str) -> Result<()> {
+    match len {
+        Some(len) if len <= *remaining => {
+            *remaining -= len;
+            Ok(())
+        }
+        _ => Err(GeoArrowError::General(format!(
+            "Corrupt spatial index sidecar file: the {what} extend past the end of the file"
+        ))),
+    }
+}
+
+/// Load the spatial index of the data file at `data_path` from its sidecar file.
+///
+/// Returns `None` if there is no sidecar file, or if it was written for different data file
+/// contents or by an incompatible version. Returns an error if the sidecar file is corrupt.
+pub fn read_sidecar_index(data_path: impl AsRef<Path>) -> Result<Option<SpatialIndex>> {
+    let data_path = data_path.as_ref();
+    let file = match File::open(sidecar_path(data_path)) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let mut remaining = file.metadata()?.len();
+    let mut reader = BufReader::new(file);
+
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(GeoArrowError::General(
+            "Not a spatial index sidecar file".to_string(),
+        ));
+    }
+    if read_u32(&mut reader)? != VERSION || read_u64(&mut reader)? != hash_file(data_path)? {
+        return Ok(None);
+    }
+
+    let num_rows = read_u64(&mut reader)?;
+    let num_items = read_u64(&mut reader)?;
+    // The header is the magic bytes, the version, the hash, the number of rows and the number
+    // of items.
+    take_bytes(
+        &mut remaining,
+        Some(MAGIC.len() as u64 + 4 + 8 * 3),
+        "header",
+    )?;
+    take_bytes(&mut remaining, num_items.checked_mul(8), "row ids")?;
+    let row_ids = (0..num_items)
+        .map(|_| read_u64(&mut reader))
+        .collect::<Result<Vec<_>>>()?;
+    let tree_len = read_u64(&mut reader)?;
+    take_bytes(&mut remaining, Some(8), "tree length")?;
+    take_bytes(&mut remaining, Some(tree_len), "tree")?;
+    let mut tree = vec![0; tree_len as usize];
+    reader.read_exact(&mut tree)?;
+
+    Ok(Some(SpatialIndex::try_new(tree, row_ids, num_rows)?))
+}
+
+/// Load the spatial index of a data file from its sidecar file, or build it with `build` and
+/// persist it if the sidecar file is missing or stale.
+pub fn load_or_build_sidecar_index<F>(data_path: impl AsRef<Path>, build: F) -> Result<SpatialIndex>
+where
+    F: FnOnce() -> Result<SpatialIndex>,
+{
+    let data_path = data_path.as_ref();
+    if let Some(index) = read_sidecar_index(data_path)? {
+        return Ok(index);
+    }
+    let index = build()?;
+    write_sidecar_index(data_path, &index)?;
+    Ok(index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo_index::SpatialIndexBuilder;
+    use crate::test::point;
+
+    #[test]
+    fn round_trip_and_invalidate() {
+        let dir = std::env::temp_dir().join(format!("geoarrow-sidecar-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let data_path = dir.join("data.bin");
+        std::fs::write(&data_path, b"some data").unwrap();
+
+        let mut builder = SpatialIndexBuilder::new();
+        builder.push_array(&point::point_array()).unwrap();
+        let index = builder.finish().unwrap();
+
+        assert!(read_sidecar_index(&data_path).unwrap().is_none());
+        write_sidecar_index(&data_path, &index).unwrap();
+        assert_eq!(read_sidecar_index(&data_path).unwrap(), Some(index.clone()));
+
+        // Changing the data invalidates the index
+        std::fs::write(&data_path, b"other data").unwrap();
+        assert!(read_sidecar_index(&data_path).unwrap().is_none());
+        let rebuilt = load_or_build_sidecar_index(&data_path, || Ok(index.clone())).unwrap();
+        assert_eq!(rebuilt, index);
+        assert!(read_sidecar_index(&data_path).unwrap().is_some());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn corrupt_lengths() {
+        let dir =
+            std::env::temp_dir().join(format!("geoarrow-sidecar-corrupt-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let data_path = dir.join("data.bin");
+        std::fs::write(&data_path, b"some data").unwrap();
+
+        let mut builder = SpatialIndexBuilder::new();
+        builder.push_array(&point::point_array()).unwrap();
+        write_sidecar_index(&data_path, &builder.finish().unwrap()).unwrap();
+
+        // Overwrite the number of items with a count that doesn't fit in the file
+        let mut bytes = std::fs::read(sidecar_path(&data_path)).unwrap();
+        bytes[28..36].copy_from_slice(&u64::MAX.to_le_bytes());
+        std::fs::write(sidecar_path(&data_path), &bytes).unwrap();
+        assert!(read_sidecar_index(&data_path).is_err());
+
+        // Truncate the tree
+        let mut builder = SpatialIndexBuilder::new();
+        builder.push_array(&point::point_array()).unwrap();
+        write_sidecar_index(&data_path, &builder.finish().unwrap()).unwrap();
+        let bytes = std::fs::read(sidecar_path(&data_path)).unwrap();
+        std::fs::write(sidecar_path(&data_path), &bytes[..bytes.len() - 1]).unwrap();
+        assert!(read_sidecar_index(&data_path).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
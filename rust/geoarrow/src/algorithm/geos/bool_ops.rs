@@ -45,7 +45,7 @@ macro_rules! impl_method_geometry {
                 |left, right| {
                     let left = to_geos_geometry(&left)?;
                     let right = to_geos_geometry(&right)?;
-                    let out = left.difference(&right)?;
+                    let out = left.$method_name(&right)?;
                     Ok(GEOSGeometry::new(out))
                 },
                 false,
@@ -0,0 +1,54 @@
+use crate::algorithm::geos::util::try_unary_geometry;
+use crate::array::GeometryArray;
+use crate::error::Result;
+use crate::io::geos::{from_geos_vec, to_geos_vec};
+use crate::NativeArray;
+use geos::Geom;
+
+/// Repair invalid geometries, such as self-intersecting polygons, without losing any vertices.
+///
+/// Valid geometries are returned unchanged, although GEOS may normalize their type.
+pub trait MakeValid {
+    type Output;
+
+    fn make_valid(&self) -> Self::Output;
+}
+
+impl MakeValid for GeometryArray {
+    type Output = Result<GeometryArray>;
+
+    fn make_valid(&self) -> Self::Output {
+        try_unary_geometry(self, |g| g.make_valid())
+    }
+}
+
+impl MakeValid for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn make_valid(&self) -> Self::Output {
+        let geoms = to_geos_vec(*self)?
+            .into_iter()
+            .map(|geom| geom.map(|geom| geom.make_valid()).transpose())
+            .collect::<std::result::Result<Vec<_>, geos::Error>>()?;
+        from_geos_vec(geoms, self.coord_type(), self.metadata())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geos::IsValid;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use geo::polygon;
+
+    #[test]
+    fn bowtie() {
+        let bowtie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        let arr: PolygonArray = (vec![bowtie].as_slice(), Dimension::XY).into();
+        assert!(!IsValid::is_valid(&arr).unwrap().value(0));
+
+        let valid = arr.as_ref().make_valid().unwrap();
+        assert!(IsValid::is_valid(&valid).unwrap().value(0));
+    }
+}
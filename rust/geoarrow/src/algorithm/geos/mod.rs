@@ -8,6 +8,7 @@ mod is_ring;
 mod is_simple;
 mod is_valid;
 mod length;
+mod make_valid;
 mod util;
 
 pub use area::Area;
@@ -18,3 +19,4 @@ pub use is_ring::IsRing;
 pub use is_simple::IsSimple;
 pub use is_valid::IsValid;
 pub use length::Length;
+pub use make_valid::MakeValid;
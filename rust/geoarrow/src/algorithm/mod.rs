@@ -2,6 +2,7 @@
 
 #![allow(missing_docs)] // FIXME
 
+pub mod backend;
 pub mod broadcasting;
 pub mod geo;
 pub mod geo_index;
@@ -13,3 +14,4 @@ pub mod polylabel;
 #[cfg(feature = "proj")]
 pub mod proj;
 pub mod rstar;
+pub mod tile;
@@ -34,6 +34,19 @@ impl BoundingRect {
         }
     }
 
+    /// A bounding rect from the minimum and maximum x, y and z values. The z values are infinite
+    /// for two-dimensional bounds.
+    pub(crate) fn from_extent(min: [f64; 3], max: [f64; 3]) -> Self {
+        BoundingRect {
+            minx: min[0],
+            miny: min[1],
+            minz: min[2],
+            maxx: max[0],
+            maxy: max[1],
+            maxz: max[2],
+        }
+    }
+
     pub fn minx(&self) -> f64 {
         self.minx
     }
@@ -69,7 +82,11 @@ impl BoundingRect {
     pub fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
         let x = coord.x();
         let y = coord.y();
-        let z = coord.nth(2);
+        // The third value of an XYM coordinate is a measure, not a Z value
+        let z = match coord.dim() {
+            geo_traits::Dimensions::Xym => None,
+            _ => coord.nth(2),
+        };
 
         if x < self.minx {
             self.minx = x;
@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use arrow_buffer::OffsetBuffer;
+
+use crate::algorithm::native::fix_rings::{coord_buffer_builder, coords_equal};
+use crate::algorithm::native::{FixRings, FixRingsOptions, RingOrientation};
+use crate::array::*;
+use crate::datatypes::NativeType;
+use crate::{ArrayBase, NativeArray};
+
+/// The ring options used by [`Canonicalize`].
+const CANONICAL_RINGS: FixRingsOptions = FixRingsOptions {
+    close_rings: true,
+    orientation: Some(RingOrientation::CounterClockwise),
+};
+
+/// Bring geometries into a canonical form.
+///
+/// This:
+///
+/// - strips consecutive duplicate coordinates from line strings and polygon rings,
+/// - closes polygon rings whose last coordinate differs from their first coordinate,
+/// - orients exterior rings counterclockwise and interior rings clockwise.
+///
+/// The output is spec-compliant for GeoJSON ([RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946))
+/// and GeoParquet writers. It has the same type, coordinate type and dimension as the input.
+/// Points, multi points and rects are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::Canonicalize;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let line_string = geo::line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 1.)];
+/// let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+///
+/// let canonical = array.canonicalize();
+/// assert_eq!(canonical.value_as_geo(0).0.len(), 2);
+/// ```
+pub trait Canonicalize {
+    type Output;
+
+    fn canonicalize(&self) -> Self::Output;
+}
+
+/// Remove consecutive duplicate coordinates within each range given by `offsets`.
+///
+/// Returns the new coordinates and offsets. Coordinates before the first offset, which belong to
+/// sliced-off geometries, are dropped.
+fn remove_consecutive_duplicates(
+    coords: &CoordBuffer,
+    offsets: &OffsetBuffer<i32>,
+) -> (CoordBuffer, OffsetBuffer<i32>) {
+    let mut builder = coord_buffer_builder(coords, coords.len());
+    let mut lengths = Vec::with_capacity(offsets.len() - 1);
+
+    for window in offsets.windows(2) {
+        let (start, end) = (window[0] as usize, window[1] as usize);
+        let mut length = 0;
+        for i in start..end {
+            if i == start || !coords_equal(coords, i - 1, i) {
+                builder.push_coord(&coords.value(i));
+                length += 1;
+            }
+        }
+        lengths.push(length);
+    }
+
+    (builder.into(), OffsetBuffer::from_lengths(lengths))
+}
+
+impl Canonicalize for PointArray {
+    type Output = PointArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl Canonicalize for MultiPointArray {
+    type Output = MultiPointArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl Canonicalize for RectArray {
+    type Output = RectArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl Canonicalize for LineStringArray {
+    type Output = LineStringArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let (coords, geom_offsets) =
+            remove_consecutive_duplicates(&self.coords, &self.geom_offsets);
+        LineStringArray::new(coords, geom_offsets, self.validity.clone(), self.metadata())
+    }
+}
+
+impl Canonicalize for MultiLineStringArray {
+    type Output = MultiLineStringArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let (coords, ring_offsets) =
+            remove_consecutive_duplicates(&self.coords, &self.ring_offsets);
+        MultiLineStringArray::new(
+            coords,
+            self.geom_offsets.clone(),
+            ring_offsets,
+            self.validity.clone(),
+            self.metadata(),
+        )
+    }
+}
+
+impl Canonicalize for PolygonArray {
+    type Output = PolygonArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let (coords, ring_offsets) =
+            remove_consecutive_duplicates(&self.coords, &self.ring_offsets);
+        let deduplicated = PolygonArray::new(
+            coords,
+            self.geom_offsets.clone(),
+            ring_offsets,
+            self.validity.clone(),
+            self.metadata(),
+        );
+        deduplicated.fix_rings(CANONICAL_RINGS).0
+    }
+}
+
+impl Canonicalize for MultiPolygonArray {
+    type Output = MultiPolygonArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let (coords, ring_offsets) =
+            remove_consecutive_duplicates(&self.coords, &self.ring_offsets);
+        let deduplicated = MultiPolygonArray::new(
+            coords,
+            self.geom_offsets.clone(),
+            self.polygon_offsets.clone(),
+            ring_offsets,
+            self.validity.clone(),
+            self.metadata(),
+        );
+        deduplicated.fix_rings(CANONICAL_RINGS).0
+    }
+}
+
+impl Canonicalize for MixedGeometryArray {
+    type Output = MixedGeometryArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let mut output = self.clone();
+        output.line_strings = self.line_strings.canonicalize();
+        output.polygons = self.polygons.canonicalize();
+        output.multi_line_strings = self.multi_line_strings.canonicalize();
+        output.multi_polygons = self.multi_polygons.canonicalize();
+        output
+    }
+}
+
+impl Canonicalize for GeometryCollectionArray {
+    type Output = GeometryCollectionArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let mut output = self.clone();
+        output.array = self.array.canonicalize();
+        output
+    }
+}
+
+impl Canonicalize for GeometryArray {
+    type Output = GeometryArray;
+
+    fn canonicalize(&self) -> Self::Output {
+        let mut output = self.clone();
+
+        macro_rules! canonicalize_child {
+            ($($field:ident),*) => {
+                $(output.$field = self.$field.canonicalize();)*
+            };
+        }
+
+        canonicalize_child!(
+            line_string_xy,
+            polygon_xy,
+            mline_string_xy,
+            mpolygon_xy,
+            gc_xy,
+            line_string_xyz,
+            polygon_xyz,
+            mline_string_xyz,
+            mpolygon_xyz,
+            gc_xyz
+        );
+
+        output
+    }
+}
+
+impl Canonicalize for &dyn NativeArray {
+    type Output = Arc<dyn NativeArray>;
+
+    fn canonicalize(&self) -> Self::Output {
+        use NativeType::*;
+
+        match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().canonicalize()),
+            LineString(_, _) => Arc::new(self.as_line_string().canonicalize()),
+            Polygon(_, _) => Arc::new(self.as_polygon().canonicalize()),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().canonicalize()),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().canonicalize()),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().canonicalize()),
+            GeometryCollection(_, _) => Arc::new(self.as_geometry_collection().canonicalize()),
+            Rect(_) => Arc::new(self.as_rect().canonicalize()),
+            Geometry(_) => Arc::new(self.as_geometry().canonicalize()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::Winding;
+
+    #[test]
+    fn canonicalize_polygon() {
+        // A clockwise exterior ring with a repeated coordinate
+        let polygon = geo::polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: 0., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 0.),
+        ];
+        let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+        let canonical = array.canonicalize();
+
+        let exterior = canonical.value_as_geo(0).exterior().clone();
+        assert_eq!(exterior.0.len(), 5);
+        assert!(exterior.is_closed());
+        assert!(exterior.is_ccw());
+    }
+
+    #[test]
+    fn canonicalize_sliced_multi_line_string() {
+        let line_strings = vec![
+            geo::MultiLineString::new(vec![geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)]]),
+            geo::MultiLineString::new(vec![geo::line_string![
+                (x: 2., y: 2.),
+                (x: 2., y: 2.),
+                (x: 3., y: 3.)
+            ]]),
+        ];
+        let array: MultiLineStringArray = (line_strings.as_slice(), Dimension::XY).into();
+        let canonical = (&array.slice(1, 1) as &dyn NativeArray).canonicalize();
+
+        let canonical = canonical.as_ref().as_multi_line_string();
+        assert_eq!(canonical.len(), 1);
+        assert_eq!(
+            canonical.value_as_geo(0),
+            geo::MultiLineString::new(vec![geo::line_string![(x: 2., y: 2.), (x: 3., y: 3.)]])
+        );
+    }
+}
@@ -7,10 +7,12 @@
 
 use std::sync::Arc;
 
+use crate::algorithm::native::ForceDimension;
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
+use crate::io::NativeArrayReader;
 use crate::NativeArray;
 
 /// CastOptions provides a way to override the default cast behaviors
@@ -50,7 +52,8 @@ fn can_cast_types(from_type: NativeType, to_type: NativeType) -> bool {
 pub trait Cast {
     type Output;
 
-    /// Note: **does not currently implement dimension casts**
+    /// Note: **only casts between dimensions on `&dyn NativeArray` and geometry arrays**, where
+    /// new Z and M values are filled with NaN
     fn cast(&self, to_type: NativeType) -> Self::Output;
 }
 
@@ -65,10 +68,8 @@ impl Cast for PointArray {
             Point(_, _) => Ok(Arc::new(array)),
             MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::from(array))),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -85,10 +86,8 @@ impl Cast for LineStringArray {
             LineString(_, _) => Ok(Arc::new(array)),
             MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::from(array))),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -105,10 +104,8 @@ impl Cast for PolygonArray {
             Polygon(_, _) => Ok(Arc::new(array)),
             MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::from(array))),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -125,10 +122,8 @@ impl Cast for MultiPointArray {
             Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
             MultiPoint(_, _) => Ok(Arc::new(array)),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -144,10 +139,8 @@ impl Cast for MultiLineStringArray {
         match to_type {
             LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -163,10 +156,8 @@ impl Cast for MultiPolygonArray {
         match to_type {
             Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -187,9 +178,7 @@ impl Cast for MixedGeometryArray {
             MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::try_from(array)?)),
             MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::try_from(array)?)),
             GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -210,10 +199,8 @@ impl Cast for GeometryCollectionArray {
             MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::try_from(array)?)),
             MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::try_from(array)?)),
             GeometryCollection(_, _) => Ok(Arc::new(array)),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+            Geometry(_) => Ok(Arc::new(GeometryArray::try_from(array)?)),
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
         }
     }
 }
@@ -222,13 +209,34 @@ impl Cast for GeometryArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
     fn cast(&self, to_type: NativeType) -> Self::Output {
-        // TODO: validate dimension
-        let array = self.to_coord_type(to_type.coord_type());
+        // The 2D geometries are promoted first, as mixed arrays have a single dimension
+        let array = match to_type.dimension() {
+            Some(Dimension::XYZ) if self.has_dimension(Dimension::XY) => self.force_3d(f64::NAN)?,
+            _ => self.clone(),
+        };
+        let array = array.to_coord_type(to_type.coord_type());
         let mixed_array = MixedGeometryArray::try_from(array)?;
         mixed_array.cast(to_type)
     }
 }
 
+impl Cast for RectArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    /// Rects are cast to polygons with a single closed exterior ring of 5 coordinates.
+    fn cast(&self, to_type: NativeType) -> Self::Output {
+        use NativeType::*;
+
+        match to_type {
+            Rect(_) => Ok(Arc::new(self.clone())),
+            Polygon(_, _) | MultiPolygon(_, _) | GeometryCollection(_, _) | Geometry(_) => {
+                PolygonArray::from(self.clone()).cast(to_type)
+            }
+            dt => Err(GeoArrowError::General(format!("invalid cast to type {dt}"))),
+        }
+    }
+}
+
 impl Cast for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
@@ -240,6 +248,13 @@ impl Cast for &dyn NativeArray {
 
         use NativeType::*;
 
+        if let (Some(from), Some(to)) = (self.data_type().dimension(), to_type.dimension()) {
+            if from != to {
+                let forced = self.force_dim(to, f64::NAN, f64::NAN)?;
+                return forced.as_ref().cast(to_type);
+            }
+        }
+
         match self.data_type() {
             Point(_, _) => self.as_ref().as_point().cast(to_type),
             LineString(_, _) => self.as_ref().as_line_string().cast(to_type),
@@ -248,8 +263,8 @@ impl Cast for &dyn NativeArray {
             MultiLineString(_, _) => self.as_ref().as_multi_line_string().cast(to_type),
             MultiPolygon(_, _) => self.as_ref().as_multi_polygon().cast(to_type),
             GeometryCollection(_, _) => self.as_ref().as_geometry_collection().cast(to_type),
+            Rect(_) => self.as_ref().as_rect().cast(to_type),
             Geometry(_) => self.as_ref().as_geometry().cast(to_type),
-            _ => todo!(),
         }
     }
 }
@@ -303,3 +318,137 @@ impl_chunked_cast!(ChunkedMultiPolygonArray);
 impl_chunked_cast!(ChunkedMixedGeometryArray);
 impl_chunked_cast!(ChunkedGeometryCollectionArray);
 impl_chunked_cast!(ChunkedUnknownGeometryArray);
+
+/// A [`NativeArrayReader`] that casts each array of another reader as it is read.
+///
+/// Created by [`cast_reader`].
+pub struct CastReader {
+    reader: Box<dyn NativeArrayReader + Send>,
+    to_type: NativeType,
+}
+
+impl Iterator for CastReader {
+    type Item = Result<Arc<dyn NativeArray>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader
+            .next()
+            .map(|array| array?.as_ref().cast(self.to_type))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.reader.size_hint()
+    }
+}
+
+impl NativeArrayReader for CastReader {
+    fn data_type(&self) -> NativeType {
+        self.to_type
+    }
+}
+
+/// Lazily cast each array of a stream to `to_type`.
+///
+/// Arrays are cast one at a time as they are pulled from the returned reader, so casting a large
+/// dataset never requires holding or concatenating all of its chunks in memory. Errors from the
+/// input reader or from a failed cast are yielded by the returned reader.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use geoarrow::algorithm::native::cast_reader;
+/// use geoarrow::array::{CoordType, PointArray};
+/// use geoarrow::datatypes::{Dimension, NativeType};
+/// use geoarrow::io::{NativeArrayIterator, NativeArrayReader};
+/// use geoarrow::NativeArray;
+///
+/// let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+/// let data_type = array.data_type();
+/// let arrays = vec![Ok(Arc::new(array) as Arc<dyn NativeArray>)];
+/// let reader = NativeArrayIterator::new(arrays, data_type);
+///
+/// let to_type = NativeType::MultiPoint(CoordType::Interleaved, Dimension::XY);
+/// let mut reader = cast_reader(Box::new(reader), to_type);
+/// assert_eq!(reader.data_type(), to_type);
+/// assert_eq!(reader.next().unwrap().unwrap().data_type(), to_type);
+/// ```
+pub fn cast_reader(reader: Box<dyn NativeArrayReader + Send>, to_type: NativeType) -> CastReader {
+    CastReader { reader, to_type }
+}
+
+/// Cast each of a list of arrays to `to_type`, without concatenating them.
+pub fn cast_arrays(
+    arrays: &[Arc<dyn NativeArray>],
+    to_type: NativeType,
+) -> Result<Vec<Arc<dyn NativeArray>>> {
+    arrays
+        .iter()
+        .map(|array| array.as_ref().cast(to_type))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::CoordType;
+    use crate::io::NativeArrayIterator;
+    use crate::test::point;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    #[test]
+    fn cast_reader_chunks() {
+        let array = point::point_array();
+        let data_type = array.data_type();
+        let arrays: Vec<Result<Arc<dyn NativeArray>>> = vec![
+            Ok(Arc::new(array.clone())),
+            Err(GeoArrowError::General("bad chunk".to_string())),
+            Ok(Arc::new(array.clone())),
+        ];
+        let reader = NativeArrayIterator::new(arrays, data_type);
+
+        let to_type = NativeType::Geometry(CoordType::Separated);
+        let mut reader = cast_reader(Box::new(reader), to_type);
+        assert_eq!(reader.data_type(), to_type);
+
+        let first = reader.next().unwrap().unwrap();
+        assert_eq!(first.data_type(), to_type);
+        assert_eq!(first.len(), array.len());
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn cast_rect_to_polygon() {
+        let rect = geo::Rect::new(geo::coord! { x: 0., y: 1. }, geo::coord! { x: 2., y: 3. });
+        let array = RectArray::from((vec![Some(rect), None], Dimension::XY));
+
+        let to_type = NativeType::Polygon(CoordType::Separated, Dimension::XY);
+        let output = array.cast(to_type).unwrap();
+        assert_eq!(output.data_type(), to_type);
+        let polygons = output.as_ref().as_polygon();
+        assert_eq!(polygons.value_as_geo(0), rect.to_polygon());
+        assert_eq!(polygons.value_as_geo(0).exterior().0.len(), 5);
+        assert!(polygons.is_null(1));
+
+        let to_type = NativeType::Geometry(CoordType::Interleaved);
+        let output = (&array as &dyn NativeArray).cast(to_type).unwrap();
+        assert_eq!(output.data_type(), to_type);
+        assert_eq!(output.len(), 2);
+    }
+
+    #[test]
+    fn cast_vec_of_arrays() {
+        let arrays: Vec<Arc<dyn NativeArray>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(point::point_array()),
+        ];
+        let to_type = NativeType::MultiPoint(CoordType::Interleaved, Dimension::XY);
+        let output = cast_arrays(&arrays, to_type).unwrap();
+        assert_eq!(output.len(), 2);
+        assert!(output.iter().all(|array| array.data_type() == to_type));
+    }
+}
@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+
+use crate::algorithm::native::Take;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Copy the referenced ranges of an array's buffers into new, minimal buffers.
+///
+/// Slicing an array is zero-copy, so a small slice of a large array keeps the full coordinate and
+/// offset buffers of the original array alive. Compacting the slice releases the reference to the
+/// original buffers, at the cost of copying the data that is still in use.
+pub trait Compact {
+    type Output;
+
+    fn compact(&self) -> Self::Output;
+}
+
+/// Rebase a (possibly sliced) offset buffer to start at zero.
+///
+/// Returns the new offsets, together with the offset and length of the range of the child array
+/// that they refer to.
+fn rebase_offsets(offsets: &OffsetBuffer<i32>) -> (OffsetBuffer<i32>, usize, usize) {
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let rebased = offsets.iter().map(|o| o - start).collect::<Vec<_>>();
+    (
+        OffsetBuffer::new(rebased.into()),
+        start as usize,
+        (end - start) as usize,
+    )
+}
+
+fn compact_validity(validity: Option<&NullBuffer>) -> Option<NullBuffer> {
+    validity.map(|v| NullBuffer::from(v.iter().collect::<Vec<_>>()))
+}
+
+impl Compact for PointArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        PointArray::new(
+            self.coords.owned_slice(0, self.coords.len()),
+            compact_validity(self.validity.as_ref()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for LineStringArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        let (geom_offsets, coord_offset, coord_length) = rebase_offsets(&self.geom_offsets);
+        LineStringArray::new(
+            self.coords.owned_slice(coord_offset, coord_length),
+            geom_offsets,
+            compact_validity(self.validity.as_ref()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for MultiPointArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        let (geom_offsets, coord_offset, coord_length) = rebase_offsets(&self.geom_offsets);
+        MultiPointArray::new(
+            self.coords.owned_slice(coord_offset, coord_length),
+            geom_offsets,
+            compact_validity(self.validity.as_ref()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for PolygonArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        let (geom_offsets, ring_offset, ring_length) = rebase_offsets(&self.geom_offsets);
+        let (ring_offsets, coord_offset, coord_length) =
+            rebase_offsets(&self.ring_offsets.slice(ring_offset, ring_length));
+        PolygonArray::new(
+            self.coords.owned_slice(coord_offset, coord_length),
+            geom_offsets,
+            ring_offsets,
+            compact_validity(self.validity.as_ref()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for MultiLineStringArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        let (geom_offsets, ring_offset, ring_length) = rebase_offsets(&self.geom_offsets);
+        let (ring_offsets, coord_offset, coord_length) =
+            rebase_offsets(&self.ring_offsets.slice(ring_offset, ring_length));
+        MultiLineStringArray::new(
+            self.coords.owned_slice(coord_offset, coord_length),
+            geom_offsets,
+            ring_offsets,
+            compact_validity(self.validity.as_ref()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for MultiPolygonArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        let (geom_offsets, polygon_offset, polygon_length) = rebase_offsets(&self.geom_offsets);
+        let (polygon_offsets, ring_offset, ring_length) =
+            rebase_offsets(&self.polygon_offsets.slice(polygon_offset, polygon_length));
+        let (ring_offsets, coord_offset, coord_length) =
+            rebase_offsets(&self.ring_offsets.slice(ring_offset, ring_length));
+        MultiPolygonArray::new(
+            self.coords.owned_slice(coord_offset, coord_length),
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+            compact_validity(self.validity.as_ref()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for RectArray {
+    type Output = Self;
+
+    fn compact(&self) -> Self::Output {
+        RectArray::new(
+            self.lower().owned_slice(0, self.len()),
+            self.upper().owned_slice(0, self.len()),
+            compact_validity(self.nulls()),
+            self.metadata(),
+        )
+    }
+}
+
+impl Compact for GeometryCollectionArray {
+    type Output = Result<Self>;
+
+    fn compact(&self) -> Self::Output {
+        // Taking the full range rebuilds the array from only the referenced geometries.
+        self.take_range(&(0..self.len()))
+    }
+}
+
+impl Compact for GeometryArray {
+    type Output = Result<Self>;
+
+    fn compact(&self) -> Self::Output {
+        // Rebuilding from the scalars keeps only the children's referenced ranges. Don't prefer
+        // multi geometries so that each geometry keeps its original type.
+        let geoms = self.iter().collect::<Vec<_>>();
+        Ok(GeometryBuilder::from_nullable_geometries(
+            &geoms,
+            self.coord_type(),
+            self.metadata(),
+            false,
+        )?
+        .finish())
+    }
+}
+
+impl Compact for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn compact(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().compact()),
+            LineString(_, _) => Arc::new(self.as_line_string().compact()),
+            Polygon(_, _) => Arc::new(self.as_polygon().compact()),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().compact()),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().compact()),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().compact()),
+            GeometryCollection(_, _) => Arc::new(self.as_geometry_collection().compact()?),
+            Rect(_) => Arc::new(self.as_rect().compact()),
+            Geometry(_) => Arc::new(self.as_geometry().compact()?),
+        };
+        Ok(result)
+    }
+}
+
+/// Implementation that iterates over chunks
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl Compact for ChunkedGeometryArray<$type> {
+            type Output = ChunkedGeometryArray<$type>;
+
+            fn compact(&self) -> Self::Output {
+                ChunkedGeometryArray::new(self.map(|chunk| chunk.compact()))
+            }
+        }
+    };
+}
+
+chunked_impl!(PointArray);
+chunked_impl!(LineStringArray);
+chunked_impl!(PolygonArray);
+chunked_impl!(MultiPointArray);
+chunked_impl!(MultiLineStringArray);
+chunked_impl!(MultiPolygonArray);
+chunked_impl!(RectArray);
+
+/// Implementation that iterates over chunks
+macro_rules! chunked_impl_fallible {
+    ($type:ty) => {
+        impl Compact for ChunkedGeometryArray<$type> {
+            type Output = Result<ChunkedGeometryArray<$type>>;
+
+            fn compact(&self) -> Self::Output {
+                Ok(ChunkedGeometryArray::new(
+                    self.try_map(|chunk| chunk.compact())?,
+                ))
+            }
+        }
+    };
+}
+
+chunked_impl_fallible!(GeometryCollectionArray);
+chunked_impl_fallible!(GeometryArray);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::test::{linestring, multipolygon, point};
+
+    #[test]
+    fn compact_line_string() {
+        let arr: LineStringArray = (
+            vec![linestring::ls0(), linestring::ls1()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let sliced = arr.slice(1, 1);
+        let compacted = sliced.compact();
+
+        assert_eq!(compacted.value_as_geo(0), linestring::ls1());
+        assert!(compacted.coords.len() < sliced.coords.len());
+        assert_eq!(compacted.geom_offsets[0], 0);
+    }
+
+    #[test]
+    fn compact_multi_polygon() {
+        let arr = multipolygon::mp_array();
+        let compacted = arr.slice(1, 1).compact();
+
+        assert_eq!(compacted.value_as_geo(0), multipolygon::mp1());
+        assert_eq!(
+            compacted.coords.len(),
+            compacted.ring_offsets[compacted.ring_offsets.len() - 1] as usize
+        );
+    }
+
+    #[test]
+    fn compact_chunked() {
+        let arr = point::point_array();
+        let chunked = ChunkedGeometryArray::new(vec![arr.slice(1, 1), arr.slice(0, 2)]);
+        let compacted = chunked.compact();
+
+        assert_eq!(compacted.chunks()[0].coords.len(), 1);
+        assert_eq!(compacted.chunks()[0].value_as_geo(0), arr.value_as_geo(1));
+    }
+}
@@ -1,10 +1,13 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 
+use crate::algorithm::native::cast_arrays;
 use crate::array::*;
 use crate::chunked_array::*;
-use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
 
 pub trait Concatenate: Sized {
     type Output;
@@ -16,7 +19,7 @@ impl Concatenate for &[PointArray] {
     type Output = Result<PointArray>;
 
     fn concatenate(&self) -> Self::Output {
-        let common_dimension = infer_common_dimension(self.iter().map(|arr| arr.dimension()));
+        let common_dimension = infer_common_dimension(self.iter().map(|arr| arr.dimension()))?;
 
         let output_capacity = self.iter().fold(0, |sum, val| sum + val.buffer_lengths());
         let mut builder = PointBuilder::with_capacity(common_dimension, output_capacity);
@@ -33,7 +36,7 @@ macro_rules! impl_concatenate {
 
             fn concatenate(&self) -> Self::Output {
                 let common_dimension =
-                    infer_common_dimension(self.iter().map(|arr| arr.dimension()));
+                    infer_common_dimension(self.iter().map(|arr| arr.dimension()))?;
 
                 let output_capacity = self.iter().fold(<$capacity>::new_empty(), |sum, val| {
                     sum + val.buffer_lengths()
@@ -88,10 +91,81 @@ impl_concatenate!(
     push_geometry_collection
 );
 
-fn infer_common_dimension(dimensions: impl Iterator<Item = Dimension>) -> Dimension {
+impl Concatenate for &[GeometryArray] {
+    type Output = Result<GeometryArray>;
+
+    fn concatenate(&self) -> Self::Output {
+        let coord_type = self.first().map(|arr| arr.coord_type()).unwrap_or_default();
+        let metadata = self.first().map(|arr| arr.metadata()).unwrap_or_default();
+        let mut builder = GeometryBuilder::new_with_options(coord_type, metadata, false);
+        for chunk in self.iter() {
+            for geom in chunk.iter() {
+                builder.push_geometry(geom.as_ref())?;
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+/// Concatenate arrays of any types, after casting them to their common type as given by
+/// [`NativeType::common_type_of`].
+///
+/// Returns an error if there are no arrays, or if the common type is a rect type with more than
+/// one array.
+impl Concatenate for &[Arc<dyn NativeArray>] {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn concatenate(&self) -> Self::Output {
+        let to_type = NativeType::common_type_of(self.iter().map(|arr| arr.data_type()))
+            .ok_or_else(|| {
+                GeoArrowError::General("Cannot concatenate an empty list of arrays".to_string())
+            })?;
+        let arrays = cast_arrays(self, to_type)?;
+
+        macro_rules! impl_concat {
+            ($method:ident) => {
+                Arc::new(
+                    arrays
+                        .iter()
+                        .map(|arr| arr.as_ref().$method().clone())
+                        .collect::<Vec<_>>()
+                        .as_slice()
+                        .concatenate()?,
+                )
+            };
+        }
+
+        use NativeType::*;
+        let result: Arc<dyn NativeArray> = match to_type {
+            Point(_, _) => impl_concat!(as_point),
+            LineString(_, _) => impl_concat!(as_line_string),
+            Polygon(_, _) => impl_concat!(as_polygon),
+            MultiPoint(_, _) => impl_concat!(as_multi_point),
+            MultiLineString(_, _) => impl_concat!(as_multi_line_string),
+            MultiPolygon(_, _) => impl_concat!(as_multi_polygon),
+            GeometryCollection(_, _) => impl_concat!(as_geometry_collection),
+            Geometry(_) => impl_concat!(as_geometry),
+            Rect(_) if arrays.len() == 1 => arrays[0].clone(),
+            Rect(_) => {
+                return Err(GeoArrowError::NotYetImplemented(
+                    "Concatenating rect arrays".to_string(),
+                ))
+            }
+        };
+        Ok(result)
+    }
+}
+
+fn infer_common_dimension(dimensions: impl Iterator<Item = Dimension>) -> Result<Dimension> {
     let dimensions: HashSet<Dimension> = HashSet::from_iter(dimensions);
-    assert_eq!(dimensions.len(), 1);
-    dimensions.into_iter().next().unwrap()
+    match dimensions.len() {
+        0 => Ok(Dimension::XY),
+        1 => Ok(dimensions.into_iter().next().unwrap()),
+        _ => Err(GeoArrowError::General(
+            "Cannot concatenate arrays of different dimensions into an array of one dimension"
+                .to_string(),
+        )),
+    }
 }
 
 impl Concatenate for ChunkedPointArray {
@@ -121,3 +195,51 @@ impl_chunked_concatenate!(ChunkedMultiLineStringArray, MultiLineStringArray);
 impl_chunked_concatenate!(ChunkedMultiPolygonArray, MultiPolygonArray);
 impl_chunked_concatenate!(ChunkedMixedGeometryArray, MixedGeometryArray);
 impl_chunked_concatenate!(ChunkedGeometryCollectionArray, GeometryCollectionArray);
+
+#[cfg(test)]
+mod test {
+    use geo_traits::{CoordTrait, PointTrait};
+
+    use super::*;
+    use crate::test::{linestring, multipoint, point};
+
+    #[test]
+    fn concatenate_mixed_types() {
+        let arrays: Vec<Arc<dyn NativeArray>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(multipoint::mp_array()),
+        ];
+        let output = arrays.as_slice().concatenate().unwrap();
+        assert!(matches!(output.data_type(), NativeType::MultiPoint(_, _)));
+        assert_eq!(output.len(), 5);
+
+        let arrays: Vec<Arc<dyn NativeArray>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(linestring::ls_array()),
+        ];
+        let output = arrays.as_slice().concatenate().unwrap();
+        assert!(matches!(output.data_type(), NativeType::Geometry(_)));
+        assert_eq!(output.len(), 5);
+
+        let arrays: Vec<Arc<dyn NativeArray>> = vec![];
+        assert!(arrays.as_slice().concatenate().is_err());
+    }
+
+    #[test]
+    fn concatenate_mixed_dimensions() {
+        let arrays: Vec<Arc<dyn NativeArray>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(point::point_z_array()),
+        ];
+        let output = arrays.as_slice().concatenate().unwrap();
+        assert!(matches!(
+            output.data_type(),
+            NativeType::Point(_, Dimension::XYZ)
+        ));
+        assert_eq!(output.len(), 6);
+        // The 2D points are promoted with unknown Z values
+        let point = output.as_ref().as_point().value(0);
+        let z = point.coord().unwrap().nth_or_panic(2);
+        assert!(z.is_nan());
+    }
+}
@@ -5,15 +5,21 @@ use std::sync::Arc;
 
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
+use geo_traits::{
+    GeometryCollectionTrait, GeometryTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait,
+};
 
 use crate::algorithm::native::cast::Cast;
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
+use crate::io::NativeArrayReader;
 use crate::schema::GeoSchemaExt;
 use crate::table::Table;
-use crate::NativeArray;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
 
 /// Downcast will change between geometry types but will not affect the dimension of the data.
 ///
@@ -300,64 +306,10 @@ impl Downcast for &dyn NativeArray {
 }
 
 /// Given a set of types, return a single type that the result should be casted to
-fn resolve_types(types: &HashSet<NativeType>) -> NativeType {
-    if types.is_empty() {
-        // TODO: error here
-        panic!("empty types");
-    }
-
-    // If only one type, we can cast to that.
-    if types.len() == 1 {
-        return *types.iter().next().unwrap();
-    }
-
-    // If Geometry is in the type set, short circuit to that.
-    if types.contains(&NativeType::Geometry(CoordType::Interleaved)) {
-        return NativeType::Geometry(CoordType::Interleaved);
-    } else if types.contains(&NativeType::Geometry(CoordType::Separated)) {
-        return NativeType::Geometry(CoordType::Separated);
-    }
-
-    // Since we don't have NativeType::Geometry, dimension should never be null
-    let dimensions: HashSet<Dimension> =
-        HashSet::from_iter(types.iter().map(|ty| ty.dimension().unwrap()));
-    let coord_types: HashSet<CoordType> =
-        HashSet::from_iter(types.iter().map(|ty| ty.coord_type()));
-
-    // Just take the first one
-    let coord_type = *coord_types.iter().next().unwrap();
-
-    // For data with multiple dimensions, we must cast to GeometryArray
-    if dimensions.len() > 1 {
-        return NativeType::Geometry(coord_type);
-    }
-    // Otherwise, we have just one dimension
-    let dimension = *dimensions.iter().next().unwrap();
-
-    // We want to compare geometry types without looking at dimension or coord type. This is a
-    // slight hack but for now we do that by the string geometry type.
-    let geometry_type_names: HashSet<&str> =
-        HashSet::from_iter(types.iter().map(|x| x.extension_name()));
-
-    if geometry_type_names.len() == 2 {
-        if geometry_type_names.contains("geoarrow.point")
-            && geometry_type_names.contains("geoarrow.multipoint")
-        {
-            return NativeType::MultiPoint(coord_type, dimension);
-        } else if geometry_type_names.contains("geoarrow.linestring")
-            && geometry_type_names.contains("geoarrow.multilinestring")
-        {
-            return NativeType::MultiLineString(coord_type, dimension);
-        } else if geometry_type_names.contains("geoarrow.polygon")
-            && geometry_type_names.contains("geoarrow.multipolygon")
-        {
-            return NativeType::MultiPolygon(coord_type, dimension);
-        } else if geometry_type_names.contains("geoarrow.geometrycollection") {
-            return NativeType::GeometryCollection(coord_type, dimension);
-        }
-    }
-
-    NativeType::Geometry(coord_type)
+fn resolve_types(types: &HashSet<NativeType>) -> Result<NativeType> {
+    NativeType::common_type_of(types.iter().copied()).ok_or_else(|| {
+        GeoArrowError::General("Cannot resolve the common type of no types".to_string())
+    })
 }
 
 impl Downcast for ChunkedPointArray {
@@ -381,7 +333,8 @@ macro_rules! impl_chunked_downcast {
                 self.chunks.iter().for_each(|chunk| {
                     types.insert(chunk.downcasted_data_type());
                 });
-                resolve_types(&types)
+                // A chunked array without chunks keeps its own type
+                resolve_types(&types).unwrap_or_else(|_| self.data_type())
             }
             fn downcast(&self) -> Self::Output {
                 let to_data_type = self.downcasted_data_type();
@@ -452,6 +405,438 @@ impl Downcast for &dyn ChunkedNativeArray {
     }
 }
 
+/// The result of [`DowncastMultiToSingle::downcast_multi_to_single`].
+#[derive(Debug, Clone)]
+pub enum MultiToSingle<A> {
+    /// No geometry has more than one part, and the array was converted.
+    Downcast(A),
+    /// The indices of the rows with more than one part, which prevent the conversion.
+    Blocked(Vec<usize>),
+}
+
+impl<A> MultiToSingle<A> {
+    /// Apply a function to the converted array.
+    pub fn map<B>(self, f: impl FnOnce(A) -> B) -> MultiToSingle<B> {
+        match self {
+            Self::Downcast(array) => MultiToSingle::Downcast(f(array)),
+            Self::Blocked(rows) => MultiToSingle::Blocked(rows),
+        }
+    }
+}
+
+/// The indices of the geometries with more than one part.
+fn rows_with_multiple_parts(geom_offsets: &OffsetBuffer<i32>) -> Vec<usize> {
+    geom_offsets
+        .windows(2)
+        .enumerate()
+        .filter_map(|(row, window)| (window[1] - window[0] > 1).then_some(row))
+        .collect()
+}
+
+/// Map each geometry offset to the offset of its first part's child.
+fn compose_offsets(
+    geom_offsets: &OffsetBuffer<i32>,
+    child_offsets: &OffsetBuffer<i32>,
+) -> OffsetBuffer<i32> {
+    let offsets: Vec<i32> = geom_offsets
+        .iter()
+        .map(|offset| child_offsets[*offset as usize])
+        .collect();
+    OffsetBuffer::new(offsets.into())
+}
+
+/// Convert a Multi-geometry array to its single-part counterpart, explicitly reporting the rows
+/// that prevent the conversion.
+///
+/// Unlike [`Downcast`], which silently leaves the array unchanged when it can't be converted, this
+/// returns the indices of all geometries with more than one part.
+///
+/// When every geometry has exactly one part, the conversion is zero-copy: the outer geometry
+/// offsets are dropped and the remaining buffers are reused. Geometries with no parts, such as
+/// nulls, are converted to empty geometries, which requires rebuilding the offsets (or the
+/// coordinates for points).
+pub trait DowncastMultiToSingle {
+    type Output;
+
+    /// Convert to the single-part array type if no geometry has more than one part.
+    fn downcast_multi_to_single(&self) -> Self::Output;
+}
+
+impl DowncastMultiToSingle for MultiPointArray {
+    type Output = MultiToSingle<PointArray>;
+
+    fn downcast_multi_to_single(&self) -> Self::Output {
+        let blocked = rows_with_multiple_parts(&self.geom_offsets);
+        if !blocked.is_empty() {
+            return MultiToSingle::Blocked(blocked);
+        }
+
+        if can_downcast_multi(&self.geom_offsets) {
+            let start = *self.geom_offsets.first().unwrap() as usize;
+            return MultiToSingle::Downcast(PointArray::new(
+                self.coords.slice(start, self.len()),
+                self.validity.clone(),
+                self.metadata(),
+            ));
+        }
+
+        let mut builder = PointBuilder::with_capacity_and_options(
+            self.dimension(),
+            self.len(),
+            self.coord_type(),
+            self.metadata(),
+        );
+        for multi_point in self.iter() {
+            match multi_point.map(|multi_point| multi_point.points().next()) {
+                Some(Some(point)) => builder.push_point(Some(&point)),
+                Some(None) => builder.push_empty(),
+                None => builder.push_null(),
+            }
+        }
+        MultiToSingle::Downcast(builder.finish())
+    }
+}
+
+impl DowncastMultiToSingle for MultiLineStringArray {
+    type Output = MultiToSingle<LineStringArray>;
+
+    fn downcast_multi_to_single(&self) -> Self::Output {
+        let blocked = rows_with_multiple_parts(&self.geom_offsets);
+        if !blocked.is_empty() {
+            return MultiToSingle::Blocked(blocked);
+        }
+
+        let geom_offsets = if can_downcast_multi(&self.geom_offsets) {
+            let start = *self.geom_offsets.first().unwrap() as usize;
+            self.ring_offsets.slice(start, self.len())
+        } else {
+            compose_offsets(&self.geom_offsets, &self.ring_offsets)
+        };
+        let coords_end = *geom_offsets.last().unwrap() as usize;
+        MultiToSingle::Downcast(LineStringArray::new(
+            self.coords.slice(0, coords_end),
+            geom_offsets,
+            self.validity.clone(),
+            self.metadata(),
+        ))
+    }
+}
+
+impl DowncastMultiToSingle for MultiPolygonArray {
+    type Output = MultiToSingle<PolygonArray>;
+
+    fn downcast_multi_to_single(&self) -> Self::Output {
+        let blocked = rows_with_multiple_parts(&self.geom_offsets);
+        if !blocked.is_empty() {
+            return MultiToSingle::Blocked(blocked);
+        }
+
+        let geom_offsets = if can_downcast_multi(&self.geom_offsets) {
+            let start = *self.geom_offsets.first().unwrap() as usize;
+            self.polygon_offsets.slice(start, self.len())
+        } else {
+            compose_offsets(&self.geom_offsets, &self.polygon_offsets)
+        };
+        let rings_end = *geom_offsets.last().unwrap() as usize;
+        let coords_end = self.ring_offsets[rings_end] as usize;
+        MultiToSingle::Downcast(PolygonArray::new(
+            self.coords.slice(0, coords_end),
+            geom_offsets,
+            self.ring_offsets.slice(0, rings_end),
+            self.validity.clone(),
+            self.metadata(),
+        ))
+    }
+}
+
+/// Convert a Multi-geometry array to its single-part counterpart if no geometry has more than one
+/// part. See [`DowncastMultiToSingle`].
+///
+/// Arrays that are already single-part are returned unchanged. Returns an error for other
+/// geometry types.
+pub fn downcast_multi_to_single(
+    array: &dyn NativeArray,
+) -> Result<MultiToSingle<Arc<dyn NativeArray>>> {
+    use NativeType::*;
+
+    let result = match array.data_type() {
+        Point(_, _) | LineString(_, _) | Polygon(_, _) => {
+            MultiToSingle::Downcast(array.slice(0, array.len()))
+        }
+        MultiPoint(_, _) => array
+            .as_multi_point()
+            .downcast_multi_to_single()
+            .map(|array| Arc::new(array) as Arc<dyn NativeArray>),
+        MultiLineString(_, _) => array
+            .as_multi_line_string()
+            .downcast_multi_to_single()
+            .map(|array| Arc::new(array) as Arc<dyn NativeArray>),
+        MultiPolygon(_, _) => array
+            .as_multi_polygon()
+            .downcast_multi_to_single()
+            .map(|array| Arc::new(array) as Arc<dyn NativeArray>),
+        data_type => {
+            return Err(GeoArrowError::IncorrectType(
+                format!("Cannot downcast {data_type} from multi to single geometries").into(),
+            ))
+        }
+    };
+    Ok(result)
+}
+
+/// Options for [`DowncastTypeInference`] and [`infer_downcast_type`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DowncastInferenceOptions {
+    /// The fraction of rows to inspect, between `0` and `1`.
+    ///
+    /// Rows are sampled at evenly spaced intervals, so the result is deterministic. With a
+    /// fraction below `1` the inferred type may be too narrow for rows that were skipped, in which
+    /// case casting to it fails.
+    pub sample_fraction: f64,
+
+    /// Stop inspecting rows once the inferred type is [`NativeType::Geometry`], as no further rows
+    /// can change it.
+    pub stop_early: bool,
+}
+
+impl Default for DowncastInferenceOptions {
+    fn default() -> Self {
+        Self {
+            sample_fraction: 1.0,
+            stop_early: true,
+        }
+    }
+}
+
+/// Incrementally infer the simplest type that a stream of geometries can be downcast to.
+///
+/// Unlike [`Downcast::downcasted_data_type`], this looks at individual rows, so arrays can be
+/// added one at a time as they are read, and rows can be sampled. Arrays with a single geometry
+/// type and no multi geometries are not scanned at all. Use [`Cast`] to convert the arrays to the
+/// inferred type.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::{DowncastInferenceOptions, DowncastTypeInference};
+/// use geoarrow::array::{CoordType, MultiPointArray};
+/// use geoarrow::datatypes::{Dimension, NativeType};
+///
+/// let multi_point = geo::MultiPoint::new(vec![geo::point!(x: 0., y: 0.)]);
+/// let array: MultiPointArray = (vec![multi_point].as_slice(), Dimension::XY).into();
+///
+/// let mut inference = DowncastTypeInference::new(DowncastInferenceOptions::default());
+/// inference.add_array(&array).unwrap();
+/// assert_eq!(
+///     inference.finish(),
+///     Some(NativeType::Point(CoordType::Interleaved, Dimension::XY))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DowncastTypeInference {
+    options: DowncastInferenceOptions,
+    data_type: Option<NativeType>,
+    num_rows: usize,
+}
+
+impl DowncastTypeInference {
+    /// Create a new inference with no rows.
+    pub fn new(options: DowncastInferenceOptions) -> Self {
+        Self {
+            options,
+            data_type: None,
+            num_rows: 0,
+        }
+    }
+
+    /// Whether adding more rows can no longer change the inferred type.
+    pub fn is_complete(&self) -> bool {
+        self.options.stop_early && matches!(self.data_type, Some(NativeType::Geometry(_)))
+    }
+
+    /// Whether the next row is part of the sample. Advances the row counter.
+    fn next_row_sampled(&mut self) -> bool {
+        let fraction = self.options.sample_fraction.clamp(0., 1.);
+        let row = self.num_rows as f64;
+        self.num_rows += 1;
+        ((row + 1.) * fraction).floor() > (row * fraction).floor()
+    }
+
+    fn add_type(&mut self, data_type: NativeType) {
+        self.data_type = NativeType::common_type_of(self.data_type.into_iter().chain([data_type]));
+    }
+
+    /// Add the rows of an array.
+    pub fn add_array(&mut self, array: &dyn NativeArray) -> Result<()> {
+        use NativeType::*;
+
+        if self.is_complete() {
+            self.num_rows += array.len();
+            return Ok(());
+        }
+
+        match array.data_type() {
+            Point(_, _) | LineString(_, _) | Polygon(_, _) | Rect(_) => {
+                if array.null_count() < array.len() {
+                    self.add_type(array.data_type());
+                }
+                self.num_rows += array.len();
+            }
+            MultiPoint(ct, dim) => {
+                let array = array.as_multi_point();
+                self.add_multi(
+                    array,
+                    &array.geom_offsets,
+                    Point(ct, dim),
+                    MultiPoint(ct, dim),
+                )
+            }
+            MultiLineString(ct, dim) => {
+                let array = array.as_multi_line_string();
+                self.add_multi(
+                    array,
+                    &array.geom_offsets,
+                    LineString(ct, dim),
+                    MultiLineString(ct, dim),
+                )
+            }
+            MultiPolygon(ct, dim) => {
+                let array = array.as_multi_polygon();
+                self.add_multi(
+                    array,
+                    &array.geom_offsets,
+                    Polygon(ct, dim),
+                    MultiPolygon(ct, dim),
+                )
+            }
+            GeometryCollection(ct, dim) => {
+                let array = array.as_geometry_collection();
+                for i in 0..array.len() {
+                    if self.next_row_sampled() && array.is_valid(i) {
+                        let geom = array.value(i);
+                        let data_type = if geom.num_geometries() == 1 {
+                            geometry_downcast_type(&geom.geometry(0).unwrap(), ct)?
+                        } else {
+                            GeometryCollection(ct, dim)
+                        };
+                        self.add_type(data_type);
+                        if self.is_complete() {
+                            self.num_rows += array.len() - i - 1;
+                            break;
+                        }
+                    }
+                }
+            }
+            Geometry(ct) => {
+                let array = array.as_geometry();
+                for i in 0..array.len() {
+                    if self.next_row_sampled() && array.is_valid(i) {
+                        self.add_type(geometry_downcast_type(&array.value(i), ct)?);
+                        if self.is_complete() {
+                            self.num_rows += array.len() - i - 1;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn add_multi(
+        &mut self,
+        array: &dyn NativeArray,
+        geom_offsets: &OffsetBuffer<i32>,
+        single_type: NativeType,
+        multi_type: NativeType,
+    ) {
+        for (i, offsets) in geom_offsets.windows(2).enumerate() {
+            if self.next_row_sampled() && array.is_valid(i) {
+                if offsets[1] - offsets[0] == 1 {
+                    self.add_type(single_type);
+                } else {
+                    // Multi geometries can't be downcast further in an array of this type
+                    self.add_type(multi_type);
+                    self.num_rows += array.len() - i - 1;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Add the rows of a WKB array, without converting them to a native array first.
+    pub fn add_wkb<O: OffsetSizeTrait>(
+        &mut self,
+        array: &WKBArray<O>,
+        coord_type: CoordType,
+    ) -> Result<()> {
+        for i in 0..array.len() {
+            if self.is_complete() {
+                self.num_rows += array.len() - i;
+                break;
+            }
+            if self.next_row_sampled() && array.is_valid(i) {
+                let geom = array.value(i);
+                self.add_type(geometry_downcast_type(&geom.parse()?, coord_type)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// The inferred type, or `None` if no non-null rows were inspected.
+    pub fn finish(self) -> Option<NativeType> {
+        self.data_type
+    }
+}
+
+/// The simplest type that a single geometry can be stored as.
+fn geometry_downcast_type(
+    geom: &impl GeometryTrait<T = f64>,
+    coord_type: CoordType,
+) -> Result<NativeType> {
+    use geo_traits::GeometryType as G;
+    use NativeType::*;
+
+    let dim = Dimension::try_from(geom.dim())?;
+    let data_type = match geom.as_type() {
+        G::Point(_) => Point(coord_type, dim),
+        G::LineString(_) => LineString(coord_type, dim),
+        G::Polygon(_) => Polygon(coord_type, dim),
+        G::MultiPoint(g) if g.num_points() == 1 => Point(coord_type, dim),
+        G::MultiPoint(_) => MultiPoint(coord_type, dim),
+        G::MultiLineString(g) if g.num_line_strings() == 1 => LineString(coord_type, dim),
+        G::MultiLineString(_) => MultiLineString(coord_type, dim),
+        G::MultiPolygon(g) if g.num_polygons() == 1 => Polygon(coord_type, dim),
+        G::MultiPolygon(_) => MultiPolygon(coord_type, dim),
+        G::GeometryCollection(g) if g.num_geometries() == 1 => {
+            return geometry_downcast_type(&g.geometry(0).unwrap(), coord_type)
+        }
+        G::GeometryCollection(_) => GeometryCollection(coord_type, dim),
+        G::Rect(_) => Rect(dim),
+        G::Triangle(_) | G::Line(_) => Geometry(coord_type),
+    };
+    Ok(data_type)
+}
+
+/// Infer the simplest type that all arrays of a stream can be downcast to.
+///
+/// The stream is consumed until it is exhausted or, with
+/// [`stop_early`][DowncastInferenceOptions::stop_early], until the type can't get any more
+/// general. Returns `None` if the stream had no non-null geometries.
+pub fn infer_downcast_type<R: NativeArrayReader + ?Sized>(
+    reader: &mut R,
+    options: DowncastInferenceOptions,
+) -> Result<Option<NativeType>> {
+    let mut inference = DowncastTypeInference::new(options);
+    while !inference.is_complete() {
+        let Some(array) = reader.next() else {
+            break;
+        };
+        inference.add_array(array?.as_ref())?;
+    }
+    Ok(inference.finish())
+}
+
 pub trait DowncastTable {
     /// If possible, convert this array to a simpler and/or smaller data type
     ///
@@ -512,3 +897,92 @@ impl DowncastTable for Table {
 
 //     }
 // }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::NativeArrayIterator;
+    use crate::test::{multipoint, multipolygon, point, polygon};
+
+    #[test]
+    fn multi_to_single_zero_copy() {
+        let multi_polygons = vec![
+            geo::MultiPolygon::new(vec![polygon::p0()]),
+            geo::MultiPolygon::new(vec![polygon::p1()]),
+        ];
+        let array: MultiPolygonArray = (multi_polygons.as_slice(), Dimension::XY).into();
+        let MultiToSingle::Downcast(downcasted) = array.slice(1, 1).downcast_multi_to_single()
+        else {
+            panic!("expected downcast");
+        };
+        assert_eq!(downcasted.len(), 1);
+        assert_eq!(downcasted.value_as_geo(0), polygon::p1());
+    }
+
+    #[test]
+    fn multi_to_single_with_nulls() {
+        let multi_points = vec![
+            Some(geo::MultiPoint::new(vec![point::p0()])),
+            None,
+            Some(geo::MultiPoint::new(vec![point::p1()])),
+        ];
+        let array: MultiPointArray = (multi_points, Dimension::XY).into();
+        let MultiToSingle::Downcast(downcasted) = array.downcast_multi_to_single() else {
+            panic!("expected downcast");
+        };
+        assert!(downcasted.is_null(1));
+        assert_eq!(downcasted.value_as_geo(2), point::p1());
+    }
+
+    #[test]
+    fn multi_to_single_blocked() {
+        let array = multipolygon::mp_array();
+        let MultiToSingle::Blocked(rows) = downcast_multi_to_single(&array).unwrap() else {
+            panic!("expected blocked rows");
+        };
+        assert_eq!(rows, vec![0, 1]);
+        assert!(downcast_multi_to_single(&point::point_array()).is_ok());
+    }
+
+    #[test]
+    fn infer_from_stream() {
+        let single: MultiPointArray = (
+            vec![geo::MultiPoint::new(vec![point::p0()])].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let single: Arc<dyn NativeArray> = Arc::new(single);
+        let multi: Arc<dyn NativeArray> = Arc::new(multipoint::mp_array());
+        let data_type = multi.data_type();
+        let mut reader =
+            NativeArrayIterator::new(vec![Ok(single), Ok(multi)].into_iter(), data_type);
+
+        let inferred = infer_downcast_type(&mut reader, Default::default()).unwrap();
+        assert!(matches!(inferred, Some(NativeType::MultiPoint(_, _))));
+    }
+
+    #[test]
+    fn stop_early() {
+        let mut builder = GeometryBuilder::new();
+        builder
+            .push_polygon(Some(&crate::test::polygon::p0()))
+            .unwrap();
+        builder.push_point(Some(&point::p0())).unwrap();
+        builder.push_point(Some(&point::p1())).unwrap();
+        let array = builder.finish();
+
+        let mut inference = DowncastTypeInference::new(Default::default());
+        inference.add_array(&array).unwrap();
+        assert!(inference.is_complete());
+        assert!(matches!(inference.finish(), Some(NativeType::Geometry(_))));
+
+        // Sampling every other row only inspects the second row
+        let options = DowncastInferenceOptions {
+            sample_fraction: 0.5,
+            stop_early: true,
+        };
+        let mut inference = DowncastTypeInference::new(options);
+        inference.add_array(&array).unwrap();
+        assert!(matches!(inference.finish(), Some(NativeType::Point(_, _))));
+    }
+}
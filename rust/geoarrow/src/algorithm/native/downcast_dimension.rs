@@ -0,0 +1,421 @@
+use std::sync::Arc;
+
+use arrow_buffer::ScalarBuffer;
+
+use crate::algorithm::native::{Concatenate, ForceDimension};
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::Result;
+use crate::{ArrayBase, NativeArray};
+
+/// Drop the Z and M dimensions of geometries when they carry no information.
+///
+/// A Z or M dimension is dropped when all of its values are equal, ignoring NaN values. This
+/// includes dimensions where every value is NaN. Each is checked on its own, so an XYZM array
+/// with constant M values and varying Z values is lowered to XYZ. Arrays with varying values and
+/// arrays that are already two-dimensional are returned unchanged. This is the inverse of
+/// [`ForceDimension`][super::ForceDimension] and is useful for data such as LiDAR or CAD exports
+/// that store a constant zero Z.
+///
+/// For chunked arrays, the values of all chunks are considered together so that every chunk
+/// keeps the same type.
+///
+/// A [`GeometryArray`] only has XY and XYZ children, so only its Z values are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::{DowncastDimension, ForceDimension};
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::NativeArray;
+///
+/// let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+/// let array_3d = array.force_3d(0.);
+/// assert_eq!(array_3d.downcast_dimension().dimension(), Dimension::XY);
+/// ```
+pub trait DowncastDimension {
+    type Output;
+
+    fn downcast_dimension(&self) -> Self::Output;
+}
+
+/// Access to the Z and M values of an array.
+trait DimensionValues {
+    /// All Z values of the array, which is empty for arrays without a Z dimension.
+    fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_>;
+
+    /// All M values of the array, which is empty for arrays without an M dimension.
+    fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_>;
+}
+
+/// Whether all non-NaN values are equal.
+fn is_constant(values: impl Iterator<Item = f64>) -> bool {
+    let mut values = values.filter(|value| !value.is_nan());
+    match values.next() {
+        Some(first) => values.all(|value| value == first),
+        None => true,
+    }
+}
+
+/// The dimension left after dropping the Z and M dimensions of `dim` that have constant values.
+fn lowered_dimension<'a>(
+    dim: Dimension,
+    z_values: impl Iterator<Item = f64> + 'a,
+    m_values: impl Iterator<Item = f64> + 'a,
+) -> Dimension {
+    Dimension::from_flags(
+        dim.has_z() && !is_constant(z_values),
+        dim.has_m() && !is_constant(m_values),
+    )
+}
+
+macro_rules! impl_coords {
+    ($type:ty) => {
+        impl DimensionValues for $type {
+            fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+                self.coords.z_values()
+            }
+
+            fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+                self.coords.m_values()
+            }
+        }
+    };
+}
+
+impl_coords!(PointArray);
+impl_coords!(LineStringArray);
+impl_coords!(PolygonArray);
+impl_coords!(MultiPointArray);
+impl_coords!(MultiLineStringArray);
+impl_coords!(MultiPolygonArray);
+
+impl DimensionValues for RectArray {
+    fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(self.lower().z_values().chain(self.upper().z_values()))
+    }
+
+    fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(self.lower().m_values().chain(self.upper().m_values()))
+    }
+}
+
+impl DimensionValues for MixedGeometryArray {
+    fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(
+            self.points
+                .z_values()
+                .chain(self.line_strings.z_values())
+                .chain(self.polygons.z_values())
+                .chain(self.multi_points.z_values())
+                .chain(self.multi_line_strings.z_values())
+                .chain(self.multi_polygons.z_values()),
+        )
+    }
+
+    fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(
+            self.points
+                .m_values()
+                .chain(self.line_strings.m_values())
+                .chain(self.polygons.m_values())
+                .chain(self.multi_points.m_values())
+                .chain(self.multi_line_strings.m_values())
+                .chain(self.multi_polygons.m_values()),
+        )
+    }
+}
+
+impl DimensionValues for GeometryCollectionArray {
+    fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        self.array.z_values()
+    }
+
+    fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        self.array.m_values()
+    }
+}
+
+impl DimensionValues for GeometryArray {
+    fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(
+            self.point_xyz
+                .z_values()
+                .chain(self.line_string_xyz.z_values())
+                .chain(self.polygon_xyz.z_values())
+                .chain(self.mpoint_xyz.z_values())
+                .chain(self.mline_string_xyz.z_values())
+                .chain(self.mpolygon_xyz.z_values())
+                .chain(self.gc_xyz.z_values()),
+        )
+    }
+
+    fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        Box::new(std::iter::empty())
+    }
+}
+
+/// Map the type ids of geometries with Z values to the type ids of the same geometry types
+/// without them.
+fn force_2d_type_ids(type_ids: &ScalarBuffer<i8>) -> ScalarBuffer<i8> {
+    type_ids.iter().map(|type_id| type_id % 10).collect()
+}
+
+/// Move the geometries of the 3D children of a geometry array to its 2D children.
+fn force_2d(array: &GeometryArray) -> Result<GeometryArray> {
+    let coord_type = array.coord_type();
+
+    // The 3D children are appended to the existing 2D children, so the offsets of 3D
+    // geometries move by the length of the matching 2D child.
+    let xy_lengths = [
+        array.point_xy.len(),
+        array.line_string_xy.len(),
+        array.polygon_xy.len(),
+        array.mpoint_xy.len(),
+        array.mline_string_xy.len(),
+        array.mpolygon_xy.len(),
+        array.gc_xy.len(),
+    ];
+    let offsets = array
+        .type_ids
+        .iter()
+        .zip(array.offsets.iter())
+        .map(|(type_id, offset)| {
+            if *type_id > 10 {
+                offset + xy_lengths[*type_id as usize - 11] as i32
+            } else {
+                *offset
+            }
+        })
+        .collect();
+
+    macro_rules! append_child {
+        ($xy:ident, $xyz:ident) => {{
+            let forced = array.$xyz.force_dim(Dimension::XY, f64::NAN, f64::NAN);
+            if array.$xy.is_empty() {
+                forced
+            } else {
+                [array.$xy.clone(), forced]
+                    .as_slice()
+                    .concatenate()?
+                    .into_coord_type(coord_type)
+            }
+        }};
+    }
+
+    Ok(GeometryArray::new(
+        force_2d_type_ids(&array.type_ids),
+        offsets,
+        Some(append_child!(point_xy, point_xyz)),
+        Some(append_child!(line_string_xy, line_string_xyz)),
+        Some(append_child!(polygon_xy, polygon_xyz)),
+        Some(append_child!(mpoint_xy, mpoint_xyz)),
+        Some(append_child!(mline_string_xy, mline_string_xyz)),
+        Some(append_child!(mpolygon_xy, mpolygon_xyz)),
+        Some(append_child!(gc_xy, gc_xyz)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        array.metadata(),
+    ))
+}
+
+macro_rules! impl_downcast {
+    ($type:ty) => {
+        impl DowncastDimension for $type {
+            type Output = Self;
+
+            fn downcast_dimension(&self) -> Self::Output {
+                let dim = self.dimension();
+                let lowered = lowered_dimension(dim, self.z_values(), self.m_values());
+                self.force_dim(lowered, f64::NAN, f64::NAN)
+            }
+        }
+    };
+}
+
+impl_downcast!(PointArray);
+impl_downcast!(LineStringArray);
+impl_downcast!(PolygonArray);
+impl_downcast!(MultiPointArray);
+impl_downcast!(MultiLineStringArray);
+impl_downcast!(MultiPolygonArray);
+impl_downcast!(MixedGeometryArray);
+impl_downcast!(GeometryCollectionArray);
+impl_downcast!(RectArray);
+
+impl DowncastDimension for GeometryArray {
+    type Output = Result<Self>;
+
+    /// Geometries with Z are lowered if the Z values of all 3D children are constant.
+    fn downcast_dimension(&self) -> Self::Output {
+        if self.type_ids.iter().any(|type_id| *type_id > 10) && is_constant(self.z_values()) {
+            force_2d(self)
+        } else {
+            Ok(self.clone())
+        }
+    }
+}
+
+impl DowncastDimension for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn downcast_dimension(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().downcast_dimension()),
+            LineString(_, _) => Arc::new(self.as_line_string().downcast_dimension()),
+            Polygon(_, _) => Arc::new(self.as_polygon().downcast_dimension()),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().downcast_dimension()),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().downcast_dimension()),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().downcast_dimension()),
+            GeometryCollection(_, _) => {
+                Arc::new(self.as_geometry_collection().downcast_dimension())
+            }
+            Rect(_) => Arc::new(self.as_rect().downcast_dimension()),
+            Geometry(_) => Arc::new(self.as_geometry().downcast_dimension()?),
+        };
+        Ok(result)
+    }
+}
+
+/// Implementation that checks the Z values of all chunks before lowering each chunk
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl DowncastDimension for ChunkedGeometryArray<$type> {
+            type Output = ChunkedGeometryArray<$type>;
+
+            fn downcast_dimension(&self) -> Self::Output {
+                let dim = self.data_type().dimension().unwrap();
+                let z_values = self.chunks.iter().flat_map(|chunk| chunk.z_values());
+                let m_values = self.chunks.iter().flat_map(|chunk| chunk.m_values());
+                let lowered = lowered_dimension(dim, z_values, m_values);
+                ChunkedGeometryArray::new(
+                    self.map(|chunk| chunk.force_dim(lowered, f64::NAN, f64::NAN)),
+                )
+            }
+        }
+    };
+}
+
+chunked_impl!(PointArray);
+chunked_impl!(LineStringArray);
+chunked_impl!(PolygonArray);
+chunked_impl!(MultiPointArray);
+chunked_impl!(MultiLineStringArray);
+chunked_impl!(MultiPolygonArray);
+chunked_impl!(GeometryCollectionArray);
+chunked_impl!(RectArray);
+
+impl DowncastDimension for ChunkedGeometryArray<GeometryArray> {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn downcast_dimension(&self) -> Self::Output {
+        let has_z = self
+            .chunks
+            .iter()
+            .any(|chunk| chunk.type_ids.iter().any(|type_id| *type_id > 10));
+        let z_values = self.chunks.iter().flat_map(|chunk| chunk.z_values());
+        if has_z && is_constant(z_values) {
+            Ok(ChunkedGeometryArray::new(self.try_map(force_2d)?))
+        } else {
+            Ok(self.clone())
+        }
+    }
+}
+
+impl DowncastDimension for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn downcast_dimension(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().downcast_dimension()),
+            LineString(_, _) => Arc::new(self.as_line_string().downcast_dimension()),
+            Polygon(_, _) => Arc::new(self.as_polygon().downcast_dimension()),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().downcast_dimension()),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().downcast_dimension()),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().downcast_dimension()),
+            GeometryCollection(_, _) => {
+                Arc::new(self.as_geometry_collection().downcast_dimension())
+            }
+            Rect(_) => Arc::new(self.as_rect().downcast_dimension()),
+            Geometry(_) => Arc::new(self.as_geometry().downcast_dimension()?),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point};
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn constant_z() {
+        let arr = linestring::ls_array();
+        let lowered = arr.force_3d(0.).downcast_dimension();
+        assert_eq!(lowered.dimension(), Dimension::XY);
+        assert_eq!(lowered.value_as_geo(0), arr.value_as_geo(0));
+
+        let lowered = arr.force_3d(f64::NAN).downcast_dimension();
+        assert_eq!(lowered.dimension(), Dimension::XY);
+    }
+
+    #[test]
+    fn varying_z() {
+        let arr = ChunkedGeometryArray::new(vec![
+            point::point_array().force_3d(0.),
+            point::point_array().force_3d(1.),
+        ]);
+        assert_eq!(
+            arr.downcast_dimension().data_type().dimension(),
+            Some(Dimension::XYZ)
+        );
+        assert_eq!(
+            arr.chunks[0].downcast_dimension().dimension(),
+            Dimension::XY
+        );
+    }
+
+    #[test]
+    fn geometry_constant_z() {
+        let mut builder = GeometryBuilder::new();
+        builder.push_point(Some(&point::p0())).unwrap();
+        builder.push_line_string(Some(&linestring::ls0())).unwrap();
+        let arr = builder.finish();
+
+        let lowered = arr.force_3d(2.).unwrap().downcast_dimension().unwrap();
+        assert!(lowered.type_ids.iter().all(|type_id| *type_id < 10));
+        assert_eq!(lowered.value_as_geo(1), arr.value_as_geo(1));
+    }
+
+    #[test]
+    fn constant_m() {
+        let arr = point::point_array().force_xyzm(0., 5.);
+        let varying_z = PointArray::new(
+            point::point_z_array()
+                .coords
+                .force_dim(Dimension::XYZM, 0., 5.),
+            None,
+            Default::default(),
+        );
+
+        assert_eq!(arr.downcast_dimension().dimension(), Dimension::XY);
+        let lowered = varying_z.downcast_dimension();
+        assert_eq!(lowered.dimension(), Dimension::XYZ);
+        assert_eq!(lowered.value_as_geo(1), varying_z.value_as_geo(1));
+
+        let arr = point::point_array().force_xym(5.);
+        assert_eq!(arr.downcast_dimension().dimension(), Dimension::XY);
+    }
+}
@@ -0,0 +1,351 @@
+use std::sync::Arc;
+
+use arrow_array::UInt32Array;
+use arrow_buffer::OffsetBuffer;
+use geo_traits::*;
+
+use crate::algorithm::native::Take;
+use crate::array::*;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// The single-geometry parts of an array, as returned by [`ExplodeParts`].
+#[derive(Debug, Clone)]
+pub struct ExplodedParts<A> {
+    /// The parts of every geometry, in order.
+    pub parts: A,
+
+    /// For every part, the index of the geometry it came from.
+    pub parent_indices: UInt32Array,
+
+    /// For every part, its 0-based position within the geometry it came from. Only computed when
+    /// requested.
+    pub part_indices: Option<UInt32Array>,
+}
+
+/// Split geometries into their single-geometry parts, like PostGIS' `ST_Dump`.
+///
+/// Multi geometries are split into their points, line strings or polygons, and geometry
+/// collections are split recursively until only single geometries are left. Single geometries are
+/// their own only part. Null geometries have no parts.
+///
+/// Unlike [`Explode`][super::Explode], this returns the index of the parent geometry of every
+/// part, which can be passed into a [`take`][arrow::compute::take] to repeat the other columns of
+/// a table.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::ExplodeParts;
+/// use geoarrow::array::MultiPointArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::ArrayBase;
+///
+/// let multi_point = geo::MultiPoint::new(vec![geo::point!(x: 0., y: 0.), geo::point!(x: 1., y: 1.)]);
+/// let array: MultiPointArray = (vec![multi_point].as_slice(), Dimension::XY).into();
+///
+/// let exploded = array.explode_parts(true).unwrap();
+/// assert_eq!(exploded.parts.len(), 2);
+/// assert_eq!(exploded.parent_indices.values().to_vec(), vec![0, 0]);
+/// assert_eq!(exploded.part_indices.unwrap().values().to_vec(), vec![0, 1]);
+/// ```
+pub trait ExplodeParts {
+    type Output;
+
+    fn explode_parts(&self, include_part_indices: bool) -> Self::Output;
+}
+
+/// Parent and part indices for geometries with the given offsets into their parts.
+fn offsets_to_indices(
+    offsets: &OffsetBuffer<i32>,
+    include_part_indices: bool,
+) -> (UInt32Array, Option<UInt32Array>) {
+    let num_parts = (offsets.last().unwrap() - offsets.first().unwrap()) as usize;
+    let mut parent_indices = Vec::with_capacity(num_parts);
+    let mut part_indices = Vec::with_capacity(if include_part_indices { num_parts } else { 0 });
+    for (geom_idx, window) in offsets.windows(2).enumerate() {
+        let num_geom_parts = (window[1] - window[0]) as u32;
+        parent_indices.extend(std::iter::repeat(geom_idx as u32).take(num_geom_parts as usize));
+        if include_part_indices {
+            part_indices.extend(0..num_geom_parts);
+        }
+    }
+    (
+        UInt32Array::from(parent_indices),
+        include_part_indices.then(|| UInt32Array::from(part_indices)),
+    )
+}
+
+/// `$try` is `?` for arrays whose [`Take`] is fallible.
+macro_rules! impl_single {
+    ($type:ty $(, $try:tt)?) => {
+        impl ExplodeParts for $type {
+            type Output = Result<ExplodedParts<$type>>;
+
+            fn explode_parts(&self, include_part_indices: bool) -> Self::Output {
+                let (parts, parent_indices) = if self.null_count() == 0 {
+                    (self.clone(), UInt32Array::from_iter_values(0..self.len() as u32))
+                } else {
+                    let indices = UInt32Array::from_iter_values(
+                        (0..self.len() as u32).filter(|i| self.is_valid(*i as usize)),
+                    );
+                    (self.take(&indices)$($try)?, indices)
+                };
+                let part_indices = include_part_indices
+                    .then(|| UInt32Array::from(vec![0; parent_indices.len()]));
+                Ok(ExplodedParts {
+                    parts,
+                    parent_indices,
+                    part_indices,
+                })
+            }
+        }
+    };
+}
+
+impl_single!(PointArray);
+impl_single!(LineStringArray, ?);
+impl_single!(PolygonArray, ?);
+
+impl ExplodeParts for MultiPointArray {
+    type Output = Result<ExplodedParts<PointArray>>;
+
+    fn explode_parts(&self, include_part_indices: bool) -> Self::Output {
+        let start = *self.geom_offsets.first().unwrap() as usize;
+        let end = *self.geom_offsets.last().unwrap() as usize;
+        let parts = PointArray::new(self.coords.slice(start, end - start), None, self.metadata());
+        let (parent_indices, part_indices) =
+            offsets_to_indices(&self.geom_offsets, include_part_indices);
+        Ok(ExplodedParts {
+            parts,
+            parent_indices,
+            part_indices,
+        })
+    }
+}
+
+impl ExplodeParts for MultiLineStringArray {
+    type Output = Result<ExplodedParts<LineStringArray>>;
+
+    fn explode_parts(&self, include_part_indices: bool) -> Self::Output {
+        let start = *self.geom_offsets.first().unwrap() as usize;
+        let end = *self.geom_offsets.last().unwrap() as usize;
+
+        // Offsets stay absolute, so only the end of the coordinates needs to be trimmed
+        let coords_end = self.ring_offsets[end] as usize;
+        let parts = LineStringArray::try_new(
+            self.coords.slice(0, coords_end),
+            self.ring_offsets.slice(start, end - start),
+            None,
+            self.metadata(),
+        )?;
+        let (parent_indices, part_indices) =
+            offsets_to_indices(&self.geom_offsets, include_part_indices);
+        Ok(ExplodedParts {
+            parts,
+            parent_indices,
+            part_indices,
+        })
+    }
+}
+
+impl ExplodeParts for MultiPolygonArray {
+    type Output = Result<ExplodedParts<PolygonArray>>;
+
+    fn explode_parts(&self, include_part_indices: bool) -> Self::Output {
+        let start = *self.geom_offsets.first().unwrap() as usize;
+        let end = *self.geom_offsets.last().unwrap() as usize;
+
+        // Offsets stay absolute, so only the ends of the rings and coordinates need to be trimmed
+        let rings_end = self.polygon_offsets[end] as usize;
+        let coords_end = self.ring_offsets[rings_end] as usize;
+        let parts = PolygonArray::try_new(
+            self.coords.slice(0, coords_end),
+            self.polygon_offsets.slice(start, end - start),
+            self.ring_offsets.slice(0, rings_end),
+            None,
+            self.metadata(),
+        )?;
+        let (parent_indices, part_indices) =
+            offsets_to_indices(&self.geom_offsets, include_part_indices);
+        Ok(ExplodedParts {
+            parts,
+            parent_indices,
+            part_indices,
+        })
+    }
+}
+
+/// Push the single-geometry parts of a geometry, returning the number of parts.
+fn push_parts(builder: &mut GeometryBuilder, geom: &impl GeometryTrait<T = f64>) -> Result<u32> {
+    use geo_traits::GeometryType::*;
+
+    let num_parts = match geom.as_type() {
+        Point(g) => {
+            builder.push_point(Some(g))?;
+            1
+        }
+        LineString(g) => {
+            builder.push_line_string(Some(g))?;
+            1
+        }
+        Polygon(g) => {
+            builder.push_polygon(Some(g))?;
+            1
+        }
+        MultiPoint(g) => {
+            for point in g.points() {
+                builder.push_point(Some(&point))?;
+            }
+            g.num_points() as u32
+        }
+        MultiLineString(g) => {
+            for line_string in g.line_strings() {
+                builder.push_line_string(Some(&line_string))?;
+            }
+            g.num_line_strings() as u32
+        }
+        MultiPolygon(g) => {
+            for polygon in g.polygons() {
+                builder.push_polygon(Some(&polygon))?;
+            }
+            g.num_polygons() as u32
+        }
+        GeometryCollection(g) => {
+            let mut num_parts = 0;
+            for geometry in g.geometries() {
+                num_parts += push_parts(builder, &geometry)?;
+            }
+            num_parts
+        }
+        Rect(_) | Triangle(_) | Line(_) => {
+            return Err(GeoArrowError::General(
+                "Exploding rect, triangle or line geometries is not supported".to_string(),
+            ))
+        }
+    };
+    Ok(num_parts)
+}
+
+macro_rules! impl_collection {
+    ($type:ty) => {
+        impl ExplodeParts for $type {
+            type Output = Result<ExplodedParts<GeometryArray>>;
+
+            fn explode_parts(&self, include_part_indices: bool) -> Self::Output {
+                let mut builder =
+                    GeometryBuilder::new_with_options(self.coord_type(), self.metadata(), false);
+                let mut parent_indices = vec![];
+                let mut part_indices = vec![];
+                for (geom_idx, geom) in self.iter().enumerate() {
+                    if let Some(geom) = geom {
+                        let num_parts = push_parts(&mut builder, &geom)?;
+                        parent_indices
+                            .extend(std::iter::repeat(geom_idx as u32).take(num_parts as usize));
+                        if include_part_indices {
+                            part_indices.extend(0..num_parts);
+                        }
+                    }
+                }
+                Ok(ExplodedParts {
+                    parts: builder.finish(),
+                    parent_indices: UInt32Array::from(parent_indices),
+                    part_indices: include_part_indices.then(|| UInt32Array::from(part_indices)),
+                })
+            }
+        }
+    };
+}
+
+impl_collection!(MixedGeometryArray);
+impl_collection!(GeometryCollectionArray);
+impl_collection!(GeometryArray);
+
+impl ExplodeParts for &dyn NativeArray {
+    type Output = Result<ExplodedParts<Arc<dyn NativeArray>>>;
+
+    fn explode_parts(&self, include_part_indices: bool) -> Self::Output {
+        use NativeType::*;
+
+        macro_rules! impl_explode {
+            ($cast_func:ident) => {{
+                let exploded = self.$cast_func().explode_parts(include_part_indices)?;
+                ExplodedParts {
+                    parts: Arc::new(exploded.parts) as Arc<dyn NativeArray>,
+                    parent_indices: exploded.parent_indices,
+                    part_indices: exploded.part_indices,
+                }
+            }};
+        }
+
+        let result = match self.data_type() {
+            Point(_, _) => impl_explode!(as_point),
+            LineString(_, _) => impl_explode!(as_line_string),
+            Polygon(_, _) => impl_explode!(as_polygon),
+            MultiPoint(_, _) => impl_explode!(as_multi_point),
+            MultiLineString(_, _) => impl_explode!(as_multi_line_string),
+            MultiPolygon(_, _) => impl_explode!(as_multi_polygon),
+            GeometryCollection(_, _) => impl_explode!(as_geometry_collection),
+            Geometry(_) => impl_explode!(as_geometry),
+            Rect(_) => {
+                return Err(GeoArrowError::IncorrectType(
+                    "Rect arrays cannot be exploded".into(),
+                ))
+            }
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipoint, multipolygon};
+
+    #[test]
+    fn explode_parts_multi_point() {
+        let arr = multipoint::mp_array();
+        let exploded = arr.explode_parts(true).unwrap();
+
+        assert_eq!(exploded.parts.value_as_geo(1), multipoint::mp0().0[1]);
+        assert_eq!(exploded.parts.value_as_geo(2), multipoint::mp1().0[0]);
+        assert_eq!(exploded.parent_indices.values().to_vec(), vec![0, 0, 1, 1]);
+        assert_eq!(
+            exploded.part_indices.unwrap().values().to_vec(),
+            vec![0, 1, 0, 1]
+        );
+    }
+
+    #[test]
+    fn explode_parts_sliced_multi_polygon() {
+        let arr = multipolygon::mp_array().slice(1, 1);
+        let exploded = arr.explode_parts(false).unwrap();
+
+        assert_eq!(exploded.parts.len(), multipolygon::mp1().0.len());
+        assert_eq!(exploded.parts.value_as_geo(0), multipolygon::mp1().0[0]);
+        assert!(exploded.parent_indices.values().iter().all(|i| *i == 0));
+        assert!(exploded.part_indices.is_none());
+    }
+
+    #[test]
+    fn explode_parts_geometry_collection() {
+        let mut builder = GeometryBuilder::new();
+        builder.push_null();
+        builder
+            .push_geometry_collection(Some(&geo::GeometryCollection::new_from(vec![
+                geo::Geometry::Point(geo::point!(x: 0., y: 0.)),
+                geo::Geometry::MultiPoint(multipoint::mp0()),
+            ])))
+            .unwrap();
+        let exploded = builder.finish().explode_parts(true).unwrap();
+
+        assert_eq!(exploded.parts.len(), 3);
+        assert_eq!(exploded.parent_indices.values().to_vec(), vec![1, 1, 1]);
+        assert_eq!(
+            exploded.part_indices.unwrap().values().to_vec(),
+            vec![0, 1, 2]
+        );
+    }
+}
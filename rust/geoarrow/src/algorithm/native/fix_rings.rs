@@ -0,0 +1,515 @@
+use std::ops::AddAssign;
+use std::sync::{Arc, Mutex};
+
+use arrow_array::{RecordBatch, RecordBatchIterator};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::ArrowError;
+use geo_traits::CoordTrait;
+
+use crate::array::*;
+use crate::datatypes::NativeType;
+use crate::io::RecordBatchReader;
+use crate::schema::GeoSchemaExt;
+use crate::{ArrayBase, NativeArray};
+
+/// The orientation of polygon rings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingOrientation {
+    /// Exterior rings are counterclockwise and interior rings are clockwise.
+    ///
+    /// This is the orientation required by [RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6)
+    /// for GeoJSON.
+    CounterClockwise,
+
+    /// Exterior rings are clockwise and interior rings are counterclockwise.
+    ///
+    /// This is the orientation used by Shapefiles.
+    Clockwise,
+}
+
+/// Options for [`FixRings`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixRingsOptions {
+    /// Close rings whose last coordinate differs from their first coordinate, by appending the
+    /// first coordinate.
+    pub close_rings: bool,
+
+    /// Reverse rings that don't have this orientation. Rings with zero area are left unchanged.
+    pub orientation: Option<RingOrientation>,
+}
+
+/// A summary of the changes made by [`FixRings`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RingCorrections {
+    /// The number of rings that were closed.
+    pub closed_rings: usize,
+
+    /// The number of rings that were reversed.
+    pub reversed_rings: usize,
+
+    /// The number of geometries with at least one changed ring.
+    pub geometries: usize,
+}
+
+impl AddAssign for RingCorrections {
+    fn add_assign(&mut self, rhs: Self) {
+        self.closed_rings += rhs.closed_rings;
+        self.reversed_rings += rhs.reversed_rings;
+        self.geometries += rhs.geometries;
+    }
+}
+
+/// Close polygon rings and enforce a ring orientation.
+///
+/// This is useful before writing to formats with strict requirements on polygon rings. Coordinates
+/// keep all of their dimensions, and geometries other than polygons and multi polygons are
+/// returned unchanged.
+///
+/// Returns the fixed array together with a summary of the changes.
+///
+/// The `robust` feature makes the ring orientation test exact, so that nearly collinear rings are
+/// not reversed or skipped because of rounding errors. It only affects this kernel: predicates
+/// such as [`Intersects`][crate::algorithm::geo::Intersects] and
+/// [`Contains`][crate::algorithm::geo::Contains] and the overlay operations use [`geo`], whose
+/// orientation tests are exact with or without the feature.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::{FixRings, FixRingsOptions, RingOrientation};
+/// use geoarrow::array::PolygonArray;
+/// use geoarrow::datatypes::Dimension;
+///
+/// // A clockwise exterior ring
+/// let polygon = geo::polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 0., y: 0.)];
+/// let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+///
+/// let options = FixRingsOptions {
+///     close_rings: true,
+///     orientation: Some(RingOrientation::CounterClockwise),
+/// };
+/// let (_fixed, corrections) = array.fix_rings(options);
+/// assert_eq!(corrections.reversed_rings, 1);
+/// assert_eq!(corrections.geometries, 1);
+/// ```
+pub trait FixRings {
+    type Output;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections);
+}
+
+/// Twice the signed area of a ring, positive for counterclockwise rings.
+///
+/// The ring does not need to be closed.
+#[cfg(not(feature = "robust"))]
+fn signed_area(coords: &CoordBuffer, start: usize, end: usize) -> f64 {
+    let mut area = 0.;
+    for i in start..end {
+        let c1 = coords.value(i);
+        let c2 = coords.value(if i + 1 == end { start } else { i + 1 });
+        area += c1.x() * c2.y() - c2.x() * c1.y();
+    }
+    area
+}
+
+/// The orientation of a ring: positive for counterclockwise rings, negative for clockwise rings
+/// and zero for degenerate rings.
+///
+/// Without the `robust` feature this is the sign of the signed area of the ring, which rounding
+/// errors can make wrong or zero for nearly collinear rings. With it, the orientation is computed with exact
+/// arithmetic at the lowest-leftmost vertex of the ring, which gives the same result as the
+/// signed area for simple rings.
+#[cfg(not(feature = "robust"))]
+fn ring_orientation(coords: &CoordBuffer, start: usize, end: usize) -> f64 {
+    signed_area(coords, start, end)
+}
+
+#[cfg(feature = "robust")]
+fn ring_orientation(coords: &CoordBuffer, start: usize, end: usize) -> f64 {
+    let xy = |i: usize| {
+        let coord = coords.value(i);
+        robust::Coord {
+            x: coord.x(),
+            y: coord.y(),
+        }
+    };
+
+    // Ignore the closing coordinate, if any.
+    let mut len = end - start;
+    if len > 1 && coords_equal(coords, start, end - 1) {
+        len -= 1;
+    }
+    if len < 3 {
+        return 0.;
+    }
+
+    let extremal = (start..start + len)
+        .min_by(|a, b| {
+            let (a, b) = (xy(*a), xy(*b));
+            a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+        })
+        .unwrap();
+    let vertex = xy(extremal);
+
+    // The nearest distinct vertices before and after the extremal vertex.
+    let neighbor = |step: usize| {
+        (1..len)
+            .map(|n| xy(start + (extremal - start + n * step) % len))
+            .find(|coord| coord.x != vertex.x || coord.y != vertex.y)
+    };
+    match (neighbor(len - 1), neighbor(1)) {
+        (Some(prev), Some(next)) => robust::orient2d(prev, vertex, next),
+        _ => 0.,
+    }
+}
+
+/// A coordinate buffer builder with the same coordinate type and dimension as `coords`.
+pub(crate) fn coord_buffer_builder(coords: &CoordBuffer, capacity: usize) -> CoordBufferBuilder {
+    match coords.coord_type() {
+        CoordType::Interleaved => CoordBufferBuilder::Interleaved(
+            InterleavedCoordBufferBuilder::with_capacity(capacity, coords.dim()),
+        ),
+        CoordType::Separated => CoordBufferBuilder::Separated(
+            SeparatedCoordBufferBuilder::with_capacity(capacity, coords.dim()),
+        ),
+    }
+}
+
+pub(crate) fn coords_equal(coords: &CoordBuffer, i: usize, j: usize) -> bool {
+    let (a, b) = (coords.value(i), coords.value(j));
+    (0..coords.dim().size()).all(|n| a.nth_or_panic(n) == b.nth_or_panic(n))
+}
+
+/// Fix every ring in a coordinate buffer.
+///
+/// `exterior_rings` holds the index of the first ring of each polygon. Returns the new coordinates
+/// and ring offsets, whether each ring was changed, and the number of closed and reversed rings.
+fn fix_ring_coords(
+    coords: &CoordBuffer,
+    ring_offsets: &OffsetBuffer<i32>,
+    exterior_rings: &[usize],
+    options: FixRingsOptions,
+) -> (CoordBuffer, OffsetBuffer<i32>, Vec<bool>, RingCorrections) {
+    let num_rings = ring_offsets.len() - 1;
+    let mut is_exterior = vec![false; num_rings];
+    for ring_idx in exterior_rings {
+        if *ring_idx < num_rings {
+            is_exterior[*ring_idx] = true;
+        }
+    }
+
+    let mut builder = coord_buffer_builder(coords, coords.len() + num_rings);
+    let mut lengths = Vec::with_capacity(num_rings);
+    let mut changed = vec![false; num_rings];
+    let mut corrections = RingCorrections::default();
+
+    for (ring_idx, window) in ring_offsets.windows(2).enumerate() {
+        let (start, end) = (window[0] as usize, window[1] as usize);
+        if start == end {
+            lengths.push(0);
+            continue;
+        }
+
+        let close = options.close_rings && !coords_equal(coords, start, end - 1);
+        let reverse = options.orientation.is_some_and(|orientation| {
+            let ccw = match orientation {
+                RingOrientation::CounterClockwise => is_exterior[ring_idx],
+                RingOrientation::Clockwise => !is_exterior[ring_idx],
+            };
+            let orientation = ring_orientation(coords, start, end);
+            if ccw {
+                orientation < 0.
+            } else {
+                orientation > 0.
+            }
+        });
+
+        let first = if reverse { end - 1 } else { start };
+        if reverse {
+            (start..end)
+                .rev()
+                .for_each(|i| builder.push_coord(&coords.value(i)));
+        } else {
+            (start..end).for_each(|i| builder.push_coord(&coords.value(i)));
+        }
+        if close {
+            builder.push_coord(&coords.value(first));
+        }
+
+        lengths.push(end - start + close as usize);
+        changed[ring_idx] = close || reverse;
+        corrections.closed_rings += close as usize;
+        corrections.reversed_rings += reverse as usize;
+    }
+
+    (
+        builder.into(),
+        OffsetBuffer::from_lengths(lengths),
+        changed,
+        corrections,
+    )
+}
+
+/// Count the geometries with a changed ring, given the ring offsets of each geometry.
+fn count_changed(ring_ranges: impl Iterator<Item = (usize, usize)>, changed: &[bool]) -> usize {
+    ring_ranges
+        .filter(|(start, end)| changed[*start..*end].iter().any(|c| *c))
+        .count()
+}
+
+impl FixRings for PolygonArray {
+    type Output = PolygonArray;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections) {
+        let exterior_rings: Vec<usize> = self.geom_offsets.iter().map(|o| *o as usize).collect();
+        let (coords, ring_offsets, changed, mut corrections) =
+            fix_ring_coords(&self.coords, &self.ring_offsets, &exterior_rings, options);
+        corrections.geometries = count_changed(
+            self.geom_offsets
+                .windows(2)
+                .map(|w| (w[0] as usize, w[1] as usize)),
+            &changed,
+        );
+
+        let output = PolygonArray::new(
+            coords,
+            self.geom_offsets.clone(),
+            ring_offsets,
+            self.validity.clone(),
+            self.metadata(),
+        );
+        (output, corrections)
+    }
+}
+
+impl FixRings for MultiPolygonArray {
+    type Output = MultiPolygonArray;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections) {
+        let exterior_rings: Vec<usize> = self.polygon_offsets.iter().map(|o| *o as usize).collect();
+        let (coords, ring_offsets, changed, mut corrections) =
+            fix_ring_coords(&self.coords, &self.ring_offsets, &exterior_rings, options);
+        corrections.geometries = count_changed(
+            self.geom_offsets.windows(2).map(|w| {
+                (
+                    self.polygon_offsets[w[0] as usize] as usize,
+                    self.polygon_offsets[w[1] as usize] as usize,
+                )
+            }),
+            &changed,
+        );
+
+        let output = MultiPolygonArray::new(
+            coords,
+            self.geom_offsets.clone(),
+            self.polygon_offsets.clone(),
+            ring_offsets,
+            self.validity.clone(),
+            self.metadata(),
+        );
+        (output, corrections)
+    }
+}
+
+impl FixRings for MixedGeometryArray {
+    type Output = MixedGeometryArray;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections) {
+        let mut output = self.clone();
+        let (polygons, mut corrections) = self.polygons.fix_rings(options);
+        let (multi_polygons, multi_corrections) = self.multi_polygons.fix_rings(options);
+        corrections += multi_corrections;
+        output.polygons = polygons;
+        output.multi_polygons = multi_polygons;
+        (output, corrections)
+    }
+}
+
+impl FixRings for GeometryCollectionArray {
+    type Output = GeometryCollectionArray;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections) {
+        let mut output = self.clone();
+        let (array, corrections) = self.array.fix_rings(options);
+        output.array = array;
+        (output, corrections)
+    }
+}
+
+impl FixRings for GeometryArray {
+    type Output = GeometryArray;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections) {
+        let mut output = self.clone();
+        let mut corrections = RingCorrections::default();
+
+        macro_rules! fix_child {
+            ($field:ident) => {
+                let (child, child_corrections) = self.$field.fix_rings(options);
+                output.$field = child;
+                corrections += child_corrections;
+            };
+        }
+
+        fix_child!(polygon_xy);
+        fix_child!(mpolygon_xy);
+        fix_child!(gc_xy);
+        fix_child!(polygon_xyz);
+        fix_child!(mpolygon_xyz);
+        fix_child!(gc_xyz);
+
+        (output, corrections)
+    }
+}
+
+impl FixRings for &dyn NativeArray {
+    type Output = Arc<dyn NativeArray>;
+
+    fn fix_rings(&self, options: FixRingsOptions) -> (Self::Output, RingCorrections) {
+        use NativeType::*;
+
+        macro_rules! impl_fix {
+            ($cast_func:ident) => {{
+                let (output, corrections) = self.$cast_func().fix_rings(options);
+                (Arc::new(output) as Arc<dyn NativeArray>, corrections)
+            }};
+        }
+
+        match self.data_type() {
+            Polygon(_, _) => impl_fix!(as_polygon),
+            MultiPolygon(_, _) => impl_fix!(as_multi_polygon),
+            GeometryCollection(_, _) => impl_fix!(as_geometry_collection),
+            Geometry(_) => impl_fix!(as_geometry),
+            Point(_, _) | LineString(_, _) | MultiPoint(_, _) | MultiLineString(_, _) | Rect(_) => {
+                (self.slice(0, self.len()), RingCorrections::default())
+            }
+        }
+    }
+}
+
+/// Wrap a stream so that [`FixRings`] is applied to the geometry columns of every batch.
+///
+/// The returned counter accumulates the corrections as the stream is consumed.
+pub(crate) fn fix_rings_reader(
+    reader: RecordBatchReader,
+    options: FixRingsOptions,
+) -> (RecordBatchReader, Arc<Mutex<RingCorrections>>) {
+    let corrections = Arc::new(Mutex::new(RingCorrections::default()));
+    if options == FixRingsOptions::default() {
+        return (reader, corrections);
+    }
+
+    let schema = reader.schema();
+    let geom_col_idxs = schema.as_ref().geometry_columns();
+    let counter = corrections.clone();
+    let batches = reader.into_inner().map(move |batch| {
+        let batch = batch?;
+        let mut columns = batch.columns().to_vec();
+        for idx in &geom_col_idxs {
+            let array =
+                NativeArrayDyn::from_arrow_array(&columns[*idx], batch.schema().field(*idx))
+                    .map_err(|err| ArrowError::ExternalError(Box::new(err)))?;
+            let (fixed, batch_corrections) = array.as_ref().fix_rings(options);
+            columns[*idx] = fixed.to_array_ref();
+            *counter.lock().unwrap() += batch_corrections;
+        }
+        RecordBatch::try_new(batch.schema(), columns)
+    });
+    let reader = RecordBatchIterator::new(batches, schema);
+    (RecordBatchReader::new(Box::new(reader)), corrections)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::Winding;
+
+    fn open_clockwise_polygon() -> geo::Polygon {
+        // geo closes rings, so build the open ring through the builder below
+        geo::Polygon::new(
+            geo::LineString::from(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.)]),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn fix_polygon() {
+        let array: PolygonArray = (vec![open_clockwise_polygon()].as_slice(), Dimension::XY).into();
+        // Drop the closing coordinate added by geo
+        let coords = array.coords.slice(0, 4);
+        let array = PolygonArray::new(
+            coords,
+            array.geom_offsets.clone(),
+            OffsetBuffer::from_lengths([4]),
+            None,
+            Default::default(),
+        );
+
+        let options = FixRingsOptions {
+            close_rings: true,
+            orientation: Some(RingOrientation::CounterClockwise),
+        };
+        let (fixed, corrections) = array.fix_rings(options);
+        assert_eq!(
+            corrections,
+            RingCorrections {
+                closed_rings: 1,
+                reversed_rings: 1,
+                geometries: 1
+            }
+        );
+
+        let polygon = fixed.value_as_geo(0);
+        assert_eq!(polygon.exterior().0.len(), 5);
+        assert!(polygon.exterior().is_closed());
+        assert!(polygon.exterior().is_ccw());
+
+        // Fixing again is a no-op
+        let (_, corrections) = fixed.fix_rings(options);
+        assert_eq!(corrections, RingCorrections::default());
+    }
+
+    #[test]
+    fn fix_geometry_array() {
+        let mut builder = GeometryBuilder::new();
+        builder
+            .push_polygon(Some(&open_clockwise_polygon()))
+            .unwrap();
+        builder
+            .push_point(Some(&geo::point!(x: 0., y: 0.)))
+            .unwrap();
+        let array = builder.finish();
+
+        let options = FixRingsOptions {
+            close_rings: true,
+            orientation: Some(RingOrientation::Clockwise),
+        };
+        let (fixed, corrections) = (&array as &dyn NativeArray).fix_rings(options);
+        assert_eq!(corrections, RingCorrections::default());
+        assert_eq!(fixed.len(), 2);
+    }
+
+    #[cfg(feature = "robust")]
+    #[test]
+    fn nearly_collinear_ring() {
+        // A clockwise ring whose signed area rounds to zero in floating point.
+        let polygon = geo::Polygon::new(
+            geo::LineString::from(vec![
+                (1000., 1000.),
+                (1002., 1002.),
+                (1001., 1000.9999999999998),
+            ]),
+            vec![],
+        );
+        let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+
+        let options = FixRingsOptions {
+            close_rings: true,
+            orientation: Some(RingOrientation::CounterClockwise),
+        };
+        let (_, corrections) = array.fix_rings(options);
+        assert_eq!(corrections.reversed_rings, 1);
+    }
+}
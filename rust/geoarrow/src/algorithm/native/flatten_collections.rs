@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use arrow_array::{RecordBatch, RecordBatchIterator};
+use arrow_schema::ArrowError;
+use geo::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use geo_traits::{GeometryTrait, GeometryType};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{GeometryArray, GeometryBuilder, NativeArrayDyn};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::io::RecordBatchReader;
+use crate::schema::GeoSchemaExt;
+use crate::NativeArray;
+
+/// How [`FlattenCollections`] handles geometry collections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollectionHandling {
+    /// Replace collections whose members all have the same dimension with the multi geometry of
+    /// their members, and return an error for collections that mix points, lines and polygons.
+    #[default]
+    Error,
+
+    /// Replace each collection with the multi geometry of its members of the highest dimension:
+    /// polygons, then lines, then points. Members of a lower dimension are dropped.
+    Dominant,
+}
+
+/// The members of a geometry collection, grouped by dimension. Nested collections are flattened.
+#[derive(Default)]
+struct CollectionMembers {
+    points: Vec<Point>,
+    line_strings: Vec<LineString>,
+    polygons: Vec<Polygon>,
+}
+
+impl CollectionMembers {
+    fn push(&mut self, geometry: Geometry) {
+        match geometry {
+            Geometry::Point(point) => self.points.push(point),
+            Geometry::MultiPoint(multi_point) => self.points.extend(multi_point),
+            Geometry::Line(line) => self.line_strings.push(line.into()),
+            Geometry::LineString(line_string) => self.line_strings.push(line_string),
+            Geometry::MultiLineString(multi_line_string) => {
+                self.line_strings.extend(multi_line_string)
+            }
+            Geometry::Polygon(polygon) => self.polygons.push(polygon),
+            Geometry::MultiPolygon(multi_polygon) => self.polygons.extend(multi_polygon),
+            Geometry::Rect(rect) => self.polygons.push(rect.to_polygon()),
+            Geometry::Triangle(triangle) => self.polygons.push(triangle.to_polygon()),
+            Geometry::GeometryCollection(collection) => {
+                collection.into_iter().for_each(|member| self.push(member))
+            }
+        }
+    }
+
+    /// The number of non-empty member groups.
+    fn num_dimensions(&self) -> usize {
+        [
+            !self.points.is_empty(),
+            !self.line_strings.is_empty(),
+            !self.polygons.is_empty(),
+        ]
+        .into_iter()
+        .filter(|present| *present)
+        .count()
+    }
+}
+
+/// Replace geometry collections with multi geometries, for formats that can't store collections,
+/// such as Shapefile and Mapbox Vector Tiles.
+///
+/// Collections are converted through [`geo`], so their Z values are dropped. Empty collections
+/// become null. Other geometries are unchanged.
+pub trait FlattenCollections {
+    type Output;
+
+    fn flatten_collections(&self, handling: CollectionHandling) -> Self::Output;
+}
+
+impl FlattenCollections for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn flatten_collections(&self, handling: CollectionHandling) -> Self::Output {
+        match self.data_type() {
+            NativeType::GeometryCollection(_, _) | NativeType::Geometry(_) => {
+                Ok(Arc::new(flatten_geometries(*self, handling)?))
+            }
+            _ => Ok(self.slice(0, self.len())),
+        }
+    }
+}
+
+fn flatten_geometries(
+    array: &dyn NativeArray,
+    handling: CollectionHandling,
+) -> Result<GeometryArray> {
+    let mut builder =
+        GeometryBuilder::new_with_options(array.coord_type(), array.metadata(), false);
+    for (index, geometry) in iter_geometries(array).enumerate() {
+        let Some(geometry) = geometry else {
+            builder.push_null();
+            continue;
+        };
+        if !matches!(geometry.as_type(), GeometryType::GeometryCollection(_)) {
+            builder.push_geometry(Some(&geometry))?;
+            continue;
+        }
+
+        let mut members = CollectionMembers::default();
+        members.push(geometry_to_geo(&geometry));
+        if handling == CollectionHandling::Error && members.num_dimensions() > 1 {
+            return Err(GeoArrowError::General(format!(
+                "Geometry collection at index {index} mixes geometries of different dimensions"
+            )));
+        }
+
+        if !members.polygons.is_empty() {
+            builder.push_multi_polygon(Some(&MultiPolygon::new(members.polygons)))?;
+        } else if !members.line_strings.is_empty() {
+            builder.push_multi_line_string(Some(&MultiLineString::new(members.line_strings)))?;
+        } else if !members.points.is_empty() {
+            builder.push_multi_point(Some(&MultiPoint::new(members.points)))?;
+        } else {
+            builder.push_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Flatten the geometry collections of every geometry column of a stream of record batches.
+pub(crate) fn flatten_collections_reader(
+    reader: RecordBatchReader,
+    handling: CollectionHandling,
+) -> Result<RecordBatchReader> {
+    let schema = reader.schema();
+    let geom_col_idxs = schema.as_ref().geometry_columns();
+    let mut output_fields = schema.fields().to_vec();
+    for idx in &geom_col_idxs {
+        let field = &output_fields[*idx];
+        if let NativeType::GeometryCollection(coord_type, _) | NativeType::Geometry(coord_type) =
+            NativeType::try_from(field.as_ref())?
+        {
+            let metadata = ArrayMetadata::try_from(field.as_ref())?;
+            let output_field = NativeType::Geometry(coord_type).to_field_with_metadata(
+                field.name(),
+                field.is_nullable(),
+                &metadata,
+            );
+            output_fields[*idx] = Arc::new(output_field);
+        }
+    }
+    let output_schema = Arc::new(arrow_schema::Schema::new_with_metadata(
+        output_fields,
+        schema.metadata().clone(),
+    ));
+
+    let batch_schema = output_schema.clone();
+    let batches = reader.into_inner().map(move |batch| {
+        let batch = batch?;
+        let mut columns = batch.columns().to_vec();
+        for idx in &geom_col_idxs {
+            let array =
+                NativeArrayDyn::from_arrow_array(&columns[*idx], batch.schema().field(*idx))
+                    .and_then(|array| array.as_ref().flatten_collections(handling))
+                    .map_err(|err| ArrowError::ExternalError(Box::new(err)))?;
+            columns[*idx] = array.to_array_ref();
+        }
+        RecordBatch::try_new(batch_schema.clone(), columns)
+    });
+    let reader = RecordBatchIterator::new(batches, output_schema);
+    Ok(RecordBatchReader::new(Box::new(reader)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::ArrayAccessor;
+
+    fn collections() -> GeometryArray {
+        let polygon = geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        let point = geo::point!(x: 5., y: 5.);
+        let lines = geo::GeometryCollection::new_from(vec![
+            geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+            geo::line_string![(x: 2., y: 2.), (x: 3., y: 3.)].into(),
+        ]);
+        let mixed = geo::GeometryCollection::new_from(vec![polygon.into(), point.into()]);
+
+        let mut builder = GeometryBuilder::new();
+        builder.push_geometry(Some(&Geometry::from(point))).unwrap();
+        builder.push_geometry_collection(Some(&lines)).unwrap();
+        builder.push_geometry_collection(Some(&mixed)).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn flatten_dominant() {
+        let array = collections();
+        let flattened = (&array as &dyn NativeArray)
+            .flatten_collections(CollectionHandling::Dominant)
+            .unwrap();
+        let flattened = flattened.as_any().downcast_ref::<GeometryArray>().unwrap();
+        assert!(matches!(flattened.value_as_geo(0), Geometry::Point(_)));
+        match flattened.value_as_geo(1) {
+            Geometry::MultiLineString(multi_line_string) => {
+                assert_eq!(multi_line_string.0.len(), 2)
+            }
+            geometry => panic!("Expected a multi line string, got {geometry:?}"),
+        }
+        assert!(matches!(
+            flattened.value_as_geo(2),
+            Geometry::MultiPolygon(_)
+        ));
+    }
+
+    #[test]
+    fn mixed_collection_error() {
+        let array = collections();
+        let err = (&array as &dyn NativeArray)
+            .flatten_collections(CollectionHandling::Error)
+            .unwrap_err();
+        assert!(err.to_string().contains("index 2"));
+    }
+}
@@ -0,0 +1,411 @@
+use std::sync::Arc;
+
+use arrow_buffer::ScalarBuffer;
+
+use crate::algorithm::native::Concatenate;
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::{ArrayBase, NativeArray};
+
+/// The default Z value used by [`ForceDimension`].
+pub const DEFAULT_Z_FILL: f64 = 0.0;
+
+/// The default M value used by [`ForceDimension`].
+pub const DEFAULT_M_FILL: f64 = 0.0;
+
+/// Add Z or M dimensions to geometries, filled with constant values.
+///
+/// This is useful for writing 2D data to sinks that require 3D or measured geometries. Each
+/// method is named after the dimension it produces: [`force_3d`][ForceDimension::force_3d]
+/// converts to XYZ, [`force_xym`][ForceDimension::force_xym] to XYM and
+/// [`force_xyzm`][ForceDimension::force_xyzm] to XYZM, in the same way as PostGIS'
+/// `ST_Force3D`, `ST_Force3DM` and `ST_Force4D`. Z and M values that the geometries already
+/// have are kept, and ones that the target dimension doesn't have are dropped, so forcing XYZM
+/// geometries to XYZ drops their M values. Arrays that already have the target dimension are
+/// returned unchanged. Empty points stay empty. [`DEFAULT_Z_FILL`] and [`DEFAULT_M_FILL`] are
+/// sensible defaults for the fill values; use `f64::NAN` to mark the values as unknown.
+///
+/// A [`GeometryArray`] only has XY and XYZ children, so only its 2D geometries are changed by
+/// `force_3d`, and forcing it to any other dimension returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::native::{ForceDimension, DEFAULT_Z_FILL};
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::NativeArray;
+///
+/// let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+/// let array_3d = array.force_3d(DEFAULT_Z_FILL);
+/// assert_eq!(array_3d.dimension(), Dimension::XYZ);
+///
+/// let array_4d = array_3d.force_xyzm(DEFAULT_Z_FILL, DEFAULT_M_FILL);
+/// assert_eq!(array_4d.dimension(), Dimension::XYZM);
+/// ```
+pub trait ForceDimension {
+    type Output;
+
+    /// Convert the geometries to the dimension `dim`, filling missing Z and M values with `z`
+    /// and `m`.
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output;
+
+    /// Convert the geometries to XYZ, filling missing Z values with `z`.
+    fn force_3d(&self, z: f64) -> Self::Output {
+        self.force_dim(Dimension::XYZ, z, f64::NAN)
+    }
+
+    /// Convert the geometries to XYM, filling missing M values with `m`.
+    fn force_xym(&self, m: f64) -> Self::Output {
+        self.force_dim(Dimension::XYM, f64::NAN, m)
+    }
+
+    /// Convert the geometries to XYZM, filling missing Z and M values with `z` and `m`.
+    fn force_xyzm(&self, z: f64, m: f64) -> Self::Output {
+        self.force_dim(Dimension::XYZM, z, m)
+    }
+}
+
+impl ForceDimension for PointArray {
+    type Output = Self;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        PointArray::new(
+            self.coords.force_dim(dim, z, m),
+            self.validity.clone(),
+            self.metadata(),
+        )
+    }
+}
+
+macro_rules! impl_single_offsets {
+    ($type:ty) => {
+        impl ForceDimension for $type {
+            type Output = Self;
+
+            fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+                <$type>::new(
+                    self.coords.force_dim(dim, z, m),
+                    self.geom_offsets.clone(),
+                    self.validity.clone(),
+                    self.metadata(),
+                )
+            }
+        }
+    };
+}
+
+impl_single_offsets!(LineStringArray);
+impl_single_offsets!(MultiPointArray);
+
+macro_rules! impl_double_offsets {
+    ($type:ty) => {
+        impl ForceDimension for $type {
+            type Output = Self;
+
+            fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+                <$type>::new(
+                    self.coords.force_dim(dim, z, m),
+                    self.geom_offsets.clone(),
+                    self.ring_offsets.clone(),
+                    self.validity.clone(),
+                    self.metadata(),
+                )
+            }
+        }
+    };
+}
+
+impl_double_offsets!(PolygonArray);
+impl_double_offsets!(MultiLineStringArray);
+
+impl ForceDimension for MultiPolygonArray {
+    type Output = Self;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        MultiPolygonArray::new(
+            self.coords.force_dim(dim, z, m),
+            self.geom_offsets.clone(),
+            self.polygon_offsets.clone(),
+            self.ring_offsets.clone(),
+            self.validity.clone(),
+            self.metadata(),
+        )
+    }
+}
+
+impl ForceDimension for RectArray {
+    type Output = Self;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        RectArray::new(
+            self.lower().force_dim(dim, z, m),
+            self.upper().force_dim(dim, z, m),
+            self.nulls().cloned(),
+            self.metadata(),
+        )
+    }
+}
+
+/// Map the type ids of geometries to the type ids of the same geometry types in the dimension
+/// `dim`.
+fn force_type_ids(type_ids: &ScalarBuffer<i8>, dim: Dimension) -> ScalarBuffer<i8> {
+    type_ids
+        .iter()
+        .map(|type_id| type_id % 10 + dim.type_id_offset())
+        .collect()
+}
+
+impl ForceDimension for MixedGeometryArray {
+    type Output = Self;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        if self.dimension() == dim {
+            return self.clone();
+        }
+
+        MixedGeometryArray::new(
+            force_type_ids(&self.type_ids, dim),
+            self.offsets.clone(),
+            Some(self.points.force_dim(dim, z, m)),
+            Some(self.line_strings.force_dim(dim, z, m)),
+            Some(self.polygons.force_dim(dim, z, m)),
+            Some(self.multi_points.force_dim(dim, z, m)),
+            Some(self.multi_line_strings.force_dim(dim, z, m)),
+            Some(self.multi_polygons.force_dim(dim, z, m)),
+            self.metadata(),
+        )
+    }
+}
+
+impl ForceDimension for GeometryCollectionArray {
+    type Output = Self;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        GeometryCollectionArray::new(
+            self.array.force_dim(dim, z, m),
+            self.geom_offsets.clone(),
+            self.validity.clone(),
+            self.metadata(),
+        )
+    }
+}
+
+impl ForceDimension for GeometryArray {
+    type Output = Result<Self>;
+
+    fn force_dim(&self, dim: Dimension, z: f64, _m: f64) -> Self::Output {
+        if dim != Dimension::XYZ {
+            return Err(GeoArrowError::NotYetImplemented(format!(
+                "Geometry arrays can only be forced to XYZ, not {dim:?}"
+            )));
+        }
+        let coord_type = self.coord_type();
+
+        // The 2D children are appended to the existing 3D children, so the offsets of 2D
+        // geometries move by the length of the matching 3D child.
+        let xyz_lengths = [
+            self.point_xyz.len(),
+            self.line_string_xyz.len(),
+            self.polygon_xyz.len(),
+            self.mpoint_xyz.len(),
+            self.mline_string_xyz.len(),
+            self.mpolygon_xyz.len(),
+            self.gc_xyz.len(),
+        ];
+        let offsets = self
+            .type_ids
+            .iter()
+            .zip(self.offsets.iter())
+            .map(|(type_id, offset)| {
+                if *type_id < 10 {
+                    offset + xyz_lengths[*type_id as usize - 1] as i32
+                } else {
+                    *offset
+                }
+            })
+            .collect();
+
+        macro_rules! append_child {
+            ($xy:ident, $xyz:ident) => {{
+                let forced = self.$xy.force_3d(z);
+                if self.$xyz.is_empty() {
+                    forced
+                } else {
+                    [self.$xyz.clone(), forced]
+                        .as_slice()
+                        .concatenate()?
+                        .into_coord_type(coord_type)
+                }
+            }};
+        }
+
+        Ok(GeometryArray::new(
+            force_type_ids(&self.type_ids, dim),
+            offsets,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(append_child!(point_xy, point_xyz)),
+            Some(append_child!(line_string_xy, line_string_xyz)),
+            Some(append_child!(polygon_xy, polygon_xyz)),
+            Some(append_child!(mpoint_xy, mpoint_xyz)),
+            Some(append_child!(mline_string_xy, mline_string_xyz)),
+            Some(append_child!(mpolygon_xy, mpolygon_xyz)),
+            Some(append_child!(gc_xy, gc_xyz)),
+            self.metadata(),
+        ))
+    }
+}
+
+impl ForceDimension for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().force_dim(dim, z, m)),
+            LineString(_, _) => Arc::new(self.as_line_string().force_dim(dim, z, m)),
+            Polygon(_, _) => Arc::new(self.as_polygon().force_dim(dim, z, m)),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().force_dim(dim, z, m)),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().force_dim(dim, z, m)),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().force_dim(dim, z, m)),
+            GeometryCollection(_, _) => {
+                Arc::new(self.as_geometry_collection().force_dim(dim, z, m))
+            }
+            Rect(_) => Arc::new(self.as_rect().force_dim(dim, z, m)),
+            Geometry(_) => Arc::new(self.as_geometry().force_dim(dim, z, m)?),
+        };
+        Ok(result)
+    }
+}
+
+/// Implementation that iterates over chunks
+macro_rules! chunked_impl {
+    ($type:ty) => {
+        impl ForceDimension for ChunkedGeometryArray<$type> {
+            type Output = ChunkedGeometryArray<$type>;
+
+            fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+                ChunkedGeometryArray::new(self.map(|chunk| chunk.force_dim(dim, z, m)))
+            }
+        }
+    };
+}
+
+chunked_impl!(PointArray);
+chunked_impl!(LineStringArray);
+chunked_impl!(PolygonArray);
+chunked_impl!(MultiPointArray);
+chunked_impl!(MultiLineStringArray);
+chunked_impl!(MultiPolygonArray);
+chunked_impl!(MixedGeometryArray);
+chunked_impl!(GeometryCollectionArray);
+chunked_impl!(RectArray);
+
+impl ForceDimension for ChunkedGeometryArray<GeometryArray> {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.force_dim(dim, z, m))?,
+        ))
+    }
+}
+
+impl ForceDimension for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().force_dim(dim, z, m)),
+            LineString(_, _) => Arc::new(self.as_line_string().force_dim(dim, z, m)),
+            Polygon(_, _) => Arc::new(self.as_polygon().force_dim(dim, z, m)),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().force_dim(dim, z, m)),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().force_dim(dim, z, m)),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().force_dim(dim, z, m)),
+            GeometryCollection(_, _) => {
+                Arc::new(self.as_geometry_collection().force_dim(dim, z, m))
+            }
+            Rect(_) => Arc::new(self.as_rect().force_dim(dim, z, m)),
+            Geometry(_) => Arc::new(self.as_geometry().force_dim(dim, z, m)?),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point};
+    use crate::trait_::ArrayAccessor;
+    use geo_traits::{CoordTrait, LineStringTrait, PointTrait};
+
+    #[test]
+    fn force_3d_line_string() {
+        let arr = linestring::ls_array();
+        let forced = arr.force_3d(5.);
+        assert_eq!(forced.dimension(), Dimension::XYZ);
+        assert_eq!(forced.geom_offsets, arr.geom_offsets);
+
+        let coord = forced.value(0).coord(0).unwrap();
+        assert_eq!(coord.x(), arr.value(0).coord(0).unwrap().x());
+        assert_eq!(coord.nth(2), Some(5.));
+    }
+
+    #[test]
+    fn force_3d_empty_point() {
+        let mut builder = PointBuilder::new(Dimension::XY);
+        builder.push_point(Some(&point::p0()));
+        builder.push_empty();
+        let forced = builder.finish().force_3d(DEFAULT_Z_FILL);
+        assert_eq!(forced.value(0).coord().unwrap().nth(2), Some(0.));
+        assert!(forced.value(1).coord().is_none());
+    }
+
+    #[test]
+    fn force_3d_geometry() {
+        let mut builder = GeometryBuilder::new();
+        builder.push_point(Some(&point::p0())).unwrap();
+        builder.push_line_string(Some(&linestring::ls0())).unwrap();
+        let arr = builder.finish();
+
+        let forced = arr.force_3d(1.).unwrap();
+        assert_eq!(forced.len(), 2);
+        assert!(forced.type_ids.iter().all(|type_id| *type_id > 10));
+        assert_eq!(forced.value_as_geo(1), arr.value_as_geo(1));
+    }
+
+    #[test]
+    fn force_measured_line_string() {
+        let arr = linestring::ls_array();
+        let xym = arr.force_xym(2.);
+        assert_eq!(xym.dimension(), Dimension::XYM);
+        assert_eq!(xym.value(0).coord(0).unwrap().nth(2), Some(2.));
+
+        // The M values are kept when adding a Z dimension
+        let xyzm = xym.force_xyzm(1., DEFAULT_M_FILL);
+        assert_eq!(xyzm.dimension(), Dimension::XYZM);
+        let coord = xyzm.value(0).coord(0).unwrap();
+        assert_eq!(coord.nth(2), Some(1.));
+        assert_eq!(coord.nth(3), Some(2.));
+
+        assert_eq!(xyzm.force_3d(0.).dimension(), Dimension::XYZ);
+    }
+
+    #[test]
+    fn force_measured_geometry() {
+        let mut builder = GeometryBuilder::new();
+        builder.push_point(Some(&point::p0())).unwrap();
+        assert!(builder.finish().force_xym(DEFAULT_M_FILL).is_err());
+    }
+}
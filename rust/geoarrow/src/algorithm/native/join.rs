@@ -0,0 +1,316 @@
+//! Joins of tables on keys derived from their geometries, as cheaper alternatives to spatial
+//! joins.
+
+use std::collections::HashMap;
+
+use arrow::compute::{concat_batches, take};
+use arrow::row::{RowConverter, SortField};
+use arrow_array::{Array, GenericBinaryArray, RecordBatch, UInt64Array};
+use arrow_schema::{Field, Schema, SchemaBuilder};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::algorithm::native::Canonicalize;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::to_wkb;
+use crate::scalar::Geometry;
+use crate::table::Table;
+use crate::trait_::IntoArrow;
+
+/// The pairs of matching rows of a join of two tables.
+///
+/// Row indices are counted across all the batches of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinIndices {
+    /// The rows of the left table.
+    pub left: UInt64Array,
+
+    /// The rows of the right table, in the same order as [`Self::left`].
+    pub right: UInt64Array,
+}
+
+impl JoinIndices {
+    fn from_pairs(pairs: Vec<(u64, u64)>) -> Self {
+        let (left, right): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        Self {
+            left: left.into(),
+            right: right.into(),
+        }
+    }
+
+    /// The number of matching pairs.
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Whether there are no matching pairs.
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Keep the pairs whose geometries satisfy `predicate`.
+    ///
+    /// This is the refinement step of an approximate join such as [`cell_join`]: `predicate` is
+    /// evaluated on the geometries at `left_geometry` and `right_geometry` of each candidate pair,
+    /// or on the only geometry column of each table if `None`. Pairs with a null geometry are
+    /// dropped.
+    pub fn refine<F>(
+        &self,
+        left: &Table,
+        left_geometry: Option<usize>,
+        right: &Table,
+        right_geometry: Option<usize>,
+        predicate: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&Geometry<'_>, &Geometry<'_>) -> bool,
+    {
+        let left_chunks = left.geometry_column(left_geometry)?.geometry_chunks();
+        let right_chunks = right.geometry_column(right_geometry)?.geometry_chunks();
+        let left_geoms = left_chunks
+            .iter()
+            .flat_map(|chunk| iter_geometries(chunk.as_ref()))
+            .collect::<Vec<_>>();
+        let right_geoms = right_chunks
+            .iter()
+            .flat_map(|chunk| iter_geometries(chunk.as_ref()))
+            .collect::<Vec<_>>();
+
+        let pairs = self
+            .left
+            .values()
+            .iter()
+            .zip(self.right.values())
+            .filter(
+                |(&l, &r)| match (&left_geoms[l as usize], &right_geoms[r as usize]) {
+                    (Some(left_geom), Some(right_geom)) => predicate(left_geom, right_geom),
+                    _ => false,
+                },
+            )
+            .map(|(&l, &r)| (l, r))
+            .collect();
+        Ok(Self::from_pairs(pairs))
+    }
+
+    /// Build the joined table, with the columns of the left table followed by the columns of the
+    /// right table.
+    ///
+    /// Right columns whose name is also the name of a left column get a `_right` suffix. The
+    /// output has a single batch.
+    pub fn take(&self, left: &Table, right: &Table) -> Result<Table> {
+        let left_batch = concat_batches(left.schema(), left.batches())?;
+        let right_batch = concat_batches(right.schema(), right.batches())?;
+
+        let mut schema_builder = SchemaBuilder::from(left.schema().fields());
+        for field in right.schema().fields() {
+            if left.schema().field_with_name(field.name()).is_ok() {
+                let name = format!("{}_right", field.name());
+                schema_builder.push(Field::clone(field).with_name(name));
+            } else {
+                schema_builder.push(field.clone());
+            }
+        }
+        let schema = Schema::new_with_metadata(
+            schema_builder.finish().fields,
+            left.schema().metadata().clone(),
+        );
+
+        let mut columns = left_batch
+            .columns()
+            .iter()
+            .map(|column| take(column, &self.left, None))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        for column in right_batch.columns() {
+            columns.push(take(column, &self.right, None)?);
+        }
+        let batch = RecordBatch::try_new(schema.into(), columns)?;
+        Table::try_new(vec![batch.clone()], batch.schema())
+    }
+}
+
+/// The canonical WKB of every geometry of a geometry column, with one array per chunk.
+fn normalized_wkb(table: &Table, index: Option<usize>) -> Result<Vec<GenericBinaryArray<i64>>> {
+    Ok(table
+        .geometry_column(index)?
+        .geometry_chunks()
+        .iter()
+        .map(|chunk| to_wkb::<i64>(chunk.as_ref().canonicalize().as_ref()).into_arrow())
+        .collect())
+}
+
+/// Join two tables on the equality of their geometries.
+///
+/// Geometries are compared after being brought into the canonical form of [`Canonicalize`], so
+/// that geometries that only differ in ring orientation, ring closure or repeated coordinates
+/// match. Geometries of different types or dimensions, such as a polygon and a multi polygon with
+/// one polygon, don't match. Null geometries don't match anything.
+///
+/// The geometry columns are `left_geometry` and `right_geometry`, or the only geometry column of
+/// each table if `None`.
+pub fn geometry_equality_join(
+    left: &Table,
+    left_geometry: Option<usize>,
+    right: &Table,
+    right_geometry: Option<usize>,
+) -> Result<JoinIndices> {
+    let right_wkb = normalized_wkb(right, right_geometry)?;
+    let mut right_rows: HashMap<&[u8], Vec<u64>> = HashMap::new();
+    for (row, value) in right_wkb.iter().flat_map(|chunk| chunk.iter()).enumerate() {
+        if let Some(value) = value {
+            right_rows.entry(value).or_default().push(row as u64);
+        }
+    }
+
+    let left_wkb = normalized_wkb(left, left_geometry)?;
+    let mut pairs = vec![];
+    for (row, value) in left_wkb.iter().flat_map(|chunk| chunk.iter()).enumerate() {
+        if let Some(rows) = value.and_then(|value| right_rows.get(value)) {
+            pairs.extend(rows.iter().map(|&right_row| (row as u64, right_row)));
+        }
+    }
+    Ok(JoinIndices::from_pairs(pairs))
+}
+
+/// Join two tables on shared cell ids, such as geohashes or H3 or S2 cells.
+///
+/// `left_cell` and `right_cell` are the indices of the columns holding the cell id of each row.
+/// They can have any type, as long as it's the same for both tables. Rows with a null cell id
+/// don't match anything.
+///
+/// Rows that share a cell are only candidates: use [`JoinIndices::refine`] to keep the pairs
+/// whose geometries actually satisfy a spatial predicate.
+pub fn cell_join(
+    left: &Table,
+    left_cell: usize,
+    right: &Table,
+    right_cell: usize,
+) -> Result<JoinIndices> {
+    let left_type = left.schema().field(left_cell).data_type();
+    let right_type = right.schema().field(right_cell).data_type();
+    if left_type != right_type {
+        return Err(GeoArrowError::General(format!(
+            "Cell id columns must have the same type, got {left_type} and {right_type}"
+        )));
+    }
+
+    let converter = RowConverter::new(vec![SortField::new(left_type.clone())])?;
+    let cell_rows = |table: &Table, index: usize| {
+        table
+            .batches()
+            .iter()
+            .map(|batch| {
+                let column = batch.column(index);
+                let rows = converter.convert_columns(&[column.clone()])?;
+                Ok((column.nulls().cloned(), rows))
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+
+    let right_rows = cell_rows(right, right_cell)?;
+    let mut rows_by_cell: HashMap<&[u8], Vec<u64>> = HashMap::new();
+    let mut row = 0;
+    for (nulls, rows) in right_rows.iter() {
+        for (i, cell) in rows.iter().enumerate() {
+            if nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                rows_by_cell.entry(cell.data()).or_default().push(row);
+            }
+            row += 1;
+        }
+    }
+
+    let left_rows = cell_rows(left, left_cell)?;
+    let mut pairs = vec![];
+    let mut row = 0;
+    for (nulls, rows) in left_rows.iter() {
+        for (i, cell) in rows.iter().enumerate() {
+            if nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                if let Some(right_rows) = rows_by_cell.get(cell.data()) {
+                    pairs.extend(right_rows.iter().map(|&right_row| (row, right_row)));
+                }
+            }
+            row += 1;
+        }
+    }
+    Ok(JoinIndices::from_pairs(pairs))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::StringArray;
+    use arrow_schema::DataType;
+    use geo::Intersects;
+
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use crate::io::geo::geometry_to_geo;
+    use crate::test::point;
+    use crate::ArrayBase;
+
+    fn polygon_table(polygons: Vec<geo::Polygon>, cells: Vec<&str>) -> Table {
+        let array: PolygonArray = (polygons.as_slice(), Dimension::XY).into();
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("cell", DataType::Utf8, true)),
+            array.extension_field(),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(StringArray::from(cells)), array.into_array_ref()],
+        )
+        .unwrap();
+        Table::try_new(vec![batch], schema).unwrap()
+    }
+
+    #[test]
+    fn equality_join() {
+        let square = geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        // The same square, clockwise and with a repeated coordinate
+        let square_cw = geo::polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 1.),
+            (x: 0., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 0.),
+        ];
+        let other = geo::polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)];
+        let left = polygon_table(vec![square.clone(), other], vec!["a", "b"]);
+        let right = polygon_table(vec![square_cw, square], vec!["a", "a"]);
+
+        let indices = geometry_equality_join(&left, None, &right, None).unwrap();
+        assert_eq!(indices.left, UInt64Array::from(vec![0, 0]));
+        assert_eq!(indices.right, UInt64Array::from(vec![0, 1]));
+
+        let joined = indices.take(&left, &right).unwrap();
+        assert_eq!(joined.len(), 2);
+        assert_eq!(joined.schema().field(2).name(), "cell_right");
+    }
+
+    #[test]
+    fn refined_cell_join() {
+        let left = polygon_table(
+            vec![
+                geo::polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+                geo::polygon![(x: 10., y: 10.), (x: 11., y: 10.), (x: 11., y: 11.)],
+            ],
+            vec!["foo", "bar"],
+        );
+        // Points (0, 1), (1, 2) and (2, 3) in cells "foo", "bar" and "baz"
+        let right = point::table();
+
+        let candidates = cell_join(&left, 0, &right, 1).unwrap();
+        assert_eq!(candidates.left, UInt64Array::from(vec![0, 1]));
+        assert_eq!(candidates.right, UInt64Array::from(vec![0, 1]));
+
+        let refined = candidates
+            .refine(&left, None, &right, None, |l, r| {
+                geometry_to_geo(l).intersects(&geometry_to_geo(r))
+            })
+            .unwrap();
+        assert_eq!(refined.left, UInt64Array::from(vec![0]));
+        assert_eq!(refined.right, UInt64Array::from(vec![0]));
+
+        // Utf8 and UInt8 cell ids
+        assert!(cell_join(&left, 0, &right, 0).is_err());
+    }
+}
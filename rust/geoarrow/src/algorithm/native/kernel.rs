@@ -0,0 +1,346 @@
+//! Helpers for writing custom kernels over geometry arrays.
+//!
+//! A kernel is written once as a closure over a single [`Geometry`] scalar, which implements
+//! [`GeometryTrait`][geo_traits::GeometryTrait] for every native geometry type. The helpers in this
+//! module take care of dispatching over the [`NativeType`] of the input, skipping nulls, and
+//! applying the kernel chunk by chunk (in parallel when the `rayon` feature is enabled).
+//!
+//! The output is collected with a [`KernelBuilder`], which is implemented for the Arrow primitive,
+//! boolean and string builders. For kernels returning geometries, use
+//! [`MapGeometries`][crate::algorithm::geo::MapGeometries].
+//!
+//! The [`unary_kernel!`][crate::unary_kernel] and [`binary_kernel!`][crate::binary_kernel] macros
+//! are shorthands for the functions in this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use arrow_array::builder::UInt32Builder;
+//! use geo_traits::{GeometryTrait, LineStringTrait};
+//! use geoarrow::algorithm::native::kernel::unary;
+//! use geoarrow::array::LineStringArray;
+//! use geoarrow::datatypes::Dimension;
+//!
+//! let line_string = geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+//! let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+//!
+//! let num_coords = unary::<UInt32Builder, _>(&array, |geom| match geom.as_type() {
+//!     geo_traits::GeometryType::LineString(line_string) => {
+//!         Ok(Some(line_string.num_coords() as u32))
+//!     }
+//!     _ => Ok(None),
+//! })
+//! .unwrap();
+//! assert_eq!(num_coords.value(0), 2);
+//! ```
+
+use arrow_array::builder::{BooleanBuilder, GenericStringBuilder, PrimitiveBuilder};
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::{Array, BooleanArray, GenericStringArray, OffsetSizeTrait, PrimitiveArray};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::array::AsNativeArray;
+use crate::chunked_array::{ChunkedArray, ChunkedNativeArray};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::scalar::Geometry;
+use crate::trait_::{ArrayAccessor, NativeGeometryAccessor};
+use crate::NativeArray;
+
+/// A builder for the output of a kernel.
+pub trait KernelBuilder {
+    /// The value produced by the kernel for each geometry.
+    type Value;
+
+    /// The finished output array.
+    type Output: Array;
+
+    /// Create a new builder with space for `capacity` values.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Append a value, or a null if `None`.
+    fn push(&mut self, value: Option<Self::Value>);
+
+    /// Finish the output array.
+    fn finish_array(self) -> Self::Output;
+}
+
+impl<T: ArrowPrimitiveType> KernelBuilder for PrimitiveBuilder<T> {
+    type Value = T::Native;
+    type Output = PrimitiveArray<T>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        PrimitiveBuilder::with_capacity(capacity)
+    }
+
+    fn push(&mut self, value: Option<Self::Value>) {
+        self.append_option(value)
+    }
+
+    fn finish_array(mut self) -> Self::Output {
+        self.finish()
+    }
+}
+
+impl KernelBuilder for BooleanBuilder {
+    type Value = bool;
+    type Output = BooleanArray;
+
+    fn with_capacity(capacity: usize) -> Self {
+        BooleanBuilder::with_capacity(capacity)
+    }
+
+    fn push(&mut self, value: Option<Self::Value>) {
+        self.append_option(value)
+    }
+
+    fn finish_array(mut self) -> Self::Output {
+        self.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> KernelBuilder for GenericStringBuilder<O> {
+    type Value = String;
+    type Output = GenericStringArray<O>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        GenericStringBuilder::with_capacity(capacity, 0)
+    }
+
+    fn push(&mut self, value: Option<Self::Value>) {
+        self.append_option(value)
+    }
+
+    fn finish_array(mut self) -> Self::Output {
+        self.finish()
+    }
+}
+
+/// Iterate over the geometries of an array of any native type, considering validity.
+pub fn iter_geometries(
+    array: &dyn NativeArray,
+) -> Box<dyn ExactSizeIterator<Item = Option<Geometry<'_>>> + '_> {
+    use NativeType::*;
+
+    match array.data_type() {
+        Point(_, _) => Box::new(array.as_point().iter_geometry()),
+        LineString(_, _) => Box::new(array.as_line_string().iter_geometry()),
+        Polygon(_, _) => Box::new(array.as_polygon().iter_geometry()),
+        MultiPoint(_, _) => Box::new(array.as_multi_point().iter_geometry()),
+        MultiLineString(_, _) => Box::new(array.as_multi_line_string().iter_geometry()),
+        MultiPolygon(_, _) => Box::new(array.as_multi_polygon().iter_geometry()),
+        GeometryCollection(_, _) => Box::new(array.as_geometry_collection().iter_geometry()),
+        Rect(_) => Box::new(array.as_rect().iter().map(|rect| rect.map(Geometry::Rect))),
+        Geometry(_) => Box::new(array.as_geometry().iter_geometry()),
+    }
+}
+
+/// Whether an array has any geometry with Z or M values.
+pub(crate) fn has_z_or_m(array: &dyn NativeArray) -> bool {
+    match array.data_type() {
+        // Geometry arrays have no M children
+        NativeType::Geometry(_) => array.as_geometry().has_dimension(Dimension::XYZ),
+        data_type => data_type
+            .dimension()
+            .is_some_and(|dim| dim != Dimension::XY),
+    }
+}
+
+/// Apply a kernel to every geometry of an array.
+///
+/// Null geometries are not passed to the kernel and are null in the output. The kernel may return
+/// `Ok(None)` to produce a null. The first error returned by the kernel is propagated.
+pub fn unary<B, F>(array: &dyn NativeArray, op: F) -> Result<B::Output>
+where
+    B: KernelBuilder,
+    F: Fn(&Geometry<'_>) -> Result<Option<B::Value>>,
+{
+    let mut builder = B::with_capacity(array.len());
+    for geom in iter_geometries(array) {
+        match geom {
+            Some(geom) => builder.push(op(&geom)?),
+            None => builder.push(None),
+        }
+    }
+    Ok(builder.finish_array())
+}
+
+/// Apply a kernel to every pair of geometries at the same index of two arrays.
+///
+/// The output is null where either input is null. Returns an error if the arrays have different
+/// lengths.
+pub fn binary<B, F>(left: &dyn NativeArray, right: &dyn NativeArray, op: F) -> Result<B::Output>
+where
+    B: KernelBuilder,
+    F: Fn(&Geometry<'_>, &Geometry<'_>) -> Result<Option<B::Value>>,
+{
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Arrays must have the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+
+    let mut builder = B::with_capacity(left.len());
+    for (left_geom, right_geom) in iter_geometries(left).zip(iter_geometries(right)) {
+        match (left_geom, right_geom) {
+            (Some(left_geom), Some(right_geom)) => builder.push(op(&left_geom, &right_geom)?),
+            _ => builder.push(None),
+        }
+    }
+    Ok(builder.finish_array())
+}
+
+/// Apply a kernel to every geometry of a chunked array.
+///
+/// See [`unary`]. If the `rayon` feature is enabled, chunks are processed in parallel.
+pub fn unary_chunked<B, F>(array: &dyn ChunkedNativeArray, op: F) -> Result<ChunkedArray<B::Output>>
+where
+    B: KernelBuilder,
+    F: Fn(&Geometry<'_>) -> Result<Option<B::Value>> + Sync + Send,
+{
+    let chunks = array.geometry_chunks();
+
+    #[cfg(feature = "rayon")]
+    let output = chunks
+        .par_iter()
+        .map(|chunk| unary::<B, _>(chunk.as_ref(), &op))
+        .collect::<Result<Vec<_>>>()?;
+
+    #[cfg(not(feature = "rayon"))]
+    let output = chunks
+        .iter()
+        .map(|chunk| unary::<B, _>(chunk.as_ref(), &op))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ChunkedArray::new(output))
+}
+
+/// Apply a kernel to every pair of geometries at the same index of two chunked arrays.
+///
+/// See [`binary`]. The arrays must have the same chunk lengths. If the `rayon` feature is enabled,
+/// chunks are processed in parallel.
+pub fn binary_chunked<B, F>(
+    left: &dyn ChunkedNativeArray,
+    right: &dyn ChunkedNativeArray,
+    op: F,
+) -> Result<ChunkedArray<B::Output>>
+where
+    B: KernelBuilder,
+    F: Fn(&Geometry<'_>, &Geometry<'_>) -> Result<Option<B::Value>> + Sync + Send,
+{
+    let left_chunks = left.geometry_chunks();
+    let right_chunks = right.geometry_chunks();
+    let same_chunks = left_chunks.len() == right_chunks.len()
+        && left_chunks
+            .iter()
+            .zip(&right_chunks)
+            .all(|(left, right)| left.len() == right.len());
+    if !same_chunks {
+        return Err(GeoArrowError::General(
+            "Chunked arrays must have the same chunk lengths".to_string(),
+        ));
+    }
+    let pairs: Vec<_> = left_chunks.iter().zip(&right_chunks).collect();
+
+    #[cfg(feature = "rayon")]
+    let output = pairs
+        .par_iter()
+        .map(|(left, right)| binary::<B, _>(left.as_ref(), right.as_ref(), &op))
+        .collect::<Result<Vec<_>>>()?;
+
+    #[cfg(not(feature = "rayon"))]
+    let output = pairs
+        .iter()
+        .map(|(left, right)| binary::<B, _>(left.as_ref(), right.as_ref(), &op))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ChunkedArray::new(output))
+}
+
+/// Apply a kernel to every geometry of an array. Shorthand for [`unary`][crate::algorithm::native::kernel::unary].
+///
+/// Takes the array, the [`KernelBuilder`][crate::algorithm::native::kernel::KernelBuilder] type of
+/// the output, and a closure from a geometry to `Result<Option<Value>>`.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::builder::BooleanBuilder;
+/// use geo_traits::GeometryTrait;
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+///
+/// let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+/// let is_point = geoarrow::unary_kernel!(&array, BooleanBuilder, |geom| {
+///     Ok(Some(matches!(geom.as_type(), geo_traits::GeometryType::Point(_))))
+/// })
+/// .unwrap();
+/// assert!(is_point.value(0));
+/// ```
+#[macro_export]
+macro_rules! unary_kernel {
+    ($array:expr, $builder:ty, |$geom:ident| $body:expr) => {
+        $crate::algorithm::native::kernel::unary::<$builder, _>($array, |$geom| $body)
+    };
+}
+
+/// Apply a kernel to every pair of geometries of two arrays. Shorthand for
+/// [`binary`][crate::algorithm::native::kernel::binary].
+#[macro_export]
+macro_rules! binary_kernel {
+    ($left:expr, $right:expr, $builder:ty, |$left_geom:ident, $right_geom:ident| $body:expr) => {
+        $crate::algorithm::native::kernel::binary::<$builder, _>(
+            $left,
+            $right,
+            |$left_geom, $right_geom| $body,
+        )
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chunked_array::ChunkedGeometryArray;
+    use crate::test::{point, polygon};
+    use arrow_array::builder::{Float64Builder, StringBuilder};
+    use geo_traits::{CoordTrait, GeometryTrait, GeometryType, PointTrait};
+
+    fn x(geom: &Geometry<'_>) -> Option<f64> {
+        match geom.as_type() {
+            GeometryType::Point(point) => point.coord().map(|coord| coord.x()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn unary_dispatch() {
+        let points = point::point_array();
+        let xs = unary::<Float64Builder, _>(&points, |geom| Ok(x(geom))).unwrap();
+        assert_eq!(xs.values().to_vec(), vec![0., 1., 2.]);
+
+        // Non-point geometries produce nulls
+        let polygons = polygon::p_array();
+        let xs = unary_kernel!(&polygons, Float64Builder, |geom| Ok(x(geom))).unwrap();
+        assert_eq!(xs.null_count(), polygons.len());
+    }
+
+    #[test]
+    fn binary_and_chunked() {
+        let points = point::point_array();
+        let sums = binary_kernel!(&points, &points, StringBuilder, |left, right| {
+            Ok(x(left).zip(x(right)).map(|(a, b)| (a + b).to_string()))
+        })
+        .unwrap();
+        assert_eq!(sums.value(2), "4");
+
+        let chunked = ChunkedGeometryArray::new(vec![points.clone(), points]);
+        let xs = unary_chunked::<Float64Builder, _>(&chunked, |geom| Ok(x(geom))).unwrap();
+        assert_eq!(xs.len(), 6);
+
+        let short = ChunkedGeometryArray::new(vec![point::point_array()]);
+        assert!(binary_chunked::<Float64Builder, _>(&chunked, &short, |_, _| Ok(None)).is_err());
+    }
+}
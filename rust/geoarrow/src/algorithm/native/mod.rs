@@ -5,13 +5,23 @@
 
 mod binary;
 pub mod bounding_rect;
+mod canonicalize;
 mod cast;
+mod compact;
 mod concatenate;
 pub(crate) mod downcast;
+mod downcast_dimension;
 pub(crate) mod eq;
 mod explode;
+mod explode_parts;
+pub(crate) mod fix_rings;
+pub(crate) mod flatten_collections;
+mod force_dimension;
+mod join;
+pub mod kernel;
 mod map_chunks;
 mod map_coords;
+pub(crate) mod polygon_sums;
 mod rechunk;
 mod take;
 mod total_bounds;
@@ -20,10 +30,21 @@ mod unary;
 
 pub use binary::Binary;
 pub use bounding_rect::BoundingRectArray;
-pub use cast::Cast;
+pub use canonicalize::Canonicalize;
+pub use cast::{cast_arrays, cast_reader, Cast, CastReader};
+pub use compact::Compact;
 pub use concatenate::Concatenate;
-pub use downcast::{Downcast, DowncastTable};
+pub use downcast::{
+    downcast_multi_to_single, infer_downcast_type, Downcast, DowncastInferenceOptions,
+    DowncastMultiToSingle, DowncastTable, DowncastTypeInference, MultiToSingle,
+};
+pub use downcast_dimension::DowncastDimension;
 pub use explode::{Explode, ExplodeTable};
+pub use explode_parts::{ExplodeParts, ExplodedParts};
+pub use fix_rings::{FixRings, FixRingsOptions, RingCorrections, RingOrientation};
+pub use flatten_collections::{CollectionHandling, FlattenCollections};
+pub use force_dimension::{ForceDimension, DEFAULT_M_FILL, DEFAULT_Z_FILL};
+pub use join::{cell_join, geometry_equality_join, JoinIndices};
 pub use map_chunks::MapChunks;
 pub use map_coords::MapCoords;
 pub use rechunk::Rechunk;
@@ -0,0 +1,335 @@
+//! Planar area and centroid of polygons, accumulated directly over coordinate buffers.
+//!
+//! These give the same results as [`geo::Area`] and [`geo::Centroid`] without building a
+//! [`geo`] geometry for every row, which dominates the cost of those kernels on large arrays.
+//! With the `rayon` feature, the rows of an array are processed in parallel.
+
+use arrow_buffer::OffsetBuffer;
+
+use crate::array::{CoordBuffer, MultiPolygonArray, PolygonArray};
+
+/// The minimum number of rows processed by a single rayon task.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_LEN: usize = 1024;
+
+/// Compute `f` for every row index, in parallel with the `rayon` feature.
+pub(crate) fn map_rows<R: Send>(len: usize, f: impl Fn(usize) -> R + Sync + Send) -> Vec<R> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        (0..len)
+            .into_par_iter()
+            .with_min_len(PARALLEL_MIN_LEN)
+            .map(f)
+            .collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    {
+        (0..len).map(f).collect()
+    }
+}
+
+/// A view of the x and y values of a coordinate buffer.
+struct XY<'a> {
+    x: &'a [f64],
+    y: &'a [f64],
+    stride: usize,
+}
+
+impl<'a> XY<'a> {
+    fn new(coords: &'a CoordBuffer) -> Self {
+        match coords {
+            CoordBuffer::Interleaved(coords) => {
+                let values: &[f64] = coords.coords.as_ref();
+                Self {
+                    x: values,
+                    y: values.get(1..).unwrap_or_default(),
+                    stride: coords.dim.size(),
+                }
+            }
+            CoordBuffer::Separated(coords) => Self {
+                x: coords.buffers[0].as_ref(),
+                y: coords.buffers[1].as_ref(),
+                stride: 1,
+            },
+        }
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> (f64, f64) {
+        (self.x[index * self.stride], self.y[index * self.stride])
+    }
+}
+
+/// The shoelace sums of a ring.
+#[derive(Debug, Default, Clone, Copy)]
+struct RingSums {
+    /// Twice the signed area.
+    twice_area: f64,
+    /// Six times the signed area times the centroid, relative to the first coordinate.
+    x: f64,
+    y: f64,
+    /// The first coordinate, which coordinates are shifted by to limit rounding errors.
+    origin: (f64, f64),
+}
+
+impl RingSums {
+    /// The sums of the ring with coordinates `start..end`, closing it if needed.
+    fn new(xy: &XY, start: usize, end: usize) -> Self {
+        if start == end {
+            return Self::default();
+        }
+
+        let origin = xy.get(start);
+        let mut sums = Self {
+            origin,
+            ..Default::default()
+        };
+        let mut prev = (0., 0.);
+        for i in start + 1..=end {
+            // The last iteration closes the ring, and is zero if it is already closed.
+            let (x, y) = if i == end { origin } else { xy.get(i) };
+            let next = (x - origin.0, y - origin.1);
+            let cross = prev.0 * next.1 - next.0 * prev.1;
+            sums.twice_area += cross;
+            sums.x += (prev.0 + next.0) * cross;
+            sums.y += (prev.1 + next.1) * cross;
+            prev = next;
+        }
+        sums
+    }
+
+    fn area(&self) -> f64 {
+        self.twice_area / 2.
+    }
+
+    fn centroid(&self) -> (f64, f64) {
+        let scale = 3. * self.twice_area;
+        (
+            self.origin.0 + self.x / scale,
+            self.origin.1 + self.y / scale,
+        )
+    }
+}
+
+/// The signed area of the polygon whose rings are `rings`, following [`geo::Area`]: interior
+/// areas are subtracted from the exterior area, and the sign is that of the exterior ring.
+fn polygon_signed_area(xy: &XY, ring_offsets: &OffsetBuffer<i32>, rings: (usize, usize)) -> f64 {
+    let (start, end) = rings;
+    if start == end {
+        return 0.;
+    }
+
+    let ring_area = |ring: usize| {
+        RingSums::new(
+            xy,
+            ring_offsets[ring] as usize,
+            ring_offsets[ring + 1] as usize,
+        )
+        .area()
+    };
+    let exterior = ring_area(start);
+    let area = (start + 1..end).fold(exterior.abs(), |area, ring| area - ring_area(ring).abs());
+    if exterior < 0. {
+        -area
+    } else {
+        area
+    }
+}
+
+/// The area-weighted sums of the centroids of a set of polygons.
+#[derive(Debug, Default)]
+struct CentroidSums {
+    weight: f64,
+    x: f64,
+    y: f64,
+}
+
+impl CentroidSums {
+    /// Add the rings of a polygon, returning `false` if any is empty.
+    fn add_polygon(
+        &mut self,
+        xy: &XY,
+        ring_offsets: &OffsetBuffer<i32>,
+        rings: (usize, usize),
+    ) -> bool {
+        let (start, end) = rings;
+        if start == end {
+            return false;
+        }
+        for ring in start..end {
+            let (coord_start, coord_end) =
+                (ring_offsets[ring] as usize, ring_offsets[ring + 1] as usize);
+            if coord_start == coord_end {
+                return false;
+            }
+
+            let sums = RingSums::new(xy, coord_start, coord_end);
+            if sums.twice_area == 0. {
+                continue;
+            }
+            let (x, y) = sums.centroid();
+            let weight = if ring == start {
+                sums.area().abs()
+            } else {
+                -sums.area().abs()
+            };
+            self.weight += weight;
+            self.x += x * weight;
+            self.y += y * weight;
+        }
+        true
+    }
+
+    /// The centroid, or `None` if the polygons have no area, where the centroid is computed
+    /// from their rings instead.
+    fn finish(self) -> Option<(f64, f64)> {
+        (self.weight != 0.).then(|| (self.x / self.weight, self.y / self.weight))
+    }
+}
+
+impl PolygonArray {
+    fn ring_range(&self, index: usize) -> (usize, usize) {
+        (
+            self.geom_offsets[index] as usize,
+            self.geom_offsets[index + 1] as usize,
+        )
+    }
+
+    /// The signed area of every polygon, including null rows.
+    pub(crate) fn raw_signed_areas(&self) -> Vec<f64> {
+        let xy = XY::new(&self.coords);
+        map_rows(self.geom_offsets.len() - 1, |index| {
+            polygon_signed_area(&xy, &self.ring_offsets, self.ring_range(index))
+        })
+    }
+
+    /// The centroid of every polygon, or `None` for null rows and where the centroid can't be
+    /// computed from areas: polygons that are empty, have an empty ring or have zero area.
+    pub(crate) fn raw_centroids(&self) -> Vec<Option<(f64, f64)>> {
+        let xy = XY::new(&self.coords);
+        map_rows(self.geom_offsets.len() - 1, |index| {
+            if self.validity.as_ref().is_some_and(|v| v.is_null(index)) {
+                return None;
+            }
+            let mut sums = CentroidSums::default();
+            sums.add_polygon(&xy, &self.ring_offsets, self.ring_range(index))
+                .then(|| sums.finish())
+                .flatten()
+        })
+    }
+}
+
+impl MultiPolygonArray {
+    fn polygon_range(&self, index: usize) -> std::ops::Range<usize> {
+        self.geom_offsets[index] as usize..self.geom_offsets[index + 1] as usize
+    }
+
+    fn ring_range(&self, polygon: usize) -> (usize, usize) {
+        (
+            self.polygon_offsets[polygon] as usize,
+            self.polygon_offsets[polygon + 1] as usize,
+        )
+    }
+
+    /// The signed area of every multi polygon, including null rows.
+    pub(crate) fn raw_signed_areas(&self) -> Vec<f64> {
+        let xy = XY::new(&self.coords);
+        map_rows(self.geom_offsets.len() - 1, |index| {
+            self.polygon_range(index)
+                .map(|polygon| {
+                    polygon_signed_area(&xy, &self.ring_offsets, self.ring_range(polygon))
+                })
+                .sum()
+        })
+    }
+
+    /// The unsigned area of every multi polygon, including null rows.
+    pub(crate) fn raw_unsigned_areas(&self) -> Vec<f64> {
+        let xy = XY::new(&self.coords);
+        map_rows(self.geom_offsets.len() - 1, |index| {
+            self.polygon_range(index)
+                .map(|polygon| {
+                    polygon_signed_area(&xy, &self.ring_offsets, self.ring_range(polygon)).abs()
+                })
+                .sum()
+        })
+    }
+
+    /// The centroid of every multi polygon, or `None` for null rows and where the centroid
+    /// can't be computed from areas: multi polygons that are empty, have an empty part or ring
+    /// or have zero area.
+    pub(crate) fn raw_centroids(&self) -> Vec<Option<(f64, f64)>> {
+        let xy = XY::new(&self.coords);
+        map_rows(self.geom_offsets.len() - 1, |index| {
+            if self.validity.as_ref().is_some_and(|v| v.is_null(index)) {
+                return None;
+            }
+            let polygons = self.polygon_range(index);
+            if polygons.is_empty() {
+                return None;
+            }
+            let mut sums = CentroidSums::default();
+            polygons
+                .into_iter()
+                .all(|polygon| sums.add_polygon(&xy, &self.ring_offsets, self.ring_range(polygon)))
+                .then(|| sums.finish())
+                .flatten()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo::{Area, Centroid};
+
+    use crate::array::{CoordType, MultiPolygonArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::test::{multipolygon, polygon};
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn matches_geo() {
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let array: PolygonArray = polygon::p_array().into_coord_type(coord_type);
+            let areas = array.raw_signed_areas();
+            let centroids = array.raw_centroids();
+            for (index, geom) in array.iter_geo_values().enumerate() {
+                approx::assert_relative_eq!(areas[index], geom.signed_area());
+                let centroid = geom.centroid().unwrap();
+                let (x, y) = centroids[index].unwrap();
+                approx::assert_relative_eq!(x, centroid.x());
+                approx::assert_relative_eq!(y, centroid.y());
+            }
+
+            let array: MultiPolygonArray = multipolygon::mp_array().into_coord_type(coord_type);
+            let areas = array.raw_unsigned_areas();
+            let centroids = array.raw_centroids();
+            for (index, geom) in array.iter_geo_values().enumerate() {
+                approx::assert_relative_eq!(areas[index], geom.unsigned_area());
+                let centroid = geom.centroid().unwrap();
+                let (x, y) = centroids[index].unwrap();
+                approx::assert_relative_eq!(x, centroid.x());
+                approx::assert_relative_eq!(y, centroid.y());
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_rings() {
+        // Polygons without area have no area-weighted centroid.
+        let square = geo::Polygon::new(
+            geo::LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.)]),
+            vec![],
+        );
+        let flat = geo::Polygon::new(
+            geo::LineString::from(vec![(0., 0.), (1., 0.), (2., 0.)]),
+            vec![],
+        );
+        let array: PolygonArray = (vec![square, flat].as_slice(), Dimension::XY).into();
+        assert_eq!(array.raw_signed_areas(), vec![4., 0.]);
+        assert_eq!(array.raw_centroids(), vec![Some((1., 1.)), None]);
+    }
+}
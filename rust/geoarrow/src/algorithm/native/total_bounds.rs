@@ -3,7 +3,7 @@ use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::NativeType;
 use crate::trait_::ArrayAccessor;
-use crate::NativeArray;
+use crate::{ArrayBase, NativeArray};
 
 /// Computes the total bounds (extent) of the input.
 pub trait TotalBounds {
@@ -12,6 +12,12 @@ pub trait TotalBounds {
 
 impl TotalBounds for PointArray {
     fn total_bounds(&self) -> BoundingRect {
+        // The coordinates of a point array are sliced with it, so without nulls they are exactly
+        // the coordinates of its geometries.
+        if self.null_count() == 0 {
+            return self.coords().bounds();
+        }
+
         let mut bounds = BoundingRect::new();
         for geom in self.iter().flatten() {
             bounds.add_point(&geom);
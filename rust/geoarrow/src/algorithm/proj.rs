@@ -1,22 +1,50 @@
 //! Bindings to the [`proj`] crate for coordinate reprojection.
 
+use std::sync::Arc;
+
 use crate::array::*;
-use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
 use proj::{Proj, Transform};
 
+/// The maximum number of times a segment is halved when densifying it during reprojection, so
+/// that a segment gains at most `2^MAX_DENSIFY_DEPTH - 1` vertices.
+const MAX_DENSIFY_DEPTH: usize = 10;
+
+/// Options for [`Reproject::reproject_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReprojectOptions {
+    /// Insert vertices into segments that the transformation curves by more than this distance,
+    /// in units of the target CRS.
+    ///
+    /// Each segment is halved until the transformed midpoint of every piece is within the
+    /// tolerance of the midpoint of its transformed end points, or until it has been halved
+    /// 10 times. If `None`, only the existing vertices are transformed, so that long segments
+    /// may be badly distorted.
+    pub densify_tolerance: Option<f64>,
+}
+
 /// Reproject an array using PROJ
 ///
 /// Note: this will currently return a two-dimensional array
 pub trait Reproject {
     fn reproject(&self, proj: &Proj) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        self.reproject_with_options(proj, Default::default())
+    }
+
+    /// Reproject an array using PROJ with specific options.
+    fn reproject_with_options(&self, proj: &Proj, options: ReprojectOptions) -> Result<Self>
     where
         Self: Sized;
 }
 
 impl Reproject for PointArray {
-    fn reproject(&self, proj: &Proj) -> Result<Self> {
+    fn reproject_with_options(&self, proj: &Proj, _options: ReprojectOptions) -> Result<Self> {
         let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.len());
 
         for maybe_geom in self.iter_geo() {
@@ -35,13 +63,17 @@ impl Reproject for PointArray {
 macro_rules! iter_geo_impl {
     ($type:ty, $builder_type:ty, $push_func:ident) => {
         impl Reproject for $type {
-            fn reproject(&self, proj: &Proj) -> Result<Self> {
+            fn reproject_with_options(
+                &self,
+                proj: &Proj,
+                options: ReprojectOptions,
+            ) -> Result<Self> {
                 let mut output_array =
                     <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
 
                 for maybe_geom in self.iter_geo() {
-                    if let Some(mut geom) = maybe_geom {
-                        geom.transform(proj)?;
+                    if let Some(geom) = maybe_geom {
+                        let geom = geom.reproject_geo(proj, options.densify_tolerance)?;
                         output_array.$push_func(Some(&geom))?;
                     } else {
                         output_array.push_null()
@@ -64,6 +96,137 @@ iter_geo_impl!(
 );
 iter_geo_impl!(MultiPolygonArray, MultiPolygonBuilder, push_multi_polygon);
 
+/// Transform a [`geo`] geometry, densifying its segments with a tolerance if given.
+trait ReprojectGeo: Sized {
+    fn reproject_geo(self, proj: &Proj, tolerance: Option<f64>) -> Result<Self>;
+}
+
+impl ReprojectGeo for geo::MultiPoint {
+    fn reproject_geo(mut self, proj: &Proj, _tolerance: Option<f64>) -> Result<Self> {
+        self.transform(proj)?;
+        Ok(self)
+    }
+}
+
+impl ReprojectGeo for geo::LineString {
+    fn reproject_geo(mut self, proj: &Proj, tolerance: Option<f64>) -> Result<Self> {
+        let Some(tolerance) = tolerance else {
+            self.transform(proj)?;
+            return Ok(self);
+        };
+        let Some(first) = self.0.first() else {
+            return Ok(self);
+        };
+
+        let mut start = (*first, convert(proj, *first)?);
+        let mut coords = vec![start.1];
+        for end in self.0.iter().skip(1) {
+            let end = (*end, convert(proj, *end)?);
+            densify_segment(proj, start, end, tolerance, 0, &mut coords)?;
+            coords.push(end.1);
+            start = end;
+        }
+        Ok(geo::LineString::new(coords))
+    }
+}
+
+impl ReprojectGeo for geo::Polygon {
+    fn reproject_geo(self, proj: &Proj, tolerance: Option<f64>) -> Result<Self> {
+        let (exterior, interiors) = self.into_inner();
+        let interiors = interiors
+            .into_iter()
+            .map(|interior| interior.reproject_geo(proj, tolerance))
+            .collect::<Result<_>>()?;
+        Ok(geo::Polygon::new(
+            exterior.reproject_geo(proj, tolerance)?,
+            interiors,
+        ))
+    }
+}
+
+impl ReprojectGeo for geo::MultiLineString {
+    fn reproject_geo(self, proj: &Proj, tolerance: Option<f64>) -> Result<Self> {
+        self.into_iter()
+            .map(|line_string| line_string.reproject_geo(proj, tolerance))
+            .collect()
+    }
+}
+
+impl ReprojectGeo for geo::MultiPolygon {
+    fn reproject_geo(self, proj: &Proj, tolerance: Option<f64>) -> Result<Self> {
+        self.into_iter()
+            .map(|polygon| polygon.reproject_geo(proj, tolerance))
+            .collect()
+    }
+}
+
+fn convert(proj: &Proj, coord: geo::Coord) -> Result<geo::Coord> {
+    Ok(proj.convert(coord)?)
+}
+
+/// Push the transformed vertices inserted between the start and end of a segment, given as pairs
+/// of source and transformed coordinates, halving the segment while its transformed midpoint is
+/// further than `tolerance` from the midpoint of its transformed end points.
+fn densify_segment(
+    proj: &Proj,
+    start: (geo::Coord, geo::Coord),
+    end: (geo::Coord, geo::Coord),
+    tolerance: f64,
+    depth: usize,
+    coords: &mut Vec<geo::Coord>,
+) -> Result<()> {
+    if depth == MAX_DENSIFY_DEPTH {
+        return Ok(());
+    }
+    let source_mid = (start.0 + end.0) / 2.;
+    let mid = (source_mid, convert(proj, source_mid)?);
+    let offset = mid.1 - (start.1 + end.1) / 2.;
+    if offset.x.hypot(offset.y) <= tolerance {
+        return Ok(());
+    }
+    densify_segment(proj, start, mid, tolerance, depth + 1, coords)?;
+    coords.push(mid.1);
+    densify_segment(proj, mid, end, tolerance, depth + 1, coords)
+}
+
+impl Reproject for Arc<dyn NativeArray> {
+    fn reproject_with_options(&self, proj: &Proj, options: ReprojectOptions) -> Result<Self> {
+        use NativeType::*;
+
+        let array = self.as_ref();
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(array.as_point().reproject_with_options(proj, options)?),
+            LineString(_, _) => Arc::new(
+                array
+                    .as_line_string()
+                    .reproject_with_options(proj, options)?,
+            ),
+            Polygon(_, _) => Arc::new(array.as_polygon().reproject_with_options(proj, options)?),
+            MultiPoint(_, _) => Arc::new(
+                array
+                    .as_multi_point()
+                    .reproject_with_options(proj, options)?,
+            ),
+            MultiLineString(_, _) => Arc::new(
+                array
+                    .as_multi_line_string()
+                    .reproject_with_options(proj, options)?,
+            ),
+            MultiPolygon(_, _) => Arc::new(
+                array
+                    .as_multi_polygon()
+                    .reproject_with_options(proj, options)?,
+            ),
+            data_type => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Reprojecting arrays of type {data_type:?}"
+                )))
+            }
+        };
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::trait_::ArrayAccessor;
@@ -87,4 +250,52 @@ mod test {
         assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
         dbg!(out);
     }
+
+    #[test]
+    fn densified_line_string() {
+        let line_string = geo::line_string![(x: 0., y: 0.), (x: 10., y: 60.)];
+        let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+        let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+        let inverse = Proj::new_known_crs("EPSG:3857", "EPSG:4326", None).unwrap();
+
+        let out = array.reproject(&proj).unwrap();
+        assert_eq!(out.value_as_geo(0).0.len(), 2);
+
+        let options = ReprojectOptions {
+            densify_tolerance: Some(1000.),
+        };
+        let out = array.reproject_with_options(&proj, options).unwrap();
+        let coords = out.value_as_geo(0).0;
+        assert!(coords.len() > 2);
+        assert!(coords.len() <= 1 << MAX_DENSIFY_DEPTH);
+        // Every inserted vertex is on the source line
+        for coord in coords {
+            let source = inverse.convert(coord).unwrap();
+            assert_relative_eq!(source.y, source.x * 6., epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn table_to_crs() {
+        use crate::array::metadata::ArrayMetadata;
+        use crate::test::point;
+
+        let mut table = point::table();
+        let wgs84 = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let web_mercator = ArrayMetadata::from_authority_code("EPSG:3857".to_string());
+        assert!(table.to_crs(&web_mercator).is_err());
+
+        table.set_crs(&wgs84, false).unwrap();
+        assert!(table.set_crs(&web_mercator, false).is_err());
+
+        let out = table.to_crs(&web_mercator).unwrap();
+        let chunk = out.geometry_column(None).unwrap().geometry_chunks()[0].clone();
+        assert_eq!(chunk.metadata().to_epsg(0), Some(3857));
+        let out_point = chunk.as_ref().as_point().value_as_geo(1);
+        assert_relative_eq!(out_point.x(), 111319.49079327357);
+
+        let bounds = table.total_bounds_in_crs(None, &web_mercator).unwrap();
+        assert_relative_eq!(bounds.maxx(), out.total_bounds(None).unwrap().maxx());
+        assert_relative_eq!(bounds.miny(), 111325.1428663851);
+    }
 }
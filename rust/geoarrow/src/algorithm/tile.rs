@@ -0,0 +1,239 @@
+//! Web mercator tiles, as used by slippy maps and vector tile servers.
+//!
+//! Tiles are given by their zoom level `z` and their column `x` and row `y`, which count from
+//! the north-west. Longitudes and latitudes are in degrees.
+
+use std::f64::consts::PI;
+
+use crate::error::{GeoArrowError, Result};
+
+/// The maximum zoom level, at which tile columns and rows still fit in 32 bits.
+pub const MAX_ZOOM: u8 = 30;
+
+/// The latitude bounds of the web mercator projection, in degrees.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// The number of pixels along each side of a tile.
+pub(crate) const TILE_SIZE: f64 = 256.;
+
+/// The position of a longitude across the web mercator world, from 0 at -180° to 1 at 180°.
+pub(crate) fn mercator_x(lon: f64) -> f64 {
+    (lon + 180.) / 360.
+}
+
+/// The position of a latitude down the web mercator world, from 0 at the north edge to 1 at the
+/// south edge. Latitudes beyond [`MAX_LATITUDE`] are clamped.
+pub(crate) fn mercator_y(lat: f64) -> f64 {
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE).to_radians();
+    (1. - (lat.tan() + 1. / lat.cos()).ln() / PI) / 2.
+}
+
+fn longitude(x: f64) -> f64 {
+    x * 360. - 180.
+}
+
+fn latitude(y: f64) -> f64 {
+    (PI * (1. - 2. * y)).sinh().atan().to_degrees()
+}
+
+fn check_zoom(z: u8) -> Result<()> {
+    if z > MAX_ZOOM {
+        return Err(GeoArrowError::General(format!(
+            "Tile zoom level must be at most {MAX_ZOOM}, got {z}"
+        )));
+    }
+    Ok(())
+}
+
+/// The bounds of the tile `z/x/y` in longitude and latitude, expanded on every side by `buffer`
+/// pixels of a 256 pixel tile.
+pub(crate) fn buffered_tile_bounds(z: u8, x: u32, y: u32, buffer: f64) -> geo::Rect {
+    let n = 2f64.powi(z.into());
+    let buffer = buffer / TILE_SIZE;
+    let min_x = (x as f64 - buffer) / n;
+    let max_x = (x as f64 + 1. + buffer) / n;
+    let min_y = ((y as f64 - buffer) / n).max(0.);
+    let max_y = ((y as f64 + 1. + buffer) / n).min(1.);
+    geo::Rect::new(
+        geo::coord! { x: longitude(min_x), y: latitude(max_y) },
+        geo::coord! { x: longitude(max_x), y: latitude(min_y) },
+    )
+}
+
+/// The bounds of the tile `z/x/y` in longitude and latitude.
+pub fn tile_bounds(z: u8, x: u32, y: u32) -> geo::Rect {
+    buffered_tile_bounds(z, x, y, 0.)
+}
+
+/// A web mercator tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// The zoom level.
+    pub z: u8,
+    /// The column, counted from the west.
+    pub x: u32,
+    /// The row, counted from the north.
+    pub y: u32,
+}
+
+impl Tile {
+    /// Create a new tile.
+    ///
+    /// Returns an error if the zoom level is above [`MAX_ZOOM`], or the column or row is out of
+    /// range at that zoom level.
+    pub fn try_new(z: u8, x: u32, y: u32) -> Result<Self> {
+        check_zoom(z)?;
+        let n = 1u64 << z;
+        if u64::from(x) >= n || u64::from(y) >= n {
+            return Err(GeoArrowError::General(format!(
+                "Tile {z}/{x}/{y} is out of range; x and y must be between 0 and {} at zoom {z}",
+                n - 1
+            )));
+        }
+        Ok(Self { z, x, y })
+    }
+
+    /// The tile at zoom level `z` containing a longitude and latitude.
+    ///
+    /// Coordinates outside of the web mercator bounds are clamped to the edge tiles. Returns an
+    /// error if the zoom level is above [`MAX_ZOOM`].
+    pub fn containing(lon: f64, lat: f64, z: u8) -> Result<Self> {
+        check_zoom(z)?;
+        let n = 2f64.powi(z.into());
+        let max = (1u64 << z) - 1;
+        let index = |position: f64| ((position * n).floor().max(0.) as u64).min(max) as u32;
+        Ok(Self {
+            z,
+            x: index(mercator_x(lon)),
+            y: index(mercator_y(lat)),
+        })
+    }
+
+    /// The smallest tile, at zoom level `max_zoom` or below, that contains the whole of `rect`.
+    ///
+    /// Rectangles across tile edges are in a tile at a lower zoom level, down to the single tile
+    /// at zoom level 0. Returns an error if the zoom level is above [`MAX_ZOOM`].
+    pub fn covering(rect: &geo::Rect, max_zoom: u8) -> Result<Self> {
+        // The north-west and south-east corners
+        let min = Self::containing(rect.min().x, rect.max().y, max_zoom)?;
+        let max = Self::containing(rect.max().x, rect.min().y, max_zoom)?;
+        let mut tile = min;
+        while tile.x != max.x >> (max_zoom - tile.z) || tile.y != max.y >> (max_zoom - tile.z) {
+            tile = tile.parent().expect("zoom level 0 covers the whole world");
+        }
+        Ok(tile)
+    }
+
+    /// Parse a quadkey, which has one digit per zoom level.
+    pub fn from_quadkey(quadkey: &str) -> Result<Self> {
+        let invalid = || GeoArrowError::General(format!("Invalid quadkey: {quadkey}"));
+        let z = u8::try_from(quadkey.len()).map_err(|_| invalid())?;
+        check_zoom(z)?;
+        let (mut x, mut y) = (0, 0);
+        for (i, digit) in quadkey.chars().enumerate() {
+            let mask = 1 << (z as usize - i - 1);
+            match digit {
+                '0' => {}
+                '1' => x |= mask,
+                '2' => y |= mask,
+                '3' => {
+                    x |= mask;
+                    y |= mask;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        Ok(Self { z, x, y })
+    }
+
+    /// The quadkey of the tile, with one digit per zoom level. The quadkeys of the tiles within a
+    /// tile share its quadkey as a prefix.
+    pub fn quadkey(&self) -> String {
+        (1..=self.z)
+            .rev()
+            .map(|i| {
+                let mask = 1 << (i - 1);
+                let digit = u8::from(self.x & mask != 0) + 2 * u8::from(self.y & mask != 0);
+                char::from(b'0' + digit)
+            })
+            .collect()
+    }
+
+    /// The tile at the zoom level below containing this tile, or `None` at zoom level 0.
+    pub fn parent(&self) -> Option<Self> {
+        if self.z == 0 {
+            return None;
+        }
+        Some(Self {
+            z: self.z - 1,
+            x: self.x / 2,
+            y: self.y / 2,
+        })
+    }
+
+    /// The bounds of the tile in longitude and latitude.
+    pub fn bounds(&self) -> geo::Rect {
+        tile_bounds(self.z, self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tile_math() {
+        let tile = Tile::containing(-122.4194, 37.7749, 10).unwrap();
+        assert_eq!(
+            tile,
+            Tile {
+                z: 10,
+                x: 163,
+                y: 395
+            }
+        );
+        assert_eq!(Tile::from_quadkey(&tile.quadkey()).unwrap(), tile);
+
+        assert_eq!(
+            Tile::containing(0., 90., 1).unwrap(),
+            Tile { z: 1, x: 1, y: 0 }
+        );
+        assert_eq!(
+            Tile::containing(180., -90., 1).unwrap(),
+            Tile { z: 1, x: 1, y: 1 }
+        );
+        assert_eq!(Tile { z: 0, x: 0, y: 0 }.quadkey(), "");
+        assert!(Tile::from_quadkey("0124").is_err());
+        assert!(Tile::try_new(2, 4, 0).is_err());
+        assert!(Tile::containing(0., 0., MAX_ZOOM + 1).is_err());
+    }
+
+    #[test]
+    fn bounds() {
+        let world = tile_bounds(0, 0, 0);
+        assert_eq!(world.min().x, -180.);
+        assert_eq!(world.max().x, 180.);
+        assert!((world.max().y - MAX_LATITUDE).abs() < 1e-9);
+
+        let north_east = Tile::try_new(1, 1, 0).unwrap().bounds();
+        assert_eq!(north_east.min().x, 0.);
+        assert_eq!(north_east.min().y, 0.);
+    }
+
+    #[test]
+    fn covering() {
+        // Within the north-east tile at zoom 1, but across the tiles at zoom 2
+        let rect = geo::Rect::new(
+            geo::coord! { x: 80., y: 10. },
+            geo::coord! { x: 100., y: 20. },
+        );
+        assert_eq!(Tile::covering(&rect, 5).unwrap(), Tile { z: 1, x: 1, y: 0 });
+
+        // Across the prime meridian
+        let rect = geo::Rect::new(geo::coord! { x: -1., y: 1. }, geo::coord! { x: 1., y: 2. });
+        assert_eq!(Tile::covering(&rect, 5).unwrap(), Tile { z: 0, x: 0, y: 0 });
+
+        let point = geo::Rect::new(geo::coord! { x: 1., y: 1. }, geo::coord! { x: 1., y: 1. });
+        assert_eq!(Tile::covering(&point, 5).unwrap().z, 5);
+    }
+}
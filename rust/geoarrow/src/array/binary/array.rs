@@ -97,6 +97,40 @@ impl<O: OffsetSizeTrait> WKBArray<O> {
         arr.metadata = metadata;
         arr
     }
+
+    /// Parse every geometry up front, returning an infallible iterator over the parsed geometries
+    /// that considers validity.
+    ///
+    /// Unlike [`iter_geo`][ArrayAccessor::iter_geo], which panics on invalid WKB, this returns an
+    /// error for the first invalid geometry before iteration starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::WKBArray;
+    /// use geo_traits::GeometryTrait;
+    ///
+    /// let points = vec![geo::point!(x: 1., y: 2.)];
+    /// let array: WKBArray<i32> = points.as_slice().try_into().unwrap();
+    /// for geom in array.try_iter_geometry().unwrap().flatten() {
+    ///     assert_eq!(geom.dim(), geo_traits::Dimensions::Xy);
+    /// }
+    /// ```
+    pub fn try_iter_geometry(
+        &self,
+    ) -> Result<impl ExactSizeIterator<Item = Option<impl GeometryTrait<T = f64> + '_>> + '_> {
+        let geometries = (0..self.len())
+            .map(|i| {
+                if self.is_null(i) {
+                    return Ok(None);
+                }
+                wkb::reader::read_wkb(self.array.value(i))
+                    .map(Some)
+                    .map_err(|err| GeoArrowError::General(format!("Invalid WKB at row {i}: {err}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(geometries.into_iter())
+    }
 }
 
 impl<O: OffsetSizeTrait> ArrayBase for WKBArray<O> {
@@ -301,4 +335,17 @@ mod test {
         // We just need to ensure that the iterator runs
         wkb_arr.iter_geo().for_each(|_x| ());
     }
+
+    #[test]
+    fn try_iter_geometry() {
+        let wkb_arr: WKBArray<i32> = vec![Some(geo::point!(x: 1., y: 2.)), None]
+            .try_into()
+            .unwrap();
+        let geoms = wkb_arr.try_iter_geometry().unwrap();
+        assert_eq!(geoms.len(), 2);
+        assert_eq!(geoms.flatten().count(), 1);
+
+        let invalid = WKBArray::<i32>::from(BinaryArray::from_vec(vec![b"not wkb"]));
+        assert!(invalid.try_iter_geometry().is_err());
+    }
 }
@@ -1,14 +1,12 @@
 use std::sync::Arc;
 
-use crate::array::{
-    CoordType, InterleavedCoordBuffer, InterleavedCoordBufferBuilder, SeparatedCoordBuffer,
-    SeparatedCoordBufferBuilder,
-};
+use crate::array::{CoordType, InterleavedCoordBuffer, SeparatedCoordBuffer};
 use crate::datatypes::Dimension;
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::Coord;
 use crate::trait_::IntoArrow;
 use arrow_array::{Array, ArrayRef, FixedSizeListArray, StructArray};
+use arrow_buffer::ScalarBuffer;
 use arrow_schema::DataType;
 
 /// An Arrow representation of an array of coordinates.
@@ -40,6 +38,40 @@ impl CoordBuffer {
         }
     }
 
+    /// Copy the coordinates in the given range into new buffers that don't reference the
+    /// original allocations.
+    pub(crate) fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        match self {
+            CoordBuffer::Interleaved(c) => CoordBuffer::Interleaved(c.owned_slice(offset, length)),
+            CoordBuffer::Separated(c) => CoordBuffer::Separated(c.owned_slice(offset, length)),
+        }
+    }
+
+    /// Convert the coordinates to the dimension `dim`, filling missing Z and M values with `z`
+    /// and `m`.
+    pub(crate) fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self {
+        match self {
+            CoordBuffer::Interleaved(c) => CoordBuffer::Interleaved(c.force_dim(dim, z, m)),
+            CoordBuffer::Separated(c) => CoordBuffer::Separated(c.force_dim(dim, z, m)),
+        }
+    }
+
+    /// The Z values of coordinates with a Z dimension.
+    pub(crate) fn z_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        match self {
+            CoordBuffer::Interleaved(c) => Box::new(c.z_values()),
+            CoordBuffer::Separated(c) => Box::new(c.z_values()),
+        }
+    }
+
+    /// The M values of coordinates with an M dimension.
+    pub(crate) fn m_values(&self) -> Box<dyn Iterator<Item = f64> + '_> {
+        match self {
+            CoordBuffer::Interleaved(c) => Box::new(c.m_values()),
+            CoordBuffer::Separated(c) => Box::new(c.m_values()),
+        }
+    }
+
     /// The underlying coordinate type
     pub fn coord_type(&self) -> CoordType {
         match self {
@@ -96,28 +128,36 @@ impl CoordBuffer {
 
     /// Convert this coordinate array into the given [CoordType]
     ///
-    /// This is a no-op if the coord_type matches the existing coord type. Otherwise a full clone
-    /// of the underlying coordinate buffers will be performed.
+    /// This is a no-op if the coord_type matches the existing coord type. Otherwise the
+    /// coordinates are copied directly between the interleaved and separated buffer layouts.
     pub fn into_coord_type(self, coord_type: CoordType) -> Self {
         let dim = self.dim();
+        let size = dim.size();
         match (self, coord_type) {
             (CoordBuffer::Interleaved(cb), CoordType::Interleaved) => CoordBuffer::Interleaved(cb),
             (CoordBuffer::Interleaved(cb), CoordType::Separated) => {
-                let mut new_buffer = SeparatedCoordBufferBuilder::with_capacity(cb.len(), dim);
-                for i in 0..cb.len() {
-                    let coord = cb.value(i);
-                    new_buffer.push_coord(&coord);
+                let mut buffers: [Vec<f64>; 4] = Default::default();
+                buffers
+                    .iter_mut()
+                    .take(size)
+                    .for_each(|buffer| buffer.reserve_exact(cb.len()));
+                for coord in cb.coords.chunks_exact(size) {
+                    for (buffer, value) in buffers.iter_mut().zip(coord) {
+                        buffer.push(*value);
+                    }
                 }
-                CoordBuffer::Separated(new_buffer.into())
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(
+                    buffers.map(ScalarBuffer::from),
+                    dim,
+                ))
             }
             (CoordBuffer::Separated(cb), CoordType::Separated) => CoordBuffer::Separated(cb),
             (CoordBuffer::Separated(cb), CoordType::Interleaved) => {
-                let mut new_buffer = InterleavedCoordBufferBuilder::with_capacity(cb.len(), dim);
+                let mut coords = Vec::with_capacity(cb.len() * size);
                 for i in 0..cb.len() {
-                    let coord = cb.value(i);
-                    new_buffer.push_coord(&coord);
+                    coords.extend(cb.buffers[..size].iter().map(|buffer| buffer[i]));
                 }
-                CoordBuffer::Interleaved(new_buffer.into())
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(coords.into(), dim))
             }
         }
     }
@@ -261,4 +301,21 @@ mod test {
         assert_eq!(buf1, buf2);
         Ok(())
     }
+
+    #[test]
+    fn test_into_coord_type_round_trip() -> Result<()> {
+        let coords = vec![0., 3., 6., 1., 4., 7., 2., 5., 8.];
+        let interleaved =
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::from_vec(coords, Dimension::XYZ)?)
+                .slice(1, 2);
+
+        let separated = interleaved.clone().into_coord_type(CoordType::Separated);
+        assert_eq!(separated.coord_type(), CoordType::Separated);
+        assert_eq!(separated, interleaved);
+
+        let round_trip = separated.into_coord_type(CoordType::Interleaved);
+        assert_eq!(round_trip.coord_type(), CoordType::Interleaved);
+        assert_eq!(round_trip, interleaved);
+        Ok(())
+    }
 }
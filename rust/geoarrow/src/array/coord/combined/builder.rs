@@ -82,6 +82,14 @@ impl CoordBufferBuilder {
         self.len() == 0
     }
 
+    /// The dimension of coordinates in this buffer
+    pub fn dim(&self) -> Dimension {
+        match self {
+            CoordBufferBuilder::Interleaved(cb) => cb.dim(),
+            CoordBufferBuilder::Separated(cb) => cb.dim(),
+        }
+    }
+
     /// The underlying coordinate type
     pub fn coord_type(&self) -> CoordType {
         match self {
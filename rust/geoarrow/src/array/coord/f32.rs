@@ -0,0 +1,151 @@
+//! Lossy conversion of coordinates to single precision, for rendering.
+
+use std::sync::Arc;
+
+use arrow_array::{FixedSizeListArray, Float32Array};
+use arrow_buffer::ScalarBuffer;
+use arrow_schema::{DataType, Field};
+use geo_traits::CoordTrait;
+
+use crate::array::CoordBuffer;
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+
+/// Interleaved single-precision coordinates, relative to an origin.
+///
+/// `f32` values only have 24 bits of precision: a longitude around 100 is stored with a precision
+/// of about 1e-5 degrees, or one meter. Subtracting an origin close to the coordinates, such as
+/// the center of their bounding box, keeps the values small and the rounding error low. Renderers
+/// such as deck.gl add the origin back with a model matrix or a coordinate origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct F32CoordBuffer {
+    /// The origin subtracted from every coordinate, with one value per dimension.
+    pub origin: Vec<f64>,
+
+    /// The coordinates as `xyxyxy` or `xyzxyzxyz`.
+    pub values: ScalarBuffer<f32>,
+
+    /// The dimension of the coordinates.
+    pub dim: Dimension,
+}
+
+impl F32CoordBuffer {
+    /// The number of coordinates.
+    pub fn len(&self) -> usize {
+        self.values.len() / self.dim.size()
+    }
+
+    /// Whether there are no coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Convert to a `FixedSizeList<Float32>` array, the layout of interleaved GeoArrow
+    /// coordinates.
+    ///
+    /// The origin is not part of the array.
+    pub fn into_arrow(self) -> FixedSizeListArray {
+        FixedSizeListArray::new(
+            Arc::new(Field::new(self.dim.to_string(), DataType::Float32, false)),
+            self.dim.size() as i32,
+            Arc::new(Float32Array::new(self.values, None)),
+            None,
+        )
+    }
+}
+
+impl CoordBuffer {
+    /// The center of the bounding box of the coordinates, with one value per dimension.
+    ///
+    /// NaN coordinates, used for empty points, are ignored. Returns zeroes if there are no finite
+    /// coordinates.
+    pub fn center(&self) -> Vec<f64> {
+        let size = self.dim().size();
+        let mut min = vec![f64::INFINITY; size];
+        let mut max = vec![f64::NEG_INFINITY; size];
+        for i in 0..self.len() {
+            let coord = self.value(i);
+            for n in 0..size {
+                let value = coord.nth_or_panic(n);
+                if value.is_finite() {
+                    min[n] = min[n].min(value);
+                    max[n] = max[n].max(value);
+                }
+            }
+        }
+        min.iter()
+            .zip(max.iter())
+            .map(|(min, max)| if min <= max { (min + max) / 2. } else { 0. })
+            .collect()
+    }
+
+    /// Convert to single-precision coordinates relative to the center of their bounding box.
+    ///
+    /// This is lossy. See [`F32CoordBuffer`].
+    pub fn to_f32_coords(&self) -> F32CoordBuffer {
+        self.to_f32_coords_with_origin(&self.center())
+            .expect("the center has one value per dimension")
+    }
+
+    /// Convert to single-precision coordinates relative to `origin`, which has one value per
+    /// dimension.
+    ///
+    /// Use the same origin for all arrays drawn together, such as the chunks of a chunked array.
+    /// This is lossy. See [`F32CoordBuffer`].
+    pub fn to_f32_coords_with_origin(&self, origin: &[f64]) -> Result<F32CoordBuffer> {
+        let size = self.dim().size();
+        if origin.len() != size {
+            return Err(GeoArrowError::General(format!(
+                "Expected an origin with {size} values, got {}",
+                origin.len()
+            )));
+        }
+
+        let mut values = Vec::with_capacity(self.len() * size);
+        for i in 0..self.len() {
+            let coord = self.value(i);
+            values.extend(
+                origin
+                    .iter()
+                    .enumerate()
+                    .map(|(n, origin)| (coord.nth_or_panic(n) - origin) as f32),
+            );
+        }
+        Ok(F32CoordBuffer {
+            origin: origin.to_vec(),
+            values: values.into(),
+            dim: self.dim(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+
+    use super::*;
+    use crate::array::{CoordType, PointArray};
+
+    #[test]
+    fn recentered() {
+        let points = vec![
+            geo::point!(x: 100_000.25, y: 50.),
+            geo::point!(x: 100_001.75, y: 52.),
+        ];
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let array: PointArray = (points.as_slice(), Dimension::XY).into();
+            let coords = array.into_coord_type(coord_type).coords().clone();
+
+            let f32_coords = coords.to_f32_coords();
+            assert_eq!(f32_coords.origin, vec![100_001., 51.]);
+            assert_eq!(f32_coords.values.as_ref(), &[-0.75, -1., 0.75, 1.]);
+
+            let arrow = f32_coords.into_arrow();
+            assert_eq!(arrow.len(), 2);
+            assert_eq!(arrow.value_length(), 2);
+        }
+
+        let array: PointArray = (points.as_slice(), Dimension::XY).into();
+        assert!(array.coords().to_f32_coords_with_origin(&[0.]).is_err());
+    }
+}
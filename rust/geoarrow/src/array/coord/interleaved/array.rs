@@ -48,9 +48,24 @@ impl InterleavedCoordBuffer {
         Ok(Self { coords, dim })
     }
 
-    // Currently used by a test
-    #[allow(dead_code)]
-    pub(crate) fn from_vec(coords: Vec<f64>, dim: Dimension) -> Result<Self> {
+    /// Construct a new InterleavedCoordBuffer without validating that the buffer length is a
+    /// multiple of the dimension size.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `coords.len()` is a multiple of `dim.size()`.
+    pub unsafe fn new_unchecked(coords: ScalarBuffer<f64>, dim: Dimension) -> Self {
+        Self { coords, dim }
+    }
+
+    /// Construct a new InterleavedCoordBuffer from a `Vec<f64>`.
+    ///
+    /// This takes ownership of the `Vec`'s allocation and does not copy any coordinates.
+    ///
+    /// # Errors
+    ///
+    /// - if the length of the vector is not a multiple of the dimension size
+    pub fn from_vec(coords: Vec<f64>, dim: Dimension) -> Result<Self> {
         Self::try_new(coords.into(), dim)
     }
 
@@ -77,10 +92,7 @@ impl InterleavedCoordBuffer {
     }
 
     pub(crate) fn values_field(&self) -> Field {
-        match self.dim {
-            Dimension::XY => Field::new("xy", DataType::Float64, false),
-            Dimension::XYZ => Field::new("xyz", DataType::Float64, false),
-        }
+        Field::new(self.dim.to_string(), DataType::Float64, false)
     }
 
     pub(crate) fn slice(&self, offset: usize, length: usize) -> Self {
@@ -96,6 +108,61 @@ impl InterleavedCoordBuffer {
         }
     }
 
+    /// Copy the coordinates in the given range into a new buffer that doesn't reference the
+    /// original allocation.
+    pub(crate) fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        let sliced = self.slice(offset, length);
+        Self {
+            coords: sliced.coords.to_vec().into(),
+            dim: self.dim,
+        }
+    }
+
+    /// Convert the coordinates to the dimension `dim`.
+    ///
+    /// Z and M values that the coordinates already have are kept, missing ones are filled with
+    /// `z` and `m`, and ones that `dim` doesn't have are dropped. Empty coordinates, where both x
+    /// and y are NaN, are filled with NaN so that they stay empty.
+    pub(crate) fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self {
+        if self.dim == dim {
+            return self.clone();
+        }
+
+        let mut coords = Vec::with_capacity(self.len() * dim.size());
+        for coord in self.coords.chunks_exact(self.dim.size()) {
+            let empty = coord[0].is_nan() && coord[1].is_nan();
+            let fill = |index: Option<usize>, value: f64| match index {
+                Some(index) => coord[index],
+                None if empty => f64::NAN,
+                None => value,
+            };
+            coords.extend_from_slice(&coord[..2]);
+            if dim.has_z() {
+                coords.push(fill(self.dim.z_index(), z));
+            }
+            if dim.has_m() {
+                coords.push(fill(self.dim.m_index(), m));
+            }
+        }
+        Self::new(coords.into(), dim)
+    }
+
+    /// The Z values of coordinates with a Z dimension.
+    pub(crate) fn z_values(&self) -> impl Iterator<Item = f64> + '_ {
+        let z_index = self.dim.z_index();
+        self.coords
+            .chunks_exact(self.dim.size())
+            .filter_map(move |coord| z_index.map(|index| coord[index]))
+    }
+
+    /// The M values of coordinates with an M dimension.
+    pub(crate) fn m_values(&self) -> impl Iterator<Item = f64> + '_ {
+        let m_index = self.dim.m_index();
+        self.coords
+            .chunks_exact(self.dim.size())
+            .filter_map(move |coord| m_index.map(|index| coord[index]))
+    }
+
     pub(crate) fn storage_type(&self) -> DataType {
         coord_type_to_data_type(CoordType::Interleaved, self.dim)
     }
@@ -185,4 +252,35 @@ mod test {
 
         assert_eq!(buf1, buf2);
     }
+
+    #[test]
+    fn test_from_vec_zero_copy() {
+        let coords = vec![0., 3., 1., 4., 2., 5.];
+        let ptr = coords.as_ptr();
+        let buf = InterleavedCoordBuffer::from_vec(coords, Dimension::XY).unwrap();
+        assert_eq!(buf.len(), 3);
+        assert_eq!(buf.coords().as_ptr(), ptr);
+
+        let coords = vec![0., 3., 1.];
+        assert!(InterleavedCoordBuffer::from_vec(coords, Dimension::XY).is_err());
+    }
+
+    #[test]
+    fn test_force_dim() {
+        let coords = vec![1., 2., 3., f64::NAN, f64::NAN, f64::NAN];
+        let xym = InterleavedCoordBuffer::from_vec(coords, Dimension::XYM).unwrap();
+        assert_eq!(xym.values_field().name(), "xym");
+        assert_eq!(xym.z_values().count(), 0);
+
+        // The M values are kept, and the empty coordinate stays empty
+        let xyzm = xym.force_dim(Dimension::XYZM, 0., 0.);
+        assert_eq!(&xyzm.coords()[..4], &[1., 2., 0., 3.]);
+        assert!(xyzm.coords()[4..].iter().all(|value| value.is_nan()));
+
+        assert_eq!(
+            &xyzm.force_dim(Dimension::XYZ, 1., f64::NAN).coords()[..3],
+            &[1., 2., 0.]
+        );
+        assert_eq!(&xyzm.force_2d().coords()[..2], &[1., 2.]);
+    }
 }
@@ -77,6 +77,11 @@ impl InterleavedCoordBufferBuilder {
         self.len() == 0
     }
 
+    /// The dimension of coordinates in this builder
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
     /// Push a new coord onto the end of this coordinate buffer
     ///
     /// ## Panics
@@ -92,18 +97,19 @@ impl InterleavedCoordBufferBuilder {
     ///
     /// - If the added coordinate does not have the same dimension as the coordinate buffer.
     pub fn try_push_coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
-        // TODO: should check xyz/zym
-        if coord.dim().size() != self.dim.size() {
-            return Err(GeoArrowError::General(
-                "coord dimension must match coord buffer dimension.".into(),
-            ));
+        if !self.dim.accepts(coord.dim()) {
+            return Err(GeoArrowError::General(format!(
+                "coord dimension {:?} must match coord buffer dimension {:?}.",
+                coord.dim(),
+                self.dim
+            )));
         }
 
         self.coords.push(coord.x());
         self.coords.push(coord.y());
-        if let Some(z) = coord.nth(2) {
-            self.coords.push(z);
-        };
+        for n in 2..self.dim.size() {
+            self.coords.push(coord.nth_or_panic(n));
+        }
         Ok(())
     }
 
@@ -5,10 +5,18 @@
 //! _separated_, where they're represented with a `StructArray`.
 
 mod combined;
+mod f32;
 mod interleaved;
 mod separated;
+mod simd;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::GeoArrowError;
 
 pub use combined::{CoordBuffer, CoordBufferBuilder};
+pub use f32::F32CoordBuffer;
 pub use interleaved::{InterleavedCoordBuffer, InterleavedCoordBufferBuilder};
 pub use separated::{SeparatedCoordBuffer, SeparatedCoordBufferBuilder};
 
@@ -26,3 +34,26 @@ pub enum CoordType {
     /// Separated coordinates.
     Separated,
 }
+
+impl Display for CoordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoordType::Interleaved => write!(f, "interleaved"),
+            CoordType::Separated => write!(f, "separated"),
+        }
+    }
+}
+
+impl FromStr for CoordType {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "interleaved" => Ok(CoordType::Interleaved),
+            "separated" => Ok(CoordType::Separated),
+            other => Err(GeoArrowError::General(format!(
+                "Unknown coordinate type '{other}', expected 'interleaved' or 'separated'"
+            ))),
+        }
+    }
+}
@@ -26,12 +26,9 @@ pub struct SeparatedCoordBuffer {
 }
 
 fn check(buffers: &[ScalarBuffer<f64>; 4], dim: Dimension) -> Result<()> {
-    let all_same_length = match dim {
-        Dimension::XY => buffers[0].len() == buffers[1].len(),
-        Dimension::XYZ => {
-            buffers[0].len() == buffers[1].len() && buffers[1].len() == buffers[2].len()
-        }
-    };
+    let all_same_length = buffers[1..dim.size()]
+        .iter()
+        .all(|buffer| buffer.len() == buffers[0].len());
 
     if !all_same_length {
         return Err(GeoArrowError::General(
@@ -62,6 +59,38 @@ impl SeparatedCoordBuffer {
         Ok(Self { buffers, dim })
     }
 
+    /// Construct a new SeparatedCoordBuffer without validating buffer lengths.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the first `dim.size()` buffers all have the same length.
+    pub unsafe fn new_unchecked(buffers: [ScalarBuffer<f64>; 4], dim: Dimension) -> Self {
+        Self { buffers, dim }
+    }
+
+    /// Construct a new SeparatedCoordBuffer from one `Vec<f64>` per dimension.
+    ///
+    /// This takes ownership of each `Vec`'s allocation and does not copy any coordinates.
+    ///
+    /// # Errors
+    ///
+    /// - if the number of vectors does not match the dimension size
+    /// - if the vectors have different lengths
+    pub fn from_vecs(buffers: Vec<Vec<f64>>, dim: Dimension) -> Result<Self> {
+        if buffers.len() != dim.size() {
+            return Err(GeoArrowError::General(format!(
+                "Expected {} coordinate buffers for dimension {:?}, got {}",
+                dim.size(),
+                dim,
+                buffers.len()
+            )));
+        }
+
+        let mut buffers = buffers.into_iter();
+        let buffers = core::array::from_fn(|_| buffers.next().unwrap_or_default().into());
+        Self::try_new(buffers, dim)
+    }
+
     /// Access the underlying coordinate buffers.
     ///
     /// Note that not all four buffers may be valid. Only so many buffers have defined meaning as
@@ -75,18 +104,7 @@ impl SeparatedCoordBuffer {
     ///
     /// In comparison to raw_buffers, all of the returned buffers are valid.
     pub fn buffers(&self) -> Vec<ScalarBuffer<f64>> {
-        match self.dim {
-            Dimension::XY => {
-                vec![self.buffers[0].clone(), self.buffers[1].clone()]
-            }
-            Dimension::XYZ => {
-                vec![
-                    self.buffers[0].clone(),
-                    self.buffers[1].clone(),
-                    self.buffers[2].clone(),
-                ]
-            }
-        }
+        self.buffers[..self.dim.size()].to_vec()
     }
 
     /// The dimension of this coordinate buffer
@@ -95,39 +113,18 @@ impl SeparatedCoordBuffer {
     }
 
     pub(crate) fn values_array(&self) -> Vec<ArrayRef> {
-        match self.dim {
-            Dimension::XY => {
-                vec![
-                    Arc::new(Float64Array::new(self.buffers[0].clone(), None)),
-                    Arc::new(Float64Array::new(self.buffers[1].clone(), None)),
-                ]
-            }
-            Dimension::XYZ => {
-                vec![
-                    Arc::new(Float64Array::new(self.buffers[0].clone(), None)),
-                    Arc::new(Float64Array::new(self.buffers[1].clone(), None)),
-                    Arc::new(Float64Array::new(self.buffers[2].clone(), None)),
-                ]
-            }
-        }
+        self.buffers[..self.dim.size()]
+            .iter()
+            .map(|buffer| Arc::new(Float64Array::new(buffer.clone(), None)) as ArrayRef)
+            .collect()
     }
 
     pub(crate) fn values_field(&self) -> Vec<Field> {
-        match self.dim {
-            Dimension::XY => {
-                vec![
-                    Field::new("x", DataType::Float64, false),
-                    Field::new("y", DataType::Float64, false),
-                ]
-            }
-            Dimension::XYZ => {
-                vec![
-                    Field::new("x", DataType::Float64, false),
-                    Field::new("y", DataType::Float64, false),
-                    Field::new("z", DataType::Float64, false),
-                ]
-            }
-        }
+        self.dim
+            .names()
+            .iter()
+            .map(|name| Field::new(*name, DataType::Float64, false))
+            .collect()
     }
 
     pub(crate) fn slice(&self, offset: usize, length: usize) -> Self {
@@ -148,6 +145,83 @@ impl SeparatedCoordBuffer {
         }
     }
 
+    /// Copy the coordinates in the given range into new buffers that don't reference the
+    /// original allocations.
+    pub(crate) fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        let sliced = self.slice(offset, length);
+        let buffers = core::array::from_fn(|i| {
+            if i < self.dim.size() {
+                sliced.buffers[i].to_vec().into()
+            } else {
+                ScalarBuffer::from(vec![])
+            }
+        });
+        Self {
+            buffers,
+            dim: self.dim,
+        }
+    }
+
+    /// Convert the coordinates to the dimension `dim`.
+    ///
+    /// Z and M values that the coordinates already have are kept, missing ones are filled with
+    /// `z` and `m`, and ones that `dim` doesn't have are dropped. Empty coordinates, where both x
+    /// and y are NaN, are filled with NaN so that they stay empty.
+    pub(crate) fn force_dim(&self, dim: Dimension, z: f64, m: f64) -> Self {
+        if self.dim == dim {
+            return self.clone();
+        }
+
+        let fill = |index: Option<usize>, value: f64| -> ScalarBuffer<f64> {
+            match index {
+                Some(index) => self.buffers[index].clone(),
+                None => self.buffers[0]
+                    .iter()
+                    .zip(self.buffers[1].iter())
+                    .map(|(x, y)| {
+                        if x.is_nan() && y.is_nan() {
+                            f64::NAN
+                        } else {
+                            value
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .into(),
+            }
+        };
+        let mut buffers = [
+            self.buffers[0].clone(),
+            self.buffers[1].clone(),
+            ScalarBuffer::from(vec![]),
+            ScalarBuffer::from(vec![]),
+        ];
+        if let Some(index) = dim.z_index() {
+            buffers[index] = fill(self.dim.z_index(), z);
+        }
+        if let Some(index) = dim.m_index() {
+            buffers[index] = fill(self.dim.m_index(), m);
+        }
+        Self::new(buffers, dim)
+    }
+
+    /// The Z values of coordinates with a Z dimension.
+    pub(crate) fn z_values(&self) -> impl Iterator<Item = f64> + '_ {
+        let z_values: &[f64] = match self.dim.z_index() {
+            Some(index) => &self.buffers[index],
+            None => &[],
+        };
+        z_values.iter().copied()
+    }
+
+    /// The M values of coordinates with an M dimension.
+    pub(crate) fn m_values(&self) -> impl Iterator<Item = f64> + '_ {
+        let m_values: &[f64] = match self.dim.m_index() {
+            Some(index) => &self.buffers[index],
+            None => &[],
+        };
+        m_values.iter().copied()
+    }
+
     pub(crate) fn storage_type(&self) -> DataType {
         coord_type_to_data_type(CoordType::Separated, self.dim)
     }
@@ -253,4 +327,41 @@ mod test {
 
         assert_eq!(buf1, buf2);
     }
+
+    #[test]
+    fn test_from_vecs() {
+        let x = vec![0., 1., 2.];
+        let y = vec![3., 4., 5.];
+        let x_ptr = x.as_ptr();
+
+        let buf = SeparatedCoordBuffer::from_vecs(vec![x, y], Dimension::XY).unwrap();
+        assert_eq!(buf.len(), 3);
+        assert_eq!(buf.raw_buffers()[0].as_ptr(), x_ptr);
+
+        assert!(SeparatedCoordBuffer::from_vecs(vec![vec![0.]], Dimension::XY).is_err());
+        assert!(
+            SeparatedCoordBuffer::from_vecs(vec![vec![0.], vec![0., 1.]], Dimension::XY).is_err()
+        );
+    }
+
+    #[test]
+    fn test_force_dim() {
+        let buf =
+            SeparatedCoordBuffer::from_vecs(vec![vec![0.], vec![1.], vec![2.]], Dimension::XYM)
+                .unwrap();
+        let names = buf.values_field();
+        assert_eq!(
+            names
+                .iter()
+                .map(|field| field.name().as_str())
+                .collect::<Vec<_>>(),
+            ["x", "y", "m"]
+        );
+        assert_eq!(buf.z_values().count(), 0);
+
+        let xyzm = buf.force_dim(Dimension::XYZM, 5., 6.);
+        assert_eq!(xyzm.z_values().collect::<Vec<_>>(), [5.]);
+        assert_eq!(xyzm.buffers()[3], ScalarBuffer::from(vec![2.]));
+        assert_eq!(xyzm.force_2d().buffers().len(), 2);
+    }
 }
@@ -0,0 +1,326 @@
+//! Bounds and affine transforms computed directly over the values of coordinate buffers.
+//!
+//! These loops are the hot spots of many kernels. With the `simd` feature on x86_64, they use
+//! SSE2 intrinsics, which every x86_64 CPU supports, to process two values per instruction.
+//! Otherwise they are plain loops, which give the same results.
+//!
+//! NaN values, used for empty points, are ignored by the bounds.
+
+use arrow_buffer::ScalarBuffer;
+use geo::AffineTransform;
+
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::array::{CoordBuffer, InterleavedCoordBuffer, SeparatedCoordBuffer};
+use crate::datatypes::Dimension;
+
+/// The coefficients `[a, b, xoff, d, e, yoff]` of an affine transform, which maps `(x, y)` to
+/// `(a * x + b * y + xoff, d * x + e * y + yoff)`.
+type Coefficients = [f64; 6];
+
+fn coefficients(transform: &AffineTransform) -> Coefficients {
+    [
+        transform.a(),
+        transform.b(),
+        transform.xoff(),
+        transform.d(),
+        transform.e(),
+        transform.yoff(),
+    ]
+}
+
+/// The lane-wise minimum and maximum of consecutive pairs of values, followed by the minimum
+/// and maximum of the last value if the length is odd.
+///
+/// For interleaved XY values, the lanes are the bounds of the x and y values.
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn min_max_pairs(values: &[f64]) -> ([f64; 2], [f64; 2], Option<f64>) {
+    let mut min = [f64::INFINITY; 2];
+    let mut max = [f64::NEG_INFINITY; 2];
+    let chunks = values.chunks_exact(2);
+    let remainder = chunks.remainder().first().copied();
+    for chunk in chunks {
+        for ((value, min), max) in chunk.iter().zip(&mut min).zip(&mut max) {
+            if *value < *min {
+                *min = *value;
+            }
+            if *value > *max {
+                *max = *value;
+            }
+        }
+    }
+    (min, max, remainder)
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn affine_interleaved_xy(values: &[f64], c: &Coefficients, output: &mut Vec<f64>) {
+    for chunk in values.chunks_exact(2) {
+        let (x, y) = (chunk[0], chunk[1]);
+        output.push(c[0] * x + c[1] * y + c[2]);
+        output.push(c[3] * x + c[4] * y + c[5]);
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn affine_separated(x: &[f64], y: &[f64], c: &Coefficients) -> (Vec<f64>, Vec<f64>) {
+    x.iter()
+        .zip(y)
+        .map(|(x, y)| (c[0] * x + c[1] * y + c[2], c[3] * x + c[4] * y + c[5]))
+        .unzip()
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use sse2::{affine_interleaved_xy, affine_separated, min_max_pairs};
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    // SAFETY (for every `unsafe` block of this module): SSE2 is part of the x86_64 baseline, and
+    // all loads and stores are unaligned and within the bounds of the slices.
+    use std::arch::x86_64::*;
+
+    use super::Coefficients;
+
+    fn to_array(value: __m128d) -> [f64; 2] {
+        let mut output = [0.; 2];
+        unsafe { _mm_storeu_pd(output.as_mut_ptr(), value) };
+        output
+    }
+
+    pub(super) fn min_max_pairs(values: &[f64]) -> ([f64; 2], [f64; 2], Option<f64>) {
+        let chunks = values.chunks_exact(2);
+        let remainder = chunks.remainder().first().copied();
+        unsafe {
+            let mut min = _mm_set1_pd(f64::INFINITY);
+            let mut max = _mm_set1_pd(f64::NEG_INFINITY);
+            for chunk in chunks {
+                let value = _mm_loadu_pd(chunk.as_ptr());
+                // The second operand is returned when either is NaN, which ignores NaN values.
+                min = _mm_min_pd(value, min);
+                max = _mm_max_pd(value, max);
+            }
+            (to_array(min), to_array(max), remainder)
+        }
+    }
+
+    pub(super) fn affine_interleaved_xy(values: &[f64], c: &Coefficients, output: &mut Vec<f64>) {
+        unsafe {
+            let x_factors = _mm_setr_pd(c[0], c[3]);
+            let y_factors = _mm_setr_pd(c[1], c[4]);
+            let offsets = _mm_setr_pd(c[2], c[5]);
+            for chunk in values.chunks_exact(2) {
+                let xy = _mm_loadu_pd(chunk.as_ptr());
+                let x = _mm_unpacklo_pd(xy, xy);
+                let y = _mm_unpackhi_pd(xy, xy);
+                let transformed = _mm_add_pd(
+                    _mm_add_pd(_mm_mul_pd(x, x_factors), _mm_mul_pd(y, y_factors)),
+                    offsets,
+                );
+                output.extend_from_slice(&to_array(transformed));
+            }
+        }
+    }
+
+    pub(super) fn affine_separated(x: &[f64], y: &[f64], c: &Coefficients) -> (Vec<f64>, Vec<f64>) {
+        let mut output_x = Vec::with_capacity(x.len());
+        let mut output_y = Vec::with_capacity(y.len());
+        let x_chunks = x.chunks_exact(2);
+        let y_chunks = y.chunks_exact(2);
+        let remainder = x_chunks
+            .remainder()
+            .first()
+            .zip(y_chunks.remainder().first());
+        unsafe {
+            let [a, b, xoff, d, e, yoff] = c.map(|value| _mm_set1_pd(value));
+            for (x, y) in x_chunks.zip(y_chunks) {
+                let x = _mm_loadu_pd(x.as_ptr());
+                let y = _mm_loadu_pd(y.as_ptr());
+                let transformed_x =
+                    _mm_add_pd(_mm_add_pd(_mm_mul_pd(a, x), _mm_mul_pd(b, y)), xoff);
+                let transformed_y =
+                    _mm_add_pd(_mm_add_pd(_mm_mul_pd(d, x), _mm_mul_pd(e, y)), yoff);
+                output_x.extend_from_slice(&to_array(transformed_x));
+                output_y.extend_from_slice(&to_array(transformed_y));
+            }
+        }
+        if let Some((x, y)) = remainder {
+            output_x.push(c[0] * x + c[1] * y + c[2]);
+            output_y.push(c[3] * x + c[4] * y + c[5]);
+        }
+        (output_x, output_y)
+    }
+}
+
+/// The minimum and maximum of contiguous values.
+fn min_max(values: &[f64]) -> (f64, f64) {
+    let (min, max, remainder) = min_max_pairs(values);
+    let (mut min, mut max) = (min[0].min(min[1]), max[0].max(max[1]));
+    if let Some(value) = remainder {
+        min = min.min(value);
+        max = max.max(value);
+    }
+    (min, max)
+}
+
+/// The minimum and maximum of every `stride`-th value, starting at `offset`.
+fn min_max_strided(values: &[f64], offset: usize, stride: usize) -> (f64, f64) {
+    values
+        .iter()
+        .skip(offset)
+        .step_by(stride)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        })
+}
+
+impl CoordBuffer {
+    /// The bounds of all coordinates of this buffer.
+    ///
+    /// This includes the coordinates of null geometries, and of geometries outside of the slice of
+    /// an array that has nested offsets. Use [`TotalBounds`] for the bounds of an array.
+    /// M values are not part of the bounds.
+    ///
+    /// [`TotalBounds`]: crate::algorithm::native::TotalBounds
+    pub fn bounds(&self) -> BoundingRect {
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        match self {
+            CoordBuffer::Interleaved(coords) => {
+                let values: &[f64] = coords.coords();
+                match coords.dim() {
+                    Dimension::XY => {
+                        let (pair_min, pair_max, _) = min_max_pairs(values);
+                        min[..2].copy_from_slice(&pair_min);
+                        max[..2].copy_from_slice(&pair_max);
+                    }
+                    dim => {
+                        for n in 0..2 {
+                            (min[n], max[n]) = min_max_strided(values, n, dim.size());
+                        }
+                        if let Some(index) = dim.z_index() {
+                            (min[2], max[2]) = min_max_strided(values, index, dim.size());
+                        }
+                    }
+                }
+            }
+            CoordBuffer::Separated(coords) => {
+                let buffers = coords.raw_buffers();
+                for n in 0..2 {
+                    (min[n], max[n]) = min_max(&buffers[n]);
+                }
+                if let Some(index) = coords.dim().z_index() {
+                    (min[2], max[2]) = min_max(&buffers[index]);
+                }
+            }
+        }
+        BoundingRect::from_extent(min, max)
+    }
+
+    /// Apply `transform` to the x and y values of every coordinate. Z and M values are unchanged.
+    pub fn affine_transform(&self, transform: &AffineTransform) -> Self {
+        let c = coefficients(transform);
+        match self {
+            CoordBuffer::Interleaved(coords) => {
+                let values: &[f64] = coords.coords();
+                let mut output = Vec::with_capacity(values.len());
+                match coords.dim() {
+                    Dimension::XY => affine_interleaved_xy(values, &c, &mut output),
+                    dim => {
+                        for chunk in values.chunks_exact(dim.size()) {
+                            let (x, y) = (chunk[0], chunk[1]);
+                            output.push(c[0] * x + c[1] * y + c[2]);
+                            output.push(c[3] * x + c[4] * y + c[5]);
+                            output.extend_from_slice(&chunk[2..]);
+                        }
+                    }
+                }
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(output.into(), coords.dim()))
+            }
+            CoordBuffer::Separated(coords) => {
+                let [x, y, z, m] = coords.raw_buffers();
+                let (x, y) = affine_separated(x, y, &c);
+                let buffers: [ScalarBuffer<f64>; 4] = [x.into(), y.into(), z.clone(), m.clone()];
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(buffers, coords.dim()))
+            }
+        }
+    }
+
+    /// Translate every coordinate by `x_offset` and `y_offset`.
+    pub fn translate(&self, x_offset: f64, y_offset: f64) -> Self {
+        self.affine_transform(&AffineTransform::translate(x_offset, y_offset))
+    }
+
+    /// Scale the x and y values of every coordinate by `x_factor` and `y_factor`, around the
+    /// origin.
+    pub fn scale(&self, x_factor: f64, y_factor: f64) -> Self {
+        self.affine_transform(&AffineTransform::scale(
+            x_factor,
+            y_factor,
+            geo::Coord::zero(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo::AffineOps;
+
+    use super::*;
+    use crate::array::{CoordType, PointArray};
+
+    fn points() -> PointArray {
+        let points = vec![
+            geo::point!(x: 1., y: -2.),
+            geo::point!(x: f64::NAN, y: f64::NAN),
+            geo::point!(x: -3., y: 5.),
+            geo::point!(x: 4., y: 0.5),
+            geo::point!(x: 0., y: 7.),
+        ];
+        (points.as_slice(), Dimension::XY).into()
+    }
+
+    #[test]
+    fn bounds() {
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let coords = points().into_coord_type(coord_type).coords().clone();
+            let bounds = coords.bounds();
+            assert_eq!(
+                (bounds.minx(), bounds.miny(), bounds.maxx(), bounds.maxy()),
+                (-3., -2., 4., 7.)
+            );
+            assert_eq!(bounds.minz(), None);
+        }
+
+        let coords = InterleavedCoordBuffer::from_vec(
+            vec![0., 1., 2., -1., 5., f64::NAN, 3., 3., 3.],
+            Dimension::XYZ,
+        )
+        .unwrap();
+        let bounds = CoordBuffer::Interleaved(coords).bounds();
+        assert_eq!((bounds.minx(), bounds.maxx()), (-1., 3.));
+        assert_eq!((bounds.minz(), bounds.maxz()), (Some(2.), Some(3.)));
+    }
+
+    #[test]
+    fn affine_matches_geo() {
+        let transform = AffineTransform::rotate(30., geo::point!(x: 1., y: 1.)).scaled(
+            2.,
+            0.5,
+            geo::Coord::zero(),
+        );
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let coords = points().into_coord_type(coord_type).coords().clone();
+            let transformed = coords.affine_transform(&transform);
+            assert_eq!(transformed.coord_type(), coord_type);
+            for i in [0, 2, 3, 4] {
+                let expected = geo::Point::from(coords.value(i)).affine_transform(&transform);
+                assert_eq!(geo::Point::from(transformed.value(i)), expected);
+            }
+            assert!(geo::Coord::from(transformed.value(1)).x.is_nan());
+
+            let translated = coords.translate(1., 2.);
+            assert_eq!(
+                geo::Coord::from(translated.value(0)),
+                geo::coord! { x: 2., y: 0. }
+            );
+        }
+    }
+}
@@ -12,7 +12,7 @@ use crate::array::wkt::WKTArray;
 use crate::array::CoordType;
 use crate::array::*;
 use crate::datatypes::{NativeType, SerializedType};
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::trait_::{NativeArrayRef, SerializedArray, SerializedArrayRef};
 use crate::{ArrayBase, NativeArray};
 
@@ -48,6 +48,28 @@ impl NativeArrayDyn {
         Ok(Self(geo_arr))
     }
 
+    /// Construct a new [NativeArrayDyn] from a plain Arrow storage array without extension
+    /// metadata, such as the output of [`ArrayBase::to_storage_array`].
+    ///
+    /// The array must have the storage layout of `data_type`, up to field names and offset sizes.
+    /// The array metadata is reset to its default; use [`NativeArray::with_metadata`] to restore
+    /// it.
+    pub fn try_from_storage(array: &dyn Array, data_type: NativeType) -> Result<Self> {
+        let field = data_type.to_field("", true);
+        let geo_arr = Self::from_arrow_array(array, &field)?;
+        if geo_arr.data_type() != data_type {
+            return Err(GeoArrowError::IncorrectType(
+                format!(
+                    "Storage array of type {:?} does not match {}",
+                    array.data_type(),
+                    data_type
+                )
+                .into(),
+            ));
+        }
+        Ok(geo_arr)
+    }
+
     /// Access the underlying [`Arc<dyn NativeArray>`]
     pub fn inner(&self) -> &NativeArrayRef {
         &self.0
@@ -167,6 +189,15 @@ impl SerializedArrayDyn {
         Ok(Self(geo_arr))
     }
 
+    /// Construct a new [SerializedArrayDyn] from a plain Arrow storage array without extension
+    /// metadata, such as the output of [`ArrayBase::to_storage_array`].
+    ///
+    /// The array metadata is reset to its default.
+    pub fn try_from_storage(array: &dyn Array, data_type: SerializedType) -> Result<Self> {
+        let field = data_type.to_field("", true);
+        Self::from_arrow_array(array, &field)
+    }
+
     /// Access the underlying [`Arc<dyn SerializedArray>`]
     pub fn inner(&self) -> &SerializedArrayRef {
         &self.0
@@ -254,6 +254,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.point_xy.is_empty(),
             Dimension::XYZ => !self.point_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -261,6 +262,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.line_string_xy.is_empty(),
             Dimension::XYZ => !self.line_string_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -268,6 +270,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.polygon_xy.is_empty(),
             Dimension::XYZ => !self.polygon_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -275,6 +278,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.mpoint_xy.is_empty(),
             Dimension::XYZ => !self.mpoint_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -282,6 +286,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.mline_string_xy.is_empty(),
             Dimension::XYZ => !self.mline_string_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -289,6 +294,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.mpolygon_xy.is_empty(),
             Dimension::XYZ => !self.mpolygon_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -296,6 +302,7 @@ impl GeometryArray {
         match dim {
             Dimension::XY => !self.gc_xy.is_empty(),
             Dimension::XYZ => !self.gc_xyz.is_empty(),
+            Dimension::XYM | Dimension::XYZM => false,
         }
     }
 
@@ -319,6 +326,7 @@ impl GeometryArray {
                     || self.has_multi_line_strings(XYZ)
                     || self.has_multi_polygons(XYZ)
             }
+            XYM | XYZM => false,
         }
     }
 
@@ -329,6 +337,7 @@ impl GeometryArray {
         match dim {
             XY => self.has_dimension(XY) && !self.has_dimension(XYZ),
             XYZ => self.has_dimension(XYZ) && !self.has_dimension(XY),
+            XYM | XYZM => false,
         }
     }
 
@@ -507,6 +516,10 @@ impl GeometryArray {
 
     /// Change the coordinate type of this array.
     pub fn into_coord_type(self, coord_type: CoordType) -> Self {
+        if coord_type == self.data_type.coord_type() {
+            return self;
+        }
+
         Self::new(
             self.type_ids,
             self.offsets,
@@ -659,8 +672,8 @@ impl GeometryArraySelfMethods for GeometryArray {
         todo!();
     }
 
-    fn into_coord_type(self, _coord_type: crate::array::CoordType) -> Self {
-        todo!();
+    fn into_coord_type(self, coord_type: crate::array::CoordType) -> Self {
+        self.into_coord_type(coord_type)
     }
 }
 
@@ -993,17 +1006,27 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryArray {
     }
 }
 
-impl From<PointArray> for GeometryArray {
-    fn from(value: PointArray) -> Self {
+/// Geometry arrays have no children for geometries with M values.
+fn unsupported_dimension(dim: Dimension) -> GeoArrowError {
+    GeoArrowError::NotYetImplemented(format!(
+        "Geometry arrays do not support the {dim:?} dimension"
+    ))
+}
+
+impl TryFrom<PointArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: PointArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![1; value.len()],
             Dimension::XYZ => vec![11; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1042,21 +1065,25 @@ impl From<PointArray> for GeometryArray {
                 None,
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<LineStringArray> for GeometryArray {
-    fn from(value: LineStringArray) -> Self {
+impl TryFrom<LineStringArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LineStringArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![2; value.len()],
             Dimension::XYZ => vec![12; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1095,21 +1122,25 @@ impl From<LineStringArray> for GeometryArray {
                 None,
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<PolygonArray> for GeometryArray {
-    fn from(value: PolygonArray) -> Self {
+impl TryFrom<PolygonArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: PolygonArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![3; value.len()],
             Dimension::XYZ => vec![13; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1148,21 +1179,25 @@ impl From<PolygonArray> for GeometryArray {
                 None,
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<MultiPointArray> for GeometryArray {
-    fn from(value: MultiPointArray) -> Self {
+impl TryFrom<MultiPointArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: MultiPointArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![4; value.len()],
             Dimension::XYZ => vec![14; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1201,21 +1236,25 @@ impl From<MultiPointArray> for GeometryArray {
                 None,
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<MultiLineStringArray> for GeometryArray {
-    fn from(value: MultiLineStringArray) -> Self {
+impl TryFrom<MultiLineStringArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: MultiLineStringArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![5; value.len()],
             Dimension::XYZ => vec![15; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1254,21 +1293,25 @@ impl From<MultiLineStringArray> for GeometryArray {
                 None,
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<MultiPolygonArray> for GeometryArray {
-    fn from(value: MultiPolygonArray) -> Self {
+impl TryFrom<MultiPolygonArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: MultiPolygonArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![6; value.len()],
             Dimension::XYZ => vec![16; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1307,21 +1350,25 @@ impl From<MultiPolygonArray> for GeometryArray {
                 None,
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<GeometryCollectionArray> for GeometryArray {
-    fn from(value: GeometryCollectionArray) -> Self {
+impl TryFrom<GeometryCollectionArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: GeometryCollectionArray) -> Result<Self> {
         let dim = value.dimension();
         let type_ids = match dim {
             Dimension::XY => vec![7; value.len()],
             Dimension::XYZ => vec![17; value.len()],
+            dim => return Err(unsupported_dimension(dim)),
         }
         .into();
         let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
         let metadata = value.metadata().clone();
-        match dim {
+        Ok(match dim {
             Dimension::XY => Self::new(
                 type_ids,
                 offsets,
@@ -1360,12 +1407,15 @@ impl From<GeometryCollectionArray> for GeometryArray {
                 Some(value),
                 metadata,
             ),
-        }
+            dim => return Err(unsupported_dimension(dim)),
+        })
     }
 }
 
-impl From<MixedGeometryArray> for GeometryArray {
-    fn from(value: MixedGeometryArray) -> Self {
+impl TryFrom<MixedGeometryArray> for GeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: MixedGeometryArray) -> Result<Self> {
         use Dimension::*;
 
         // TODO: include slice offset
@@ -1400,9 +1450,10 @@ impl From<MixedGeometryArray> for GeometryArray {
                 mline_string_xyz = Some(value.multi_line_strings);
                 mpolygon_xyz = Some(value.multi_polygons);
             }
+            dim => return Err(unsupported_dimension(dim)),
         }
 
-        Self::new(
+        Ok(Self::new(
             value.type_ids,
             value.offsets,
             point_xy,
@@ -1420,7 +1471,7 @@ impl From<MixedGeometryArray> for GeometryArray {
             mpolygon_xyz,
             None,
             value.metadata,
-        )
+        ))
     }
 }
 
@@ -1677,4 +1728,36 @@ mod test {
         assert_eq!(arr.slice(1, 2).value_as_geo(1), geoms[2]);
         assert_eq!(arr.slice(3, 3).value_as_geo(2), geoms[5]);
     }
+
+    #[test]
+    fn push_wkb_and_wkt() {
+        let mut wkb = vec![];
+        wkb::writer::write_polygon(&mut wkb, &polygon::p0(), wkb::Endianness::LittleEndian)
+            .unwrap();
+
+        let mut builder = GeometryBuilder::new();
+        builder.push_wkb(Some(&wkb)).unwrap();
+        builder.push_wkt(Some("LINESTRING (0 1, 1 2)")).unwrap();
+        builder.push_wkt(None).unwrap();
+        assert!(builder.push_wkt(Some("LINESTRING (0 1,")).is_err());
+        assert!(builder.push_wkb(Some(&wkb[..8])).is_err());
+
+        let arr = builder.finish();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.value_as_geo(0), geo::Geometry::Polygon(polygon::p0()));
+        assert_eq!(
+            arr.value_as_geo(1),
+            geo::Geometry::LineString(linestring::ls0())
+        );
+        assert!(arr.is_null(2));
+    }
+
+    #[test]
+    fn reject_m_dimension() {
+        let points = PointBuilder::new(Dimension::XYM).finish();
+        assert!(GeometryArray::try_from(points).is_err());
+
+        let points = PointBuilder::new(Dimension::XYZ).finish();
+        assert!(GeometryArray::try_from(points).is_ok());
+    }
 }
@@ -1,13 +1,15 @@
 use std::sync::Arc;
 
+use crate::algorithm::native::Cast;
 use crate::array::geometry::array::GeometryArray;
 use crate::array::geometry::capacity::GeometryCapacity;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::impl_push_wkb_wkt;
 use crate::array::{
     CoordType, GeometryCollectionBuilder, LineStringBuilder, MultiLineStringBuilder,
     MultiPointBuilder, MultiPolygonBuilder, PointBuilder, PolygonBuilder, WKBArray,
 };
-use crate::datatypes::Dimension;
+use crate::datatypes::{union_child_type, Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::WKB;
 use crate::trait_::{ArrayAccessor, GeometryArrayBuilder, IntoArrow};
@@ -311,6 +313,31 @@ impl<'a> GeometryBuilder {
         self.into()
     }
 
+    /// The narrowest type that can hold every geometry pushed so far, following the promotion
+    /// rules of [`NativeType::common_type_of`], or `None` if no geometry has been pushed.
+    pub fn resolved_type(&self) -> Result<Option<NativeType>> {
+        let coord_type = GeometryArrayBuilder::coord_type(self);
+        let mut type_ids = self.types.clone();
+        type_ids.sort_unstable();
+        type_ids.dedup();
+        let types = type_ids
+            .into_iter()
+            .map(|type_id| union_child_type(type_id, coord_type))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(NativeType::common_type_of(types))
+    }
+
+    /// Consume the builder and convert to an array of its [resolved type][Self::resolved_type],
+    /// or to a [`GeometryArray`] if no geometry has been pushed.
+    pub fn finish_resolved(self) -> Result<Arc<dyn NativeArray>> {
+        let resolved_type = self.resolved_type()?;
+        let array = self.finish();
+        match resolved_type {
+            Some(to_type) => array.cast(to_type),
+            None => Ok(Arc::new(array)),
+        }
+    }
+
     /// Creates a new builder with a capacity inferred by the provided iterator.
     pub fn with_capacity_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
@@ -372,7 +399,7 @@ impl<'a> GeometryBuilder {
     pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) -> Result<()> {
         if let Some(point) = value {
             if self.prefer_multi {
-                self.add_multi_point_type(point.dim().try_into().unwrap());
+                self.add_multi_point_type(point.dim().try_into()?)?;
                 match point.dim() {
                     Dimensions::Xy | Dimensions::Unknown(2) => {
                         // Flush deferred nulls
@@ -395,7 +422,7 @@ impl<'a> GeometryBuilder {
                     }
                 }
             } else {
-                self.add_point_type(point.dim().try_into().unwrap());
+                self.add_point_type(point.dim().try_into()?)?;
                 match point.dim() {
                     Dimensions::Xy | Dimensions::Unknown(2) => {
                         // Flush deferred nulls
@@ -426,7 +453,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_point_type(&mut self, dim: Dimension) {
+    fn add_point_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets.push(self.point_xy.len().try_into().unwrap());
@@ -436,7 +463,13 @@ impl<'a> GeometryBuilder {
                 self.offsets.push(self.point_xyz.len().try_into().unwrap());
                 self.types.push(11)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Add a new LineString to the end of this array.
@@ -454,7 +487,7 @@ impl<'a> GeometryBuilder {
     ) -> Result<()> {
         if let Some(line_string) = value {
             if self.prefer_multi {
-                self.add_multi_line_string_type(line_string.dim().try_into().unwrap());
+                self.add_multi_line_string_type(line_string.dim().try_into()?)?;
                 match line_string.dim() {
                     Dimensions::Xy | Dimensions::Unknown(2) => {
                         // Flush deferred nulls
@@ -477,7 +510,7 @@ impl<'a> GeometryBuilder {
                     }
                 }
             } else {
-                self.add_line_string_type(line_string.dim().try_into().unwrap());
+                self.add_line_string_type(line_string.dim().try_into()?)?;
                 match line_string.dim() {
                     Dimensions::Xy | Dimensions::Unknown(2) => {
                         // Flush deferred nulls
@@ -508,7 +541,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_line_string_type(&mut self, dim: Dimension) {
+    fn add_line_string_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets
@@ -520,7 +553,13 @@ impl<'a> GeometryBuilder {
                     .push(self.line_string_xyz.len().try_into().unwrap());
                 self.types.push(12)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Add a new Polygon to the end of this array.
@@ -535,7 +574,7 @@ impl<'a> GeometryBuilder {
     pub fn push_polygon(&mut self, value: Option<&impl PolygonTrait<T = f64>>) -> Result<()> {
         if let Some(polygon) = value {
             if self.prefer_multi {
-                self.add_multi_polygon_type(polygon.dim().try_into().unwrap());
+                self.add_multi_polygon_type(polygon.dim().try_into()?)?;
                 match polygon.dim() {
                     Dimensions::Xy | Dimensions::Unknown(2) => {
                         // Flush deferred nulls
@@ -558,7 +597,7 @@ impl<'a> GeometryBuilder {
                     }
                 }
             } else {
-                self.add_polygon_type(polygon.dim().try_into().unwrap());
+                self.add_polygon_type(polygon.dim().try_into()?)?;
                 match polygon.dim() {
                     Dimensions::Xy | Dimensions::Unknown(2) => {
                         // Flush deferred nulls
@@ -589,7 +628,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_polygon_type(&mut self, dim: Dimension) {
+    fn add_polygon_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets.push(self.polygon_xy.len().try_into().unwrap());
@@ -600,7 +639,13 @@ impl<'a> GeometryBuilder {
                     .push(self.polygon_xyz.len().try_into().unwrap());
                 self.types.push(13)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Add a new MultiPoint to the end of this array.
@@ -614,7 +659,7 @@ impl<'a> GeometryBuilder {
         value: Option<&impl MultiPointTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(multi_point) = value {
-            self.add_multi_point_type(multi_point.dim().try_into().unwrap());
+            self.add_multi_point_type(multi_point.dim().try_into()?)?;
             match multi_point.dim() {
                 Dimensions::Xy | Dimensions::Unknown(2) => {
                     // Flush deferred nulls
@@ -644,7 +689,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_multi_point_type(&mut self, dim: Dimension) {
+    fn add_multi_point_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets.push(self.mpoint_xy.len().try_into().unwrap());
@@ -654,7 +699,13 @@ impl<'a> GeometryBuilder {
                 self.offsets.push(self.mpoint_xyz.len().try_into().unwrap());
                 self.types.push(14)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Add a new MultiLineString to the end of this array.
@@ -668,7 +719,7 @@ impl<'a> GeometryBuilder {
         value: Option<&impl MultiLineStringTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(multi_line_string) = value {
-            self.add_multi_line_string_type(multi_line_string.dim().try_into().unwrap());
+            self.add_multi_line_string_type(multi_line_string.dim().try_into()?)?;
             match multi_line_string.dim() {
                 Dimensions::Xy | Dimensions::Unknown(2) => {
                     // Flush deferred nulls
@@ -700,7 +751,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_multi_line_string_type(&mut self, dim: Dimension) {
+    fn add_multi_line_string_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets
@@ -712,7 +763,13 @@ impl<'a> GeometryBuilder {
                     .push(self.mline_string_xyz.len().try_into().unwrap());
                 self.types.push(15)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Add a new MultiPolygon to the end of this array.
@@ -726,7 +783,7 @@ impl<'a> GeometryBuilder {
         value: Option<&impl MultiPolygonTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(multi_polygon) = value {
-            self.add_multi_polygon_type(multi_polygon.dim().try_into().unwrap());
+            self.add_multi_polygon_type(multi_polygon.dim().try_into()?)?;
             match multi_polygon.dim() {
                 Dimensions::Xy | Dimensions::Unknown(2) => {
                     // Flush deferred nulls
@@ -756,7 +813,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_multi_polygon_type(&mut self, dim: Dimension) {
+    fn add_multi_polygon_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets
@@ -768,7 +825,13 @@ impl<'a> GeometryBuilder {
                     .push(self.mpolygon_xyz.len().try_into().unwrap());
                 self.types.push(16)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Add a new geometry to this builder
@@ -816,7 +879,7 @@ impl<'a> GeometryBuilder {
         value: Option<&impl GeometryCollectionTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(gc) = value {
-            self.add_geometry_collection_type(gc.dim().try_into().unwrap());
+            self.add_geometry_collection_type(gc.dim().try_into()?)?;
             match gc.dim() {
                 Dimensions::Xy | Dimensions::Unknown(2) => {
                     // Flush deferred nulls
@@ -846,7 +909,7 @@ impl<'a> GeometryBuilder {
     }
 
     #[inline]
-    fn add_geometry_collection_type(&mut self, dim: Dimension) {
+    fn add_geometry_collection_type(&mut self, dim: Dimension) -> Result<()> {
         match dim {
             Dimension::XY => {
                 self.offsets.push(self.gc_xy.len().try_into().unwrap());
@@ -856,7 +919,13 @@ impl<'a> GeometryBuilder {
                 self.offsets.push(self.gc_xyz.len().try_into().unwrap());
                 self.types.push(17)
             }
+            Dimension::XYM | Dimension::XYZM => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "Geometry arrays do not support the {dim:?} dimension"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Push a null to this builder
@@ -952,6 +1021,8 @@ impl<'a> GeometryBuilder {
     }
 }
 
+impl_push_wkb_wkt!(GeometryBuilder);
+
 impl Default for GeometryBuilder {
     fn default() -> Self {
         Self::new()
@@ -122,6 +122,10 @@ impl GeometryCollectionArray {
 
     /// Change the coordinate type of this array.
     pub fn into_coord_type(self, coord_type: CoordType) -> Self {
+        if coord_type == self.array.coord_type() {
+            return self;
+        }
+
         Self::new(
             self.array.into_coord_type(coord_type),
             self.geom_offsets,
@@ -209,8 +213,8 @@ impl GeometryArraySelfMethods for GeometryCollectionArray {
         todo!()
     }
 
-    fn into_coord_type(self, _coord_type: CoordType) -> Self {
-        todo!()
+    fn into_coord_type(self, coord_type: CoordType) -> Self {
+        self.into_coord_type(coord_type)
     }
 }
 
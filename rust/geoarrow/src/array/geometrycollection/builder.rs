@@ -7,6 +7,7 @@ use crate::array::geometrycollection::GeometryCollectionCapacity;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::mixed::builder::DEFAULT_PREFER_MULTI;
 use crate::array::offset_builder::OffsetsBuilder;
+use crate::array::util::impl_push_wkb_wkt;
 use crate::array::{CoordType, GeometryCollectionArray, MixedGeometryBuilder, WKBArray};
 use crate::datatypes::Dimension;
 use crate::error::{GeoArrowError, Result};
@@ -393,6 +394,8 @@ impl<'a> GeometryCollectionBuilder {
     }
 }
 
+impl_push_wkb_wkt!(GeometryCollectionBuilder);
+
 impl GeometryArrayBuilder for GeometryCollectionBuilder {
     fn new(dim: Dimension) -> Self {
         Self::new(dim)
@@ -110,6 +110,35 @@ impl LineStringArray {
         })
     }
 
+    /// Create a new LineStringArray from parts without validating that the parts are consistent.
+    ///
+    /// This is useful when the parts are known to be valid, e.g. when they have been produced by
+    /// this crate and persisted to disk, and avoids the cost of validation.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the parts pass the checks performed by [`Self::try_new`].
+    /// Accessing values of an inconsistent array may read out of bounds.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<i32>,
+        validity: Option<NullBuffer>,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let data_type = NativeType::LineString(coords.coord_type(), coords.dim());
+        Self {
+            data_type,
+            coords,
+            geom_offsets,
+            validity,
+            metadata,
+        }
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("vertices", self.coords.storage_type(), false).into()
     }
@@ -565,6 +594,16 @@ mod test {
         assert_eq!(sliced.get_as_geo(0), Some(ls1()));
     }
 
+    #[test]
+    fn new_unchecked_from_parts() {
+        let arr: LineStringArray = (vec![ls0(), ls1()].as_slice(), Dimension::XY).into();
+        let (coords, geom_offsets, validity) = arr.clone().into_inner();
+        let arr2 = unsafe {
+            LineStringArray::new_unchecked(coords, geom_offsets, validity, arr.metadata())
+        };
+        assert_eq!(arr, arr2);
+    }
+
     #[test]
     fn parse_wkb_geoarrow_interleaved_example() {
         let linestring_arr = example_linestring_interleaved();
@@ -584,4 +623,19 @@ mod test {
 
         assert_eq!(linestring_arr, parsed_linestring_arr);
     }
+
+    #[test]
+    fn push_wkt() {
+        let mut builder = LineStringBuilder::new(Dimension::XY);
+        builder.push_wkt(Some("LINESTRING (3 4, 5 6)")).unwrap();
+        builder
+            .push_wkt(Some("MULTILINESTRING ((0 1, 1 2))"))
+            .unwrap();
+        assert!(builder.push_wkt(Some("POINT (0 1)")).is_err());
+
+        let arr: LineStringArray = builder.into();
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.value_as_geo(0), ls1());
+        assert_eq!(arr.value_as_geo(1), ls0());
+    }
 }
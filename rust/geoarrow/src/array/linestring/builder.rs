@@ -1,5 +1,6 @@
 use crate::array::linestring::capacity::LineStringCapacity;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::impl_push_wkb_wkt;
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -339,6 +340,8 @@ impl LineStringBuilder {
     }
 }
 
+impl_push_wkb_wkt!(LineStringBuilder);
+
 impl GeometryArrayBuilder for LineStringBuilder {
     fn new(dim: Dimension) -> Self {
         Self::new(dim)
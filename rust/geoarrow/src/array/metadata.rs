@@ -4,9 +4,10 @@
 
 use arrow_schema::Field;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 use crate::error::GeoArrowError;
+use crate::io::crs::WktNode;
 
 /// If present, instructs consumers that edges follow a spherical path rather than a planar one. If
 /// this value is omitted, edges will be interpreted as planar.
@@ -48,6 +49,170 @@ pub enum CRSType {
     Srid,
 }
 
+/// How strictly [`ArrayMetadata::crs_equivalent_to`] compares two CRSs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrsStrictness {
+    /// Require the same CRS, with the same axis order.
+    Strict,
+
+    /// Also treat CRSs that differ only in axis order as equivalent, such as `EPSG:4326` and
+    /// `OGC:CRS84`.
+    ///
+    /// GeoArrow always stores coordinates in (longitude, latitude) or (easting, northing) order,
+    /// regardless of the axis order of the CRS, so such CRSs describe the same data.
+    #[default]
+    IgnoreAxisOrder,
+}
+
+/// Pairs of authority codes of CRSs that only differ in axis order.
+const AXIS_SWAPPED_CRS: &[(&str, &str)] = &[
+    ("OGC:CRS84", "EPSG:4326"),
+    ("OGC:CRS83", "EPSG:4269"),
+    ("OGC:CRS27", "EPSG:4267"),
+];
+
+/// A CRS reduced to a form that can be compared across representations.
+#[derive(Debug, PartialEq)]
+enum CrsIdentity {
+    /// An uppercased `AUTHORITY:CODE` identifier.
+    AuthorityCode(String),
+    /// A PROJJSON object without an identifier.
+    Projjson(Value),
+    /// A normalized WKT string without an identifier.
+    Wkt(String),
+    /// Any other string, compared as is.
+    Opaque(String),
+}
+
+/// Parse an `AUTHORITY:CODE` identifier, or its URN form such as `urn:ogc:def:crs:EPSG::4326`.
+fn parse_authority_code(crs: &str) -> Option<String> {
+    let crs = crs.trim();
+    let (authority, code) = match crs.strip_prefix("urn:ogc:def:crs:") {
+        Some(urn) => {
+            let (authority, rest) = urn.split_once(':')?;
+            (authority, rest.rsplit(':').next()?)
+        }
+        None => crs.split_once(':')?,
+    };
+    let is_identifier = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    };
+    (is_identifier(authority) && is_identifier(code))
+        .then(|| format!("{authority}:{code}").to_ascii_uppercase())
+}
+
+fn crs_identity(crs: &Value, crs_type: Option<&CRSType>) -> CrsIdentity {
+    match crs {
+        Value::String(crs) if crs_type == Some(&CRSType::Srid) => CrsIdentity::Opaque(crs.clone()),
+        Value::String(crs) => {
+            if let Some(code) = parse_authority_code(crs) {
+                return CrsIdentity::AuthorityCode(code);
+            }
+            match WktNode::parse(crs) {
+                Ok(node) => match node.authority_code() {
+                    Some(code) => CrsIdentity::AuthorityCode(code.to_ascii_uppercase()),
+                    None => CrsIdentity::Wkt(node.normalize().to_string()),
+                },
+                Err(_) => CrsIdentity::Opaque(crs.clone()),
+            }
+        }
+        Value::Object(object) => {
+            let id = object.get("id").and_then(|id| {
+                let authority = id.get("authority")?.as_str()?;
+                let code = match id.get("code")? {
+                    Value::String(code) => code.clone(),
+                    Value::Number(code) => code.to_string(),
+                    _ => return None,
+                };
+                Some(format!("{authority}:{code}").to_ascii_uppercase())
+            });
+            match id {
+                Some(code) => CrsIdentity::AuthorityCode(code),
+                None => CrsIdentity::Projjson(crs.clone()),
+            }
+        }
+        crs => CrsIdentity::Opaque(crs.to_string()),
+    }
+}
+
+/// The confidence of an EPSG code read from an explicit EPSG identifier.
+const EPSG_CONFIDENCE_IDENTIFIER: u8 = 100;
+
+/// The confidence of an EPSG code for a CRS that only differs from it in axis order.
+const EPSG_CONFIDENCE_AXIS_SWAPPED: u8 = 90;
+
+/// The confidence of an EPSG code inferred from the name of a CRS without identifier.
+const EPSG_CONFIDENCE_NAME: u8 = 70;
+
+/// The confidence of an EPSG code read from an opaque SRID, which is often but not always an
+/// EPSG code.
+const EPSG_CONFIDENCE_SRID: u8 = 50;
+
+/// The minimum confidence used by [`ArrayMetadata::srid`].
+pub const DEFAULT_SRID_CONFIDENCE: u8 = EPSG_CONFIDENCE_AXIS_SWAPPED;
+
+/// The EPSG code of an uppercased `AUTHORITY:CODE` identifier, with its confidence.
+fn epsg_from_authority_code(code: &str) -> Option<(u32, u8)> {
+    if let Some(code) = code.strip_prefix("EPSG:") {
+        return Some((code.parse().ok()?, EPSG_CONFIDENCE_IDENTIFIER));
+    }
+    let (_, epsg) = AXIS_SWAPPED_CRS.iter().find(|(other, _)| *other == code)?;
+    let code = epsg.strip_prefix("EPSG:")?.parse().ok()?;
+    Some((code, EPSG_CONFIDENCE_AXIS_SWAPPED))
+}
+
+/// The EPSG codes of the well-known geographic CRSs.
+const GEOGRAPHIC_EPSG_CODES: [u32; 4] = [4326, 4269, 4267, 4258];
+
+/// The EPSG code of a well-known CRS, from its name.
+///
+/// `geographic` is whether the CRS is a geographic CRS, as geocentric CRSs often share the name
+/// of the geographic CRS with the same datum.
+fn epsg_from_name(name: &str, geographic: bool) -> Option<u32> {
+    if geographic {
+        return match name {
+            "WGS 84" | "WGS84" => Some(4326),
+            "NAD83" => Some(4269),
+            "NAD27" => Some(4267),
+            "ETRS89" => Some(4258),
+            _ => None,
+        };
+    }
+
+    if name == "WGS 84 / Pseudo-Mercator" {
+        return Some(3857);
+    }
+    let zone = name.strip_prefix("WGS 84 / UTM zone ")?;
+    let (zone, base) = match zone.strip_suffix('N') {
+        Some(zone) => (zone, 32600),
+        None => (zone.strip_suffix('S')?, 32700),
+    };
+    let zone: u32 = zone.parse().ok()?;
+    (1..=60).contains(&zone).then_some(base + zone)
+}
+
+/// The name of a PROJJSON or WKT CRS, and whether it is a geographic CRS.
+fn crs_name(crs: &Value) -> Option<(String, bool)> {
+    match crs {
+        Value::Object(object) => {
+            let name = object.get("name")?.as_str()?;
+            let geographic = object.get("type")?.as_str()? == "GeographicCRS";
+            Some((name.to_string(), geographic))
+        }
+        Value::String(wkt) => {
+            let node = WktNode::parse(wkt).ok()?;
+            let geographic = ["GEOGCS", "GEOGCRS", "GEOGRAPHICCRS"]
+                .iter()
+                .any(|keyword| node.keyword.eq_ignore_ascii_case(keyword));
+            Some((node.name()?.to_string(), geographic))
+        }
+        _ => None,
+    }
+}
+
 /// A GeoArrow metadata object following the extension metadata [defined by the GeoArrow
 /// specification](https://geoarrow.org/extension-types).
 ///
@@ -83,13 +248,20 @@ pub struct ArrayMetadata {
     /// If present, instructs consumers that edges follow a spherical path rather than a planar
     /// one. If this value is omitted, edges will be interpreted as planar.
     pub edges: Option<Edges>,
+
+    /// Any other keys of the metadata object, such as fields added by a later version of the
+    /// specification or vendor-specific keys.
+    ///
+    /// These are not interpreted, but are kept so that they are written back unchanged.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 impl ArrayMetadata {
     /// Decide whether this [ArrayMetadata] should be written to Arrow metadata (aka if it is
     /// non-empty)
     pub fn should_serialize(&self) -> bool {
-        self.crs.is_some() || self.edges.is_some()
+        self.crs.is_some() || self.edges.is_some() || !self.extra.is_empty()
     }
 
     /// Construct from a PROJJSON object.
@@ -152,6 +324,231 @@ impl ArrayMetadata {
         self.edges = Some(edges);
         self
     }
+
+    /// The coordinate epoch of a dynamic CRS, as a decimal year, e.g. `2021.47`.
+    ///
+    /// This isn't part of the GeoArrow specification. It's kept as an `"epoch"` key of
+    /// [`extra`][Self::extra], so that the epoch of formats like GeoParquet survives a round trip.
+    pub fn epoch(&self) -> Option<f64> {
+        self.extra.get("epoch").and_then(Value::as_f64)
+    }
+
+    /// Set the coordinate epoch of a dynamic CRS, as a decimal year.
+    pub fn with_epoch(mut self, epoch: f64) -> Self {
+        self.extra.insert("epoch".to_string(), epoch.into());
+        self
+    }
+
+    /// Whether the CRS of this metadata describes the same CRS as the CRS of `other`, even if
+    /// they are stored in different representations.
+    ///
+    /// Authority codes, their URN form, and the identifiers embedded in PROJJSON and WKT
+    /// definitions are compared, so that e.g. `EPSG:4326` and the PROJJSON of EPSG:4326 are
+    /// equivalent. CRSs without an identifier are compared structurally. Two missing CRSs are
+    /// equivalent, but a missing CRS is not equivalent to any CRS.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::metadata::{ArrayMetadata, CrsStrictness};
+    ///
+    /// let epsg = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+    /// let crs84 = ArrayMetadata::from_authority_code("OGC:CRS84".to_string());
+    /// assert!(epsg.crs_equivalent_to(&crs84, CrsStrictness::IgnoreAxisOrder));
+    /// assert!(!epsg.crs_equivalent_to(&crs84, CrsStrictness::Strict));
+    /// ```
+    pub fn crs_equivalent_to(&self, other: &ArrayMetadata, strictness: CrsStrictness) -> bool {
+        let (left, right) = match (&self.crs, &other.crs) {
+            (None, None) => return true,
+            (Some(left), Some(right)) => (
+                crs_identity(left, self.crs_type.as_ref()),
+                crs_identity(right, other.crs_type.as_ref()),
+            ),
+            _ => return false,
+        };
+
+        if left == right {
+            return true;
+        }
+
+        match (left, right, strictness) {
+            (
+                CrsIdentity::AuthorityCode(left),
+                CrsIdentity::AuthorityCode(right),
+                CrsStrictness::IgnoreAxisOrder,
+            ) => AXIS_SWAPPED_CRS
+                .iter()
+                .any(|(a, b)| (left == *a && right == *b) || (left == *b && right == *a)),
+            _ => false,
+        }
+    }
+}
+
+impl ArrayMetadata {
+    /// Infer the EPSG code of the CRS, with a confidence between 0 and 100.
+    ///
+    /// The confidence is:
+    ///
+    /// - 100 for an explicit EPSG identifier, whether an authority code, a URN, or the identifier
+    ///   of a PROJJSON or WKT CRS.
+    /// - 90 for an identifier of a CRS that only differs from an EPSG CRS in axis order, such as
+    ///   `OGC:CRS84` for `EPSG:4326`.
+    /// - 70 for a PROJJSON or WKT CRS without identifier, whose name is that of a well-known CRS.
+    /// - 50 for an opaque SRID that is a number, which is assumed to be an EPSG code.
+    ///
+    /// Returns `None` if there is no CRS or no EPSG code could be inferred.
+    pub fn epsg_with_confidence(&self) -> Option<(u32, u8)> {
+        let crs = self.crs.as_ref()?;
+        if self.crs_type == Some(CRSType::Srid) {
+            let code = match crs {
+                Value::String(srid) => srid.trim().parse().ok()?,
+                Value::Number(srid) => u32::try_from(srid.as_u64()?).ok()?,
+                _ => return None,
+            };
+            return Some((code, EPSG_CONFIDENCE_SRID));
+        }
+
+        match crs_identity(crs, self.crs_type.as_ref()) {
+            CrsIdentity::AuthorityCode(code) => epsg_from_authority_code(&code),
+            CrsIdentity::Projjson(_) | CrsIdentity::Wkt(_) => {
+                let (name, geographic) = crs_name(crs)?;
+                Some((epsg_from_name(&name, geographic)?, EPSG_CONFIDENCE_NAME))
+            }
+            CrsIdentity::Opaque(_) => None,
+        }
+    }
+
+    /// The EPSG code of the CRS, if it can be inferred with at least `min_confidence`.
+    ///
+    /// See [`epsg_with_confidence`][Self::epsg_with_confidence] for how the confidence is
+    /// computed.
+    ///
+    /// ```
+    /// use geoarrow::array::metadata::ArrayMetadata;
+    /// use serde_json::json;
+    ///
+    /// let metadata = ArrayMetadata::from_projjson(json!({
+    ///     "type": "ProjectedCRS",
+    ///     "name": "WGS 84 / UTM zone 33N",
+    /// }));
+    /// assert_eq!(metadata.to_epsg(70), Some(32633));
+    /// assert_eq!(metadata.to_epsg(100), None);
+    /// ```
+    pub fn to_epsg(&self, min_confidence: u8) -> Option<u32> {
+        self.epsg_with_confidence()
+            .filter(|(_, confidence)| *confidence >= min_confidence)
+            .map(|(code, _)| code)
+    }
+
+    /// Whether the CRS is known to be geographic, with coordinates in degrees of longitude and
+    /// latitude.
+    ///
+    /// This is the type of a PROJJSON or WKT CRS if it has one. Otherwise, the CRS is geographic
+    /// if its EPSG code, inferred with at least [`DEFAULT_SRID_CONFIDENCE`], is that of a
+    /// well-known geographic CRS such as `EPSG:4326`.
+    ///
+    /// ```
+    /// use geoarrow::array::metadata::ArrayMetadata;
+    ///
+    /// assert!(ArrayMetadata::from_authority_code("OGC:CRS84".to_string()).is_geographic());
+    /// assert!(!ArrayMetadata::from_authority_code("EPSG:3857".to_string()).is_geographic());
+    /// assert!(!ArrayMetadata::default().is_geographic());
+    /// ```
+    pub fn is_geographic(&self) -> bool {
+        if let Some((_, geographic)) = self.crs.as_ref().and_then(crs_name) {
+            return geographic;
+        }
+        self.to_epsg(DEFAULT_SRID_CONFIDENCE)
+            .is_some_and(|code| GEOGRAPHIC_EPSG_CODES.contains(&code))
+    }
+
+    /// The numeric SRID of the CRS, for formats such as EWKB and databases such as PostGIS.
+    ///
+    /// An opaque SRID is returned as is. Otherwise, this is the EPSG code of the CRS if it can be
+    /// inferred with at least [`DEFAULT_SRID_CONFIDENCE`].
+    pub fn srid(&self) -> Option<i32> {
+        if let (Some(crs), Some(CRSType::Srid)) = (&self.crs, &self.crs_type) {
+            return match crs {
+                Value::String(srid) => srid.trim().parse().ok(),
+                Value::Number(srid) => i32::try_from(srid.as_i64()?).ok(),
+                _ => None,
+            };
+        }
+        i32::try_from(self.to_epsg(DEFAULT_SRID_CONFIDENCE)?).ok()
+    }
+}
+
+/// The `AUTHORITY:CODE` identifier of a PROJJSON object, from its `id` member.
+#[cfg(feature = "proj")]
+fn projjson_authority_code(projjson: &Value) -> Option<String> {
+    let id = projjson.get("id")?;
+    let authority = id.get("authority")?.as_str()?;
+    let code = match id.get("code")? {
+        Value::String(code) => code.clone(),
+        Value::Number(code) => code.to_string(),
+        _ => return None,
+    };
+    Some(format!("{authority}:{code}"))
+}
+
+#[cfg(feature = "proj")]
+impl ArrayMetadata {
+    /// Convert the CRS to a PROJJSON object using PROJ, whatever representation it is stored in.
+    ///
+    /// A CRS that is already PROJJSON is returned as is. Returns `None` if there is no CRS or if
+    /// the CRS is an opaque SRID, which PROJ can't resolve.
+    pub fn to_projjson(&self) -> Result<Option<Value>, GeoArrowError> {
+        let definition = match &self.crs {
+            None => return Ok(None),
+            Some(crs @ Value::Object(_)) => return Ok(Some(crs.clone())),
+            Some(Value::String(_)) if self.crs_type == Some(CRSType::Srid) => return Ok(None),
+            Some(Value::String(definition)) => definition,
+            Some(crs) => {
+                return Err(GeoArrowError::General(format!(
+                    "Unexpected CRS value: {crs}"
+                )))
+            }
+        };
+
+        let proj =
+            proj::Proj::new(definition).map_err(|err| GeoArrowError::General(err.to_string()))?;
+        let projjson = proj.to_projjson(None, None, None)?;
+        Ok(Some(serde_json::from_str(&projjson)?))
+    }
+
+    /// The definition of the CRS to pass to PROJ: the PROJJSON serialized as a string, or the
+    /// string CRS as is.
+    ///
+    /// Returns `None` if there is no CRS, and an error if the CRS is an opaque SRID.
+    pub(crate) fn proj_definition(&self) -> Result<Option<String>, GeoArrowError> {
+        match &self.crs {
+            None => Ok(None),
+            Some(crs @ Value::Object(_)) => Ok(Some(serde_json::to_string(crs)?)),
+            Some(Value::String(srid)) if self.crs_type == Some(CRSType::Srid) => Err(
+                GeoArrowError::General(format!("Cannot resolve the opaque SRID {srid} with PROJ")),
+            ),
+            Some(Value::String(definition)) => Ok(Some(definition.clone())),
+            Some(crs) => Err(GeoArrowError::General(format!(
+                "Unexpected CRS value: {crs}"
+            ))),
+        }
+    }
+
+    /// Convert the CRS to an `AUTHORITY:CODE` identifier such as `EPSG:4326`, using PROJ.
+    ///
+    /// Returns `None` if there is no CRS, or if the CRS has no identifier, e.g. a custom
+    /// projection.
+    pub fn to_authority_code(&self) -> Result<Option<String>, GeoArrowError> {
+        if let (Some(Value::String(code)), Some(CRSType::AuthorityCode)) =
+            (&self.crs, &self.crs_type)
+        {
+            return Ok(Some(code.clone()));
+        }
+
+        Ok(self
+            .to_projjson()?
+            .and_then(|projjson| projjson_authority_code(&projjson)))
+    }
 }
 
 impl TryFrom<&Field> for ArrayMetadata {
@@ -165,3 +562,103 @@ impl TryFrom<&Field> for ArrayMetadata {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn crs_equivalence() {
+        let epsg = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let urn = ArrayMetadata::from_unknown_crs_type("urn:ogc:def:crs:EPSG::4326".to_string());
+        let projjson = ArrayMetadata::from_projjson(json!({
+            "type": "GeographicCRS",
+            "name": "WGS 84",
+            "id": {"authority": "EPSG", "code": 4326}
+        }));
+        let wkt = ArrayMetadata::from_wkt2_2019(
+            r#"GEOGCRS["WGS 84",CS[ellipsoidal,2],ID["EPSG",4326]]"#.to_string(),
+        );
+        for other in [&urn, &projjson, &wkt] {
+            assert!(epsg.crs_equivalent_to(other, CrsStrictness::Strict));
+        }
+
+        let crs84 =
+            ArrayMetadata::from_projjson(json!({"id": {"authority": "OGC", "code": "CRS84"}}));
+        assert!(projjson.crs_equivalent_to(&crs84, CrsStrictness::IgnoreAxisOrder));
+        assert!(!projjson.crs_equivalent_to(&crs84, CrsStrictness::Strict));
+
+        let other = ArrayMetadata::from_authority_code("EPSG:3857".to_string());
+        assert!(!epsg.crs_equivalent_to(&other, CrsStrictness::IgnoreAxisOrder));
+        assert!(!epsg.crs_equivalent_to(&ArrayMetadata::default(), Default::default()));
+        assert!(ArrayMetadata::default().crs_equivalent_to(&Default::default(), Default::default()));
+    }
+
+    #[test]
+    fn epsg_code() {
+        let urn = ArrayMetadata::from_unknown_crs_type("urn:ogc:def:crs:EPSG::3857".to_string());
+        assert_eq!(urn.epsg_with_confidence(), Some((3857, 100)));
+        assert_eq!(urn.srid(), Some(3857));
+
+        let crs84 = ArrayMetadata::from_authority_code("OGC:CRS84".to_string());
+        assert_eq!(crs84.epsg_with_confidence(), Some((4326, 90)));
+        assert_eq!(crs84.srid(), Some(4326));
+        assert_eq!(crs84.to_epsg(100), None);
+
+        let wkt = ArrayMetadata::from_unknown_crs_type(
+            r#"GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]]]"#
+                .to_string(),
+        );
+        assert_eq!(wkt.epsg_with_confidence(), Some((4326, 70)));
+        assert_eq!(wkt.srid(), None);
+
+        let geocentric = ArrayMetadata::from_projjson(json!({
+            "type": "GeodeticCRS",
+            "name": "WGS 84",
+        }));
+        assert_eq!(geocentric.epsg_with_confidence(), None);
+
+        let srid = ArrayMetadata {
+            crs: Some(Value::String("2154".to_string())),
+            crs_type: Some(CRSType::Srid),
+            ..Default::default()
+        };
+        assert_eq!(srid.srid(), Some(2154));
+        assert_eq!(srid.to_epsg(DEFAULT_SRID_CONFIDENCE), None);
+        assert_eq!(srid.to_epsg(50), Some(2154));
+
+        assert_eq!(ArrayMetadata::default().srid(), None);
+    }
+
+    #[test]
+    fn unknown_keys_round_trip() {
+        let json = r#"{"crs":"EPSG:4326","crs_type":"authority_code","vendor:key":{"a":1}}"#;
+        let field = Field::new("", arrow_schema::DataType::Binary, true)
+            .with_metadata([("ARROW:extension:metadata".to_string(), json.to_string())].into());
+        let metadata = ArrayMetadata::try_from(&field).unwrap();
+        assert_eq!(metadata.extra["vendor:key"], json!({"a": 1}));
+        assert!(metadata.extra.get("crs").is_none());
+
+        let serialized: Value = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(serialized["vendor:key"], json!({"a": 1}));
+        assert_eq!(serialized["crs"], json!("EPSG:4326"));
+
+        let only_unknown: ArrayMetadata = serde_json::from_str(r#"{"future": true}"#).unwrap();
+        assert!(only_unknown.should_serialize());
+    }
+
+    #[cfg(feature = "proj")]
+    #[test]
+    fn authority_code_round_trip() {
+        let metadata = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let projjson = metadata.to_projjson().unwrap().unwrap();
+        assert_eq!(projjson["id"]["code"], 4326);
+
+        let metadata = ArrayMetadata::from_projjson(projjson);
+        assert_eq!(
+            metadata.to_authority_code().unwrap().as_deref(),
+            Some("EPSG:4326")
+        );
+    }
+}
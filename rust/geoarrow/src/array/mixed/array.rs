@@ -424,7 +424,12 @@ impl MixedGeometryArray {
     }
 
     pub fn into_coord_type(self, coord_type: CoordType) -> Self {
-        Self::new(
+        if coord_type == self.coord_type {
+            return self;
+        }
+
+        let slice_offset = self.slice_offset;
+        let mut array = Self::new(
             self.type_ids,
             self.offsets,
             Some(self.points.into_coord_type(coord_type)),
@@ -434,7 +439,9 @@ impl MixedGeometryArray {
             Some(self.multi_line_strings.into_coord_type(coord_type)),
             Some(self.multi_polygons.into_coord_type(coord_type)),
             self.metadata,
-        )
+        );
+        array.slice_offset = slice_offset;
+        array
     }
 
     pub fn contained_types(&self) -> HashSet<NativeType> {
@@ -561,8 +568,8 @@ impl GeometryArraySelfMethods for MixedGeometryArray {
         todo!();
     }
 
-    fn into_coord_type(self, _coord_type: crate::array::CoordType) -> Self {
-        todo!();
+    fn into_coord_type(self, coord_type: crate::array::CoordType) -> Self {
+        self.into_coord_type(coord_type)
     }
 }
 
@@ -571,7 +578,7 @@ impl NativeGeometryAccessor for MixedGeometryArray {
         let type_id = self.type_ids[index];
         let offset = self.offsets[index] as usize;
 
-        match type_id {
+        match type_id % 10 {
             1 => Geometry::Point(self.points.value(offset)),
             2 => Geometry::LineString(self.line_strings.value(offset)),
             3 => Geometry::Polygon(self.polygons.value(offset)),
@@ -581,15 +588,6 @@ impl NativeGeometryAccessor for MixedGeometryArray {
             7 => {
                 panic!("nested geometry collections not supported")
             }
-            11 => Geometry::Point(self.points.value(offset)),
-            12 => Geometry::LineString(self.line_strings.value(offset)),
-            13 => Geometry::Polygon(self.polygons.value(offset)),
-            14 => Geometry::MultiPoint(self.multi_points.value(offset)),
-            15 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
-            16 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
-            17 => {
-                panic!("nested geometry collections not supported")
-            }
             _ => panic!("unknown type_id {}", type_id),
         }
     }
@@ -614,7 +612,7 @@ impl<'a> ArrayAccessor<'a> for MixedGeometryArray {
         let type_id = self.type_ids[index];
         let offset = self.offsets[index] as usize;
 
-        match type_id {
+        match type_id % 10 {
             1 => Geometry::Point(self.points.value(offset)),
             2 => Geometry::LineString(self.line_strings.value(offset)),
             3 => Geometry::Polygon(self.polygons.value(offset)),
@@ -624,15 +622,6 @@ impl<'a> ArrayAccessor<'a> for MixedGeometryArray {
             7 => {
                 panic!("nested geometry collections not supported")
             }
-            11 => Geometry::Point(self.points.value(offset)),
-            12 => Geometry::LineString(self.line_strings.value(offset)),
-            13 => Geometry::Polygon(self.polygons.value(offset)),
-            14 => Geometry::MultiPoint(self.multi_points.value(offset)),
-            15 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
-            16 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
-            17 => {
-                panic!("nested geometry collections not supported")
-            }
             _ => panic!("unknown type_id {}", type_id),
         }
     }
@@ -684,42 +673,33 @@ impl TryFrom<(&UnionArray, Dimension)> for MixedGeometryArray {
                 }
 
                 for (type_id, _field) in fields.iter() {
-                    let found_dimension = if type_id < 10 {
-                        Dimension::XY
-                    } else if type_id < 20 {
-                        Dimension::XYZ
-                    } else {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported type_id: {}",
-                            type_id
-                        )));
-                    };
+                    let found_dimension = Dimension::from_type_id(type_id)?;
 
                     if dim != found_dimension {
                         return Err(  GeoArrowError::General(format!("expected dimension: {:?}, found child array with dimension {:?} and type_id: {}", dim, found_dimension, type_id )));
                     }
 
-                    match type_id {
-                        1 | 11 => {
+                    match type_id % 10 {
+                        1 => {
                             points = Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        2 | 12 => {
+                        2 => {
                             line_strings =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        3 | 13 => {
+                        3 => {
                             polygons =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        4 | 14 => {
+                        4 => {
                             multi_points =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        5 | 15 => {
+                        5 => {
                             multi_line_strings =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        6 | 16 => {
+                        6 => {
                             multi_polygons =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
@@ -814,10 +794,7 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for MixedGeometryArra
 
 impl From<PointArray> for MixedGeometryArray {
     fn from(value: PointArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![1; value.len()],
-            Dimension::XYZ => vec![11; value.len()],
-        };
+        let type_ids = vec![1 + value.dimension().type_id_offset(); value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -835,10 +812,7 @@ impl From<PointArray> for MixedGeometryArray {
 
 impl From<LineStringArray> for MixedGeometryArray {
     fn from(value: LineStringArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![2; value.len()],
-            Dimension::XYZ => vec![12; value.len()],
-        };
+        let type_ids = vec![2 + value.dimension().type_id_offset(); value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -856,10 +830,7 @@ impl From<LineStringArray> for MixedGeometryArray {
 
 impl From<PolygonArray> for MixedGeometryArray {
     fn from(value: PolygonArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![3; value.len()],
-            Dimension::XYZ => vec![13; value.len()],
-        };
+        let type_ids = vec![3 + value.dimension().type_id_offset(); value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -877,10 +848,7 @@ impl From<PolygonArray> for MixedGeometryArray {
 
 impl From<MultiPointArray> for MixedGeometryArray {
     fn from(value: MultiPointArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![4; value.len()],
-            Dimension::XYZ => vec![14; value.len()],
-        };
+        let type_ids = vec![4 + value.dimension().type_id_offset(); value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -898,10 +866,7 @@ impl From<MultiPointArray> for MixedGeometryArray {
 
 impl From<MultiLineStringArray> for MixedGeometryArray {
     fn from(value: MultiLineStringArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![5; value.len()],
-            Dimension::XYZ => vec![15; value.len()],
-        };
+        let type_ids = vec![5 + value.dimension().type_id_offset(); value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -919,10 +884,7 @@ impl From<MultiLineStringArray> for MixedGeometryArray {
 
 impl From<MultiPolygonArray> for MixedGeometryArray {
     fn from(value: MultiPolygonArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![6; value.len()],
-            Dimension::XYZ => vec![16; value.len()],
-        };
+        let type_ids = vec![6 + value.dimension().type_id_offset(); value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -1087,4 +1049,18 @@ mod test {
         assert_eq!(round_trip_arr.value_as_geo(0), geoms[0]);
         assert_eq!(round_trip_arr.value_as_geo(1), geoms[1]);
     }
+
+    #[test]
+    fn push_null() {
+        let mut builder = MixedGeometryBuilder::new(Dimension::XY);
+        builder.push_wkt(Some("LINESTRING (3 4, 5 6)")).unwrap();
+        builder.push_wkt(None).unwrap();
+        builder.push_wkb(None).unwrap();
+
+        let arr = builder.finish();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.line_strings.len(), 3);
+        assert!(arr.line_strings.is_null(1));
+        assert!(arr.line_strings.is_null(2));
+    }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::mixed::MixedCapacity;
+use crate::array::util::impl_push_wkb_wkt;
 use crate::array::{
     CoordType, LineStringBuilder, MixedGeometryArray, MultiLineStringBuilder, MultiPointBuilder,
     MultiPolygonBuilder, PointBuilder, PolygonBuilder, WKBArray,
@@ -269,10 +270,7 @@ impl<'a> MixedGeometryBuilder {
     #[inline]
     fn add_point_type(&mut self) {
         self.offsets.push(self.points.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(1),
-            Dimension::XYZ => self.types.push(11),
-        }
+        self.types.push(1 + self.dim.type_id_offset());
     }
 
     /// Add a new LineString to the end of this array.
@@ -301,10 +299,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_line_string_type(&mut self) {
         self.offsets
             .push(self.line_strings.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(2),
-            Dimension::XYZ => self.types.push(12),
-        }
+        self.types.push(2 + self.dim.type_id_offset());
     }
 
     /// Add a new Polygon to the end of this array.
@@ -329,10 +324,7 @@ impl<'a> MixedGeometryBuilder {
     #[inline]
     fn add_polygon_type(&mut self) {
         self.offsets.push(self.polygons.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(3),
-            Dimension::XYZ => self.types.push(13),
-        }
+        self.types.push(3 + self.dim.type_id_offset());
     }
 
     /// Add a new MultiPoint to the end of this array.
@@ -353,10 +345,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_multi_point_type(&mut self) {
         self.offsets
             .push(self.multi_points.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(4),
-            Dimension::XYZ => self.types.push(14),
-        }
+        self.types.push(4 + self.dim.type_id_offset());
     }
 
     /// Add a new MultiLineString to the end of this array.
@@ -377,10 +366,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_multi_line_string_type(&mut self) {
         self.offsets
             .push(self.multi_line_strings.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(5),
-            Dimension::XYZ => self.types.push(15),
-        }
+        self.types.push(5 + self.dim.type_id_offset());
     }
 
     /// Add a new MultiPolygon to the end of this array.
@@ -401,10 +387,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_multi_polygon_type(&mut self) {
         self.offsets
             .push(self.multi_polygons.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(6),
-            Dimension::XYZ => self.types.push(16),
-        }
+        self.types.push(6 + self.dim.type_id_offset());
     }
 
     #[inline]
@@ -442,9 +425,35 @@ impl<'a> MixedGeometryBuilder {
         Ok(())
     }
 
+    /// Push a null to this builder
+    ///
+    /// A union has no validity buffer of its own, so the null is pushed to one of the underlying
+    /// non-empty arrays, to simplify downcasting. If all of them are empty, it is pushed to the
+    /// points.
     #[inline]
     pub fn push_null(&mut self) {
-        todo!("push null geometry")
+        if !self.points.is_empty() {
+            self.add_point_type();
+            self.points.push_null();
+        } else if !self.line_strings.is_empty() {
+            self.add_line_string_type();
+            self.line_strings.push_null();
+        } else if !self.polygons.is_empty() {
+            self.add_polygon_type();
+            self.polygons.push_null();
+        } else if !self.multi_points.is_empty() {
+            self.add_multi_point_type();
+            self.multi_points.push_null();
+        } else if !self.multi_line_strings.is_empty() {
+            self.add_multi_line_string_type();
+            self.multi_line_strings.push_null();
+        } else if !self.multi_polygons.is_empty() {
+            self.add_multi_polygon_type();
+            self.multi_polygons.push_null();
+        } else {
+            self.add_point_type();
+            self.points.push_null();
+        }
     }
 
     /// Extend this builder with the given geometries
@@ -511,6 +520,8 @@ impl<'a> MixedGeometryBuilder {
     }
 }
 
+impl_push_wkb_wkt!(MixedGeometryBuilder);
+
 impl Default for MixedGeometryBuilder {
     fn default() -> Self {
         Self::new(Dimension::XY)
@@ -56,7 +56,7 @@
 pub use binary::{WKBArray, WKBBuilder, WKBCapacity};
 pub use cast::{AsChunkedNativeArray, AsNativeArray, AsSerializedArray};
 pub use coord::{
-    CoordBuffer, CoordBufferBuilder, CoordType, InterleavedCoordBuffer,
+    CoordBuffer, CoordBufferBuilder, CoordType, F32CoordBuffer, InterleavedCoordBuffer,
     InterleavedCoordBufferBuilder, SeparatedCoordBuffer, SeparatedCoordBufferBuilder,
 };
 pub use dynamic::{NativeArrayDyn, SerializedArrayDyn};
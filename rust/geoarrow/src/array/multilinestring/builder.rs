@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multilinestring::MultiLineStringCapacity;
+use crate::array::util::impl_push_wkb_wkt;
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -425,6 +426,8 @@ impl MultiLineStringBuilder {
     }
 }
 
+impl_push_wkb_wkt!(MultiLineStringBuilder);
+
 impl GeometryArrayBuilder for MultiLineStringBuilder {
     fn new(dim: Dimension) -> Self {
         Self::new(dim)
@@ -107,6 +107,35 @@ impl MultiPointArray {
         })
     }
 
+    /// Create a new MultiPointArray from parts without validating that the parts are consistent.
+    ///
+    /// This is useful when the parts are known to be valid, e.g. when they have been produced by
+    /// this crate and persisted to disk, and avoids the cost of validation.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the parts pass the checks performed by [`Self::try_new`].
+    /// Accessing values of an inconsistent array may read out of bounds.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<i32>,
+        validity: Option<NullBuffer>,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let data_type = NativeType::MultiPoint(coords.coord_type(), coords.dim());
+        Self {
+            data_type,
+            coords,
+            geom_offsets,
+            validity,
+            metadata,
+        }
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("points", self.coords.storage_type(), false).into()
     }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipoint::MultiPointCapacity;
+use crate::array::util::impl_push_wkb_wkt;
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -364,6 +365,8 @@ impl MultiPointBuilder {
     }
 }
 
+impl_push_wkb_wkt!(MultiPointBuilder);
+
 impl Default for MultiPointBuilder {
     fn default() -> Self {
         Self::new(Dimension::XY)
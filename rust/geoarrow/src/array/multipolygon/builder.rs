@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipolygon::MultiPolygonCapacity;
+use crate::array::util::impl_push_wkb_wkt;
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::{
@@ -485,6 +486,8 @@ impl MultiPolygonBuilder {
     }
 }
 
+impl_push_wkb_wkt!(MultiPolygonBuilder);
+
 impl Default for MultiPolygonBuilder {
     fn default() -> Self {
         Self::new(Dimension::XY)
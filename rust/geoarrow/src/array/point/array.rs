@@ -85,6 +85,33 @@ impl PointArray {
         })
     }
 
+    /// Create a new PointArray from parts without validating that the parts are consistent.
+    ///
+    /// This is useful when the parts are known to be valid, e.g. when they have been produced by
+    /// this crate and persisted to disk, and avoids the cost of validation.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the parts pass the checks performed by [`Self::try_new`].
+    /// Accessing values of an inconsistent array may read out of bounds.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        validity: Option<NullBuffer>,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let data_type = NativeType::Point(coords.coord_type(), coords.dim());
+        Self {
+            data_type,
+            coords,
+            validity,
+            metadata,
+        }
+    }
+
     /// Access the underlying coordinate buffer
     ///
     /// Note that some coordinates may be null, depending on the value of [`Self::nulls`]
@@ -2,6 +2,7 @@ use core::f64;
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::impl_push_wkb_wkt;
 // use super::array::check;
 use crate::array::{
     CoordBufferBuilder, CoordType, InterleavedCoordBufferBuilder, PointArray,
@@ -290,6 +291,8 @@ impl PointBuilder {
     }
 }
 
+impl_push_wkb_wkt!(PointBuilder);
+
 impl GeometryArrayBuilder for PointBuilder {
     fn new(dim: Dimension) -> Self {
         Self::new(dim)
@@ -131,6 +131,37 @@ impl PolygonArray {
         })
     }
 
+    /// Create a new PolygonArray from parts without validating that the parts are consistent.
+    ///
+    /// This is useful when the parts are known to be valid, e.g. when they have been produced by
+    /// this crate and persisted to disk, and avoids the cost of validation.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the parts pass the checks performed by [`Self::try_new`].
+    /// Accessing values of an inconsistent array may read out of bounds.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<i32>,
+        ring_offsets: OffsetBuffer<i32>,
+        validity: Option<NullBuffer>,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Self {
+        let data_type = NativeType::Polygon(coords.coord_type(), coords.dim());
+        Self {
+            data_type,
+            coords,
+            geom_offsets,
+            ring_offsets,
+            validity,
+            metadata,
+        }
+    }
+
     fn vertices_field(&self) -> Arc<Field> {
         Field::new("vertices", self.coords.storage_type(), false).into()
     }
@@ -484,13 +515,16 @@ impl From<RectArray> for PolygonArray {
         let coord_capacity = (value.len() - value.null_count()) * 5;
 
         let capacity = PolygonCapacity::new(coord_capacity, ring_capacity, geom_capacity);
-        let mut output_array = PolygonBuilder::with_capacity(dim, capacity);
+        let mut output_array = PolygonBuilder::with_capacity_and_options(
+            dim,
+            capacity,
+            Default::default(),
+            value.metadata(),
+        );
 
-        value.iter_geo().for_each(|maybe_g| {
-            output_array
-                .push_polygon(maybe_g.map(|geom| geom.to_polygon()).as_ref())
-                .unwrap()
-        });
+        value
+            .iter()
+            .for_each(|maybe_g| output_array.push_rect(maybe_g.as_ref()).unwrap());
 
         output_array.into()
     }
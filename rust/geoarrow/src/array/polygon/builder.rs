@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::impl_push_wkb_wkt;
 // use super::array::check;
 use crate::array::offset_builder::OffsetsBuilder;
 use crate::array::polygon::PolygonCapacity;
@@ -45,6 +46,40 @@ pub struct PolygonBuilder {
     pub(crate) validity: NullBufferBuilder,
 }
 
+/// A corner of the exterior ring of a polygon created from a rect.
+struct RectCorner {
+    x: f64,
+    y: f64,
+    z: Option<f64>,
+    m: Option<f64>,
+}
+
+impl CoordTrait for RectCorner {
+    type T = f64;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        Dimension::from_flags(self.z.is_some(), self.m.is_some()).into()
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        match (n, self.z, self.m) {
+            (0, _, _) => self.x,
+            (1, _, _) => self.y,
+            (2, Some(z), _) => z,
+            (2, None, Some(m)) | (3, Some(_), Some(m)) => m,
+            _ => panic!("Coordinate index {n} out of range"),
+        }
+    }
+
+    fn x(&self) -> Self::T {
+        self.x
+    }
+
+    fn y(&self) -> Self::T {
+        self.y
+    }
+}
+
 impl PolygonBuilder {
     /// Creates a new empty [`PolygonBuilder`].
     pub fn new(dim: Dimension) -> Self {
@@ -292,29 +327,28 @@ impl PolygonBuilder {
             let lower = rect.min();
             let upper = rect.max();
 
+            // A polygon with Z or M values is flat at the lower Z and M bounds of the rect
+            let dim = self.coords.dim();
+            let z = dim
+                .z_index()
+                .map(|index| lower.nth(index).unwrap_or(f64::NAN));
+            let m = dim
+                .m_index()
+                .map(|index| lower.nth(index).unwrap_or(f64::NAN));
+
             // Ref below because I always forget the ordering
             // https://github.com/georust/geo/blob/76ad2a358bd079e9d47b1229af89608744d2635b/geo-types/src/geometry/rect.rs#L217-L225
-
-            self.coords.push_coord(&geo::Coord {
-                x: lower.x(),
-                y: lower.y(),
-            });
-            self.coords.push_coord(&geo::Coord {
-                x: lower.x(),
-                y: upper.y(),
-            });
-            self.coords.push_coord(&geo::Coord {
-                x: upper.x(),
-                y: upper.y(),
-            });
-            self.coords.push_coord(&geo::Coord {
-                x: upper.x(),
-                y: lower.y(),
-            });
-            self.coords.push_coord(&geo::Coord {
-                x: lower.x(),
-                y: lower.y(),
-            });
+            let corners = [
+                (lower.x(), lower.y()),
+                (lower.x(), upper.y()),
+                (upper.x(), upper.y()),
+                (upper.x(), lower.y()),
+                (lower.x(), lower.y()),
+            ];
+            for (x, y) in corners {
+                self.coords.push_coord(&RectCorner { x, y, z, m });
+            }
+            self.validity.append(true);
         } else {
             self.push_null();
         }
@@ -452,6 +486,8 @@ impl PolygonBuilder {
     }
 }
 
+impl_push_wkb_wkt!(PolygonBuilder);
+
 impl Default for PolygonBuilder {
     fn default() -> Self {
         Self::new(Dimension::XY)
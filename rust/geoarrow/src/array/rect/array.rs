@@ -169,8 +169,9 @@ impl GeometryArraySelfMethods for RectArray {
         unimplemented!()
     }
 
+    /// Rect arrays always store separated coordinates, so this returns the array unchanged.
     fn into_coord_type(self, _coord_type: CoordType) -> Self {
-        unimplemented!()
+        self
     }
 }
 
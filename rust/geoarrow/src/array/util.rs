@@ -5,6 +5,44 @@ use arrow_buffer::OffsetBuffer;
 
 use crate::error::Result;
 
+/// Implement `push_wkb` and `push_wkt` for a geometry builder in terms of its `push_geometry` and
+/// `push_null` methods.
+macro_rules! impl_push_wkb_wkt {
+    ($builder:ty) => {
+        impl $builder {
+            /// Add a new geometry to this builder from its WKB encoding.
+            ///
+            /// This will error if the WKB is invalid, or if
+            /// [`push_geometry`][Self::push_geometry] would error for its geometry type.
+            #[inline]
+            pub fn push_wkb(&mut self, value: Option<&[u8]>) -> $crate::error::Result<()> {
+                let Some(buf) = value else {
+                    self.push_null();
+                    return Ok(());
+                };
+                self.push_geometry(Some(&wkb::reader::read_wkb(buf)?))
+            }
+
+            /// Add a new geometry to this builder from its WKT representation.
+            ///
+            /// This will error if the WKT is invalid, or if
+            /// [`push_geometry`][Self::push_geometry] would error for its geometry type.
+            #[inline]
+            pub fn push_wkt(&mut self, value: Option<&str>) -> $crate::error::Result<()> {
+                let Some(text) = value else {
+                    self.push_null();
+                    return Ok(());
+                };
+                let geometry = <wkt::Wkt<f64> as std::str::FromStr>::from_str(text)
+                    .map_err($crate::error::GeoArrowError::WktStrError)?;
+                self.push_geometry(Some(&geometry))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_push_wkb_wkt;
+
 pub(crate) fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
     let i64_offsets = offsets.iter().map(|x| *x as i64).collect::<Vec<_>>();
     unsafe { OffsetBuffer::new_unchecked(i64_offsets.into()) }
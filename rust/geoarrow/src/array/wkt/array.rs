@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow::array::AsArray;
@@ -6,6 +7,7 @@ use arrow_array::{
 };
 use arrow_buffer::NullBuffer;
 use arrow_schema::{DataType, Field};
+use geo_traits::GeometryTrait;
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
@@ -78,6 +80,26 @@ impl<O: OffsetSizeTrait> WKTArray<O> {
         arr.metadata = metadata;
         arr
     }
+
+    /// Parse every geometry up front, returning an infallible iterator over the parsed geometries
+    /// that considers validity.
+    ///
+    /// Returns an error for the first invalid geometry before iteration starts.
+    pub fn try_iter_geometry(
+        &self,
+    ) -> Result<impl ExactSizeIterator<Item = Option<impl GeometryTrait<T = f64>>>> {
+        let geometries = (0..self.len())
+            .map(|i| {
+                if self.is_null(i) {
+                    return Ok(None);
+                }
+                wkt::Wkt::<f64>::from_str(self.array.value(i))
+                    .map(Some)
+                    .map_err(|err| GeoArrowError::General(format!("Invalid WKT at row {i}: {err}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(geometries.into_iter())
+    }
 }
 
 impl<O: OffsetSizeTrait> ArrayBase for WKTArray<O> {
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use arrow_array::Array;
 use arrow_schema::Field;
 
+use crate::algorithm::native::Cast;
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
 use crate::datatypes::NativeType;
@@ -75,6 +76,9 @@ impl ChunkedNativeArrayDyn {
 
     /// Creates a chunked geometry array from geoarrow chunks.
     ///
+    /// If the chunks have different types, they are all cast to their
+    /// [common type][NativeType::common_type_of].
+    ///
     /// # Examples
     ///
     /// ```
@@ -99,6 +103,23 @@ impl ChunkedNativeArrayDyn {
             data_types.insert(chunk.as_ref().data_type());
         });
 
+        if data_types.len() > 1 {
+            let common_type =
+                NativeType::common_type_of(chunks.iter().map(|chunk| chunk.data_type())).unwrap();
+            let casted = chunks
+                .iter()
+                .map(|chunk| chunk.cast(common_type))
+                .collect::<Result<Vec<_>>>()?;
+            data_types = casted.iter().map(|chunk| chunk.data_type()).collect();
+            if data_types.len() == 1 {
+                let casted_refs = casted
+                    .iter()
+                    .map(|chunk| chunk.as_ref())
+                    .collect::<Vec<_>>();
+                return Self::from_geoarrow_chunks(&casted_refs);
+            }
+        }
+
         if data_types.len() == 1 {
             macro_rules! impl_downcast {
                 ($cast_func:ident) => {
@@ -125,7 +146,10 @@ impl ChunkedNativeArrayDyn {
             };
             Ok(Self(result))
         } else {
-            Err(GeoArrowError::General(format!("Handling multiple geometry types in `from_geoarrow_chunks` not yet implemented. Received {:?}", data_types)))
+            Err(GeoArrowError::General(format!(
+                "Could not cast chunks to a common geometry type. Received {:?}",
+                data_types
+            )))
         }
     }
 
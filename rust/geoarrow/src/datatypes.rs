@@ -2,6 +2,8 @@
 //! crate.
 
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow_array::OffsetSizeTrait;
@@ -27,6 +29,12 @@ pub enum Dimension {
 
     /// Three-dimensional.
     XYZ,
+
+    /// Two-dimensional with a measure.
+    XYM,
+
+    /// Three-dimensional with a measure.
+    XYZM,
 }
 
 impl Dimension {
@@ -39,11 +47,102 @@ impl Dimension {
     ///
     /// assert_eq!(Dimension::XY.size(), 2);
     /// assert_eq!(Dimension::XYZ.size(), 3);
+    /// assert_eq!(Dimension::XYM.size(), 3);
+    /// assert_eq!(Dimension::XYZM.size(), 4);
     /// ```
     pub fn size(&self) -> usize {
         match self {
             Dimension::XY => 2,
-            Dimension::XYZ => 3,
+            Dimension::XYZ | Dimension::XYM => 3,
+            Dimension::XYZM => 4,
+        }
+    }
+
+    /// Whether this dimension has a Z value.
+    pub fn has_z(&self) -> bool {
+        matches!(self, Dimension::XYZ | Dimension::XYZM)
+    }
+
+    /// Whether this dimension has an M value.
+    pub fn has_m(&self) -> bool {
+        matches!(self, Dimension::XYM | Dimension::XYZM)
+    }
+
+    /// The dimension with or without Z and M values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// assert_eq!(Dimension::from_flags(true, true), Dimension::XYZM);
+    /// assert_eq!(Dimension::from_flags(false, true), Dimension::XYM);
+    /// ```
+    pub fn from_flags(has_z: bool, has_m: bool) -> Self {
+        match (has_z, has_m) {
+            (false, false) => Dimension::XY,
+            (true, false) => Dimension::XYZ,
+            (false, true) => Dimension::XYM,
+            (true, true) => Dimension::XYZM,
+        }
+    }
+
+    /// The position of the Z value within a coordinate of this dimension, if there is one.
+    pub(crate) fn z_index(&self) -> Option<usize> {
+        self.has_z().then_some(2)
+    }
+
+    /// The position of the M value within a coordinate of this dimension, if there is one.
+    pub(crate) fn m_index(&self) -> Option<usize> {
+        match self {
+            Dimension::XYM => Some(2),
+            Dimension::XYZM => Some(3),
+            _ => None,
+        }
+    }
+
+    /// Whether coordinates of the `geo_traits` dimension `dim` can be stored with this dimension.
+    ///
+    /// Coordinates of an unknown dimension are accepted as long as they have the same size.
+    pub(crate) fn accepts(&self, dim: geo_traits::Dimensions) -> bool {
+        match dim {
+            geo_traits::Dimensions::Unknown(size) => size == self.size(),
+            dim => Dimension::try_from(dim).is_ok_and(|dim| dim == *self),
+        }
+    }
+
+    /// The offset of this dimension in the type ids of union arrays, e.g. an XYZ point has the
+    /// type id `1 + 10`.
+    pub(crate) fn type_id_offset(&self) -> i8 {
+        match self {
+            Dimension::XY => 0,
+            Dimension::XYZ => 10,
+            Dimension::XYM => 20,
+            Dimension::XYZM => 30,
+        }
+    }
+
+    /// The dimension of a union type id.
+    pub(crate) fn from_type_id(type_id: i8) -> Result<Self> {
+        match type_id / 10 {
+            0 => Ok(Dimension::XY),
+            1 => Ok(Dimension::XYZ),
+            2 => Ok(Dimension::XYM),
+            3 => Ok(Dimension::XYZM),
+            _ => Err(GeoArrowError::General(format!(
+                "Unsupported union type id {type_id}"
+            ))),
+        }
+    }
+
+    /// The names of the values of a coordinate of this dimension, i.e. the field names of
+    /// separated coordinates.
+    pub(crate) fn names(&self) -> &'static [&'static str] {
+        match self {
+            Dimension::XY => &["x", "y"],
+            Dimension::XYZ => &["x", "y", "z"],
+            Dimension::XYM => &["x", "y", "m"],
+            Dimension::XYZM => &["x", "y", "z", "m"],
         }
     }
 }
@@ -54,7 +153,10 @@ impl TryFrom<usize> for Dimension {
     fn try_from(value: usize) -> std::result::Result<Self, Self::Error> {
         match value {
             2 => Ok(Dimension::XY),
+            // A size of 3 is ambiguous, and taken to be XYZ. XYM coordinates can only be told
+            // apart by their field names.
             3 => Ok(Dimension::XYZ),
+            4 => Ok(Dimension::XYZM),
             v => Err(GeoArrowError::General(format!("Unexpected array size {v}"))),
         }
     }
@@ -75,6 +177,8 @@ impl From<Dimension> for geo_traits::Dimensions {
         match value {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
+            Dimension::XYZM => geo_traits::Dimensions::Xyzm,
         }
     }
 }
@@ -86,6 +190,10 @@ impl TryFrom<geo_traits::Dimensions> for Dimension {
         match value {
             geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => Ok(Dimension::XY),
             geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => Ok(Dimension::XYZ),
+            geo_traits::Dimensions::Xym => Ok(Dimension::XYM),
+            geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
+                Ok(Dimension::XYZM)
+            }
             _ => Err(GeoArrowError::General(format!(
                 "Unsupported dimension {:?}",
                 value
@@ -94,6 +202,9 @@ impl TryFrom<geo_traits::Dimensions> for Dimension {
     }
 }
 
+/// The flag set on OGR geometry type codes of the legacy "2.5D" types, which have a Z dimension.
+const OGR_25D_FLAG: u32 = 0x8000_0000;
+
 /// A type enum representing "native" GeoArrow geometry types.
 ///
 /// This is designed to aid in downcasting from dynamically-typed geometry arrays.
@@ -184,28 +295,17 @@ pub enum AnyType {
 }
 
 pub(crate) fn coord_type_to_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
-    match (coord_type, dim) {
-        (CoordType::Interleaved, Dimension::XY) => {
-            let values_field = Field::new("xy", DataType::Float64, false);
-            DataType::FixedSizeList(Arc::new(values_field), 2)
-        }
-        (CoordType::Interleaved, Dimension::XYZ) => {
-            let values_field = Field::new("xyz", DataType::Float64, false);
-            DataType::FixedSizeList(Arc::new(values_field), 3)
+    match coord_type {
+        CoordType::Interleaved => {
+            let values_field = Field::new(dim.to_string(), DataType::Float64, false);
+            DataType::FixedSizeList(Arc::new(values_field), dim.size() as i32)
         }
-        (CoordType::Separated, Dimension::XY) => {
-            let values_fields = vec![
-                Field::new("x", DataType::Float64, false),
-                Field::new("y", DataType::Float64, false),
-            ];
-            DataType::Struct(values_fields.into())
-        }
-        (CoordType::Separated, Dimension::XYZ) => {
-            let values_fields = vec![
-                Field::new("x", DataType::Float64, false),
-                Field::new("y", DataType::Float64, false),
-                Field::new("z", DataType::Float64, false),
-            ];
+        CoordType::Separated => {
+            let values_fields = dim
+                .names()
+                .iter()
+                .map(|name| Field::new(*name, DataType::Float64, false))
+                .collect::<Vec<_>>();
             DataType::Struct(values_fields.into())
         }
     }
@@ -253,10 +353,7 @@ pub(crate) fn mixed_data_type(coord_type: CoordType, dim: Dimension) -> DataType
     let mut fields = vec![];
     let mut type_ids = vec![];
 
-    match dim {
-        Dimension::XY => type_ids.extend([1, 2, 3, 4, 5, 6]),
-        Dimension::XYZ => type_ids.extend([11, 12, 13, 14, 15, 16]),
-    }
+    type_ids.extend([1, 2, 3, 4, 5, 6].map(|type_id| type_id + dim.type_id_offset()));
 
     // Note: we manually construct the fields because these fields shouldn't have their own
     // GeoArrow extension metadata
@@ -305,114 +402,144 @@ fn wkt_data_type<O: OffsetSizeTrait>() -> DataType {
 }
 
 pub(crate) fn rect_fields(dim: Dimension) -> Fields {
-    let values_fields = match dim {
-        Dimension::XY => {
-            vec![
-                Field::new("xmin", DataType::Float64, false),
-                Field::new("ymin", DataType::Float64, false),
-                Field::new("xmax", DataType::Float64, false),
-                Field::new("ymax", DataType::Float64, false),
-            ]
-        }
-        Dimension::XYZ => {
-            vec![
-                Field::new("xmin", DataType::Float64, false),
-                Field::new("ymin", DataType::Float64, false),
-                Field::new("zmin", DataType::Float64, false),
-                Field::new("xmax", DataType::Float64, false),
-                Field::new("ymax", DataType::Float64, false),
-                Field::new("zmax", DataType::Float64, false),
-            ]
+    let names = dim.names();
+    let min_fields = names
+        .iter()
+        .map(|name| Field::new(format!("{name}min"), DataType::Float64, false));
+    let max_fields = names
+        .iter()
+        .map(|name| Field::new(format!("{name}max"), DataType::Float64, false));
+    min_fields.chain(max_fields).collect()
+}
+
+fn rect_data_type(dim: Dimension) -> DataType {
+    DataType::Struct(rect_fields(dim))
+}
+
+/// The union type id of a geometry type and dimension in a `geoarrow.geometry` array.
+///
+/// These follow the GeoArrow specification, which uses the ISO WKB geometry type codes: 1 to 7
+/// for `Point` to `GeometryCollection`, plus 10 for XYZ geometries. Note that these differ from
+/// the values of [`TypeIds`][crate::algorithm::native::TypeIds].
+///
+/// Returns `None` for [`NativeType::Rect`] and [`NativeType::Geometry`], which aren't children of
+/// the union.
+///
+/// ```
+/// use geoarrow::array::CoordType;
+/// use geoarrow::datatypes::{union_type_id, Dimension, NativeType};
+///
+/// let polygon = NativeType::Polygon(CoordType::Separated, Dimension::XYZ);
+/// assert_eq!(union_type_id(&polygon), Some(13));
+/// ```
+pub fn union_type_id(data_type: &NativeType) -> Option<i8> {
+    use NativeType::*;
+
+    let (geometry_type, dim) = match data_type {
+        Point(_, dim) => (1, dim),
+        LineString(_, dim) => (2, dim),
+        Polygon(_, dim) => (3, dim),
+        MultiPoint(_, dim) => (4, dim),
+        MultiLineString(_, dim) => (5, dim),
+        MultiPolygon(_, dim) => (6, dim),
+        GeometryCollection(_, dim) => (7, dim),
+        Rect(_) | Geometry(_) => return None,
+    };
+    Some(geometry_type + dim.type_id_offset())
+}
+
+/// The geometry type and dimension of a union type id, with the given coordinate type.
+///
+/// This is the inverse of [`union_type_id`].
+pub fn union_child_type(type_id: i8, coord_type: CoordType) -> Result<NativeType> {
+    let dim = Dimension::from_type_id(type_id)?;
+    let data_type = match type_id % 10 {
+        1 => NativeType::Point(coord_type, dim),
+        2 => NativeType::LineString(coord_type, dim),
+        3 => NativeType::Polygon(coord_type, dim),
+        4 => NativeType::MultiPoint(coord_type, dim),
+        5 => NativeType::MultiLineString(coord_type, dim),
+        6 => NativeType::MultiPolygon(coord_type, dim),
+        7 => NativeType::GeometryCollection(coord_type, dim),
+        _ => {
+            return Err(GeoArrowError::General(format!(
+                "Unsupported union type id {type_id}"
+            )))
         }
     };
+    Ok(data_type)
+}
 
-    values_fields.into()
+/// The child field of a `geoarrow.geometry` union for a union type id.
+///
+/// Child fields are nullable and unnamed, and have no extension metadata of their own.
+pub fn union_child_field(type_id: i8, coord_type: CoordType) -> Result<Field> {
+    let data_type = union_child_type(type_id, coord_type)?;
+    Ok(Field::new("", data_type.to_data_type(), true))
 }
 
-fn rect_data_type(dim: Dimension) -> DataType {
-    DataType::Struct(rect_fields(dim))
+/// The union type ids of every child of a `geoarrow.geometry` array, in order.
+pub const GEOMETRY_UNION_TYPE_IDS: [i8; 14] = [1, 2, 3, 4, 5, 6, 7, 11, 12, 13, 14, 15, 16, 17];
+
+/// The union fields of a `geoarrow.geometry` array, i.e. a child for every geometry type and
+/// dimension, with the given coordinate type.
+///
+/// The storage type of a `geoarrow.geometry` array is a dense union of these fields.
+pub fn geometry_union_fields(coord_type: CoordType) -> UnionFields {
+    let fields = GEOMETRY_UNION_TYPE_IDS
+        .iter()
+        .map(|type_id| union_child_field(*type_id, coord_type).unwrap());
+    UnionFields::new(GEOMETRY_UNION_TYPE_IDS, fields)
 }
 
-fn geometry_data_type(coord_type: CoordType) -> DataType {
-    let mut fields = vec![];
-    let type_ids = vec![1, 2, 3, 4, 5, 6, 7, 11, 12, 13, 14, 15, 16, 17];
+/// Check that a data type is a valid storage type for a `geoarrow.geometry` array, returning its
+/// coordinate type.
+///
+/// The data type must be a dense union, and each child must have the data type that
+/// [`union_child_field`] gives for its type id, with the same coordinate type for every child.
+/// Unions with only some of the children, such as arrays written by producers that only support
+/// 2D geometries, are valid.
+pub fn validate_geometry_union(data_type: &DataType) -> Result<CoordType> {
+    let DataType::Union(fields, mode) = data_type else {
+        return Err(GeoArrowError::General(format!(
+            "Expected a union data type, got {data_type}"
+        )));
+    };
+    if *mode != UnionMode::Dense {
+        return Err(GeoArrowError::General(
+            "Expected a dense union, got a sparse union".to_string(),
+        ));
+    }
 
-    // Note: we manually construct the fields because these fields shouldn't have their own
-    // GeoArrow extension metadata
-    fields.push(Field::new(
-        "",
-        NativeType::Point(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::LineString(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::Polygon(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPoint(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiLineString(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPolygon(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::GeometryCollection(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
+    let mut coord_type = None;
+    for (type_id, field) in fields.iter() {
+        let child_coord_type = [CoordType::Interleaved, CoordType::Separated]
+            .into_iter()
+            .find(|coord_type| {
+                union_child_field(type_id, *coord_type)
+                    .is_ok_and(|expected| expected.data_type() == field.data_type())
+            })
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Unexpected data type {} for union type id {type_id}",
+                    field.data_type()
+                ))
+            })?;
+        match coord_type {
+            Some(coord_type) if coord_type != child_coord_type => {
+                return Err(GeoArrowError::General(
+                    "Multiple coord types in union".to_string(),
+                ))
+            }
+            _ => coord_type = Some(child_coord_type),
+        }
+    }
 
-    fields.push(Field::new(
-        "",
-        NativeType::Point(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::LineString(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::Polygon(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPoint(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiLineString(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPolygon(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::GeometryCollection(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
+    coord_type.ok_or_else(|| GeoArrowError::General("Union has no children".to_string()))
+}
 
-    let union_fields = UnionFields::new(type_ids, fields);
-    DataType::Union(union_fields, UnionMode::Dense)
+fn geometry_data_type(coord_type: CoordType) -> DataType {
+    DataType::Union(geometry_union_fields(coord_type), UnionMode::Dense)
 }
 
 impl NativeType {
@@ -612,6 +739,201 @@ impl NativeType {
             Geometry(coord_type) => Geometry(coord_type),
         }
     }
+
+    /// Returns the narrowest type that can hold the geometries of both `self` and `other`.
+    ///
+    /// This is shorthand for [`NativeType::common_type_of`] with two types; see there for the
+    /// promotion rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::CoordType;
+    /// use geoarrow::datatypes::{Dimension, NativeType};
+    ///
+    /// let point = NativeType::Point(CoordType::Interleaved, Dimension::XY);
+    /// let multi_point = NativeType::MultiPoint(CoordType::Interleaved, Dimension::XY);
+    /// assert_eq!(point.common_type(&multi_point), multi_point);
+    ///
+    /// let line_string = NativeType::LineString(CoordType::Interleaved, Dimension::XY);
+    /// assert_eq!(
+    ///     point.common_type(&line_string),
+    ///     NativeType::Geometry(CoordType::Interleaved)
+    /// );
+    /// ```
+    pub fn common_type(&self, other: &NativeType) -> NativeType {
+        // Can't be None with two input types
+        NativeType::common_type_of([*self, *other]).unwrap()
+    }
+
+    /// Returns the narrowest type that can hold the geometries of all of `types`, or `None` if
+    /// `types` is empty.
+    ///
+    /// The rules are:
+    ///
+    /// - The coordinate type of the first input is used for the output.
+    /// - Types that differ only in their coordinate type are the same type.
+    /// - Single and multi geometries of the same kind, such as `Point` and `MultiPoint`, promote
+    ///   to the multi geometry.
+    /// - Any mix of point, line and polygon types promotes to `GeometryCollection` if a
+    ///   `GeometryCollection` is part of the input.
+    /// - Inputs with more than one dimension promote to the smallest dimension that has every Z
+    ///   and M value of the inputs, so `XY` and `XYZ` promote to `XYZ`, and `XYZ` and `XYM` to
+    ///   `XYZM`. [Casting][crate::algorithm::native::Cast] to the promoted type fills the new
+    ///   values with NaN.
+    /// - Any other mix, including `Rect` with any other type, promotes to `Geometry`.
+    ///
+    /// The result doesn't depend on the order of the input types, except for its coordinate
+    /// type. Folding [`NativeType::common_type`] over more than two types may give a wider type
+    /// than this function, because `GeometryCollection` absorbs other types only when it is part of
+    /// the same input.
+    pub fn common_type_of(types: impl IntoIterator<Item = NativeType>) -> Option<NativeType> {
+        use NativeType::*;
+
+        let mut types = types.into_iter().peekable();
+        let coord_type = types.peek()?.coord_type();
+
+        let mut unique: Vec<NativeType> = vec![];
+        for ty in types {
+            let ty = ty.with_coord_type(coord_type);
+            if !unique.contains(&ty) {
+                unique.push(ty);
+            }
+        }
+
+        if unique.len() == 1 {
+            return Some(unique[0]);
+        }
+
+        let geometry = Geometry(coord_type);
+        if unique.iter().any(|ty| matches!(ty, Geometry(_) | Rect(_))) {
+            return Some(geometry);
+        }
+        // There are at least two types, and none of them is Geometry or Rect
+        let dims = unique.iter().filter_map(|ty| ty.dimension());
+        let dim = Dimension::from_flags(
+            dims.clone().any(|dim| dim.has_z()),
+            dims.clone().any(|dim| dim.has_m()),
+        );
+        if unique
+            .iter()
+            .all(|ty| ty.with_dimension(dim) == unique[0].with_dimension(dim))
+        {
+            return Some(unique[0].with_dimension(dim));
+        }
+
+        let all = |f: fn(&NativeType) -> bool| unique.iter().all(f);
+        let output = if all(|ty| matches!(ty, Point(_, _) | MultiPoint(_, _))) {
+            MultiPoint(coord_type, dim)
+        } else if all(|ty| matches!(ty, LineString(_, _) | MultiLineString(_, _))) {
+            MultiLineString(coord_type, dim)
+        } else if all(|ty| matches!(ty, Polygon(_, _) | MultiPolygon(_, _))) {
+            MultiPolygon(coord_type, dim)
+        } else if unique
+            .iter()
+            .any(|ty| matches!(ty, GeometryCollection(_, _)))
+        {
+            GeometryCollection(coord_type, dim)
+        } else {
+            geometry
+        };
+        Some(output)
+    }
+
+    /// Converts an [OGR geometry type
+    /// code](https://gdal.org/en/stable/api/vector_c_api.html#_CPPv418OGRwkbGeometryType), as
+    /// used by GDAL, shapefiles and GeoPackage, to a [`NativeType`] with the given coordinate type.
+    ///
+    /// Both the legacy "2.5D" flag (`0x80000000`, e.g. `wkbPoint25D`) and the ISO `+1000` offset
+    /// (e.g. `wkbPointZ = 1001`) are accepted for Z. `wkbUnknown` maps to
+    /// [`NativeType::Geometry`].
+    ///
+    /// Measured types use the ISO `+2000` (M) and `+3000` (ZM) offsets. Curved types are not
+    /// supported.
+    ///
+    /// ```
+    /// use geoarrow::array::CoordType;
+    /// use geoarrow::datatypes::{Dimension, NativeType};
+    ///
+    /// let polygon_25d = 0x80000003;
+    /// assert_eq!(
+    ///     NativeType::from_ogr_type_code(polygon_25d, CoordType::Interleaved).unwrap(),
+    ///     NativeType::Polygon(CoordType::Interleaved, Dimension::XYZ)
+    /// );
+    /// assert_eq!(
+    ///     NativeType::from_ogr_type_code(1003, CoordType::Interleaved).unwrap(),
+    ///     NativeType::Polygon(CoordType::Interleaved, Dimension::XYZ)
+    /// );
+    /// ```
+    pub fn from_ogr_type_code(code: u32, coord_type: CoordType) -> Result<Self> {
+        use NativeType::*;
+
+        let (base, dim) = if code & OGR_25D_FLAG != 0 {
+            (code & !OGR_25D_FLAG, Dimension::XYZ)
+        } else {
+            match code / 1000 {
+                0 => (code, Dimension::XY),
+                1 => (code % 1000, Dimension::XYZ),
+                2 => (code % 1000, Dimension::XYM),
+                3 => (code % 1000, Dimension::XYZM),
+                _ => {
+                    return Err(GeoArrowError::General(format!(
+                        "Invalid OGR geometry type {code}"
+                    )))
+                }
+            }
+        };
+
+        let data_type = match base {
+            0 => Geometry(coord_type),
+            1 => Point(coord_type, dim),
+            2 => LineString(coord_type, dim),
+            3 => Polygon(coord_type, dim),
+            4 => MultiPoint(coord_type, dim),
+            5 => MultiLineString(coord_type, dim),
+            6 => MultiPolygon(coord_type, dim),
+            7 => GeometryCollection(coord_type, dim),
+            8..=17 => {
+                return Err(GeoArrowError::NotYetImplemented(format!(
+                    "OGR geometry type {code} is a curved or surface type, which is not supported"
+                )))
+            }
+            _ => {
+                return Err(GeoArrowError::General(format!(
+                    "Invalid OGR geometry type {code}"
+                )))
+            }
+        };
+        Ok(data_type)
+    }
+
+    /// Converts this type to an [OGR geometry type
+    /// code](https://gdal.org/en/stable/api/vector_c_api.html#_CPPv418OGRwkbGeometryType).
+    ///
+    /// 3D types use the legacy "2.5D" flag, as GDAL does for these geometry types, e.g.
+    /// `wkbPoint25D = 0x80000001`, and measured types the ISO codes, e.g. `wkbPointM = 2001`.
+    /// [`NativeType::Rect`] maps to a polygon, which is how boxes are written to OGR formats, and
+    /// [`NativeType::Geometry`] maps to `wkbUnknown`.
+    pub fn to_ogr_type_code(&self) -> u32 {
+        use NativeType::*;
+
+        let base = match self {
+            Geometry(_) => return 0,
+            Point(_, _) => 1,
+            LineString(_, _) => 2,
+            Polygon(_, _) | Rect(_) => 3,
+            MultiPoint(_, _) => 4,
+            MultiLineString(_, _) => 5,
+            MultiPolygon(_, _) => 6,
+            GeometryCollection(_, _) => 7,
+        };
+        match self.dimension() {
+            Some(Dimension::XYZ) => base | OGR_25D_FLAG,
+            Some(Dimension::XYM) => base + 2000,
+            Some(Dimension::XYZM) => base + 3000,
+            _ => base,
+        }
+    }
 }
 
 impl SerializedType {
@@ -716,13 +1038,233 @@ impl AnyType {
     }
 }
 
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dimension::XY => write!(f, "xy"),
+            Dimension::XYZ => write!(f, "xyz"),
+            Dimension::XYM => write!(f, "xym"),
+            Dimension::XYZM => write!(f, "xyzm"),
+        }
+    }
+}
+
+impl FromStr for Dimension {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "xy" => Ok(Dimension::XY),
+            "xyz" => Ok(Dimension::XYZ),
+            "xym" => Ok(Dimension::XYM),
+            "xyzm" => Ok(Dimension::XYZM),
+            other => Err(GeoArrowError::General(format!(
+                "Unknown dimension '{other}', expected 'xy', 'xyz', 'xym' or 'xyzm'"
+            ))),
+        }
+    }
+}
+
+/// Split a type specification such as `point<xy, interleaved>` into its lowercased name and its
+/// parameters. A `geoarrow.` prefix is removed from the name, so that extension names are
+/// accepted too.
+fn split_type_spec(spec: &str) -> Result<(String, Vec<&str>)> {
+    let spec = spec.trim();
+    let (name, params) = match spec.split_once('<') {
+        Some((name, rest)) => {
+            let params = rest.trim_end().strip_suffix('>').ok_or_else(|| {
+                GeoArrowError::General(format!("Missing closing '>' in type '{spec}'"))
+            })?;
+            let params = params
+                .split(',')
+                .map(str::trim)
+                .filter(|param| !param.is_empty())
+                .collect();
+            (name, params)
+        }
+        None => (spec, vec![]),
+    };
+    let name = name.trim().to_ascii_lowercase();
+    let name = match name.strip_prefix("geoarrow.") {
+        Some(name) => name.to_string(),
+        None => name,
+    };
+    Ok((name, params))
+}
+
+/// Displays a type in the compact syntax parsed by [`FromStr`], such as `point<xy, interleaved>`,
+/// `box<xyz>` or `geometry<separated>`.
+impl Display for NativeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use NativeType::*;
+        match self {
+            Point(ct, dim) => write!(f, "point<{dim}, {ct}>"),
+            LineString(ct, dim) => write!(f, "linestring<{dim}, {ct}>"),
+            Polygon(ct, dim) => write!(f, "polygon<{dim}, {ct}>"),
+            MultiPoint(ct, dim) => write!(f, "multipoint<{dim}, {ct}>"),
+            MultiLineString(ct, dim) => write!(f, "multilinestring<{dim}, {ct}>"),
+            MultiPolygon(ct, dim) => write!(f, "multipolygon<{dim}, {ct}>"),
+            GeometryCollection(ct, dim) => write!(f, "geometrycollection<{dim}, {ct}>"),
+            Rect(dim) => write!(f, "box<{dim}>"),
+            Geometry(ct) => write!(f, "geometry<{ct}>"),
+        }
+    }
+}
+
+/// Parses a type from the syntax written by [`Display`].
+///
+/// Names and parameters are case-insensitive, parameters can be given in any order, and the name
+/// can be a GeoArrow extension name such as `geoarrow.point`. An omitted dimension defaults to
+/// [`Dimension::XY`] and an omitted coordinate type to [`CoordType::default`].
+///
+/// ```
+/// use geoarrow::array::CoordType;
+/// use geoarrow::datatypes::{Dimension, NativeType};
+///
+/// let data_type: NativeType = "point<xyz, separated>".parse().unwrap();
+/// assert_eq!(data_type, NativeType::Point(CoordType::Separated, Dimension::XYZ));
+/// assert_eq!(data_type.to_string(), "point<xyz, separated>");
+/// assert_eq!("box".parse::<NativeType>().unwrap(), NativeType::Rect(Dimension::XY));
+/// ```
+impl FromStr for NativeType {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, params) = split_type_spec(s)?;
+
+        let mut dim = None;
+        let mut coord_type = None;
+        for param in params {
+            let duplicate = if let Ok(value) = param.parse::<Dimension>() {
+                dim.replace(value).is_some()
+            } else if let Ok(value) = param.parse::<CoordType>() {
+                coord_type.replace(value).is_some()
+            } else {
+                return Err(GeoArrowError::General(format!(
+                    "Unknown parameter '{param}' in type '{s}', expected a dimension or coordinate type"
+                )));
+            };
+            if duplicate {
+                return Err(GeoArrowError::General(format!(
+                    "Duplicate parameter '{param}' in type '{s}'"
+                )));
+            }
+        }
+
+        let ct = coord_type.unwrap_or_default();
+        let d = dim.unwrap_or(Dimension::XY);
+        let data_type = match name.as_str() {
+            "point" => NativeType::Point(ct, d),
+            "linestring" => NativeType::LineString(ct, d),
+            "polygon" => NativeType::Polygon(ct, d),
+            "multipoint" => NativeType::MultiPoint(ct, d),
+            "multilinestring" => NativeType::MultiLineString(ct, d),
+            "multipolygon" => NativeType::MultiPolygon(ct, d),
+            "geometrycollection" => NativeType::GeometryCollection(ct, d),
+            "box" | "rect" => {
+                if coord_type.is_some() {
+                    return Err(GeoArrowError::General(format!(
+                        "Type '{s}' does not take a coordinate type"
+                    )));
+                }
+                NativeType::Rect(d)
+            }
+            "geometry" => {
+                if dim.is_some() {
+                    return Err(GeoArrowError::General(format!(
+                        "Type '{s}' does not take a dimension"
+                    )));
+                }
+                NativeType::Geometry(ct)
+            }
+            _ => {
+                return Err(GeoArrowError::General(format!(
+                    "Unknown native geometry type '{s}'"
+                )))
+            }
+        };
+        Ok(data_type)
+    }
+}
+
+impl Display for SerializedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use SerializedType::*;
+        match self {
+            WKB => write!(f, "wkb"),
+            LargeWKB => write!(f, "large_wkb"),
+            WKT => write!(f, "wkt"),
+            LargeWKT => write!(f, "large_wkt"),
+        }
+    }
+}
+
+impl FromStr for SerializedType {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, params) = split_type_spec(s)?;
+        if !params.is_empty() {
+            return Err(GeoArrowError::General(format!(
+                "Type '{s}' does not take parameters"
+            )));
+        }
+        match name.as_str() {
+            "wkb" => Ok(SerializedType::WKB),
+            "large_wkb" => Ok(SerializedType::LargeWKB),
+            "wkt" => Ok(SerializedType::WKT),
+            "large_wkt" => Ok(SerializedType::LargeWKT),
+            _ => Err(GeoArrowError::General(format!(
+                "Unknown serialized geometry type '{s}'"
+            ))),
+        }
+    }
+}
+
+impl Display for AnyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Native(x) => x.fmt(f),
+            Self::Serialized(x) => x.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AnyType {
+    type Err = GeoArrowError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.parse::<SerializedType>() {
+            Ok(serialized) => Ok(Self::Serialized(serialized)),
+            Err(_) => Ok(Self::Native(s.parse()?)),
+        }
+    }
+}
+
 fn parse_data_type(data_type: &DataType) -> Result<(CoordType, Dimension)> {
     match data_type {
-        DataType::FixedSizeList(_, list_size) => {
-            Ok((CoordType::Interleaved, (*list_size).try_into()?))
+        DataType::FixedSizeList(inner_field, list_size) => {
+            // The field name tells XYZ and XYM apart, as both have three values
+            let dim = match inner_field.name().as_str() {
+                "xym" => Dimension::XYM,
+                _ => (*list_size).try_into()?,
+            };
+            if dim.size() != *list_size as usize {
+                return Err(GeoArrowError::General(format!(
+                    "Interleaved coordinates named '{}' must have {} values, got {list_size}",
+                    inner_field.name(),
+                    dim.size()
+                )));
+            }
+            Ok((CoordType::Interleaved, dim))
         }
         DataType::Struct(struct_fields) => {
-            Ok((CoordType::Separated, struct_fields.len().try_into()?))
+            let has_m = struct_fields.iter().any(|field| field.name() == "m");
+            let dim = match (struct_fields.len(), has_m) {
+                (3, true) => Dimension::XYM,
+                (len, _) => len.try_into()?,
+            };
+            Ok((CoordType::Separated, dim))
         }
         dt => Err(GeoArrowError::General(format!("Unexpected data type {dt}"))),
     }
@@ -928,6 +1470,26 @@ fn parse_mixed(field: &Field) -> Result<(CoordType, Dimension)> {
                         }
                         _ => unreachable!(),
                     },
+                    21..=27 | 31..=37 => {
+                        let data_type = match type_id % 10 {
+                            1 => parse_point(field)?,
+                            2 => parse_linestring(field)?,
+                            3 => parse_polygon(field)?,
+                            4 => parse_multi_point(field)?,
+                            5 => parse_multi_linestring(field)?,
+                            6 => parse_multi_polygon(field)?,
+                            _ => parse_geometry_collection(field)?,
+                        };
+                        let dim = Dimension::from_type_id(type_id)?;
+                        if data_type.dimension() != Some(dim) {
+                            return Err(GeoArrowError::General(format!(
+                                "Expected a child of dimension {dim:?} for type id {type_id}, \
+                                got {data_type:?}"
+                            )));
+                        }
+                        coord_types.insert(data_type.coord_type());
+                        dimensions.insert(dim);
+                    }
                     id => panic!("unexpected type id {}", id),
                 };
                 Ok::<_, GeoArrowError>(())
@@ -980,14 +1542,26 @@ fn parse_wkt(field: &Field) -> SerializedType {
     }
 }
 
-fn parse_rect(field: &Field) -> NativeType {
+fn parse_rect(field: &Field) -> Result<NativeType> {
     match field.data_type() {
-        DataType::Struct(struct_fields) => match struct_fields.len() {
-            4 => NativeType::Rect(Dimension::XY),
-            6 => NativeType::Rect(Dimension::XYZ),
-            _ => panic!("unexpected number of struct fields"),
-        },
-        _ => panic!("unexpected data type parsing rect"),
+        DataType::Struct(struct_fields) => {
+            let has_m = struct_fields.iter().any(|field| field.name() == "mmin");
+            let dim = match (struct_fields.len(), has_m) {
+                (4, false) => Dimension::XY,
+                (6, false) => Dimension::XYZ,
+                (6, true) => Dimension::XYM,
+                (8, true) => Dimension::XYZM,
+                (n, _) => {
+                    return Err(GeoArrowError::General(format!(
+                        "Unexpected number of struct fields for a box: {n}"
+                    )))
+                }
+            };
+            Ok(NativeType::Rect(dim))
+        }
+        dt => Err(GeoArrowError::General(format!(
+            "Unexpected data type for a box: {dt}"
+        ))),
     }
 }
 
@@ -1081,6 +1655,11 @@ fn parse_geometry(field: &Field) -> Result<NativeType> {
                     }
                     _ => unreachable!(),
                 },
+                21..=27 | 31..=37 => {
+                    return Err(GeoArrowError::NotYetImplemented(
+                        "Geometry arrays with an M dimension are not supported".to_string(),
+                    ))
+                }
                 id => panic!("unexpected type id {}", id),
             };
             Ok::<_, GeoArrowError>(())
@@ -1112,7 +1691,7 @@ impl TryFrom<&Field> for NativeType {
                 "geoarrow.multilinestring" => parse_multi_linestring(field)?,
                 "geoarrow.multipolygon" => parse_multi_polygon(field)?,
                 "geoarrow.geometrycollection" => parse_geometry_collection(field)?,
-                "geoarrow.box" => parse_rect(field),
+                "geoarrow.box" => parse_rect(field)?,
                 "geoarrow.geometry" => parse_geometry(field)?,
                 // We always parse geoarrow.geometry to a GeometryArray
                 // "geoarrow.geometry" => parse_mixed(field)?,
@@ -1124,12 +1703,10 @@ impl TryFrom<&Field> for NativeType {
             // metadata should use TryFrom for a specific geometry type directly, instead of using
             // GeometryArray
             let data_type = match field.data_type() {
-                DataType::Struct(struct_fields) => match struct_fields.len() {
-                    2 => NativeType::Point(CoordType::Separated, Dimension::XY),
-                    3 => NativeType::Point(CoordType::Separated, Dimension::XYZ),
-                    l => return Err(GeoArrowError::General(format!("incorrect number of struct fields {l}"))),
-                },
-                DataType::FixedSizeList(_, list_size) => NativeType::Point(CoordType::Interleaved, (*list_size as usize).try_into()?),
+                DataType::Struct(_) | DataType::FixedSizeList(_, _) => {
+                    let (coord_type, dim) = parse_data_type(field.data_type())?;
+                    NativeType::Point(coord_type, dim)
+                }
                 _ => return Err(GeoArrowError::General("Only FixedSizeList and Struct arrays are unambigously typed for a GeoArrow native type and can be used without extension metadata.".to_string())),
             };
             Ok(data_type)
@@ -1214,4 +1791,233 @@ mod test {
         let data_type: NativeType = field.as_ref().try_into().unwrap();
         assert_eq!(geom_array.data_type(), data_type);
     }
+
+    #[test]
+    fn parse_and_display() {
+        use CoordType::*;
+        use Dimension::*;
+        use NativeType::*;
+
+        for data_type in [
+            Point(Interleaved, XY),
+            LineString(Separated, XYZ),
+            Polygon(Interleaved, XYZ),
+            MultiPoint(Separated, XY),
+            MultiLineString(Interleaved, XY),
+            MultiPolygon(Separated, XYZ),
+            GeometryCollection(Interleaved, XY),
+            Rect(XYZ),
+            Geometry(Separated),
+        ] {
+            assert_eq!(
+                data_type.to_string().parse::<NativeType>().unwrap(),
+                data_type
+            );
+        }
+
+        assert_eq!(
+            " Geoarrow.Polygon < Separated,XYZ > "
+                .parse::<NativeType>()
+                .unwrap(),
+            Polygon(Separated, XYZ)
+        );
+        assert_eq!(
+            "multipoint".parse::<NativeType>().unwrap(),
+            MultiPoint(Interleaved, XY)
+        );
+        assert!("point<xy, xyz>".parse::<NativeType>().is_err());
+        assert!("box<xy, separated>".parse::<NativeType>().is_err());
+        assert!("point<xy".parse::<NativeType>().is_err());
+        assert!("curve".parse::<NativeType>().is_err());
+
+        assert_eq!(
+            "geoarrow.wkb".parse::<AnyType>().unwrap(),
+            AnyType::Serialized(SerializedType::WKB)
+        );
+        assert_eq!(SerializedType::LargeWKT.to_string(), "large_wkt");
+        assert_eq!(
+            "box<xy>".parse::<AnyType>().unwrap(),
+            AnyType::Native(Rect(XY))
+        );
+    }
+
+    #[test]
+    fn measure_dimensions() {
+        let extension = |name: &str| {
+            [("ARROW:extension:name".to_string(), name.to_string())]
+                .into_iter()
+                .collect::<HashMap<_, _>>()
+        };
+
+        // XYM and XYZ coordinates both have three values, and are told apart by their names
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            for dim in [Dimension::XYZ, Dimension::XYM, Dimension::XYZM] {
+                let point = NativeType::Point(coord_type, dim);
+                let field = point.to_field("geometry", true);
+                assert_eq!(NativeType::try_from(&field).unwrap(), point);
+            }
+        }
+
+        for dim in [Dimension::XYM, Dimension::XYZM] {
+            let field = Field::new("geometry", rect_data_type(dim), true)
+                .with_metadata(extension("geoarrow.box"));
+            assert_eq!(NativeType::try_from(&field).unwrap(), NativeType::Rect(dim));
+        }
+        assert_eq!(
+            rect_fields(Dimension::XYM)
+                .iter()
+                .map(|field| field.name().as_str())
+                .collect::<Vec<_>>(),
+            ["xmin", "ymin", "mmin", "xmax", "ymax", "mmax"]
+        );
+        assert_eq!(
+            "box<xyzm>".parse::<NativeType>().unwrap(),
+            NativeType::Rect(Dimension::XYZM)
+        );
+    }
+
+    #[test]
+    fn ogr_type_code() {
+        use CoordType::*;
+        use Dimension::*;
+        use NativeType::*;
+
+        for data_type in [
+            Point(Interleaved, XY),
+            LineString(Interleaved, XYZ),
+            MultiPolygon(Interleaved, XYZ),
+            Polygon(Interleaved, XYM),
+            MultiPoint(Interleaved, XYZM),
+            GeometryCollection(Interleaved, XY),
+            Geometry(Interleaved),
+        ] {
+            let code = data_type.to_ogr_type_code();
+            assert_eq!(
+                NativeType::from_ogr_type_code(code, Interleaved).unwrap(),
+                data_type
+            );
+        }
+
+        assert_eq!(Point(Separated, XYZ).to_ogr_type_code(), 0x80000001);
+        assert_eq!(Rect(XY).to_ogr_type_code(), 3);
+        assert_eq!(
+            NativeType::from_ogr_type_code(1005, Separated).unwrap(),
+            MultiLineString(Separated, XYZ)
+        );
+        assert_eq!(
+            NativeType::from_ogr_type_code(2001, Separated).unwrap(),
+            Point(Separated, XYM)
+        );
+        assert_eq!(Polygon(Separated, XYZM).to_ogr_type_code(), 3003);
+        assert!(NativeType::from_ogr_type_code(8, Separated).is_err());
+        assert!(NativeType::from_ogr_type_code(100, Separated).is_err());
+    }
+
+    #[test]
+    fn common_type() {
+        use CoordType::*;
+        use Dimension::*;
+        use NativeType::*;
+
+        assert_eq!(NativeType::common_type_of([]), None);
+        assert_eq!(
+            Point(Interleaved, XY).common_type(&Point(Separated, XY)),
+            Point(Interleaved, XY)
+        );
+        assert_eq!(
+            Polygon(Separated, XYZ).common_type(&MultiPolygon(Interleaved, XYZ)),
+            MultiPolygon(Separated, XYZ)
+        );
+        assert_eq!(
+            Point(Separated, XY).common_type(&Point(Separated, XYZ)),
+            Point(Separated, XYZ)
+        );
+        // Dimensions are promoted the same way in any order
+        for types in [
+            [Point(Separated, XY), MultiPoint(Separated, XYZ)],
+            [MultiPoint(Separated, XYZ), Point(Separated, XY)],
+        ] {
+            assert_eq!(
+                NativeType::common_type_of(types),
+                Some(MultiPoint(Separated, XYZ))
+            );
+        }
+        assert_eq!(
+            LineString(Separated, XYZ).common_type(&LineString(Separated, XYM)),
+            LineString(Separated, XYZM)
+        );
+        assert_eq!(
+            Point(Separated, XYZ).common_type(&LineString(Separated, XY)),
+            Geometry(Separated)
+        );
+        assert_eq!(
+            Rect(XY).common_type(&Polygon(Separated, XY)),
+            Geometry(Separated)
+        );
+        assert_eq!(
+            NativeType::common_type_of([
+                Point(Separated, XY),
+                LineString(Separated, XY),
+                GeometryCollection(Separated, XY),
+            ]),
+            Some(GeometryCollection(Separated, XY))
+        );
+        assert_eq!(
+            NativeType::common_type_of([
+                Point(Separated, XY),
+                MultiPoint(Separated, XY),
+                LineString(Separated, XY),
+            ]),
+            Some(Geometry(Separated))
+        );
+    }
+
+    #[test]
+    fn union_type_ids_round_trip() {
+        for type_id in GEOMETRY_UNION_TYPE_IDS {
+            let data_type = union_child_type(type_id, CoordType::Separated).unwrap();
+            assert_eq!(union_type_id(&data_type), Some(type_id));
+        }
+        assert!(union_child_type(8, CoordType::Separated).is_err());
+        assert!(union_child_type(21, CoordType::Separated).is_err());
+        assert_eq!(union_type_id(&NativeType::Rect(Dimension::XY)), None);
+    }
+
+    #[test]
+    fn validate_union() {
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            let data_type = NativeType::Geometry(coord_type).to_data_type();
+            assert_eq!(validate_geometry_union(&data_type).unwrap(), coord_type);
+        }
+
+        let xy_only = UnionFields::new(
+            [1, 3],
+            [
+                union_child_field(1, CoordType::Interleaved).unwrap(),
+                union_child_field(3, CoordType::Interleaved).unwrap(),
+            ],
+        );
+        let data_type = DataType::Union(xy_only.clone(), UnionMode::Dense);
+        assert_eq!(
+            validate_geometry_union(&data_type).unwrap(),
+            CoordType::Interleaved
+        );
+        let sparse = DataType::Union(xy_only, UnionMode::Sparse);
+        assert!(validate_geometry_union(&sparse).is_err());
+
+        let mixed_coord_types = UnionFields::new(
+            [1, 2],
+            [
+                union_child_field(1, CoordType::Interleaved).unwrap(),
+                union_child_field(2, CoordType::Separated).unwrap(),
+            ],
+        );
+        let data_type = DataType::Union(mixed_coord_types, UnionMode::Dense);
+        assert!(validate_geometry_union(&data_type).is_err());
+
+        let wrong_child =
+            UnionFields::new([2], [union_child_field(1, CoordType::Interleaved).unwrap()]);
+        let data_type = DataType::Union(wrong_child, UnionMode::Dense);
+        assert!(validate_geometry_union(&data_type).is_err());
+    }
 }
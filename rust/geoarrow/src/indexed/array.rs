@@ -81,6 +81,28 @@ impl<'a, G: NativeArray + ArrayAccessor<'a>> IndexedGeometryArray<G> {
         BooleanArray::new(buffer.finish(), nulls)
     }
 
+    /// Like [`unary_boolean`][Self::unary_boolean], for a scalar that may be empty and so have no
+    /// bounding box.
+    ///
+    /// An empty scalar intersects nothing, so every valid row is `false` and `op` is not called.
+    pub fn unary_boolean_or_empty<F>(
+        &'a self,
+        rhs_rect: Option<impl RectTrait<T = f64>>,
+        op: F,
+    ) -> BooleanArray
+    where
+        F: Fn(G::Item) -> bool,
+    {
+        match rhs_rect {
+            Some(rhs_rect) => self.unary_boolean(&rhs_rect, op),
+            None => {
+                let mut buffer = BooleanBufferBuilder::new(self.len());
+                buffer.append_n(self.len(), false);
+                BooleanArray::new(buffer.finish(), self.array.nulls().cloned())
+            }
+        }
+    }
+
     /// A helper function for boolean operations where it only applies `op` to pairs whose bounding
     /// boxes intersect.
     ///
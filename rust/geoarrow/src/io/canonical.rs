@@ -0,0 +1,112 @@
+//! Canonical text formatting shared by the text writers, so that the same data is always written
+//! to the same bytes.
+
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::error::{GeoArrowError, Result};
+
+/// The largest magnitude below which every integer is exactly representable as an `f64`.
+const MAX_EXACT_INTEGER: f64 = 9007199254740992.0;
+
+/// Write a number in canonical form.
+///
+/// Integral numbers that are exactly representable are written without a fraction, so that `-0`,
+/// `0` and `0.0` are all written as `0`. Other numbers are written in the shortest form that
+/// round-trips, as formatted by [`ryu`].
+pub(crate) fn write_f64<W: Write>(out: &mut W, value: f64) -> std::fmt::Result {
+    if value.fract() == 0.0 && value.abs() < MAX_EXACT_INTEGER {
+        write!(out, "{}", value as i64)
+    } else {
+        out.write_str(ryu::Buffer::new().format(value))
+    }
+}
+
+/// Rewrite a sequence of whitespace-separated JSON documents in canonical form, one document per
+/// line.
+///
+/// Object members are sorted by key, there is no insignificant whitespace, and numbers are
+/// written with [`write_f64`] unless they're integers.
+pub(crate) fn write_canonical_json<W: std::io::Write>(json: &[u8], mut writer: W) -> Result<()> {
+    let mut line = String::new();
+    for value in serde_json::Deserializer::from_slice(json).into_iter::<Value>() {
+        line.clear();
+        write_json_value(&mut line, &value?)
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        line.push('\n');
+        writer.write_all(line.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn write_json_value(out: &mut String, value: &Value) -> std::fmt::Result {
+    match value {
+        Value::Number(number) => {
+            if let Some(number) = number.as_i64() {
+                write!(out, "{number}")?;
+            } else if let Some(number) = number.as_u64() {
+                write!(out, "{number}")?;
+            } else if let Some(number) = number.as_f64() {
+                write_f64(out, number)?;
+            }
+        }
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_value(out, value)?;
+            }
+            out.push(']');
+        }
+        Value::Object(members) => {
+            let mut members = members.iter().collect::<Vec<_>>();
+            members.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+            out.push('{');
+            for (i, (key, value)) in members.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write!(out, "{}", Value::from(key.as_str()))?;
+                out.push(':');
+                write_json_value(out, value)?;
+            }
+            out.push('}');
+        }
+        Value::Null | Value::Bool(_) | Value::String(_) => write!(out, "{value}")?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn canonical_numbers() {
+        let format = |value| {
+            let mut out = String::new();
+            write_f64(&mut out, value).unwrap();
+            out
+        };
+        assert_eq!(format(1.0), "1");
+        assert_eq!(format(-0.0), "0");
+        assert_eq!(format(0.1), "0.1");
+        assert_eq!(format(-2.5), "-2.5");
+        assert_eq!(format(1e300), "1e300");
+    }
+
+    #[test]
+    fn canonical_json() {
+        let json = br#"{"b": [1.0, 2.50, -0], "a": {"y": null, "x": "s"}}
+            {"c": 1e2}"#;
+        let mut out = Vec::new();
+        write_canonical_json(json, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"a\":{\"x\":\"s\",\"y\":null},\"b\":[1,2.5,0]}\n{\"c\":100}\n"
+        );
+    }
+}
@@ -0,0 +1,526 @@
+//! A reusable converter of the geometry columns of record batches, meant to be embedded in
+//! services that convert many independent requests.
+//!
+//! [`GeoTableConverter`] owns a thread pool and its conversion settings, so that a server, e.g.
+//! behind Arrow Flight or HTTP, creates it once and shares it between requests. Cloning it is
+//! cheap and shares the thread pool.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use arrow_array::RecordBatch;
+//! use arrow_schema::Schema;
+//! use geoarrow::array::{CoordType, PointArray, WKBArray};
+//! use geoarrow::datatypes::{Dimension, NativeType};
+//! use geoarrow::io::convert::{GeoTableConverter, GeoTableConverterOptions};
+//! use geoarrow::io::wkb::to_wkb;
+//! use geoarrow::ArrayBase;
+//!
+//! let converter = GeoTableConverter::try_new(GeoTableConverterOptions {
+//!     target_type: Some(NativeType::Geometry(CoordType::Separated)),
+//!     precision: Some(6),
+//!     ..Default::default()
+//! })
+//! .unwrap();
+//!
+//! // A request with a WKB geometry column
+//! let points = vec![geo::point!(x: 1.23456789, y: 2.)];
+//! let points: PointArray = (points.as_slice(), Dimension::XY).into();
+//! let wkb: WKBArray<i32> = to_wkb(&points);
+//! let schema = Arc::new(Schema::new(vec![wkb.extension_field()]));
+//! let request_batch = RecordBatch::try_new(schema, vec![wkb.to_array_ref()]).unwrap();
+//!
+//! let batch = converter.convert_batch(&request_batch).unwrap();
+//! assert_eq!(
+//!     NativeType::try_from(batch.schema_ref().field(0)).unwrap(),
+//!     NativeType::Geometry(CoordType::Separated)
+//! );
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, RecordBatchIterator};
+use arrow_schema::{ArrowError, Field, FieldRef, Schema, SchemaRef};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::algorithm::native::kernel::has_z_or_m;
+use crate::algorithm::native::{Cast, Downcast, MapCoords};
+use crate::array::metadata::{ArrayMetadata, CrsStrictness};
+use crate::array::{CoordType, NativeArrayDyn, WKBArray, WKTArray};
+use crate::datatypes::{AnyType, Dimension, NativeType, SerializedType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::from_wkb;
+use crate::io::wkt::read_wkt;
+use crate::io::RecordBatchReader;
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
+use crate::NativeArray;
+
+/// Options for [`GeoTableConverter`].
+#[derive(Debug, Clone, Default)]
+pub struct GeoTableConverterOptions {
+    /// The type of every geometry column of the output. If `None`, native columns keep their
+    /// type and serialized (WKB and WKT) columns are parsed to geometry arrays.
+    pub target_type: Option<NativeType>,
+
+    /// The CRS of the output. Geometry columns in another CRS are transformed to it, which
+    /// requires the `proj` feature, and columns without a CRS are assumed to be in it.
+    pub crs: Option<ArrayMetadata>,
+
+    /// The number of decimal places to round coordinates to. A negative precision rounds to
+    /// tens, hundreds, and so on.
+    pub precision: Option<i32>,
+
+    /// The number of threads of the pool. If `None`, the number of logical CPUs.
+    pub num_threads: Option<usize>,
+
+    /// The most batches of a stream that are converted, or waiting to be read, at once. If
+    /// `None`, the number of threads of the pool.
+    pub max_in_flight: Option<usize>,
+}
+
+/// Converts the geometry columns of record batches with a thread pool and fixed settings.
+///
+/// Every geometry column, native or serialized, is converted to the type, CRS and precision of
+/// the [`GeoTableConverterOptions`]. Reprojected and rounded columns are two-dimensional, and
+/// rounding is only supported for two-dimensional input: rounding a column with Z or M values
+/// is an error, whether it's native or serialized. Other columns are passed through.
+#[derive(Clone)]
+pub struct GeoTableConverter {
+    pool: Arc<ThreadPool>,
+    options: Arc<GeoTableConverterOptions>,
+}
+
+impl GeoTableConverter {
+    /// Create a new converter with its own thread pool.
+    pub fn try_new(options: GeoTableConverterOptions) -> Result<Self> {
+        let mut builder =
+            ThreadPoolBuilder::new().thread_name(|index| format!("geoarrow-convert-{index}"));
+        if let Some(num_threads) = options.num_threads {
+            builder = builder.num_threads(num_threads);
+        }
+        let pool = builder
+            .build()
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        Ok(Self {
+            pool: Arc::new(pool),
+            options: Arc::new(options),
+        })
+    }
+
+    /// The settings of this converter.
+    pub fn options(&self) -> &GeoTableConverterOptions {
+        &self.options
+    }
+
+    /// The schema of the batches converted from batches with `schema`.
+    pub fn output_schema(&self, schema: &Schema) -> Result<SchemaRef> {
+        let plan = Plan::try_new(&self.options, schema)?;
+        Ok(plan.schema)
+    }
+
+    /// Convert a single batch on the thread pool, blocking until it's converted.
+    pub fn convert_batch(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let plan = Plan::try_new(&self.options, batch.schema_ref())?;
+        self.pool.install(|| plan.convert(batch))
+    }
+
+    /// Convert every batch of a table in parallel on the thread pool.
+    pub fn convert_table(&self, table: &Table) -> Result<Table> {
+        let plan = Plan::try_new(&self.options, table.schema())?;
+        let batches = self.pool.install(|| {
+            table
+                .batches()
+                .par_iter()
+                .map(|batch| plan.convert(batch))
+                .collect::<Result<Vec<_>>>()
+        })?;
+        Table::try_new(batches, plan.schema)
+    }
+
+    /// Lazily convert a stream of batches on the thread pool.
+    ///
+    /// Batches are converted in parallel and yielded in their input order. At most
+    /// [`GeoTableConverterOptions::max_in_flight`] batches are read ahead of the consumer, so a
+    /// slow consumer slows down reading the input rather than buffering it in memory.
+    pub fn convert_stream(&self, reader: RecordBatchReader) -> Result<RecordBatchReader> {
+        let plan = Arc::new(Plan::try_new(&self.options, &reader.schema())?);
+        let schema = plan.schema.clone();
+        let max_in_flight = self
+            .options
+            .max_in_flight
+            .unwrap_or_else(|| self.pool.current_num_threads())
+            .max(1);
+        let batches = ConvertStream {
+            input: Some(reader.into_inner()),
+            pool: self.pool.clone(),
+            plan,
+            pending: VecDeque::with_capacity(max_in_flight),
+            max_in_flight,
+        };
+        Ok(RecordBatchReader::new(Box::new(RecordBatchIterator::new(
+            batches, schema,
+        ))))
+    }
+}
+
+impl std::fmt::Debug for GeoTableConverter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeoTableConverter")
+            .field("num_threads", &self.pool.current_num_threads())
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+/// How to convert one geometry column.
+struct ColumnPlan {
+    index: usize,
+    field: FieldRef,
+    metadata: Arc<ArrayMetadata>,
+    data_type: NativeType,
+    /// The source and target PROJ definitions, if the column is reprojected.
+    reproject: Option<(String, String)>,
+}
+
+/// The conversion of batches with a given schema.
+struct Plan {
+    schema: SchemaRef,
+    columns: Vec<ColumnPlan>,
+    coord_type: CoordType,
+    precision: Option<i32>,
+}
+
+impl Plan {
+    fn try_new(options: &GeoTableConverterOptions, schema: &Schema) -> Result<Self> {
+        let coord_type = options
+            .target_type
+            .map(|target_type| target_type.coord_type())
+            .unwrap_or_default();
+
+        let mut fields = schema.fields().to_vec();
+        let mut columns = vec![];
+        for index in schema.geometry_columns() {
+            let field = schema.fields()[index].clone();
+            let input_type = AnyType::try_from(field.as_ref())?;
+            let mut metadata = ArrayMetadata::try_from(field.as_ref())?;
+            let reproject = match &options.crs {
+                Some(crs) => {
+                    let reproject = reprojection(&field, &metadata, crs)?;
+                    metadata.crs = crs.crs.clone();
+                    metadata.crs_type = crs.crs_type.clone();
+                    reproject
+                }
+                None => None,
+            };
+
+            let mut data_type = match (options.target_type, input_type) {
+                (Some(target_type), _) => target_type,
+                (None, AnyType::Native(native_type)) => native_type,
+                (None, AnyType::Serialized(_)) => NativeType::Geometry(coord_type),
+            };
+            if reproject.is_some() || options.precision.is_some() {
+                data_type = data_type.with_dimension(Dimension::XY);
+            }
+            // Serialized and geometry columns are checked when they're converted
+            if let AnyType::Native(native_type) = input_type {
+                let has_z_or_m = native_type
+                    .dimension()
+                    .is_some_and(|dim| dim != Dimension::XY);
+                if options.precision.is_some() && has_z_or_m {
+                    return Err(cannot_round(&field));
+                }
+            }
+
+            let output_field = Arc::new(data_type.to_field_with_metadata(
+                field.name(),
+                field.is_nullable(),
+                &metadata,
+            ));
+            fields[index] = output_field;
+            columns.push(ColumnPlan {
+                index,
+                field,
+                metadata: Arc::new(metadata),
+                data_type,
+                reproject,
+            });
+        }
+
+        Ok(Self {
+            schema: Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+            columns,
+            coord_type,
+            precision: options.precision,
+        })
+    }
+
+    fn convert(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let mut arrays = batch.columns().to_vec();
+        for column in &self.columns {
+            arrays[column.index] = self.convert_column(column, batch.column(column.index))?;
+        }
+        Ok(RecordBatch::try_new(self.schema.clone(), arrays)?)
+    }
+
+    fn convert_column(&self, column: &ColumnPlan, array: &ArrayRef) -> Result<ArrayRef> {
+        let mut array = match AnyType::try_from(column.field.as_ref())? {
+            AnyType::Native(_) => {
+                NativeArrayDyn::from_arrow_array(array, &column.field)?.into_inner()
+            }
+            AnyType::Serialized(_) => parse_serialized(array, &column.field, self.coord_type)?,
+        };
+
+        let transform = column.reproject.is_some() || self.precision.is_some();
+        if transform {
+            array = array.as_ref().downcast()?;
+        }
+        #[cfg(feature = "proj")]
+        if let Some((source, target)) = &column.reproject {
+            use crate::algorithm::proj::Reproject;
+
+            // PROJ objects can't be sent between threads, so every task creates its own
+            let proj = proj::Proj::new_known_crs(source, target, None)
+                .map_err(|err| GeoArrowError::General(err.to_string()))?;
+            array = array.reproject(&proj)?;
+        }
+        if let Some(precision) = self.precision {
+            if has_z_or_m(array.as_ref()) {
+                return Err(cannot_round(&column.field));
+            }
+            let scale = 10f64.powi(precision);
+            array = array.as_ref().map_coords(|coord| {
+                use geo_traits::CoordTrait;
+                geo::coord! {
+                    x: (coord.x() * scale).round() / scale,
+                    y: (coord.y() * scale).round() / scale,
+                }
+            })?;
+        }
+
+        if array.data_type() != column.data_type {
+            array = array.as_ref().cast(column.data_type)?;
+        }
+        Ok(array.with_metadata(column.metadata.clone()).to_array_ref())
+    }
+}
+
+fn cannot_round(field: &Field) -> GeoArrowError {
+    GeoArrowError::NotYetImplemented(format!(
+        "Rounding the geometry column {} with Z or M values",
+        field.name()
+    ))
+}
+
+/// The source and target PROJ definitions to transform a column to `crs`, or `None` if it's
+/// already in `crs` or has no CRS.
+fn reprojection(
+    field: &Field,
+    metadata: &ArrayMetadata,
+    crs: &ArrayMetadata,
+) -> Result<Option<(String, String)>> {
+    if metadata.crs.is_none() || metadata.crs_equivalent_to(crs, CrsStrictness::default()) {
+        return Ok(None);
+    }
+    if cfg!(not(feature = "proj")) {
+        return Err(GeoArrowError::General(format!(
+            "Transforming geometry column {} to another CRS requires the `proj` feature",
+            field.name()
+        )));
+    }
+    let missing = || GeoArrowError::General("The target CRS of the converter is missing".into());
+    let source = metadata.proj_definition()?.ok_or_else(missing)?;
+    let target = crs.proj_definition()?.ok_or_else(missing)?;
+    Ok(Some((source, target)))
+}
+
+/// Parse a WKB or WKT column to a geometry array.
+fn parse_serialized(
+    array: &ArrayRef,
+    field: &Field,
+    coord_type: CoordType,
+) -> Result<Arc<dyn NativeArray>> {
+    let target_type = NativeType::Geometry(coord_type);
+    match SerializedType::try_from(field)? {
+        SerializedType::WKB => from_wkb(
+            &WKBArray::<i32>::try_from((array.as_ref(), field))?,
+            target_type,
+            true,
+        ),
+        SerializedType::LargeWKB => from_wkb(
+            &WKBArray::<i64>::try_from((array.as_ref(), field))?,
+            target_type,
+            true,
+        ),
+        SerializedType::WKT => read_wkt(
+            &WKTArray::<i32>::try_from((array.as_ref(), field))?,
+            coord_type,
+            true,
+        ),
+        SerializedType::LargeWKT => read_wkt(
+            &WKTArray::<i64>::try_from((array.as_ref(), field))?,
+            coord_type,
+            true,
+        ),
+    }
+}
+
+/// The batches of [`GeoTableConverter::convert_stream`], converted in order with a bounded
+/// number of batches in flight.
+struct ConvertStream {
+    /// The input, until it's exhausted or fails.
+    input: Option<Box<dyn arrow_array::RecordBatchReader>>,
+    pool: Arc<ThreadPool>,
+    plan: Arc<Plan>,
+    pending: VecDeque<Receiver<Result<RecordBatch>>>,
+    max_in_flight: usize,
+}
+
+impl Iterator for ConvertStream {
+    type Item = std::result::Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.len() < self.max_in_flight {
+            let Some(input) = self.input.as_mut() else {
+                break;
+            };
+            let (sender, receiver) = sync_channel(1);
+            match input.next() {
+                Some(Ok(batch)) => {
+                    let plan = self.plan.clone();
+                    self.pool.spawn(move || {
+                        // The receiver is gone if the stream was dropped
+                        let _ = sender.send(plan.convert(&batch));
+                    });
+                }
+                Some(Err(err)) => {
+                    let _ = sender.send(Err(err.into()));
+                    self.input = None;
+                }
+                None => {
+                    self.input = None;
+                    break;
+                }
+            }
+            self.pending.push_back(receiver);
+        }
+
+        let result = self.pending.pop_front()?.recv().unwrap_or_else(|_| {
+            Err(GeoArrowError::General(
+                "A conversion task panicked".to_string(),
+            ))
+        });
+        Some(result.map_err(|err| ArrowError::ExternalError(Box::new(err))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Int32Array;
+    use geo::point;
+
+    use super::*;
+    use crate::array::{AsNativeArray, PointArray};
+    use crate::io::wkb::to_wkb;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    fn wkb_batch(offset: f64) -> RecordBatch {
+        let points: PointArray = (
+            vec![
+                point!(x: offset + 0.123, y: 1.987),
+                point!(x: offset + 2.5, y: 3.25),
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let wkb: WKBArray<i32> = to_wkb(&points);
+        let schema = Schema::new(vec![
+            Field::new("id", arrow_schema::DataType::Int32, false),
+            wkb.extension_field().as_ref().clone(),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int32Array::from(vec![1, 2])), wkb.to_array_ref()],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn convert_batch() {
+        let target_type = NativeType::Point(CoordType::Separated, Dimension::XY);
+        let converter = GeoTableConverter::try_new(GeoTableConverterOptions {
+            target_type: Some(target_type),
+            precision: Some(1),
+            num_threads: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let batch = converter.convert_batch(&wkb_batch(0.)).unwrap();
+        assert_eq!(batch.schema_ref().field(0).name(), "id");
+        let field = batch.schema_ref().field(1);
+        assert_eq!(NativeType::try_from(field).unwrap(), target_type);
+        let array = NativeArrayDyn::from_arrow_array(batch.column(1), field)
+            .unwrap()
+            .into_inner();
+        assert_eq!(array.as_point().value_as_geo(0), point!(x: 0.1, y: 2.0));
+    }
+
+    #[test]
+    fn convert_stream_in_order() {
+        let converter = GeoTableConverter::try_new(GeoTableConverterOptions {
+            num_threads: Some(4),
+            max_in_flight: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let batches = (0..10)
+            .map(|i| wkb_batch(i as f64 * 10.))
+            .collect::<Vec<_>>();
+        let schema = batches[0].schema();
+        let reader = RecordBatchReader::new(Box::new(RecordBatchIterator::new(
+            batches.into_iter().map(Ok),
+            schema,
+        )));
+        let output = converter.convert_stream(reader).unwrap();
+        let output_schema = output.schema();
+        assert!(matches!(
+            NativeType::try_from(output_schema.field(1)).unwrap(),
+            NativeType::Geometry(_)
+        ));
+
+        let table = Table::try_from(output).unwrap();
+        assert_eq!(table.batches().len(), 10);
+        for (i, batch) in table.batches().iter().enumerate() {
+            let array = NativeArrayDyn::from_arrow_array(batch.column(1), output_schema.field(1))
+                .unwrap()
+                .into_inner();
+            let first = array.as_geometry().value_as_geo(0);
+            let expected = point!(x: i as f64 * 10. + 0.123, y: 1.987);
+            assert_eq!(first, geo::Geometry::Point(expected));
+        }
+    }
+
+    #[test]
+    fn round_three_dimensional_wkb() {
+        use crate::algorithm::native::ForceDimension;
+
+        let points = crate::test::point::point_array().force_3d(1.);
+        let wkb: WKBArray<i32> = to_wkb(&points);
+        let schema = Schema::new(vec![wkb.extension_field().as_ref().clone()]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![wkb.to_array_ref()]).unwrap();
+
+        let converter = GeoTableConverter::try_new(GeoTableConverterOptions {
+            precision: Some(1),
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(converter.convert_batch(&batch).is_err());
+    }
+}
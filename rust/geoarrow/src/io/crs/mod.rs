@@ -1,6 +1,10 @@
 //! Defines CRS transforms used for writing GeoArrow data to file formats that require different
 //! CRS representations.
 
+mod wkt;
+
+pub use wkt::{WktNode, WktValue};
+
 use std::fmt::Debug;
 
 use serde_json::Value;
@@ -75,3 +79,22 @@ impl CRSTransform for DefaultCRSTransform {
         Ok(None)
     }
 }
+
+/// An implementation of [CRSTransform] using PROJ, through the [`proj`] crate.
+///
+/// Only conversion to PROJJSON is supported; conversion to WKT silently drops the CRS, like
+/// [DefaultCRSTransform].
+#[cfg(feature = "proj")]
+#[derive(Debug, Clone, Default)]
+pub struct ProjCRSTransform {}
+
+#[cfg(feature = "proj")]
+impl CRSTransform for ProjCRSTransform {
+    fn _convert_to_projjson(&self, meta: &ArrayMetadata) -> Result<Option<Value>> {
+        meta.to_projjson()
+    }
+
+    fn _convert_to_wkt(&self, _meta: &ArrayMetadata) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,352 @@
+//! A structural parser for CRS definitions in [WKT2:2019](https://docs.ogc.org/is/18-010r7/18-010r7.html)
+//! and the older WKT1 dialects found in `.prj` files and FlatGeobuf headers.
+//!
+//! The parser does not interpret the CRS; it only builds the tree of keywords and values, so that
+//! CRS strings can be inspected, normalized and compared instead of being treated as opaque
+//! strings.
+
+use std::fmt::{self, Display};
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::error::{GeoArrowError, Result};
+
+/// The keywords of the top-level CRS objects of WKT2, which don't exist in WKT1.
+const WKT2_CRS_KEYWORDS: &[&str] = &[
+    "BOUNDCRS",
+    "COMPOUNDCRS",
+    "DERIVEDPROJCRS",
+    "ENGCRS",
+    "ENGINEERINGCRS",
+    "GEODCRS",
+    "GEODETICCRS",
+    "GEOGCRS",
+    "GEOGRAPHICCRS",
+    "PARAMETRICCRS",
+    "PROJCRS",
+    "PROJECTEDCRS",
+    "TIMECRS",
+    "VERTCRS",
+    "VERTICALCRS",
+];
+
+/// Keywords of nodes that describe the usage of a CRS rather than the CRS itself, which are
+/// removed by [`WktNode::normalize`].
+const INFORMATIONAL_KEYWORDS: &[&str] = &["AREA", "BBOX", "REMARK", "SCOPE", "USAGE"];
+
+/// A value in a WKT node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WktValue {
+    /// A nested node, such as `DATUM[...]`.
+    Node(WktNode),
+
+    /// A quoted string, without its quotes.
+    String(String),
+
+    /// A number.
+    Number(f64),
+
+    /// An unquoted enumeration value, such as `north` in `AXIS["x",north]`.
+    Enum(String),
+}
+
+/// A node of a WKT CRS definition, made of a keyword and a list of values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WktNode {
+    /// The keyword of this node, such as `GEOGCRS`.
+    pub keyword: String,
+
+    /// The values of this node, in order.
+    pub values: Vec<WktValue>,
+}
+
+impl WktNode {
+    /// Parse a WKT CRS definition.
+    ///
+    /// Both square brackets and parentheses are accepted as delimiters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::io::crs::WktNode;
+    ///
+    /// let wkt = r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984",ELLIPSOID["WGS 84",6378137,298.257223563]],ID["EPSG",4326]]"#;
+    /// let crs = WktNode::parse(wkt).unwrap();
+    /// assert_eq!(crs.name(), Some("WGS 84"));
+    /// assert!(crs.is_wkt2());
+    /// assert_eq!(crs.authority_code().as_deref(), Some("EPSG:4326"));
+    /// ```
+    pub fn parse(wkt: &str) -> Result<Self> {
+        let mut parser = Parser {
+            input: wkt,
+            chars: wkt.char_indices().peekable(),
+        };
+        let node = parser.node()?;
+        parser.skip_whitespace();
+        if let Some((position, _)) = parser.chars.peek().copied() {
+            return Err(parser.error(position, "unexpected characters after the end"));
+        }
+        Ok(node)
+    }
+
+    /// The name of this node, which is its first value if it is a string.
+    pub fn name(&self) -> Option<&str> {
+        match self.values.first() {
+            Some(WktValue::String(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The child nodes of this node.
+    pub fn children(&self) -> impl Iterator<Item = &WktNode> {
+        self.values.iter().filter_map(|value| match value {
+            WktValue::Node(node) => Some(node),
+            _ => None,
+        })
+    }
+
+    /// The first child node with the given keyword, ignoring case.
+    pub fn child(&self, keyword: &str) -> Option<&WktNode> {
+        self.children()
+            .find(|node| node.keyword.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Whether this is a WKT2 CRS, as opposed to a WKT1 CRS.
+    pub fn is_wkt2(&self) -> bool {
+        WKT2_CRS_KEYWORDS
+            .iter()
+            .any(|keyword| self.keyword.eq_ignore_ascii_case(keyword))
+    }
+
+    /// The `AUTHORITY:CODE` identifier of this CRS, from its WKT2 `ID` or WKT1 `AUTHORITY` node.
+    pub fn authority_code(&self) -> Option<String> {
+        let id = self.child("ID").or_else(|| self.child("AUTHORITY"))?;
+        let authority = id.name()?;
+        let code = match id.values.get(1)? {
+            WktValue::String(code) => code.clone(),
+            WktValue::Number(code) => code.to_string(),
+            _ => return None,
+        };
+        Some(format!("{authority}:{code}"))
+    }
+
+    /// Normalize this CRS for comparison.
+    ///
+    /// Keywords are uppercased and nodes that only describe the usage of the CRS (`USAGE`,
+    /// `SCOPE`, `AREA`, `BBOX` and `REMARK`) are removed. Combined with the canonical formatting of
+    /// [`Display`], two definitions that differ only in whitespace, delimiters, keyword case or
+    /// number formatting normalize to the same string.
+    pub fn normalize(&self) -> Self {
+        let values = self
+            .values
+            .iter()
+            .filter_map(|value| match value {
+                WktValue::Node(node) => {
+                    let informational = INFORMATIONAL_KEYWORDS
+                        .iter()
+                        .any(|keyword| node.keyword.eq_ignore_ascii_case(keyword));
+                    (!informational).then(|| WktValue::Node(node.normalize()))
+                }
+                WktValue::Enum(value) => Some(WktValue::Enum(value.to_ascii_lowercase())),
+                value => Some(value.clone()),
+            })
+            .collect();
+        Self {
+            keyword: self.keyword.to_ascii_uppercase(),
+            values,
+        }
+    }
+}
+
+impl Display for WktValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WktValue::Node(node) => node.fmt(f),
+            WktValue::String(value) => write!(f, "\"{}\"", value.replace('"', "\"\"")),
+            WktValue::Number(value) => write!(f, "{value}"),
+            WktValue::Enum(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Writes the node in a compact form, with square brackets and without whitespace.
+impl Display for WktNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[", self.keyword)?;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            value.fmt(f)?;
+        }
+        write!(f, "]")
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, position: usize, message: &str) -> GeoArrowError {
+        GeoArrowError::General(format!("Invalid WKT CRS at position {position}: {message}"))
+    }
+
+    fn end_error(&self) -> GeoArrowError {
+        self.error(self.input.len(), "unexpected end")
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    /// Read a keyword, enumeration value or number, up to the next delimiter.
+    fn word(&mut self) -> &'a str {
+        let start = self.chars.peek().map_or(self.input.len(), |(i, _)| *i);
+        let mut end = start;
+        while let Some((i, c)) = self.chars.next_if(|&(_, c)| {
+            !matches!(c, '[' | ']' | '(' | ')' | ',' | '"') && !c.is_whitespace()
+        }) {
+            end = i + c.len_utf8();
+        }
+        &self.input[start..end]
+    }
+
+    fn node(&mut self) -> Result<WktNode> {
+        self.skip_whitespace();
+        let position = self.chars.peek().map_or(self.input.len(), |(i, _)| *i);
+        let keyword = self.word().to_string();
+        if keyword.is_empty()
+            || !keyword
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(self.error(position, "expected a keyword"));
+        }
+
+        self.skip_whitespace();
+        let close = match self.chars.next() {
+            Some((_, '[')) => ']',
+            Some((_, '(')) => ')',
+            Some((i, _)) => return Err(self.error(i, "expected '[' or '('")),
+            None => return Err(self.end_error()),
+        };
+
+        let mut values = vec![];
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, c)) if c == close => break,
+                Some((i, _)) => return Err(self.error(i, "expected ',' or a closing delimiter")),
+                None => return Err(self.end_error()),
+            }
+        }
+
+        Ok(WktNode { keyword, values })
+    }
+
+    fn value(&mut self) -> Result<WktValue> {
+        self.skip_whitespace();
+        let Some((position, c)) = self.chars.peek().copied() else {
+            return Err(self.end_error());
+        };
+
+        if c == '"' {
+            self.chars.next();
+            let mut value = String::new();
+            loop {
+                match self.chars.next() {
+                    // A doubled quote is an escaped quote
+                    Some((_, '"')) if self.chars.next_if(|&(_, c)| c == '"').is_some() => {
+                        value.push('"')
+                    }
+                    Some((_, '"')) => return Ok(WktValue::String(value)),
+                    Some((_, c)) => value.push(c),
+                    None => return Err(self.end_error()),
+                }
+            }
+        }
+
+        if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') {
+            let word = self.word();
+            return word
+                .parse()
+                .map(WktValue::Number)
+                .map_err(|_| self.error(position, "invalid number"));
+        }
+
+        // Either a nested node or an enumeration value, depending on what follows the word
+        let mut lookahead = self.chars.clone();
+        while lookahead
+            .next_if(|&(_, c)| !matches!(c, '[' | ']' | '(' | ')' | ',') && !c.is_whitespace())
+            .is_some()
+        {}
+        while lookahead.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+        if matches!(lookahead.peek(), Some((_, '[' | '('))) {
+            Ok(WktValue::Node(self.node()?))
+        } else {
+            let word = self.word();
+            if word.is_empty() {
+                return Err(self.error(position, "expected a value"));
+            }
+            Ok(WktValue::Enum(word.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WKT1: &str = r#"GEOGCS["WGS 84",
+        DATUM["WGS_1984", SPHEROID["WGS 84",6378137,298.257223563,AUTHORITY["EPSG","7030"]]],
+        PRIMEM["Greenwich",0],
+        UNIT["degree",0.0174532925199433],
+        AUTHORITY["EPSG","4326"]]"#;
+
+    #[test]
+    fn parse_wkt1() {
+        let crs = WktNode::parse(WKT1).unwrap();
+        assert_eq!(crs.keyword, "GEOGCS");
+        assert!(!crs.is_wkt2());
+        assert_eq!(crs.authority_code().as_deref(), Some("EPSG:4326"));
+        assert_eq!(
+            crs.child("datum")
+                .unwrap()
+                .child("SPHEROID")
+                .unwrap()
+                .values[1],
+            WktValue::Number(6378137.)
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let a = WktNode::parse(
+            r#"geogcrs("WGS 84", CS[ellipsoidal,2], AXIS["lat",NORTH], USAGE[SCOPE["x"]])"#,
+        )
+        .unwrap();
+        let b =
+            WktNode::parse(r#"GEOGCRS["WGS 84",CS[ellipsoidal,2.0],AXIS["lat",north]]"#).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(a.normalize(), b.normalize());
+        assert_eq!(
+            a.normalize().to_string(),
+            r#"GEOGCRS["WGS 84",CS[ellipsoidal,2],AXIS["lat",north]]"#
+        );
+    }
+
+    #[test]
+    fn escaped_quotes_and_errors() {
+        let crs = WktNode::parse(r#"ENGCRS["a ""quoted"" name",EDATUM["x"]]"#).unwrap();
+        assert_eq!(crs.name(), Some(r#"a "quoted" name"#));
+        assert_eq!(WktNode::parse(&crs.to_string()).unwrap(), crs);
+
+        assert!(WktNode::parse(r#"GEOGCRS["WGS 84""#).is_err());
+        assert!(WktNode::parse(r#"GEOGCRS["WGS 84"] extra"#).is_err());
+        assert!(WktNode::parse("EPSG:4326").is_err());
+    }
+}
@@ -7,17 +7,25 @@ use geozero::ToWkt;
 use crate::scalar::*;
 use crate::trait_::NativeScalar;
 
+/// The number of decimal places written when the formatter doesn't specify a precision.
+const DEFAULT_DISPLAY_PRECISION: usize = 3;
+
 /// Write geometry to display formatter
 /// This takes inspiration from Shapely, which prints a max of 80 characters for the geometry:
 /// https://github.com/shapely/shapely/blob/c3ddf310f108a7f589d763d613d755ac12ab5d4f/shapely/geometry/base.py#L163-L177
+///
+/// Coordinates are truncated to the precision of the formatter, e.g. `format!("{:.5}", geom)`,
+/// defaulting to 3 decimal places.
 pub(crate) fn write_geometry(
     f: &mut fmt::Formatter<'_>,
     mut geom: geo::Geometry,
     max_chars: usize,
 ) -> fmt::Result {
+    let precision = f.precision().unwrap_or(DEFAULT_DISPLAY_PRECISION);
+    let factor = 10_f64.powi(precision as i32);
     geom.map_coords_in_place(|geo::Coord { x, y }| geo::Coord {
-        x: (x * 1000.0).trunc() / 1000.0,
-        y: (y * 1000.0).trunc() / 1000.0,
+        x: (x * factor).trunc() / factor,
+        y: (y * factor).trunc() / factor,
     });
 
     let wkt = geom.to_wkt().unwrap();
@@ -107,6 +115,16 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_display_point_precision() {
+        let point = geo::Point::from((0.12345, 1.23456));
+        let point_array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+        let result = format!("{:.1}", point_array.value(0));
+        assert_eq!(result, "<POINT(0.1 1.2)>");
+        let result = format!("{:.5}", point_array.value(0));
+        assert_eq!(result, "<POINT(0.12345 1.23456)>");
+    }
+
     #[test]
     fn test_display_multipolygon() {
         let multipolygon_array = multipolygon::mp_array();
@@ -6,4 +6,11 @@ mod writer;
 #[cfg(feature = "flatgeobuf_async")]
 pub use reader::read_flatgeobuf_async;
 pub use reader::{FlatGeobufReader, FlatGeobufReaderBuilder, FlatGeobufReaderOptions};
-pub use writer::{write_flatgeobuf, write_flatgeobuf_with_options, FlatGeobufWriterOptions};
+pub use writer::{
+    write_flatgeobuf, write_flatgeobuf_with_options, write_flatgeobuf_with_ring_corrections,
+    FlatGeobufWriterOptions,
+};
+
+/// The key, in the JSON metadata of a FlatGeobuf column, of the Arrow data type of a property that
+/// FlatGeobuf has no column type for, such as dates and times.
+const ARROW_TYPE_KEY: &str = "ARROW:type";
@@ -1,12 +1,16 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow_schema::{DataType, Field, SchemaBuilder, SchemaRef, TimeUnit};
-use flatgeobuf::{ColumnType, Crs, Header};
+use flatgeobuf::{Column, ColumnType, Crs, Header};
 use serde_json::Value;
 
 use crate::array::metadata::{ArrayMetadata, CRSType};
 use crate::array::CoordType;
+use crate::io::crs::WktNode;
+use crate::io::flatgeobuf::ARROW_TYPE_KEY;
+use crate::io::uuid::{uuid_field, UUID_EXTENSION_NAME};
 
 /// Options for the FlatGeobuf reader
 #[derive(Debug, Clone)]
@@ -66,12 +70,55 @@ pub(super) fn infer_schema(header: Header<'_>) -> SchemaRef {
             // we've matched all types
             _ => unreachable!(),
         };
-        schema.push(field);
+        schema.push(restore_arrow_type(field, &col));
     }
 
     Arc::new(schema.finish())
 }
 
+/// Restore the Arrow data type that the writer stored in the metadata of a column, for types
+/// that FlatGeobuf stores as another column type, such as dates as date times or UUIDs as
+/// strings.
+fn restore_arrow_type(field: Field, col: &Column<'_>) -> Field {
+    let Some(Value::Object(metadata)) = col
+        .metadata()
+        .and_then(|metadata| serde_json::from_str(metadata).ok())
+    else {
+        return field;
+    };
+    let Some(data_type) = metadata
+        .get(ARROW_TYPE_KEY)
+        .and_then(Value::as_str)
+        .and_then(|data_type| DataType::from_str(data_type).ok())
+    else {
+        return field;
+    };
+
+    let is_uuid =
+        metadata.get("ARROW:extension:name").and_then(Value::as_str) == Some(UUID_EXTENSION_NAME);
+
+    // Only restore types that the values of the column can be converted to
+    let restorable = match (field.data_type(), &data_type) {
+        (
+            DataType::Timestamp(_, _),
+            DataType::Timestamp(_, _) | DataType::Date32 | DataType::Date64,
+        ) => true,
+        (DataType::Utf8, DataType::Time32(_) | DataType::Time64(_)) => true,
+        (DataType::Utf8, DataType::FixedSizeBinary(16)) => is_uuid,
+        (DataType::Binary, DataType::FixedSizeBinary(_)) => !is_uuid,
+        _ => false,
+    };
+    if !restorable {
+        return field;
+    }
+
+    if is_uuid {
+        uuid_field(field.name(), field.is_nullable())
+    } else {
+        Field::new(field.name(), data_type, field.is_nullable())
+    }
+}
+
 /// Parse CRS information provided by FlatGeobuf into an [ArrayMetadata].
 ///
 /// WKT is preferred if it exists. Otherwise, authority code will be used as a fallback.
@@ -80,6 +127,10 @@ pub(super) fn parse_crs(crs: Option<Crs<'_>>) -> Arc<ArrayMetadata> {
         let mut meta = ArrayMetadata::default();
         if let Some(wkt) = crs.wkt() {
             meta.crs = Some(Value::String(wkt.to_string()));
+            // Only mark the CRS as WKT2 if it is; FlatGeobuf writers commonly store WKT1
+            if WktNode::parse(wkt).is_ok_and(|node| node.is_wkt2()) {
+                meta.crs_type = Some(CRSType::Wkt2_2019);
+            }
             return Arc::new(meta);
         }
 
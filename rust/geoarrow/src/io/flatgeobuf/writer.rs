@@ -1,14 +1,22 @@
 use std::io::Write;
 
-use arrow_schema::Schema;
-use flatgeobuf::{FgbCrs, FgbWriter, FgbWriterOptions};
-use geozero::GeozeroDatasource;
+use arrow_schema::{DataType, Field, Schema};
+use flatgeobuf::{ColumnType, FgbCrs, FgbWriter, FgbWriterOptions};
+use serde_json::{json, Map};
 
-use crate::array::metadata::ArrayMetadata;
-use crate::datatypes::{Dimension, NativeType};
-use crate::error::Result;
+use crate::algorithm::native::fix_rings::fix_rings_reader;
+use crate::algorithm::native::flatten_collections::flatten_collections_reader;
+use crate::algorithm::native::{
+    CollectionHandling, FixRingsOptions, RingCorrections, RingOrientation,
+};
+use crate::array::metadata::{ArrayMetadata, DEFAULT_SRID_CONFIDENCE};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
 use crate::io::crs::{CRSTransform, DefaultCRSTransform};
+use crate::io::flatgeobuf::ARROW_TYPE_KEY;
+use crate::io::geozero::table::data_source::{process_stream, ProcessOptions, PropertyIndex};
 use crate::io::stream::RecordBatchReader;
+use crate::io::uuid::{is_uuid_field, UUID_EXTENSION_NAME};
 use crate::schema::GeoSchemaExt;
 
 /// Options for the FlatGeobuf writer
@@ -32,6 +40,13 @@ pub struct FlatGeobufWriterOptions {
     /// for CRS conversions. For example, the Python API uses the `pyproj` Python library to
     /// perform the conversion rather than linking into PROJ from Rust.
     pub crs_transform: Option<Box<dyn CRSTransform>>,
+    /// Close polygon rings whose last coordinate differs from their first coordinate.
+    pub close_rings: bool,
+    /// Reverse polygon rings that don't have this orientation.
+    pub ring_orientation: Option<RingOrientation>,
+    /// Replace geometry collections with multi geometries before writing, for consumers that
+    /// can't read collections. See [`FlattenCollections`][crate::algorithm::native::FlattenCollections].
+    pub collections: Option<CollectionHandling>,
 }
 
 impl Default for FlatGeobufWriterOptions {
@@ -44,6 +59,9 @@ impl Default for FlatGeobufWriterOptions {
             title: None,
             description: None,
             metadata: None,
+            close_rings: false,
+            ring_orientation: None,
+            collections: None,
         }
     }
 }
@@ -63,18 +81,23 @@ impl FlatGeobufWriterOptions {
     }
 
     /// Create [FgbWriterOptions]
+    ///
+    /// The CRS is written both as the EPSG code inferred from the [ArrayMetadata], which readers
+    /// such as OGR use to identify it, and as WKT if the [CRSTransform] can produce it.
     fn create_fgb_options<'a>(
         &'a self,
         geo_data_type: NativeType,
+        epsg: Option<u32>,
         wkt_crs: Option<&'a str>,
     ) -> FgbWriterOptions<'a> {
         let (has_z, has_m) = match geo_data_type.dimension() {
-            Some(Dimension::XY) => (false, false),
-            Some(Dimension::XYZ) => (true, false),
+            Some(dim) => (dim.has_z(), dim.has_m()),
             // TODO: not sure how to handle geometry arrays
             None => (false, false),
         };
         let crs = FgbCrs {
+            org: epsg.map(|_| "EPSG"),
+            code: epsg.and_then(|code| i32::try_from(code).ok()).unwrap_or(0),
             wkt: wkt_crs,
             ..Default::default()
         };
@@ -117,7 +140,30 @@ pub fn write_flatgeobuf_with_options<W: Write, S: Into<RecordBatchReader>>(
     name: &str,
     options: FlatGeobufWriterOptions,
 ) -> Result<()> {
-    let mut stream: RecordBatchReader = stream.into();
+    write_flatgeobuf_with_ring_corrections(stream, writer, name, options)?;
+    Ok(())
+}
+
+/// Write a Table to a FlatGeobuf file with specific writer options, returning the number of
+/// polygon rings that were corrected while writing.
+///
+/// This is the same as [`write_flatgeobuf_with_options`], for callers that want to report the
+/// corrections made by the `close_rings` and `ring_orientation` options.
+pub fn write_flatgeobuf_with_ring_corrections<W: Write, S: Into<RecordBatchReader>>(
+    stream: S,
+    writer: W,
+    name: &str,
+    options: FlatGeobufWriterOptions,
+) -> Result<RingCorrections> {
+    let fix_options = FixRingsOptions {
+        close_rings: options.close_rings,
+        orientation: options.ring_orientation,
+    };
+    let mut stream = stream.into();
+    if let Some(handling) = options.collections {
+        stream = flatten_collections_reader(stream, handling)?;
+    }
+    let (mut stream, corrections) = fix_rings_reader(stream, fix_options);
 
     let schema = stream.schema();
     let fields = &schema.fields;
@@ -131,16 +177,110 @@ pub fn write_flatgeobuf_with_options<W: Write, S: Into<RecordBatchReader>>(
     let array_meta = ArrayMetadata::try_from(geometry_field.as_ref())?;
 
     let wkt_crs_str = options.create_wkt_crs(&array_meta)?;
-    let fgb_options = options.create_fgb_options(geo_data_type, wkt_crs_str.as_deref());
+    let epsg = array_meta.to_epsg(DEFAULT_SRID_CONFIDENCE);
+    let fgb_options = options.create_fgb_options(geo_data_type, epsg, wkt_crs_str.as_deref());
 
     let geometry_type = infer_flatgeobuf_geometry_type(stream.schema().as_ref())?;
 
     let mut fgb = FgbWriter::create_with_options(name, geometry_type, fgb_options)?;
-    stream.process(&mut fgb)?;
+    add_property_columns(&mut fgb, stream.schema().as_ref())?;
+    // Properties are indexed by the declared columns, so that null properties don't shift them
+    let process_options = ProcessOptions {
+        property_index: PropertyIndex::Column,
+        ..Default::default()
+    };
+    process_stream(&mut stream, &mut fgb, process_options)?;
     fgb.write(writer)?;
+    let corrections = *corrections.lock().unwrap();
+    Ok(corrections)
+}
+
+/// Declare the FlatGeobuf columns of the non-geometry fields of a schema.
+///
+/// Declaring them up front, instead of inferring them from the values of the first feature, keeps
+/// the columns whose first value is null. Arrow types that FlatGeobuf has no column type for are
+/// stored as the closest column type, with the Arrow type in the column metadata so that the
+/// reader can restore it.
+fn add_property_columns(fgb: &mut FgbWriter, schema: &Schema) -> Result<()> {
+    let geom_col_idxs = schema.geometry_columns();
+    for (field_idx, field) in schema.fields().iter().enumerate() {
+        // Same as the properties passed by the geozero data source
+        if geom_col_idxs.contains(&field_idx)
+            || field
+                .metadata()
+                .get("ARROW:extension:name")
+                .is_some_and(|ext_name| ext_name == "geoarrow.box")
+        {
+            continue;
+        }
+
+        let (column_type, metadata) = flatgeobuf_column_type(field)?;
+        fgb.add_column(field.name(), column_type, |fbb, col| {
+            col.nullable = field.is_nullable();
+            col.metadata = metadata.map(|metadata| fbb.create_string(&metadata));
+        });
+    }
     Ok(())
 }
 
+/// The FlatGeobuf column type of a field, and the column metadata if the column type doesn't
+/// round trip to the Arrow type.
+fn flatgeobuf_column_type(field: &Field) -> Result<(ColumnType, Option<String>)> {
+    let column_type = match field.data_type() {
+        DataType::Boolean => ColumnType::Bool,
+        DataType::Int8 => ColumnType::Byte,
+        DataType::UInt8 => ColumnType::UByte,
+        DataType::Int16 => ColumnType::Short,
+        DataType::UInt16 => ColumnType::UShort,
+        DataType::Int32 => ColumnType::Int,
+        DataType::UInt32 => ColumnType::UInt,
+        DataType::Int64 => ColumnType::Long,
+        DataType::UInt64 => ColumnType::ULong,
+        DataType::Float16 | DataType::Float32 => ColumnType::Float,
+        DataType::Float64 => ColumnType::Double,
+        DataType::Utf8 | DataType::LargeUtf8 => ColumnType::String,
+        DataType::Binary | DataType::LargeBinary => ColumnType::Binary,
+        DataType::Struct(_) | DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _) => {
+            ColumnType::Json
+        }
+        DataType::Timestamp(_, _) | DataType::Date32 | DataType::Date64 => ColumnType::DateTime,
+        // Times are written as ISO 8601 strings
+        DataType::Time32(_) | DataType::Time64(_) => ColumnType::String,
+        DataType::FixedSizeBinary(16) if is_uuid_field(field) => ColumnType::String,
+        DataType::FixedSizeBinary(_) => ColumnType::Binary,
+        data_type => {
+            return Err(GeoArrowError::General(format!(
+                "Unsupported data type {data_type} of FlatGeobuf property {}",
+                field.name()
+            )))
+        }
+    };
+
+    let metadata = match field.data_type() {
+        DataType::Timestamp(_, _)
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::FixedSizeBinary(_) => {
+            let mut metadata = Map::new();
+            metadata.insert(
+                ARROW_TYPE_KEY.to_string(),
+                json!(field.data_type().to_string()),
+            );
+            if is_uuid_field(field) {
+                metadata.insert(
+                    "ARROW:extension:name".to_string(),
+                    json!(UUID_EXTENSION_NAME),
+                );
+            }
+            Some(serde_json::Value::Object(metadata).to_string())
+        }
+        _ => None,
+    };
+    Ok((column_type, metadata))
+}
+
 fn infer_flatgeobuf_geometry_type(schema: &Schema) -> Result<flatgeobuf::GeometryType> {
     let fields = &schema.fields;
     let geom_col_idxs = schema.geometry_columns();
@@ -155,11 +295,12 @@ fn infer_flatgeobuf_geometry_type(schema: &Schema) -> Result<flatgeobuf::Geometr
     let geometry_type = match geo_data_type {
         Point(_, _) => flatgeobuf::GeometryType::Point,
         LineString(_, _) => flatgeobuf::GeometryType::LineString,
-        Polygon(_, _) => flatgeobuf::GeometryType::Polygon,
+        // Rects are written as polygons
+        Polygon(_, _) | Rect(_) => flatgeobuf::GeometryType::Polygon,
         MultiPoint(_, _) => flatgeobuf::GeometryType::MultiPoint,
         MultiLineString(_, _) => flatgeobuf::GeometryType::MultiLineString,
         MultiPolygon(_, _) => flatgeobuf::GeometryType::MultiPolygon,
-        Rect(_) | Geometry(_) => flatgeobuf::GeometryType::Unknown,
+        Geometry(_) => flatgeobuf::GeometryType::Unknown,
         GeometryCollection(_, _) => flatgeobuf::GeometryType::GeometryCollection,
     };
     Ok(geometry_type)
@@ -168,10 +309,20 @@ fn infer_flatgeobuf_geometry_type(schema: &Schema) -> Result<flatgeobuf::Geometr
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
     use crate::io::flatgeobuf::FlatGeobufReaderBuilder;
     use crate::table::Table;
     use crate::test::point;
+    use crate::ArrayBase;
+    use arrow_array::{
+        ArrayRef, Date32Array, FixedSizeBinaryArray, RecordBatch, RecordBatchReader as _,
+        Time64MicrosecondArray, TimestampMillisecondArray,
+    };
+    use arrow_schema::TimeUnit;
+    use std::collections::HashMap;
     use std::io::{BufWriter, Cursor};
+    use std::sync::Arc;
 
     #[test]
     fn test_write() {
@@ -219,6 +370,128 @@ mod test {
         assert_eq!(table, new_table);
     }
 
+    #[test]
+    fn test_write_ring_corrections() {
+        // A clockwise exterior ring
+        let polygon = geo::polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+        let field = array.extension_field();
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![field])),
+            vec![array.into_array_ref()],
+        )
+        .unwrap();
+        let table = Table::try_new(vec![batch.clone()], batch.schema()).unwrap();
+
+        let options = FlatGeobufWriterOptions {
+            ring_orientation: Some(RingOrientation::CounterClockwise),
+            ..Default::default()
+        };
+        let corrections =
+            write_flatgeobuf_with_ring_corrections(&table, Vec::new(), "name", options).unwrap();
+        assert_eq!(corrections.reversed_rings, 1);
+    }
+
+    #[test]
+    fn test_write_epsg() {
+        let mut table = point::table();
+        let crs = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        table.set_crs(&crs, false).unwrap();
+
+        let mut output_buffer = Vec::new();
+        let writer = BufWriter::new(&mut output_buffer);
+        write_flatgeobuf(&table, writer, "name").unwrap();
+
+        let reader = Cursor::new(output_buffer);
+        let reader_builder = FlatGeobufReaderBuilder::open(reader).unwrap();
+        let record_batch_reader = reader_builder.read(Default::default()).unwrap();
+        let schema = record_batch_reader.schema();
+        let geometry_field = schema.field(schema.as_ref().geometry_columns()[0]);
+        let metadata = ArrayMetadata::try_from(geometry_field).unwrap();
+        assert_eq!(metadata.to_epsg(100), Some(4326));
+    }
+
+    #[test]
+    fn test_write_temporal_and_uuid() {
+        let point_array = point::point_array();
+        let uuid_metadata =
+            HashMap::from([("ARROW:extension:name".to_string(), "arrow.uuid".to_string())]);
+        let fields = vec![
+            Arc::new(Field::new("date", DataType::Date32, true)),
+            Arc::new(Field::new(
+                "timestamp",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+                true,
+            )),
+            Arc::new(Field::new(
+                "time",
+                DataType::Time64(TimeUnit::Microsecond),
+                true,
+            )),
+            Arc::new(
+                Field::new("uuid", DataType::FixedSizeBinary(16), true)
+                    .with_metadata(uuid_metadata),
+            ),
+            Arc::new(Field::new("bytes", DataType::FixedSizeBinary(2), true)),
+            point_array.extension_field(),
+        ];
+        let schema = Arc::new(Schema::new(fields));
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Date32Array::from(vec![None, Some(19_000), Some(-1)])),
+            Arc::new(
+                TimestampMillisecondArray::from(vec![None, Some(1_700_000_000_123), Some(0)])
+                    .with_timezone("+01:00"),
+            ),
+            Arc::new(Time64MicrosecondArray::from(vec![
+                None,
+                Some(45_296_789_000),
+                Some(0),
+            ])),
+            Arc::new(
+                FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                    [Some([0x12; 16]), None, Some([0xab; 16])].into_iter(),
+                    16,
+                )
+                .unwrap(),
+            ),
+            Arc::new(
+                FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                    [None, Some([1, 2]), Some([3, 4])].into_iter(),
+                    2,
+                )
+                .unwrap(),
+            ),
+            point_array.into_array_ref(),
+        ];
+        let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+
+        let mut output_buffer = Vec::new();
+        let writer = BufWriter::new(&mut output_buffer);
+        let options = FlatGeobufWriterOptions {
+            write_index: false,
+            ..Default::default()
+        };
+        write_flatgeobuf_with_options(&table, writer, "name", options).unwrap();
+
+        let reader = Cursor::new(output_buffer);
+        let reader_builder = FlatGeobufReaderBuilder::open(reader).unwrap();
+        let record_batch_reader = reader_builder.read(Default::default()).unwrap();
+        let new_table = Table::try_from(
+            Box::new(record_batch_reader) as Box<dyn arrow_array::RecordBatchReader>
+        )
+        .unwrap();
+        let (batch, new_batch) = (&table.batches()[0], &new_table.batches()[0]);
+        for name in ["date", "timestamp", "time", "uuid", "bytes"] {
+            let field = new_table.schema().field_with_name(name).unwrap();
+            assert_eq!(field, table.schema().field_with_name(name).unwrap());
+            assert_eq!(
+                new_batch.column_by_name(name).unwrap(),
+                batch.column_by_name(name).unwrap(),
+            );
+        }
+    }
+
     #[test]
     fn test_write_z() {
         let table = point::table_z();
@@ -1,5 +1,9 @@
 //! Convert structs that implement geo-traits to [geo-types] objects.
 //!
+//! [`to_geo_vec`] and [`from_geo_iter`] convert whole arrays to and from [geo-types] objects. This
+//! is the supported bridge for calling [geo]- or GEOS-based code that has no array kernel yet.
+//! Prefer array kernels where they exist, as each conversion copies every coordinate.
+//!
 //! Note that this is the same underlying implementation as upstream [geo] in
 //! <https://github.com/georust/geo/pull/1255>. However, the trait-based implementation hits this
 //! compiler regression <https://github.com/rust-lang/rust/issues/128887>,
@@ -11,7 +15,11 @@
 //! Note that it's only `GeometryTrait` and `GeometryCollectionTrait` that hit this compiler bug.
 //! Other traits can use the upstream impls.
 
+use std::sync::Arc;
+
 use geo::{CoordNum, Geometry, GeometryCollection};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 use geo_traits::to_geo::{
     ToGeoLine, ToGeoLineString, ToGeoMultiLineString, ToGeoMultiPoint, ToGeoMultiPolygon,
@@ -19,6 +27,15 @@ use geo_traits::to_geo::{
 };
 use geo_traits::{GeometryCollectionTrait, GeometryTrait, GeometryType};
 
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::{CoordType, GeometryArray, GeometryBuilder};
+use crate::error::Result;
+use crate::NativeArray;
+
+/// The number of geometries converted by each task of [`to_geo_vec`].
+#[cfg(feature = "rayon")]
+const BATCH_SIZE: usize = 65_536;
+
 /// Convert any Geometry to a [`Geometry`].
 ///
 /// Only the first two dimensions will be kept.
@@ -52,3 +69,81 @@ pub fn geometry_collection_to_geo<T: CoordNum>(
             .collect(),
     )
 }
+
+/// Convert every geometry of an array to a [`Geometry`], considering validity.
+///
+/// If the `rayon` feature is enabled, the array is converted in parallel batches. Only the first
+/// two dimensions are kept.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::io::geo::to_geo_vec;
+///
+/// let point = geo::point!(x: 1., y: 2.);
+/// let array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+/// assert_eq!(to_geo_vec(&array), vec![Some(geo::Geometry::Point(point))]);
+/// ```
+pub fn to_geo_vec(array: &dyn NativeArray) -> Vec<Option<Geometry>> {
+    let mut geoms = Vec::with_capacity(array.len());
+
+    #[cfg(feature = "rayon")]
+    {
+        let len = array.len();
+        geoms.par_extend(
+            (0..len)
+                .into_par_iter()
+                .step_by(BATCH_SIZE)
+                .flat_map_iter(|offset| {
+                    let batch = array.slice(offset, BATCH_SIZE.min(len - offset));
+                    iter_geometries(batch.as_ref())
+                        .map(|geom| geom.map(|geom| geometry_to_geo(&geom)))
+                        .collect::<Vec<_>>()
+                }),
+        );
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    geoms.extend(iter_geometries(array).map(|geom| geom.map(|geom| geometry_to_geo(&geom))));
+
+    geoms
+}
+
+/// Build a [`GeometryArray`] from an iterator of optional geometries, such as [`Geometry`]
+/// objects produced by [geo] or GEOS-based code.
+///
+/// The output buffers are allocated up front from the exact capacity of the input. Use
+/// [`Downcast`][crate::algorithm::native::Downcast] to get a more specific array type.
+pub fn from_geo_iter<G: GeometryTrait<T = f64>>(
+    geoms: impl IntoIterator<Item = Option<G>>,
+    coord_type: CoordType,
+) -> Result<GeometryArray> {
+    let geoms = geoms.into_iter().collect::<Vec<_>>();
+    let builder =
+        GeometryBuilder::from_nullable_geometries(&geoms, coord_type, Arc::default(), false)?;
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    #[test]
+    fn round_trip() {
+        let array = polygon::p_array();
+        let geoms = to_geo_vec(&array);
+        assert_eq!(geoms.len(), array.len());
+
+        let round_trip = from_geo_iter(geoms, CoordType::Interleaved).unwrap();
+        assert_eq!(round_trip.len(), array.len());
+        assert_eq!(
+            round_trip.value_as_geo(1),
+            geo::Geometry::Polygon(polygon::p1())
+        );
+    }
+}
@@ -1,7 +1,10 @@
 //! Read from and write to [GeoJSON](https://geojson.org/) files.
 
-pub use reader::read_geojson;
-pub use writer::write_geojson;
+pub use reader::{read_geojson, read_geojson_with_options, GeoJsonReaderOptions};
+pub use writer::{
+    write_geojson, write_geojson_with_options, GeoJsonIdOptions, GeoJsonWriterOptions,
+    GeometryTextEncoding,
+};
 
-mod reader;
-mod writer;
+pub(crate) mod reader;
+pub(crate) mod writer;
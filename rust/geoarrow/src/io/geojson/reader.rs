@@ -1,17 +1,83 @@
-use geozero::geojson::GeoJsonReader;
-use geozero::GeozeroDatasource;
+use arrow_array::cast::AsArray;
+use arrow_array::Array;
+use arrow_schema::DataType;
+use geozero::geojson::{GeoJson, GeoJsonReader};
+use geozero::{GeozeroDatasource, ToGeo};
+use serde_json::Value;
 use std::io::Read;
+use std::str::FromStr;
+use std::sync::Arc;
 
-use crate::array::CoordType;
-use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, GeometryArray, GeometryBuilder};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
 use crate::io::geozero::array::GeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::schema::GeoSchemaExt;
 use crate::table::Table;
+use crate::ArrayBase;
+
+/// Options for the GeoJSON reader
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeoJsonReaderOptions {
+    /// The number of rows in each batch.
+    pub batch_size: Option<usize>,
+
+    /// The name of a column to read the `id` member of features into, if any.
+    ///
+    /// Ids are read as integers if every id is an integer, and as strings otherwise. Features
+    /// without an id have a null value. It's an error if a feature has a property with this
+    /// name.
+    pub id_column: Option<String>,
+
+    /// The names of string properties to parse into geometry columns.
+    ///
+    /// Each value is parsed as a GeoJSON geometry object if it starts with `{`, and as WKT
+    /// otherwise, as written by the
+    /// [`secondary_geometries`][crate::io::geojson::GeoJsonWriterOptions::secondary_geometries]
+    /// writer option.
+    pub geometry_properties: Vec<String>,
+}
 
 /// Read a GeoJSON file to a Table.
 pub fn read_geojson<R: Read>(reader: R, batch_size: Option<usize>) -> Result<Table> {
-    let mut geojson = GeoJsonReader(reader);
+    read_geojson_with_options(
+        reader,
+        GeoJsonReaderOptions {
+            batch_size,
+            ..Default::default()
+        },
+    )
+}
+
+/// Read a GeoJSON file to a Table with specific reader options.
+pub fn read_geojson_with_options<R: Read>(
+    reader: R,
+    options: GeoJsonReaderOptions,
+) -> Result<Table> {
+    let Some(id_column) = &options.id_column else {
+        let table = process_geojson(GeoJsonReader(reader), options.batch_size)?;
+        return parse_geometry_properties(table, &options.geometry_properties);
+    };
+
+    // Geozero doesn't read feature ids, so they're moved to the properties before reading
+    let mut geojson: Value = serde_json::from_reader(reader)?;
+    match geojson.get("type").and_then(Value::as_str) {
+        Some("FeatureCollection") => {
+            if let Some(Value::Array(features)) = geojson.get_mut("features") {
+                move_feature_ids(features, id_column)?;
+            }
+        }
+        Some("Feature") => move_feature_ids(std::slice::from_mut(&mut geojson), id_column)?,
+        _ => (),
+    }
+    let buf = serde_json::to_vec(&geojson)?;
+    let table = process_geojson(GeoJsonReader(buf.as_slice()), options.batch_size)?;
+    parse_geometry_properties(table, &options.geometry_properties)
+}
+
+fn process_geojson(mut source: impl GeozeroDatasource, batch_size: Option<usize>) -> Result<Table> {
     // TODO: set CRS to epsg:4326?
     let options = GeoTableBuilderOptions::new(
         CoordType::Interleaved,
@@ -23,16 +89,129 @@ pub fn read_geojson<R: Read>(reader: R, batch_size: Option<usize>) -> Result<Tab
     );
     let mut geo_table =
         GeoTableBuilder::<GeometryStreamBuilder>::new_with_options(Dimension::XY, options);
-    geojson.process(&mut geo_table)?;
+    source.process(&mut geo_table)?;
     geo_table.finish()
 }
 
+/// Move the `id` member of GeoJSON features to a property named `id_column`.
+///
+/// The ids are converted to strings unless they're all integers, so that the column has a
+/// single type.
+pub(crate) fn move_feature_ids(features: &mut [Value], id_column: &str) -> Result<()> {
+    let integer_ids = features
+        .iter()
+        .filter_map(|feature| feature.get("id"))
+        .all(|id| id.is_i64());
+
+    for feature in features.iter_mut() {
+        let Some(feature) = feature.as_object_mut() else {
+            continue;
+        };
+        let id = feature.remove("id");
+        let properties = feature
+            .entry("properties")
+            .or_insert_with(|| Value::Object(Default::default()));
+        if properties.is_null() {
+            *properties = Value::Object(Default::default());
+        }
+        let Some(properties) = properties.as_object_mut() else {
+            continue;
+        };
+        if properties.contains_key(id_column) {
+            return Err(GeoArrowError::General(format!(
+                "Feature property {id_column} conflicts with the id column"
+            )));
+        }
+        let id = match id {
+            Some(Value::String(id)) => Value::String(id),
+            Some(id) if !integer_ids => Value::String(id.to_string()),
+            Some(id) => id,
+            None => continue,
+        };
+        properties.insert(id_column.to_string(), id);
+    }
+    Ok(())
+}
+
+/// Replace the string columns with the given names by geometry columns of the GeoJSON or WKT
+/// geometries they contain.
+///
+/// The new columns have the metadata of the feature geometry column, as a GeoJSON file has a
+/// single CRS.
+pub(crate) fn parse_geometry_properties(mut table: Table, names: &[String]) -> Result<Table> {
+    let metadata = match table.schema().as_ref().geometry_columns().first() {
+        Some(index) => Arc::new(ArrayMetadata::try_from(table.schema().field(*index))?),
+        None => Default::default(),
+    };
+    for name in names {
+        let index = table.schema().index_of(name)?;
+        let chunks = table
+            .batches()
+            .iter()
+            .map(|batch| {
+                let array =
+                    parse_geometry_property(batch.column(index).as_ref(), name, metadata.clone())?;
+                Ok(array.into_array_ref())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let field = NativeType::Geometry(CoordType::Interleaved)
+            .to_field_with_metadata(name, true, &metadata);
+        table.set_column(index, field.into(), chunks)?;
+    }
+    Ok(table)
+}
+
+fn parse_geometry_property(
+    array: &dyn Array,
+    name: &str,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<GeometryArray> {
+    let values: Vec<Option<&str>> = match array.data_type() {
+        DataType::Utf8 => array.as_string::<i32>().iter().collect(),
+        DataType::LargeUtf8 => array.as_string::<i64>().iter().collect(),
+        // Properties that are null in every feature
+        DataType::Null => vec![None; array.len()],
+        data_type => {
+            return Err(GeoArrowError::IncorrectType(
+                format!("Geometry property {name} must have a string type, got {data_type}").into(),
+            ))
+        }
+    };
+
+    let mut builder = GeometryBuilder::new_with_options(CoordType::Interleaved, metadata, false);
+    for (row, value) in values.into_iter().enumerate() {
+        let Some(text) = value else {
+            builder.push_null();
+            continue;
+        };
+        if text.trim_start().starts_with('{') {
+            let geometry = GeoJson(text).to_geo()?;
+            builder.push_geometry(Some(&geometry))?;
+        } else {
+            let geometry = wkt::Wkt::<f64>::from_str(text).map_err(|err| {
+                GeoArrowError::General(format!(
+                    "Invalid WKT in geometry property {name} at row {row}: {err}"
+                ))
+            })?;
+            builder.push_geometry(Some(&geometry))?;
+        }
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod test {
     use std::fs::File;
     use std::io::BufReader;
 
+    use arrow_array::types::Int64Type;
+    use arrow_array::{RecordBatch, StringArray};
+    use arrow_schema::{Field, Schema};
+
     use super::*;
+    use crate::array::NativeArrayDyn;
+    use crate::test::point;
+    use crate::NativeArray;
 
     #[ignore = "non-vendored file"]
     #[test]
@@ -41,4 +220,71 @@ mod test {
         let mut filein = BufReader::new(File::open(path).unwrap());
         let _table = read_geojson(&mut filein, None).unwrap();
     }
+
+    fn read_ids(geojson: &str) -> Result<Table> {
+        let options = GeoJsonReaderOptions {
+            id_column: Some("fid".to_string()),
+            ..Default::default()
+        };
+        read_geojson_with_options(geojson.as_bytes(), options)
+    }
+
+    #[test]
+    fn test_read_ids() {
+        let feature = |id: &str| {
+            format!(
+                r#"{{"type": "Feature", {id} "properties": {{"name": "a"}}, "geometry": {{"type": "Point", "coordinates": [1, 2]}}}}"#
+            )
+        };
+        let collection = |ids: [&str; 3]| {
+            format!(
+                r#"{{"type": "FeatureCollection", "features": [{}]}}"#,
+                ids.map(feature).join(",")
+            )
+        };
+
+        let table = read_ids(&collection([r#""id": 3,"#, "", r#""id": 1,"#])).unwrap();
+        let batch = &table.batches()[0];
+        let ids = batch.column_by_name("fid").unwrap();
+        assert_eq!(
+            ids.as_primitive::<Int64Type>().iter().collect::<Vec<_>>(),
+            vec![Some(3), None, Some(1)]
+        );
+
+        let table = read_ids(&collection([r#""id": "a-1","#, r#""id": 2,"#, ""])).unwrap();
+        let batch = &table.batches()[0];
+        let ids = batch.column_by_name("fid").unwrap();
+        assert_eq!(
+            ids.as_string::<i32>().iter().collect::<Vec<_>>(),
+            vec![Some("a-1"), Some("2"), None]
+        );
+
+        let conflicting = collection(["", "", ""]).replace("name", "fid");
+        assert!(read_ids(&conflicting).is_err());
+    }
+
+    #[test]
+    fn test_geometry_properties_metadata() {
+        let metadata = Arc::new(ArrayMetadata::from_authority_code("EPSG:4326".to_string()));
+        let points = point::point_array().with_metadata(metadata.clone());
+        let centroids = StringArray::from(vec![Some("POINT(1 2)"), None, Some("POINT(3 4)")]);
+        let schema = Arc::new(Schema::new(vec![
+            points.extension_field(),
+            Arc::new(Field::new("centroid", DataType::Utf8, true)),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![points.to_array_ref(), Arc::new(centroids)],
+        )
+        .unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+
+        let table = parse_geometry_properties(table, &["centroid".to_string()]).unwrap();
+        let field = table.schema().field_with_name("centroid").unwrap();
+        assert_eq!(ArrayMetadata::try_from(field).unwrap(), *metadata);
+        let centroids =
+            NativeArrayDyn::from_arrow_array(table.batches()[0].column(1), field).unwrap();
+        assert_eq!(centroids.as_ref().metadata(), metadata);
+        assert_eq!(centroids.as_ref().null_count(), 1);
+    }
 }
@@ -1,23 +1,347 @@
-use crate::error::Result;
+use crate::algorithm::native::fix_rings::fix_rings_reader;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::algorithm::native::{FixRingsOptions, RingCorrections, RingOrientation};
+use crate::array::metadata::ArrayMetadata;
+use crate::array::NativeArrayDyn;
+use crate::error::{GeoArrowError, Result};
+use crate::io::canonical::write_canonical_json;
+use crate::io::geo::geometry_to_geo;
+use crate::io::geozero::table::data_source::{process_stream, FeatureIdWriter, ProcessOptions};
 use crate::io::stream::RecordBatchReader;
+use crate::io::wkt::{ToWKT, WktWriterOptions};
+use crate::schema::GeoSchemaExt;
+use crate::NativeArray;
+use arrow::array::AsArray;
+use arrow_array::{Array, RecordBatch, RecordBatchIterator, StringArray};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use geozero::error::GeozeroError;
 use geozero::geojson::GeoJsonWriter;
-use geozero::GeozeroDatasource;
+use geozero::{FeatureProcessor, ToJson};
+use std::cell::RefCell;
 use std::io::Write;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// How the GeoJSON writers fill the `id` member of features.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum GeoJsonIdOptions {
+    /// Write no feature ids, and every column as a property.
+    #[default]
+    Disabled,
+    /// Write the values of the column with this name as the feature ids, instead of as a
+    /// property.
+    ///
+    /// The column must have an integer or a string type. Features with a null id have no `id`
+    /// member.
+    Column(String),
+}
+
+/// A text encoding of geometries written as GeoJSON properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryTextEncoding {
+    /// Well-known text, such as `POINT(1 2)`.
+    Wkt,
+    /// A GeoJSON geometry object serialized to a string, such as
+    /// `{"type": "Point", "coordinates": [1, 2]}`.
+    GeoJson,
+}
+
+/// Options for the GeoJSON writer
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeoJsonWriterOptions {
+    /// Close polygon rings whose last coordinate differs from their first coordinate.
+    pub close_rings: bool,
+    /// Reverse polygon rings that don't have this orientation.
+    pub ring_orientation: Option<RingOrientation>,
+    /// The column of the feature ids, if any.
+    pub id: GeoJsonIdOptions,
+    /// How to write geometry columns other than the first one, if any.
+    ///
+    /// With `None`, writing a table with multiple geometry columns is an error. Otherwise, the
+    /// first geometry column is the feature geometry and the other geometry columns are written as
+    /// string properties with this encoding. They must have the same metadata, such as the CRS, as
+    /// the feature geometry column.
+    pub secondary_geometries: Option<GeometryTextEncoding>,
+    /// Write canonical output, so that the same table is always written to the same bytes.
+    ///
+    /// Features are written in row order, object members are sorted by key, there is no
+    /// insignificant whitespace, and integral numbers are written without a fraction and other
+    /// numbers in their shortest round-trip form. The output is buffered in memory until every
+    /// feature has been written.
+    pub canonical: bool,
+}
+
+impl GeoJsonWriterOptions {
+    /// Options that fix polygon rings to follow
+    /// [RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6): rings are closed,
+    /// exterior rings are counterclockwise and holes are clockwise.
+    pub fn rfc7946() -> Self {
+        Self {
+            close_rings: true,
+            ring_orientation: Some(RingOrientation::CounterClockwise),
+            ..Default::default()
+        }
+    }
+}
 
 /// Write a Table to GeoJSON
 ///
 /// Note: Does not reproject to WGS84 for you
 pub fn write_geojson<W: Write, S: Into<RecordBatchReader>>(stream: S, writer: W) -> Result<()> {
-    let mut geojson = GeoJsonWriter::new(writer);
-    stream.into().process(&mut geojson)?;
+    write_geojson_with_options(stream, writer, Default::default())?;
     Ok(())
 }
 
+/// Write a Table to GeoJSON with specific writer options.
+///
+/// Returns the number of polygon rings that were corrected while writing.
+///
+/// Note: Does not reproject to WGS84 for you
+pub fn write_geojson_with_options<W: Write, S: Into<RecordBatchReader>>(
+    stream: S,
+    writer: W,
+    options: GeoJsonWriterOptions,
+) -> Result<RingCorrections> {
+    write_features(stream.into(), writer, &options, GeoJsonWriter::new)
+}
+
+/// Write the features of a stream with a geozero GeoJSON writer, created from the output by
+/// `processor`, fixing rings and writing feature ids as given by the options.
+pub(crate) fn write_features<W: Write, P: FeatureProcessor>(
+    stream: RecordBatchReader,
+    writer: W,
+    options: &GeoJsonWriterOptions,
+    processor: impl FnOnce(SharedWriter<W>) -> P,
+) -> Result<RingCorrections> {
+    let stream = match options.secondary_geometries {
+        Some(encoding) => encode_secondary_geometries(stream, encoding, options.canonical)?,
+        None => stream,
+    };
+    let fix_options = FixRingsOptions {
+        close_rings: options.close_rings,
+        orientation: options.ring_orientation,
+    };
+    let (mut stream, corrections) = fix_rings_reader(stream, fix_options);
+
+    let id_column_index = match &options.id {
+        GeoJsonIdOptions::Disabled => None,
+        GeoJsonIdOptions::Column(name) => {
+            let schema = stream.schema();
+            let column_index = schema.index_of(name)?;
+            let data_type = schema.field(column_index).data_type();
+            if !(data_type.is_integer()
+                || matches!(data_type, DataType::Utf8 | DataType::LargeUtf8))
+            {
+                return Err(GeoArrowError::IncorrectType(
+                    format!("Feature id column {name} must have an integer or string type, got {data_type}").into(),
+                ));
+            }
+            Some(column_index)
+        }
+    };
+
+    let output = SharedWriter(Rc::new(RefCell::new(SharedOutput {
+        writer,
+        canonical_buffer: options.canonical.then(Vec::new),
+    })));
+    let mut processor = processor(output.clone());
+    let mut write_id = |array: &dyn Array, row: usize| -> std::result::Result<(), GeozeroError> {
+        if array.is_null(row) {
+            return Ok(());
+        }
+        let id = match array.data_type() {
+            DataType::Utf8 => serde_json::to_string(array.as_string::<i32>().value(row)),
+            DataType::LargeUtf8 => serde_json::to_string(array.as_string::<i64>().value(row)),
+            // Integers are formatted as JSON numbers
+            _ => Ok(ArrayFormatter::try_new(array, &FormatOptions::default())
+                .map_err(|err| GeozeroError::Property(err.to_string()))?
+                .value(row)
+                .to_string()),
+        }
+        .map_err(|err| GeozeroError::Property(err.to_string()))?;
+        write!(output.0.borrow_mut(), r#", "id": {id}"#)?;
+        Ok(())
+    };
+    let process_options = ProcessOptions {
+        feature_ids: id_column_index
+            .map(|column_index| (column_index, &mut write_id as &mut FeatureIdWriter)),
+        ..Default::default()
+    };
+    process_stream(&mut stream, &mut processor, process_options)?;
+    output.0.borrow_mut().finish()?;
+
+    let corrections = *corrections.lock().unwrap();
+    Ok(corrections)
+}
+
+/// Replace every geometry column of a stream except the first one with a string column of the
+/// geometries in the given text encoding.
+fn encode_secondary_geometries(
+    stream: RecordBatchReader,
+    encoding: GeometryTextEncoding,
+    canonical: bool,
+) -> Result<RecordBatchReader> {
+    let schema = stream.schema();
+    let geom_col_idxs = schema.as_ref().geometry_columns();
+    let Some((primary_idx, secondary_idxs)) = geom_col_idxs.split_first() else {
+        return Ok(stream);
+    };
+    let secondary_idxs = secondary_idxs.to_vec();
+
+    // GeoJSON has a single CRS, so the secondary geometries are only read back with their
+    // metadata if it's the metadata of the feature geometries
+    let primary_metadata = ArrayMetadata::try_from(schema.field(*primary_idx))?;
+    for idx in &secondary_idxs {
+        let field = schema.field(*idx);
+        if ArrayMetadata::try_from(field)? != primary_metadata {
+            return Err(GeoArrowError::General(format!(
+                "Geometry column {} has different metadata, such as its CRS, than the feature geometry column {}",
+                field.name(),
+                schema.field(*primary_idx).name()
+            )));
+        }
+    }
+
+    let mut output_fields = schema.fields().to_vec();
+    for idx in &secondary_idxs {
+        let field = &output_fields[*idx];
+        output_fields[*idx] = Arc::new(Field::new(field.name(), DataType::Utf8, true));
+    }
+    let output_schema = Arc::new(Schema::new_with_metadata(
+        output_fields,
+        schema.metadata().clone(),
+    ));
+
+    let batch_schema = output_schema.clone();
+    let batches = stream.into_inner().map(move |batch| {
+        let batch = batch?;
+        let mut columns = batch.columns().to_vec();
+        for idx in &secondary_idxs {
+            let array =
+                NativeArrayDyn::from_arrow_array(&columns[*idx], batch.schema().field(*idx))
+                    .and_then(|array| encode_geometries(array.as_ref(), encoding, canonical))
+                    .map_err(|err| ArrowError::ExternalError(Box::new(err)))?;
+            columns[*idx] = Arc::new(array);
+        }
+        RecordBatch::try_new(batch_schema.clone(), columns)
+    });
+    let reader = RecordBatchIterator::new(batches, output_schema);
+    Ok(RecordBatchReader::new(Box::new(reader)))
+}
+
+/// Encode the geometries of an array as strings, in canonical form if `canonical` is set.
+fn encode_geometries(
+    array: &dyn NativeArray,
+    encoding: GeometryTextEncoding,
+    canonical: bool,
+) -> Result<StringArray> {
+    match encoding {
+        GeometryTextEncoding::Wkt => Ok(array
+            .to_wkt_with_options::<i32>(WktWriterOptions { canonical })?
+            .into_inner()),
+        GeometryTextEncoding::GeoJson => iter_geometries(array)
+            .map(|geom| -> Result<Option<String>> {
+                let Some(geom) = geom else {
+                    return Ok(None);
+                };
+                let json = to_geojson_geometry(geometry_to_geo(&geom)).to_json()?;
+                if !canonical {
+                    return Ok(Some(json));
+                }
+                let mut buffer = Vec::new();
+                write_canonical_json(json.as_bytes(), &mut buffer)?;
+                let json = String::from_utf8(buffer)
+                    .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                Ok(Some(json.trim_end().to_string()))
+            })
+            .collect(),
+    }
+}
+
+/// Replace the geometry types that GeoJSON doesn't have with their equivalent line string or
+/// polygon.
+fn to_geojson_geometry(geometry: geo::Geometry) -> geo::Geometry {
+    match geometry {
+        geo::Geometry::Line(line) => geo::Geometry::LineString(line.into()),
+        geo::Geometry::Rect(rect) => geo::Geometry::Polygon(rect.to_polygon()),
+        geo::Geometry::Triangle(triangle) => geo::Geometry::Polygon(triangle.to_polygon()),
+        geo::Geometry::GeometryCollection(collection) => geo::Geometry::GeometryCollection(
+            collection.into_iter().map(to_geojson_geometry).collect(),
+        ),
+        geometry => geometry,
+    }
+}
+
+/// An output shared between a geozero writer and the feature ids written between its features.
+///
+/// Geozero has no feature ids, so they're written directly to the output after the beginning of
+/// each feature.
+pub(crate) struct SharedWriter<W>(Rc<RefCell<SharedOutput<W>>>);
+
+/// The output of a [`SharedWriter`], which is buffered to be rewritten in canonical form if
+/// `canonical_buffer` is set.
+pub(crate) struct SharedOutput<W> {
+    writer: W,
+    canonical_buffer: Option<Vec<u8>>,
+}
+
+impl<W: Write> SharedOutput<W> {
+    /// Write the canonical form of the buffered output, if any.
+    fn finish(&mut self) -> Result<()> {
+        if let Some(buffer) = self.canonical_buffer.take() {
+            write_canonical_json(&buffer, &mut self.writer)?;
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for SharedOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match &mut self.canonical_buffer {
+            Some(buffer) => buffer.write(buf),
+            None => self.writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self.canonical_buffer {
+            Some(_) => Ok(()),
+            None => self.writer.flush(),
+        }
+    }
+}
+
+impl<W> Clone for SharedWriter<W> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<W: Write> Write for SharedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.borrow_mut().flush()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::{PointArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::io::geojson::{read_geojson_with_options, GeoJsonReaderOptions};
+    use crate::table::Table;
     use crate::test::point;
+    use crate::ArrayBase;
+    use arrow_array::{Float64Array, RecordBatch, StringArray};
+    use arrow_schema::Schema;
     use std::io::BufWriter;
+    use std::sync::Arc;
 
     #[test]
     fn test_write() {
@@ -29,4 +353,186 @@ mod test {
         let output_string = String::from_utf8(output_buffer).unwrap();
         println!("{}", output_string);
     }
+
+    #[test]
+    fn test_write_ids() {
+        let table = point::table();
+        let write = |id| {
+            let mut output_buffer = Vec::new();
+            let options = GeoJsonWriterOptions {
+                id,
+                ..Default::default()
+            };
+            write_geojson_with_options(&table, &mut output_buffer, options)
+                .map(|_| String::from_utf8(output_buffer).unwrap())
+        };
+
+        let output = write(GeoJsonIdOptions::Column("string".to_string())).unwrap();
+        assert!(output.contains(r#""id": "foo""#));
+        assert!(!output.contains(r#""string": "#));
+        let output = write(GeoJsonIdOptions::Column("u8".to_string())).unwrap();
+        assert!(output.contains(r#""id": 1"#));
+        let output = write(GeoJsonIdOptions::Disabled).unwrap();
+        assert!(!output.contains(r#""id": "#));
+        assert!(write(GeoJsonIdOptions::Column("missing".to_string())).is_err());
+
+        // Ids round trip through the reader
+        let output = write(GeoJsonIdOptions::Column("string".to_string())).unwrap();
+        let options = GeoJsonReaderOptions {
+            id_column: Some("string".to_string()),
+            ..Default::default()
+        };
+        let new_table = read_geojson_with_options(output.as_bytes(), options).unwrap();
+        let batch = &new_table.batches()[0];
+        assert_eq!(
+            batch.column_by_name("string").unwrap().as_ref(),
+            &StringArray::from(vec!["foo", "bar", "baz"]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_write_secondary_geometries() {
+        let polygon = geo::polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)];
+        let polygons: PolygonArray = (vec![polygon; 3].as_slice(), Dimension::XY).into();
+        let centroids = point::point_array();
+        let schema = Arc::new(Schema::new(vec![
+            polygons.extension_field(),
+            Arc::new(
+                centroids
+                    .extension_field()
+                    .as_ref()
+                    .clone()
+                    .with_name("centroid"),
+            ),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![polygons.into_array_ref(), centroids.into_array_ref()],
+        )
+        .unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+
+        let mut output_buffer = Vec::new();
+        assert!(write_geojson(&table, &mut output_buffer).is_err());
+
+        for encoding in [GeometryTextEncoding::Wkt, GeometryTextEncoding::GeoJson] {
+            let mut output_buffer = Vec::new();
+            let options = GeoJsonWriterOptions {
+                secondary_geometries: Some(encoding),
+                ..Default::default()
+            };
+            write_geojson_with_options(&table, &mut output_buffer, options).unwrap();
+            let output = String::from_utf8(output_buffer).unwrap();
+            match encoding {
+                GeometryTextEncoding::Wkt => assert!(output.contains(r#""centroid": "POINT("#)),
+                GeometryTextEncoding::GeoJson => {
+                    assert!(output.contains(r#""centroid": "{\"type\": \"Point\""#))
+                }
+            }
+
+            let options = GeoJsonReaderOptions {
+                geometry_properties: vec!["centroid".to_string()],
+                ..Default::default()
+            };
+            let new_table = read_geojson_with_options(output.as_bytes(), options).unwrap();
+            let index = new_table.schema().index_of("centroid").unwrap();
+            let geometries = new_table.geometry_columns().unwrap();
+            assert_eq!(geometries.len(), 2);
+            let centroids = NativeArrayDyn::from_arrow_array(
+                new_table.batches()[0].column(index),
+                new_table.schema().field(index),
+            )
+            .unwrap();
+            let centroids = iter_geometries(centroids.as_ref())
+                .map(|geom| geometry_to_geo(&geom.unwrap()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                centroids,
+                vec![point::p0().into(), point::p1().into(), point::p2().into()]
+            );
+        }
+
+        // A secondary geometry column in another CRS
+        let centroids = point::point_array().with_metadata(Arc::new(
+            ArrayMetadata::from_authority_code("EPSG:3857".to_string()),
+        ));
+        let polygons = table.batches()[0].column(0).clone();
+        let schema = Arc::new(Schema::new(vec![
+            table.schema().field(0).clone(),
+            centroids
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name("centroid"),
+        ]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![polygons, centroids.to_array_ref()]).unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+        let options = GeoJsonWriterOptions {
+            secondary_geometries: Some(GeometryTextEncoding::Wkt),
+            ..Default::default()
+        };
+        let mut output_buffer = Vec::new();
+        assert!(write_geojson_with_options(&table, &mut output_buffer, options).is_err());
+    }
+
+    #[test]
+    fn test_write_canonical() {
+        let point = geo::point!(x: -0., y: 0.1);
+        let array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+        let schema = Arc::new(Schema::new(vec![
+            Arc::new(Field::new("b", DataType::Float64, true)),
+            Arc::new(Field::new("a", DataType::Utf8, true)),
+            array.extension_field(),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Float64Array::from(vec![2.0])),
+                Arc::new(StringArray::from(vec!["x"])),
+                array.into_array_ref(),
+            ],
+        )
+        .unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+
+        let mut output_buffer = Vec::new();
+        let options = GeoJsonWriterOptions {
+            canonical: true,
+            ..Default::default()
+        };
+        write_geojson_with_options(&table, &mut output_buffer, options).unwrap();
+        assert_eq!(
+            String::from_utf8(output_buffer).unwrap(),
+            concat!(
+                r#"{"features":[{"geometry":{"coordinates":[0,0.1],"type":"Point"},"#,
+                r#""properties":{"a":"x","b":2},"type":"Feature"}],"type":"FeatureCollection"}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_rfc7946() {
+        // A clockwise exterior ring
+        let polygon = geo::polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+        let field = array.extension_field();
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![field])),
+            vec![array.into_array_ref()],
+        )
+        .unwrap();
+        let table = Table::try_new(vec![batch.clone()], batch.schema()).unwrap();
+
+        let mut output_buffer = Vec::new();
+        let corrections =
+            write_geojson_with_options(&table, &mut output_buffer, GeoJsonWriterOptions::rfc7946())
+                .unwrap();
+        assert_eq!(corrections.reversed_rings, 1);
+        assert_eq!(corrections.geometries, 1);
+
+        let output_string = String::from_utf8(output_buffer).unwrap();
+        assert!(output_string.contains("[[[0,0],[1,1],[0,1],[0,0]]]"));
+    }
 }
@@ -3,5 +3,5 @@
 mod reader;
 mod writer;
 
-pub use reader::read_geojson_lines;
-pub use writer::write_geojson_lines;
+pub use reader::{read_geojson_lines, read_geojson_lines_with_options};
+pub use writer::{write_geojson_lines, write_geojson_lines_with_options};
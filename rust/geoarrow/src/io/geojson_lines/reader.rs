@@ -1,10 +1,13 @@
 use geozero::geojson::GeoJsonLineReader;
 use geozero::GeozeroDatasource;
+use serde_json::Value;
 use std::io::BufRead;
 
 use crate::array::CoordType;
 use crate::datatypes::Dimension;
 use crate::error::Result;
+use crate::io::geojson::reader::{move_feature_ids, parse_geometry_properties};
+use crate::io::geojson::GeoJsonReaderOptions;
 use crate::io::geozero::array::GeometryStreamBuilder;
 use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
 use crate::table::Table;
@@ -14,8 +17,47 @@ use crate::table::Table;
 /// This expects a GeoJSON Feature on each line of a text file, with a newline character separating
 /// each Feature.
 pub fn read_geojson_lines<R: BufRead>(reader: R, batch_size: Option<usize>) -> Result<Table> {
-    let mut geojson_line_reader = GeoJsonLineReader::new(reader);
+    read_geojson_lines_with_options(
+        reader,
+        GeoJsonReaderOptions {
+            batch_size,
+            ..Default::default()
+        },
+    )
+}
+
+/// Read a GeoJSON Lines file with specific reader options.
+pub fn read_geojson_lines_with_options<R: BufRead>(
+    reader: R,
+    options: GeoJsonReaderOptions,
+) -> Result<Table> {
+    let Some(id_column) = &options.id_column else {
+        let table = process_geojson_lines(GeoJsonLineReader::new(reader), options.batch_size)?;
+        return parse_geometry_properties(table, &options.geometry_properties);
+    };
+
+    // Geozero doesn't read feature ids, so they're moved to the properties before reading
+    let mut features = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            features.push(serde_json::from_str::<Value>(&line)?);
+        }
+    }
+    move_feature_ids(&mut features, id_column)?;
+    let mut buf = Vec::new();
+    for feature in features {
+        serde_json::to_writer(&mut buf, &feature)?;
+        buf.push(b'\n');
+    }
+    let table = process_geojson_lines(GeoJsonLineReader::new(buf.as_slice()), options.batch_size)?;
+    parse_geometry_properties(table, &options.geometry_properties)
+}
 
+fn process_geojson_lines(
+    mut source: impl GeozeroDatasource,
+    batch_size: Option<usize>,
+) -> Result<Table> {
     // TODO: set crs to epsg:4326?
     let options = GeoTableBuilderOptions::new(
         CoordType::Interleaved,
@@ -27,6 +69,6 @@ pub fn read_geojson_lines<R: BufRead>(reader: R, batch_size: Option<usize>) -> R
     );
     let mut geo_table =
         GeoTableBuilder::<GeometryStreamBuilder>::new_with_options(Dimension::XY, options);
-    geojson_line_reader.process(&mut geo_table)?;
+    source.process(&mut geo_table)?;
     geo_table.finish()
 }
@@ -1,8 +1,10 @@
 use geozero::geojson::GeoJsonLineWriter;
-use geozero::GeozeroDatasource;
 use std::io::Write;
 
+use crate::algorithm::native::RingCorrections;
 use crate::error::Result;
+use crate::io::geojson::writer::write_features;
+use crate::io::geojson::GeoJsonWriterOptions;
 use crate::io::stream::RecordBatchReader;
 
 /// Write a table to newline-delimited GeoJSON
@@ -10,7 +12,17 @@ pub fn write_geojson_lines<W: Write, S: Into<RecordBatchReader>>(
     stream: S,
     writer: W,
 ) -> Result<()> {
-    let mut geojson_writer = GeoJsonLineWriter::new(writer);
-    stream.into().process(&mut geojson_writer)?;
+    write_geojson_lines_with_options(stream, writer, Default::default())?;
     Ok(())
 }
+
+/// Write a table to newline-delimited GeoJSON with specific writer options.
+///
+/// Returns the number of polygon rings that were corrected while writing.
+pub fn write_geojson_lines_with_options<W: Write, S: Into<RecordBatchReader>>(
+    stream: S,
+    writer: W,
+    options: GeoJsonWriterOptions,
+) -> Result<RingCorrections> {
+    write_features(stream.into(), writer, &options, GeoJsonLineWriter::new)
+}
@@ -2,3 +2,49 @@
 
 mod array;
 pub(crate) mod scalar;
+
+use std::sync::Arc;
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, GeometryArray, GeometryBuilder};
+use crate::error::Result;
+use crate::scalar::Geometry;
+use crate::NativeArray;
+use scalar::{to_geos_geometry, GEOSGeometry};
+
+/// Convert every geometry of an array to a [`geos::Geometry`], considering validity.
+///
+/// Rects are converted to polygons, as GEOS has no rect type. Only the first two dimensions are
+/// kept.
+pub fn to_geos_vec(array: &dyn NativeArray) -> Result<Vec<Option<geos::Geometry>>> {
+    iter_geometries(array)
+        .map(|geom| {
+            let geom = match geom {
+                Some(Geometry::Rect(rect)) => {
+                    Some(to_geos_geometry(&geo::Rect::from(rect).to_polygon())?)
+                }
+                Some(geom) => Some(to_geos_geometry(&geom)?),
+                None => None,
+            };
+            Ok(geom)
+        })
+        .collect()
+}
+
+/// Build a [`GeometryArray`] from optional [`geos::Geometry`] objects, such as the output of a
+/// GEOS operation on the result of [`to_geos_vec`].
+///
+/// Pass the metadata of the input array to keep its CRS.
+pub fn from_geos_vec(
+    geoms: Vec<Option<geos::Geometry>>,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<GeometryArray> {
+    let geoms = geoms
+        .into_iter()
+        .map(|geom| geom.map(GEOSGeometry::new))
+        .collect::<Vec<_>>();
+    let builder = GeometryBuilder::from_nullable_geometries(&geoms, coord_type, metadata, false)?;
+    Ok(builder.finish())
+}
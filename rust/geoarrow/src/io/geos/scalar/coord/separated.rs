@@ -1,21 +1,16 @@
 use crate::array::SeparatedCoordBuffer;
-use crate::datatypes::Dimension;
 use geos::CoordSeq;
 
 impl TryFrom<SeparatedCoordBuffer> for CoordSeq {
     type Error = geos::Error;
 
     fn try_from(value: SeparatedCoordBuffer) -> std::result::Result<Self, geos::Error> {
-        match value.dim {
-            Dimension::XY => {
-                CoordSeq::new_from_arrays(&value.buffers[0], &value.buffers[1], None, None)
-            }
-            Dimension::XYZ => CoordSeq::new_from_arrays(
-                &value.buffers[0],
-                &value.buffers[1],
-                Some(&value.buffers[2]),
-                None,
-            ),
-        }
+        let buffer = |index: Option<usize>| index.map(|index| &*value.buffers[index]);
+        CoordSeq::new_from_arrays(
+            &value.buffers[0],
+            &value.buffers[1],
+            buffer(value.dim.z_index()),
+            buffer(value.dim.m_index()),
+        )
     }
 }
@@ -7,11 +7,13 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_array::builder::{
-    ArrayBuilder, BinaryBuilder, BooleanBuilder, Date32Builder, Float32Builder, Float64Builder,
-    Int16Builder, Int32Builder, Int64Builder, Int8Builder, StringBuilder,
-    TimestampMicrosecondBuilder, UInt16Builder, UInt32Builder, UInt64Builder, UInt8Builder,
+    ArrayBuilder, BinaryBuilder, BooleanBuilder, Date32Builder, FixedSizeBinaryBuilder,
+    Float32Builder, Float64Builder, Int16Builder, Int32Builder, Int64Builder, Int8Builder,
+    StringBuilder, TimestampMicrosecondBuilder, UInt16Builder, UInt32Builder, UInt64Builder,
+    UInt8Builder,
 };
 use arrow_array::ArrayRef;
+use arrow_cast::cast;
 use arrow_cast::parse::string_to_datetime;
 use arrow_schema::{DataType, Field, TimeUnit};
 use chrono::{DateTime, Utc};
@@ -19,6 +21,7 @@ use enum_as_inner::EnumAsInner;
 use geozero::ColumnValue;
 
 use crate::error::{GeoArrowError, Result};
+use crate::io::uuid::{is_uuid_field, uuid_field};
 
 // Types implemented by FlatGeobuf/Geozero
 /// Builder for a single column's properties
@@ -42,6 +45,11 @@ pub enum AnyBuilder {
     Date32(Date32Builder),
     DateTime((TimestampMicrosecondBuilder, Option<Arc<str>>)),
     Binary(BinaryBuilder),
+    /// UUIDs, stored as strings by geozero, as an `arrow.uuid` extension array.
+    Uuid(FixedSizeBinaryBuilder),
+    /// Values of a type that geozero doesn't have, such as times, collected with the builder of
+    /// the geozero type they're stored as and cast to the data type when finished.
+    Cast((Box<AnyBuilder>, DataType)),
 }
 
 impl AnyBuilder {
@@ -151,10 +159,35 @@ impl AnyBuilder {
             DataType::Utf8 => String(StringBuilder::with_capacity(capacity, 0)),
             DataType::Binary => Binary(BinaryBuilder::with_capacity(capacity, 0)),
             DataType::Date32 => Date32(Date32Builder::with_capacity(capacity)),
-            DataType::Timestamp(_time_unit, tz) => DateTime((
+            DataType::Timestamp(TimeUnit::Microsecond, tz) => DateTime((
                 TimestampMicrosecondBuilder::with_capacity(capacity),
                 tz.clone(),
             )),
+            DataType::Timestamp(_, tz) => Cast((
+                Box::new(DateTime((
+                    TimestampMicrosecondBuilder::with_capacity(capacity),
+                    tz.clone(),
+                ))),
+                field.data_type().clone(),
+            )),
+            DataType::Date64 => Cast((
+                Box::new(DateTime((
+                    TimestampMicrosecondBuilder::with_capacity(capacity),
+                    None,
+                ))),
+                field.data_type().clone(),
+            )),
+            DataType::Time32(_) | DataType::Time64(_) => Cast((
+                Box::new(String(StringBuilder::with_capacity(capacity, 0))),
+                field.data_type().clone(),
+            )),
+            DataType::FixedSizeBinary(16) if is_uuid_field(field) => {
+                Uuid(FixedSizeBinaryBuilder::with_capacity(capacity, 16))
+            }
+            DataType::FixedSizeBinary(_) => Cast((
+                Box::new(Binary(BinaryBuilder::with_capacity(capacity, 0))),
+                field.data_type().clone(),
+            )),
             _ => todo!("Unsupported type {}", field.data_type()),
         }
     }
@@ -179,6 +212,25 @@ impl AnyBuilder {
     pub fn add_value(&mut self, value: &ColumnValue) -> geozero::error::Result<()> {
         use ColumnValue::*;
 
+        match self {
+            AnyBuilder::Cast((builder, _)) => return builder.add_value(value),
+            AnyBuilder::Uuid(builder) => {
+                let bytes = match value {
+                    String(v) => parse_uuid(v),
+                    Binary(v) => v.to_vec().try_into().ok(),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    geozero::error::GeozeroError::Property(format!("Invalid UUID {value:?}"))
+                })?;
+                builder
+                    .append_value(bytes)
+                    .map_err(|err| geozero::error::GeozeroError::Property(err.to_string()))?;
+                return Ok(());
+            }
+            _ => (),
+        }
+
         macro_rules! impl_add_value {
             ($downcast_func:ident, $v:ident) => {{
                 self.$downcast_func().unwrap().append_value(*$v);
@@ -201,9 +253,16 @@ impl AnyBuilder {
             Json(v) => impl_add_value!(as_json_mut, v),
             Binary(v) => impl_add_value!(as_binary_mut, v),
             DateTime(v) => {
-                let (arr, _tz) = self.as_date_time_mut().unwrap();
                 let dt = string_to_datetime(&Utc, v).unwrap();
-                arr.append_value(dt.timestamp_micros());
+                match self {
+                    AnyBuilder::Date32(arr) => {
+                        arr.append_value(dt.timestamp().div_euclid(SECONDS_PER_DAY) as i32)
+                    }
+                    _ => {
+                        let (arr, _tz) = self.as_date_time_mut().unwrap();
+                        arr.append_value(dt.timestamp_micros());
+                    }
+                }
             }
         }
 
@@ -230,6 +289,8 @@ impl AnyBuilder {
             Date32(arr) => arr.append_null(),
             DateTime((arr, _tz)) => arr.append_null(),
             Binary(arr) => arr.append_null(),
+            Uuid(arr) => arr.append_null(),
+            Cast((arr, _)) => arr.append_null(),
         }
     }
 
@@ -269,6 +330,8 @@ impl AnyBuilder {
                 true,
             ),
             Binary(_) => Field::new("", DataType::Binary, true),
+            Uuid(_) => uuid_field("", true),
+            Cast((_, data_type)) => Field::new("", data_type.clone(), true),
         }
     }
 
@@ -291,6 +354,8 @@ impl AnyBuilder {
             Date32(arr) => arr.len(),
             DateTime((arr, _tz)) => arr.len(),
             Binary(arr) => arr.len(),
+            Uuid(arr) => arr.len(),
+            Cast((arr, _)) => arr.len(),
         }
     }
 
@@ -311,14 +376,34 @@ impl AnyBuilder {
             String(mut arr) => Arc::new(arr.finish()),
             Json(mut arr) => Arc::new(arr.finish()),
             Date32(mut arr) => Arc::new(arr.finish()),
-            // TODO: how to support timezones? Or is this always naive tz?
-            DateTime((mut arr, _tz)) => Arc::new(arr.finish()),
+            // Values are parsed as UTC, so the timezone only changes how they're displayed
+            DateTime((mut arr, tz)) => Arc::new(arr.finish().with_timezone_opt(tz)),
             Binary(mut arr) => Arc::new(arr.finish()),
+            Uuid(mut arr) => Arc::new(arr.finish()),
+            Cast((arr, data_type)) => cast(&arr.finish()?, &data_type)?,
         };
         Ok(arr)
     }
 }
 
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Parse a UUID string, with or without hyphens, into its bytes.
+fn parse_uuid(value: &str) -> Option<[u8; 16]> {
+    let hex = value.as_bytes().iter().filter(|&&b| b != b'-');
+    if hex.clone().count() != 32 {
+        return None;
+    }
+    let digits = hex
+        .map(|&b| (b as char).to_digit(16).map(|d| d as u8))
+        .collect::<Option<Vec<_>>>()?;
+    let mut bytes = [0; 16];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    Some(bytes)
+}
+
 macro_rules! impl_from {
     ($from_ty:ty, $variant:expr) => {
         impl From<$from_ty> for AnyBuilder {
@@ -11,9 +11,10 @@ use crate::io::geozero::scalar::{
 };
 use crate::io::geozero::table::json_encoder::{make_encoder, EncoderOptions};
 use crate::io::stream::RecordBatchReader;
+use crate::io::uuid::is_uuid_field;
 use crate::schema::GeoSchemaExt;
 use crate::table::Table;
-use crate::trait_::ArrayAccessor;
+use crate::trait_::{ArrayAccessor, NativeScalar};
 use crate::NativeArray;
 use arrow::array::AsArray;
 use arrow::datatypes::*;
@@ -23,38 +24,81 @@ use arrow_schema::{DataType, Schema};
 use geozero::error::GeozeroError;
 use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, PropertyProcessor};
 
+/// How the properties of a feature are indexed when passed to a [PropertyProcessor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PropertyIndex {
+    /// Consecutive among the properties passed, for writers that separate values by index, such
+    /// as GeoJSON.
+    Passed,
+    /// The index of the column among the property columns, for writers that declared the property
+    /// columns up front, such as FlatGeobuf.
+    Column,
+}
+
+/// Writes the id of the feature at a row of the id column, between the beginning of the feature
+/// and its properties.
+pub(crate) type FeatureIdWriter<'a> = dyn FnMut(&dyn Array, usize) -> Result<(), GeozeroError> + 'a;
+
+/// Options of [process_stream].
+pub(crate) struct ProcessOptions<'a, 'b> {
+    /// How properties are indexed.
+    pub(crate) property_index: PropertyIndex,
+    /// The index of a column whose values are feature ids instead of properties, and the function
+    /// that writes them.
+    pub(crate) feature_ids: Option<(usize, &'a mut FeatureIdWriter<'b>)>,
+}
+
+impl Default for ProcessOptions<'_, '_> {
+    fn default() -> Self {
+        Self {
+            property_index: PropertyIndex::Passed,
+            feature_ids: None,
+        }
+    }
+}
+
 impl GeozeroDatasource for RecordBatchReader {
     fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<(), GeozeroError> {
-        let reader = self.inner_mut();
-        let schema = reader.schema();
-        let geom_indices = schema.as_ref().geometry_columns();
-        let geometry_column_index = if geom_indices.len() != 1 {
-            Err(GeozeroError::Dataset(
-                "Writing through geozero not supported with multiple geometries".to_string(),
-            ))?
-        } else {
-            geom_indices[0]
-        };
-
-        processor.dataset_begin(None)?;
-
-        let mut overall_row_idx = 0;
-        for batch in reader.into_iter() {
-            let batch = batch.map_err(|err| GeozeroError::Dataset(err.to_string()))?;
-            process_batch(
-                &batch,
-                &schema,
-                geometry_column_index,
-                overall_row_idx,
-                processor,
-            )?;
-            overall_row_idx += batch.num_rows();
-        }
+        process_stream(self, processor, Default::default())
+    }
+}
 
-        processor.dataset_end()?;
+/// Process a stream of record batches with specific options.
+pub(crate) fn process_stream<P: FeatureProcessor>(
+    stream: &mut RecordBatchReader,
+    processor: &mut P,
+    mut options: ProcessOptions,
+) -> Result<(), GeozeroError> {
+    let reader = stream.inner_mut();
+    let schema = reader.schema();
+    let geom_indices = schema.as_ref().geometry_columns();
+    let geometry_column_index = if geom_indices.len() != 1 {
+        Err(GeozeroError::Dataset(
+            "Writing through geozero not supported with multiple geometries".to_string(),
+        ))?
+    } else {
+        geom_indices[0]
+    };
 
-        Ok(())
+    processor.dataset_begin(None)?;
+
+    let mut overall_row_idx = 0;
+    for batch in reader.into_iter() {
+        let batch = batch.map_err(|err| GeozeroError::Dataset(err.to_string()))?;
+        process_batch(
+            &batch,
+            &schema,
+            geometry_column_index,
+            overall_row_idx,
+            &mut options,
+            processor,
+        )?;
+        overall_row_idx += batch.num_rows();
     }
+
+    processor.dataset_end()?;
+
+    Ok(())
 }
 
 impl GeozeroDatasource for Table {
@@ -84,6 +128,7 @@ fn process_geotable<P: FeatureProcessor>(
             schema,
             geometry_column_index,
             overall_row_idx,
+            &mut Default::default(),
             processor,
         )?;
         overall_row_idx += batch.num_rows();
@@ -99,6 +144,7 @@ fn process_batch<P: FeatureProcessor>(
     schema: &Schema,
     geometry_column_index: usize,
     batch_start_idx: usize,
+    options: &mut ProcessOptions,
     processor: &mut P,
 ) -> Result<(), GeozeroError> {
     let num_rows = batch.num_rows();
@@ -109,6 +155,12 @@ fn process_batch<P: FeatureProcessor>(
 
     for within_batch_row_idx in 0..num_rows {
         processor.feature_begin((within_batch_row_idx + batch_start_idx) as u64)?;
+        if let Some((id_column_index, write_id)) = options.feature_ids.as_mut() {
+            write_id(
+                batch.column(*id_column_index).as_ref(),
+                within_batch_row_idx,
+            )?;
+        }
 
         processor.properties_begin()?;
         process_properties(
@@ -116,6 +168,7 @@ fn process_batch<P: FeatureProcessor>(
             schema,
             within_batch_row_idx,
             geometry_column_index,
+            options,
             processor,
         )?;
         processor.properties_end()?;
@@ -135,21 +188,39 @@ fn process_properties<P: PropertyProcessor>(
     schema: &Schema,
     within_batch_row_idx: usize,
     geometry_column_index: usize,
+    options: &ProcessOptions,
     processor: &mut P,
 ) -> Result<(), GeozeroError> {
+    let id_column_index = options
+        .feature_ids
+        .as_ref()
+        .map(|(column_index, _)| *column_index);
     // Note: the `column_idx` will be off by one if the geometry column is not the last column in
     // the table, so we maintain a separate property index counter
     let mut property_idx = 0;
     for (column_idx, (field, array)) in schema.fields.iter().zip(batch.columns().iter()).enumerate()
     {
-        // Don't include geometry column in properties
-        if column_idx == geometry_column_index {
+        // Don't include geometry column, or the feature id column, in properties
+        if column_idx == geometry_column_index || Some(column_idx) == id_column_index {
+            continue;
+        }
+
+        // Bounding box columns can't be represented as properties, and are redundant with the
+        // geometry column.
+        if field
+            .metadata()
+            .get("ARROW:extension:name")
+            .is_some_and(|ext_name| ext_name == "geoarrow.box")
+        {
             continue;
         }
         let name = field.name();
 
         // Don't pass null properties to geozero
         if array.is_null(within_batch_row_idx) {
+            if options.property_index == PropertyIndex::Column {
+                property_idx += 1;
+            }
             continue;
         }
 
@@ -350,6 +421,38 @@ fn process_properties<P: PropertyProcessor>(
                     }
                 }
             }
+            DataType::Time32(unit) | DataType::Time64(unit) => {
+                macro_rules! impl_time {
+                    ($arrow_type:ty) => {{
+                        array
+                            .as_primitive::<$arrow_type>()
+                            .value_as_time(within_batch_row_idx)
+                    }};
+                }
+
+                let time = match unit {
+                    TimeUnit::Second => impl_time!(Time32SecondType),
+                    TimeUnit::Millisecond => impl_time!(Time32MillisecondType),
+                    TimeUnit::Microsecond => impl_time!(Time64MicrosecondType),
+                    TimeUnit::Nanosecond => impl_time!(Time64NanosecondType),
+                }
+                .ok_or_else(|| GeozeroError::Property(format!("Invalid time in {name}")))?;
+                // Geozero has no time type, so times are passed as ISO 8601 strings
+                processor.property(property_idx, name, &ColumnValue::String(&time.to_string()))?;
+            }
+            DataType::FixedSizeBinary(size) => {
+                let arr = array.as_fixed_size_binary();
+                let value = arr.value(within_batch_row_idx);
+                if *size == 16 && is_uuid_field(field) {
+                    processor.property(
+                        property_idx,
+                        name,
+                        &ColumnValue::String(&format_uuid(value)),
+                    )?;
+                } else {
+                    processor.property(property_idx, name, &ColumnValue::Binary(value))?;
+                }
+            }
             dt => todo!("unsupported type: {:?}", dt),
         }
         property_idx += 1;
@@ -358,6 +461,18 @@ fn process_properties<P: PropertyProcessor>(
     Ok(())
 }
 
+/// Format the bytes of a UUID as a hyphenated, lowercase string.
+fn format_uuid(bytes: &[u8]) -> String {
+    let mut uuid = String::with_capacity(36);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            uuid.push('-');
+        }
+        uuid.push_str(&format!("{byte:02x}"));
+    }
+    uuid
+}
+
 fn process_geometry_n<P: GeomProcessor>(
     geometry_column: &Arc<dyn NativeArray>,
     within_batch_row_idx: usize,
@@ -404,9 +519,9 @@ fn process_geometry_n<P: GeomProcessor>(
         //     process_geometry(&geom.to_wkb_object(), 0, processor)?;
         // }
         Rect(_) => {
-            todo!("process rect")
-            // let geom = arr.as_ref().as_rect::<2>().value(i);
-            // process_rect
+            // Formats written through geozero have no box type, so write the polygon instead
+            let geom = arr.as_rect().value(i).to_geo().to_polygon();
+            process_polygon(&geom, true, 0, processor)?;
         }
         Geometry(_) => {
             let geom = arr.as_geometry().value(i);
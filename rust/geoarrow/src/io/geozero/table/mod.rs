@@ -1,5 +1,5 @@
 pub(crate) mod builder;
-mod data_source;
+pub(crate) mod data_source;
 mod json_encoder;
 
 pub use builder::{GeoTableBuilder, GeoTableBuilderOptions};
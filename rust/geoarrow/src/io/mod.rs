@@ -1,6 +1,9 @@
 //! Reader and writer implementations of many common geospatial file formats, including
 //! interoperability with the [`geozero`] crate.
 
+pub(crate) mod canonical;
+#[cfg(feature = "rayon")]
+pub mod convert;
 pub mod crs;
 #[cfg(feature = "csv")]
 pub mod csv;
@@ -9,20 +12,30 @@ pub(crate) mod display;
 pub mod flatgeobuf;
 #[cfg(feature = "gdal")]
 pub mod gdal;
-pub(crate) mod geo;
+pub mod geo;
 pub mod geojson;
 pub mod geojson_lines;
 #[cfg(feature = "geos")]
-pub(crate) mod geos;
+pub mod geos;
 pub mod geozero;
 pub mod ipc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+mod parse_error;
+pub mod polars;
 #[cfg(feature = "postgis")]
 pub mod postgis;
+#[cfg(feature = "round_trip")]
+pub mod round_trip;
 pub mod shapefile;
+pub mod spatialite;
 mod stream;
+pub mod twkb;
+pub(crate) mod uuid;
 pub mod wkb;
 pub mod wkt;
 
-pub use stream::RecordBatchReader;
+pub use parse_error::{ParseErrorPolicy, ParseOptions};
+pub use stream::{
+    ChunkedNativeArrayReader, NativeArrayIterator, NativeArrayReader, RecordBatchReader,
+};
@@ -4,13 +4,15 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::str::FromStr;
 
-use crate::array::metadata::{ArrayMetadata, Edges};
-use crate::array::CoordType;
+use crate::array::metadata::{ArrayMetadata, CrsStrictness, Edges};
+use crate::array::{AsNativeArray, CoordType};
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
 use crate::io::parquet::GeoParquetWriterEncoding;
+use crate::NativeArray;
 
 use arrow_schema::Schema;
+use geo_traits::{Dimensions, GeometryTrait, GeometryType};
 use parquet::file::metadata::FileMetaData;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -53,6 +55,12 @@ impl GeoParquetColumnEncoding {
         let new_encoding = match writer_encoding {
             GeoParquetWriterEncoding::WKB => Self::WKB,
             GeoParquetWriterEncoding::Native => match data_type {
+                dt if dt.dimension().is_some_and(|dim| dim.has_m()) => {
+                    return Err(GeoArrowError::General(format!(
+                        "native encoding does not support M values, use WKB instead: {:?}",
+                        dt
+                    )))
+                }
                 NativeType::Point(_, _) => Self::Point,
                 NativeType::LineString(_, _) => Self::LineString,
                 NativeType::Polygon(_, _) => Self::Polygon,
@@ -181,6 +189,91 @@ impl GeoParquetGeometryType {
         }
     }
 
+    /// This type with the Z dimension of `dim`. M values are ignored, since GeoParquet has no M
+    /// types.
+    fn with_dimension(self, dim: Dimension) -> Self {
+        use GeoParquetGeometryType::*;
+        match (self, dim.has_z()) {
+            (Point | PointZ, false) => Point,
+            (LineString | LineStringZ, false) => LineString,
+            (Polygon | PolygonZ, false) => Polygon,
+            (MultiPoint | MultiPointZ, false) => MultiPoint,
+            (MultiLineString | MultiLineStringZ, false) => MultiLineString,
+            (MultiPolygon | MultiPolygonZ, false) => MultiPolygon,
+            (GeometryCollection | GeometryCollectionZ, false) => GeometryCollection,
+            (Point | PointZ, true) => PointZ,
+            (LineString | LineStringZ, true) => LineStringZ,
+            (Polygon | PolygonZ, true) => PolygonZ,
+            (MultiPoint | MultiPointZ, true) => MultiPointZ,
+            (MultiLineString | MultiLineStringZ, true) => MultiLineStringZ,
+            (MultiPolygon | MultiPolygonZ, true) => MultiPolygonZ,
+            (GeometryCollection | GeometryCollectionZ, true) => GeometryCollectionZ,
+        }
+    }
+
+    /// The type of a single geometry. Geometries with M values are typed by their other
+    /// dimensions, since GeoParquet has no M types.
+    pub(crate) fn from_geometry(geom: &impl GeometryTrait) -> Self {
+        use GeoParquetGeometryType::*;
+        let geometry_type = match geom.geometry_type() {
+            GeometryType::Point(_) => Point,
+            GeometryType::LineString(_) | GeometryType::Line(_) => LineString,
+            GeometryType::Polygon(_) | GeometryType::Rect(_) | GeometryType::Triangle(_) => Polygon,
+            GeometryType::MultiPoint(_) => MultiPoint,
+            GeometryType::MultiLineString(_) => MultiLineString,
+            GeometryType::MultiPolygon(_) => MultiPolygon,
+            GeometryType::GeometryCollection(_) => GeometryCollection,
+        };
+        match geom.dim() {
+            Dimensions::Xyz | Dimensions::Xyzm => geometry_type.with_dimension(Dimension::XYZ),
+            _ => geometry_type,
+        }
+    }
+
+    /// The types of the geometries of an array.
+    ///
+    /// Only geometry arrays are inspected, as the geometry type of other arrays is known from
+    /// their data type.
+    pub(crate) fn from_array(array: &dyn NativeArray) -> HashSet<Self> {
+        use GeoParquetGeometryType::*;
+        match array.data_type() {
+            NativeType::Point(_, dim) => HashSet::from([Point.with_dimension(dim)]),
+            NativeType::LineString(_, dim) => HashSet::from([LineString.with_dimension(dim)]),
+            NativeType::Polygon(_, dim) | NativeType::Rect(dim) => {
+                HashSet::from([Polygon.with_dimension(dim)])
+            }
+            NativeType::MultiPoint(_, dim) => HashSet::from([MultiPoint.with_dimension(dim)]),
+            NativeType::MultiLineString(_, dim) => {
+                HashSet::from([MultiLineString.with_dimension(dim)])
+            }
+            NativeType::MultiPolygon(_, dim) => HashSet::from([MultiPolygon.with_dimension(dim)]),
+            NativeType::GeometryCollection(_, dim) => {
+                HashSet::from([GeometryCollection.with_dimension(dim)])
+            }
+            NativeType::Geometry(_) => {
+                let array = array.as_geometry();
+                let mut geometry_types = HashSet::new();
+                for dim in [Dimension::XY, Dimension::XYZ] {
+                    let children = [
+                        (array.has_points(dim), Point),
+                        (array.has_line_strings(dim), LineString),
+                        (array.has_polygons(dim), Polygon),
+                        (array.has_multi_points(dim), MultiPoint),
+                        (array.has_multi_line_strings(dim), MultiLineString),
+                        (array.has_multi_polygons(dim), MultiPolygon),
+                        (array.has_geometry_collections(dim), GeometryCollection),
+                    ];
+                    for (has_child, geometry_type) in children {
+                        if has_child {
+                            geometry_types.insert(geometry_type.with_dimension(dim));
+                        }
+                    }
+                }
+                geometry_types
+            }
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn has_z(&self) -> bool {
         match self {
@@ -598,7 +691,15 @@ impl GeoParquetMetadata {
 
             match (left.crs.as_ref(), right.crs.as_ref()) {
                 (Some(left_crs), Some(right_crs)) => {
-                    if left_crs != right_crs {
+                    let left_crs = ArrayMetadata {
+                        crs: Some(left_crs.clone()),
+                        ..Default::default()
+                    };
+                    let right_crs = ArrayMetadata {
+                        crs: Some(right_crs.clone()),
+                        ..Default::default()
+                    };
+                    if !left_crs.crs_equivalent_to(&right_crs, CrsStrictness::default()) {
                         return Err(GeoArrowError::General(format!(
                             "Different GeoParquet CRS for column {}",
                             key
@@ -655,6 +756,9 @@ impl From<GeoParquetColumnMetadata> for ArrayMetadata {
                 meta = meta.with_edges(Edges::Spherical);
             }
         };
+        if let Some(epoch) = value.epoch {
+            meta = meta.with_epoch(epoch);
+        }
         meta
     }
 }
@@ -664,17 +768,16 @@ impl From<&GeoParquetColumnMetadata> for ArrayMetadata {
         value.clone().into()
     }
 }
-// TODO: deduplicate with `resolve_types` in `downcast.rs`
 pub(crate) fn infer_geo_data_type(
     geometry_types: &HashSet<GeoParquetGeometryType>,
     coord_type: CoordType,
 ) -> Result<Option<NativeType>> {
     use GeoParquetGeometryType::*;
 
-    match geometry_types.len() {
-        // TODO: for unknown geometry type, should we leave it as WKB?
-        0 => Ok(None),
-        1 => Ok(Some(match *geometry_types.iter().next().unwrap() {
+    // TODO: for unknown geometry type, should we leave it as WKB?
+    let native_types = geometry_types
+        .iter()
+        .map(|geometry_type| match geometry_type {
             Point => NativeType::Point(coord_type, Dimension::XY),
             LineString => NativeType::LineString(coord_type, Dimension::XY),
             Polygon => NativeType::Polygon(coord_type, Dimension::XY),
@@ -689,89 +792,8 @@ pub(crate) fn infer_geo_data_type(
             MultiLineStringZ => NativeType::MultiLineString(coord_type, Dimension::XYZ),
             MultiPolygonZ => NativeType::MultiPolygon(coord_type, Dimension::XYZ),
             GeometryCollectionZ => NativeType::GeometryCollection(coord_type, Dimension::XYZ),
-        })),
-        _ => {
-            // Check if we can cast to MultiPoint
-            let mut point_count = 0;
-            if geometry_types.contains(&Point) {
-                point_count += 1;
-            }
-            if geometry_types.contains(&MultiPoint) {
-                point_count += 1;
-            }
-
-            if geometry_types.len() == point_count {
-                return Ok(Some(NativeType::MultiPoint(coord_type, Dimension::XY)));
-            }
-
-            // Check if we can cast to MultiPointZ
-            if geometry_types.contains(&PointZ) {
-                point_count += 1;
-            }
-            if geometry_types.contains(&MultiPointZ) {
-                point_count += 1;
-            }
-
-            if geometry_types.len() == point_count {
-                return Ok(Some(NativeType::MultiPoint(coord_type, Dimension::XYZ)));
-            }
-
-            // Check if we can cast to MultiLineString
-            let mut linestring_count = 0;
-            if geometry_types.contains(&LineString) {
-                linestring_count += 1;
-            }
-            if geometry_types.contains(&MultiLineString) {
-                linestring_count += 1;
-            }
-
-            if geometry_types.len() == linestring_count {
-                return Ok(Some(NativeType::MultiLineString(coord_type, Dimension::XY)));
-            }
-
-            // Check if we can cast to MultiLineStringZ
-            if geometry_types.contains(&LineStringZ) {
-                linestring_count += 1;
-            }
-            if geometry_types.contains(&MultiLineStringZ) {
-                linestring_count += 1;
-            }
-
-            if geometry_types.len() == linestring_count {
-                return Ok(Some(NativeType::MultiLineString(
-                    coord_type,
-                    Dimension::XYZ,
-                )));
-            }
-
-            // Check if we can cast to MultiPolygon
-            let mut polygon_count = 0;
-            if geometry_types.contains(&Polygon) {
-                polygon_count += 1;
-            }
-            if geometry_types.contains(&MultiPolygon) {
-                polygon_count += 1;
-            }
-
-            if geometry_types.len() == polygon_count {
-                return Ok(Some(NativeType::MultiPolygon(coord_type, Dimension::XY)));
-            }
-
-            // Check if we can cast to MultiPolygonZ
-            if geometry_types.contains(&PolygonZ) {
-                polygon_count += 1;
-            }
-            if geometry_types.contains(&MultiPolygonZ) {
-                polygon_count += 1;
-            }
-
-            if geometry_types.len() == polygon_count {
-                return Ok(Some(NativeType::MultiPolygon(coord_type, Dimension::XYZ)));
-            }
-
-            Ok(Some(NativeType::Geometry(coord_type)))
-        }
-    }
+        });
+    Ok(NativeType::common_type_of(native_types))
 }
 
 /// Find all geometry columns in the Arrow schema, constructing their NativeTypes
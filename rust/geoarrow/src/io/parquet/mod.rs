@@ -67,7 +67,7 @@ mod writer;
 
 pub use reader::{
     GeoParquetDatasetMetadata, GeoParquetReaderMetadata, GeoParquetReaderOptions,
-    GeoParquetRecordBatchReader, GeoParquetRecordBatchReaderBuilder,
+    GeoParquetRecordBatchReader, GeoParquetRecordBatchReaderBuilder, GeometryTypesPolicy,
 };
 #[cfg(feature = "parquet_async")]
 pub use reader::{GeoParquetRecordBatchStream, GeoParquetRecordBatchStreamBuilder};
@@ -3,7 +3,7 @@ use crate::io::parquet::metadata::GeoParquetMetadata;
 use crate::io::parquet::reader::builder::GeoParquetReaderBuilder;
 use crate::io::parquet::reader::metadata::GeoParquetReaderMetadata;
 use crate::io::parquet::reader::options::GeoParquetReaderOptions;
-use crate::io::parquet::reader::parse::{infer_target_schema, parse_record_batch};
+use crate::io::parquet::reader::parse::{infer_target_schema, parse_record_batch, ColumnChecks};
 use crate::table::Table;
 
 use arrow_array::RecordBatch;
@@ -86,6 +86,7 @@ impl<T: AsyncFileReader + Send + 'static> GeoParquetRecordBatchStreamBuilder<T>
     /// Consume this builder, returning a [`GeoParquetRecordBatchStream`]
     pub fn build(self) -> Result<GeoParquetRecordBatchStream<T>> {
         let output_schema = self.output_schema()?;
+        let checks = ColumnChecks::new(self.geo_meta.as_ref(), &self.options);
         let builder = self
             .options
             .apply_to_builder(self.builder, self.geo_meta.as_ref())?;
@@ -93,6 +94,7 @@ impl<T: AsyncFileReader + Send + 'static> GeoParquetRecordBatchStreamBuilder<T>
         Ok(GeoParquetRecordBatchStream {
             stream,
             output_schema,
+            checks,
         })
     }
 }
@@ -116,7 +118,11 @@ impl<T: AsyncFileReader + Send + 'static> GeoParquetReaderBuilder
 {
     fn output_schema(&self) -> Result<SchemaRef> {
         if let Some(geo_meta) = &self.geo_meta {
-            infer_target_schema(self.builder.schema(), geo_meta, self.options.coord_type)
+            infer_target_schema(
+                self.builder.schema(),
+                &self.options.target_geo_meta(geo_meta),
+                self.options.coord_type,
+            )
         } else {
             // If non-geospatial, return the same schema as output
             Ok(self.builder.schema().clone())
@@ -136,6 +142,7 @@ impl<T: AsyncFileReader + Send + 'static> GeoParquetReaderBuilder
 pub struct GeoParquetRecordBatchStream<T: AsyncFileReader + Send + 'static> {
     stream: ParquetRecordBatchStream<T>,
     output_schema: SchemaRef,
+    checks: ColumnChecks,
 }
 
 impl<T: AsyncFileReader + Unpin + Send + 'static> GeoParquetRecordBatchStream<T> {
@@ -148,7 +155,7 @@ impl<T: AsyncFileReader + Unpin + Send + 'static> GeoParquetRecordBatchStream<T>
     ) -> impl Stream<Item = std::result::Result<RecordBatch, ArrowError>> + 'static {
         try_stream! {
             for await batch in self.stream {
-                yield parse_record_batch(batch?, self.output_schema.clone()).map_err(|err| ArrowError::CastError(err.to_string()))?
+                yield parse_record_batch(batch?, self.output_schema.clone(), &self.checks).map_err(|err| ArrowError::CastError(err.to_string()))?
             }
         }
     }
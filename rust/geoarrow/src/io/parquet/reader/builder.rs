@@ -10,7 +10,7 @@ use crate::error::Result;
 use crate::io::parquet::metadata::GeoParquetMetadata;
 use crate::io::parquet::reader::metadata::GeoParquetReaderMetadata;
 use crate::io::parquet::reader::options::GeoParquetReaderOptions;
-use crate::io::parquet::reader::parse::{infer_target_schema, parse_record_batch};
+use crate::io::parquet::reader::parse::{infer_target_schema, parse_record_batch, ColumnChecks};
 use crate::table::Table;
 
 pub trait GeoParquetReaderBuilder: Sized {
@@ -79,6 +79,7 @@ impl<T: ChunkReader + 'static> GeoParquetRecordBatchReaderBuilder<T> {
     /// Consume this builder, returning a [`GeoParquetRecordBatchReader`]
     pub fn build(self) -> Result<GeoParquetRecordBatchReader> {
         let output_schema = self.output_schema()?;
+        let checks = ColumnChecks::new(self.geo_meta.as_ref(), &self.options);
         let builder = self
             .options
             .apply_to_builder(self.builder, self.geo_meta.as_ref())?;
@@ -86,6 +87,7 @@ impl<T: ChunkReader + 'static> GeoParquetRecordBatchReaderBuilder<T> {
         Ok(GeoParquetRecordBatchReader {
             reader,
             output_schema,
+            checks,
         })
     }
 }
@@ -107,7 +109,11 @@ impl<T: ChunkReader + 'static> From<ParquetRecordBatchReaderBuilder<T>>
 impl<T: ChunkReader + 'static> GeoParquetReaderBuilder for GeoParquetRecordBatchReaderBuilder<T> {
     fn output_schema(&self) -> Result<SchemaRef> {
         if let Some(geo_meta) = &self.geo_meta {
-            infer_target_schema(self.builder.schema(), geo_meta, self.options.coord_type)
+            infer_target_schema(
+                self.builder.schema(),
+                &self.options.target_geo_meta(geo_meta),
+                self.options.coord_type,
+            )
         } else {
             // If non-geospatial, return the same schema as output
             Ok(self.builder.schema().clone())
@@ -125,6 +131,7 @@ impl<T: ChunkReader + 'static> GeoParquetReaderBuilder for GeoParquetRecordBatch
 pub struct GeoParquetRecordBatchReader {
     reader: ParquetRecordBatchReader,
     output_schema: SchemaRef,
+    checks: ColumnChecks,
 }
 
 impl GeoParquetRecordBatchReader {
@@ -143,7 +150,7 @@ impl Iterator for GeoParquetRecordBatchReader {
         if let Some(batch) = self.reader.next() {
             match batch {
                 Ok(batch) => Some(
-                    parse_record_batch(batch, self.output_schema.clone())
+                    parse_record_batch(batch, self.output_schema.clone(), &self.checks)
                         .map_err(|err| ArrowError::CastError(err.to_string())),
                 ),
                 Err(err) => Some(Err(err)),
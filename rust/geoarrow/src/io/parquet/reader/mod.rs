@@ -8,7 +8,7 @@ mod spatial_filter;
 
 pub use builder::{GeoParquetRecordBatchReader, GeoParquetRecordBatchReaderBuilder};
 pub use metadata::{GeoParquetDatasetMetadata, GeoParquetReaderMetadata};
-pub use options::GeoParquetReaderOptions;
+pub use options::{GeoParquetReaderOptions, GeometryTypesPolicy};
 #[cfg(feature = "parquet_async")]
 pub use r#async::{GeoParquetRecordBatchStream, GeoParquetRecordBatchStreamBuilder};
 
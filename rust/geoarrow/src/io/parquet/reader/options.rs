@@ -2,13 +2,33 @@ use geo::Rect;
 use parquet::arrow::arrow_reader::ArrowReaderBuilder;
 use parquet::arrow::ProjectionMask;
 
+use crate::algorithm::native::RingOrientation;
 use crate::array::CoordType;
 use crate::error::{GeoArrowError, Result};
-use crate::io::parquet::metadata::{GeoParquetBboxCovering, GeoParquetMetadata};
+use crate::io::parquet::metadata::{
+    GeoParquetBboxCovering, GeoParquetColumnEncoding, GeoParquetMetadata,
+};
 use crate::io::parquet::reader::spatial_filter::{
     apply_bbox_row_filter, apply_bbox_row_groups, ParquetBboxStatistics,
 };
 
+/// How the `geometry_types` declared in the GeoParquet metadata of a column are used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GeometryTypesPolicy {
+    /// Trust the declared types, which choose the array type that WKB columns are parsed to.
+    ///
+    /// Geometries of an undeclared type are an error if they can't be parsed to that array type.
+    #[default]
+    Trust,
+
+    /// Check that every geometry has one of the declared types, and error otherwise.
+    Validate,
+
+    /// Ignore the declared types of WKB columns, which are parsed to geometry arrays that hold
+    /// any geometry type. This reads files whose declared types don't match their data.
+    Infer,
+}
+
 /// Options for reading (Geo)Parquet
 ///
 /// Geospatial options will only be applied if the target file has geospatial metadata.
@@ -41,6 +61,12 @@ pub struct GeoParquetReaderOptions {
     /// The paths in the Parquet schema to the bounding box columns. This will not be necessary as
     /// of GeoParquet 1.1.
     bbox_paths: Option<GeoParquetBboxCovering>,
+
+    /// How the declared geometry types of each column are used.
+    pub(crate) geometry_types: GeometryTypesPolicy,
+
+    /// The orientation to give polygons, if any.
+    pub(crate) orientation: Option<RingOrientation>,
 }
 
 impl GeoParquetReaderOptions {
@@ -115,6 +141,39 @@ impl GeoParquetReaderOptions {
         }
     }
 
+    /// Set how the `geometry_types` declared in the GeoParquet metadata are used.
+    pub fn with_geometry_types_policy(self, geometry_types: GeometryTypesPolicy) -> Self {
+        Self {
+            geometry_types,
+            ..self
+        }
+    }
+
+    /// Reorient the rings of polygons, e.g. to produce valid GeoJSON.
+    ///
+    /// Columns whose declared `"orientation"` is already counterclockwise aren't reoriented to
+    /// [`RingOrientation::CounterClockwise`].
+    pub fn with_orientation(self, orientation: RingOrientation) -> Self {
+        Self {
+            orientation: Some(orientation),
+            ..self
+        }
+    }
+
+    /// The GeoParquet metadata used to infer the output schema, without the declared geometry
+    /// types of WKB columns if they're ignored.
+    pub(crate) fn target_geo_meta(&self, geo_meta: &GeoParquetMetadata) -> GeoParquetMetadata {
+        let mut geo_meta = geo_meta.clone();
+        if self.geometry_types == GeometryTypesPolicy::Infer {
+            for column_meta in geo_meta.columns.values_mut() {
+                if column_meta.encoding == GeoParquetColumnEncoding::WKB {
+                    column_meta.geometry_types.clear();
+                }
+            }
+        }
+        geo_meta
+    }
+
     /// Apply these settings to an [ArrowReaderBuilder]
     pub(crate) fn apply_to_builder<T>(
         self,
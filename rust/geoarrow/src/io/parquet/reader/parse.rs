@@ -1,22 +1,26 @@
 //! Parse an Arrow record batch given GeoParquet metadata
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, OffsetSizeTrait, RecordBatch, StructArray};
 use arrow_schema::{DataType, Field, FieldRef, Schema, SchemaRef};
 
+use crate::algorithm::native::{FixRings, FixRingsOptions, RingOrientation};
 use crate::array::{
     CoordType, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    PointArray, PolygonArray, WKBArray,
+    NativeArrayDyn, PointArray, PolygonArray, RectArray, WKBArray,
 };
-use crate::datatypes::{AnyType, Dimension, NativeType, SerializedType};
+use crate::datatypes::{rect_fields, AnyType, Dimension, NativeType, SerializedType};
 use crate::error::{GeoArrowError, Result};
 use crate::io::parquet::metadata::{
     infer_geo_data_type, GeoParquetColumnEncoding, GeoParquetColumnMetadata,
     GeoParquetGeometryType, GeoParquetMetadata,
 };
+use crate::io::parquet::reader::options::{GeoParquetReaderOptions, GeometryTypesPolicy};
 use crate::io::wkb::from_wkb;
+use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 
 pub fn infer_target_schema(
@@ -24,10 +28,23 @@ pub fn infer_target_schema(
     geo_meta: &GeoParquetMetadata,
     coord_type: CoordType,
 ) -> Result<SchemaRef> {
+    let covering_columns = bbox_covering_columns(geo_meta);
     let mut new_fields: Vec<FieldRef> = Vec::with_capacity(existing_schema.fields().len());
     for existing_field in existing_schema.fields() {
         if let Some(column_meta) = geo_meta.columns.get(existing_field.name()) {
             new_fields.push(infer_target_field(existing_field, column_meta, coord_type)?)
+        } else if let (Some((dim, column_meta)), DataType::Struct(_), false) = (
+            covering_columns.get(existing_field.name().as_str()),
+            existing_field.data_type(),
+            existing_field
+                .metadata()
+                .contains_key("ARROW:extension:name"),
+        ) {
+            new_fields.push(Arc::new(NativeType::Rect(*dim).to_field_with_metadata(
+                existing_field.name(),
+                existing_field.is_nullable(),
+                &(*column_meta).into(),
+            )))
         } else {
             new_fields.push(existing_field.clone());
         }
@@ -39,6 +56,32 @@ pub fn infer_target_schema(
     )))
 }
 
+/// The bounding box covering columns referenced by the GeoParquet metadata.
+///
+/// Returns a map from the name of each covering column to its dimension and the metadata of the
+/// geometry column it covers.
+fn bbox_covering_columns(
+    geo_meta: &GeoParquetMetadata,
+) -> HashMap<&str, (Dimension, &GeoParquetColumnMetadata)> {
+    geo_meta
+        .columns
+        .values()
+        .filter_map(|column_meta| {
+            let bbox = &column_meta.covering.as_ref()?.bbox;
+            // Only top-level struct columns can be read as boxes
+            if bbox.xmin.len() != 2 {
+                return None;
+            }
+            let dim = if bbox.zmin.is_some() {
+                Dimension::XYZ
+            } else {
+                Dimension::XY
+            };
+            Some((bbox.xmin[0].as_str(), (dim, column_meta)))
+        })
+        .collect()
+}
+
 /// For native encodings we always load to the separated encoding so that we don't need an extra
 /// copy.
 fn infer_target_field(
@@ -137,8 +180,118 @@ fn infer_target_wkb_type(
     )
 }
 
+/// Checks of the declared geometry types, and fixes of the ring orientation, applied to the
+/// geometry columns of each batch while parsing.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ColumnChecks {
+    /// The declared geometry types to validate the data against, by column name. Columns without
+    /// declared types aren't validated.
+    geometry_types: HashMap<String, HashSet<GeoParquetGeometryType>>,
+
+    /// The orientation to give polygons, and the columns to reorient.
+    orientation: Option<(RingOrientation, HashSet<String>)>,
+}
+
+impl ColumnChecks {
+    pub(crate) fn new(
+        geo_meta: Option<&GeoParquetMetadata>,
+        options: &GeoParquetReaderOptions,
+    ) -> Self {
+        let Some(geo_meta) = geo_meta else {
+            return Self::default();
+        };
+
+        let geometry_types = if options.geometry_types == GeometryTypesPolicy::Validate {
+            geo_meta
+                .columns
+                .iter()
+                .filter(|(_, column_meta)| !column_meta.geometry_types.is_empty())
+                .map(|(name, column_meta)| (name.clone(), column_meta.geometry_types.clone()))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        let orientation = options.orientation.map(|orientation| {
+            let columns = geo_meta
+                .columns
+                .iter()
+                .filter(|(_, column_meta)| {
+                    orientation != RingOrientation::CounterClockwise
+                        || column_meta.orientation.as_deref() != Some("counterclockwise")
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            (orientation, columns)
+        });
+
+        Self {
+            geometry_types,
+            orientation,
+        }
+    }
+
+    /// Validate a column, before and after it's parsed, and reorient its polygons.
+    fn apply(
+        &self,
+        name: &str,
+        original: &dyn Array,
+        parsed: ArrayRef,
+        target_field: &Field,
+    ) -> Result<ArrayRef> {
+        let validate = self.geometry_types.get(name);
+        let reorient = self
+            .orientation
+            .as_ref()
+            .filter(|(_, columns)| columns.contains(name));
+        if validate.is_none() && reorient.is_none() {
+            return Ok(parsed);
+        }
+
+        let array = NativeArrayDyn::from_arrow_array(&parsed, target_field)?.into_inner();
+        if let Some(declared) = validate {
+            // WKB is checked geometry by geometry, as its parsed array type comes from the
+            // declared types.
+            let actual = match original.data_type() {
+                DataType::Binary => wkb_geometry_types(&WKBArray::<i32>::try_from(original)?)?,
+                DataType::LargeBinary => wkb_geometry_types(&WKBArray::<i64>::try_from(original)?)?,
+                _ => GeoParquetGeometryType::from_array(array.as_ref()),
+            };
+            if let Some(undeclared) = actual.difference(declared).next() {
+                return Err(GeoArrowError::General(format!(
+                    "Column {name} has {undeclared} geometries, which aren't declared"
+                )));
+            }
+        }
+        match reorient {
+            Some((orientation, _)) => {
+                let options = FixRingsOptions {
+                    orientation: Some(*orientation),
+                    ..Default::default()
+                };
+                Ok(array.as_ref().fix_rings(options).0.to_array_ref())
+            }
+            None => Ok(parsed),
+        }
+    }
+}
+
+fn wkb_geometry_types<O: OffsetSizeTrait>(
+    array: &WKBArray<O>,
+) -> Result<HashSet<GeoParquetGeometryType>> {
+    let mut geometry_types = HashSet::new();
+    for wkb in array.iter().flatten() {
+        geometry_types.insert(GeoParquetGeometryType::from_geometry(&wkb.parse()?));
+    }
+    Ok(geometry_types)
+}
+
 /// Parse a record batch to a GeoArrow record batch.
-pub fn parse_record_batch(batch: RecordBatch, target_schema: SchemaRef) -> Result<RecordBatch> {
+pub fn parse_record_batch(
+    batch: RecordBatch,
+    target_schema: SchemaRef,
+    checks: &ColumnChecks,
+) -> Result<RecordBatch> {
     let orig_columns = batch.columns().to_vec();
     let mut output_columns = Vec::with_capacity(orig_columns.len());
 
@@ -153,14 +306,19 @@ pub fn parse_record_batch(batch: RecordBatch, target_schema: SchemaRef) -> Resul
         // some fields are desired to be parsed.
         assert_eq!(orig_field.name(), target_field.name());
 
-        if orig_field.data_type() != target_field.data_type()
+        let output_column = if orig_field.data_type() != target_field.data_type()
             || orig_field.metadata() != target_field.metadata()
         {
-            let output_column = parse_array(column, orig_field, target_field)?;
-            output_columns.push(output_column);
+            parse_array(column.clone(), orig_field, target_field)?
         } else {
-            output_columns.push(column);
-        }
+            column.clone()
+        };
+        output_columns.push(checks.apply(
+            orig_field.name(),
+            column.as_ref(),
+            output_column,
+            target_field,
+        )?);
     }
 
     Ok(RecordBatch::try_new(target_schema, output_columns)?)
@@ -170,6 +328,11 @@ pub fn parse_record_batch(batch: RecordBatch, target_schema: SchemaRef) -> Resul
 fn parse_array(array: ArrayRef, orig_field: &Field, target_field: &Field) -> Result<ArrayRef> {
     use NativeType::*;
 
+    // Bounding box covering columns are plain structs without extension metadata
+    if let Ok(Rect(dim)) = NativeType::try_from(target_field) {
+        return parse_rect_column(array.as_ref(), dim);
+    }
+
     let orig_type = AnyType::try_from(orig_field)?;
     let arr = array.as_ref();
     match orig_type {
@@ -222,6 +385,33 @@ fn parse_point_column(array: &dyn Array, dim: Dimension) -> Result<ArrayRef> {
     Ok(geom_arr.into_array_ref())
 }
 
+/// Parse a GeoParquet bounding box struct column to a [RectArray].
+///
+/// The bounding box columns may be stored as float32 and in any field order.
+fn parse_rect_column(array: &dyn Array, dim: Dimension) -> Result<ArrayRef> {
+    let struct_array = array.as_struct_opt().ok_or(GeoArrowError::General(format!(
+        "Expected bounding box column to have struct data type, got {}",
+        array.data_type()
+    )))?;
+    let names = rect_fields(dim);
+
+    let mut fields = Vec::with_capacity(names.len());
+    let mut columns = Vec::with_capacity(names.len());
+    for name in names.iter().map(|field| field.name()) {
+        let column = struct_array
+            .column_by_name(name)
+            .ok_or(GeoArrowError::General(format!(
+                "Missing field '{name}' in bounding box column"
+            )))?;
+        fields.push(Field::new(name, DataType::Float64, true));
+        columns.push(arrow_cast::cast(column, &DataType::Float64)?);
+    }
+
+    let bbox_array = StructArray::try_new(fields.into(), columns, struct_array.nulls().cloned())?;
+    let rect_array = RectArray::try_from((&bbox_array as &dyn Array, dim))?;
+    Ok(rect_array.into_array_ref())
+}
+
 macro_rules! impl_parse_fn {
     ($fn_name:ident, $geoarrow_type:ty) => {
         fn $fn_name(array: &dyn Array, dim: Dimension) -> Result<ArrayRef> {
@@ -5,12 +5,27 @@ use std::sync::Arc;
 use arrow_array::{BooleanArray, RecordBatch};
 use arrow_schema::{Field, Schema};
 use bytes::Bytes;
+use geo::Winding;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::file::metadata::KeyValue;
 
-use crate::array::GeometryBuilder;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{GeometryBuilder, PolygonArray, RectArray};
 use crate::chunked_array::ChunkedNativeArrayDyn;
+use crate::datatypes::{Dimension, NativeType};
 use crate::error::Result;
-use crate::io::parquet::{write_geoparquet, GeoParquetRecordBatchReaderBuilder};
+use crate::io::parquet::metadata::GeoParquetMetadata;
+use crate::io::parquet::{
+    write_geoparquet, GeoParquetReaderOptions, GeoParquetRecordBatchReaderBuilder,
+    GeoParquetWriterOptions, GeometryTypesPolicy,
+};
+use crate::io::wkb::ToWKB;
+use crate::schema::GeoSchemaExt;
 use crate::table::Table;
+use crate::test::point;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
 
 #[ignore = "fails!"]
 #[test]
@@ -71,3 +86,134 @@ fn mixed_geometry_roundtrip() {
         .read_table()
         .unwrap();
 }
+
+#[test]
+fn box_column_roundtrip() -> Result<()> {
+    let geometry = point::point_array();
+    let bbox = RectArray::from((
+        vec![geo::Rect::new(
+            geo::coord! { x: 0., y: 1. },
+            geo::coord! { x: 2., y: 3. },
+        )]
+        .repeat(geometry.len())
+        .as_slice(),
+        Dimension::XY,
+    ));
+    let schema = Arc::new(Schema::new(vec![
+        geometry.extension_field(),
+        Arc::new(NativeType::Rect(Dimension::XY).to_field("bbox", true)),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![geometry.into_array_ref(), bbox.clone().into_array_ref()],
+    )?;
+    let table = Table::try_new(vec![batch], schema)?;
+
+    let mut buf = vec![];
+    write_geoparquet(
+        table.into_record_batch_reader(),
+        Cursor::new(&mut buf),
+        &Default::default(),
+    )?;
+    let again = GeoParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf))?
+        .build()?
+        .read_table()?;
+
+    // The box column is not a GeoParquet geometry column, but keeps its extension type
+    assert_eq!(again.schema().as_ref().geometry_columns().len(), 1);
+    let bbox_field = again.schema().field_with_name("bbox")?;
+    assert_eq!(
+        bbox_field.metadata()["ARROW:extension:name"],
+        "geoarrow.box"
+    );
+    let bbox_again = RectArray::try_from((again.batches()[0].column(1).as_ref(), Dimension::XY))?;
+    assert_eq!(bbox_again.value_as_geo(0), bbox.value_as_geo(0));
+    Ok(())
+}
+
+fn read_geo_meta(buf: &[u8]) -> GeoParquetMetadata {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::copy_from_slice(buf)).unwrap();
+    GeoParquetMetadata::from_parquet_meta(builder.metadata().file_metadata()).unwrap()
+}
+
+#[test]
+fn orientation_and_epoch_roundtrip() -> Result<()> {
+    // A clockwise exterior ring
+    let polygon = geo::polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)];
+    let polygons: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+    let polygons = polygons.with_metadata(Arc::new(ArrayMetadata::default().with_epoch(2021.47)));
+    let schema = Arc::new(Schema::new(vec![polygons.extension_field()]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![polygons.to_array_ref()])?;
+    let table = Table::try_new(vec![batch], schema)?;
+
+    let mut buf = vec![];
+    let options = GeoParquetWriterOptions {
+        orient_polygons: true,
+        ..Default::default()
+    };
+    write_geoparquet(
+        table.into_record_batch_reader(),
+        Cursor::new(&mut buf),
+        &options,
+    )?;
+
+    let column_meta = &read_geo_meta(&buf).columns["geometry"];
+    assert_eq!(column_meta.orientation.as_deref(), Some("counterclockwise"));
+    assert_eq!(column_meta.epoch, Some(2021.47));
+
+    let again = GeoParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf))?
+        .build()?
+        .read_table()?;
+    let field = again.schema().field(0);
+    assert_eq!(ArrayMetadata::try_from(field)?.epoch(), Some(2021.47));
+    let polygons = PolygonArray::try_from((again.batches()[0].column(0).as_ref(), field))?;
+    assert!(polygons.value_as_geo(0).exterior().is_ccw());
+    Ok(())
+}
+
+#[test]
+fn geometry_types_policy() -> Result<()> {
+    let mut builder = GeometryBuilder::new();
+    builder.push_point(Some(&geo::point!(x: 0., y: 1.)))?;
+    builder.push_line_string(Some(&geo::line_string![(x: 0., y: 0.), (x: 1., y: 1.)]))?;
+    let wkb = builder.finish().as_ref().to_wkb::<i32>();
+
+    // A file whose metadata only declares points
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "geometry",
+        arrow_schema::DataType::Binary,
+        true,
+    )]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![wkb.into_array_ref()])?;
+    let mut buf = vec![];
+    let mut writer = ArrowWriter::try_new(&mut buf, schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    let geo_meta = r#"{
+        "version": "1.1.0",
+        "primary_column": "geometry",
+        "columns": {"geometry": {"encoding": "WKB", "geometry_types": ["Point"]}}
+    }"#;
+    writer.append_key_value_metadata(KeyValue::new("geo".to_string(), geo_meta.to_string()));
+    writer.close().unwrap();
+
+    let read = |policy| {
+        let options = GeoParquetReaderOptions::default().with_geometry_types_policy(policy);
+        GeoParquetRecordBatchReaderBuilder::try_new_with_options(
+            Bytes::from(buf.clone()),
+            Default::default(),
+            options,
+        )?
+        .build()?
+        .read_table()
+    };
+    assert!(read(GeometryTypesPolicy::Validate).is_err());
+
+    let table = read(GeometryTypesPolicy::Infer)?;
+    let field = table.schema().field(0);
+    assert!(matches!(
+        NativeType::try_from(field)?,
+        NativeType::Geometry(_)
+    ));
+    assert_eq!(table.len(), 2);
+    Ok(())
+}
@@ -1,25 +1,29 @@
-use crate::error::Result;
-use crate::io::parquet::writer::encode::encode_record_batch;
+use std::sync::Arc;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::parquet::metadata::GeoParquetColumnEncoding;
+use crate::io::parquet::writer::encode::{encode_batch, encode_record_batch};
 use crate::io::parquet::writer::metadata::GeoParquetMetadataBuilder;
 use crate::io::parquet::writer::options::GeoParquetWriterOptions;
 use arrow_array::{RecordBatch, RecordBatchReader};
 use arrow_schema::Schema;
+use futures::{Stream, StreamExt};
 use parquet::arrow::AsyncArrowWriter;
 use parquet::file::metadata::KeyValue;
 use tokio::io::AsyncWrite;
 
 /// Write a [RecordBatchReader] to GeoParquet.
+///
+/// Geometry columns are encoded concurrently with writing when called from within a tokio
+/// runtime, see [`GeoParquetWriterAsync::write_stream`].
 pub async fn write_geoparquet_async<W: AsyncWrite + Unpin + Send>(
     stream: Box<dyn RecordBatchReader>,
     writer: W,
     options: &GeoParquetWriterOptions,
 ) -> Result<()> {
     let mut parquet_writer = GeoParquetWriterAsync::try_new(writer, &stream.schema(), options)?;
-
-    for batch in stream {
-        parquet_writer.write_batch(&batch?).await?;
-    }
-
+    let batches = futures::stream::iter(stream.map(|batch| Ok(batch?)));
+    parquet_writer.write_stream(batches).await?;
     parquet_writer.finish().await?;
     Ok(())
 }
@@ -28,6 +32,7 @@ pub async fn write_geoparquet_async<W: AsyncWrite + Unpin + Send>(
 pub struct GeoParquetWriterAsync<W: AsyncWrite + Unpin + Send> {
     writer: AsyncArrowWriter<W>,
     metadata_builder: GeoParquetMetadataBuilder,
+    encoding_concurrency: usize,
 }
 
 impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
@@ -41,12 +46,26 @@ impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
             options.writer_properties.clone(),
         )?;
 
+        let encoding_concurrency = std::thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1);
         Ok(Self {
             writer,
             metadata_builder,
+            encoding_concurrency,
         })
     }
 
+    /// Set the maximum number of batches that [`write_stream`][Self::write_stream] encodes at
+    /// the same time.
+    ///
+    /// This defaults to the available parallelism. Each batch being encoded is held in memory with
+    /// its encoded geometry columns.
+    pub fn with_encoding_concurrency(mut self, encoding_concurrency: usize) -> Self {
+        self.encoding_concurrency = encoding_concurrency.max(1);
+        self
+    }
+
     /// Write a batch to an output file
     pub async fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         let encoded_batch = encode_record_batch(batch, &mut self.metadata_builder)?;
@@ -54,6 +73,53 @@ impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
         Ok(())
     }
 
+    /// Write a stream of batches to an output file.
+    ///
+    /// Unlike calling [`write_batch`][Self::write_batch] for every batch, the geometry columns of
+    /// the following batches are encoded on tokio's blocking thread pool while a batch is encoded
+    /// into pages and written, so that neither the CPU nor the output is left idle for large
+    /// files. Batches are written in order.
+    ///
+    /// Outside of a tokio runtime, there is no blocking thread pool, and each batch is instead
+    /// encoded inline before it is written.
+    pub async fn write_stream<S>(&mut self, batches: S) -> Result<()>
+    where
+        S: Stream<Item = Result<RecordBatch>> + Unpin,
+    {
+        let output_schema = self.metadata_builder.output_schema.clone();
+        let column_encodings: Arc<[(usize, GeoParquetColumnEncoding)]> =
+            self.metadata_builder.column_encodings().into();
+        let orient_polygons = self.metadata_builder.orient_polygons;
+
+        let mut encoded_batches = batches
+            .map(|batch| {
+                let output_schema = output_schema.clone();
+                let column_encodings = column_encodings.clone();
+                async move {
+                    let batch = batch?;
+                    let encode = move || {
+                        encode_batch(&batch, &output_schema, &column_encodings, orient_polygons)
+                    };
+                    match tokio::runtime::Handle::try_current() {
+                        Ok(handle) => handle.spawn_blocking(encode).await.map_err(|err| {
+                            GeoArrowError::General(format!(
+                                "GeoParquet encoding task failed: {err}"
+                            ))
+                        })?,
+                        Err(_) => encode(),
+                    }
+                }
+            })
+            .buffered(self.encoding_concurrency);
+
+        while let Some(encoded) = encoded_batches.next().await {
+            let encoded = encoded?;
+            self.metadata_builder.update(&encoded);
+            self.writer.write(&encoded.batch).await?;
+        }
+        Ok(())
+    }
+
     /// Access the underlying writer.
     pub fn writer(&self) -> &AsyncArrowWriter<W> {
         &self.writer
@@ -74,3 +140,73 @@ impl<W: AsyncWrite + Unpin + Send> GeoParquetWriterAsync<W> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::io::parquet::{write_geoparquet, GeoParquetRecordBatchReaderBuilder};
+    use crate::table::Table;
+    use crate::test::point;
+
+    fn table() -> Table {
+        let batches = (0..4)
+            .map(|_| point::table().batches()[0].clone())
+            .collect();
+        Table::try_new(batches, point::table().schema().clone()).unwrap()
+    }
+
+    async fn write_stream(table: &Table) -> Vec<u8> {
+        let mut buf = vec![];
+        let mut writer =
+            GeoParquetWriterAsync::try_new(&mut buf, table.schema(), &Default::default())
+                .unwrap()
+                .with_encoding_concurrency(2);
+        writer
+            .write_stream(futures::stream::iter(
+                table.batches().iter().cloned().map(Ok),
+            ))
+            .await
+            .unwrap();
+        writer.finish().await.unwrap();
+        buf
+    }
+
+    fn assert_matches_sync(table: Table, async_buf: Vec<u8>) {
+        let mut sync_buf = vec![];
+        write_geoparquet(
+            table.into_record_batch_reader(),
+            &mut sync_buf,
+            &Default::default(),
+        )
+        .unwrap();
+
+        let read = |buf: Vec<u8>| {
+            GeoParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf))
+                .unwrap()
+                .build()
+                .unwrap()
+                .read_table()
+                .unwrap()
+        };
+        let (sync_table, async_table) = (read(sync_buf), read(async_buf));
+        assert_eq!(async_table.len(), 4 * point::table().len());
+        assert_eq!(sync_table.schema(), async_table.schema());
+        assert_eq!(sync_table.batches(), async_table.batches());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn concurrent_encoding_matches_sync() {
+        let table = table();
+        let async_buf = write_stream(&table).await;
+        assert_matches_sync(table, async_buf);
+    }
+
+    #[test]
+    fn encoding_outside_tokio() {
+        let table = table();
+        let async_buf = futures::executor::block_on(write_stream(&table));
+        assert_matches_sync(table, async_buf);
+    }
+}
@@ -1,12 +1,14 @@
+use std::collections::HashSet;
+
 use arrow_array::{Array, ArrayRef, RecordBatch};
-use arrow_schema::Field;
+use arrow_schema::{Field, SchemaRef};
 
 use crate::algorithm::native::bounding_rect::BoundingRect;
-use crate::algorithm::native::TotalBounds;
+use crate::algorithm::native::{FixRings, FixRingsOptions, RingOrientation, TotalBounds};
 use crate::array::{CoordType, NativeArrayDyn};
 use crate::error::Result;
-use crate::io::parquet::metadata::GeoParquetColumnEncoding;
-use crate::io::parquet::writer::metadata::{ColumnInfo, GeoParquetMetadataBuilder};
+use crate::io::parquet::metadata::{GeoParquetColumnEncoding, GeoParquetGeometryType};
+use crate::io::parquet::writer::metadata::{array_geometry_types, GeoParquetMetadataBuilder};
 use crate::io::wkb::ToWKB;
 use crate::{ArrayBase, NativeArray};
 
@@ -14,32 +16,73 @@ pub(super) fn encode_record_batch(
     batch: &RecordBatch,
     metadata_builder: &mut GeoParquetMetadataBuilder,
 ) -> Result<RecordBatch> {
+    let encoded = encode_batch(
+        batch,
+        &metadata_builder.output_schema,
+        &metadata_builder.column_encodings(),
+        metadata_builder.orient_polygons,
+    )?;
+    metadata_builder.update(&encoded);
+    Ok(encoded.batch)
+}
+
+/// A record batch with its geometry columns encoded for GeoParquet, and what they add to the
+/// GeoParquet metadata.
+pub(super) struct EncodedBatch {
+    /// The encoded batch, with the output schema of the writer.
+    pub batch: RecordBatch,
+
+    /// The bounds and geometry types of each geometry column, by column index.
+    pub columns: Vec<(usize, BoundingRect, HashSet<GeoParquetGeometryType>)>,
+}
+
+/// Encode the geometry columns of a batch.
+///
+/// Unlike [`encode_record_batch`], this doesn't touch the metadata builder, so that batches can be
+/// encoded in parallel and the metadata updated in order afterwards.
+///
+/// If `orient_polygons`, polygons are made counterclockwise before encoding.
+pub(super) fn encode_batch(
+    batch: &RecordBatch,
+    output_schema: &SchemaRef,
+    column_encodings: &[(usize, GeoParquetColumnEncoding)],
+    orient_polygons: bool,
+) -> Result<EncodedBatch> {
     let mut new_columns = batch.columns().to_vec();
-    for (column_idx, column_info) in metadata_builder.columns.iter_mut() {
+    let mut columns = Vec::with_capacity(column_encodings.len());
+    for (column_idx, encoding) in column_encodings {
         let array = batch.column(*column_idx);
         let field = batch.schema_ref().field(*column_idx);
-        column_info.update_geometry_types(array, field)?;
+        let geometry_types = array_geometry_types(array, field)?;
 
-        let (encoded_column, array_bounds) = encode_column(array, field, column_info)?;
+        let (encoded_column, array_bounds) =
+            encode_column(array, field, *encoding, orient_polygons)?;
         new_columns[*column_idx] = encoded_column;
-
-        column_info.update_bbox(&array_bounds);
+        columns.push((*column_idx, array_bounds, geometry_types));
     }
 
-    Ok(RecordBatch::try_new(
-        metadata_builder.output_schema.clone(),
-        new_columns,
-    )?)
+    Ok(EncodedBatch {
+        batch: RecordBatch::try_new(output_schema.clone(), new_columns)?,
+        columns,
+    })
 }
 
 fn encode_column(
     array: &dyn Array,
     field: &Field,
-    column_info: &mut ColumnInfo,
+    encoding: GeoParquetColumnEncoding,
+    orient_polygons: bool,
 ) -> Result<(ArrayRef, BoundingRect)> {
-    let geo_arr = NativeArrayDyn::from_arrow_array(array, field)?.into_inner();
+    let mut geo_arr = NativeArrayDyn::from_arrow_array(array, field)?.into_inner();
+    if orient_polygons {
+        let options = FixRingsOptions {
+            orientation: Some(RingOrientation::CounterClockwise),
+            ..Default::default()
+        };
+        geo_arr = geo_arr.as_ref().fix_rings(options).0;
+    }
     let array_bounds = geo_arr.as_ref().total_bounds();
-    let encoded_array = match column_info.encoding {
+    let encoded_array = match encoding {
         GeoParquetColumnEncoding::WKB => encode_wkb_column(geo_arr.as_ref())?,
         _ => encode_native_column(geo_arr.as_ref())?,
     };
@@ -7,14 +7,16 @@ use serde_json::Value;
 
 use crate::algorithm::native::bounding_rect::BoundingRect;
 use crate::array::metadata::{ArrayMetadata, Edges};
-use crate::array::{AsNativeArray, CoordType, NativeArrayDyn};
+use crate::array::{CoordType, NativeArrayDyn};
 use crate::datatypes::{Dimension, NativeType, SerializedType};
 use crate::error::Result;
 use crate::io::crs::{CRSTransform, DefaultCRSTransform};
 use crate::io::parquet::metadata::{
     GeoParquetColumnEncoding, GeoParquetColumnMetadata, GeoParquetGeometryType, GeoParquetMetadata,
 };
+use crate::io::parquet::writer::encode::EncodedBatch;
 use crate::io::parquet::writer::options::{GeoParquetWriterEncoding, GeoParquetWriterOptions};
+use crate::NativeArray;
 
 /// Information for one geometry column being written to Parquet
 pub struct ColumnInfo {
@@ -36,6 +38,12 @@ pub struct ColumnInfo {
     /// If present, instructs consumers that edges follow a spherical path rather than a planar
     /// one. If this value is omitted, edges will be interpreted as planar.
     pub edges: Option<Edges>,
+
+    /// The winding order of polygons, which is only declared when they're reoriented on write.
+    pub orientation: Option<String>,
+
+    /// The coordinate epoch of a dynamic CRS, as a decimal year.
+    pub epoch: Option<f64>,
 }
 
 impl ColumnInfo {
@@ -46,6 +54,7 @@ impl ColumnInfo {
         data_type: &NativeType,
         array_meta: ArrayMetadata,
         crs_transform: Option<&Box<dyn CRSTransform>>,
+        orient_polygons: bool,
     ) -> Result<Self> {
         let encoding = GeoParquetColumnEncoding::try_new(writer_encoding, data_type)?;
         let geometry_types = get_geometry_types(data_type);
@@ -55,7 +64,9 @@ impl ColumnInfo {
         } else {
             DefaultCRSTransform::default().extract_projjson(&array_meta)?
         };
+        let epoch = array_meta.epoch();
         let edges = array_meta.edges;
+        let orientation = orient_polygons.then(|| "counterclockwise".to_string());
 
         Ok(Self {
             name,
@@ -64,6 +75,8 @@ impl ColumnInfo {
             bbox: None,
             crs,
             edges,
+            orientation,
+            epoch,
         })
     }
 
@@ -75,50 +88,6 @@ impl ColumnInfo {
         }
     }
 
-    /// Update the geometry types in the encoder for mixed arrays
-    // TODO: for multi columns, should we do a check to see if there are non-multi geometries in
-    // the file? E.g. check if the diff in geom_offsets is 1 for any row, in which case we should
-    // write, e.g. Polygon in addition to MultiPolygon
-    //
-    // Note: for these multi columns, we should first check the geometry_types HashSet, because we
-    // shouldn't compute that for every array if we see in the first that the data is both multi
-    // and single polygons.
-    pub fn update_geometry_types(&mut self, array: &ArrayRef, field: &Field) -> Result<()> {
-        let array = NativeArrayDyn::from_arrow_array(array, field)?.into_inner();
-        let array_ref = array.as_ref();
-
-        // We only have to do this for geometry arrays because other arrays are statically known
-        if let NativeType::Geometry(_) = array_ref.data_type() {
-            let arr = array_ref.as_geometry();
-            if arr.has_points(Dimension::XY) || arr.has_points(Dimension::XYZ) {
-                self.geometry_types.insert(GeoParquetGeometryType::Point);
-            }
-            if arr.has_line_strings(Dimension::XY) || arr.has_line_strings(Dimension::XYZ) {
-                self.geometry_types
-                    .insert(GeoParquetGeometryType::LineString);
-            }
-            if arr.has_polygons(Dimension::XY) || arr.has_polygons(Dimension::XYZ) {
-                self.geometry_types.insert(GeoParquetGeometryType::Polygon);
-            }
-            if arr.has_multi_points(Dimension::XY) || arr.has_multi_points(Dimension::XYZ) {
-                self.geometry_types
-                    .insert(GeoParquetGeometryType::MultiPoint);
-            }
-            if arr.has_multi_line_strings(Dimension::XY)
-                || arr.has_multi_line_strings(Dimension::XYZ)
-            {
-                self.geometry_types
-                    .insert(GeoParquetGeometryType::MultiLineString);
-            }
-            if arr.has_multi_polygons(Dimension::XY) || arr.has_multi_polygons(Dimension::XYZ) {
-                self.geometry_types
-                    .insert(GeoParquetGeometryType::MultiPolygon);
-            }
-        }
-
-        Ok(())
-    }
-
     /// Returns (column_name, column_metadata)
     pub fn finish(self) -> (String, GeoParquetColumnMetadata) {
         let edges = self.edges.map(|edges| match edges {
@@ -146,8 +115,8 @@ impl ColumnInfo {
             crs: self.crs,
             bbox,
             edges,
-            orientation: None,
-            epoch: None,
+            orientation: self.orientation,
+            epoch: self.epoch,
             covering: None,
         };
         (self.name, column_meta)
@@ -158,6 +127,7 @@ pub struct GeoParquetMetadataBuilder {
     pub output_schema: SchemaRef,
     pub primary_column: Option<String>,
     pub columns: HashMap<usize, ColumnInfo>,
+    pub orient_polygons: bool,
 }
 
 impl GeoParquetMetadataBuilder {
@@ -170,6 +140,12 @@ impl GeoParquetMetadataBuilder {
                     continue;
                 }
 
+                // Box columns are not GeoParquet geometry columns. Their storage is the same
+                // struct as a GeoParquet bounding box covering column, so they're written as-is.
+                if ext_name == "geoarrow.box" {
+                    continue;
+                }
+
                 let column_name = schema.field(col_idx).name().clone();
 
                 let array_meta =
@@ -187,6 +163,7 @@ impl GeoParquetMetadataBuilder {
                     &geo_data_type,
                     array_meta,
                     options.crs_transform.as_ref(),
+                    options.orient_polygons,
                 )?;
 
                 columns.insert(col_idx, column_info);
@@ -198,16 +175,26 @@ impl GeoParquetMetadataBuilder {
             primary_column: None,
             columns,
             output_schema,
+            orient_polygons: options.orient_polygons,
         })
     }
 
-    #[allow(dead_code)]
-    fn update_bounds(&mut self, bounds: &HashMap<usize, BoundingRect>) {
-        for (column_idx, column_bounds) in bounds.iter() {
-            self.columns
-                .get_mut(column_idx)
-                .unwrap()
-                .update_bbox(column_bounds);
+    /// The index and encoding of every geometry column.
+    pub(super) fn column_encodings(&self) -> Vec<(usize, GeoParquetColumnEncoding)> {
+        self.columns
+            .iter()
+            .map(|(column_idx, column_info)| (*column_idx, column_info.encoding))
+            .collect()
+    }
+
+    /// Add the bounds and geometry types of an encoded batch.
+    pub(super) fn update(&mut self, encoded: &EncodedBatch) {
+        for (column_idx, bounds, geometry_types) in &encoded.columns {
+            let column_info = self.columns.get_mut(column_idx).unwrap();
+            column_info.update_bbox(bounds);
+            column_info
+                .geometry_types
+                .extend(geometry_types.iter().copied());
         }
     }
 
@@ -232,54 +219,85 @@ impl GeoParquetMetadataBuilder {
     }
 }
 
+/// The geometry types present in a geometry array.
+///
+/// This is only computed for geometry arrays, as the geometry types of other arrays are known
+/// from their data type.
+///
+/// 3D children are named with a `" Z"` suffix, as in `"Point Z"`, and geometry collections are
+/// included, as required by the GeoParquet spec.
+// TODO: for multi columns, should we do a check to see if there are non-multi geometries in
+// the file? E.g. check if the diff in geom_offsets is 1 for any row, in which case we should
+// write, e.g. Polygon in addition to MultiPolygon
+//
+// Note: for these multi columns, we should first check the geometry_types HashSet, because we
+// shouldn't compute that for every array if we see in the first that the data is both multi
+// and single polygons.
+pub fn array_geometry_types(
+    array: &ArrayRef,
+    field: &Field,
+) -> Result<HashSet<GeoParquetGeometryType>> {
+    let array = NativeArrayDyn::from_arrow_array(array, field)?.into_inner();
+
+    // We only have to do this for geometry arrays because other arrays are statically known
+    if let NativeType::Geometry(_) = array.data_type() {
+        Ok(GeoParquetGeometryType::from_array(array.as_ref()))
+    } else {
+        Ok(HashSet::new())
+    }
+}
+
 pub fn get_geometry_types(data_type: &NativeType) -> HashSet<GeoParquetGeometryType> {
     use GeoParquetGeometryType::*;
     let mut geometry_types = HashSet::new();
 
+    // GeoParquet has no M types, so measured geometries are typed by their other dimensions
     match data_type {
-        NativeType::Point(_, Dimension::XY) => {
+        NativeType::Point(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(Point);
         }
-        NativeType::Point(_, Dimension::XYZ) => {
+        NativeType::Point(_, Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(PointZ);
         }
-        NativeType::LineString(_, Dimension::XY) => {
+        NativeType::LineString(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(LineString);
         }
-        NativeType::LineString(_, Dimension::XYZ) => {
+        NativeType::LineString(_, Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(LineStringZ);
         }
-        NativeType::Polygon(_, Dimension::XY) | NativeType::Rect(Dimension::XY) => {
+        NativeType::Polygon(_, Dimension::XY | Dimension::XYM)
+        | NativeType::Rect(Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(Polygon);
         }
-        NativeType::Polygon(_, Dimension::XYZ) | NativeType::Rect(Dimension::XYZ) => {
+        NativeType::Polygon(_, Dimension::XYZ | Dimension::XYZM)
+        | NativeType::Rect(Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(PolygonZ);
         }
-        NativeType::MultiPoint(_, Dimension::XY) => {
+        NativeType::MultiPoint(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(MultiPoint);
         }
-        NativeType::MultiPoint(_, Dimension::XYZ) => {
+        NativeType::MultiPoint(_, Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(MultiPointZ);
         }
-        NativeType::MultiLineString(_, Dimension::XY) => {
+        NativeType::MultiLineString(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(MultiLineString);
         }
-        NativeType::MultiLineString(_, Dimension::XYZ) => {
+        NativeType::MultiLineString(_, Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(MultiLineStringZ);
         }
-        NativeType::MultiPolygon(_, Dimension::XY) => {
+        NativeType::MultiPolygon(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(MultiPolygon);
         }
-        NativeType::MultiPolygon(_, Dimension::XYZ) => {
+        NativeType::MultiPolygon(_, Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(MultiPolygonZ);
         }
         NativeType::Geometry(_) => {
             // We don't have access to the actual data here, so we can't inspect better than this.
         }
-        NativeType::GeometryCollection(_, Dimension::XY) => {
+        NativeType::GeometryCollection(_, Dimension::XY | Dimension::XYM) => {
             geometry_types.insert(GeometryCollection);
         }
-        NativeType::GeometryCollection(_, Dimension::XYZ) => {
+        NativeType::GeometryCollection(_, Dimension::XYZ | Dimension::XYZM) => {
             geometry_types.insert(GeometryCollectionZ);
         }
     };
@@ -28,4 +28,10 @@ pub struct GeoParquetWriterOptions {
 
     /// A transformer for converting CRS from the GeoArrow representation to PROJJSON.
     pub crs_transform: Option<Box<dyn CRSTransform>>,
+
+    /// Reorient polygons so that exterior rings are counterclockwise and interior rings are
+    /// clockwise, and declare this `"orientation"` in the metadata of every geometry column.
+    ///
+    /// Without it, no orientation is declared, as the input may have any winding order.
+    pub orient_polygons: bool,
 }
@@ -0,0 +1,46 @@
+/// How to handle values that can't be parsed when reading an encoded geometry array, such as with
+/// [`from_wkb_with_policy`][crate::io::wkb::from_wkb_with_policy] or
+/// [`read_wkt_with_policy`][crate::io::wkt::read_wkt_with_policy].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseErrorPolicy {
+    /// Fail on the first value that can't be parsed.
+    #[default]
+    Strict,
+
+    /// Replace values that can't be parsed with nulls.
+    NullOnError,
+
+    /// Replace values that can't be parsed with nulls, and report the indices of their rows.
+    Collect,
+}
+
+/// Options for parsing an encoded geometry array, such as with
+/// [`from_wkb_with_policy`][crate::io::wkb::from_wkb_with_policy] or
+/// [`read_wkt_with_policy`][crate::io::wkt::read_wkt_with_policy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// How to handle values that can't be parsed.
+    pub policy: ParseErrorPolicy,
+
+    /// Whether large arrays are parsed in parallel with the `rayon` feature. Without it, this has
+    /// no effect. Defaults to `true`.
+    pub parallel: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            policy: Default::default(),
+            parallel: true,
+        }
+    }
+}
+
+impl ParseErrorPolicy {
+    /// Record that `row` couldn't be parsed, if the policy collects failing rows.
+    pub(crate) fn record(self, row: usize, invalid_rows: &mut Vec<usize>) {
+        if self == Self::Collect {
+            invalid_rows.push(row);
+        }
+    }
+}
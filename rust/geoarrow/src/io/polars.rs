@@ -0,0 +1,182 @@
+//! Conversion of GeoArrow arrays to and from the nested layouts that [Polars](https://pola.rs)
+//! can hold.
+//!
+//! Polars stores nested data as large lists and structs, and drops Arrow extension metadata. The
+//! helpers in this module convert native arrays to that layout and re-attach the GeoArrow
+//! extension type on the way back, so that plugins don't need to rebuild arrays by hand.
+//!
+//! As Polars keeps column names, the extension type and metadata can also be embedded in the
+//! field name with [`embed_extension_in_name`], in the form
+//! `<name>::<extension name>::<extension metadata>`, and recovered with
+//! [`from_polars_named`]. The column name itself must not contain `::`.
+//!
+//! Union arrays, and thus [`GeometryArray`][crate::array::GeometryArray], have no Polars
+//! equivalent. Downcast them with [`Downcast`][crate::algorithm::native::Downcast] or convert them
+//! to WKB first.
+//!
+//! # Examples
+//!
+//! ```
+//! use geoarrow::array::PolygonArray;
+//! use geoarrow::datatypes::Dimension;
+//! use geoarrow::io::polars::{from_polars_named, to_polars_named};
+//! use geoarrow::NativeArray;
+//!
+//! let polygon = geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+//! let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+//!
+//! let (name, storage) = to_polars_named("geometry", &array).unwrap();
+//! let (name, round_trip) = from_polars_named(&name, &storage).unwrap();
+//! assert_eq!(name, "geometry");
+//! assert_eq!(round_trip.dimension(), Dimension::XY);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{DataType, Field};
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, NativeArrayDyn};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+
+/// The separator between the parts of a field name with an embedded extension type.
+pub const FIELD_NAME_SEPARATOR: &str = "::";
+
+/// Replace every list in a data type with a large list, which is how Polars stores lists.
+fn to_large_lists(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::List(field) | DataType::LargeList(field) => DataType::LargeList(Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(to_large_lists(field.data_type())),
+        )),
+        data_type => data_type.clone(),
+    }
+}
+
+/// Convert a native array to a plain Arrow array that Polars can hold without copying.
+///
+/// Coordinates are converted to the separated layout and lists to large lists. The extension
+/// metadata is not part of the output; keep [`NativeArray::data_type`] and
+/// [`ArrayBase::metadata`][crate::ArrayBase::metadata] to convert back with [`from_polars`], or
+/// use [`to_polars_named`].
+pub fn to_polars(array: &dyn NativeArray) -> Result<ArrayRef> {
+    if let NativeType::Geometry(_) = array.data_type() {
+        return Err(GeoArrowError::IncorrectType(
+            "Polars does not support union arrays, downcast or convert to WKB first".into(),
+        ));
+    }
+
+    let storage = array.to_coord_type(CoordType::Separated).to_array_ref();
+    let large_type = to_large_lists(storage.data_type());
+    Ok(arrow_cast::cast(&storage, &large_type)?)
+}
+
+/// Convert an array held by Polars back to a native array, re-attaching the extension type.
+///
+/// `extension_name` is the GeoArrow extension name, such as `geoarrow.polygon`. The coordinate
+/// type and dimension are inferred from the layout of the array.
+pub fn from_polars(
+    array: &dyn Array,
+    extension_name: &str,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<Arc<dyn NativeArray>> {
+    let mut field_metadata = HashMap::with_capacity(2);
+    field_metadata.insert(
+        "ARROW:extension:name".to_string(),
+        extension_name.to_string(),
+    );
+    if metadata.should_serialize() {
+        field_metadata.insert(
+            "ARROW:extension:metadata".to_string(),
+            serde_json::to_string(metadata.as_ref())?,
+        );
+    }
+    let field = Field::new("", array.data_type().clone(), true).with_metadata(field_metadata);
+    Ok(NativeArrayDyn::from_arrow_array(array, &field)?.into_inner())
+}
+
+/// Embed the extension name and metadata of an array in a field name.
+pub fn embed_extension_in_name(name: &str, array: &dyn NativeArray) -> Result<String> {
+    if name.contains(FIELD_NAME_SEPARATOR) {
+        return Err(GeoArrowError::General(format!(
+            "Field name '{name}' must not contain '{FIELD_NAME_SEPARATOR}'"
+        )));
+    }
+
+    let mut embedded = format!(
+        "{name}{FIELD_NAME_SEPARATOR}{}",
+        array.data_type().extension_name()
+    );
+    let metadata = array.metadata();
+    if metadata.should_serialize() {
+        embedded.push_str(FIELD_NAME_SEPARATOR);
+        embedded.push_str(&serde_json::to_string(metadata.as_ref())?);
+    }
+    Ok(embedded)
+}
+
+/// Convert a native array with [`to_polars`], and embed its extension type in the field name
+/// with [`embed_extension_in_name`].
+pub fn to_polars_named(name: &str, array: &dyn NativeArray) -> Result<(String, ArrayRef)> {
+    Ok((embed_extension_in_name(name, array)?, to_polars(array)?))
+}
+
+/// Convert an array held by Polars back to a native array, using the extension type embedded in
+/// its field name by [`embed_extension_in_name`].
+///
+/// Returns the original field name and the native array.
+pub fn from_polars_named(name: &str, array: &dyn Array) -> Result<(String, Arc<dyn NativeArray>)> {
+    let mut parts = name.splitn(3, FIELD_NAME_SEPARATOR);
+    let field_name = parts.next().unwrap_or_default();
+    let extension_name = parts.next().ok_or_else(|| {
+        GeoArrowError::General(format!(
+            "Field name '{name}' does not contain a GeoArrow extension name"
+        ))
+    })?;
+    let metadata = match parts.next() {
+        Some(metadata) => serde_json::from_str(metadata)?,
+        None => ArrayMetadata::default(),
+    };
+
+    let array = from_polars(array, extension_name, Arc::new(metadata))?;
+    Ok((field_name.to_string(), array))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsNativeArray;
+    use crate::test::multipolygon;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    #[test]
+    fn round_trip_with_crs() {
+        let metadata = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let array = multipolygon::mp_array().with_metadata(Arc::new(metadata.clone()));
+
+        let (name, storage) = to_polars_named("geom", array.as_ref()).unwrap();
+        assert!(matches!(storage.data_type(), DataType::LargeList(_)));
+
+        let (field_name, round_trip) = from_polars_named(&name, &storage).unwrap();
+        assert_eq!(field_name, "geom");
+        assert_eq!(round_trip.metadata().as_ref(), &metadata);
+        assert_eq!(
+            round_trip.as_ref().as_multi_polygon().value_as_geo(0),
+            multipolygon::mp_array().value_as_geo(0)
+        );
+    }
+
+    #[test]
+    fn missing_extension_name() {
+        let array = multipolygon::mp_array();
+        let storage = to_polars(&array).unwrap();
+        assert!(from_polars_named("geom", &storage).is_err());
+    }
+}
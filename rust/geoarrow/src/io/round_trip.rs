@@ -0,0 +1,479 @@
+//! A harness for testing that tables survive a write and read through a file format.
+//!
+//! Each [`RoundTripFormat`] writes a [`Table`] and reads it back. [`check_round_trip`] then
+//! asserts that the geometries, the non-geometry column names and, where the format supports it,
+//! the CRS of the geometry column are unchanged. Geometries are compared independently of their
+//! storage type, so a format that reads a `Point` back as a single-part `MultiPoint` still passes.
+//!
+//! Downstream format crates can implement [`RoundTripFormat`] and run their format against the
+//! same [`fixture_tables`] as the built-in formats:
+//!
+//! ```ignore
+//! use geoarrow::io::round_trip::{assert_round_trips, RoundTripFormat};
+//!
+//! struct MyFormat;
+//!
+//! impl RoundTripFormat for MyFormat {
+//!     fn name(&self) -> &str {
+//!         "my-format"
+//!     }
+//!
+//!     fn round_trip(&self, table: &Table) -> Result<Table> {
+//!         let mut buf = vec![];
+//!         write_my_format(table, &mut buf)?;
+//!         read_my_format(buf.as_slice())
+//!     }
+//! }
+//!
+//! assert_round_trips(&[&MyFormat]);
+//! ```
+
+use std::fmt;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, RecordBatchReader};
+use arrow_schema::{DataType, Field, Schema};
+use serde_json::json;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{
+    CoordType, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
+    PointArray, PolygonArray,
+};
+use crate::chunked_array::{ChunkedNativeArray, ChunkedNativeArrayDyn};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geojson::{read_geojson, write_geojson};
+use crate::io::wkb::{from_wkb, ToWKB};
+use crate::io::wkt::{read_wkt, ToWKT};
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
+use crate::trait_::{ArrayAccessor, NativeScalar};
+use crate::NativeArray;
+
+/// A file format or encoding that a [`Table`] can be written to and read back from.
+pub trait RoundTripFormat {
+    /// A short name for this format, used in failure messages.
+    fn name(&self) -> &str;
+
+    /// Write `table` and read the result back into a new [`Table`].
+    fn round_trip(&self, table: &Table) -> Result<Table>;
+
+    /// Whether this format keeps the CRS of the geometry column.
+    fn preserves_crs(&self) -> bool {
+        true
+    }
+}
+
+/// A round trip of one fixture through one format that didn't preserve the table.
+#[derive(Debug, Clone)]
+pub struct RoundTripFailure {
+    /// The [name][RoundTripFormat::name] of the format.
+    pub format: String,
+
+    /// The name of the fixture table.
+    pub fixture: String,
+
+    /// A description of what differed.
+    pub message: String,
+}
+
+impl fmt::Display for RoundTripFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} / {}: {}", self.format, self.fixture, self.message)
+    }
+}
+
+fn fixture_crs() -> ArrayMetadata {
+    ArrayMetadata::from_projjson(json!({
+        "type": "GeographicCRS",
+        "name": "WGS 84",
+        "id": {"authority": "EPSG", "code": 4326}
+    }))
+}
+
+fn fixture_table(geometry: &dyn NativeArray) -> Result<Table> {
+    let geometry = geometry.with_metadata(Arc::new(fixture_crs()));
+    let ids = Int64Array::from_iter_values(0..geometry.len() as i64);
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(ids)])?;
+    let geometry = ChunkedNativeArrayDyn::from_geoarrow_chunks(&[geometry.as_ref()])?.into_inner();
+    Table::from_arrow_and_geometry(vec![batch], schema, geometry)
+}
+
+/// The tables that [`run_round_trips`] passes through each format.
+///
+/// There is one table per geometry type, each with an `id` column and a geometry column with a
+/// PROJJSON CRS.
+pub fn fixture_tables() -> Result<Vec<(&'static str, Table)>> {
+    use Dimension::XY;
+
+    let points: PointArray = (
+        vec![geo::point!(x: 0., y: 1.), geo::point!(x: -105.5, y: 40.25)].as_slice(),
+        XY,
+    )
+        .into();
+    let line_strings: LineStringArray = (
+        vec![
+            geo::line_string![(x: 0., y: 1.), (x: 1., y: 2.)],
+            geo::line_string![(x: 3., y: 4.), (x: 5., y: 6.), (x: 7.5, y: 8.25)],
+        ]
+        .as_slice(),
+        XY,
+    )
+        .into();
+    let polygon = geo::polygon![
+        (x: -111., y: 45.),
+        (x: -111., y: 41.),
+        (x: -104., y: 41.),
+        (x: -104., y: 45.),
+    ];
+    let polygon_with_hole = geo::polygon!(
+        exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+        interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+    );
+    let polygons: PolygonArray = (
+        vec![polygon.clone(), polygon_with_hole.clone()].as_slice(),
+        XY,
+    )
+        .into();
+    let multi_points: MultiPointArray = (
+        vec![
+            geo::MultiPoint::new(vec![geo::point!(x: 0., y: 1.), geo::point!(x: 1., y: 2.)]),
+            geo::MultiPoint::new(vec![geo::point!(x: 3., y: 4.)]),
+        ]
+        .as_slice(),
+        XY,
+    )
+        .into();
+    let multi_line_strings: MultiLineStringArray = (
+        vec![
+            geo::MultiLineString::new(vec![
+                geo::line_string![(x: 0., y: 1.), (x: 1., y: 2.)],
+                geo::line_string![(x: 3., y: 4.), (x: 5., y: 6.)],
+            ]),
+            geo::MultiLineString::new(vec![geo::line_string![(x: 7., y: 8.), (x: 9., y: 10.)]]),
+        ]
+        .as_slice(),
+        XY,
+    )
+        .into();
+    let multi_polygons: MultiPolygonArray = (
+        vec![
+            geo::MultiPolygon::new(vec![polygon.clone(), polygon_with_hole]),
+            geo::MultiPolygon::new(vec![polygon]),
+        ]
+        .as_slice(),
+        XY,
+    )
+        .into();
+
+    Ok(vec![
+        ("point", fixture_table(&points)?),
+        ("linestring", fixture_table(&line_strings)?),
+        ("polygon", fixture_table(&polygons)?),
+        ("multipoint", fixture_table(&multi_points)?),
+        ("multilinestring", fixture_table(&multi_line_strings)?),
+        ("multipolygon", fixture_table(&multi_polygons)?),
+    ])
+}
+
+/// Single-part geometries compare equal to multi-part geometries with one part, because many
+/// formats don't keep the distinction.
+fn normalize(geom: geo::Geometry) -> geo::Geometry {
+    match geom {
+        geo::Geometry::Point(g) => geo::Geometry::MultiPoint(geo::MultiPoint(vec![g])),
+        geo::Geometry::LineString(g) => {
+            geo::Geometry::MultiLineString(geo::MultiLineString(vec![g]))
+        }
+        geo::Geometry::Polygon(g) => geo::Geometry::MultiPolygon(geo::MultiPolygon(vec![g])),
+        g => g,
+    }
+}
+
+fn normalized_geometries(table: &Table) -> Result<Vec<Option<geo::Geometry>>> {
+    let geometry = table.geometry_column(None)?;
+    let wkb = geometry.as_ref().to_wkb::<i32>();
+    Ok(wkb
+        .chunks()
+        .iter()
+        .flat_map(|chunk| {
+            chunk
+                .iter()
+                .map(|maybe_wkb| maybe_wkb.map(|wkb| normalize(wkb.to_geo_geometry())))
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+fn geometry_metadata(table: &Table) -> Result<ArrayMetadata> {
+    let index = table.default_geometry_column_idx()?;
+    ArrayMetadata::try_from(table.schema().field(index))
+}
+
+fn property_names(table: &Table) -> Vec<String> {
+    let geometry_columns = table.schema().as_ref().geometry_columns();
+    table
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !geometry_columns.contains(i))
+        .map(|(_, field)| field.name().clone())
+        .collect()
+}
+
+/// Pass `table` through `format` and check that the result matches the input.
+///
+/// Returns [`GeoArrowError::General`] describing the first difference found.
+pub fn check_round_trip(format: &dyn RoundTripFormat, table: &Table) -> Result<()> {
+    let output = format.round_trip(table)?;
+
+    if output.len() != table.len() {
+        return Err(GeoArrowError::General(format!(
+            "expected {} rows, got {}",
+            table.len(),
+            output.len()
+        )));
+    }
+
+    let expected = normalized_geometries(table)?;
+    let actual = normalized_geometries(&output)?;
+    if let Some(i) = (0..expected.len()).find(|&i| expected[i] != actual[i]) {
+        return Err(GeoArrowError::General(format!(
+            "geometry {i} differs: expected {:?}, got {:?}",
+            expected[i], actual[i]
+        )));
+    }
+
+    let mut expected_names = property_names(table);
+    let mut actual_names = property_names(&output);
+    expected_names.sort();
+    actual_names.sort();
+    if expected_names != actual_names {
+        return Err(GeoArrowError::General(format!(
+            "expected columns {expected_names:?}, got {actual_names:?}"
+        )));
+    }
+
+    if format.preserves_crs() {
+        let expected_crs = geometry_metadata(table)?.crs;
+        let actual_crs = geometry_metadata(&output)?.crs;
+        if expected_crs != actual_crs {
+            return Err(GeoArrowError::General(format!(
+                "expected CRS {expected_crs:?}, got {actual_crs:?}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Pass every [fixture table][fixture_tables] through every format, collecting the failures.
+pub fn run_round_trips(formats: &[&dyn RoundTripFormat]) -> Result<Vec<RoundTripFailure>> {
+    let fixtures = fixture_tables()?;
+    let mut failures = vec![];
+    for format in formats {
+        for (fixture, table) in fixtures.iter() {
+            if let Err(err) = check_round_trip(*format, table) {
+                failures.push(RoundTripFailure {
+                    format: format.name().to_string(),
+                    fixture: fixture.to_string(),
+                    message: err.to_string(),
+                });
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// Like [`run_round_trips`], but panics with a list of all failures.
+pub fn assert_round_trips(formats: &[&dyn RoundTripFormat]) {
+    let failures = run_round_trips(formats).unwrap();
+    if !failures.is_empty() {
+        let messages = failures
+            .iter()
+            .map(|failure| failure.to_string())
+            .collect::<Vec<_>>();
+        panic!("round trip failures:\n{}", messages.join("\n"));
+    }
+}
+
+fn replace_geometry_column(table: &Table, chunks: Vec<Arc<dyn NativeArray>>) -> Result<Table> {
+    let index = table.default_geometry_column_idx()?;
+    let name = table.schema().field(index).name().clone();
+    let field = chunks[0].extension_field().as_ref().clone().with_name(name);
+    let arrays = chunks
+        .iter()
+        .map(|chunk| chunk.to_array_ref())
+        .collect::<Vec<ArrayRef>>();
+
+    let mut table = table.clone();
+    table.set_column(index, Arc::new(field), arrays)?;
+    Ok(table)
+}
+
+fn read_batches(reader: impl RecordBatchReader) -> Result<Table> {
+    let schema = reader.schema();
+    let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+    Table::try_new(batches, schema)
+}
+
+/// Encode the geometry column as WKB and parse it back.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Wkb;
+
+impl RoundTripFormat for Wkb {
+    fn name(&self) -> &str {
+        "wkb"
+    }
+
+    fn round_trip(&self, table: &Table) -> Result<Table> {
+        let geometry = table.geometry_column(None)?;
+        let chunks = geometry.as_ref().to_wkb::<i32>().try_map(|chunk| {
+            from_wkb(chunk, NativeType::Geometry(CoordType::Interleaved), false)
+        })?;
+        replace_geometry_column(table, chunks)
+    }
+}
+
+/// Encode the geometry column as WKT and parse it back.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Wkt;
+
+impl RoundTripFormat for Wkt {
+    fn name(&self) -> &str {
+        "wkt"
+    }
+
+    fn round_trip(&self, table: &Table) -> Result<Table> {
+        let geometry = table.geometry_column(None)?;
+        let chunks = geometry
+            .as_ref()
+            .to_wkt::<i32>()?
+            .try_map(|chunk| read_wkt(chunk, CoordType::Interleaved, false))?;
+        replace_geometry_column(table, chunks)
+    }
+}
+
+/// Write and read a GeoJSON FeatureCollection.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeoJson;
+
+impl RoundTripFormat for GeoJson {
+    fn name(&self) -> &str {
+        "geojson"
+    }
+
+    fn round_trip(&self, table: &Table) -> Result<Table> {
+        let mut buf = vec![];
+        write_geojson(table, &mut buf)?;
+        read_geojson(Cursor::new(buf), None)
+    }
+
+    fn preserves_crs(&self) -> bool {
+        false
+    }
+}
+
+/// Write and read a FlatGeobuf file.
+#[cfg(feature = "flatgeobuf")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlatGeobuf;
+
+#[cfg(feature = "flatgeobuf")]
+impl RoundTripFormat for FlatGeobuf {
+    fn name(&self) -> &str {
+        "flatgeobuf"
+    }
+
+    fn round_trip(&self, table: &Table) -> Result<Table> {
+        use crate::io::flatgeobuf::{write_flatgeobuf, FlatGeobufReaderBuilder};
+
+        let mut buf = vec![];
+        write_flatgeobuf(table, &mut buf, "round_trip")?;
+        let reader = FlatGeobufReaderBuilder::open(Cursor::new(buf))?.read(Default::default())?;
+        read_batches(reader)
+    }
+
+    // The writer only knows how to convert a PROJJSON CRS to WKT when PROJ is available.
+    fn preserves_crs(&self) -> bool {
+        false
+    }
+}
+
+/// Write and read a GeoParquet file.
+#[cfg(feature = "parquet")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeoParquet;
+
+#[cfg(feature = "parquet")]
+impl RoundTripFormat for GeoParquet {
+    fn name(&self) -> &str {
+        "geoparquet"
+    }
+
+    fn round_trip(&self, table: &Table) -> Result<Table> {
+        use std::fs::File;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::io::parquet::{write_geoparquet, GeoParquetRecordBatchReaderBuilder};
+
+        // The Parquet reader needs a `ChunkReader`, so go through a temporary file.
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "geoarrow-round-trip-{}-{}.parquet",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        write_geoparquet(
+            table.clone().into_record_batch_reader(),
+            File::create(&path)?,
+            &Default::default(),
+        )?;
+        let result = GeoParquetRecordBatchReaderBuilder::try_new(File::open(&path)?)
+            .and_then(|builder| builder.build())
+            .and_then(|reader| reader.read_table());
+        std::fs::remove_file(&path)?;
+        result
+    }
+}
+
+/// Write and read a CSV file with a WKT geometry column.
+#[cfg(feature = "csv")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Csv;
+
+#[cfg(feature = "csv")]
+impl RoundTripFormat for Csv {
+    fn name(&self) -> &str {
+        "csv"
+    }
+
+    fn round_trip(&self, table: &Table) -> Result<Table> {
+        use crate::io::csv::{write_csv, CSVReader};
+
+        let mut buf = vec![];
+        write_csv(table, &mut buf)?;
+        read_batches(CSVReader::try_new(Cursor::new(buf), Default::default())?)
+    }
+
+    fn preserves_crs(&self) -> bool {
+        false
+    }
+}
+
+/// All formats implemented in this crate that are enabled by the current features.
+pub fn builtin_formats() -> Vec<Box<dyn RoundTripFormat>> {
+    let mut formats: Vec<Box<dyn RoundTripFormat>> =
+        vec![Box::new(Wkb), Box::new(Wkt), Box::new(GeoJson)];
+    #[cfg(feature = "flatgeobuf")]
+    formats.push(Box::new(FlatGeobuf));
+    #[cfg(feature = "parquet")]
+    formats.push(Box::new(GeoParquet));
+    #[cfg(feature = "csv")]
+    formats.push(Box::new(Csv));
+    formats
+}
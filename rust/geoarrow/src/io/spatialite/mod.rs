@@ -0,0 +1,156 @@
+//! Read and write geometries in the
+//! [SpatiaLite BLOB format](https://www.gaia-gis.it/gaia-sins/BLOB-Geometry.html), the internal
+//! geometry format of SpatiaLite databases.
+//!
+//! A SpatiaLite geometry starts with a header holding the byte order, SRID and bounding box of
+//! the geometry, followed by a WKB-like body in which the parts of multi geometries and
+//! collections start with an entity marker instead of a byte order.
+//!
+//! SpatiaLite geometries are read from and written to plain Arrow binary arrays. Compressed
+//! geometries and geometries with M values are not supported.
+
+mod reader;
+mod writer;
+
+pub use reader::{read_spatialite, spatialite_srid};
+pub use writer::write_spatialite;
+
+use crate::error::{GeoArrowError, Result};
+
+/// The first byte of a SpatiaLite geometry.
+const START: u8 = 0x00;
+
+/// The byte after the bounding box.
+const MBR_END: u8 = 0x7C;
+
+/// The byte before every part of a multi geometry or collection.
+const ENTITY: u8 = 0x69;
+
+/// The last byte of a SpatiaLite geometry.
+const END: u8 = 0xFE;
+
+/// The length of the header, up to and including the geometry class.
+const HEADER_LEN: usize = 43;
+
+/// The maximum nesting depth of multi geometries and collections, so that malicious input can't
+/// overflow the stack.
+const MAX_COLLECTION_DEPTH: usize = 32;
+
+fn read_u32(buf: &[u8], offset: usize, little_endian: bool) -> Result<u32> {
+    let bytes: [u8; 4] = buf
+        .get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| GeoArrowError::General("Unexpected end of geometry buffer".to_string()))?;
+    Ok(if little_endian {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    })
+}
+
+fn copy_bytes(buf: &[u8], offset: &mut usize, len: usize, out: &mut Vec<u8>) -> Result<()> {
+    let bytes = buf
+        .get(*offset..*offset + len)
+        .ok_or_else(|| GeoArrowError::General("Unexpected end of geometry buffer".to_string()))?;
+    out.extend_from_slice(bytes);
+    *offset += len;
+    Ok(())
+}
+
+/// Copy a geometry body, starting at `offset`, between WKB and SpatiaLite.
+///
+/// The two only differ in the first byte of the parts of multi geometries and collections: a
+/// byte order in WKB, and [`ENTITY`] in SpatiaLite. The body is copied as is otherwise, so both
+/// must have the same byte order. `depth` is the number of multi geometries and collections the
+/// geometry is nested in.
+fn copy_body(
+    buf: &[u8],
+    offset: &mut usize,
+    geometry_type: u32,
+    little_endian: bool,
+    from_spatialite: bool,
+    depth: usize,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    if geometry_type >= 1_000_000 {
+        return Err(GeoArrowError::NotYetImplemented(
+            "Compressed SpatiaLite geometries are not supported".to_string(),
+        ));
+    }
+    let has_z = match geometry_type / 1000 {
+        0 => false,
+        1 => true,
+        2 | 3 => {
+            return Err(GeoArrowError::NotYetImplemented(
+                "Geometries with M values are not supported".to_string(),
+            ))
+        }
+        _ => {
+            return Err(GeoArrowError::General(format!(
+                "Unsupported geometry type {geometry_type}"
+            )))
+        }
+    };
+
+    let coord_len = 8 * (2 + has_z as usize);
+    match geometry_type % 1000 {
+        // Point
+        1 => copy_bytes(buf, offset, coord_len, out),
+        // LineString
+        2 => {
+            let num_coords = read_u32(buf, *offset, little_endian)? as usize;
+            copy_bytes(buf, offset, 4 + num_coords * coord_len, out)
+        }
+        // Polygon
+        3 => {
+            let num_rings = read_u32(buf, *offset, little_endian)?;
+            copy_bytes(buf, offset, 4, out)?;
+            for _ in 0..num_rings {
+                let num_coords = read_u32(buf, *offset, little_endian)? as usize;
+                copy_bytes(buf, offset, 4 + num_coords * coord_len, out)?;
+            }
+            Ok(())
+        }
+        // MultiPoint, MultiLineString, MultiPolygon and GeometryCollection
+        4..=7 => {
+            if depth >= MAX_COLLECTION_DEPTH {
+                return Err(GeoArrowError::General(format!(
+                    "Geometry collections are nested more than {MAX_COLLECTION_DEPTH} levels deep"
+                )));
+            }
+            let num_parts = read_u32(buf, *offset, little_endian)?;
+            copy_bytes(buf, offset, 4, out)?;
+            let byte_order = little_endian as u8;
+            let (expected, replacement) = if from_spatialite {
+                (ENTITY, byte_order)
+            } else {
+                (byte_order, ENTITY)
+            };
+            for _ in 0..num_parts {
+                if buf.get(*offset) != Some(&expected) {
+                    return Err(GeoArrowError::General(format!(
+                        "Expected {expected:#04x} at the start of a geometry part at offset {}",
+                        *offset
+                    )));
+                }
+                out.push(replacement);
+                *offset += 1;
+                let part_type = read_u32(buf, *offset, little_endian)?;
+                copy_bytes(buf, offset, 4, out)?;
+                copy_body(
+                    buf,
+                    offset,
+                    part_type,
+                    little_endian,
+                    from_spatialite,
+                    depth + 1,
+                    out,
+                )?;
+            }
+            Ok(())
+        }
+        _ => Err(GeoArrowError::General(format!(
+            "Unsupported geometry type {geometry_type}"
+        ))),
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, GenericBinaryArray, OffsetSizeTrait};
+use arrow_buffer::OffsetBuffer;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, GeometryBuilder, WKBArray};
+use crate::error::{GeoArrowError, Result};
+use crate::io::spatialite::*;
+use crate::scalar::WKB;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Whether a SpatiaLite geometry is little endian.
+fn byte_order(buf: &[u8]) -> Result<bool> {
+    match buf {
+        [START, 0, ..] => Ok(false),
+        [START, 1, ..] => Ok(true),
+        _ => Err(GeoArrowError::General(
+            "Invalid SpatiaLite geometry header".to_string(),
+        )),
+    }
+}
+
+/// The SRID of a SpatiaLite geometry.
+pub fn spatialite_srid(buf: &[u8]) -> Result<i32> {
+    let little_endian = byte_order(buf)?;
+    Ok(read_u32(buf, 2, little_endian)? as i32)
+}
+
+/// Transcode a SpatiaLite geometry to ISO WKB with the same byte order.
+fn spatialite_to_wkb(buf: &[u8], out: &mut Vec<u8>) -> Result<()> {
+    let little_endian = byte_order(buf)?;
+    if buf.len() <= HEADER_LEN || buf[38] != MBR_END {
+        return Err(GeoArrowError::General(
+            "Invalid SpatiaLite geometry header".to_string(),
+        ));
+    }
+
+    let geometry_type = read_u32(buf, 39, little_endian)?;
+    out.push(buf[1]);
+    out.extend_from_slice(&buf[39..HEADER_LEN]);
+    let mut offset = HEADER_LEN;
+    copy_body(buf, &mut offset, geometry_type, little_endian, true, 0, out)?;
+
+    if buf.get(offset) != Some(&END) || offset + 1 != buf.len() {
+        return Err(GeoArrowError::General(
+            "Invalid end of SpatiaLite geometry".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse an array of SpatiaLite geometries into a native GeoArrow array.
+///
+/// The geometries are transcoded to WKB, and then parsed with the same two-pass WKB reader as
+/// [`FromWKB`][crate::io::wkb::FromWKB]. The SRID shared by the geometries is set as the CRS of
+/// the output, interpreted as an EPSG code. SRIDs of 0 and -1, which SpatiaLite uses for an
+/// undefined CRS, are ignored, and it is an error for geometries to have different SRIDs.
+///
+/// Currently, a [GeometryArray][crate::array::GeometryArray] is always returned.
+pub fn read_spatialite<O: OffsetSizeTrait>(
+    arr: &GenericBinaryArray<O>,
+    coord_type: CoordType,
+    prefer_multi: bool,
+) -> Result<Arc<dyn NativeArray>> {
+    let mut values = Vec::with_capacity(arr.value_data().len());
+    let mut offsets = Vec::with_capacity(arr.len() + 1);
+    offsets.push(0i64);
+    let mut srid = None;
+    for i in 0..arr.len() {
+        if arr.is_valid(i) {
+            let buf = arr.value(i);
+            match (spatialite_srid(buf)?, srid) {
+                (0 | -1, _) => {}
+                (geom_srid, None) => srid = Some(geom_srid),
+                (geom_srid, Some(srid)) if geom_srid != srid => {
+                    return Err(GeoArrowError::General(format!(
+                        "SpatiaLite geometries have different SRIDs: {srid} and {geom_srid}"
+                    )))
+                }
+                _ => {}
+            }
+            spatialite_to_wkb(buf, &mut values)?;
+        }
+        offsets.push(values.len() as i64);
+    }
+
+    let wkb_array: WKBArray<i64> = GenericBinaryArray::new(
+        OffsetBuffer::new(offsets.into()),
+        values.into(),
+        arr.nulls().cloned(),
+    )
+    .into();
+    let wkb_objects: Vec<Option<WKB<'_, i64>>> = wkb_array.iter().collect();
+    let metadata = match srid {
+        Some(srid) => ArrayMetadata::from_authority_code(format!("EPSG:{srid}")),
+        None => Default::default(),
+    };
+    let builder =
+        GeometryBuilder::from_wkb(&wkb_objects, coord_type, Arc::new(metadata), prefer_multi)?;
+    Ok(Arc::new(builder.finish()))
+}
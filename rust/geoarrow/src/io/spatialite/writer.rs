@@ -0,0 +1,191 @@
+use arrow_array::builder::GenericBinaryBuilder;
+use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
+
+use crate::algorithm::native::bounding_rect::bounding_rect_geometry;
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::error::{GeoArrowError, Result};
+use crate::io::spatialite::*;
+use crate::io::wkb::to_wkb;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Write a native GeoArrow array to an array of SpatiaLite geometries.
+///
+/// Every geometry is written with `srid`, or if `srid` is `None`, with the SRID of the CRS of the
+/// array, from [`ArrayMetadata::srid`][crate::array::metadata::ArrayMetadata::srid]. Arrays
+/// without a CRS are written with an SRID of 0, and it is an error if the array has a CRS without
+/// a known SRID.
+///
+/// Null geometries are written as nulls.
+pub fn write_spatialite<O: OffsetSizeTrait>(
+    arr: &dyn NativeArray,
+    srid: Option<i32>,
+) -> Result<GenericBinaryArray<O>> {
+    let srid = match srid {
+        Some(srid) => srid,
+        None => {
+            let metadata = arr.metadata();
+            match (&metadata.crs, metadata.srid()) {
+                (None, _) => 0,
+                (Some(_), Some(srid)) => srid,
+                (Some(_), None) => {
+                    return Err(GeoArrowError::General(
+                        "The CRS of the array has no known SRID; pass an explicit SRID".to_string(),
+                    ))
+                }
+            }
+        }
+    };
+
+    let wkb = to_wkb::<i64>(arr);
+    let mut builder =
+        GenericBinaryBuilder::<O>::with_capacity(wkb.len(), wkb.num_bytes() + wkb.len() * 40);
+    let mut buf = vec![];
+    for (geom, wkb_geom) in iter_geometries(arr).zip(wkb.iter()) {
+        match (geom, wkb_geom) {
+            (Some(geom), Some(wkb_geom)) => {
+                buf.clear();
+                let mbr = bounding_rect_geometry(&geom);
+                write_spatialite_geometry(wkb_geom.as_ref(), srid, mbr, &mut buf)?;
+                builder.append_value(&buf);
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Write a WKB geometry as a SpatiaLite geometry with the same byte order.
+fn write_spatialite_geometry(
+    wkb: &[u8],
+    srid: i32,
+    (min, max): ([f64; 2], [f64; 2]),
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    let little_endian = match wkb.first() {
+        Some(0) => false,
+        Some(1) => true,
+        _ => return Err(GeoArrowError::General("Invalid WKB byte order".to_string())),
+    };
+    let geometry_type = read_u32(wkb, 1, little_endian)?;
+
+    out.push(START);
+    out.push(wkb[0]);
+    if little_endian {
+        out.extend_from_slice(&srid.to_le_bytes());
+        for value in [min[0], min[1], max[0], max[1]] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+    } else {
+        out.extend_from_slice(&srid.to_be_bytes());
+        for value in [min[0], min[1], max[0], max[1]] {
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+    out.push(MBR_END);
+    out.extend_from_slice(&wkb[1..5]);
+
+    let mut offset = 5;
+    copy_body(
+        wkb,
+        &mut offset,
+        geometry_type,
+        little_endian,
+        false,
+        0,
+        out,
+    )?;
+    out.push(END);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::BinaryArray;
+
+    use super::*;
+    use crate::array::metadata::ArrayMetadata;
+    use crate::array::{CoordType, GeometryArray, PointArray};
+    use crate::io::geo::geometry_to_geo;
+    use crate::io::spatialite::{read_spatialite, spatialite_srid};
+    use crate::test::{multipolygon, point};
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// SRID=4326;POINT(1 2), little endian.
+    const POINT: &str = concat!(
+        "0001e6100000",
+        "000000000000f03f0000000000000040000000000000f03f0000000000000040",
+        "7c01000000",
+        "000000000000f03f0000000000000040",
+        "fe"
+    );
+
+    #[test]
+    fn point() {
+        let geoms = [geo::point!(x: 1., y: 2.)];
+        let array: PointArray = (geoms.as_slice(), crate::datatypes::Dimension::XY).into();
+        let array = array.with_metadata(Arc::new(ArrayMetadata::from_authority_code(
+            "EPSG:4326".to_string(),
+        )));
+        let blobs = write_spatialite::<i32>(array.as_ref(), None).unwrap();
+        assert_eq!(blobs.value(0), hex(POINT));
+        assert_eq!(spatialite_srid(blobs.value(0)).unwrap(), 4326);
+
+        let read = read_spatialite(&blobs, CoordType::Interleaved, false).unwrap();
+        assert_eq!(read.metadata().srid(), Some(4326));
+        let read = read.as_any().downcast_ref::<GeometryArray>().unwrap();
+        assert_eq!(read.value_as_geo(0), geo::Geometry::Point(geoms[0]));
+    }
+
+    #[test]
+    fn round_trip() {
+        let arrays: [Arc<dyn NativeArray>; 3] = [
+            Arc::new(point::point_z_array()),
+            Arc::new(multipolygon::mp_array()),
+            Arc::new(GeometryArray::try_from(multipolygon::mp_array()).unwrap()),
+        ];
+        for array in arrays {
+            let blobs = write_spatialite::<i64>(array.as_ref(), Some(3857)).unwrap();
+            let read = read_spatialite(&blobs, CoordType::Interleaved, false).unwrap();
+            assert_eq!(read.metadata().srid(), Some(3857));
+            let read = read.as_any().downcast_ref::<GeometryArray>().unwrap();
+            let expected = iter_geometries(array.as_ref())
+                .map(|geom| geom.map(|geom| geometry_to_geo(&geom)))
+                .collect::<Vec<_>>();
+            assert_eq!(read.iter_geo().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let mut truncated = hex(POINT);
+        truncated.pop();
+        let blobs = BinaryArray::from(vec![Some(hex(POINT).as_slice()), None]);
+        let read = read_spatialite(&blobs, CoordType::Interleaved, false).unwrap();
+        assert!(read.is_null(1));
+
+        let truncated = BinaryArray::from(vec![truncated.as_slice()]);
+        assert!(read_spatialite(&truncated, CoordType::Interleaved, false).is_err());
+        assert!(spatialite_srid(&[0x01]).is_err());
+    }
+
+    #[test]
+    fn deeply_nested() {
+        // Empty geometry collections, each nested in the last, with an empty bounding box.
+        let mut blob = hex(&format!("0001e6100000{}7c07000000", "00".repeat(32)));
+        for _ in 0..100 {
+            blob.extend_from_slice(&hex("010000006907000000"));
+        }
+        blob.extend_from_slice(&hex("00000000fe"));
+        let blobs = BinaryArray::from(vec![blob.as_slice()]);
+        assert!(read_spatialite(&blobs, CoordType::Interleaved, false).is_err());
+    }
+}
@@ -1,7 +1,14 @@
+use std::sync::Arc;
+
+use crate::chunked_array::{ChunkedNativeArray, ChunkedNativeArrayDyn};
+use crate::datatypes::NativeType;
 use crate::error::GeoArrowError;
 use crate::table::Table;
+use crate::NativeArray;
 use arrow_array::{RecordBatchIterator, RecordBatchReader as _RecordBatchReader};
 use arrow_schema::SchemaRef;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// A newtype wrapper around an [`arrow_array::RecordBatchReader`] so that we can implement the
 /// [`geozero::GeozeroDatasource`] trait on it.
@@ -69,3 +76,234 @@ impl From<Box<dyn _RecordBatchReader + Send>> for RecordBatchReader {
         Self(value)
     }
 }
+
+/// A stream of native GeoArrow arrays that all have the same [`NativeType`].
+///
+/// This is the geometry array equivalent of an [`arrow_array::RecordBatchReader`], and allows
+/// processing a geometry column chunk by chunk without holding all of it in memory.
+pub trait NativeArrayReader: Iterator<Item = crate::error::Result<Arc<dyn NativeArray>>> {
+    /// The type of each array yielded by this reader.
+    fn data_type(&self) -> NativeType;
+}
+
+/// A [`NativeArrayReader`] over an iterator of arrays.
+pub struct NativeArrayIterator<I>
+where
+    I: IntoIterator<Item = crate::error::Result<Arc<dyn NativeArray>>>,
+{
+    inner: I::IntoIter,
+    data_type: NativeType,
+}
+
+impl<I> NativeArrayIterator<I>
+where
+    I: IntoIterator<Item = crate::error::Result<Arc<dyn NativeArray>>>,
+{
+    /// Create a new NativeArrayIterator.
+    ///
+    /// Each array yielded by `iter` must have type `data_type`.
+    pub fn new(iter: I, data_type: NativeType) -> Self {
+        Self {
+            inner: iter.into_iter(),
+            data_type,
+        }
+    }
+}
+
+impl<I> Iterator for NativeArrayIterator<I>
+where
+    I: IntoIterator<Item = crate::error::Result<Arc<dyn NativeArray>>>,
+{
+    type Item = crate::error::Result<Arc<dyn NativeArray>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I> NativeArrayReader for NativeArrayIterator<I>
+where
+    I: IntoIterator<Item = crate::error::Result<Arc<dyn NativeArray>>>,
+{
+    fn data_type(&self) -> NativeType {
+        self.data_type
+    }
+}
+
+/// A [`NativeArrayReader`] over the chunks of an in-memory chunked array.
+///
+/// This lets a chunked array be passed to any API that consumes a stream of arrays. Because all
+/// chunks are already in memory, a kernel can also be applied to the remaining chunks at once with
+/// [`map_parallel`][Self::map_parallel], which runs in parallel when the `rayon` feature is
+/// enabled.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::PointArray;
+/// use geoarrow::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::io::{ChunkedNativeArrayReader, NativeArrayReader};
+/// use geoarrow::ArrayBase;
+///
+/// let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+/// let chunked = ChunkedGeometryArray::new(vec![array.clone(), array]);
+///
+/// let reader = ChunkedNativeArrayReader::from(chunked.as_ref());
+/// assert_eq!(reader.data_type(), chunked.data_type());
+/// let lengths = reader.map_parallel(|chunk| Ok(chunk.len())).unwrap();
+/// assert_eq!(lengths, vec![1, 1]);
+/// ```
+pub struct ChunkedNativeArrayReader {
+    chunks: std::vec::IntoIter<Arc<dyn NativeArray>>,
+    data_type: NativeType,
+}
+
+impl ChunkedNativeArrayReader {
+    /// Create a new reader over a list of arrays.
+    ///
+    /// Returns an error if there are no arrays, since the type of the stream can't be known, or if
+    /// the arrays don't all have the same [`NativeType`].
+    pub fn try_new(chunks: Vec<Arc<dyn NativeArray>>) -> crate::error::Result<Self> {
+        let data_type = chunks
+            .first()
+            .ok_or_else(|| {
+                GeoArrowError::General("Cannot create a reader from zero arrays".to_string())
+            })?
+            .data_type();
+        if let Some(chunk) = chunks.iter().find(|chunk| chunk.data_type() != data_type) {
+            return Err(GeoArrowError::General(format!(
+                "All arrays must have the same type, got {} and {}",
+                data_type,
+                chunk.data_type()
+            )));
+        }
+        Ok(Self {
+            chunks: chunks.into_iter(),
+            data_type,
+        })
+    }
+
+    /// Apply a kernel to each remaining chunk, returning the outputs in chunk order.
+    ///
+    /// If the `rayon` feature is enabled, chunks are processed in parallel. The first error
+    /// returned by the kernel is propagated.
+    pub fn map_parallel<F, R>(self, kernel: F) -> crate::error::Result<Vec<R>>
+    where
+        F: Fn(&dyn NativeArray) -> crate::error::Result<R> + Sync + Send,
+        R: Send,
+    {
+        let chunks = self.chunks.collect::<Vec<_>>();
+
+        #[cfg(feature = "rayon")]
+        let output = chunks
+            .par_iter()
+            .map(|chunk| kernel(chunk.as_ref()))
+            .collect();
+
+        #[cfg(not(feature = "rayon"))]
+        let output = chunks.iter().map(|chunk| kernel(chunk.as_ref())).collect();
+
+        output
+    }
+
+    /// Collect the remaining chunks back into a chunked array.
+    pub fn into_chunked_array(self) -> crate::error::Result<Arc<dyn ChunkedNativeArray>> {
+        let chunks = self.chunks.collect::<Vec<_>>();
+        let refs = chunks.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+        Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(refs.as_slice())?.into_inner())
+    }
+}
+
+impl From<&dyn ChunkedNativeArray> for ChunkedNativeArrayReader {
+    fn from(value: &dyn ChunkedNativeArray) -> Self {
+        Self {
+            chunks: value.geometry_chunks().into_iter(),
+            data_type: value.data_type(),
+        }
+    }
+}
+
+impl TryFrom<Vec<Arc<dyn NativeArray>>> for ChunkedNativeArrayReader {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Vec<Arc<dyn NativeArray>>) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl Iterator for ChunkedNativeArrayReader {
+    type Item = crate::error::Result<Arc<dyn NativeArray>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(Ok)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl NativeArrayReader for ChunkedNativeArrayReader {
+    fn data_type(&self) -> NativeType {
+        self.data_type
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::cast_reader;
+    use crate::array::CoordType;
+    use crate::chunked_array::{ChunkedArrayBase, ChunkedGeometryArray};
+    use crate::test::{linestring, point};
+    use crate::ArrayBase;
+
+    #[test]
+    fn chunked_reader() {
+        let array = point::point_array();
+        let chunked = ChunkedGeometryArray::new(vec![array.clone(), array.clone()]);
+
+        let mut reader = ChunkedNativeArrayReader::from(chunked.as_ref());
+        assert_eq!(reader.size_hint(), (2, Some(2)));
+        assert_eq!(reader.next().unwrap().unwrap().len(), array.len());
+
+        // Only the remaining chunk is mapped
+        let lengths = reader.map_parallel(|chunk| Ok(chunk.len())).unwrap();
+        assert_eq!(lengths, vec![array.len()]);
+
+        // Readers over chunked arrays can be passed to streaming APIs
+        let reader = ChunkedNativeArrayReader::from(chunked.as_ref());
+        let to_type = NativeType::Geometry(CoordType::Separated);
+        let cast = cast_reader(Box::new(reader), to_type)
+            .collect::<crate::error::Result<Vec<_>>>()
+            .unwrap();
+        let reader = ChunkedNativeArrayReader::try_new(cast).unwrap();
+        let output = reader.into_chunked_array().unwrap();
+        assert_eq!(output.data_type(), to_type);
+        assert_eq!(output.len(), chunked.len());
+    }
+
+    #[test]
+    fn chunked_reader_errors() {
+        assert!(ChunkedNativeArrayReader::try_new(vec![]).is_err());
+
+        let chunks: Vec<Arc<dyn NativeArray>> = vec![
+            Arc::new(point::point_array()),
+            Arc::new(linestring::ls_array()),
+        ];
+        assert!(ChunkedNativeArrayReader::try_new(chunks).is_err());
+
+        let reader = ChunkedNativeArrayReader::try_new(vec![Arc::new(point::point_array())]);
+        let output = reader
+            .unwrap()
+            .map_parallel(|_| -> crate::error::Result<()> {
+                Err(GeoArrowError::General("kernel failed".to_string()))
+            });
+        assert!(output.is_err());
+    }
+}
@@ -0,0 +1,50 @@
+//! Read and write geometries encoded as
+//! [Tiny Well-Known Binary](https://github.com/TWKB/Specification/blob/master/twkb.md) (TWKB).
+//!
+//! TWKB stores coordinates as integers, rounded to a fixed number of decimal digits and
+//! delta-encoded as variable-length integers, which makes it much smaller than WKB. It is written
+//! by PostGIS' `ST_AsTWKB` and used in tile pipelines.
+//!
+//! TWKB is not a GeoArrow encoding, so it is read from and written to plain Arrow binary arrays.
+//!
+//! ## Example
+//!
+//! ```
+//! use arrow_array::BinaryArray;
+//! use geoarrow::array::{AsNativeArray, CoordType};
+//! use geoarrow::io::twkb::{read_twkb, write_twkb, TWKBWriteOptions};
+//! use geoarrow::trait_::ArrayAccessor;
+//!
+//! // LINESTRING(1 1, 5 5), as written by `ST_AsTWKB`
+//! let twkb = BinaryArray::from_iter_values([[0x02, 0x00, 0x02, 0x02, 0x02, 0x08, 0x08]]);
+//! let array = read_twkb(&twkb, CoordType::Interleaved, false).unwrap();
+//! assert_eq!(
+//!     array.as_ref().as_geometry().value_as_geo(0),
+//!     geo::Geometry::LineString(geo::line_string![(x: 1., y: 1.), (x: 5., y: 5.)])
+//! );
+//!
+//! let again = write_twkb::<i32>(array.as_ref(), &TWKBWriteOptions::default()).unwrap();
+//! assert_eq!(again.value(0), twkb.value(0));
+//! ```
+
+mod reader;
+mod writer;
+
+pub use reader::read_twkb;
+pub use writer::{write_twkb, TWKBWriteOptions};
+
+/// TWKB geometry types, stored in the low four bits of the first byte.
+const POINT: u8 = 1;
+const LINE_STRING: u8 = 2;
+const POLYGON: u8 = 3;
+const MULTI_POINT: u8 = 4;
+const MULTI_LINE_STRING: u8 = 5;
+const MULTI_POLYGON: u8 = 6;
+const GEOMETRY_COLLECTION: u8 = 7;
+
+/// Flags of the metadata byte.
+const BBOX_FLAG: u8 = 0x01;
+const SIZE_FLAG: u8 = 0x02;
+const ID_LIST_FLAG: u8 = 0x04;
+const EXTENDED_DIMENSIONS_FLAG: u8 = 0x08;
+const EMPTY_FLAG: u8 = 0x10;
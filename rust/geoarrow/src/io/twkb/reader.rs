@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, GenericBinaryArray, OffsetSizeTrait};
+use arrow_buffer::OffsetBuffer;
+
+use crate::array::{CoordType, GeometryBuilder, WKBArray};
+use crate::error::{GeoArrowError, Result};
+use crate::io::twkb::*;
+use crate::scalar::WKB;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Parse an array of TWKB geometries into a native GeoArrow array.
+///
+/// The TWKB geometries are first transcoded to ISO WKB, and then parsed with the same two-pass
+/// WKB reader as [`FromWKB`][crate::io::wkb::FromWKB]. Bounding boxes and ID lists are skipped.
+/// Geometries with M values are not supported.
+///
+/// Currently, a [GeometryArray][crate::array::GeometryArray] is always returned.
+pub fn read_twkb<O: OffsetSizeTrait>(
+    arr: &GenericBinaryArray<O>,
+    coord_type: CoordType,
+    prefer_multi: bool,
+) -> Result<Arc<dyn NativeArray>> {
+    let wkb_array: WKBArray<i64> = twkb_to_wkb(arr)?.into();
+    let wkb_objects: Vec<Option<WKB<'_, i64>>> = wkb_array.iter().collect();
+    let builder =
+        GeometryBuilder::from_wkb(&wkb_objects, coord_type, Default::default(), prefer_multi)?;
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Transcode an array of TWKB geometries to a WKB array with the same validity.
+fn twkb_to_wkb<O: OffsetSizeTrait>(arr: &GenericBinaryArray<O>) -> Result<GenericBinaryArray<i64>> {
+    let mut values = Vec::with_capacity(arr.value_data().len() * 4);
+    let mut offsets = Vec::with_capacity(arr.len() + 1);
+    offsets.push(0i64);
+    for i in 0..arr.len() {
+        if arr.is_valid(i) {
+            Decoder::new(arr.value(i)).transcode_geometry(&mut values, 0)?;
+        }
+        offsets.push(values.len() as i64);
+    }
+    Ok(GenericBinaryArray::new(
+        OffsetBuffer::new(offsets.into()),
+        values.into(),
+        arr.nulls().cloned(),
+    ))
+}
+
+/// Scale an integer TWKB value back to a float with `precision` decimal digits.
+fn descale(value: i64, precision: i32) -> f64 {
+    if precision >= 0 {
+        value as f64 / 10f64.powi(precision)
+    } else {
+        value as f64 * 10f64.powi(-precision)
+    }
+}
+
+/// The maximum nesting depth of geometry collections, so that malicious input can't overflow the
+/// stack.
+const MAX_COLLECTION_DEPTH: usize = 32;
+
+/// A decoder of one TWKB geometry, which writes it as ISO WKB.
+struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+/// The dimensions and precisions of a TWKB geometry, and the last decoded coordinate, which
+/// coordinates are delta-encoded against.
+struct CoordState {
+    has_z: bool,
+    precision: i32,
+    z_precision: i32,
+    prev: [i64; 3],
+}
+
+impl<'a> Decoder<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        let byte = *self
+            .buf
+            .get(self.pos)
+            .ok_or_else(|| GeoArrowError::General("Unexpected end of TWKB buffer".to_string()))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_uvarint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(GeoArrowError::General(
+            "TWKB varint is too long".to_string(),
+        ))
+    }
+
+    fn read_svarint(&mut self) -> Result<i64> {
+        let value = self.read_uvarint()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn read_count(&mut self) -> Result<u32> {
+        u32::try_from(self.read_uvarint()?)
+            .map_err(|_| GeoArrowError::General("TWKB count is too large".to_string()))
+    }
+
+    /// Transcode a geometry, including its header, to WKB, where `depth` is the number of
+    /// geometry collections that contain it.
+    fn transcode_geometry(&mut self, out: &mut Vec<u8>, depth: usize) -> Result<()> {
+        let type_and_precision = self.read_u8()?;
+        let geometry_type = type_and_precision & 0x0f;
+        let zigzag_precision = type_and_precision >> 4;
+        let precision = (zigzag_precision >> 1) as i32 ^ -((zigzag_precision & 1) as i32);
+
+        let metadata = self.read_u8()?;
+        let (mut has_z, mut z_precision) = (false, 0);
+        if metadata & EXTENDED_DIMENSIONS_FLAG != 0 {
+            let extended = self.read_u8()?;
+            if extended & 0x02 != 0 {
+                return Err(GeoArrowError::NotYetImplemented(
+                    "TWKB geometries with M values are not supported".to_string(),
+                ));
+            }
+            has_z = extended & 0x01 != 0;
+            z_precision = ((extended >> 2) & 0x07) as i32;
+        }
+        if metadata & SIZE_FLAG != 0 {
+            self.read_uvarint()?;
+        }
+        if metadata & BBOX_FLAG != 0 {
+            for _ in 0..2 * (2 + has_z as usize) {
+                self.read_svarint()?;
+            }
+        }
+
+        let mut state = CoordState {
+            has_z,
+            precision,
+            z_precision,
+            prev: [0; 3],
+        };
+        write_header(out, geometry_type, has_z);
+        if metadata & EMPTY_FLAG != 0 {
+            if geometry_type == POINT {
+                let num_values = 2 + has_z as usize;
+                (0..num_values).for_each(|_| out.extend_from_slice(&f64::NAN.to_le_bytes()));
+            } else {
+                out.extend_from_slice(&0u32.to_le_bytes());
+            }
+            return Ok(());
+        }
+
+        let has_ids = metadata & ID_LIST_FLAG != 0;
+        match geometry_type {
+            POINT | LINE_STRING | POLYGON => self.transcode_body(geometry_type, &mut state, out),
+            MULTI_POINT | MULTI_LINE_STRING | MULTI_POLYGON => {
+                let num_parts = self.read_count()?;
+                self.skip_ids(has_ids, num_parts)?;
+                out.extend_from_slice(&num_parts.to_le_bytes());
+                let part_type = geometry_type - 3;
+                for _ in 0..num_parts {
+                    write_header(out, part_type, has_z);
+                    self.transcode_body(part_type, &mut state, out)?;
+                }
+                Ok(())
+            }
+            GEOMETRY_COLLECTION => {
+                if depth >= MAX_COLLECTION_DEPTH {
+                    return Err(GeoArrowError::General(format!(
+                        "TWKB geometry collections are nested more than {MAX_COLLECTION_DEPTH} levels deep"
+                    )));
+                }
+                let num_geometries = self.read_count()?;
+                self.skip_ids(has_ids, num_geometries)?;
+                out.extend_from_slice(&num_geometries.to_le_bytes());
+                for _ in 0..num_geometries {
+                    self.transcode_geometry(out, depth + 1)?;
+                }
+                Ok(())
+            }
+            _ => Err(GeoArrowError::General(format!(
+                "Invalid TWKB geometry type {geometry_type}"
+            ))),
+        }
+    }
+
+    fn skip_ids(&mut self, has_ids: bool, count: u32) -> Result<()> {
+        if has_ids {
+            for _ in 0..count {
+                self.read_svarint()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Transcode the body of a point, line string or polygon, without header.
+    fn transcode_body(
+        &mut self,
+        geometry_type: u8,
+        state: &mut CoordState,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        match geometry_type {
+            POINT => self.transcode_coords(1, state, out),
+            LINE_STRING => {
+                let num_coords = self.read_count()?;
+                out.extend_from_slice(&num_coords.to_le_bytes());
+                self.transcode_coords(num_coords, state, out)
+            }
+            _ => {
+                let num_rings = self.read_count()?;
+                out.extend_from_slice(&num_rings.to_le_bytes());
+                for _ in 0..num_rings {
+                    let num_coords = self.read_count()?;
+                    out.extend_from_slice(&num_coords.to_le_bytes());
+                    self.transcode_coords(num_coords, state, out)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn transcode_coords(
+        &mut self,
+        num_coords: u32,
+        state: &mut CoordState,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        let num_values = 2 + state.has_z as usize;
+        for _ in 0..num_coords {
+            for n in 0..num_values {
+                let delta = self.read_svarint()?;
+                state.prev[n] = state.prev[n].checked_add(delta).ok_or_else(|| {
+                    GeoArrowError::General("TWKB coordinate is out of range".to_string())
+                })?;
+                let precision = if n == 2 {
+                    state.z_precision
+                } else {
+                    state.precision
+                };
+                out.extend_from_slice(&descale(state.prev[n], precision).to_le_bytes());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write a little-endian ISO WKB header.
+fn write_header(out: &mut Vec<u8>, geometry_type: u8, has_z: bool) {
+    out.push(1);
+    let wkb_type = geometry_type as u32 + if has_z { 1000 } else { 0 };
+    out.extend_from_slice(&wkb_type.to_le_bytes());
+}
@@ -0,0 +1,462 @@
+use arrow_array::builder::GenericBinaryBuilder;
+use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+
+use crate::algorithm::native::kernel::iter_geometries;
+use crate::error::{GeoArrowError, Result};
+use crate::io::twkb::*;
+use crate::NativeArray;
+
+/// Options for writing TWKB.
+///
+/// The defaults match PostGIS' `ST_AsTWKB`: coordinates are rounded to whole units, and neither
+/// bounding boxes nor sizes are written.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TWKBWriteOptions {
+    /// The number of decimal digits kept of x and y values, between -7 and 7.
+    ///
+    /// A negative precision rounds to tens, hundreds, and so on.
+    pub precision: i8,
+
+    /// The number of decimal digits kept of z values, between 0 and 7.
+    pub z_precision: u8,
+
+    /// Whether to write the bounding box of every geometry.
+    pub include_bbox: bool,
+
+    /// Whether to write the size in bytes of every geometry, which lets readers skip it.
+    pub include_size: bool,
+}
+
+impl TWKBWriteOptions {
+    fn validate(&self) -> Result<()> {
+        if !(-7..=7).contains(&self.precision) {
+            return Err(GeoArrowError::General(format!(
+                "TWKB precision must be between -7 and 7, got {}",
+                self.precision
+            )));
+        }
+        if self.z_precision > 7 {
+            return Err(GeoArrowError::General(format!(
+                "TWKB z precision must be between 0 and 7, got {}",
+                self.z_precision
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Write a native GeoArrow array to an array of TWKB geometries.
+///
+/// Null geometries are written as nulls. M values are not written. It is an error if a
+/// coordinate, or the difference between two coordinates, doesn't fit in a 64-bit integer at the
+/// given precision.
+pub fn write_twkb<O: OffsetSizeTrait>(
+    arr: &dyn NativeArray,
+    options: &TWKBWriteOptions,
+) -> Result<GenericBinaryArray<O>> {
+    options.validate()?;
+    let mut builder = GenericBinaryBuilder::<O>::with_capacity(arr.len(), 0);
+    let mut buf = vec![];
+    for geom in iter_geometries(arr) {
+        if let Some(geom) = geom {
+            buf.clear();
+            write_twkb_geometry(&geom, options, &mut buf)?;
+            builder.append_value(&buf);
+        } else {
+            builder.append_null();
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Write a geometry, including its header, as TWKB.
+///
+/// Returns the encoder, whose integer bounds are merged into those of a parent collection.
+fn write_twkb_geometry<'a>(
+    geom: &impl GeometryTrait<T = f64>,
+    options: &'a TWKBWriteOptions,
+    out: &mut Vec<u8>,
+) -> Result<Encoder<'a>> {
+    let has_z = matches!(
+        geom.dim(),
+        Dimensions::Xyz | Dimensions::Xyzm | Dimensions::Unknown(3..)
+    );
+    let mut encoder = Encoder::new(options, has_z);
+    let geometry_type = match geom.as_type() {
+        GeometryType::Point(point) => {
+            if let Some(coord) = point.coord() {
+                encoder.coord(&coord)?;
+            }
+            POINT
+        }
+        GeometryType::LineString(line_string) => {
+            if line_string.num_coords() > 0 {
+                encoder.line_string(line_string)?;
+            }
+            LINE_STRING
+        }
+        GeometryType::Polygon(polygon) => {
+            if polygon.exterior().is_some() {
+                encoder.polygon(polygon)?;
+            }
+            POLYGON
+        }
+        GeometryType::MultiPoint(multi_point) => {
+            encoder.count(multi_point.num_points());
+            for point in multi_point.points() {
+                let coord = point.coord().ok_or_else(|| {
+                    GeoArrowError::General(
+                        "Empty points in a multi point cannot be written as TWKB".to_string(),
+                    )
+                })?;
+                encoder.coord(&coord)?;
+            }
+            MULTI_POINT
+        }
+        GeometryType::MultiLineString(multi_line_string) => {
+            encoder.count(multi_line_string.num_line_strings());
+            for line_string in multi_line_string.line_strings() {
+                encoder.line_string(&line_string)?;
+            }
+            MULTI_LINE_STRING
+        }
+        GeometryType::MultiPolygon(multi_polygon) => {
+            encoder.count(multi_polygon.num_polygons());
+            for polygon in multi_polygon.polygons() {
+                encoder.polygon(&polygon)?;
+            }
+            MULTI_POLYGON
+        }
+        GeometryType::GeometryCollection(collection) => {
+            encoder.count(collection.num_geometries());
+            for geometry in collection.geometries() {
+                let member = write_twkb_geometry(&geometry, options, &mut encoder.body)?;
+                encoder.extend_bounds(&member);
+            }
+            GEOMETRY_COLLECTION
+        }
+        GeometryType::Rect(rect) => {
+            encoder.rect(rect)?;
+            POLYGON
+        }
+        _ => {
+            return Err(GeoArrowError::NotYetImplemented(
+                "Writing triangles and lines as TWKB is not supported".to_string(),
+            ))
+        }
+    };
+    encoder.finish(geometry_type, out)?;
+    Ok(encoder)
+}
+
+/// Scale a float to an integer with `precision` decimal digits.
+///
+/// It is an error if the value is not finite or the scaled value doesn't fit in an `i64`.
+fn scale(value: f64, precision: i32) -> Result<i64> {
+    let scaled = if precision >= 0 {
+        (value * 10f64.powi(precision)).round()
+    } else {
+        (value / 10f64.powi(-precision)).round()
+    };
+    // i64::MAX as f64 rounds up to 2^63, which is out of range
+    if scaled >= i64::MIN as f64 && scaled < i64::MAX as f64 {
+        Ok(scaled as i64)
+    } else {
+        Err(GeoArrowError::General(format!(
+            "Coordinate {value} cannot be written as TWKB with precision {precision}"
+        )))
+    }
+}
+
+/// The difference of two scaled values, as written in deltas and bounding boxes.
+fn checked_delta(value: i64, prev: i64) -> Result<i64> {
+    value
+        .checked_sub(prev)
+        .ok_or_else(|| GeoArrowError::General("TWKB coordinate delta is out of range".to_string()))
+}
+
+fn write_uvarint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_svarint(out: &mut Vec<u8>, value: i64) {
+    write_uvarint(out, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+/// An encoder of one TWKB geometry.
+///
+/// The body is encoded first, so that the bounding box and size can be written before it.
+struct Encoder<'a> {
+    options: &'a TWKBWriteOptions,
+    has_z: bool,
+    body: Vec<u8>,
+    /// The last encoded coordinate, which coordinates are delta-encoded against.
+    prev: [i64; 3],
+    min: [i64; 3],
+    max: [i64; 3],
+    is_empty: bool,
+}
+
+impl<'a> Encoder<'a> {
+    fn new(options: &'a TWKBWriteOptions, has_z: bool) -> Self {
+        Self {
+            options,
+            has_z,
+            body: vec![],
+            prev: [0; 3],
+            min: [i64::MAX; 3],
+            max: [i64::MIN; 3],
+            is_empty: true,
+        }
+    }
+
+    fn count(&mut self, count: usize) {
+        self.is_empty &= count == 0;
+        write_uvarint(&mut self.body, count as u64);
+    }
+
+    fn values(&mut self, values: [f64; 3]) -> Result<()> {
+        self.is_empty = false;
+        let num_values = 2 + self.has_z as usize;
+        for (n, value) in values.into_iter().enumerate().take(num_values) {
+            let precision = if n == 2 {
+                self.options.z_precision as i32
+            } else {
+                self.options.precision as i32
+            };
+            let value = scale(value, precision)?;
+            write_svarint(&mut self.body, checked_delta(value, self.prev[n])?);
+            self.prev[n] = value;
+            self.min[n] = self.min[n].min(value);
+            self.max[n] = self.max[n].max(value);
+        }
+        Ok(())
+    }
+
+    fn extend_bounds(&mut self, other: &Self) {
+        for n in 0..3 {
+            self.min[n] = self.min[n].min(other.min[n]);
+            self.max[n] = self.max[n].max(other.max[n]);
+        }
+    }
+
+    fn coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
+        self.values([coord.x(), coord.y(), coord.nth(2).unwrap_or(0.)])
+    }
+
+    fn line_string(&mut self, line_string: &impl LineStringTrait<T = f64>) -> Result<()> {
+        write_uvarint(&mut self.body, line_string.num_coords() as u64);
+        line_string
+            .coords()
+            .try_for_each(|coord| self.coord(&coord))
+    }
+
+    fn polygon(&mut self, polygon: &impl PolygonTrait<T = f64>) -> Result<()> {
+        let Some(exterior) = polygon.exterior() else {
+            write_uvarint(&mut self.body, 0);
+            return Ok(());
+        };
+        write_uvarint(&mut self.body, 1 + polygon.num_interiors() as u64);
+        self.line_string(&exterior)?;
+        polygon
+            .interiors()
+            .try_for_each(|interior| self.line_string(&interior))
+    }
+
+    /// Encode a rect as a polygon, flat at its lower Z bound.
+    fn rect(&mut self, rect: &impl RectTrait<T = f64>) -> Result<()> {
+        let (lower, upper) = (rect.min(), rect.max());
+        let z = lower.nth(2).unwrap_or(0.);
+        write_uvarint(&mut self.body, 1);
+        write_uvarint(&mut self.body, 5);
+        for (x, y) in [
+            (lower.x(), lower.y()),
+            (lower.x(), upper.y()),
+            (upper.x(), upper.y()),
+            (upper.x(), lower.y()),
+            (lower.x(), lower.y()),
+        ] {
+            self.values([x, y, z])?;
+        }
+        Ok(())
+    }
+
+    fn finish(&self, geometry_type: u8, out: &mut Vec<u8>) -> Result<()> {
+        let precision = self.options.precision as i64;
+        let zigzag_precision = ((precision << 1) ^ (precision >> 63)) as u8;
+        out.push(geometry_type | (zigzag_precision << 4));
+
+        // A collection of empty geometries has no bounds
+        let include_bbox = self.options.include_bbox && self.min[0] <= self.max[0];
+        let mut metadata = 0;
+        if include_bbox {
+            metadata |= BBOX_FLAG;
+        }
+        if self.options.include_size {
+            metadata |= SIZE_FLAG;
+        }
+        if self.has_z {
+            metadata |= EXTENDED_DIMENSIONS_FLAG;
+        }
+        if self.is_empty {
+            metadata |= EMPTY_FLAG;
+        }
+        out.push(metadata);
+        if self.has_z {
+            out.push(0x01 | (self.options.z_precision << 2));
+        }
+
+        let mut bbox = vec![];
+        if include_bbox {
+            for n in 0..2 + self.has_z as usize {
+                write_svarint(&mut bbox, self.min[n]);
+                write_svarint(&mut bbox, checked_delta(self.max[n], self.min[n])?);
+            }
+        }
+        let body: &[u8] = if self.is_empty { &[] } else { &self.body };
+        if self.options.include_size {
+            write_uvarint(out, (bbox.len() + body.len()) as u64);
+        }
+        out.extend_from_slice(&bbox);
+        out.extend_from_slice(body);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{CoordType, GeometryArray};
+    use crate::io::geo::geometry_to_geo;
+    use crate::io::twkb::read_twkb;
+    use crate::test::{multipolygon, point};
+    use crate::trait_::ArrayAccessor;
+    use arrow_array::{Array, BinaryArray};
+    use std::sync::Arc;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn postgis_output() {
+        let geoms = vec![
+            geo::Geometry::Point(geo::point!(x: 1., y: 2.)),
+            geo::Geometry::LineString(geo::line_string![(x: 1., y: 1.), (x: 5., y: 5.)]),
+        ];
+        let array = GeometryArray::try_from(geoms.as_slice()).unwrap();
+        let twkb = write_twkb::<i32>(&array, &Default::default()).unwrap();
+        // SELECT ST_AsTWKB('POINT(1 2)'::geometry), ST_AsTWKB('LINESTRING(1 1, 5 5)'::geometry)
+        assert_eq!(hex(twkb.value(0)), "01000204");
+        assert_eq!(hex(twkb.value(1)), "02000202020808");
+    }
+
+    #[test]
+    fn round_trip() {
+        let options = TWKBWriteOptions {
+            precision: 2,
+            include_bbox: true,
+            include_size: true,
+            ..Default::default()
+        };
+        let collection = geo::Geometry::GeometryCollection(geo::GeometryCollection(vec![
+            geo::Geometry::Point(point::p0()),
+            geo::Geometry::MultiPolygon(multipolygon::mp1()),
+        ]));
+        let arrays: [Arc<dyn NativeArray>; 3] = [
+            Arc::new(point::point_array()),
+            Arc::new(multipolygon::mp_array()),
+            Arc::new(GeometryArray::try_from([collection].as_slice()).unwrap()),
+        ];
+        for array in arrays {
+            let twkb = write_twkb::<i32>(array.as_ref(), &options).unwrap();
+            let read = read_twkb(&twkb, CoordType::Interleaved, false).unwrap();
+            let read = read.as_any().downcast_ref::<GeometryArray>().unwrap();
+            let expected = iter_geometries(array.as_ref())
+                .map(|geom| geom.map(|geom| geometry_to_geo(&geom)))
+                .collect::<Vec<_>>();
+            assert_eq!(read.iter_geo().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn precision_and_nulls() {
+        let twkb = BinaryArray::from(vec![
+            // POINT(1.23 -4.56) with precision 2, and a bounding box
+            Some(
+                [
+                    0x41, 0x01, 0xf6, 0x01, 0x00, 0x8f, 0x07, 0x00, 0xf6, 0x01, 0x8f, 0x07,
+                ]
+                .as_slice(),
+            ),
+            None,
+        ]);
+        let array = read_twkb(&twkb, CoordType::Interleaved, false).unwrap();
+        let array = array.as_any().downcast_ref::<GeometryArray>().unwrap();
+        assert_eq!(
+            array.value_as_geo(0),
+            geo::Geometry::Point(geo::point!(x: 1.23, y: -4.56))
+        );
+        assert!(array.is_null(1));
+
+        let options = TWKBWriteOptions {
+            precision: 2,
+            include_bbox: true,
+            ..Default::default()
+        };
+        let again = write_twkb::<i32>(array, &options).unwrap();
+        assert_eq!(again.value(0), twkb.value(0));
+        assert!(again.is_null(1));
+    }
+
+    #[test]
+    fn invalid_precision() {
+        let options = TWKBWriteOptions {
+            precision: 8,
+            ..Default::default()
+        };
+        assert!(write_twkb::<i32>(&multipolygon::mp_array(), &options).is_err());
+    }
+
+    #[test]
+    fn coordinate_overflow() {
+        // A line string whose second x coordinate overflows an i64
+        let mut buf = vec![LINE_STRING, 0x00, 0x02];
+        (0..4).for_each(|_| write_uvarint(&mut buf, u64::MAX - 1));
+        let twkb = BinaryArray::from(vec![Some(buf.as_slice())]);
+        assert!(read_twkb(&twkb, CoordType::Interleaved, false).is_err());
+    }
+
+    #[test]
+    fn write_out_of_range() {
+        let line_string = geo::line_string![(x: 9e18, y: 0.), (x: -9e18, y: 0.)];
+        let array =
+            GeometryArray::try_from([geo::Geometry::LineString(line_string)].as_slice()).unwrap();
+        assert!(write_twkb::<i32>(&array, &Default::default()).is_err());
+
+        let point = geo::point!(x: f64::INFINITY, y: 0.);
+        let array = GeometryArray::try_from([geo::Geometry::Point(point)].as_slice()).unwrap();
+        assert!(write_twkb::<i32>(&array, &Default::default()).is_err());
+    }
+
+    #[test]
+    fn collection_depth() {
+        let nested = |depth: usize| {
+            let mut buf = [GEOMETRY_COLLECTION, 0x00, 0x01].repeat(depth);
+            buf.extend_from_slice(&[POINT, EMPTY_FLAG]);
+            buf
+        };
+        let twkb = BinaryArray::from(vec![Some(nested(2).as_slice())]);
+        assert!(read_twkb(&twkb, CoordType::Interleaved, false).is_ok());
+        let twkb = BinaryArray::from(vec![Some(nested(1000).as_slice())]);
+        assert!(read_twkb(&twkb, CoordType::Interleaved, false).is_err());
+    }
+}
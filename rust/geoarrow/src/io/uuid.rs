@@ -0,0 +1,25 @@
+//! The canonical `arrow.uuid` extension type, shared by the readers and writers of formats that
+//! store UUIDs as strings.
+
+use std::collections::HashMap;
+
+use arrow_schema::{DataType, Field};
+
+/// The extension name of UUID arrays.
+pub(crate) const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// Whether a field is an `arrow.uuid` extension field.
+pub(crate) fn is_uuid_field(field: &Field) -> bool {
+    field
+        .metadata()
+        .get("ARROW:extension:name")
+        .is_some_and(|ext_name| ext_name == UUID_EXTENSION_NAME)
+}
+
+/// An `arrow.uuid` extension field.
+pub(crate) fn uuid_field(name: &str, nullable: bool) -> Field {
+    Field::new(name, DataType::FixedSizeBinary(16), nullable).with_metadata(HashMap::from([(
+        "ARROW:extension:name".to_string(),
+        UUID_EXTENSION_NAME.to_string(),
+    )]))
+}
@@ -5,6 +5,7 @@ use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
+use crate::io::{ParseErrorPolicy, ParseOptions};
 use crate::scalar::WKB;
 use crate::trait_::ArrayAccessor;
 use crate::NativeArray;
@@ -186,45 +187,162 @@ impl FromWKB for Arc<dyn ChunkedNativeArray> {
 /// The returned array is guaranteed to have exactly the type of `target_type`.
 ///
 /// `NativeType::Rect` is currently not allowed.
+///
+/// With the `rayon` feature, large arrays are parsed in parallel, see [`from_wkb_with_policy`].
 pub fn from_wkb<O: OffsetSizeTrait>(
     arr: &WKBArray<O>,
     target_type: NativeType,
     prefer_multi: bool,
+) -> Result<Arc<dyn NativeArray>> {
+    let (array, _) = from_wkb_with_policy(arr, target_type, prefer_multi, &Default::default())?;
+    Ok(array)
+}
+
+/// Parse a [WKBArray] to a GeometryArray with GeoArrow native encoding, with a policy for values
+/// that aren't valid WKB and a choice of parallelism.
+///
+/// This is [`from_wkb`] for dirty data: with [`ParseErrorPolicy::NullOnError`] or
+/// [`ParseErrorPolicy::Collect`], values that can't be parsed are nulled out instead of failing
+/// the whole array. The indices of their rows are returned with the array when using
+/// [`ParseErrorPolicy::Collect`], and the returned list is empty otherwise.
+///
+/// Valid WKB geometries that don't fit in `target_type`, such as a line string when parsing to
+/// points, are still an error.
+///
+/// If [`ParseOptions::parallel`] is `true` and the `rayon` feature is enabled, arrays longer than
+/// [`PARALLEL_SLICE_LEN`] are split into slices of that length, which are parsed concurrently and
+/// then concatenated. Otherwise, and without the `rayon` feature, the array is parsed on the
+/// current thread.
+pub fn from_wkb_with_policy<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    target_type: NativeType,
+    prefer_multi: bool,
+    options: &ParseOptions,
+) -> Result<(Arc<dyn NativeArray>, Vec<usize>)> {
+    #[cfg(feature = "rayon")]
+    if options.parallel && arr.len() > PARALLEL_SLICE_LEN {
+        return from_wkb_parallel(arr, target_type, prefer_multi, options.policy);
+    }
+
+    parse_wkb(arr, target_type, prefer_multi, options.policy)
+}
+
+/// The number of rows parsed by each task when parsing WKB in parallel.
+pub const PARALLEL_SLICE_LEN: usize = 65_536;
+
+fn parse_wkb<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    target_type: NativeType,
+    prefer_multi: bool,
+    policy: ParseErrorPolicy,
+) -> Result<(Arc<dyn NativeArray>, Vec<usize>)> {
+    let mut invalid_rows = vec![];
+    let wkb_objects: Vec<Option<WKB<'_, O>>> = if policy == ParseErrorPolicy::Strict {
+        arr.iter().collect()
+    } else {
+        arr.iter()
+            .enumerate()
+            .map(|(row, maybe_wkb)| {
+                maybe_wkb.filter(|wkb| {
+                    let is_valid = wkb.parse().is_ok();
+                    if !is_valid {
+                        policy.record(row, &mut invalid_rows);
+                    }
+                    is_valid
+                })
+            })
+            .collect()
+    };
+    let array = from_wkb_objects(arr, &wkb_objects, target_type, prefer_multi)?;
+    Ok((array, invalid_rows))
+}
+
+/// Parse slices of the array concurrently, each into its own builder, and concatenate the parsed
+/// slices.
+#[cfg(feature = "rayon")]
+fn from_wkb_parallel<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    target_type: NativeType,
+    prefer_multi: bool,
+    policy: ParseErrorPolicy,
+) -> Result<(Arc<dyn NativeArray>, Vec<usize>)> {
+    use rayon::prelude::*;
+
+    let parsed = (0..arr.len())
+        .step_by(PARALLEL_SLICE_LEN)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|offset| {
+            let slice = arr.slice(offset, PARALLEL_SLICE_LEN.min(arr.len() - offset));
+            let (array, invalid_rows) = parse_wkb(&slice, target_type, prefer_multi, policy)?;
+            let invalid_rows = invalid_rows.into_iter().map(|row| row + offset).collect();
+            Ok((array, invalid_rows))
+        })
+        .collect::<Result<Vec<(Arc<dyn NativeArray>, Vec<usize>)>>>()?;
+
+    let (arrays, invalid_rows): (Vec<_>, Vec<Vec<usize>>) = parsed.into_iter().unzip();
+    Ok((concat_native_arrays(&arrays)?, invalid_rows.concat()))
+}
+
+/// Concatenate native arrays of the same type, keeping the metadata of the first.
+#[cfg(feature = "rayon")]
+fn concat_native_arrays(arrays: &[Arc<dyn NativeArray>]) -> Result<Arc<dyn NativeArray>> {
+    let field = arrays
+        .first()
+        .ok_or_else(|| GeoArrowError::General("No arrays to concatenate".to_string()))?
+        .extension_field();
+    let arrays = arrays
+        .iter()
+        .map(|array| array.to_array_ref())
+        .collect::<Vec<_>>();
+    let array = arrow::compute::concat(
+        &arrays
+            .iter()
+            .map(|array| array.as_ref())
+            .collect::<Vec<_>>(),
+    )?;
+    Ok(NativeArrayDyn::from_arrow_array(&array, &field)?.into_inner())
+}
+
+fn from_wkb_objects<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    wkb_objects: &[Option<WKB<'_, O>>],
+    target_type: NativeType,
+    prefer_multi: bool,
 ) -> Result<Arc<dyn NativeArray>> {
     use NativeType::*;
-    let wkb_objects: Vec<Option<crate::scalar::WKB<'_, O>>> = arr.iter().collect();
     match target_type {
         Point(coord_type, dim) => {
-            let builder = PointBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
+            let builder = PointBuilder::from_wkb(wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         LineString(coord_type, dim) => {
             let builder =
-                LineStringBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
+                LineStringBuilder::from_wkb(wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         Polygon(coord_type, dim) => {
-            let builder = PolygonBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
+            let builder = PolygonBuilder::from_wkb(wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         MultiPoint(coord_type, dim) => {
             let builder =
-                MultiPointBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
+                MultiPointBuilder::from_wkb(wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         MultiLineString(coord_type, dim) => {
             let builder =
-                MultiLineStringBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
+                MultiLineStringBuilder::from_wkb(wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         MultiPolygon(coord_type, dim) => {
             let builder =
-                MultiPolygonBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
+                MultiPolygonBuilder::from_wkb(wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
         GeometryCollection(coord_type, dim) => {
             let builder = GeometryCollectionBuilder::from_wkb(
-                &wkb_objects,
+                wkb_objects,
                 dim,
                 coord_type,
                 arr.metadata(),
@@ -238,7 +356,7 @@ pub fn from_wkb<O: OffsetSizeTrait>(
         ))),
         Geometry(coord_type) => {
             let builder =
-                GeometryBuilder::from_wkb(&wkb_objects, coord_type, arr.metadata(), prefer_multi)?;
+                GeometryBuilder::from_wkb(wkb_objects, coord_type, arr.metadata(), prefer_multi)?;
             Ok(Arc::new(builder.finish()))
         }
     }
@@ -326,8 +444,11 @@ pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn NativeArray) -> WKBArray<O> {
 
 #[cfg(test)]
 mod test {
+    use arrow_array::BinaryArray;
+
     use super::*;
     use crate::test::point;
+    use crate::ArrayBase;
 
     #[test]
     fn point_round_trip_explicit_casting() {
@@ -359,6 +480,90 @@ mod test {
         assert_eq!(&arr, rt_point_arr);
     }
 
+    #[test]
+    fn parse_error_policy() {
+        let valid: WKBArray<i32> = to_wkb(&point::point_array());
+        let valid = valid.into_inner();
+        let arr = WKBArray::new(
+            BinaryArray::from_opt_vec(vec![
+                Some(valid.value(0)),
+                Some(&[1u8, 1, 0, 0][..]),
+                None,
+                Some(valid.value(2)),
+            ]),
+            Default::default(),
+        );
+        let target_type = NativeType::Point(CoordType::Interleaved, Dimension::XY);
+
+        assert!(from_wkb(&arr, target_type, false).is_err());
+
+        let options = ParseOptions {
+            policy: ParseErrorPolicy::NullOnError,
+            parallel: false,
+        };
+        let (parsed, invalid_rows) =
+            from_wkb_with_policy(&arr, target_type, false, &options).unwrap();
+        assert!(invalid_rows.is_empty());
+        let parsed_ref = parsed.as_ref();
+        let points = parsed_ref.as_point();
+        assert_eq!(points.len(), 4);
+        assert_eq!(points.value_as_geo(0), point::p0());
+        assert!(points.is_null(1));
+        assert!(points.is_null(2));
+        assert_eq!(points.value_as_geo(3), point::p2());
+
+        let options = ParseOptions {
+            policy: ParseErrorPolicy::Collect,
+            parallel: false,
+        };
+        let (_, invalid_rows) = from_wkb_with_policy(&arr, target_type, false, &options).unwrap();
+        assert_eq!(invalid_rows, vec![1]);
+    }
+
+    #[test]
+    fn parallel_matches_serial() {
+        let valid: WKBArray<i32> = to_wkb(&point::point_array());
+        let valid = valid.into_inner();
+        let len = 2 * PARALLEL_SLICE_LEN + 3;
+        let values = (0..len)
+            .map(|i| match i % 7 {
+                0 => None,
+                1 => Some(&[1u8, 1, 0, 0][..]),
+                _ => Some(valid.value(i % 3)),
+            })
+            .collect::<Vec<_>>();
+        let arr = WKBArray::new(BinaryArray::from_opt_vec(values), Default::default());
+
+        for target_type in [
+            NativeType::Point(CoordType::Separated, Dimension::XY),
+            NativeType::Geometry(CoordType::Interleaved),
+        ] {
+            let serial_options = ParseOptions {
+                policy: ParseErrorPolicy::Collect,
+                parallel: false,
+            };
+            let (serial, serial_invalid) =
+                from_wkb_with_policy(&arr, target_type, false, &serial_options).unwrap();
+            let parallel_options = ParseOptions {
+                parallel: true,
+                ..serial_options
+            };
+            let (parallel, parallel_invalid) =
+                from_wkb_with_policy(&arr, target_type, false, &parallel_options).unwrap();
+            assert_eq!(parallel.data_type(), target_type);
+            assert_eq!(parallel.len(), len);
+            assert_eq!(parallel_invalid, serial_invalid);
+            assert_eq!(
+                serial_invalid.len(),
+                (0..len).filter(|i| i % 7 == 1).count()
+            );
+            assert_eq!(
+                parallel.to_array_ref().as_ref(),
+                serial.to_array_ref().as_ref()
+            );
+        }
+    }
+
     #[test]
     fn point_3d_round_trip() {
         let arr = point::point_z_array();
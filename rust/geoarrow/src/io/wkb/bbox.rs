@@ -0,0 +1,184 @@
+//! Bounding box filters of WKB buffers without parsing them into geometries.
+
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geo_traits::{CoordTrait, RectTrait};
+
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::validate::{
+    Header, Validator, GEOMETRY_COLLECTION, LINE_STRING, MAX_DEPTH, MIN_GEOMETRY_SIZE,
+    MULTI_LINE_STRING, MULTI_POINT, MULTI_POLYGON, POINT, POLYGON,
+};
+use crate::io::wkb::NanPolicy;
+use crate::trait_::ArrayAccessor;
+
+/// Whether the bounding box of each WKB geometry intersects a rectangle, boundary included.
+///
+/// The coordinates are read straight from the buffers, without parsing geometries, and only the
+/// exterior rings of polygons are read. This makes it a cheap pre-filter of WKB columns, such as
+/// those of GeoParquet batches, before they're parsed to native arrays.
+///
+/// Only the X and Y values are compared. Empty geometries, and NaN coordinates such as those of
+/// empty points, never intersect. The output is null where the input is null, and it's an error
+/// if any value isn't well-formed WKB.
+pub fn filter_wkb_bbox<O: OffsetSizeTrait>(
+    arr: &WKBArray<O>,
+    rect: &impl RectTrait<T = f64>,
+) -> Result<BooleanArray> {
+    let (min, max) = (rect.min(), rect.max());
+    arr.iter()
+        .map(|wkb| {
+            let Some(wkb) = wkb else {
+                return Ok(None);
+            };
+            let bounds = wkb_bounds(wkb.as_ref()).ok_or_else(|| {
+                GeoArrowError::General("Invalid WKB geometry in bounding box filter".to_string())
+            })?;
+            Ok(Some(bounds.is_some_and(|[min_x, min_y, max_x, max_y]| {
+                min_x <= max.x() && max_x >= min.x() && min_y <= max.y() && max_y >= min.y()
+            })))
+        })
+        .collect()
+}
+
+/// The bounding box of a WKB buffer as `[min_x, min_y, max_x, max_y]`, or `Some(None)` if the
+/// geometry is empty. Returns `None` if the buffer is invalid.
+fn wkb_bounds(buf: &[u8]) -> Option<Option<[f64; 4]>> {
+    let mut scanner = BoundsScanner {
+        cursor: Validator::new(buf, NanPolicy::Allow),
+        bounds: [
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ],
+    };
+    scanner.geometry(0)?;
+    let bounds = scanner.bounds;
+    Some((bounds[0] <= bounds[2]).then_some(bounds))
+}
+
+/// Accumulates the bounds of the coordinates of a WKB buffer.
+struct BoundsScanner<'a> {
+    cursor: Validator<'a>,
+    bounds: [f64; 4],
+}
+
+impl BoundsScanner<'_> {
+    fn geometry(&mut self, depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let header = self.cursor.header()?;
+        let little_endian = header.little_endian;
+        let coord_size = header.num_dims * 8;
+        match header.geometry_type {
+            POINT => self.coords(&header, 1),
+            LINE_STRING => {
+                let num_coords = self.cursor.read_count(little_endian, coord_size)?;
+                self.coords(&header, num_coords)
+            }
+            POLYGON => {
+                let num_rings = self.cursor.read_count(little_endian, 4)?;
+                for ring_idx in 0..num_rings {
+                    let num_coords = self.cursor.read_count(little_endian, coord_size)?;
+                    // Interior rings are inside the exterior ring
+                    if ring_idx == 0 {
+                        self.coords(&header, num_coords)?;
+                    } else {
+                        self.cursor.take(num_coords * coord_size)?;
+                    }
+                }
+                Some(())
+            }
+            MULTI_POINT | MULTI_LINE_STRING | MULTI_POLYGON | GEOMETRY_COLLECTION => {
+                let num_parts = self.cursor.read_count(little_endian, MIN_GEOMETRY_SIZE)?;
+                for _ in 0..num_parts {
+                    self.geometry(depth + 1)?;
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn coords(&mut self, header: &Header, num_coords: usize) -> Option<()> {
+        for _ in 0..num_coords {
+            let x = self.cursor.read_f64(header.little_endian)?;
+            let y = self.cursor.read_f64(header.little_endian)?;
+            self.cursor.take((header.num_dims - 2) * 8)?;
+            // NaN coordinates fail every comparison, so they don't extend the bounds
+            if x < self.bounds[0] {
+                self.bounds[0] = x;
+            }
+            if y < self.bounds[1] {
+                self.bounds[1] = y;
+            }
+            if x > self.bounds[2] {
+                self.bounds[2] = x;
+            }
+            if y > self.bounds[3] {
+                self.bounds[3] = y;
+            }
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::BinaryArray;
+
+    use super::*;
+    use crate::array::PolygonArray;
+    use crate::datatypes::Dimension;
+    use crate::io::wkb::to_wkb;
+    use crate::test::point;
+
+    fn rect(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> geo::Rect {
+        geo::Rect::new(
+            geo::coord! { x: min_x, y: min_y },
+            geo::coord! { x: max_x, y: max_y },
+        )
+    }
+
+    #[test]
+    fn points() {
+        let points: WKBArray<i32> = to_wkb(&point::point_z_array());
+        let all = filter_wkb_bbox(&points, &rect(-1000., -1000., 1000., 1000.)).unwrap();
+        assert_eq!(all.true_count(), points.len());
+        let none = filter_wkb_bbox(&points, &rect(1000., 1000., 2000., 2000.)).unwrap();
+        assert_eq!(none.true_count(), 0);
+    }
+
+    #[test]
+    fn polygons_nulls_and_invalid() {
+        let polygons: PolygonArray = (
+            vec![
+                Some(geo::polygon![
+                    exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+                    interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.)]],
+                ]),
+                None,
+                Some(geo::Polygon::new(geo::LineString::new(vec![]), vec![])),
+            ],
+            Dimension::XY,
+        )
+            .into();
+        let wkb: WKBArray<i32> = to_wkb(&polygons);
+        assert_eq!(
+            filter_wkb_bbox(&wkb, &rect(9., 9., 12., 12.)).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(false)])
+        );
+        assert_eq!(
+            filter_wkb_bbox(&wkb, &rect(11., 11., 12., 12.)).unwrap(),
+            BooleanArray::from(vec![Some(false), None, Some(false)])
+        );
+
+        let truncated = WKBArray::new(
+            BinaryArray::from_iter_values([[1, 1, 0, 0, 0, 0]]),
+            Default::default(),
+        );
+        assert!(filter_wkb_bbox(&truncated, &rect(0., 0., 1., 1.)).is_err());
+    }
+}
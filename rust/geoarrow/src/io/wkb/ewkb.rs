@@ -0,0 +1,299 @@
+//! Support for the SRID of [Extended WKB](https://libgeos.org/specifications/wkb/#extended-wkb)
+//! (EWKB), the WKB variant written by PostGIS.
+//!
+//! EWKB stores the dimension of a geometry as flags in the high bits of its type, and may embed
+//! an SRID after the type. Parsing EWKB geometries is handled by the [wkb] crate, which ignores
+//! the SRID; the functions here read it so that it can be stored as the CRS of the array.
+//! [`to_ewkb`] writes EWKB with an SRID, which PostGIS accepts directly into `geometry` columns.
+
+use arrow_array::OffsetSizeTrait;
+use std::sync::Arc;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::{to_wkb_with_options, WkbFlavor, WkbWriteOptions};
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// The EWKB flag set when the geometry has Z values.
+pub(crate) const EWKB_Z_FLAG: u32 = 0x8000_0000;
+
+/// The EWKB flag set when the geometry has M values.
+pub(crate) const EWKB_M_FLAG: u32 = 0x4000_0000;
+
+/// The EWKB flag set when an SRID follows the geometry type.
+pub(crate) const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+pub(crate) fn read_u32(buf: &[u8], offset: usize, little_endian: bool) -> Result<u32> {
+    let bytes: [u8; 4] = buf
+        .get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| GeoArrowError::General("Unexpected end of WKB buffer".to_string()))?;
+    Ok(if little_endian {
+        u32::from_le_bytes(bytes)
+    } else {
+        u32::from_be_bytes(bytes)
+    })
+}
+
+/// The byte order and geometry type of a WKB buffer.
+fn read_header(buf: &[u8]) -> Result<(bool, u32)> {
+    let little_endian = match buf.first() {
+        Some(0) => false,
+        Some(1) => true,
+        Some(byte_order) => {
+            return Err(GeoArrowError::General(format!(
+                "Invalid WKB byte order {byte_order}"
+            )))
+        }
+        None => return Err(GeoArrowError::General("Empty WKB buffer".to_string())),
+    };
+    Ok((little_endian, read_u32(buf, 1, little_endian)?))
+}
+
+/// Whether a WKB buffer is EWKB, i.e. whether its geometry type has any of the EWKB flags.
+///
+/// ISO WKB encodes dimensions by adding 1000, 2000 or 3000 to the type instead.
+pub fn is_ewkb(buf: &[u8]) -> Result<bool> {
+    let (_, geometry_type) = read_header(buf)?;
+    Ok(geometry_type & (EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG) != 0)
+}
+
+/// The SRID embedded in an EWKB buffer, or `None` if it has no SRID.
+pub fn ewkb_srid(buf: &[u8]) -> Result<Option<i32>> {
+    let (little_endian, geometry_type) = read_header(buf)?;
+    if geometry_type & EWKB_SRID_FLAG == 0 {
+        return Ok(None);
+    }
+    Ok(Some(read_u32(buf, 5, little_endian)? as i32))
+}
+
+/// The SRID that [`to_ewkb`] writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EwkbSrid {
+    /// The SRID of the CRS of the array, from [`ArrayMetadata::srid`].
+    ///
+    /// No SRID is written if the array has no CRS, and it is an error if the array has a CRS
+    /// without a known SRID.
+    #[default]
+    FromMetadata,
+
+    /// The given SRID, whatever the CRS of the array.
+    Srid(i32),
+
+    /// No SRID.
+    None,
+}
+
+impl EwkbSrid {
+    /// The SRID to embed in geometries of an array with the given metadata.
+    pub(crate) fn resolve(self, metadata: &ArrayMetadata) -> Result<Option<i32>> {
+        match self {
+            Self::FromMetadata => match (&metadata.crs, metadata.srid()) {
+                (None, _) => Ok(None),
+                (Some(_), Some(srid)) => Ok(Some(srid)),
+                (Some(_), None) => Err(GeoArrowError::General(
+                    "The CRS of the array has no known SRID; pass an explicit SRID".to_string(),
+                )),
+            },
+            Self::Srid(srid) => Ok(Some(srid)),
+            Self::None => Ok(None),
+        }
+    }
+}
+
+/// Encode a geometry array as EWKB.
+///
+/// Unlike [`to_wkb`], which writes ISO WKB, the dimension of each geometry is stored in the EWKB
+/// flags, and the top-level geometries embed the SRID given by `srid`. The output keeps the
+/// metadata of the input.
+///
+/// This is a shorthand for [`to_wkb_with_options`] with [`WkbFlavor::Extended`].
+///
+/// [`to_wkb`]: crate::io::wkb::to_wkb
+pub fn to_ewkb<O: OffsetSizeTrait>(arr: &dyn NativeArray, srid: EwkbSrid) -> Result<WKBArray<O>> {
+    let options = WkbWriteOptions {
+        flavor: WkbFlavor::Extended(srid),
+        ..Default::default()
+    };
+    to_wkb_with_options(arr, &options)
+}
+
+impl<O: OffsetSizeTrait> WKBArray<O> {
+    /// The SRID shared by the EWKB geometries of this array.
+    ///
+    /// Geometries without an SRID, and with an SRID of 0, which PostGIS uses for an unknown SRID,
+    /// are ignored. Returns `None` if no geometry has an SRID, and an error if geometries have
+    /// different SRIDs.
+    pub fn ewkb_srid(&self) -> Result<Option<i32>> {
+        let mut srid = None;
+        for geom in self.iter().flatten() {
+            match (ewkb_srid(geom.as_ref())?, srid) {
+                (None | Some(0), _) => {}
+                (Some(geom_srid), None) => srid = Some(geom_srid),
+                (Some(geom_srid), Some(srid)) if geom_srid != srid => {
+                    return Err(GeoArrowError::General(format!(
+                        "EWKB geometries have different SRIDs: {srid} and {geom_srid}"
+                    )))
+                }
+                _ => {}
+            }
+        }
+        Ok(srid)
+    }
+
+    /// Set the CRS of this array to the SRID of its EWKB geometries, interpreted as an EPSG
+    /// code.
+    ///
+    /// The array is returned unchanged if no geometry has an SRID. Other metadata, such as the
+    /// edges, is kept.
+    pub fn with_ewkb_srid(&self) -> Result<Self> {
+        match self.ewkb_srid()? {
+            Some(srid) => {
+                let metadata = self
+                    .metadata()
+                    .as_ref()
+                    .clone()
+                    .with_authority_code(format!("EPSG:{srid}"));
+                Ok(self.with_metadata(Arc::new(metadata)))
+            }
+            None => Ok(self.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::BinaryArray;
+    use geo_traits::{CoordTrait, PointTrait};
+
+    use super::*;
+    use crate::array::{MultiPolygonArray, PointArray};
+    use crate::datatypes::{Dimension, NativeType};
+    use crate::io::wkb::from_wkb;
+    use crate::test::{multipolygon, point};
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Test cases from the wkx test data.
+
+    /// POINT(1 2), ISO WKB.
+    const POINT_WKB: &str = "0101000000000000000000f03f0000000000000040";
+    /// SRID=4326;POINT(1 2), little endian.
+    const POINT_EWKB: &str = "0101000020e6100000000000000000f03f0000000000000040";
+    /// SRID=4326;POINT(1 2), big endian.
+    const POINT_EWKB_BE: &str = "0020000001000010e63ff00000000000004000000000000000";
+    /// SRID=4326;POINT Z(1 2 3).
+    const POINT_Z_EWKB: &str = "01010000a0e6100000000000000000f03f00000000000000400000000000000840";
+
+    #[test]
+    fn srid() {
+        assert_eq!(ewkb_srid(&hex(POINT_WKB)).unwrap(), None);
+        assert!(!is_ewkb(&hex(POINT_WKB)).unwrap());
+        for ewkb in [POINT_EWKB, POINT_EWKB_BE, POINT_Z_EWKB] {
+            assert!(is_ewkb(&hex(ewkb)).unwrap());
+            assert_eq!(ewkb_srid(&hex(ewkb)).unwrap(), Some(4326));
+        }
+        assert!(ewkb_srid(&[]).is_err());
+        assert!(ewkb_srid(&hex("0101000020e610")).is_err());
+    }
+
+    #[test]
+    fn parse_ewkb() {
+        let values = [hex(POINT_WKB), hex(POINT_EWKB), hex(POINT_EWKB_BE)];
+        let array: WKBArray<i32> = BinaryArray::from_iter_values(values).into();
+        let array = array.with_ewkb_srid().unwrap();
+        assert_eq!(array.metadata().srid(), Some(4326));
+
+        let parsed = from_wkb(
+            &array,
+            NativeType::Point(Default::default(), Dimension::XY),
+            false,
+        )
+        .unwrap();
+        assert_eq!(parsed.metadata().srid(), Some(4326));
+        let points = parsed.as_any().downcast_ref::<PointArray>().unwrap();
+        for point in points.iter().flatten() {
+            let coord = point.coord().unwrap();
+            assert_eq!((coord.x(), coord.y()), (1., 2.));
+        }
+
+        let array: WKBArray<i32> = BinaryArray::from_iter_values([hex(POINT_Z_EWKB)]).into();
+        let parsed = from_wkb(
+            &array,
+            NativeType::Point(Default::default(), Dimension::XYZ),
+            false,
+        )
+        .unwrap();
+        let points = parsed.as_any().downcast_ref::<PointArray>().unwrap();
+        let coord = points.value(0).coord().unwrap();
+        assert_eq!(coord.nth_or_panic(2), 3.);
+
+        let mixed = [
+            hex(POINT_EWKB),
+            hex("0101000020110f0000000000000000f03f0000000000000040"),
+        ];
+        let array: WKBArray<i32> = BinaryArray::from_iter_values(mixed).into();
+        assert!(array.ewkb_srid().is_err());
+    }
+
+    #[test]
+    fn write_ewkb() {
+        let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+        let array = array.with_metadata(Arc::new(ArrayMetadata::from_authority_code(
+            "EPSG:4326".to_string(),
+        )));
+        let ewkb: WKBArray<i32> = to_ewkb(array.as_ref(), EwkbSrid::FromMetadata).unwrap();
+        assert_eq!(ewkb.value(0).as_ref(), hex(POINT_EWKB));
+        let ewkb: WKBArray<i32> = to_ewkb(array.as_ref(), EwkbSrid::Srid(3857)).unwrap();
+        assert_eq!(ewkb.ewkb_srid().unwrap(), Some(3857));
+        let ewkb: WKBArray<i32> = to_ewkb(array.as_ref(), EwkbSrid::None).unwrap();
+        assert_eq!(
+            ewkb.value(0).as_ref(),
+            hex("0101000000000000000000f03f0000000000000040")
+        );
+
+        let unknown = array.with_metadata(Arc::new(ArrayMetadata::from_unknown_crs_type(
+            "my crs".to_string(),
+        )));
+        assert!(to_ewkb::<i32>(unknown.as_ref(), EwkbSrid::FromMetadata).is_err());
+    }
+
+    #[test]
+    fn ewkb_round_trip() {
+        let array = point::point_z_array();
+        let ewkb: WKBArray<i32> = to_ewkb(&array, EwkbSrid::Srid(4326)).unwrap();
+        assert!(is_ewkb(ewkb.value(0).as_ref()).unwrap());
+        let parsed = from_wkb(
+            &ewkb,
+            NativeType::Point(Default::default(), Dimension::XYZ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            parsed.as_any().downcast_ref::<PointArray>().unwrap(),
+            &array
+        );
+
+        let array = multipolygon::mp_array();
+        let ewkb: WKBArray<i32> = to_ewkb(&array, EwkbSrid::Srid(4326)).unwrap();
+        assert_eq!(ewkb.ewkb_srid().unwrap(), Some(4326));
+        let parsed = from_wkb(
+            &ewkb,
+            NativeType::MultiPolygon(Default::default(), Dimension::XY),
+            false,
+        )
+        .unwrap();
+        let parsed = parsed.as_any().downcast_ref::<MultiPolygonArray>().unwrap();
+        assert_eq!(
+            parsed.iter_geo_values().collect::<Vec<_>>(),
+            array.iter_geo_values().collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,9 +1,25 @@
 //! Read and write geometries encoded as [Well-Known Binary](https://libgeos.org/specifications/wkb/).
 //!
 //! This wraps the [wkb] crate. As such, it currently supports reading the ISO and extended (EWKB)
-//! variants of WKB. Currently, it always writes the ISO WKB variant.
+//! variants of WKB. The SRID of EWKB geometries can be read with [`WKBArray::ewkb_srid`] and stored
+//! as the CRS of the array with [`WKBArray::with_ewkb_srid`]. [`to_wkb`] writes the ISO WKB
+//! variant, and [`to_ewkb`] writes EWKB with an SRID. [`to_wkb_with_options`] also controls the
+//! byte order and the encoding of empty points. [`validate_wkb`] checks that WKB values are
+//! well-formed without parsing them, and [`filter_wkb_bbox`] filters them by bounding box without
+//! parsing them.
+//!
+//! [`WKBArray::ewkb_srid`]: crate::array::WKBArray::ewkb_srid
+//! [`WKBArray::with_ewkb_srid`]: crate::array::WKBArray::with_ewkb_srid
 
 mod api;
+mod bbox;
+mod ewkb;
+mod validate;
+mod write_options;
 pub(crate) mod writer;
 
-pub use api::{from_wkb, to_wkb, FromWKB, ToWKB};
+pub use api::{from_wkb, from_wkb_with_policy, to_wkb, FromWKB, ToWKB, PARALLEL_SLICE_LEN};
+pub use bbox::filter_wkb_bbox;
+pub use ewkb::{ewkb_srid, is_ewkb, to_ewkb, EwkbSrid};
+pub use validate::{is_valid_wkb, validate_wkb, NanPolicy};
+pub use write_options::{to_wkb_with_options, WkbByteOrder, WkbFlavor, WkbWriteOptions};
@@ -0,0 +1,326 @@
+//! Validation of WKB buffers without parsing them into geometries.
+
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+
+use crate::array::WKBArray;
+use crate::io::wkb::ewkb::{EWKB_M_FLAG, EWKB_SRID_FLAG, EWKB_Z_FLAG};
+use crate::trait_::ArrayAccessor;
+
+/// Geometries nested deeper than this, which only happens in malicious or corrupt input, are
+/// invalid.
+pub(super) const MAX_DEPTH: usize = 64;
+
+pub(super) const POINT: u32 = 1;
+pub(super) const LINE_STRING: u32 = 2;
+pub(super) const POLYGON: u32 = 3;
+pub(super) const MULTI_POINT: u32 = 4;
+pub(super) const MULTI_LINE_STRING: u32 = 5;
+pub(super) const MULTI_POLYGON: u32 = 6;
+pub(super) const GEOMETRY_COLLECTION: u32 = 7;
+
+/// The smallest encoded geometry, a header with an empty count.
+pub(super) const MIN_GEOMETRY_SIZE: usize = 9;
+
+/// Which NaN coordinates are valid in [`validate_wkb`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Any coordinate may be NaN.
+    Allow,
+
+    /// Only points whose coordinates are all NaN, which is how WKB encodes an empty point, may
+    /// have NaN coordinates.
+    #[default]
+    EmptyPoints,
+
+    /// No coordinate may be NaN, including those of empty points.
+    Reject,
+}
+
+/// Check whether every value of a WKB array is a well-formed WKB geometry, without parsing it.
+///
+/// This checks the byte order and geometry type of every (nested) geometry, that the declared
+/// numbers of coordinates, rings and parts fit in the buffer, that nothing follows the geometry,
+/// and that NaN coordinates are allowed by `nan_policy`. It's much cheaper than a full parse, and
+/// meant to triage large WKB columns before parsing them.
+///
+/// ISO WKB and EWKB, including EWKB with an SRID, are accepted, with any of the XY, XYZ, XYM and
+/// XYZM dimensions. Multi geometries must only contain geometries of their single type.
+///
+/// The output is null where the input is null.
+pub fn validate_wkb<O: OffsetSizeTrait>(arr: &WKBArray<O>, nan_policy: NanPolicy) -> BooleanArray {
+    arr.iter()
+        .map(|wkb| wkb.map(|wkb| is_valid_wkb(wkb.as_ref(), nan_policy)))
+        .collect()
+}
+
+/// Check whether a buffer is a single well-formed WKB geometry. See [`validate_wkb`].
+pub fn is_valid_wkb(buf: &[u8], nan_policy: NanPolicy) -> bool {
+    let mut validator = Validator::new(buf, nan_policy);
+    validator.geometry(None, 0).is_some() && validator.is_finished()
+}
+
+/// The parts of a WKB header needed to check the geometry that follows.
+pub(super) struct Header {
+    pub(super) little_endian: bool,
+    pub(super) geometry_type: u32,
+    pub(super) num_dims: usize,
+}
+
+/// A cursor over a WKB buffer. Methods return `None` as soon as the buffer is invalid.
+pub(super) struct Validator<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    nan_policy: NanPolicy,
+}
+
+impl<'a> Validator<'a> {
+    pub(super) fn new(buf: &'a [u8], nan_policy: NanPolicy) -> Self {
+        Self {
+            buf,
+            pos: 0,
+            nan_policy,
+        }
+    }
+
+    /// Whether the whole buffer has been read.
+    pub(super) fn is_finished(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    pub(super) fn take(&mut self, len: usize) -> Option<&[u8]> {
+        let bytes = self.buf.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Option<u32> {
+        let bytes: [u8; 4] = self.take(4)?.try_into().ok()?;
+        Some(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    pub(super) fn read_f64(&mut self, little_endian: bool) -> Option<f64> {
+        let bytes: [u8; 8] = self.take(8)?.try_into().ok()?;
+        Some(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Read a count of items that take at least `min_item_size` bytes each, checking that they
+    /// can fit in the rest of the buffer.
+    pub(super) fn read_count(
+        &mut self,
+        little_endian: bool,
+        min_item_size: usize,
+    ) -> Option<usize> {
+        let count = self.read_u32(little_endian)? as usize;
+        (count.checked_mul(min_item_size)? <= self.remaining()).then_some(count)
+    }
+
+    pub(super) fn header(&mut self) -> Option<Header> {
+        let little_endian = match self.take(1)?[0] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        let code = self.read_u32(little_endian)?;
+        if code & EWKB_SRID_FLAG != 0 {
+            self.take(4)?;
+        }
+        let mut has_z = code & EWKB_Z_FLAG != 0;
+        let mut has_m = code & EWKB_M_FLAG != 0;
+        let iso_code = code & !(EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG);
+        match iso_code / 1000 {
+            0 => {}
+            1 => has_z = true,
+            2 => has_m = true,
+            3 => (has_z, has_m) = (true, true),
+            _ => return None,
+        }
+        let geometry_type = iso_code % 1000;
+        (POINT..=GEOMETRY_COLLECTION)
+            .contains(&geometry_type)
+            .then_some(Header {
+                little_endian,
+                geometry_type,
+                num_dims: 2 + has_z as usize + has_m as usize,
+            })
+    }
+
+    /// Check a geometry, which must have type `expected_type` if given.
+    fn geometry(&mut self, expected_type: Option<u32>, depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let header = self.header()?;
+        if expected_type.is_some_and(|expected| expected != header.geometry_type) {
+            return None;
+        }
+        let little_endian = header.little_endian;
+        let coord_size = header.num_dims * 8;
+        match header.geometry_type {
+            POINT => self.point(&header),
+            LINE_STRING => {
+                let num_coords = self.read_count(little_endian, coord_size)?;
+                self.coords(&header, num_coords)
+            }
+            POLYGON => {
+                let num_rings = self.read_count(little_endian, 4)?;
+                for _ in 0..num_rings {
+                    let num_coords = self.read_count(little_endian, coord_size)?;
+                    self.coords(&header, num_coords)?;
+                }
+                Some(())
+            }
+            GEOMETRY_COLLECTION => {
+                let num_geometries = self.read_count(little_endian, MIN_GEOMETRY_SIZE)?;
+                for _ in 0..num_geometries {
+                    self.geometry(None, depth + 1)?;
+                }
+                Some(())
+            }
+            MULTI_POINT | MULTI_LINE_STRING | MULTI_POLYGON => {
+                let num_parts = self.read_count(little_endian, MIN_GEOMETRY_SIZE)?;
+                for _ in 0..num_parts {
+                    self.geometry(Some(header.geometry_type - 3), depth + 1)?;
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn point(&mut self, header: &Header) -> Option<()> {
+        let mut num_nan = 0;
+        for _ in 0..header.num_dims {
+            num_nan += self.read_f64(header.little_endian)?.is_nan() as usize;
+        }
+        match (self.nan_policy, num_nan) {
+            (_, 0) | (NanPolicy::Allow, _) => Some(()),
+            (NanPolicy::EmptyPoints, n) if n == header.num_dims => Some(()),
+            _ => None,
+        }
+    }
+
+    fn coords(&mut self, header: &Header, num_coords: usize) -> Option<()> {
+        let num_values = num_coords * header.num_dims;
+        if self.nan_policy == NanPolicy::Allow {
+            self.take(num_values * 8)?;
+            return Some(());
+        }
+        for _ in 0..num_values {
+            if self.read_f64(header.little_endian)?.is_nan() {
+                return None;
+            }
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::BinaryArray;
+
+    use super::*;
+    use crate::io::wkb::to_wkb;
+    use crate::test::{multipolygon, point};
+    use crate::ArrayBase;
+
+    fn point_wkb(x: f64, y: f64) -> Vec<u8> {
+        let mut buf = vec![1, 1, 0, 0, 0];
+        buf.extend_from_slice(&x.to_le_bytes());
+        buf.extend_from_slice(&y.to_le_bytes());
+        buf
+    }
+
+    #[test]
+    fn valid_arrays() {
+        let points: WKBArray<i32> = to_wkb(&point::point_z_array());
+        let validity = validate_wkb(&points, NanPolicy::Reject);
+        assert_eq!(validity.true_count(), points.len());
+
+        let multi_polygons: WKBArray<i64> = to_wkb(&multipolygon::mp_array());
+        let validity = validate_wkb(&multi_polygons, NanPolicy::Reject);
+        assert_eq!(validity.true_count(), multi_polygons.len());
+    }
+
+    #[test]
+    fn invalid_values() {
+        let point = point_wkb(1., 2.);
+        let truncated = point[..point.len() - 1].to_vec();
+        let mut big_endian_type = point.clone();
+        big_endian_type[0] = 0;
+        let mut bad_byte_order = point.clone();
+        bad_byte_order[0] = 2;
+        let mut bad_type = point.clone();
+        bad_type[1] = 8;
+        let mut trailing = point.clone();
+        trailing.push(0);
+        // A line string that declares 1000 coordinates but has none
+        let too_many_coords = vec![1, 2, 0, 0, 0, 0xe8, 0x03, 0, 0];
+        // A multi point that contains a line string
+        let mut wrong_part = vec![1, 4, 0, 0, 0, 1, 0, 0, 0];
+        wrong_part.extend_from_slice(&[1, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+        let values: Vec<Option<&[u8]>> = vec![
+            Some(&point),
+            None,
+            Some(&truncated),
+            Some(&big_endian_type),
+            Some(&bad_byte_order),
+            Some(&bad_type),
+            Some(&trailing),
+            Some(&too_many_coords),
+            Some(&wrong_part),
+        ]
+        .into_iter()
+        .map(|value: Option<&Vec<u8>>| value.map(Vec::as_slice))
+        .collect();
+        let arr = WKBArray::new(BinaryArray::from_opt_vec(values), Default::default());
+        assert_eq!(
+            validate_wkb(&arr, NanPolicy::default()),
+            BooleanArray::from(vec![
+                Some(true),
+                None,
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+            ])
+        );
+    }
+
+    #[test]
+    fn nan_policy() {
+        let empty = point_wkb(f64::NAN, f64::NAN);
+        let half_empty = point_wkb(1., f64::NAN);
+        let arr = WKBArray::new(
+            BinaryArray::from_iter_values([empty, half_empty]),
+            Default::default(),
+        );
+        assert_eq!(
+            validate_wkb(&arr, NanPolicy::Allow),
+            BooleanArray::from(vec![true, true])
+        );
+        assert_eq!(
+            validate_wkb(&arr, NanPolicy::EmptyPoints),
+            BooleanArray::from(vec![true, false])
+        );
+        assert_eq!(
+            validate_wkb(&arr, NanPolicy::Reject),
+            BooleanArray::from(vec![false, false])
+        );
+    }
+}
@@ -0,0 +1,366 @@
+//! Options for the byte order and variant of written WKB.
+
+use arrow_array::builder::GenericBinaryBuilder;
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::WKBArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::ewkb::{read_u32, EwkbSrid, EWKB_M_FLAG, EWKB_SRID_FLAG, EWKB_Z_FLAG};
+use crate::io::wkb::to_wkb;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+const POINT: u32 = 1;
+const MULTI_POINT: u32 = 4;
+
+/// The byte order of written WKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WkbByteOrder {
+    /// Little endian, also called NDR.
+    #[default]
+    LittleEndian,
+
+    /// Big endian, also called XDR.
+    BigEndian,
+}
+
+/// The variant of written WKB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WkbFlavor {
+    /// ISO WKB, which adds 1000, 2000 or 3000 to the geometry type of geometries with Z and/or M
+    /// values.
+    #[default]
+    Iso,
+
+    /// Extended WKB, as written by PostGIS, which stores the dimension of geometries as flags,
+    /// with the given SRID embedded in the top-level geometries.
+    Extended(EwkbSrid),
+}
+
+/// Options for [`to_wkb_with_options`].
+///
+/// The default options write the same WKB as [`to_wkb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WkbWriteOptions {
+    /// The byte order of every geometry.
+    pub byte_order: WkbByteOrder,
+
+    /// ISO WKB or EWKB.
+    pub flavor: WkbFlavor,
+
+    /// Whether empty points are written as points with NaN coordinates, which is the convention of
+    /// recent versions of GEOS and PostGIS.
+    ///
+    /// If `false`, they are written as empty multi points, since WKB has no other way to encode an
+    /// empty point. This is what some older consumers expect. Defaults to `true`.
+    pub empty_points_as_nan: bool,
+}
+
+impl Default for WkbWriteOptions {
+    fn default() -> Self {
+        Self {
+            byte_order: Default::default(),
+            flavor: Default::default(),
+            empty_points_as_nan: true,
+        }
+    }
+}
+
+/// Encode a geometry array as WKB with the given byte order and variant.
+///
+/// The output keeps the metadata of the input. With [`WkbFlavor::Extended`], it is an error if the
+/// SRID is taken from a CRS without a known SRID, see [`EwkbSrid::FromMetadata`].
+pub fn to_wkb_with_options<O: OffsetSizeTrait>(
+    arr: &dyn NativeArray,
+    options: &WkbWriteOptions,
+) -> Result<WKBArray<O>> {
+    let wkb = to_wkb::<O>(arr);
+    if *options == WkbWriteOptions::default() {
+        return Ok(wkb);
+    }
+
+    let metadata = arr.metadata();
+    let srid = match options.flavor {
+        WkbFlavor::Iso => None,
+        WkbFlavor::Extended(srid) => srid.resolve(&metadata)?,
+    };
+    let srid_len = if srid.is_some() { 4 } else { 0 };
+    let mut builder =
+        GenericBinaryBuilder::<O>::with_capacity(wkb.len(), wkb.num_bytes() + wkb.len() * srid_len);
+    let mut buf = vec![];
+    for geom in wkb.iter() {
+        match geom {
+            Some(geom) => {
+                buf.clear();
+                let mut writer = Writer {
+                    buf: geom.as_ref(),
+                    offset: 0,
+                    options,
+                    out: &mut buf,
+                };
+                writer.geometry(srid, true)?;
+                builder.append_value(&buf);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(WKBArray::new(builder.finish(), metadata))
+}
+
+/// Rewrites one ISO WKB or EWKB geometry, of any byte order, according to the options.
+struct Writer<'a> {
+    buf: &'a [u8],
+    offset: usize,
+    options: &'a WkbWriteOptions,
+    out: &'a mut Vec<u8>,
+}
+
+impl Writer<'_> {
+    fn write_u32(&mut self, value: u32) {
+        match self.options.byte_order {
+            WkbByteOrder::LittleEndian => self.out.extend_from_slice(&value.to_le_bytes()),
+            WkbByteOrder::BigEndian => self.out.extend_from_slice(&value.to_be_bytes()),
+        }
+    }
+
+    fn write_f64(&mut self, value: f64) {
+        match self.options.byte_order {
+            WkbByteOrder::LittleEndian => self.out.extend_from_slice(&value.to_le_bytes()),
+            WkbByteOrder::BigEndian => self.out.extend_from_slice(&value.to_be_bytes()),
+        }
+    }
+
+    fn read_f64(&self, offset: usize, little_endian: bool) -> Result<f64> {
+        let bytes: [u8; 8] = self
+            .buf
+            .get(offset..offset + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| GeoArrowError::General("Unexpected end of WKB buffer".to_string()))?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Copy a count, returning it.
+    fn copy_u32(&mut self, little_endian: bool) -> Result<u32> {
+        let value = read_u32(self.buf, self.offset, little_endian)?;
+        self.offset += 4;
+        self.write_u32(value);
+        Ok(value)
+    }
+
+    fn copy_f64s(&mut self, num_values: usize, little_endian: bool) -> Result<()> {
+        for _ in 0..num_values {
+            let value = self.read_f64(self.offset, little_endian)?;
+            self.offset += 8;
+            self.write_f64(value);
+        }
+        Ok(())
+    }
+
+    fn write_header(&mut self, base_type: u32, has_z: bool, has_m: bool, srid: Option<i32>) {
+        self.out.push(match self.options.byte_order {
+            WkbByteOrder::LittleEndian => 1,
+            WkbByteOrder::BigEndian => 0,
+        });
+        let geometry_type = match self.options.flavor {
+            WkbFlavor::Iso => base_type + 1000 * has_z as u32 + 2000 * has_m as u32,
+            WkbFlavor::Extended(_) => {
+                let mut geometry_type = base_type;
+                if has_z {
+                    geometry_type |= EWKB_Z_FLAG;
+                }
+                if has_m {
+                    geometry_type |= EWKB_M_FLAG;
+                }
+                if srid.is_some() {
+                    geometry_type |= EWKB_SRID_FLAG;
+                }
+                geometry_type
+            }
+        };
+        self.write_u32(geometry_type);
+        if let Some(srid) = srid {
+            self.write_u32(srid as u32);
+        }
+    }
+
+    /// Write the geometry at the current offset, with an SRID if given.
+    ///
+    /// Empty points are only rewritten at the top level, as a multi point or collection can't
+    /// contain a multi point in place of a point.
+    fn geometry(&mut self, srid: Option<i32>, top_level: bool) -> Result<()> {
+        let little_endian = match self.buf.get(self.offset) {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return Err(GeoArrowError::General("Invalid WKB byte order".to_string())),
+        };
+        let geometry_type = read_u32(self.buf, self.offset + 1, little_endian)?;
+        let ewkb_flags = EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG;
+        let (base_type, has_z, has_m) = if geometry_type & ewkb_flags != 0 {
+            (
+                geometry_type & !ewkb_flags,
+                geometry_type & EWKB_Z_FLAG != 0,
+                geometry_type & EWKB_M_FLAG != 0,
+            )
+        } else {
+            let dim = geometry_type / 1000;
+            (
+                geometry_type % 1000,
+                dim == 1 || dim == 3,
+                dim == 2 || dim == 3,
+            )
+        };
+        self.offset += if geometry_type & EWKB_SRID_FLAG != 0 {
+            9
+        } else {
+            5
+        };
+        let num_dims = 2 + has_z as usize + has_m as usize;
+
+        if top_level && base_type == POINT && !self.options.empty_points_as_nan {
+            let is_empty = (0..num_dims)
+                .map(|i| self.read_f64(self.offset + 8 * i, little_endian))
+                .collect::<Result<Vec<_>>>()?
+                .iter()
+                .all(|value| value.is_nan());
+            if is_empty {
+                self.offset += 8 * num_dims;
+                self.write_header(MULTI_POINT, has_z, has_m, srid);
+                self.write_u32(0);
+                return Ok(());
+            }
+        }
+
+        self.write_header(base_type, has_z, has_m, srid);
+        match base_type {
+            // Point
+            1 => self.copy_f64s(num_dims, little_endian),
+            // LineString
+            2 => {
+                let num_coords = self.copy_u32(little_endian)? as usize;
+                self.copy_f64s(num_coords * num_dims, little_endian)
+            }
+            // Polygon
+            3 => {
+                let num_rings = self.copy_u32(little_endian)?;
+                for _ in 0..num_rings {
+                    let num_coords = self.copy_u32(little_endian)? as usize;
+                    self.copy_f64s(num_coords * num_dims, little_endian)?;
+                }
+                Ok(())
+            }
+            // MultiPoint, MultiLineString, MultiPolygon and GeometryCollection
+            4..=7 => {
+                let num_geometries = self.copy_u32(little_endian)?;
+                for _ in 0..num_geometries {
+                    self.geometry(None, false)?;
+                }
+                Ok(())
+            }
+            _ => Err(GeoArrowError::General(format!(
+                "Unsupported WKB geometry type {geometry_type}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MultiPointArray, PointArray};
+    use crate::datatypes::{Dimension, NativeType};
+    use crate::io::wkb::from_wkb;
+    use crate::test::multipolygon;
+
+    fn hex(value: &[u8]) -> String {
+        value.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn byte_order_and_flavor() {
+        let array: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+
+        let options = WkbWriteOptions {
+            byte_order: WkbByteOrder::BigEndian,
+            ..Default::default()
+        };
+        let wkb: WKBArray<i32> = to_wkb_with_options(&array, &options).unwrap();
+        assert_eq!(
+            hex(wkb.value(0).as_ref()),
+            "00000000013ff00000000000004000000000000000"
+        );
+
+        let options = WkbWriteOptions {
+            byte_order: WkbByteOrder::BigEndian,
+            flavor: WkbFlavor::Extended(EwkbSrid::Srid(4326)),
+            ..Default::default()
+        };
+        let wkb: WKBArray<i32> = to_wkb_with_options(&array, &options).unwrap();
+        assert_eq!(
+            hex(wkb.value(0).as_ref()),
+            "0020000001000010e63ff00000000000004000000000000000"
+        );
+    }
+
+    #[test]
+    fn empty_points() {
+        let array: PointArray = (
+            vec![geo::point!(x: f64::NAN, y: f64::NAN)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let wkb: WKBArray<i32> = to_wkb_with_options(&array, &Default::default()).unwrap();
+        assert_eq!(wkb.value(0).as_ref().len(), 21);
+
+        let options = WkbWriteOptions {
+            empty_points_as_nan: false,
+            ..Default::default()
+        };
+        let wkb: WKBArray<i32> = to_wkb_with_options(&array, &options).unwrap();
+        assert_eq!(hex(wkb.value(0).as_ref()), "010400000000000000");
+    }
+
+    #[test]
+    fn nested_empty_points() {
+        let multi_point = geo::MultiPoint::new(vec![
+            geo::point!(x: 1., y: 2.),
+            geo::point!(x: f64::NAN, y: f64::NAN),
+        ]);
+        let array: MultiPointArray = (vec![multi_point].as_slice(), Dimension::XY).into();
+        let options = WkbWriteOptions {
+            empty_points_as_nan: false,
+            ..Default::default()
+        };
+        let wkb: WKBArray<i32> = to_wkb_with_options(&array, &options).unwrap();
+        let expected: WKBArray<i32> = to_wkb(&array);
+        assert_eq!(wkb.value(0).as_ref(), expected.value(0).as_ref());
+    }
+
+    #[test]
+    fn big_endian_round_trip() {
+        let array = multipolygon::mp_array();
+        let options = WkbWriteOptions {
+            byte_order: WkbByteOrder::BigEndian,
+            ..Default::default()
+        };
+        let wkb: WKBArray<i32> = to_wkb_with_options(&array, &options).unwrap();
+        assert_eq!(wkb.value(0).as_ref()[0], 0);
+        let parsed = from_wkb(
+            &wkb,
+            NativeType::MultiPolygon(Default::default(), Dimension::XY),
+            false,
+        )
+        .unwrap();
+        let parsed = parsed
+            .as_any()
+            .downcast_ref::<crate::array::MultiPolygonArray>()
+            .unwrap();
+        assert_eq!(
+            parsed.iter_geo_values().collect::<Vec<_>>(),
+            array.iter_geo_values().collect::<Vec<_>>()
+        );
+    }
+}
@@ -55,8 +55,11 @@
 //! assert_eq!(wkt_array_again.into_inner().value(0), "POINT(30 10)")
 //! ```
 
+mod parser;
 mod reader;
+mod stream;
 mod writer;
 
-pub use reader::read_wkt;
-pub use writer::ToWKT;
+pub use reader::{read_wkt, read_wkt_with_policy};
+pub use stream::{WktArrayReader, WktReaderOptions};
+pub use writer::{ToWKT, WktWriterOptions};
@@ -0,0 +1,473 @@
+//! A WKT parser that transcodes directly to ISO WKB.
+//!
+//! The tokenizer borrows from the input string and parses numbers in place, so no allocations are
+//! made per token. The WKB output is appended to a caller-provided buffer, which can be reused
+//! across rows.
+
+use crate::error::{GeoArrowError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    ParenOpen,
+    ParenClose,
+    Comma,
+    Number(f64),
+    Word(&'a str),
+}
+
+/// A tokenizer over a WKT string that yields borrowed tokens.
+#[derive(Debug, Clone, Copy)]
+struct Tokenizer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token<'a>>> {
+        let bytes = self.input.as_bytes();
+        while self.pos < bytes.len() && bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+
+        let Some(&b) = bytes.get(self.pos) else {
+            return Ok(None);
+        };
+        let start = self.pos;
+        self.pos += 1;
+
+        let token = match b {
+            b'(' => Token::ParenOpen,
+            b')' => Token::ParenClose,
+            b',' => Token::Comma,
+            b'0'..=b'9' | b'-' | b'+' | b'.' => {
+                while self.pos < bytes.len()
+                    && matches!(
+                        bytes[self.pos],
+                        b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'
+                    )
+                {
+                    self.pos += 1;
+                }
+                let value = lexical_core::parse::<f64>(&bytes[start..self.pos])
+                    .map_err(|_| GeoArrowError::WktStrError("Invalid number"))?;
+                Token::Number(value)
+            }
+            b if b.is_ascii_alphabetic() => {
+                while self.pos < bytes.len() && bytes[self.pos].is_ascii_alphabetic() {
+                    self.pos += 1;
+                }
+                Token::Word(&self.input[start..self.pos])
+            }
+            _ => return Err(GeoArrowError::WktStrError("Unexpected character")),
+        };
+        Ok(Some(token))
+    }
+
+    fn peek_token(&self) -> Result<Option<Token<'a>>> {
+        let mut tokens = *self;
+        tokens.next_token()
+    }
+
+    fn expect(&mut self, expected: Token<'static>, message: &'static str) -> Result<()> {
+        match self.next_token()? {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(GeoArrowError::WktStrError(message)),
+        }
+    }
+
+    /// Consume an `EMPTY` keyword if it is the next token.
+    fn next_if_empty(&mut self) -> Result<bool> {
+        match self.peek_token()? {
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("EMPTY") => {
+                self.next_token()?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+/// The coordinate dimension of a WKT geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dim {
+    Xy,
+    Xyz,
+    Xym,
+    Xyzm,
+}
+
+impl Dim {
+    fn from_tag(tag: &str) -> Option<Self> {
+        if tag.eq_ignore_ascii_case("Z") {
+            Some(Dim::Xyz)
+        } else if tag.eq_ignore_ascii_case("M") {
+            Some(Dim::Xym)
+        } else if tag.eq_ignore_ascii_case("ZM") {
+            Some(Dim::Xyzm)
+        } else {
+            None
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Dim::Xy => 2,
+            Dim::Xyz | Dim::Xym => 3,
+            Dim::Xyzm => 4,
+        }
+    }
+
+    fn wkb_offset(&self) -> u32 {
+        match self {
+            Dim::Xy => 0,
+            Dim::Xyz => 1000,
+            Dim::Xym => 2000,
+            Dim::Xyzm => 3000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GeometryKind {
+    Point = 1,
+    LineString = 2,
+    Polygon = 3,
+    MultiPoint = 4,
+    MultiLineString = 5,
+    MultiPolygon = 6,
+    GeometryCollection = 7,
+}
+
+/// Parse a geometry keyword, which may have a dimension suffix attached, such as `POINTZ`.
+fn parse_keyword(word: &str) -> Result<(GeometryKind, Option<Dim>)> {
+    const KEYWORDS: [(&str, GeometryKind); 7] = [
+        ("GEOMETRYCOLLECTION", GeometryKind::GeometryCollection),
+        ("MULTILINESTRING", GeometryKind::MultiLineString),
+        ("MULTIPOLYGON", GeometryKind::MultiPolygon),
+        ("MULTIPOINT", GeometryKind::MultiPoint),
+        ("LINESTRING", GeometryKind::LineString),
+        ("POLYGON", GeometryKind::Polygon),
+        ("POINT", GeometryKind::Point),
+    ];
+
+    for (keyword, kind) in KEYWORDS {
+        if word.len() >= keyword.len() && word[..keyword.len()].eq_ignore_ascii_case(keyword) {
+            let suffix = &word[keyword.len()..];
+            if suffix.is_empty() {
+                return Ok((kind, None));
+            }
+            if let Some(dim) = Dim::from_tag(suffix) {
+                return Ok((kind, Some(dim)));
+            }
+        }
+    }
+    Err(GeoArrowError::WktStrError("Invalid geometry type"))
+}
+
+/// Reserve space for a count, returning its position in the output.
+fn begin_count(out: &mut Vec<u8>) -> usize {
+    let pos = out.len();
+    out.extend_from_slice(&0u32.to_le_bytes());
+    pos
+}
+
+fn end_count(out: &mut [u8], pos: usize, count: u32) {
+    out[pos..pos + 4].copy_from_slice(&count.to_le_bytes());
+}
+
+/// Transcodes WKT strings to ISO WKB.
+///
+/// The parser keeps some scratch state between calls, so reuse a single parser when transcoding
+/// many strings.
+#[derive(Debug, Default)]
+pub(crate) struct WktParser {
+    /// Positions of WKB type codes that still need the dimension of their geometry added.
+    headers: Vec<usize>,
+}
+
+impl WktParser {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a single WKT string, appending its WKB encoding to `out`.
+    ///
+    /// If parsing fails, `out` may contain a partially-written geometry.
+    pub(crate) fn write_wkb(&mut self, wkt: &str, out: &mut Vec<u8>) -> Result<()> {
+        self.headers.clear();
+        let mut tokens = Tokenizer::new(wkt);
+        self.geometry(&mut tokens, out)?;
+        if tokens.next_token()?.is_some() {
+            return Err(GeoArrowError::WktStrError(
+                "Unexpected input after geometry",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Write a geometry, returning its dimension.
+    fn geometry(&mut self, tokens: &mut Tokenizer, out: &mut Vec<u8>) -> Result<Dim> {
+        let word = match tokens.next_token()? {
+            Some(Token::Word(word)) => word,
+            _ => return Err(GeoArrowError::WktStrError("Expected a geometry type")),
+        };
+        let (kind, mut dim) = parse_keyword(word)?;
+        if dim.is_none() {
+            if let Some(Token::Word(tag)) = tokens.peek_token()? {
+                dim = Dim::from_tag(tag);
+                if dim.is_some() {
+                    tokens.next_token()?;
+                }
+            }
+        }
+
+        let scope = self.headers.len();
+        self.header(kind, out);
+        match kind {
+            GeometryKind::Point => self.point_body(tokens, &mut dim, out)?,
+            GeometryKind::LineString => self.line_string_body(tokens, &mut dim, out)?,
+            GeometryKind::Polygon => self.polygon_body(tokens, &mut dim, out)?,
+            GeometryKind::MultiPoint => {
+                self.list(tokens, out, |parser, tokens, out| {
+                    parser.header(GeometryKind::Point, out);
+                    match tokens.peek_token()? {
+                        // Points within a multi point may or may not be wrapped in parentheses
+                        Some(Token::Number(_)) => parser.coord(tokens, &mut dim, out),
+                        _ => parser.point_body(tokens, &mut dim, out),
+                    }
+                })?;
+            }
+            GeometryKind::MultiLineString => {
+                self.list(tokens, out, |parser, tokens, out| {
+                    parser.header(GeometryKind::LineString, out);
+                    parser.line_string_body(tokens, &mut dim, out)
+                })?;
+            }
+            GeometryKind::MultiPolygon => {
+                self.list(tokens, out, |parser, tokens, out| {
+                    parser.header(GeometryKind::Polygon, out);
+                    parser.polygon_body(tokens, &mut dim, out)
+                })?;
+            }
+            GeometryKind::GeometryCollection => {
+                // Each child has its own dimension. Without a tag, the collection takes the
+                // dimension of its first child.
+                self.list(tokens, out, |parser, tokens, out| {
+                    let child_dim = parser.geometry(tokens, out)?;
+                    if dim.is_none() {
+                        dim = Some(child_dim);
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        let dim = dim.unwrap_or(Dim::Xy);
+        for pos in self.headers.drain(scope..) {
+            let code = u32::from_le_bytes(out[pos..pos + 4].try_into().unwrap());
+            out[pos..pos + 4].copy_from_slice(&(code + dim.wkb_offset()).to_le_bytes());
+        }
+        Ok(dim)
+    }
+
+    /// Write the byte order and the type code of a geometry, without its dimension.
+    fn header(&mut self, kind: GeometryKind, out: &mut Vec<u8>) {
+        out.push(1);
+        self.headers.push(out.len());
+        out.extend_from_slice(&(kind as u32).to_le_bytes());
+    }
+
+    /// Write a parenthesized, comma-separated list prefixed by its length, or an empty list for
+    /// `EMPTY`.
+    fn list<F>(&mut self, tokens: &mut Tokenizer, out: &mut Vec<u8>, mut item: F) -> Result<()>
+    where
+        F: FnMut(&mut Self, &mut Tokenizer, &mut Vec<u8>) -> Result<()>,
+    {
+        let count_pos = begin_count(out);
+        if tokens.next_if_empty()? {
+            return Ok(());
+        }
+
+        tokens.expect(Token::ParenOpen, "Expected '('")?;
+        let mut count = 0;
+        loop {
+            item(self, tokens, out)?;
+            count += 1;
+            match tokens.next_token()? {
+                Some(Token::Comma) => continue,
+                Some(Token::ParenClose) => break,
+                _ => return Err(GeoArrowError::WktStrError("Expected ',' or ')'")),
+            }
+        }
+        end_count(out, count_pos, count);
+        Ok(())
+    }
+
+    fn point_body(
+        &mut self,
+        tokens: &mut Tokenizer,
+        dim: &mut Option<Dim>,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        if tokens.next_if_empty()? {
+            // Empty points are represented in WKB with NaN coordinates
+            let dim = *dim.get_or_insert(Dim::Xy);
+            (0..dim.size()).for_each(|_| out.extend_from_slice(&f64::NAN.to_le_bytes()));
+            return Ok(());
+        }
+
+        tokens.expect(Token::ParenOpen, "Expected '('")?;
+        self.coord(tokens, dim, out)?;
+        tokens.expect(Token::ParenClose, "Expected ')'")
+    }
+
+    fn line_string_body(
+        &mut self,
+        tokens: &mut Tokenizer,
+        dim: &mut Option<Dim>,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        self.list(tokens, out, |parser, tokens, out| {
+            parser.coord(tokens, dim, out)
+        })
+    }
+
+    fn polygon_body(
+        &mut self,
+        tokens: &mut Tokenizer,
+        dim: &mut Option<Dim>,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        self.list(tokens, out, |parser, tokens, out| {
+            parser.line_string_body(tokens, dim, out)
+        })
+    }
+
+    /// Write a single coordinate, inferring the dimension from its number of values if it isn't
+    /// known yet.
+    fn coord(
+        &mut self,
+        tokens: &mut Tokenizer,
+        dim: &mut Option<Dim>,
+        out: &mut Vec<u8>,
+    ) -> Result<()> {
+        let mut size = 0;
+        while let Some(Token::Number(value)) = tokens.peek_token()? {
+            tokens.next_token()?;
+            size += 1;
+            if size > 4 {
+                return Err(GeoArrowError::WktStrError("Too many values in coordinate"));
+            }
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let inferred = match size {
+            2 => Dim::Xy,
+            3 => Dim::Xyz,
+            4 => Dim::Xyzm,
+            _ => return Err(GeoArrowError::WktStrError("Invalid coordinate")),
+        };
+        match dim {
+            Some(dim) if dim.size() != size => Err(GeoArrowError::WktStrError(
+                "Coordinate does not match the geometry dimension",
+            )),
+            Some(_) => Ok(()),
+            None => {
+                *dim = Some(inferred);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo_traits::{Dimensions, GeometryTrait};
+
+    use super::*;
+    use crate::io::geo::geometry_to_geo;
+
+    fn parse(wkt: &str) -> Result<Vec<u8>> {
+        let mut out = vec![];
+        WktParser::new().write_wkb(wkt, &mut out)?;
+        Ok(out)
+    }
+
+    fn parse_to_geo(wkt: &str) -> geo::Geometry {
+        let buf = parse(wkt).unwrap();
+        geometry_to_geo(&wkb::reader::read_wkb(&buf).unwrap())
+    }
+
+    #[test]
+    fn parse_geometries() {
+        assert_eq!(
+            parse_to_geo("POINT (30 10)"),
+            geo::Geometry::Point(geo::point!(x: 30., y: 10.))
+        );
+        assert_eq!(
+            parse_to_geo("linestring(30 10,10 30, 40 40)"),
+            geo::Geometry::LineString(geo::line_string![
+                (x: 30., y: 10.),
+                (x: 10., y: 30.),
+                (x: 40., y: 40.),
+            ])
+        );
+        assert_eq!(
+            parse_to_geo("MULTIPOINT (10 40, 40 30)"),
+            parse_to_geo("MULTIPOINT ((10 40), (40 30))")
+        );
+
+        let expected: geo::MultiPolygon = geo::MultiPolygon::new(vec![
+            geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)],
+            geo::polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 5.)],
+        ]);
+        assert_eq!(
+            parse_to_geo("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))"),
+            geo::Geometry::MultiPolygon(expected)
+        );
+    }
+
+    #[test]
+    fn parse_dimensions() {
+        let buf = parse("POINT Z (1 2 3)").unwrap();
+        assert_eq!(buf, parse("POINT (1 2 3)").unwrap());
+        assert_eq!(buf, parse("POINTZ(1 2 3)").unwrap());
+        let geom = wkb::reader::read_wkb(&buf).unwrap();
+        assert_eq!(geom.dim(), Dimensions::Xyz);
+
+        let buf =
+            parse("GEOMETRYCOLLECTION (POINT Z (1 2 3), LINESTRING Z (1 2 3, 4 5 6))").unwrap();
+        let geom = wkb::reader::read_wkb(&buf).unwrap();
+        assert_eq!(geom.dim(), Dimensions::Xyz);
+
+        assert!(parse("POINT Z (1 2)").is_err());
+        assert!(parse("LINESTRING (1 2, 3 4 5)").is_err());
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(
+            parse_to_geo("LINESTRING EMPTY"),
+            geo::Geometry::LineString(geo::LineString::new(vec![]))
+        );
+        assert_eq!(
+            parse_to_geo("GEOMETRYCOLLECTION EMPTY"),
+            geo::Geometry::GeometryCollection(Default::default())
+        );
+        assert!(parse("POINT EMPTY").is_ok());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(parse("").is_err());
+        assert!(parse("POINT").is_err());
+        assert!(parse("POINT (1 2").is_err());
+        assert!(parse("POINT (1 2) POINT (3 4)").is_err());
+        assert!(parse("POLYGON ((1 2, x 4))").is_err());
+        assert!(parse("TRIANGLE ((0 0, 1 0, 1 1, 0 0))").is_err());
+    }
+}
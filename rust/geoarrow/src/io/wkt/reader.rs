@@ -1,43 +1,178 @@
-use std::str::FromStr;
+use std::ops::Range;
 use std::sync::Arc;
 
-use arrow_array::OffsetSizeTrait;
+use arrow_array::{Array, GenericBinaryArray, GenericStringArray, OffsetSizeTrait};
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer, OffsetBuffer};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
-use crate::array::metadata::ArrayMetadata;
-use crate::array::{CoordType, GeometryArray, GeometryBuilder, WKTArray};
-use crate::error::{GeoArrowError, Result};
+use crate::array::{CoordType, WKBArray, WKTArray};
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::io::wkb::from_wkb_with_policy;
+use crate::io::wkt::parser::WktParser;
+use crate::io::{ParseErrorPolicy, ParseOptions};
 use crate::{ArrayBase, NativeArray};
 
+/// The number of rows transcoded by each task when parsing in parallel.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_SIZE: usize = 8192;
+
 /// Parse a WKT array into a native GeoArrow array.
 ///
-/// Currently, a [GeometryArray] is always returned. This may change in the future with the
-/// addition of a `downcast` parameter, which would automatically downcast the result.
+/// The WKT strings are first transcoded to WKB with a tokenizer that doesn't allocate per token,
+/// and then parsed with the same two-pass WKB reader as [`FromWKB`][crate::io::wkb::FromWKB].
+/// With the `rayon` feature, rows are transcoded and parsed in parallel, see
+/// [`read_wkt_with_policy`].
+///
+/// Currently, a [GeometryArray][crate::array::GeometryArray] is always returned. This may change
+/// in the future with the addition of a `downcast` parameter, which would automatically downcast
+/// the result.
 pub fn read_wkt<O: OffsetSizeTrait>(
     arr: &WKTArray<O>,
     coord_type: CoordType,
     prefer_multi: bool,
 ) -> Result<Arc<dyn NativeArray>> {
-    let array_metadata = arr.metadata();
-    let parsed = from_str_iter(arr.array.iter(), coord_type, array_metadata, prefer_multi)?;
-    Ok(Arc::new(parsed))
+    let (array, _) = read_wkt_with_policy(arr, coord_type, prefer_multi, &Default::default())?;
+    Ok(array)
 }
 
-fn from_str_iter<'a>(
-    iter: impl Iterator<Item = Option<&'a str>>,
+/// Parse a WKT array into a native GeoArrow array, with a policy for values that aren't valid WKT
+/// and a choice of parallelism.
+///
+/// This is [`read_wkt`] for dirty data: with [`ParseErrorPolicy::NullOnError`] or
+/// [`ParseErrorPolicy::Collect`], values that can't be parsed are nulled out instead of failing
+/// the whole array. The indices of their rows are returned with the array when using
+/// [`ParseErrorPolicy::Collect`], and the returned list is empty otherwise.
+///
+/// If [`ParseOptions::parallel`] is `true` and the `rayon` feature is enabled, rows are transcoded
+/// in parallel, and the transcoded WKB is parsed in parallel as in [`from_wkb_with_policy`].
+/// Otherwise, and without the `rayon` feature, the array is parsed on the current thread.
+pub fn read_wkt_with_policy<O: OffsetSizeTrait>(
+    arr: &WKTArray<O>,
     coord_type: CoordType,
-    metadata: Arc<ArrayMetadata>,
     prefer_multi: bool,
-) -> Result<GeometryArray> {
-    let mut builder = GeometryBuilder::new_with_options(coord_type, metadata, prefer_multi);
-    for wkt_str in iter {
-        if let Some(s) = wkt_str {
-            let wkt = wkt::Wkt::<f64>::from_str(s).map_err(GeoArrowError::WktStrError)?;
-            builder.push_geometry(Some(&wkt))?;
-        } else {
-            builder.push_null();
+    options: &ParseOptions,
+) -> Result<(Arc<dyn NativeArray>, Vec<usize>)> {
+    let (wkb_array, invalid_rows) = wkt_to_wkb(&arr.array, options.policy, options.parallel)?;
+    let wkb_array = WKBArray::new(wkb_array, arr.metadata());
+    // Rows that couldn't be transcoded are already null, so the WKB is valid
+    let (array, _) = from_wkb_with_policy(
+        &wkb_array,
+        NativeType::Geometry(coord_type),
+        prefer_multi,
+        &ParseOptions {
+            policy: ParseErrorPolicy::Strict,
+            parallel: options.parallel,
+        },
+    )?;
+    Ok((array, invalid_rows))
+}
+
+/// WKT strings of a range of rows transcoded to WKB.
+struct TranscodedRange {
+    /// The WKB bytes of all rows.
+    values: Vec<u8>,
+
+    /// The end offset of each row in `values`. Null and invalid rows are empty.
+    ends: Vec<usize>,
+
+    /// The rows that couldn't be parsed.
+    invalid_rows: Vec<usize>,
+}
+
+/// Transcode a range of WKT strings to WKB.
+fn transcode_range<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+    range: Range<usize>,
+    policy: ParseErrorPolicy,
+) -> Result<TranscodedRange> {
+    let mut parser = WktParser::new();
+    let mut values = Vec::new();
+    let mut ends = Vec::with_capacity(range.len());
+    let mut invalid_rows = vec![];
+    for i in range {
+        if arr.is_valid(i) {
+            let start = values.len();
+            if let Err(err) = parser.write_wkb(arr.value(i), &mut values) {
+                if policy == ParseErrorPolicy::Strict {
+                    return Err(err);
+                }
+                values.truncate(start);
+                invalid_rows.push(i);
+            }
         }
+        ends.push(values.len());
     }
-    Ok(builder.finish())
+    Ok(TranscodedRange {
+        values,
+        ends,
+        invalid_rows,
+    })
+}
+
+/// Transcode an array of WKT strings to a WKB array with the same validity, except for the rows
+/// that couldn't be parsed under `policy`.
+fn wkt_to_wkb<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+    policy: ParseErrorPolicy,
+    parallel: bool,
+) -> Result<(GenericBinaryArray<i64>, Vec<usize>)> {
+    #[cfg(feature = "rayon")]
+    let chunks = if parallel {
+        (0..arr.len())
+            .step_by(PARALLEL_CHUNK_SIZE)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|start| {
+                let end = (start + PARALLEL_CHUNK_SIZE).min(arr.len());
+                transcode_range(arr, start..end, policy)
+            })
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        vec![transcode_range(arr, 0..arr.len(), policy)?]
+    };
+
+    #[cfg(not(feature = "rayon"))]
+    let chunks = {
+        let _ = parallel;
+        vec![transcode_range(arr, 0..arr.len(), policy)?]
+    };
+
+    let mut values = Vec::with_capacity(chunks.iter().map(|chunk| chunk.values.len()).sum());
+    let mut offsets = Vec::with_capacity(arr.len() + 1);
+    offsets.push(0i64);
+    let mut invalid_rows = vec![];
+    for chunk in chunks {
+        let base = values.len();
+        offsets.extend(chunk.ends.into_iter().map(|end| (base + end) as i64));
+        values.extend_from_slice(&chunk.values);
+        invalid_rows.extend(chunk.invalid_rows);
+    }
+
+    let nulls = if invalid_rows.is_empty() {
+        arr.nulls().cloned()
+    } else {
+        let mut validity = BooleanBufferBuilder::new(arr.len());
+        validity.append_n(arr.len(), true);
+        if let Some(nulls) = arr.nulls() {
+            (0..arr.len())
+                .filter(|&i| nulls.is_null(i))
+                .for_each(|i| validity.set_bit(i, false));
+        }
+        invalid_rows
+            .iter()
+            .for_each(|&i| validity.set_bit(i, false));
+        Some(NullBuffer::new(validity.finish()))
+    };
+
+    if policy != ParseErrorPolicy::Collect {
+        invalid_rows.clear();
+    }
+    Ok((
+        GenericBinaryArray::new(OffsetBuffer::new(offsets.into()), values.into(), nulls),
+        invalid_rows,
+    ))
 }
 
 #[cfg(test)]
@@ -72,6 +207,66 @@ mod test {
         // assert_eq!(geo_point.y(), 10.0);
     }
 
+    #[test]
+    fn test_read_wkt_nulls_and_errors() {
+        let mut builder = StringBuilder::new();
+        builder.append_value("POINT Z (1 2 3)");
+        builder.append_null();
+        builder.append_value("LINESTRING (1 2, 3 4)");
+        let arr = WKTArray::new(builder.finish(), Default::default());
+
+        let parsed = read_wkt(&arr, Default::default(), false).unwrap();
+        let parsed_ref = parsed.as_ref();
+        let geom_arr = parsed_ref.as_geometry();
+        assert!(geom_arr.get(0).is_some());
+        assert!(geom_arr.get(1).is_none());
+        assert_eq!(
+            geom_arr.value_as_geo(2),
+            geo::Geometry::LineString(geo::line_string![(x: 1., y: 2.), (x: 3., y: 4.)])
+        );
+
+        let mut builder = StringBuilder::new();
+        builder.append_value("POINT (1 2");
+        let arr = WKTArray::new(builder.finish(), Default::default());
+        assert!(read_wkt(&arr, Default::default(), false).is_err());
+    }
+
+    #[test]
+    fn test_read_wkt_with_policy() {
+        let mut builder = StringBuilder::new();
+        builder.append_value("POINT (1 2");
+        builder.append_null();
+        builder.append_value("LINESTRING (1 2, 3 4)");
+        builder.append_value("POLYGON ((0 0, 1 0))) extra");
+        let arr = WKTArray::new(builder.finish(), Default::default());
+
+        let options = ParseOptions {
+            policy: ParseErrorPolicy::Collect,
+            parallel: true,
+        };
+        let (parsed, invalid_rows) =
+            read_wkt_with_policy(&arr, Default::default(), false, &options).unwrap();
+        assert_eq!(invalid_rows, vec![0, 3]);
+        let parsed_ref = parsed.as_ref();
+        let geom_arr = parsed_ref.as_geometry();
+        assert_eq!(geom_arr.len(), 4);
+        assert!(geom_arr.get(0).is_none());
+        assert!(geom_arr.get(1).is_none());
+        assert_eq!(
+            geom_arr.value_as_geo(2),
+            geo::Geometry::LineString(geo::line_string![(x: 1., y: 2.), (x: 3., y: 4.)])
+        );
+        assert!(geom_arr.get(3).is_none());
+
+        let options = ParseOptions {
+            policy: ParseErrorPolicy::NullOnError,
+            parallel: false,
+        };
+        let (_, invalid_rows) =
+            read_wkt_with_policy(&arr, Default::default(), false, &options).unwrap();
+        assert!(invalid_rows.is_empty());
+    }
+
     // #[test]
     // fn test_read_wkt_downcast_from_multi() {
     //     let wkt_geoms = ["POINT (30 10)", "POINT (20 5)", "POINT (3 10)"];
@@ -0,0 +1,264 @@
+//! Parse a stream of WKT chunks into native arrays, one chunk at a time.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{GenericStringArray, OffsetSizeTrait, RecordBatchReader};
+
+use crate::algorithm::native::{Cast, Downcast};
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, WKTArray};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::stream::NativeArrayReader;
+use crate::io::wkt::read_wkt;
+use crate::NativeArray;
+
+/// Options for [`WktArrayReader`].
+#[derive(Debug, Clone, Default)]
+pub struct WktReaderOptions {
+    /// The coordinate layout of the parsed arrays.
+    pub coord_type: CoordType,
+
+    /// Infer multi geometry types rather than single ones, e.g. `MultiPoint` when the first chunk
+    /// only has points, so that later chunks may also contain multi geometries.
+    pub prefer_multi: bool,
+
+    /// The type of every parsed array. If `None`, the type is inferred from the first chunk.
+    pub target_type: Option<NativeType>,
+
+    /// The metadata, such as the CRS, of the parsed arrays.
+    pub metadata: Arc<ArrayMetadata>,
+}
+
+/// A [`NativeArrayReader`] that parses a stream of WKT string chunks one chunk at a time.
+///
+/// Only the chunk being parsed is held in memory, so a WKT column of any size can be converted,
+/// e.g. to GeoParquet, while it is read.
+///
+/// Every chunk is parsed to [`WktReaderOptions::target_type`], or, if it isn't set, to the
+/// simplest type of the first chunk. In that case, a later chunk with geometries that don't fit
+/// that type, such as a line string after a chunk of points, is an error yielded by the reader.
+/// Set a target type, or [`WktReaderOptions::prefer_multi`], for streams with mixed types.
+pub struct WktArrayReader<O: OffsetSizeTrait, I> {
+    chunks: I,
+    /// The first chunk, parsed during type inference.
+    first: Option<Arc<dyn NativeArray>>,
+    data_type: NativeType,
+    options: WktReaderOptions,
+    phantom: PhantomData<O>,
+}
+
+impl<O, I> WktArrayReader<O, I>
+where
+    O: OffsetSizeTrait,
+    I: Iterator<Item = Result<GenericStringArray<O>>>,
+{
+    /// Create a new reader over chunks of WKT strings.
+    ///
+    /// Without a target type, the first chunk is parsed immediately to infer the type of the
+    /// stream.
+    pub fn try_new(
+        chunks: impl IntoIterator<IntoIter = I>,
+        options: WktReaderOptions,
+    ) -> Result<Self> {
+        let mut chunks = chunks.into_iter();
+        let (data_type, first) = match options.target_type {
+            Some(target_type) => (target_type, None),
+            None => match chunks.next() {
+                Some(chunk) => {
+                    let array = parse_chunk(chunk?, &options)?;
+                    let data_type = inferred_type(array.as_ref(), options.prefer_multi);
+                    (data_type, Some(cast_to(array, data_type)?))
+                }
+                None => (NativeType::Geometry(options.coord_type), None),
+            },
+        };
+        Ok(Self {
+            chunks,
+            first,
+            data_type,
+            options,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<O> WktArrayReader<O, Box<dyn Iterator<Item = Result<GenericStringArray<O>>> + Send>>
+where
+    O: OffsetSizeTrait,
+{
+    /// Create a new reader over a WKT column of a stream of record batches.
+    ///
+    /// The column at `index` must be a string column with offsets of type `O`, i.e. `Utf8` for
+    /// `i32` and `LargeUtf8` for `i64`.
+    pub fn try_from_record_batch_reader(
+        reader: impl RecordBatchReader + Send + 'static,
+        index: usize,
+        options: WktReaderOptions,
+    ) -> Result<Self> {
+        let field = reader.schema().field(index).clone();
+        let chunks = reader.map(move |batch| {
+            let batch = batch?;
+            batch
+                .column(index)
+                .as_string_opt::<O>()
+                .cloned()
+                .ok_or_else(|| {
+                    GeoArrowError::IncorrectType(
+                        format!(
+                            "Column {} has type {}, expected a string column",
+                            field.name(),
+                            field.data_type()
+                        )
+                        .into(),
+                    )
+                })
+        });
+        Self::try_new(
+            Box::new(chunks) as Box<dyn Iterator<Item = _> + Send>,
+            options,
+        )
+    }
+}
+
+fn parse_chunk<O: OffsetSizeTrait>(
+    chunk: GenericStringArray<O>,
+    options: &WktReaderOptions,
+) -> Result<Arc<dyn NativeArray>> {
+    let wkt = WKTArray::new(chunk, options.metadata.clone());
+    read_wkt(&wkt, options.coord_type, options.prefer_multi)
+}
+
+/// The simplest type of an array, or its multi counterpart if `prefer_multi`.
+fn inferred_type(array: &dyn NativeArray, prefer_multi: bool) -> NativeType {
+    let data_type = array.downcasted_data_type();
+    if !prefer_multi {
+        return data_type;
+    }
+    match data_type {
+        NativeType::Point(coord_type, dim) => NativeType::MultiPoint(coord_type, dim),
+        NativeType::LineString(coord_type, dim) => NativeType::MultiLineString(coord_type, dim),
+        NativeType::Polygon(coord_type, dim) => NativeType::MultiPolygon(coord_type, dim),
+        data_type => data_type,
+    }
+}
+
+/// Parsed WKT arrays are geometry arrays, so cast them unless that's the target.
+fn cast_to(array: Arc<dyn NativeArray>, data_type: NativeType) -> Result<Arc<dyn NativeArray>> {
+    if array.data_type() == data_type {
+        Ok(array)
+    } else {
+        array.as_ref().cast(data_type)
+    }
+}
+
+impl<O, I> Iterator for WktArrayReader<O, I>
+where
+    O: OffsetSizeTrait,
+    I: Iterator<Item = Result<GenericStringArray<O>>>,
+{
+    type Item = Result<Arc<dyn NativeArray>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(first) = self.first.take() {
+            return Some(Ok(first));
+        }
+        let chunk = self.chunks.next()?;
+        Some(chunk.and_then(|chunk| cast_to(parse_chunk(chunk, &self.options)?, self.data_type)))
+    }
+}
+
+impl<O, I> NativeArrayReader for WktArrayReader<O, I>
+where
+    O: OffsetSizeTrait,
+    I: Iterator<Item = Result<GenericStringArray<O>>>,
+{
+    fn data_type(&self) -> NativeType {
+        self.data_type
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{RecordBatch, RecordBatchIterator, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::ArrayBase;
+
+    fn chunks(chunks: &[&[&str]]) -> Vec<Result<StringArray>> {
+        chunks
+            .iter()
+            .map(|chunk| Ok(StringArray::from_iter_values(chunk.iter())))
+            .collect()
+    }
+
+    #[test]
+    fn infer_from_first_chunk() {
+        let input = chunks(&[&["POINT (1 2)", "POINT (3 4)"], &["POINT (5 6)"]]);
+        let reader = WktArrayReader::try_new(input, Default::default()).unwrap();
+        let point_type = NativeType::Point(CoordType::Interleaved, Dimension::XY);
+        assert_eq!(reader.data_type(), point_type);
+        let arrays = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(arrays.len(), 2);
+        assert!(arrays.iter().all(|array| array.data_type() == point_type));
+        assert_eq!(arrays[1].len(), 1);
+
+        let input = chunks(&[&["POINT (1 2)"], &["LINESTRING (0 0, 1 1)"]]);
+        let mut reader = WktArrayReader::try_new(input, Default::default()).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn prefer_multi_and_hint() {
+        let input = chunks(&[&["POINT (1 2)"], &["MULTIPOINT ((1 2), (3 4))"]]);
+        let options = WktReaderOptions {
+            prefer_multi: true,
+            ..Default::default()
+        };
+        let reader = WktArrayReader::try_new(input, options).unwrap();
+        assert!(matches!(reader.data_type(), NativeType::MultiPoint(_, _)));
+        assert_eq!(reader.collect::<Result<Vec<_>>>().unwrap().len(), 2);
+
+        let input = chunks(&[&["POINT (1 2)"], &["LINESTRING (0 0, 1 1)"]]);
+        let target_type = NativeType::Geometry(CoordType::Separated);
+        let options = WktReaderOptions {
+            target_type: Some(target_type),
+            ..Default::default()
+        };
+        let reader = WktArrayReader::try_new(input, options).unwrap();
+        for array in reader {
+            assert_eq!(array.unwrap().data_type(), target_type);
+        }
+    }
+
+    #[test]
+    fn record_batch_reader() {
+        let schema = Arc::new(Schema::new(vec![Field::new("wkt", DataType::Utf8, true)]));
+        let batches = [
+            ["POINT (1 2)", "POINT (3 4)"],
+            ["POINT (5 6)", "POINT (7 8)"],
+        ]
+        .into_iter()
+        .map(|values| {
+            RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(StringArray::from_iter_values(values))],
+            )
+        })
+        .collect::<Vec<_>>();
+        let batch_reader = RecordBatchIterator::new(batches, schema);
+        let reader = WktArrayReader::<i32, _>::try_from_record_batch_reader(
+            batch_reader,
+            0,
+            Default::default(),
+        )
+        .unwrap();
+        let lengths = reader.map(|array| array.unwrap().len()).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![2, 2]);
+    }
+}
@@ -1,10 +1,24 @@
 use arrow::array::GenericStringBuilder;
 use arrow_array::OffsetSizeTrait;
 
+use std::fmt::Write;
+
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+use crate::algorithm::native::kernel::iter_geometries;
 use crate::array::{AsChunkedNativeArray, AsNativeArray, WKTArray};
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
 use crate::datatypes::NativeType;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
+use crate::io::canonical::write_f64;
+use crate::scalar::{
+    Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon, Rect,
+};
 use crate::trait_::ArrayAccessor;
 use crate::NativeArray;
 use wkt::to_wkt::{
@@ -12,6 +26,16 @@ use wkt::to_wkt::{
     write_multi_point, write_multi_polygon, write_point, write_polygon, write_rect,
 };
 
+/// Options for [`ToWKT`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WktWriterOptions {
+    /// Write canonical output, so that the same geometries are always written to the same bytes.
+    ///
+    /// Integral coordinates are written without a fraction and other coordinates in their
+    /// shortest round-trip form, as formatted by [`ryu`], and negative zero is written as `0`.
+    pub canonical: bool,
+}
+
 /// Serialize a geometry array to Well-Known Text
 pub trait ToWKT {
     /// The output type of the operation. You can specify whether you want to use i32 or i64
@@ -19,16 +43,64 @@ pub trait ToWKT {
     type Output<O: OffsetSizeTrait>;
 
     /// Convert to WKT.
-    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O>;
+    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+        self.to_wkt_with_options(Default::default())
+    }
+
+    /// Convert to WKT with specific writer options.
+    fn to_wkt_with_options<O: OffsetSizeTrait>(&self, options: WktWriterOptions)
+        -> Self::Output<O>;
 }
 
+macro_rules! impl_scalar_to_wkt {
+    ($scalar_type:ty, $write_wkt_func:expr) => {
+        impl $scalar_type {
+            /// Serialize this geometry to a Well-Known Text string.
+            ///
+            /// Unlike the [`Display`][std::fmt::Display] implementation, the output is neither
+            /// truncated nor rounded.
+            pub fn to_wkt(&self) -> Result<String> {
+                let mut wkt = String::new();
+                $write_wkt_func(&mut wkt, self)?;
+                Ok(wkt)
+            }
+        }
+    };
+}
+
+impl_scalar_to_wkt!(Point<'_>, write_point);
+impl_scalar_to_wkt!(LineString<'_>, write_linestring);
+impl_scalar_to_wkt!(Polygon<'_>, write_polygon);
+impl_scalar_to_wkt!(MultiPoint<'_>, write_multi_point);
+impl_scalar_to_wkt!(MultiLineString<'_>, write_multi_linestring);
+impl_scalar_to_wkt!(MultiPolygon<'_>, write_multi_polygon);
+impl_scalar_to_wkt!(GeometryCollection<'_>, write_geometry_collection);
+impl_scalar_to_wkt!(Rect<'_>, write_rect);
+impl_scalar_to_wkt!(Geometry<'_>, write_geometry);
+
 impl ToWKT for &dyn NativeArray {
     type Output<O: OffsetSizeTrait> = Result<WKTArray<O>>;
 
-    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+    fn to_wkt_with_options<O: OffsetSizeTrait>(
+        &self,
+        options: WktWriterOptions,
+    ) -> Self::Output<O> {
         let metadata = self.metadata();
         let mut output_array = GenericStringBuilder::<O>::new();
 
+        if options.canonical {
+            for maybe_geom in iter_geometries(*self) {
+                if let Some(geom) = maybe_geom {
+                    write_canonical_geometry(&mut output_array, &geom)
+                        .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                    output_array.append_value("");
+                } else {
+                    output_array.append_null();
+                }
+            }
+            return Ok(WKTArray::new(output_array.finish(), metadata));
+        }
+
         use NativeType::*;
 
         macro_rules! impl_to_wkt {
@@ -67,12 +139,17 @@ impl ToWKT for &dyn NativeArray {
 impl ToWKT for &dyn ChunkedNativeArray {
     type Output<O: OffsetSizeTrait> = Result<ChunkedGeometryArray<WKTArray<O>>>;
 
-    fn to_wkt<O: OffsetSizeTrait>(&self) -> Self::Output<O> {
+    fn to_wkt_with_options<O: OffsetSizeTrait>(
+        &self,
+        options: WktWriterOptions,
+    ) -> Self::Output<O> {
         use NativeType::*;
 
         macro_rules! impl_to_wkt {
             ($cast_func:ident) => {{
-                let chunks = self.$cast_func().try_map(|chunk| chunk.as_ref().to_wkt())?;
+                let chunks = self
+                    .$cast_func()
+                    .try_map(|chunk| chunk.as_ref().to_wkt_with_options(options))?;
                 Ok(ChunkedGeometryArray::new(chunks))
             }};
         }
@@ -90,3 +167,201 @@ impl ToWKT for &dyn ChunkedNativeArray {
         }
     }
 }
+
+/// Write a geometry as canonical WKT, in the same layout as the [`wkt`] writer but with every
+/// coordinate formatted by [`write_f64`].
+fn write_canonical_geometry<W: Write>(
+    out: &mut W,
+    geom: &impl GeometryTrait<T = f64>,
+) -> std::fmt::Result {
+    use geo_traits::GeometryType::*;
+
+    match geom.as_type() {
+        Point(point) => {
+            write_tag(out, "POINT", point.dim())?;
+            write_list(out, point.coord().into_iter(), " EMPTY", write_coord)
+        }
+        LineString(line_string) => {
+            write_tag(out, "LINESTRING", line_string.dim())?;
+            write_list(out, line_string.coords(), " EMPTY", write_coord)
+        }
+        Polygon(polygon) => {
+            write_tag(out, "POLYGON", polygon.dim())?;
+            write_polygon(out, polygon, " EMPTY")
+        }
+        MultiPoint(multi_point) => {
+            write_tag(out, "MULTIPOINT", multi_point.dim())?;
+            write_list(out, multi_point.points(), " EMPTY", |out, point| {
+                write_list(out, point.coord().into_iter(), "EMPTY", write_coord)
+            })
+        }
+        MultiLineString(multi_line_string) => {
+            write_tag(out, "MULTILINESTRING", multi_line_string.dim())?;
+            write_list(
+                out,
+                multi_line_string.line_strings(),
+                " EMPTY",
+                |out, line_string| write_list(out, line_string.coords(), "EMPTY", write_coord),
+            )
+        }
+        MultiPolygon(multi_polygon) => {
+            write_tag(out, "MULTIPOLYGON", multi_polygon.dim())?;
+            write_list(out, multi_polygon.polygons(), " EMPTY", |out, polygon| {
+                write_polygon(out, &polygon, "EMPTY")
+            })
+        }
+        GeometryCollection(collection) => {
+            write_tag(out, "GEOMETRYCOLLECTION", collection.dim())?;
+            write_list(out, collection.geometries(), " EMPTY", |out, geom| {
+                write_canonical_geometry(out, &geom)
+            })
+        }
+        Rect(rect) => {
+            let (min, max) = (rect.min(), rect.max());
+            let ring = [
+                (min.x(), min.y()),
+                (max.x(), min.y()),
+                (max.x(), max.y()),
+                (min.x(), max.y()),
+                (min.x(), min.y()),
+            ];
+            out.write_str("POLYGON(")?;
+            write_list(out, ring.into_iter(), "EMPTY", write_coord)?;
+            out.write_char(')')
+        }
+        Triangle(triangle) => {
+            write_tag(out, "POLYGON", triangle.dim())?;
+            let ring = [
+                triangle.first(),
+                triangle.second(),
+                triangle.third(),
+                triangle.first(),
+            ];
+            out.write_char('(')?;
+            write_list(out, ring.into_iter(), "EMPTY", write_coord)?;
+            out.write_char(')')
+        }
+        Line(line) => {
+            write_tag(out, "LINESTRING", line.dim())?;
+            write_list(
+                out,
+                [line.start(), line.end()].into_iter(),
+                " EMPTY",
+                write_coord,
+            )
+        }
+    }
+}
+
+/// Write the geometry type of a WKT geometry, followed by its dimension if it isn't XY.
+fn write_tag<W: Write>(out: &mut W, tag: &str, dim: Dimensions) -> std::fmt::Result {
+    out.write_str(tag)?;
+    match dim {
+        Dimensions::Xy | Dimensions::Unknown(2) => Ok(()),
+        Dimensions::Xyz | Dimensions::Unknown(3) => out.write_str(" Z"),
+        Dimensions::Xym => out.write_str(" M"),
+        Dimensions::Xyzm | Dimensions::Unknown(_) => out.write_str(" ZM"),
+    }
+}
+
+/// Write the items of a WKT list in parentheses, separated by commas, or `empty` if there are no
+/// items.
+fn write_list<W: Write, T>(
+    out: &mut W,
+    items: impl Iterator<Item = T>,
+    empty: &str,
+    mut write_item: impl FnMut(&mut W, T) -> std::fmt::Result,
+) -> std::fmt::Result {
+    let mut items = items.peekable();
+    if items.peek().is_none() {
+        return out.write_str(empty);
+    }
+    out.write_char('(')?;
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            out.write_char(',')?;
+        }
+        write_item(out, item)?;
+    }
+    out.write_char(')')
+}
+
+fn write_polygon<W: Write>(
+    out: &mut W,
+    polygon: &impl PolygonTrait<T = f64>,
+    empty: &str,
+) -> std::fmt::Result {
+    let rings = polygon.exterior().into_iter().chain(polygon.interiors());
+    write_list(out, rings, empty, |out, ring| {
+        write_list(out, ring.coords(), "EMPTY", write_coord)
+    })
+}
+
+fn write_coord<W: Write>(out: &mut W, coord: impl CoordTrait<T = f64>) -> std::fmt::Result {
+    for i in 0..coord.dim().size() {
+        if i > 0 {
+            out.write_char(' ')?;
+        }
+        write_f64(out, coord.nth_or_panic(i))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{GeometryBuilder, PointArray};
+    use crate::datatypes::Dimension;
+    use crate::test::linestring;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn scalar_to_wkt() {
+        let point = geo::Point::from((0.12345, 1.23456));
+        let point_array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+        assert_eq!(
+            point_array.value(0).to_wkt().unwrap(),
+            "POINT(0.12345 1.23456)"
+        );
+
+        let line_string_array = linestring::ls_array();
+        assert_eq!(
+            line_string_array.value(0).to_wkt().unwrap(),
+            "LINESTRING(0 1,1 2)"
+        );
+    }
+
+    #[test]
+    fn canonical_wkt() {
+        let geoms = vec![
+            Some(geo::Geometry::Point(geo::point!(x: -0., y: 1e300))),
+            Some(geo::Geometry::Polygon(geo::polygon![
+                (x: 0., y: 0.), (x: 1.5, y: 0.), (x: 1.5, y: 0.1), (x: 0., y: 0.)
+            ])),
+            Some(geo::Geometry::MultiPoint(geo::MultiPoint::new(vec![]))),
+            None,
+        ];
+        let array = GeometryBuilder::from_nullable_geometries(
+            &geoms,
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+        let options = WktWriterOptions { canonical: true };
+        let wkt = (&array as &dyn NativeArray)
+            .to_wkt_with_options::<i32>(options)
+            .unwrap()
+            .into_inner();
+        assert_eq!(
+            wkt.iter().collect::<Vec<_>>(),
+            vec![
+                Some("POINT(0 1e300)"),
+                Some("POLYGON((0 0,1.5 0,1.5 0.1,0 0))"),
+                Some("MULTIPOINT EMPTY"),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! The names of traits and types before their renames in 0.4, so that code written against
+//! geoarrow 0.3 keeps compiling while it migrates.
+//!
+//! Every item here is the renamed item itself, not a wrapper, so arrays can be passed freely
+//! between code that uses the old and the new names. These aliases will be removed in a future
+//! release; prefer the new names in new code.
+//!
+//! ```
+//! use geoarrow::legacy::GeometryArrayTrait;
+//! use geoarrow::ArrayBase;
+//!
+//! fn num_geometries(array: &dyn GeometryArrayTrait) -> usize {
+//!     array.len()
+//! }
+//! ```
+
+pub use crate::array::{
+    AsChunkedNativeArray as AsChunkedGeometryArray, AsNativeArray as AsGeometryArray,
+    NativeArrayDyn as GeometryArrayDyn,
+};
+pub use crate::chunked_array::ChunkedNativeArray as ChunkedGeometryArrayTrait;
+pub use crate::trait_::{
+    ArrayAccessor as GeometryArrayAccessor, NativeArray as GeometryArrayTrait,
+    NativeArrayRef as GeometryArrayRef, NativeScalar as GeometryScalarTrait,
+};
@@ -70,6 +70,7 @@ pub mod error;
 // Long-term we want this to be part of the public API, but not yet stabilized in v0.3.
 pub(crate) mod indexed;
 pub mod io;
+pub mod legacy;
 pub mod scalar;
 pub mod schema;
 pub mod table;
@@ -1,8 +1,8 @@
+use crate::algorithm::native::bounding_rect::bounding_rect_geometry;
 use crate::error::Result;
 use crate::io::geo::geometry_to_geo;
 use crate::trait_::NativeScalar;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
-use geo::BoundingRect;
 use geo_traits::GeometryTrait;
 use rstar::{RTreeObject, AABB};
 
@@ -98,9 +98,7 @@ impl<O: OffsetSizeTrait> RTreeObject for WKB<'_, O> {
 
     fn envelope(&self) -> Self::Envelope {
         let geom: geo::Geometry = self.into();
-        let rect = geom.bounding_rect().unwrap();
-        let lower: [f64; 2] = rect.min().into();
-        let upper: [f64; 2] = rect.max().into();
+        let (lower, upper) = bounding_rect_geometry(&geom);
         AABB::from_corners(lower, upper)
     }
 }
@@ -1,7 +1,6 @@
 use crate::algorithm::native::eq::geometry_collection_eq;
 use crate::array::util::OffsetBufferUtils;
 use crate::array::MixedGeometryArray;
-use crate::datatypes::Dimension;
 use crate::io::geo::geometry_collection_to_geo;
 use crate::scalar::Geometry;
 use crate::trait_::ArrayAccessor;
@@ -72,10 +71,7 @@ impl<'a> GeometryCollectionTrait for GeometryCollection<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.array.dimension() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.array.dimension().into()
     }
 
     fn num_geometries(&self) -> usize {
@@ -96,10 +92,7 @@ impl<'a> GeometryCollectionTrait for &'a GeometryCollection<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.array.dimension() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.array.dimension().into()
     }
 
     fn num_geometries(&self) -> usize {
@@ -21,6 +21,7 @@ pub use polygon::Polygon;
 pub(crate) use rect::OwnedRect;
 pub use rect::Rect;
 pub use scalar::GeometryScalar;
+pub use to_geo::{TryToGeo, ZHandling};
 
 mod binary;
 mod coord;
@@ -35,3 +36,4 @@ mod polygon;
 mod rect;
 #[allow(clippy::module_inception)]
 mod scalar;
+mod to_geo;
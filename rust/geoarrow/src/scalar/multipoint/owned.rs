@@ -1,6 +1,5 @@
 use crate::algorithm::native::eq::multi_point_eq;
 use crate::array::{CoordBuffer, MultiPointArray};
-use crate::datatypes::Dimension;
 use crate::scalar::{MultiPoint, Point};
 use arrow_buffer::OffsetBuffer;
 use geo_traits::MultiPointTrait;
@@ -59,10 +58,7 @@ impl MultiPointTrait for OwnedMultiPoint {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_points(&self) -> usize {
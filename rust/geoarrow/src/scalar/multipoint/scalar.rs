@@ -2,7 +2,6 @@ use crate::algorithm::native::bounding_rect::bounding_rect_multipoint;
 use crate::algorithm::native::eq::multi_point_eq;
 use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
-use crate::datatypes::Dimension;
 use crate::scalar::Point;
 use crate::trait_::NativeScalar;
 use arrow_buffer::OffsetBuffer;
@@ -75,10 +74,7 @@ impl<'a> MultiPointTrait for MultiPoint<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_points(&self) -> usize {
@@ -99,10 +95,7 @@ impl<'a> MultiPointTrait for &'a MultiPoint<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_points(&self) -> usize {
@@ -2,7 +2,6 @@ use crate::algorithm::native::bounding_rect::bounding_rect_polygon;
 use crate::algorithm::native::eq::polygon_eq;
 use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
-use crate::datatypes::Dimension;
 use crate::scalar::LineString;
 use crate::trait_::NativeScalar;
 use arrow_buffer::OffsetBuffer;
@@ -82,10 +81,7 @@ impl<'a> PolygonTrait for Polygon<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
@@ -115,10 +111,7 @@ impl<'a> PolygonTrait for &'a Polygon<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
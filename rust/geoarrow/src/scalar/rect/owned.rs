@@ -1,6 +1,5 @@
 use crate::algorithm::native::eq::rect_eq;
 use crate::array::{RectArray, SeparatedCoordBuffer};
-use crate::datatypes::Dimension;
 use crate::scalar::{Rect, SeparatedCoord};
 use geo_traits::RectTrait;
 
@@ -52,10 +51,7 @@ impl RectTrait for OwnedRect {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.lower.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.lower.dim().into()
     }
 
     fn min(&self) -> Self::CoordType<'_> {
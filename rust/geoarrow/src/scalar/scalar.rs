@@ -1,11 +1,21 @@
+use std::cell::Cell;
+use std::sync::Arc;
+
+use geo::{CoordsIter, MapCoords, Translate};
+use geo_traits::GeometryTrait;
+
+use crate::algorithm::native::{Cast, Downcast};
+use crate::array::metadata::ArrayMetadata;
 use crate::array::{
-    AsNativeArray, GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray,
-    MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, RectArray,
+    AsNativeArray, CoordType, GeometryArray, GeometryBuilder, GeometryCollectionArray,
+    LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, PointArray,
+    PolygonArray, RectArray, RectBuilder,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::Geometry;
 use crate::trait_::{ArrayAccessor, NativeArrayRef, NativeScalar};
+use crate::NativeArray;
 
 /// A dynamically typed GeoArrow scalar
 ///
@@ -28,6 +38,93 @@ impl GeometryScalar {
         }
     }
 
+    /// Create a scalar from a single geometry, such as a [`geo`] geometry.
+    ///
+    /// The scalar has the simplest type that holds the geometry, e.g. a point type for a point.
+    /// This is handy to construct query geometries and test fixtures.
+    pub fn from_geometry(
+        geometry: &impl GeometryTrait<T = f64>,
+        coord_type: CoordType,
+        metadata: Arc<ArrayMetadata>,
+    ) -> Result<Self> {
+        let mut builder = GeometryBuilder::new_with_options(coord_type, metadata, false);
+        builder.push_geometry(Some(geometry))?;
+        Self::try_new(builder.finish().downcast()?)
+    }
+
+    /// Return a new scalar with the CRS of `crs`, without transforming its coordinates.
+    ///
+    /// Other metadata, such as the edges, is kept.
+    pub fn with_crs(&self, crs: &ArrayMetadata) -> Self {
+        let mut metadata = self.0.metadata().as_ref().clone();
+        metadata.crs = crs.crs.clone();
+        metadata.crs_type = crs.crs_type.clone();
+        Self(self.0.with_metadata(Arc::new(metadata)))
+    }
+
+    /// Return a new scalar translated by `x_offset` and `y_offset`.
+    ///
+    /// Like other edits, this returns a two-dimensional scalar of the same geometry type, with
+    /// the same coordinate type and metadata.
+    pub fn translate(&self, x_offset: f64, y_offset: f64) -> Result<Self> {
+        self.with_geo(self.to_geo().translate(x_offset, y_offset))
+    }
+
+    /// Return a new scalar with its coordinates replaced by `coords`, in order.
+    ///
+    /// `coords` must have as many coordinates as the scalar, in the order of
+    /// [`CoordsIter::coords_iter`]: the exterior ring of each polygon, then its interior rings.
+    /// A rect instead takes two coordinates, its minimum and maximum corners.
+    pub fn set_coordinates(&self, coords: &[geo::Coord]) -> Result<Self> {
+        let geometry = self.to_geo();
+        // The coordinates of a rect iterate over its four corners, but only the two corners
+        // that define it can be set
+        if let geo::Geometry::Rect(_) = geometry {
+            let [min, max] = coords else {
+                return Err(GeoArrowError::General(format!(
+                    "Expected 2 coordinates for a rect, got {}",
+                    coords.len()
+                )));
+            };
+            return self.with_geo(geo::Rect::new(*min, *max).into());
+        }
+        if geometry.coords_count() != coords.len() {
+            return Err(GeoArrowError::General(format!(
+                "Expected {} coordinates, got {}",
+                geometry.coords_count(),
+                coords.len()
+            )));
+        }
+        let index = Cell::new(0);
+        let geometry = geometry.map_coords(|_| {
+            let coord = coords[index.get()];
+            index.set(index.get() + 1);
+            coord
+        });
+        self.with_geo(geometry)
+    }
+
+    /// A new scalar of the same type and metadata as this one, with the geometry `geometry`.
+    fn with_geo(&self, geometry: geo::Geometry) -> Result<Self> {
+        let metadata = self.0.metadata();
+        let array: NativeArrayRef = match (self.data_type(), &geometry) {
+            (NativeType::Rect(_), geo::Geometry::Rect(rect)) => {
+                let mut builder = RectBuilder::new_with_options(Dimension::XY, metadata);
+                builder.push_rect(Some(rect));
+                Arc::new(builder.finish())
+            }
+            (data_type, _) => {
+                let mut builder =
+                    GeometryBuilder::new_with_options(self.0.coord_type(), metadata, false);
+                builder.push_geometry(Some(&geometry))?;
+                builder
+                    .finish()
+                    .cast(data_type.with_dimension(Dimension::XY))?
+            }
+        };
+        Self::try_new(array)
+    }
+
     /// The data type of this scalar
     pub fn data_type(&self) -> NativeType {
         self.0.data_type()
@@ -213,6 +310,70 @@ impl GeometryScalar {
     }
 }
 
+#[cfg(test)]
+mod test {
+    use geo::{line_string, point, polygon};
+
+    use super::*;
+
+    fn scalar(geometry: &geo::Geometry) -> GeometryScalar {
+        let metadata = Arc::new(ArrayMetadata::from_authority_code("EPSG:4326".to_string()));
+        GeometryScalar::from_geometry(geometry, CoordType::Separated, metadata).unwrap()
+    }
+
+    #[test]
+    fn edit_scalars() {
+        let point = scalar(&point!(x: 1., y: 2.).into());
+        assert!(matches!(
+            point.data_type(),
+            NativeType::Point(CoordType::Separated, Dimension::XY)
+        ));
+        let translated = point.translate(1., -1.).unwrap();
+        assert_eq!(translated.data_type(), point.data_type());
+        assert_eq!(translated.to_geo_point().unwrap(), point!(x: 2., y: 1.));
+        assert_eq!(translated.inner().metadata(), point.inner().metadata());
+
+        let line = scalar(&line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into());
+        let coords = [geo::coord! { x: 5., y: 5. }, geo::coord! { x: 6., y: 7. }];
+        let edited = line.set_coordinates(&coords).unwrap();
+        assert_eq!(
+            edited.to_geo_line_string().unwrap(),
+            geo::LineString::from(coords.to_vec())
+        );
+        assert!(line.set_coordinates(&coords[..1]).is_err());
+
+        let polygon = scalar(&polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)].into());
+        let crs = ArrayMetadata::from_authority_code("EPSG:3857".to_string());
+        let reassigned = polygon.with_crs(&crs);
+        assert_eq!(reassigned.inner().metadata().crs, crs.crs);
+        assert_eq!(reassigned.to_geo(), polygon.to_geo());
+    }
+
+    #[test]
+    fn set_rect_coordinates() {
+        let mut builder = RectBuilder::new(Dimension::XY);
+        builder.push_rect(Some(&geo::Rect::new(
+            geo::coord! { x: 0., y: 0. },
+            geo::coord! { x: 1., y: 1. },
+        )));
+        let rect = GeometryScalar::try_new(Arc::new(builder.finish())).unwrap();
+
+        let coords = [geo::coord! { x: 2., y: 3. }, geo::coord! { x: 4., y: 5. }];
+        let edited = rect.set_coordinates(&coords).unwrap();
+        assert!(matches!(
+            edited.data_type(),
+            NativeType::Rect(Dimension::XY)
+        ));
+        assert_eq!(
+            edited.to_geo(),
+            geo::Geometry::Rect(geo::Rect::new(coords[0], coords[1]))
+        );
+
+        let corners = rect.to_geo().coords_iter().collect::<Vec<_>>();
+        assert!(rect.set_coordinates(&corners).is_err());
+    }
+}
+
 // #[allow(clippy::upper_case_acronyms)]
 // enum AnyPoint<'a> {
 //     XY(Point<'a, 2>),
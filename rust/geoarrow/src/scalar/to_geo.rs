@@ -0,0 +1,172 @@
+use geo_traits::{
+    Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+};
+
+use crate::error::{GeoArrowError, Result};
+use crate::scalar::{
+    Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon, Rect,
+};
+use crate::trait_::NativeScalar;
+
+/// How to convert geometries with a Z dimension to [mod@geo] types, which only store X and Y.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZHandling {
+    /// Return an error for geometries with a Z dimension.
+    #[default]
+    Error,
+
+    /// Drop the Z values.
+    Drop,
+}
+
+/// A fallible conversion from a geoarrow scalar to its [mod@geo] equivalent.
+///
+/// Unlike [`NativeScalar::to_geo`], this checks the dimension of the geometry instead of silently
+/// dropping Z values, and returns an error instead of panicking for empty points.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::array::PointArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::scalar::{TryToGeo, ZHandling};
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let point = geo::point!(x: 1., y: 2.);
+/// let array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+/// let point = array.value(0).try_to_geo(ZHandling::Error).unwrap();
+/// assert_eq!(point, geo::point!(x: 1., y: 2.));
+/// ```
+pub trait TryToGeo: NativeScalar {
+    /// Converts this value to its [mod@geo] equivalent.
+    fn try_to_geo(&self, z_handling: ZHandling) -> Result<Self::ScalarGeo>;
+}
+
+fn check_dimension(dim: Dimensions, z_handling: ZHandling) -> Result<()> {
+    match (dim, z_handling) {
+        (Dimensions::Xy | Dimensions::Unknown(2), _) | (_, ZHandling::Drop) => Ok(()),
+        _ => Err(GeoArrowError::General(format!(
+            "Cannot convert geometry with dimension {:?} to geo without dropping Z values",
+            dim
+        ))),
+    }
+}
+
+impl TryToGeo for Point<'_> {
+    fn try_to_geo(&self, z_handling: ZHandling) -> Result<Self::ScalarGeo> {
+        check_dimension(PointTrait::dim(self), z_handling)?;
+        if self.coord().is_none() {
+            return Err(GeoArrowError::General(
+                "Cannot convert an empty point to geo::Point".to_string(),
+            ));
+        }
+        Ok(self.to_geo())
+    }
+}
+
+macro_rules! impl_try_to_geo {
+    ($scalar_type:ty, $trait:ident) => {
+        impl TryToGeo for $scalar_type {
+            fn try_to_geo(&self, z_handling: ZHandling) -> Result<Self::ScalarGeo> {
+                check_dimension($trait::dim(self), z_handling)?;
+                Ok(self.to_geo())
+            }
+        }
+    };
+}
+
+impl_try_to_geo!(LineString<'_>, LineStringTrait);
+impl_try_to_geo!(Polygon<'_>, PolygonTrait);
+impl_try_to_geo!(MultiPoint<'_>, MultiPointTrait);
+impl_try_to_geo!(MultiLineString<'_>, MultiLineStringTrait);
+impl_try_to_geo!(MultiPolygon<'_>, MultiPolygonTrait);
+impl_try_to_geo!(GeometryCollection<'_>, GeometryCollectionTrait);
+impl_try_to_geo!(Rect<'_>, RectTrait);
+
+impl TryToGeo for Geometry<'_> {
+    fn try_to_geo(&self, z_handling: ZHandling) -> Result<Self::ScalarGeo> {
+        match self {
+            Geometry::Point(g) => Ok(geo::Geometry::Point(g.try_to_geo(z_handling)?)),
+            _ => {
+                check_dimension(GeometryTrait::dim(self), z_handling)?;
+                Ok(self.to_geo())
+            }
+        }
+    }
+}
+
+// Conversions from a geometry scalar to a specific geo type, which fail if the scalar holds a
+// different geometry type.
+macro_rules! impl_try_from_geometry {
+    ($geo_type:ty, $variant:ident) => {
+        impl TryFrom<&Geometry<'_>> for $geo_type {
+            type Error = GeoArrowError;
+
+            fn try_from(value: &Geometry<'_>) -> Result<Self> {
+                match value.try_to_geo(ZHandling::Error)? {
+                    geo::Geometry::$variant(geom) => Ok(geom),
+                    _ => Err(GeoArrowError::IncorrectType(
+                        concat!("Expected a ", stringify!($variant), " geometry").into(),
+                    )),
+                }
+            }
+        }
+
+        impl TryFrom<Geometry<'_>> for $geo_type {
+            type Error = GeoArrowError;
+
+            fn try_from(value: Geometry<'_>) -> Result<Self> {
+                (&value).try_into()
+            }
+        }
+    };
+}
+
+impl_try_from_geometry!(geo::Point, Point);
+impl_try_from_geometry!(geo::LineString, LineString);
+impl_try_from_geometry!(geo::Polygon, Polygon);
+impl_try_from_geometry!(geo::MultiPoint, MultiPoint);
+impl_try_from_geometry!(geo::MultiLineString, MultiLineString);
+impl_try_from_geometry!(geo::MultiPolygon, MultiPolygon);
+impl_try_from_geometry!(geo::GeometryCollection, GeometryCollection);
+impl_try_from_geometry!(geo::Rect, Rect);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{GeometryBuilder, PointBuilder};
+    use crate::datatypes::Dimension;
+    use crate::test::point;
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn z_handling() {
+        let array = point::point_z_array();
+        let point = array.value(0);
+        assert!(point.try_to_geo(ZHandling::Error).is_err());
+        assert_eq!(point.try_to_geo(ZHandling::Drop).unwrap(), point.to_geo());
+    }
+
+    #[test]
+    fn empty_point() {
+        let mut builder = PointBuilder::new(Dimension::XY);
+        builder.push_empty();
+        let array = builder.finish();
+        assert!(array.value(0).try_to_geo(ZHandling::Drop).is_err());
+    }
+
+    #[test]
+    fn from_geometry() {
+        let mut builder = GeometryBuilder::new();
+        builder
+            .push_point(Some(&geo::point!(x: 1., y: 2.)))
+            .unwrap();
+        let array = builder.finish();
+
+        let point: geo::Point = array.value(0).try_into().unwrap();
+        assert_eq!(point, geo::point!(x: 1., y: 2.));
+        assert!(geo::LineString::try_from(array.value(0)).is_err());
+    }
+}
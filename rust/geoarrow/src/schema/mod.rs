@@ -1,5 +1,13 @@
 //! Geospatial operations on Arrow schemas
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+use crate::array::metadata::ArrayMetadata;
+use crate::datatypes::SerializedType;
+use crate::error::{GeoArrowError, Result};
 use crate::table::GEOARROW_EXTENSION_NAMES;
 
 /// Extra geospatial-specific functionality on Arrow schemas
@@ -9,9 +17,21 @@ pub trait GeoSchemaExt {
     /// The returned `Vec` may be empty if the table contains no geometry columns, or it may
     /// contain more than one element if the table contains multiple tagged geometry columns.
     fn geometry_columns(&self) -> Vec<usize>;
+
+    /// Tag the untyped column at `index` as a serialized geometry column with the given metadata,
+    /// returning the new schema.
+    ///
+    /// `Binary` and `LargeBinary` columns become WKB, and `Utf8` and `LargeUtf8` columns become
+    /// WKT. Other field metadata is kept.
+    fn with_serialized_column(&self, index: usize, metadata: &ArrayMetadata) -> Result<SchemaRef>;
+
+    /// Replace the CRS and edges of the geometry column at `index`, returning the new schema.
+    ///
+    /// Keys of the existing GeoArrow metadata that this crate doesn't know are kept.
+    fn with_column_metadata(&self, index: usize, metadata: &ArrayMetadata) -> Result<SchemaRef>;
 }
 
-impl GeoSchemaExt for &arrow_schema::Schema {
+impl GeoSchemaExt for &Schema {
     fn geometry_columns(&self) -> Vec<usize> {
         let mut geom_indices = vec![];
         for (field_idx, field) in self.fields().iter().enumerate() {
@@ -24,4 +44,119 @@ impl GeoSchemaExt for &arrow_schema::Schema {
         }
         geom_indices
     }
+
+    fn with_serialized_column(&self, index: usize, metadata: &ArrayMetadata) -> Result<SchemaRef> {
+        let field = field(self, index)?;
+        let typ = match field.data_type() {
+            DataType::Binary => SerializedType::WKB,
+            DataType::LargeBinary => SerializedType::LargeWKB,
+            DataType::Utf8 => SerializedType::WKT,
+            DataType::LargeUtf8 => SerializedType::LargeWKT,
+            data_type => {
+                return Err(GeoArrowError::IncorrectType(
+                    format!(
+                        "Expected a binary or string column to tag as WKB or WKT, got {data_type}"
+                    )
+                    .into(),
+                ))
+            }
+        };
+        let field = with_extension(field, typ.extension_name(), metadata);
+        Ok(replace_field(self, index, field))
+    }
+
+    fn with_column_metadata(&self, index: usize, metadata: &ArrayMetadata) -> Result<SchemaRef> {
+        if !self.geometry_columns().contains(&index) {
+            return Err(GeoArrowError::General(format!(
+                "Column {index} is not a geometry column"
+            )));
+        }
+        let field = field(self, index)?;
+        let mut existing = ArrayMetadata::try_from(field)?;
+        existing.crs = metadata.crs.clone();
+        existing.crs_type = metadata.crs_type.clone();
+        existing.edges = metadata.edges.clone();
+        existing.extra.extend(metadata.extra.clone());
+
+        let extension_name = field.metadata()["ARROW:extension:name"].clone();
+        let field = with_extension(field, &extension_name, &existing);
+        Ok(replace_field(self, index, field))
+    }
+}
+
+fn field(schema: &Schema, index: usize) -> Result<&Field> {
+    schema
+        .fields()
+        .get(index)
+        .map(|f| f.as_ref())
+        .ok_or_else(|| {
+            GeoArrowError::General(format!(
+                "Column index {index} out of bounds for a schema with {} fields",
+                schema.fields().len()
+            ))
+        })
+}
+
+/// Set the GeoArrow extension name and metadata of a field, keeping its other metadata.
+fn with_extension(field: &Field, extension_name: &str, metadata: &ArrayMetadata) -> Field {
+    let mut field_metadata: HashMap<String, String> = field.metadata().clone();
+    field_metadata.insert(
+        "ARROW:extension:name".to_string(),
+        extension_name.to_string(),
+    );
+    if metadata.should_serialize() {
+        field_metadata.insert(
+            "ARROW:extension:metadata".to_string(),
+            serde_json::to_string(metadata).unwrap(),
+        );
+    } else {
+        field_metadata.remove("ARROW:extension:metadata");
+    }
+    field.clone().with_metadata(field_metadata)
+}
+
+fn replace_field(schema: &Schema, index: usize, field: Field) -> SchemaRef {
+    let mut fields = schema.fields().to_vec();
+    fields[index] = Arc::new(field);
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::metadata::Edges;
+    use crate::datatypes::AnyType;
+
+    #[test]
+    fn tag_and_replace_metadata() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("geometry", DataType::Binary, true)
+                .with_metadata([("source".to_string(), "csv".to_string())].into()),
+        ]);
+        let schema = &schema;
+        assert!(schema.geometry_columns().is_empty());
+        assert!(schema
+            .with_serialized_column(0, &Default::default())
+            .is_err());
+
+        let crs = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let tagged = schema.with_serialized_column(1, &crs).unwrap();
+        assert_eq!(tagged.as_ref().geometry_columns(), vec![1]);
+        let field = tagged.field(1);
+        assert_eq!(
+            AnyType::try_from(field).unwrap(),
+            AnyType::Serialized(SerializedType::WKB)
+        );
+        assert_eq!(field.metadata()["source"], "csv");
+        assert_eq!(ArrayMetadata::try_from(field).unwrap(), crs);
+
+        let spherical = ArrayMetadata::default().with_edges(Edges::Spherical);
+        let replaced = tagged.as_ref().with_column_metadata(1, &spherical).unwrap();
+        assert_eq!(
+            ArrayMetadata::try_from(replaced.field(1)).unwrap(),
+            spherical
+        );
+        assert!(tagged.as_ref().with_column_metadata(0, &spherical).is_err());
+    }
 }
@@ -8,8 +8,9 @@ use std::sync::Arc;
 use arrow_array::{ArrayRef, RecordBatch, RecordBatchIterator, RecordBatchReader};
 use arrow_schema::{ArrowError, FieldRef, Schema, SchemaBuilder, SchemaRef};
 
-use crate::algorithm::native::{Cast, Downcast};
-use crate::array::metadata::ArrayMetadata;
+use crate::algorithm::native::bounding_rect::BoundingRect;
+use crate::algorithm::native::{Cast, Downcast, TotalBounds};
+use crate::array::metadata::{ArrayMetadata, CrsStrictness};
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedNativeArray, ChunkedNativeArrayDyn};
 use crate::datatypes::{AnyType, NativeType, SerializedType};
@@ -577,6 +578,201 @@ impl Table {
         Ok(self.schema.fields().len() - 1)
     }
 
+    /// Set the CRS of every geometry column, without transforming any coordinates.
+    ///
+    /// Like GeoPandas' `GeoDataFrame.set_crs`, this errors if a geometry column already has a
+    /// different CRS, unless `allow_override` is `true`. The CRS and CRS type are taken from `crs`,
+    /// while the edges and other metadata of each column are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # {
+    /// use std::fs::File;
+    /// use geoarrow::array::metadata::ArrayMetadata;
+    ///
+    /// let file = File::open("fixtures/roads.geojson").unwrap();
+    /// let mut table = geoarrow::io::geojson::read_geojson(file, Default::default()).unwrap();
+    /// let crs = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+    /// table.set_crs(&crs, false).unwrap();
+    ///
+    /// let metadata = table.geometry_column(None).unwrap().geometry_chunks()[0].metadata();
+    /// assert_eq!(metadata.to_epsg(0), Some(4326));
+    /// # }
+    /// ```
+    pub fn set_crs(&mut self, crs: &ArrayMetadata, allow_override: bool) -> Result<()> {
+        let mut schema = self.schema.clone();
+        for index in self.schema.as_ref().geometry_columns() {
+            let field = schema.field(index);
+            let mut metadata = ArrayMetadata::try_from(field)?;
+            if !allow_override
+                && metadata.crs.is_some()
+                && !metadata.crs_equivalent_to(crs, CrsStrictness::default())
+            {
+                return Err(GeoArrowError::General(format!(
+                    "Geometry column {} already has a different CRS. Use `allow_override` to replace it, or `to_crs` to transform it.",
+                    field.name()
+                )));
+            }
+            metadata.crs = crs.crs.clone();
+            metadata.crs_type = crs.crs_type.clone();
+            schema = schema.as_ref().with_column_metadata(index, &metadata)?;
+        }
+        self.replace_schema(schema)
+    }
+
+    /// Transform every geometry column to `crs`, returning a new table.
+    ///
+    /// This is the equivalent of GeoPandas' `GeoDataFrame.to_crs`. Every geometry column must
+    /// have a CRS that PROJ can resolve, see [`Self::set_crs`] for columns without one. The
+    /// transformed columns are two-dimensional, and their metadata is updated to the new CRS.
+    ///
+    /// Only point, line string, polygon and multi geometry columns are supported.
+    #[cfg(feature = "proj")]
+    pub fn to_crs(&self, crs: &ArrayMetadata) -> Result<Self> {
+        self.to_crs_with_options(crs, Default::default())
+    }
+
+    /// Transform every geometry column to `crs` with specific options, returning a new table.
+    ///
+    /// See [`Self::to_crs`], and
+    /// [`ReprojectOptions`][crate::algorithm::proj::ReprojectOptions] to densify segments that
+    /// the transformation curves.
+    #[cfg(feature = "proj")]
+    pub fn to_crs_with_options(
+        &self,
+        crs: &ArrayMetadata,
+        options: crate::algorithm::proj::ReprojectOptions,
+    ) -> Result<Self> {
+        use crate::algorithm::proj::Reproject;
+
+        let target = crs.proj_definition()?.ok_or_else(|| {
+            GeoArrowError::General("The target CRS of `to_crs` is missing".to_string())
+        })?;
+
+        let mut table = self.clone();
+        for index in self.schema.as_ref().geometry_columns() {
+            let field = self.schema.field(index);
+            let mut metadata = ArrayMetadata::try_from(field)?;
+            let source = metadata.proj_definition()?.ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Cannot transform geometry column {} without a CRS. Use `set_crs` first.",
+                    field.name()
+                ))
+            })?;
+            let proj = proj::Proj::new_known_crs(&source, &target, None)
+                .map_err(|err| GeoArrowError::General(err.to_string()))?;
+
+            metadata.crs = crs.crs.clone();
+            metadata.crs_type = crs.crs_type.clone();
+            let metadata = Arc::new(metadata);
+            let chunks = self
+                .geometry_column(Some(index))?
+                .geometry_chunks()
+                .into_iter()
+                .map(|chunk| {
+                    Ok(chunk
+                        .reproject_with_options(&proj, options)?
+                        .with_metadata(metadata.clone()))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Chunks that are all null keep their type, so take the type of the first chunk
+            let field = match chunks.first() {
+                Some(chunk) => chunk.data_type().to_field_with_metadata(
+                    field.name(),
+                    field.is_nullable(),
+                    &metadata,
+                ),
+                None => field.as_ref().clone(),
+            };
+            let arrays = chunks.iter().map(|chunk| chunk.to_array_ref()).collect();
+            table.set_column(index, field.into(), arrays)?;
+        }
+        Ok(table)
+    }
+
+    /// Compute the total bounds of the geometry column at `index`.
+    ///
+    /// If index is `None` and there is only one geometry column, the bounds of that column are
+    /// returned. Otherwise, this method will return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # {
+    /// use std::fs::File;
+    ///
+    /// let file = File::open("fixtures/roads.geojson").unwrap();
+    /// let table = geoarrow::io::geojson::read_geojson(file, Default::default()).unwrap();
+    /// let bounds = table.total_bounds(None).unwrap();
+    /// assert!(bounds.minx() <= bounds.maxx());
+    /// # }
+    /// ```
+    pub fn total_bounds(&self, index: Option<usize>) -> Result<BoundingRect> {
+        Ok(self.geometry_column(index)?.as_ref().total_bounds())
+    }
+
+    /// Compute the total bounds of the geometry column at `index`, transformed to `crs`.
+    ///
+    /// The edges of the bounds are densified with 21 points each before being transformed with
+    /// PROJ's `proj_trans_bounds`, so that the result contains the transformed bounds even when
+    /// the transformation curves straight lines. When transforming to a geographic CRS, bounds
+    /// that cross the antimeridian have a `minx` larger than their `maxx`.
+    ///
+    /// The z bounds are not transformed, and are not set in the result.
+    #[cfg(feature = "proj")]
+    pub fn total_bounds_in_crs(
+        &self,
+        index: Option<usize>,
+        crs: &ArrayMetadata,
+    ) -> Result<BoundingRect> {
+        let column = self.geometry_column(index)?;
+        let bounds = column.as_ref().total_bounds();
+        if bounds.minx() > bounds.maxx() {
+            // Empty bounds
+            return Ok(bounds);
+        }
+
+        let metadata = column
+            .geometry_chunks()
+            .first()
+            .map(|chunk| chunk.metadata())
+            .unwrap_or_default();
+        let source = metadata.proj_definition()?.ok_or_else(|| {
+            GeoArrowError::General(
+                "Cannot transform the bounds of a geometry column without a CRS".to_string(),
+            )
+        })?;
+        let target = crs.proj_definition()?.ok_or_else(|| {
+            GeoArrowError::General("The target CRS of the bounds is missing".to_string())
+        })?;
+        let proj = proj::Proj::new_known_crs(&source, &target, None)
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        let [minx, miny, maxx, maxy] = proj.transform_bounds(
+            bounds.minx(),
+            bounds.miny(),
+            bounds.maxx(),
+            bounds.maxy(),
+            21,
+        )?;
+        Ok(BoundingRect::from_extent(
+            [minx, miny, f64::INFINITY],
+            [maxx, maxy, -f64::INFINITY],
+        ))
+    }
+
+    /// Replace the schema of this table with one that only differs in metadata.
+    fn replace_schema(&mut self, schema: SchemaRef) -> Result<()> {
+        self.batches = self
+            .batches
+            .iter()
+            .map(|batch| RecordBatch::try_new(schema.clone(), batch.columns().to_vec()))
+            .collect::<std::result::Result<Vec<_>, ArrowError>>()?;
+        self.schema = schema;
+        Ok(())
+    }
+
     /// Convert this table into a [RecordBatchIterator]
     pub fn into_record_batch_reader(self) -> Box<dyn RecordBatchReader + Send> {
         Box::new(RecordBatchIterator::new(
@@ -0,0 +1,70 @@
+//! Arrays with empty geometries, for checking the behavior of kernels on empty input.
+
+use std::sync::Arc;
+
+use geo::{
+    point, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon,
+};
+
+use crate::array::*;
+use crate::datatypes::Dimension;
+use crate::NativeArray;
+
+/// One array of each geometry type with a single empty geometry.
+pub(crate) fn empty_arrays() -> Vec<(&'static str, Arc<dyn NativeArray>)> {
+    let mut point_builder = PointBuilder::new(Dimension::XY);
+    point_builder.push_empty();
+
+    let line_string: LineString = LineString::new(vec![]);
+    let polygon: Polygon = Polygon::new(LineString::new(vec![]), vec![]);
+    let multi_point: MultiPoint = MultiPoint::new(vec![]);
+    let multi_line_string: MultiLineString = MultiLineString::new(vec![]);
+    let multi_polygon: MultiPolygon = MultiPolygon::new(vec![]);
+    let collection: GeometryCollection = GeometryCollection::new_from(vec![]);
+
+    vec![
+        ("Point", Arc::new(point_builder.finish())),
+        (
+            "LineString",
+            Arc::new(
+                LineStringBuilder::from((vec![line_string].as_slice(), Dimension::XY)).finish(),
+            ),
+        ),
+        (
+            "Polygon",
+            Arc::new(PolygonBuilder::from((vec![polygon].as_slice(), Dimension::XY)).finish()),
+        ),
+        (
+            "MultiPoint",
+            Arc::new(
+                MultiPointBuilder::from((vec![multi_point].as_slice(), Dimension::XY)).finish(),
+            ),
+        ),
+        (
+            "MultiLineString",
+            Arc::new(
+                MultiLineStringBuilder::from((vec![multi_line_string].as_slice(), Dimension::XY))
+                    .finish(),
+            ),
+        ),
+        (
+            "MultiPolygon",
+            Arc::new(
+                MultiPolygonBuilder::from((vec![multi_polygon].as_slice(), Dimension::XY)).finish(),
+            ),
+        ),
+        (
+            "GeometryCollection",
+            Arc::new(
+                GeometryCollectionBuilder::from((vec![collection].as_slice(), Dimension::XY))
+                    .finish(),
+            ),
+        ),
+    ]
+}
+
+/// A multi point with an empty point and the point `(1, 2)`.
+pub(crate) fn multi_point_with_empty_part() -> MultiPointArray {
+    let multi_point = MultiPoint::new(vec![point!(x: f64::NAN, y: f64::NAN), point!(x: 1., y: 2.)]);
+    MultiPointBuilder::from((vec![multi_point].as_slice(), Dimension::XY)).finish()
+}
@@ -1,5 +1,6 @@
 pub mod binary;
 pub mod coord;
+pub mod empty;
 pub mod geoarrow_data;
 pub mod geometry;
 pub mod linestring;
@@ -123,6 +123,33 @@ pub trait ArrayBase: std::fmt::Debug + Send + Sync {
     #[must_use]
     fn to_array_ref(&self) -> ArrayRef;
 
+    /// Converts this array into its plain Arrow storage array, without extension metadata.
+    ///
+    /// This is the same array as [`to_array_ref`][Self::to_array_ref]. Together with the data
+    /// type of this array, it can be converted back losslessly with
+    /// [`NativeArrayDyn::try_from_storage`][crate::array::NativeArrayDyn::try_from_storage] or
+    /// [`SerializedArrayDyn::try_from_storage`][crate::array::SerializedArrayDyn::try_from_storage],
+    /// e.g. after passing through a consumer that strips extension metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::{NativeArrayDyn, PolygonArray};
+    /// use geoarrow::datatypes::Dimension;
+    /// use geoarrow::{ArrayBase, NativeArray};
+    ///
+    /// let polygon = geo::polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+    /// let array: PolygonArray = (vec![polygon].as_slice(), Dimension::XY).into();
+    ///
+    /// let storage = array.to_storage_array();
+    /// let round_trip = NativeArrayDyn::try_from_storage(&storage, array.data_type()).unwrap();
+    /// assert_eq!(round_trip.data_type(), array.data_type());
+    /// ```
+    #[must_use]
+    fn to_storage_array(&self) -> ArrayRef {
+        self.to_array_ref()
+    }
+
     /// The number of geometries contained in this array.
     ///
     /// # Examples
@@ -448,6 +475,26 @@ pub trait NativeGeometryAccessor: NativeArray {
 
         Some(unsafe { self.value_as_geometry_unchecked(index) })
     }
+
+    /// Iterates over this array's values as `Geometry` scalars, considering validity.
+    ///
+    /// Every item implements [`GeometryTrait`][geo_traits::GeometryTrait], so kernels can be
+    /// written once for all native array types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::Dimension;
+    /// use geoarrow::trait_::NativeGeometryAccessor;
+    ///
+    /// let point = geo::point!(x: 1., y: 2.);
+    /// let array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+    /// assert_eq!(array.iter_geometry().flatten().count(), 1);
+    /// ```
+    fn iter_geometry(&self) -> impl ExactSizeIterator<Item = Option<Geometry<'_>>> + '_ {
+        (0..self.len()).map(|i| unsafe { self.get_as_geometry_unchecked(i) })
+    }
 }
 
 /// Trait for accessing generic `geos::Geometry` scalars
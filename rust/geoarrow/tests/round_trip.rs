@@ -0,0 +1,17 @@
+use geoarrow::io::round_trip::{assert_round_trips, builtin_formats, RoundTripFormat};
+
+#[test]
+fn builtin_formats_round_trip() {
+    let formats = builtin_formats();
+    let formats = formats.iter().map(|f| f.as_ref()).collect::<Vec<_>>();
+    assert_round_trips(&formats);
+}
+
+#[test]
+fn every_format_has_a_unique_name() {
+    let formats = builtin_formats();
+    let mut names = formats.iter().map(|f| f.name()).collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), formats.len());
+}
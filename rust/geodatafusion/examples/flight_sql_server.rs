@@ -0,0 +1,108 @@
+//! A minimal Flight SQL service that runs spatial queries with geodatafusion.
+//!
+//! Geometry columns are returned with GeoArrow extension metadata. Clients can choose the
+//! geometry encoding per session with `SET geodatafusion.geometry_output = 'wkb'`, which is
+//! useful for BI tools that only understand Well-Known Binary.
+//!
+//! Run with:
+//!
+//! ```sh
+//! cargo run --example flight_sql_server --features flight-sql
+//! ```
+//!
+//! Then connect with any Flight SQL client on `localhost:50051`, for example:
+//!
+//! ```sql
+//! SELECT ST_AsText(ST_Point(1.0, 2.0)) AS wkt, ST_Point(1.0, 2.0) AS geometry;
+//! ```
+//!
+//! This example keeps a single session for all clients and does not implement authentication,
+//! prepared statements or catalog metadata requests.
+
+use std::pin::Pin;
+
+use arrow_flight::flight_service_server::FlightServiceServer;
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::{CommandStatementQuery, ProstMessageExt, SqlInfo, TicketStatementQuery};
+use arrow_flight::{FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, Ticket};
+use datafusion::prelude::{SessionConfig, SessionContext};
+use futures::{Stream, TryStreamExt};
+use geodatafusion::flight::{execute_geometry_stream, flight_data_stream, geometry_output_schema};
+use geodatafusion::output::GeoDataFusionOptions;
+use geodatafusion::udf::native::register_native;
+use prost::Message;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+struct GeoFlightSqlService {
+    ctx: SessionContext,
+}
+
+impl GeoFlightSqlService {
+    fn new() -> Self {
+        let config = SessionConfig::new().with_option_extension(GeoDataFusionOptions::default());
+        let ctx = SessionContext::new_with_config(config);
+        register_native(&ctx);
+        Self { ctx }
+    }
+}
+
+fn to_status(err: impl std::fmt::Display) -> Status {
+    Status::internal(err.to_string())
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for GeoFlightSqlService {
+    type FlightService = GeoFlightSqlService;
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        // Planning the query gives the output schema without executing it. `SET` statements are
+        // applied to the session here.
+        let df = self.ctx.sql(&query.query).await.map_err(to_status)?;
+        let schema = geometry_output_schema(&df, None).map_err(to_status)?;
+
+        // The ticket carries the SQL text, so `DoGet` can plan the query again.
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.into(),
+        };
+        let endpoint =
+            FlightEndpoint::new().with_ticket(Ticket::new(ticket.as_any().encode_to_vec()));
+
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(to_status)?
+            .with_endpoint(endpoint)
+            .with_descriptor(request.into_inner());
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send>>>, Status>
+    {
+        let query = String::from_utf8(ticket.statement_handle.to_vec()).map_err(to_status)?;
+        let df = self.ctx.sql(&query).await.map_err(to_status)?;
+        let stream = execute_geometry_stream(df, None).await.map_err(to_status)?;
+
+        let flight_data = flight_data_stream(stream).map_err(to_status);
+        Ok(Response::new(Box::pin(flight_data)))
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = "0.0.0.0:50051".parse()?;
+    let service = FlightServiceServer::new(GeoFlightSqlService::new());
+
+    println!("Listening for Flight SQL requests on {addr}");
+    Server::builder().add_service(service).serve(addr).await?;
+    Ok(())
+}
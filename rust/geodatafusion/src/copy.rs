@@ -0,0 +1,337 @@
+//! Write query output to geospatial file formats with the geometry metadata of the plan.
+//!
+//! The output of a UDF is a new field without GeoArrow metadata, so writing query output directly
+//! loses the CRS of geometry columns. The writers here attach the metadata recovered by
+//! [`geometry_schema`] before writing, so that the output file describes its CRS correctly
+//! regardless of the CRS of the source:
+//!
+//! - [`write_geojson`] writes [RFC 7946](https://datatracker.ietf.org/doc/html/rfc7946) GeoJSON,
+//!   which must be in WGS 84. Geometries in another CRS are reprojected with the `proj` feature,
+//!   and are an error without it.
+//! - [`write_flatgeobuf`] stores the CRS in the FlatGeobuf header.
+//! - [`write_geoparquet`] stores the CRS, and the geometry types found in the data, in the
+//!   GeoParquet metadata. It requires the `parquet` feature.
+//!
+//! A geometry column without a CRS is never assumed to be in WGS 84. It is an error for GeoJSON
+//! and GeoParquet, where readers would take it to be WGS 84, and written without a CRS to
+//! FlatGeobuf. Use `ST_SetSRID` to assign a CRS.
+//!
+//! ```ignore
+//! use geodatafusion::copy::write_geojson;
+//!
+//! let df = ctx.sql("SELECT ST_Centroid(geometry) AS geometry, name FROM t").await?;
+//! write_geojson(df, File::create("centroids.geojson")?, Default::default()).await?;
+//! ```
+
+use std::io::Write;
+
+use datafusion::common::plan_err;
+use datafusion::dataframe::DataFrame;
+use datafusion::error::{DataFusionError, Result};
+use geoarrow::array::metadata::{ArrayMetadata, CrsStrictness};
+#[cfg(feature = "parquet")]
+use geoarrow::io::crs::{CRSTransform, DefaultCRSTransform};
+use geoarrow::io::flatgeobuf::{write_flatgeobuf_with_options, FlatGeobufWriterOptions};
+use geoarrow::io::geojson::{write_geojson_with_options, GeoJsonWriterOptions};
+#[cfg(feature = "parquet")]
+use geoarrow::io::parquet::{write_geoparquet as write_geoparquet_stream, GeoParquetWriterOptions};
+use geoarrow::schema::GeoSchemaExt;
+use geoarrow::table::Table;
+
+use crate::error::GeoDataFusionResult;
+use crate::metadata::{collect_with_metadata, geometry_schema};
+
+/// Collect the output of a [`DataFrame`] into a [`Table`] whose geometry columns carry the
+/// metadata of the plan.
+async fn collect_table(df: DataFrame) -> GeoDataFusionResult<Table> {
+    let schema = geometry_schema(&df)?;
+    let batches = collect_with_metadata(df).await?;
+    Ok(Table::try_new(batches, schema)?)
+}
+
+/// Check that the output has a geometry column, and only one unless `allow_multiple`, for formats
+/// with one geometry per feature.
+fn check_geometry_columns(table: &Table, format: &str, allow_multiple: bool) -> Result<()> {
+    match table.schema().as_ref().geometry_columns().len() {
+        0 => plan_err!("{format} output requires a geometry column"),
+        n if n > 1 && !allow_multiple => {
+            plan_err!("{format} output requires a single geometry column, found {n}")
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The CRS that GeoJSON coordinates are in.
+fn wgs84() -> ArrayMetadata {
+    ArrayMetadata::from_authority_code("EPSG:4326".to_string())
+}
+
+/// Transform the geometry column of a table to WGS 84, unless it already is. It is an error if the
+/// column has no CRS.
+fn to_wgs84(table: Table) -> GeoDataFusionResult<Table> {
+    let index = table.schema().as_ref().geometry_columns()[0];
+    let metadata = ArrayMetadata::try_from(table.schema().field(index))?;
+    if metadata.crs.is_none() {
+        return Err(DataFusionError::Plan(
+            "GeoJSON output must be in WGS 84, but the geometry column has no CRS. \
+            Use ST_SetSRID to assign one."
+                .to_string(),
+        )
+        .into());
+    }
+    if metadata.crs_equivalent_to(&wgs84(), CrsStrictness::default()) {
+        return Ok(table);
+    }
+
+    #[cfg(feature = "proj")]
+    {
+        Ok(table.to_crs(&wgs84())?)
+    }
+    #[cfg(not(feature = "proj"))]
+    {
+        Err(DataFusionError::Plan(format!(
+            "GeoJSON output must be in WGS 84, but the geometry column has CRS {}. \
+            Enable the `proj` feature to reproject it.",
+            metadata.crs.unwrap()
+        ))
+        .into())
+    }
+}
+
+async fn write_geojson_impl<W: Write>(
+    df: DataFrame,
+    writer: W,
+    options: GeoJsonWriterOptions,
+) -> GeoDataFusionResult<()> {
+    let table = collect_table(df).await?;
+    check_geometry_columns(&table, "GeoJSON", false)?;
+    let table = to_wgs84(table)?;
+    write_geojson_with_options(table, writer, options)?;
+    Ok(())
+}
+
+/// Write the output of a [`DataFrame`] as a GeoJSON feature collection.
+///
+/// The output must have exactly one geometry column. Geometries in a CRS other than WGS 84 are
+/// reprojected to it if the `proj` feature is enabled, and are an error otherwise. Geometries
+/// without a CRS are an error.
+pub async fn write_geojson<W: Write>(
+    df: DataFrame,
+    writer: W,
+    options: GeoJsonWriterOptions,
+) -> Result<()> {
+    Ok(write_geojson_impl(df, writer, options).await?)
+}
+
+async fn write_flatgeobuf_impl<W: Write>(
+    df: DataFrame,
+    writer: W,
+    name: &str,
+    options: FlatGeobufWriterOptions,
+) -> GeoDataFusionResult<()> {
+    let table = collect_table(df).await?;
+    check_geometry_columns(&table, "FlatGeobuf", false)?;
+    write_flatgeobuf_with_options(table, writer, name, options)?;
+    Ok(())
+}
+
+/// Write the output of a [`DataFrame`] to a FlatGeobuf file.
+///
+/// The output must have exactly one geometry column, whose CRS is written to the header of the
+/// file. If it has no CRS, none is written. `name` is the layer name of the file.
+pub async fn write_flatgeobuf<W: Write>(
+    df: DataFrame,
+    writer: W,
+    name: &str,
+    options: FlatGeobufWriterOptions,
+) -> Result<()> {
+    Ok(write_flatgeobuf_impl(df, writer, name, options).await?)
+}
+
+/// Check that the CRS of every geometry column can be written as PROJJSON, since GeoParquet
+/// readers take a column without a CRS to be in WGS 84. Columns already in WGS 84 may be written
+/// without one.
+#[cfg(feature = "parquet")]
+fn check_projjson(table: &Table, options: &GeoParquetWriterOptions) -> GeoDataFusionResult<()> {
+    let schema = table.schema();
+    for index in schema.as_ref().geometry_columns() {
+        let field = schema.field(index);
+        let metadata = ArrayMetadata::try_from(field)?;
+        let projjson = match &options.crs_transform {
+            Some(crs_transform) => crs_transform.extract_projjson(&metadata)?,
+            None => DefaultCRSTransform::default().extract_projjson(&metadata)?,
+        };
+        let is_wgs84 = metadata.crs.is_some()
+            && metadata.crs_equivalent_to(&wgs84(), CrsStrictness::IgnoreAxisOrder);
+        if projjson.is_none() && !is_wgs84 {
+            let reason = if metadata.crs.is_none() {
+                "it has no CRS. Use ST_SetSRID to assign one"
+            } else {
+                "its CRS can't be converted to PROJJSON. Set a CRS transform in the writer options"
+            };
+            return Err(DataFusionError::Plan(format!(
+                "GeoParquet readers would take geometry column {} to be in WGS 84, but {reason}.",
+                field.name()
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "parquet")]
+async fn write_geoparquet_impl<W: Write + Send>(
+    df: DataFrame,
+    writer: W,
+    options: &GeoParquetWriterOptions,
+) -> GeoDataFusionResult<()> {
+    let table = collect_table(df).await?;
+    check_geometry_columns(&table, "GeoParquet", true)?;
+    check_projjson(&table, options)?;
+    write_geoparquet_stream(table.into_record_batch_reader(), writer, options)?;
+    Ok(())
+}
+
+/// Write the output of a [`DataFrame`] to a GeoParquet file.
+///
+/// The CRS of every geometry column is written to the GeoParquet metadata as PROJJSON, along with
+/// the geometry types found in the data. It is an error if a geometry column has no CRS, or a CRS
+/// other than WGS 84 that the [`CRSTransform`] of `options` can't convert to PROJJSON.
+#[cfg(feature = "parquet")]
+pub async fn write_geoparquet<W: Write + Send>(
+    df: DataFrame,
+    writer: W,
+    options: &GeoParquetWriterOptions,
+) -> Result<()> {
+    Ok(write_geoparquet_impl(df, writer, options).await?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow_array::{RecordBatch, RecordBatchReader};
+    use arrow_schema::Schema;
+    use datafusion::prelude::SessionContext;
+    use geoarrow::algorithm::native::kernel::iter_geometries;
+    use geoarrow::array::PointArray;
+    use geoarrow::datatypes::Dimension;
+    use geoarrow::io::flatgeobuf::FlatGeobufReaderBuilder;
+    use geoarrow::io::geo::geometry_to_geo;
+    use geoarrow::io::geojson::read_geojson;
+    use geoarrow::{ArrayBase, NativeArray};
+
+    use super::*;
+    use crate::udf::native::register_native;
+
+    fn create_context(crs: &str) -> Result<SessionContext> {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let points: PointArray = (
+            vec![geo::point!(x: 1., y: 2.), geo::point!(x: 3., y: 4.)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let points = points.with_metadata(Arc::new(ArrayMetadata::from_authority_code(
+            crs.to_string(),
+        )));
+        let schema = Schema::new(vec![points.extension_field()]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![points.to_array_ref()])?;
+        ctx.register_batch("t", batch)?;
+        Ok(ctx)
+    }
+
+    #[tokio::test]
+    async fn geojson() -> Result<()> {
+        let ctx = create_context("OGC:CRS84")?;
+        let df = ctx
+            .sql("SELECT ST_Centroid(geometry) AS geometry FROM t")
+            .await?;
+        let mut output = vec![];
+        write_geojson(df, &mut output, Default::default()).await?;
+
+        let table = read_geojson(output.as_slice(), None).unwrap();
+        let geometries = table
+            .geometry_column(None)
+            .unwrap()
+            .geometry_chunks()
+            .iter()
+            .flat_map(|chunk| {
+                iter_geometries(chunk.as_ref())
+                    .map(|geom| geom.map(|geom| geometry_to_geo(&geom)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            geometries,
+            vec![
+                Some(geo::Geometry::Point(geo::point!(x: 1., y: 2.))),
+                Some(geo::Geometry::Point(geo::point!(x: 3., y: 4.))),
+            ]
+        );
+
+        let df = ctx.sql("SELECT 1 AS id").await?;
+        assert!(write_geojson(df, vec![], Default::default()).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn geojson_requires_crs() -> Result<()> {
+        let ctx = create_context("OGC:CRS84")?;
+        let df = ctx
+            .sql("SELECT ST_SetSRID(geometry, 0) AS geometry FROM t")
+            .await?;
+        let err = write_geojson(df, vec![], Default::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("no CRS"), "{err}");
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn geoparquet_requires_crs() -> Result<()> {
+        let ctx = create_context("OGC:CRS84")?;
+        let df = ctx
+            .sql("SELECT ST_SetSRID(geometry, 0) AS geometry FROM t")
+            .await?;
+        let err = write_geoparquet(df, vec![], &Default::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("no CRS"), "{err}");
+        Ok(())
+    }
+
+    #[cfg(not(feature = "proj"))]
+    #[tokio::test]
+    async fn geojson_requires_wgs84() -> Result<()> {
+        let ctx = create_context("EPSG:3857")?;
+        let df = ctx
+            .sql("SELECT ST_Centroid(geometry) AS geometry FROM t")
+            .await?;
+        assert!(write_geojson(df, vec![], Default::default()).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flatgeobuf_crs() -> Result<()> {
+        let ctx = create_context("EPSG:3857")?;
+        let df = ctx
+            .sql("SELECT ST_Centroid(geometry) AS geometry FROM t")
+            .await?;
+        let mut output = vec![];
+        write_flatgeobuf(df, &mut output, "centroids", Default::default()).await?;
+
+        let reader = FlatGeobufReaderBuilder::open(Cursor::new(output))
+            .unwrap()
+            .read(Default::default())
+            .unwrap();
+        let schema = reader.schema();
+        let field = schema.field(schema.as_ref().geometry_columns()[0]);
+        let metadata = ArrayMetadata::try_from(field).unwrap();
+        assert_eq!(metadata.to_epsg(100), Some(3857));
+        Ok(())
+    }
+}
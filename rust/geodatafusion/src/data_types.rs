@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use arrow_array::ArrayRef;
+use arrow_schema::DataType;
 use datafusion::error::DataFusionError;
-use datafusion::logical_expr::{Signature, Volatility};
+use datafusion::logical_expr::{Signature, TypeSignature, Volatility};
 use geoarrow::array::{CoordType, GeometryArray, PointArray, RectArray};
 use geoarrow::datatypes::{Dimension, NativeType};
 use geoarrow::NativeArray;
@@ -15,20 +16,52 @@ pub const BOX2D_TYPE: NativeType = NativeType::Rect(Dimension::XY);
 pub const BOX3D_TYPE: NativeType = NativeType::Rect(Dimension::XYZ);
 pub const GEOMETRY_TYPE: NativeType = NativeType::Geometry(CoordType::Separated);
 
+/// All geometry types accepted and produced by geodatafusion UDFs.
+pub(crate) const GEOMETRY_TYPES: [NativeType; 5] = [
+    POINT2D_TYPE,
+    POINT3D_TYPE,
+    BOX2D_TYPE,
+    BOX3D_TYPE,
+    GEOMETRY_TYPE,
+];
+
 pub(crate) fn any_single_geometry_type_input() -> Signature {
     Signature::uniform(
         1,
-        vec![
-            POINT2D_TYPE.into(),
-            POINT3D_TYPE.into(),
-            BOX2D_TYPE.into(),
-            BOX3D_TYPE.into(),
-            GEOMETRY_TYPE.into(),
-        ],
+        GEOMETRY_TYPES.iter().map(|t| t.to_data_type()).collect(),
         Volatility::Immutable,
     )
 }
 
+/// A signature accepting any pair of geometry types, followed by arguments of the `trailing`
+/// types.
+pub(crate) fn any_geometry_pair_input(trailing: &[DataType]) -> Signature {
+    let signatures = GEOMETRY_TYPES
+        .iter()
+        .flat_map(|left| {
+            GEOMETRY_TYPES.iter().map(move |right| {
+                let mut types = vec![left.to_data_type(), right.to_data_type()];
+                types.extend_from_slice(trailing);
+                TypeSignature::Exact(types)
+            })
+        })
+        .collect();
+    Signature::one_of(signatures, Volatility::Immutable)
+}
+
+/// Returns the geometry type matching this [DataType], if it is one of the geometry types
+/// produced by geodatafusion UDFs.
+pub(crate) fn geometry_type(data_type: &DataType) -> Option<NativeType> {
+    GEOMETRY_TYPES
+        .into_iter()
+        .find(|geo_type| data_type.equals_datatype(&geo_type.to_data_type()))
+}
+
+/// Returns `true` if this [DataType] is one of the geometry types produced by geodatafusion UDFs.
+pub(crate) fn is_geometry_type(data_type: &DataType) -> bool {
+    geometry_type(data_type).is_some()
+}
+
 /// This will not cast a PointArray to a GeometryArray
 pub(crate) fn parse_to_native_array(array: ArrayRef) -> GeoDataFusionResult<Arc<dyn NativeArray>> {
     let data_type = array.data_type();
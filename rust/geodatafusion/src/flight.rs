@@ -0,0 +1,186 @@
+//! Helpers for serving geodatafusion query results over [Arrow Flight](arrow_flight).
+//!
+//! The data types returned by geodatafusion UDFs are plain Arrow types, so a Flight client can't
+//! tell a geometry column apart from any other struct, union or binary column. These helpers tag
+//! each geometry column with its GeoArrow extension type and [metadata](crate::metadata), and
+//! apply the [geometry output encoding](crate::output) of the session or query.
+//!
+//! See `examples/flight_sql_server.rs` for a minimal Flight SQL service built on these helpers.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_flight::encode::{FlightDataEncoder, FlightDataEncoderBuilder};
+use arrow_flight::error::FlightError;
+use arrow_schema::{Field, Schema, SchemaRef};
+use datafusion::dataframe::DataFrame;
+use datafusion::error::Result;
+use datafusion::execution::SendableRecordBatchStream;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use futures::{StreamExt, TryStreamExt};
+use geoarrow::array::metadata::ArrayMetadata;
+use geoarrow::datatypes::{NativeType, SerializedType};
+
+use crate::data_types::geometry_type;
+use crate::metadata::geometry_metadata;
+use crate::output::{cast_geometry_output, resolve_encoding, GeometryOutputEncoding};
+
+/// Add GeoArrow extension metadata to a field holding geometries of `native_type`.
+fn tag_field(
+    field: &Field,
+    native_type: NativeType,
+    array_metadata: &ArrayMetadata,
+    encoding: GeometryOutputEncoding,
+) -> Field {
+    let extension_field = match encoding {
+        GeometryOutputEncoding::Native => {
+            native_type.to_field_with_metadata(field.name(), field.is_nullable(), array_metadata)
+        }
+        GeometryOutputEncoding::Wkb => SerializedType::WKB.to_field_with_metadata(
+            field.name(),
+            field.is_nullable(),
+            array_metadata,
+        ),
+    };
+    let mut metadata = field.metadata().clone();
+    metadata.extend(extension_field.metadata().clone());
+    field.clone().with_metadata(metadata)
+}
+
+/// Tag the geometry fields of `output`, using the field types of the matching input schema.
+///
+/// `input` must be the schema before the geometry output was encoded, so that WKB-encoded columns
+/// can be told apart from other binary columns. `input_metadata` is the GeoArrow metadata of each
+/// input field, such as its CRS.
+fn tag_geometry_fields(
+    input: &Schema,
+    input_metadata: Vec<Option<ArrayMetadata>>,
+    output: &Schema,
+    encoding: GeometryOutputEncoding,
+) -> Schema {
+    let fields = input
+        .fields()
+        .iter()
+        .zip(input_metadata)
+        .zip(output.fields())
+        .map(|((input_field, array_metadata), output_field)| {
+            match geometry_type(input_field.data_type()) {
+                Some(native_type) => Arc::new(tag_field(
+                    output_field,
+                    native_type,
+                    &array_metadata.unwrap_or_default(),
+                    encoding,
+                )),
+                None => output_field.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+    Schema::new_with_metadata(fields, output.metadata().clone())
+}
+
+/// The schema that [`execute_geometry_stream`] will produce for this [`DataFrame`].
+///
+/// Use this to answer Flight `GetFlightInfo` requests without executing the query.
+pub fn geometry_output_schema(
+    df: &DataFrame,
+    encoding: Option<GeometryOutputEncoding>,
+) -> Result<SchemaRef> {
+    let encoding = resolve_encoding(df, encoding)?;
+    let output = cast_geometry_output(df.clone(), Some(encoding))?;
+    Ok(Arc::new(tag_geometry_fields(
+        df.schema().as_arrow(),
+        geometry_metadata(df.logical_plan())?,
+        output.schema().as_arrow(),
+        encoding,
+    )))
+}
+
+/// Execute a [`DataFrame`], returning batches whose geometry columns are encoded with `encoding`
+/// and carry GeoArrow extension metadata.
+///
+/// If `encoding` is `None`, the encoding configured for the session is used.
+pub async fn execute_geometry_stream(
+    df: DataFrame,
+    encoding: Option<GeometryOutputEncoding>,
+) -> Result<SendableRecordBatchStream> {
+    let schema = geometry_output_schema(&df, encoding)?;
+    let encoding = resolve_encoding(&df, encoding)?;
+    let stream = cast_geometry_output(df, Some(encoding))?
+        .execute_stream()
+        .await?;
+
+    let batch_schema = schema.clone();
+    let stream = stream
+        .map(move |batch| -> Result<RecordBatch> { Ok(batch?.with_schema(batch_schema.clone())?) });
+    Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
+}
+
+/// Encode a stream of record batches as Flight data, e.g. for a `DoGet` response.
+///
+/// The schema message is always sent first, so that clients receive the geometry extension
+/// metadata even if the query returns no rows.
+pub fn flight_data_stream(stream: SendableRecordBatchStream) -> FlightDataEncoder {
+    let schema = stream.schema();
+    FlightDataEncoderBuilder::new()
+        .with_schema(schema)
+        .build(stream.map_err(|err| FlightError::ExternalError(Box::new(err))))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_flight::decode::FlightRecordBatchStream;
+    use datafusion::prelude::{SessionConfig, SessionContext};
+
+    use super::*;
+    use crate::output::GeoDataFusionOptions;
+    use crate::udf::native::register_native;
+
+    fn create_context() -> SessionContext {
+        let config = SessionConfig::new().with_option_extension(GeoDataFusionOptions::default());
+        let ctx = SessionContext::new_with_config(config);
+        register_native(&ctx);
+        ctx
+    }
+
+    #[tokio::test]
+    async fn output_schema_is_tagged() -> Result<()> {
+        let ctx = create_context();
+        let df = ctx
+            .sql("SELECT ST_Point(1.0, 2.0) AS geometry, 1 AS id")
+            .await?;
+
+        let schema = geometry_output_schema(&df, Some(GeometryOutputEncoding::Native))?;
+        assert_eq!(
+            schema.field(0).metadata()["ARROW:extension:name"],
+            "geoarrow.point"
+        );
+        assert!(schema.field(1).metadata().is_empty());
+
+        let schema = geometry_output_schema(&df, Some(GeometryOutputEncoding::Wkb))?;
+        assert_eq!(
+            schema.field(0).metadata()["ARROW:extension:name"],
+            "geoarrow.wkb"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flight_round_trip() -> Result<()> {
+        let ctx = create_context();
+        let df = ctx.sql("SELECT ST_Point(1.0, 2.0) AS geometry").await?;
+
+        let stream = execute_geometry_stream(df, Some(GeometryOutputEncoding::Wkb)).await?;
+        let flight_data = flight_data_stream(stream);
+        let batches = FlightRecordBatchStream::new_from_flight_data(flight_data)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(batches[0].num_rows(), 1);
+        assert_eq!(
+            batches[0].schema().field(0).metadata()["ARROW:extension:name"],
+            "geoarrow.wkb"
+        );
+        Ok(())
+    }
+}
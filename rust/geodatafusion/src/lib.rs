@@ -1,3 +1,8 @@
+pub mod copy;
 pub(crate) mod data_types;
 pub(crate) mod error;
+#[cfg(feature = "flight-sql")]
+pub mod flight;
+pub mod metadata;
+pub mod output;
 pub mod udf;
@@ -0,0 +1,515 @@
+//! Propagate GeoArrow extension metadata, such as the CRS, through query plans.
+//!
+//! DataFusion keeps the metadata of fields that are passed through a plan unchanged, such as
+//! projected, filtered or joined columns, but the output of a UDF is a new field without
+//! metadata. So `SELECT ST_Centroid(geometry) FROM t` returns a geometry column whose CRS is lost.
+//!
+//! [`geometry_metadata`] recovers the metadata of each output column of a [`LogicalPlan`]: the
+//! output of a UDF that returns geometries inherits the metadata of its first geometry argument.
+//! [`geometry_schema`] and [`collect_with_metadata`] attach it to the query output. This is opt-in:
+//! the batches returned by [`DataFrame::collect`] don't carry the metadata of UDF outputs.
+//!
+//! The same analysis lets [`CrsAnalyzerRule`] resolve `ST_SRID` and `ST_CRS` at planning time, and
+//! reject calls that combine geometries with different CRSs.
+//!
+//! ```ignore
+//! use geodatafusion::metadata::collect_with_metadata;
+//!
+//! let df = ctx.sql("SELECT ST_Centroid(geometry) AS centroid FROM t").await?;
+//! let batches = collect_with_metadata(df).await?;
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::DataType;
+use arrow_schema::{Field, Schema, SchemaRef};
+use datafusion::common::tree_node::{Transformed, TreeNode};
+use datafusion::common::{plan_err, Column, DFSchema};
+use datafusion::config::ConfigOptions;
+use datafusion::dataframe::DataFrame;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::expr::ScalarFunction;
+use datafusion::logical_expr::expr_rewriter::NamePreserver;
+use datafusion::logical_expr::{lit, Expr, ExprSchemable, LogicalPlan};
+use datafusion::optimizer::AnalyzerRule;
+use datafusion::scalar::ScalarValue;
+use geoarrow::array::metadata::{ArrayMetadata, CrsStrictness};
+use geoarrow::datatypes::SerializedType;
+
+use crate::data_types::{geometry_type, is_geometry_type};
+
+const EXTENSION_METADATA: &str = "ARROW:extension:metadata";
+
+/// The UDFs that return geometries encoded as Well-Known Binary.
+const WKB_OUTPUT_UDFS: &[&str] = &["st_asbinary"];
+
+/// The GeoArrow metadata stored on a field, if any.
+fn field_metadata(field: &Field) -> Result<Option<ArrayMetadata>> {
+    if field.metadata().contains_key(EXTENSION_METADATA) {
+        let metadata = ArrayMetadata::try_from(field)
+            .map_err(|err| DataFusionError::External(Box::new(err)))?;
+        Ok(Some(metadata))
+    } else {
+        Ok(None)
+    }
+}
+
+/// The integer value of a literal, possibly cast to another type.
+fn literal_i64(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Literal(value) => match value.cast_to(&DataType::Int64).ok()? {
+            ScalarValue::Int64(value) => value,
+            _ => None,
+        },
+        Expr::Cast(cast) => literal_i64(&cast.expr),
+        _ => None,
+    }
+}
+
+/// The inputs of a plan node, which its expressions are evaluated against, with the GeoArrow
+/// metadata of each of their fields.
+///
+/// Nodes with more than one input, such as joins, evaluate expressions against the fields of all
+/// their inputs.
+pub(crate) struct PlanInputs<'a> {
+    inputs: Vec<(&'a LogicalPlan, Vec<Option<ArrayMetadata>>)>,
+
+    /// The fields of all inputs, to resolve the types of expressions.
+    schema: DFSchema,
+}
+
+impl<'a> PlanInputs<'a> {
+    pub(crate) fn try_new(inputs: impl IntoIterator<Item = &'a LogicalPlan>) -> Result<Self> {
+        let inputs = inputs
+            .into_iter()
+            .map(|input| Ok((input, geometry_metadata(input)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let mut schema = DFSchema::empty();
+        for (input, _) in &inputs {
+            schema.merge(input.schema());
+        }
+        Ok(Self { inputs, schema })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+
+    /// The metadata of each field of the first input.
+    fn first(&self) -> &[Option<ArrayMetadata>] {
+        &self.inputs[0].1
+    }
+
+    /// The metadata of a column of any input.
+    fn column_metadata(&self, column: &Column) -> Option<ArrayMetadata> {
+        self.inputs.iter().find_map(|(input, input_metadata)| {
+            input
+                .schema()
+                .maybe_index_of_column(column)
+                .and_then(|index| input_metadata[index].clone())
+        })
+    }
+
+    /// Whether `expr` returns geometries, natively or as Well-Known Binary.
+    fn returns_geometry(&self, expr: &Expr) -> bool {
+        is_wkb_output(expr)
+            || expr
+                .get_type(&self.schema)
+                .is_ok_and(|data_type| is_geometry_type(&data_type))
+    }
+}
+
+/// The metadata set by `ST_SetSRID(geom, srid)`, or `None` if the SRID is not a literal.
+fn set_srid_metadata(function: &ScalarFunction, inputs: &PlanInputs) -> Option<ArrayMetadata> {
+    let srid = literal_i64(function.args.get(1)?)?;
+    // Other keys, such as the edges, are kept.
+    let mut metadata = expr_metadata(&function.args[0], inputs).unwrap_or_default();
+    if srid == 0 {
+        metadata.crs = None;
+        metadata.crs_type = None;
+    } else {
+        metadata = metadata.with_authority_code(format!("EPSG:{srid}"));
+    }
+    Some(metadata)
+}
+
+/// The GeoArrow metadata of the output of `expr`, evaluated against `inputs`.
+pub(crate) fn expr_metadata(expr: &Expr, inputs: &PlanInputs) -> Option<ArrayMetadata> {
+    match expr {
+        Expr::Column(column) => inputs.column_metadata(column),
+        Expr::Alias(alias) => expr_metadata(&alias.expr, inputs),
+        Expr::Cast(cast) => expr_metadata(&cast.expr, inputs),
+        Expr::TryCast(cast) => expr_metadata(&cast.expr, inputs),
+        Expr::ScalarFunction(function) if function.name() == "st_setsrid" => {
+            set_srid_metadata(function, inputs)
+        }
+        // Other outputs, such as coordinates or areas, have no CRS.
+        Expr::ScalarFunction(_) if !inputs.returns_geometry(expr) => None,
+        Expr::ScalarFunction(function) => function
+            .args
+            .iter()
+            .find_map(|arg| expr_metadata(arg, inputs)),
+        _ => None,
+    }
+}
+
+/// The metadata of each output field of a node that passes its input columns through, matched by
+/// qualified name.
+fn passthrough_metadata(plan: &LogicalPlan, inputs: &PlanInputs) -> Vec<Option<ArrayMetadata>> {
+    plan.schema()
+        .iter()
+        .map(|(qualifier, field)| {
+            inputs.column_metadata(&Column::new(qualifier.cloned(), field.name()))
+        })
+        .collect()
+}
+
+/// The GeoArrow metadata of each output field of a logical plan.
+///
+/// Metadata already stored on an output field is used as is. Otherwise it is derived from the
+/// plan's inputs: columns keep their metadata through projections, aliases, joins and unions, and
+/// the output of a UDF that returns geometries inherits the metadata of its first geometry
+/// argument. The output of other UDFs has no metadata. This is returned for every field; only
+/// attach it to geometry fields.
+pub fn geometry_metadata(plan: &LogicalPlan) -> Result<Vec<Option<ArrayMetadata>>> {
+    let inputs = PlanInputs::try_new(plan.inputs())?;
+
+    let derived = match plan {
+        LogicalPlan::Projection(projection) => projection
+            .expr
+            .iter()
+            .map(|expr| expr_metadata(expr, &inputs))
+            .collect(),
+        LogicalPlan::Aggregate(aggregate) => aggregate
+            .group_expr
+            .iter()
+            .chain(aggregate.aggr_expr.iter())
+            .map(|expr| expr_metadata(expr, &inputs))
+            .collect(),
+        LogicalPlan::Window(window) => inputs
+            .first()
+            .iter()
+            .cloned()
+            .chain(
+                window
+                    .window_expr
+                    .iter()
+                    .map(|expr| expr_metadata(expr, &inputs)),
+            )
+            .collect(),
+        // These nodes output the columns of their first input, by position.
+        LogicalPlan::SubqueryAlias(_) | LogicalPlan::Union(_) => inputs.first().to_vec(),
+        _ => passthrough_metadata(plan, &inputs),
+    };
+
+    plan.schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match field_metadata(field)? {
+            Some(metadata) => Ok(Some(metadata)),
+            None => Ok(derived.get(index).cloned().flatten()),
+        })
+        .collect()
+}
+
+/// Whether `expr` is a call to a UDF that returns Well-Known Binary geometries.
+fn is_wkb_output(expr: &Expr) -> bool {
+    match expr {
+        Expr::Alias(alias) => is_wkb_output(&alias.expr),
+        Expr::ScalarFunction(function) => WKB_OUTPUT_UDFS.contains(&function.name()),
+        _ => false,
+    }
+}
+
+/// The output schema of a [`DataFrame`], with GeoArrow extension types and metadata attached to
+/// its geometry columns.
+pub fn geometry_schema(df: &DataFrame) -> Result<SchemaRef> {
+    let plan = df.logical_plan();
+    let metadata = geometry_metadata(plan)?;
+    let wkb_columns = match plan {
+        LogicalPlan::Projection(projection) => projection.expr.iter().map(is_wkb_output).collect(),
+        _ => vec![false; metadata.len()],
+    };
+
+    let schema = df.schema().as_arrow();
+    let fields = schema
+        .fields()
+        .iter()
+        .zip(metadata)
+        .zip(wkb_columns)
+        .map(|((field, metadata), is_wkb)| {
+            let extension_field = match (geometry_type(field.data_type()), is_wkb) {
+                (Some(native_type), _) => native_type.to_field_with_metadata(
+                    field.name(),
+                    field.is_nullable(),
+                    &metadata.unwrap_or_default(),
+                ),
+                (None, true) => SerializedType::WKB.to_field_with_metadata(
+                    field.name(),
+                    field.is_nullable(),
+                    &metadata.unwrap_or_default(),
+                ),
+                (None, false) => return field.clone(),
+            };
+            let mut field_metadata = field.metadata().clone();
+            field_metadata.extend(extension_field.metadata().clone());
+            Arc::new(field.as_ref().clone().with_metadata(field_metadata))
+        })
+        .collect::<Vec<_>>();
+    Ok(Arc::new(Schema::new_with_metadata(
+        fields,
+        schema.metadata().clone(),
+    )))
+}
+
+/// Execute a [`DataFrame`] and collect its output, with GeoArrow extension types and metadata
+/// attached to its geometry columns.
+///
+/// Use this instead of [`DataFrame::collect`], whose output only keeps the metadata of columns
+/// that are passed through unchanged.
+pub async fn collect_with_metadata(df: DataFrame) -> Result<Vec<RecordBatch>> {
+    let schema = geometry_schema(&df)?;
+    df.collect()
+        .await?
+        .into_iter()
+        .map(|batch| Ok(batch.with_schema(schema.clone())?))
+        .collect()
+}
+
+/// An [`AnalyzerRule`] that resolves the CRS of the inputs of spatial functions.
+///
+/// `ST_SRID(geom)` and `ST_CRS(geom)` are rewritten to take the SRID or CRS of `geom` as a
+/// literal second argument, and calls to other spatial functions whose geometry arguments have
+/// different CRSs are rejected with a planning error, as their coordinates can't be combined.
+/// Arguments without CRS are not checked. This applies to the expressions of every node with
+/// inputs, including the join conditions and filters of joins, whose arguments may come from
+/// either side.
+///
+/// This is registered by [`register_native`](crate::udf::native::register_native).
+#[derive(Debug, Default)]
+pub struct CrsAnalyzerRule {}
+
+impl CrsAnalyzerRule {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn rewrite_expr(&self, expr: Expr, inputs: &PlanInputs) -> Result<Transformed<Expr>> {
+        let Expr::ScalarFunction(mut function) = expr else {
+            return Ok(Transformed::no(expr));
+        };
+        let name = function.name().to_string();
+
+        match name.as_str() {
+            "st_srid" | "st_crs" if function.args.len() == 1 => {
+                let metadata = expr_metadata(&function.args[0], inputs);
+                let value = if name == "st_srid" {
+                    lit(metadata.and_then(|metadata| metadata.srid()).unwrap_or(0))
+                } else {
+                    // Authority codes and WKT are strings, PROJJSON is serialized.
+                    let crs = metadata.and_then(|metadata| metadata.crs).map(|crs| {
+                        crs.as_str()
+                            .map(str::to_string)
+                            .unwrap_or_else(|| crs.to_string())
+                    });
+                    lit(ScalarValue::Utf8(crs))
+                };
+                function.args.push(value);
+                Ok(Transformed::yes(Expr::ScalarFunction(function)))
+            }
+            // The SRID argument of ST_SetSRID doesn't carry a CRS.
+            "st_setsrid" => Ok(Transformed::no(Expr::ScalarFunction(function))),
+            name if name.starts_with("st_") => {
+                let mut crs_args = function
+                    .args
+                    .iter()
+                    .filter_map(|arg| expr_metadata(arg, inputs))
+                    .filter(|metadata| metadata.crs.is_some());
+                if let Some(first) = crs_args.next() {
+                    if let Some(other) = crs_args.find(|metadata| {
+                        !first.crs_equivalent_to(metadata, CrsStrictness::IgnoreAxisOrder)
+                    }) {
+                        return plan_err!(
+                            "{} was called on geometries with different CRSs ({} and {}). Use ST_SetSRID to assign a common CRS to its inputs.",
+                            name.to_uppercase(),
+                            describe_crs(&first),
+                            describe_crs(&other),
+                        );
+                    }
+                }
+                Ok(Transformed::no(Expr::ScalarFunction(function)))
+            }
+            _ => Ok(Transformed::no(Expr::ScalarFunction(function))),
+        }
+    }
+}
+
+/// A short description of a CRS for error messages: its EPSG code if known.
+fn describe_crs(metadata: &ArrayMetadata) -> String {
+    match metadata.srid() {
+        Some(srid) => format!("SRID {srid}"),
+        None => "a CRS without SRID".to_string(),
+    }
+}
+
+impl AnalyzerRule for CrsAnalyzerRule {
+    fn analyze(&self, plan: LogicalPlan, _config: &ConfigOptions) -> Result<LogicalPlan> {
+        plan.transform_up(|plan| {
+            let plan_inputs = plan.inputs().into_iter().cloned().collect::<Vec<_>>();
+            let inputs = PlanInputs::try_new(&plan_inputs)?;
+            // The expressions of leaf nodes, such as pushed down filters, have no inputs to
+            // resolve against.
+            if inputs.is_empty() {
+                return Ok(Transformed::no(plan));
+            }
+
+            let name_preserver = NamePreserver::new(&plan);
+            plan.map_expressions(|expr| {
+                let original_name = name_preserver.save(&expr);
+                let transformed = expr.transform_up(|expr| self.rewrite_expr(expr, &inputs))?;
+                Ok(transformed.update_data(|expr| original_name.restore(expr)))
+            })
+        })
+        .map(|transformed| transformed.data)
+    }
+
+    fn name(&self) -> &str {
+        "geodatafusion_crs"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::RecordBatch;
+    use datafusion::prelude::SessionContext;
+    use geoarrow::array::{CoordType, PointArray};
+    use geoarrow::datatypes::Dimension;
+    use geoarrow::{ArrayBase, NativeArray};
+
+    use super::*;
+    use crate::udf::native::register_native;
+
+    fn crs() -> ArrayMetadata {
+        ArrayMetadata::from_authority_code("EPSG:4326".to_string())
+    }
+
+    fn create_context() -> Result<SessionContext> {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let points: PointArray = (
+            vec![geo::point!(x: 1., y: 2.), geo::point!(x: 3., y: 4.)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let points = points
+            .into_coord_type(CoordType::Separated)
+            .with_metadata(Arc::new(crs()));
+        let schema = Schema::new(vec![points.extension_field()]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![points.to_array_ref()])?;
+        ctx.register_batch("t", batch)?;
+        Ok(ctx)
+    }
+
+    #[tokio::test]
+    async fn udf_output_keeps_crs() -> Result<()> {
+        let ctx = create_context()?;
+        let df = ctx
+            .sql(
+                "SELECT ST_Centroid(geometry) AS centroid, ST_AsBinary(geometry) AS wkb \
+                FROM (SELECT geometry FROM t) AS sub",
+            )
+            .await?;
+
+        let batches = collect_with_metadata(df).await?;
+        let schema = batches[0].schema();
+        for (field, extension_name) in schema
+            .fields()
+            .iter()
+            .zip(["geoarrow.point", "geoarrow.wkb"])
+        {
+            assert_eq!(field.metadata()["ARROW:extension:name"], extension_name);
+            let metadata = ArrayMetadata::try_from(field.as_ref()).unwrap();
+            assert_eq!(metadata, crs());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn non_geometry_arguments_have_no_crs() -> Result<()> {
+        let ctx = create_context()?;
+        let df = ctx
+            .sql("SELECT ST_Point(ST_XMin(geometry), ST_YMin(geometry)) AS point FROM t")
+            .await?;
+
+        let batches = collect_with_metadata(df).await?;
+        let schema = batches[0].schema();
+        let field = schema.field(0);
+        assert_eq!(field.metadata()["ARROW:extension:name"], "geoarrow.point");
+        let metadata = ArrayMetadata::try_from(field).unwrap();
+        assert!(metadata.crs.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn srid_and_crs() -> Result<()> {
+        let ctx = create_context()?;
+        let df = ctx
+            .sql(
+                "SELECT ST_SRID(geometry) AS srid, \
+                ST_SRID(ST_SetSRID(geometry, 3857)) AS new_srid, \
+                ST_CRS(geometry) AS crs FROM t",
+            )
+            .await?;
+        let batch = &df.collect().await?[0];
+
+        let srid = batch.column(0).as_primitive::<Int32Type>();
+        assert_eq!(srid.value(0), 4326);
+        let new_srid = batch.column(1).as_primitive::<Int32Type>();
+        assert_eq!(new_srid.value(0), 3857);
+        let crs = batch.column(2).as_string::<i32>();
+        assert_eq!(crs.value(0), "EPSG:4326");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mixed_crs_error() -> Result<()> {
+        let ctx = create_context()?;
+        let result = ctx
+            .sql("SELECT ST_MakeBox2D(ST_SetSRID(geometry, 3857), geometry) FROM t")
+            .await?
+            .collect()
+            .await;
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("different CRSs"), "{err}");
+
+        // Reassigning the CRS makes the inputs compatible.
+        ctx.sql("SELECT ST_MakeBox2D(ST_SetSRID(geometry, 4326), geometry) FROM t")
+            .await?
+            .collect()
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mixed_crs_join_error() -> Result<()> {
+        let ctx = create_context()?;
+        ctx.sql("CREATE VIEW u AS SELECT ST_SetSRID(geometry, 3857) AS geometry FROM t")
+            .await?;
+        let result = ctx
+            .sql(
+                "SELECT * FROM t JOIN u \
+                ON ST_XMin(ST_MakeBox2D(t.geometry, u.geometry)) IS NOT NULL",
+            )
+            .await?
+            .collect()
+            .await;
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("different CRSs"), "{err}");
+        Ok(())
+    }
+}
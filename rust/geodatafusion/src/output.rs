@@ -0,0 +1,215 @@
+//! Control how geometry columns are encoded in query results.
+//!
+//! geodatafusion UDFs return native GeoArrow arrays. Some consumers of query results, such as BI
+//! tools connected over Flight SQL, only understand Well-Known Binary. The output encoding can be
+//! set for a whole session through [`GeoDataFusionOptions`] or overridden per query when calling
+//! [`cast_geometry_output`].
+//!
+//! ```ignore
+//! use datafusion::prelude::{SessionConfig, SessionContext};
+//! use geodatafusion::output::{cast_geometry_output, GeoDataFusionOptions};
+//!
+//! let config = SessionConfig::new().with_option_extension(GeoDataFusionOptions::default());
+//! let ctx = SessionContext::new_with_config(config);
+//! ctx.sql("SET geodatafusion.geometry_output = 'wkb'").await?;
+//!
+//! let df = ctx.sql("SELECT ST_Point(1.0, 2.0) AS geometry").await?;
+//! let df = cast_geometry_output(df, None)?;
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use datafusion::common::{extensions_options, Column};
+use datafusion::config::ConfigExtension;
+use datafusion::dataframe::DataFrame;
+use datafusion::error::{DataFusionError, Result};
+use datafusion::logical_expr::{Expr, ScalarUDF};
+use datafusion::prelude::SessionConfig;
+
+use crate::data_types::is_geometry_type;
+use crate::udf::native::io::wkb::AsBinary;
+
+/// The encoding of geometry columns in query output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GeometryOutputEncoding {
+    /// Return geometries as native GeoArrow arrays, exactly as produced by the UDFs.
+    #[default]
+    Native,
+
+    /// Return geometries as ISO Well-Known Binary.
+    Wkb,
+}
+
+impl FromStr for GeometryOutputEncoding {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "native" => Ok(Self::Native),
+            "wkb" => Ok(Self::Wkb),
+            other => Err(DataFusionError::Configuration(format!(
+                "Unknown geometry output encoding '{other}', expected 'native' or 'wkb'"
+            ))),
+        }
+    }
+}
+
+impl Display for GeometryOutputEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Native => write!(f, "native"),
+            Self::Wkb => write!(f, "wkb"),
+        }
+    }
+}
+
+extensions_options! {
+    /// Session-level configuration for geodatafusion.
+    ///
+    /// Register with [`SessionConfig::with_option_extension`]. Options can then be changed from
+    /// SQL, e.g. `SET geodatafusion.geometry_output = 'wkb'`.
+    pub struct GeoDataFusionOptions {
+        /// Encoding of geometry columns in query results, either `native` or `wkb`.
+        pub geometry_output: String, default = GeometryOutputEncoding::Native.to_string()
+    }
+}
+
+impl ConfigExtension for GeoDataFusionOptions {
+    const PREFIX: &'static str = "geodatafusion";
+}
+
+impl GeoDataFusionOptions {
+    /// The parsed geometry output encoding.
+    pub fn geometry_output_encoding(&self) -> Result<GeometryOutputEncoding> {
+        self.geometry_output.parse()
+    }
+}
+
+/// The geometry output encoding configured for this session.
+///
+/// Returns [`GeometryOutputEncoding::Native`] if [`GeoDataFusionOptions`] has not been registered
+/// on the session.
+pub fn geometry_output_encoding(config: &SessionConfig) -> Result<GeometryOutputEncoding> {
+    config
+        .options()
+        .extensions
+        .get::<GeoDataFusionOptions>()
+        .map(|options| options.geometry_output_encoding())
+        .unwrap_or(Ok(GeometryOutputEncoding::Native))
+}
+
+/// Use the given encoding, falling back to the one configured for the [`DataFrame`]'s session.
+pub(crate) fn resolve_encoding(
+    df: &DataFrame,
+    encoding: Option<GeometryOutputEncoding>,
+) -> Result<GeometryOutputEncoding> {
+    match encoding {
+        Some(encoding) => Ok(encoding),
+        None => geometry_output_encoding(df.task_ctx().session_config()),
+    }
+}
+
+/// Encode the geometry columns of a [`DataFrame`]'s output.
+///
+/// If `encoding` is `None`, the encoding configured for the session through
+/// [`GeoDataFusionOptions`] is used. Non-geometry columns are passed through unchanged and output
+/// column names are preserved.
+pub fn cast_geometry_output(
+    df: DataFrame,
+    encoding: Option<GeometryOutputEncoding>,
+) -> Result<DataFrame> {
+    match resolve_encoding(&df, encoding)? {
+        GeometryOutputEncoding::Native => Ok(df),
+        GeometryOutputEncoding::Wkb => {
+            if !df
+                .schema()
+                .fields()
+                .iter()
+                .any(|field| is_geometry_type(field.data_type()))
+            {
+                return Ok(df);
+            }
+
+            let as_binary = ScalarUDF::new_from_impl(AsBinary::new());
+            let exprs = df
+                .schema()
+                .iter()
+                .map(|(qualifier, field)| {
+                    let column = Expr::Column(Column::from((qualifier, field.as_ref())));
+                    if is_geometry_type(field.data_type()) {
+                        as_binary.call(vec![column]).alias(field.name())
+                    } else {
+                        column
+                    }
+                })
+                .collect::<Vec<_>>();
+            df.select(exprs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_schema::DataType;
+    use datafusion::prelude::SessionContext;
+
+    use super::*;
+    use crate::udf::native::register_native;
+
+    fn create_context() -> SessionContext {
+        let config = SessionConfig::new().with_option_extension(GeoDataFusionOptions::default());
+        let ctx = SessionContext::new_with_config(config);
+        register_native(&ctx);
+        ctx
+    }
+
+    #[test]
+    fn parse_encoding() {
+        assert_eq!(
+            "WKB".parse::<GeometryOutputEncoding>().unwrap(),
+            GeometryOutputEncoding::Wkb
+        );
+        assert_eq!(
+            "native".parse::<GeometryOutputEncoding>().unwrap(),
+            GeometryOutputEncoding::Native
+        );
+        assert!("geojson".parse::<GeometryOutputEncoding>().is_err());
+    }
+
+    #[tokio::test]
+    async fn session_encoding() -> Result<()> {
+        let ctx = create_context();
+        ctx.sql("SET geodatafusion.geometry_output = 'wkb'")
+            .await?
+            .collect()
+            .await?;
+
+        let df = ctx
+            .sql("SELECT ST_Point(1.0, 2.0) AS geometry, 1 AS id")
+            .await?;
+        let df = cast_geometry_output(df, None)?;
+        let schema = df.schema().clone();
+        assert_eq!(schema.field(0).name(), "geometry");
+        assert_eq!(schema.field(0).data_type(), &DataType::Binary);
+        assert_eq!(schema.field(1).data_type(), &DataType::Int64);
+
+        let batches = df.collect().await?;
+        assert_eq!(batches[0].num_rows(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn per_query_override() -> Result<()> {
+        let ctx = create_context();
+        ctx.sql("SET geodatafusion.geometry_output = 'wkb'")
+            .await?
+            .collect()
+            .await?;
+
+        let df = ctx.sql("SELECT ST_Point(1.0, 2.0) AS geometry").await?;
+        let df = cast_geometry_output(df, Some(GeometryOutputEncoding::Native))?;
+        assert!(is_geometry_type(df.schema().field(0).data_type()));
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ use geoarrow::datatypes::Dimension;
 use geoarrow::ArrayBase;
 
 use crate::data_types::{POINT2D_TYPE, POINT3D_TYPE};
+use crate::error::GeoDataFusionError;
 
 #[derive(Debug)]
 pub(super) struct Point {
@@ -211,9 +212,8 @@ impl ScalarUDFImpl for MakePoint {
             }
         }
 
-        Ok(GeometryArray::from(builder.finish())
-            .into_array_ref()
-            .into())
+        let output = GeometryArray::try_from(builder.finish()).map_err(GeoDataFusionError::from)?;
+        Ok(output.into_array_ref().into())
     }
 
     fn documentation(&self) -> Option<&Documentation> {
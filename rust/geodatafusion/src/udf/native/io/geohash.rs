@@ -1,21 +1,47 @@
 use std::any::Any;
 use std::sync::{Arc, OnceLock};
 
-use arrow::array::{AsArray, StringBuilder};
-use arrow_schema::DataType;
+use arrow::array::{AsArray, ListBuilder, StringBuilder};
+use arrow::datatypes::Int64Type;
+use arrow_schema::{DataType, Field};
+use datafusion::error::DataFusionError;
 use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
 use datafusion::logical_expr::{
-    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, TypeSignature, Volatility,
 };
+use geo::{BoundingRect, Intersects};
 use geo_traits::PointTrait;
-use geoarrow::array::{CoordType, PointArray, PointBuilder, RectBuilder};
+use geoarrow::array::{
+    AsNativeArray, CoordType, GeometryBuilder, PointArray, PointBuilder, RectBuilder,
+};
 use geoarrow::datatypes::Dimension;
 use geoarrow::trait_::{ArrayAccessor, NativeScalar};
 use geoarrow::ArrayBase;
+use geohash::Direction;
 
-use crate::data_types::{BOX2D_TYPE, POINT2D_TYPE};
+use crate::data_types::{parse_to_native_array, BOX2D_TYPE, GEOMETRY_TYPE, POINT2D_TYPE};
 use crate::error::GeoDataFusionResult;
 
+/// The maximum geohash length supported by the `geohash` crate.
+const MAX_PRECISION: i64 = 12;
+
+/// The maximum number of geohashes returned by `ST_GeoHashCoverage` for a single geometry.
+const MAX_COVERAGE_CELLS: usize = 1_000_000;
+
+fn string_list_type() -> DataType {
+    DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)))
+}
+
+fn check_precision(precision: i64) -> GeoDataFusionResult<usize> {
+    if !(1..=MAX_PRECISION).contains(&precision) {
+        return Err(DataFusionError::Execution(format!(
+            "GeoHash precision must be between 1 and {MAX_PRECISION}, got {precision}"
+        ))
+        .into());
+    }
+    Ok(precision as usize)
+}
+
 #[derive(Debug)]
 pub(super) struct Box2DFromGeoHash {
     signature: Signature,
@@ -165,7 +191,13 @@ pub(super) struct GeoHash {
 impl GeoHash {
     pub fn new() -> Self {
         Self {
-            signature: Signature::exact(vec![POINT2D_TYPE.into()], Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::Exact(vec![POINT2D_TYPE.into()]),
+                    TypeSignature::Exact(vec![POINT2D_TYPE.into(), DataType::Int64]),
+                ],
+                Volatility::Immutable,
+            ),
         }
     }
 }
@@ -198,31 +230,278 @@ impl ScalarUDFImpl for GeoHash {
             Documentation::builder(
                 DOC_SECTION_OTHER,
                 "Computes a GeoHash representation of a geometry. A GeoHash encodes a geographic Point into a text form that is sortable and searchable based on prefixing. A shorter GeoHash is a less precise representation of a point. It can be thought of as a box that contains the point.",
-                "ST_GeoHash(point)",
+                "ST_GeoHash(point, precision)",
             )
             .with_argument("geom", "geometry")
+            .with_argument(
+                "precision",
+                "integer, the number of characters of the GeoHash, between 1 and 12. Defaults to 12.",
+            )
             .build()
         }))
     }
 }
 
 fn geohash_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let point_array = PointArray::try_from((arrays[0].as_ref(), Dimension::XY))?;
+    let precision = arrays.get(1).map(|array| array.as_primitive::<Int64Type>());
+
+    let mut builder = StringBuilder::with_capacity(point_array.len(), 0);
+
+    for (i, point) in point_array.iter().enumerate() {
+        let precision = match precision {
+            Some(precision) if precision.is_null(i) => None,
+            Some(precision) => Some(check_precision(precision.value(i))?),
+            None => Some(MAX_PRECISION as usize),
+        };
+        match (point.and_then(|point| point.coord()), precision) {
+            (Some(coord), Some(precision)) => {
+                builder.append_value(geohash::encode(coord.to_geo(), precision)?)
+            }
+            _ => builder.append_null(),
+        }
+    }
+
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[derive(Debug)]
+pub(super) struct GeomFromGeoHash {
+    signature: Signature,
+}
+
+impl GeomFromGeoHash {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Utf8], Volatility::Immutable),
+        }
+    }
+}
+
+static GEOM_FROM_GEOHASH_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeomFromGeoHash {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geomfromgeohash"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(GEOMETRY_TYPE.into())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(geom_from_geohash_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOM_FROM_GEOHASH_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return a geometry from a GeoHash string. The geometry will be a polygon representing the GeoHash bounds.",
+                "ST_GeomFromGeoHash(geohash)",
+            )
+            .with_argument("text", "geohash")
+            .build()
+        }))
+    }
+}
+
+fn geom_from_geohash_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let array = ColumnarValue::values_to_arrays(args)?
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let string_array = array.as_string::<i32>();
+    let mut builder =
+        GeometryBuilder::new_with_options(CoordType::Separated, Default::default(), false);
+
+    for s in string_array.iter() {
+        if let Some(s) = s {
+            let polygon = geohash::decode_bbox(s)?.to_polygon();
+            builder.push_polygon(Some(&polygon))?;
+        } else {
+            builder.push_null();
+        }
+    }
+
+    Ok(builder.finish().into_array_ref().into())
+}
+
+#[derive(Debug)]
+pub(super) struct GeoHashNeighbor {
+    signature: Signature,
+}
+
+impl GeoHashNeighbor {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![DataType::Utf8, DataType::Utf8],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+static GEOHASH_NEIGHBOR_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeoHashNeighbor {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geohashneighbor"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(geohash_neighbor_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOHASH_NEIGHBOR_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the adjacent GeoHash of the same precision in the given direction.",
+                "ST_GeoHashNeighbor(geohash, direction)",
+            )
+            .with_argument("text", "geohash")
+            .with_argument(
+                "direction",
+                "text, one of 'n', 'ne', 'e', 'se', 's', 'sw', 'w' or 'nw'",
+            )
+            .build()
+        }))
+    }
+}
+
+fn parse_direction(direction: &str) -> GeoDataFusionResult<Direction> {
+    let direction = match direction.to_ascii_lowercase().as_str() {
+        "n" => Direction::N,
+        "ne" => Direction::NE,
+        "e" => Direction::E,
+        "se" => Direction::SE,
+        "s" => Direction::S,
+        "sw" => Direction::SW,
+        "w" => Direction::W,
+        "nw" => Direction::NW,
+        _ => {
+            return Err(DataFusionError::Execution(format!(
+                "Invalid GeoHash neighbor direction: {direction}"
+            ))
+            .into())
+        }
+    };
+    Ok(direction)
+}
+
+fn geohash_neighbor_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let hashes = arrays[0].as_string::<i32>();
+    let directions = arrays[1].as_string::<i32>();
+
+    let mut builder = StringBuilder::with_capacity(hashes.len(), 0);
+    for (hash, direction) in hashes.iter().zip(directions.iter()) {
+        if let (Some(hash), Some(direction)) = (hash, direction) {
+            builder.append_value(geohash::neighbor(hash, parse_direction(direction)?)?);
+        } else {
+            builder.append_null();
+        }
+    }
+
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[derive(Debug)]
+pub(super) struct GeoHashNeighbors {
+    signature: Signature,
+}
+
+impl GeoHashNeighbors {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Utf8], Volatility::Immutable),
+        }
+    }
+}
+
+static GEOHASH_NEIGHBORS_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeoHashNeighbors {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geohashneighbors"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(string_list_type())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(geohash_neighbors_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOHASH_NEIGHBORS_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the eight adjacent GeoHashes of the same precision, in the order n, ne, e, se, s, sw, w, nw.",
+                "ST_GeoHashNeighbors(geohash)",
+            )
+            .with_argument("text", "geohash")
+            .build()
+        }))
+    }
+}
+
+fn geohash_neighbors_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
     let array = ColumnarValue::values_to_arrays(args)?
         .into_iter()
         .next()
         .unwrap();
-    let point_array = PointArray::try_from((array.as_ref(), Dimension::XY))?;
-
-    let mut builder = StringBuilder::with_capacity(array.len(), 0);
-
-    for point in point_array.iter() {
-        if let Some(point) = point {
-            let coord = point.coord().unwrap();
-            // TODO: make arg
-            // 12 is the max length supported by rust geohash. We should document this and maybe
-            // clamp numbers to 12.
-            let s = geohash::encode(coord.to_geo(), 12)?;
-            builder.append_value(s);
+
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for hash in array.as_string::<i32>().iter() {
+        if let Some(hash) = hash {
+            let neighbors = geohash::neighbors(hash)?;
+            for neighbor in [
+                neighbors.n,
+                neighbors.ne,
+                neighbors.e,
+                neighbors.se,
+                neighbors.s,
+                neighbors.sw,
+                neighbors.w,
+                neighbors.nw,
+            ] {
+                builder.values().append_value(neighbor);
+            }
+            builder.append(true);
         } else {
             builder.append_null();
         }
@@ -231,6 +510,132 @@ fn geohash_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
     Ok(ColumnarValue::Array(Arc::new(builder.finish())))
 }
 
+#[derive(Debug)]
+pub(super) struct GeoHashCoverage {
+    signature: Signature,
+}
+
+impl GeoHashCoverage {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(
+                vec![GEOMETRY_TYPE.into(), DataType::Int64],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+static GEOHASH_COVERAGE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for GeoHashCoverage {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_geohashcoverage"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(string_list_type())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(geohash_coverage_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(GEOHASH_COVERAGE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Return the set of GeoHashes of the given precision whose cells intersect a geometry. Unnesting the result allows joining geometries on shared GeoHash cells.",
+                "ST_GeoHashCoverage(geometry, precision)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("precision", "integer, between 1 and 12")
+            .build()
+        }))
+    }
+}
+
+/// The width and height in degrees of a GeoHash cell of the given precision.
+fn cell_size(precision: usize) -> (f64, f64) {
+    let bits = 5 * precision as i32;
+    let lon_bits = (bits + 1) / 2;
+    let lat_bits = bits / 2;
+    (360. / 2f64.powi(lon_bits), 180. / 2f64.powi(lat_bits))
+}
+
+/// All GeoHashes of the given precision whose cells intersect the geometry, in row-major order
+/// from the south-west.
+fn geohash_coverage(geom: &geo::Geometry, precision: usize) -> GeoDataFusionResult<Vec<String>> {
+    let Some(rect) = geom.bounding_rect() else {
+        return Ok(vec![]);
+    };
+    let (width, height) = cell_size(precision);
+    let col_start = ((rect.min().x + 180.) / width).floor().max(0.) as usize;
+    let col_end = ((rect.max().x + 180.) / width)
+        .floor()
+        .min(360. / width - 1.) as usize;
+    let row_start = ((rect.min().y + 90.) / height).floor().max(0.) as usize;
+    let row_end = ((rect.max().y + 90.) / height)
+        .floor()
+        .min(180. / height - 1.) as usize;
+
+    let num_cells =
+        (col_end.saturating_sub(col_start) + 1) * (row_end.saturating_sub(row_start) + 1);
+    if num_cells > MAX_COVERAGE_CELLS {
+        return Err(DataFusionError::Execution(format!(
+            "GeoHash coverage would have {num_cells} cells, more than the maximum of {MAX_COVERAGE_CELLS}; use a lower precision"
+        ))
+        .into());
+    }
+
+    let mut hashes = vec![];
+    for row in row_start..=row_end {
+        for col in col_start..=col_end {
+            let min_x = col as f64 * width - 180.;
+            let min_y = row as f64 * height - 90.;
+            let cell = geo::Rect::new(
+                geo::coord! { x: min_x, y: min_y },
+                geo::coord! { x: min_x + width, y: min_y + height },
+            );
+            if geom.intersects(&cell) {
+                hashes.push(geohash::encode(cell.center(), precision)?);
+            }
+        }
+    }
+    Ok(hashes)
+}
+
+fn geohash_coverage_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let native_array = parse_to_native_array(arrays[0].clone())?;
+    let geometry_array = native_array.as_ref().as_geometry();
+    let precision = arrays[1].as_primitive::<Int64Type>();
+
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for (i, geom) in geometry_array.iter().enumerate() {
+        match geom {
+            Some(geom) if precision.is_valid(i) => {
+                let precision = check_precision(precision.value(i))?;
+                for hash in geohash_coverage(&geom.to_geo_geometry(), precision)? {
+                    builder.values().append_value(hash);
+                }
+                builder.append(true);
+            }
+            _ => builder.append_null(),
+        }
+    }
+
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
 #[cfg(test)]
 mod test {
     use approx::relative_eq;
@@ -324,4 +729,58 @@ mod test {
         let arr = batch.columns()[0].as_string::<i32>();
         assert_eq!(arr.value(0), "c0w3hf1s70w3");
     }
+
+    #[tokio::test]
+    async fn test_geohash_precision() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_GeoHash(ST_Point(-126,48), 5);")
+            .await
+            .unwrap();
+
+        let batches = out.collect().await.unwrap();
+        let arr = batches[0].columns()[0].as_string::<i32>();
+        assert_eq!(arr.value(0), "c0w3h");
+    }
+
+    #[tokio::test]
+    async fn test_geohash_neighbors() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_GeoHashNeighbor('9q', 'e'), ST_GeoHashNeighbors('9q');")
+            .await
+            .unwrap();
+
+        let batches = out.collect().await.unwrap();
+        let batch = &batches[0];
+        assert_eq!(batch.columns()[0].as_string::<i32>().value(0), "9w");
+
+        let neighbors = batch.columns()[1].as_list::<i32>().value(0);
+        let neighbors = neighbors.as_string::<i32>();
+        assert_eq!(neighbors.len(), 8);
+        assert_eq!(neighbors.value(2), "9w");
+    }
+
+    #[tokio::test]
+    async fn test_geohash_coverage() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        // The geometry of a geohash is covered by exactly that geohash, plus the neighbors it
+        // touches on its boundary
+        let out = ctx
+            .sql("SELECT ST_GeoHashCoverage(ST_GeomFromGeoHash('9q'), 2);")
+            .await
+            .unwrap();
+
+        let batches = out.collect().await.unwrap();
+        let coverage = batches[0].columns()[0].as_list::<i32>().value(0);
+        let coverage = coverage.as_string::<i32>();
+        assert!(coverage.iter().any(|hash| hash == Some("9q")));
+        assert!(coverage.len() <= 9);
+    }
 }
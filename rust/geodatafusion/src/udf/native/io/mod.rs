@@ -1,7 +1,7 @@
 //! Geometry Input and Output
 
 mod geohash;
-mod wkb;
+pub(crate) mod wkb;
 mod wkt;
 
 use datafusion::prelude::SessionContext;
@@ -10,6 +10,10 @@ use datafusion::prelude::SessionContext;
 pub fn register_udfs(ctx: &SessionContext) {
     ctx.register_udf(geohash::Box2DFromGeoHash::new().into());
     ctx.register_udf(geohash::GeoHash::new().into());
+    ctx.register_udf(geohash::GeoHashCoverage::new().into());
+    ctx.register_udf(geohash::GeoHashNeighbor::new().into());
+    ctx.register_udf(geohash::GeoHashNeighbors::new().into());
+    ctx.register_udf(geohash::GeomFromGeoHash::new().into());
     ctx.register_udf(geohash::PointFromGeoHash::new().into());
     ctx.register_udf(wkb::AsBinary::new().into());
     ctx.register_udf(wkb::GeomFromWKB::new().into());
@@ -16,7 +16,7 @@ use crate::data_types::{any_single_geometry_type_input, parse_to_native_array, G
 use crate::error::GeoDataFusionResult;
 
 #[derive(Debug)]
-pub(super) struct AsBinary {
+pub(crate) struct AsBinary {
     signature: Signature,
 }
 
@@ -3,9 +3,11 @@
 mod accessors;
 mod bounding_box;
 mod constructors;
-mod io;
+pub(crate) mod io;
 mod measurement;
 mod processing;
+mod spatial_ref;
+mod tile;
 
 use datafusion::prelude::SessionContext;
 
@@ -17,4 +19,6 @@ pub fn register_native(ctx: &SessionContext) {
     io::register_udfs(ctx);
     measurement::register_udfs(ctx);
     processing::register_udfs(ctx);
+    spatial_ref::register_udfs(ctx);
+    tile::register_udfs(ctx);
 }
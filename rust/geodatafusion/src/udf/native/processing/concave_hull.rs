@@ -94,5 +94,5 @@ fn concave_hull_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValu
         }
     };
 
-    Ok(GeometryArray::from(output).to_array_ref().into())
+    Ok(GeometryArray::try_from(output)?.to_array_ref().into())
 }
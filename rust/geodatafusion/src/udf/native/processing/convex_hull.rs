@@ -66,5 +66,5 @@ fn convex_hull_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue
         .as_ref()
         .convex_hull()?
         .into_coord_type(CoordType::Separated);
-    Ok(GeometryArray::from(output).into_array_ref().into())
+    Ok(GeometryArray::try_from(output)?.into_array_ref().into())
 }
@@ -3,9 +3,12 @@ mod chaikin_smoothing;
 mod concave_hull;
 mod convex_hull;
 mod point_on_surface;
+mod shared_paths;
 mod simplify;
 mod simplify_preserve_topology;
 mod simplify_vw;
+mod snap;
+mod split;
 
 use datafusion::prelude::SessionContext;
 
@@ -18,4 +21,7 @@ pub fn register_udfs(ctx: &SessionContext) {
     ctx.register_udf(simplify_preserve_topology::SimplifyPreserveTopology::new().into());
     ctx.register_udf(simplify_vw::SimplifyVw::new().into());
     ctx.register_udf(simplify::Simplify::new().into());
+    ctx.register_udf(shared_paths::SharedPaths::new().into());
+    ctx.register_udf(snap::Snap::new().into());
+    ctx.register_udf(split::Split::new().into());
 }
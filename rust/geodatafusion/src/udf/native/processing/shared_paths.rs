@@ -0,0 +1,104 @@
+use std::any::Any;
+use std::sync::OnceLock;
+
+use arrow_schema::DataType;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
+use geoarrow::algorithm::geo::shared_paths;
+use geoarrow::array::CoordType;
+use geoarrow::ArrayBase;
+
+use crate::data_types::{any_geometry_pair_input, parse_to_native_array, GEOMETRY_TYPE};
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug)]
+pub(super) struct SharedPaths {
+    signature: Signature,
+}
+
+impl SharedPaths {
+    pub fn new() -> Self {
+        Self {
+            signature: any_geometry_pair_input(&[]),
+        }
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for SharedPaths {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_sharedpaths"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(GEOMETRY_TYPE.into())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(shared_paths_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns a collection containing paths shared by the two input linestrings/multilinestrings. Those going in the same direction are in the first element of the collection, those going in the opposite direction are in the second element. The paths themselves are given in the direction of the first geometry.",
+                "ST_SharedPaths(lineal1, lineal2)",
+            )
+            .with_argument("lineal1", "geometry")
+            .with_argument("lineal2", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn shared_paths_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let left = parse_to_native_array(arrays[0].clone())?;
+    let right = parse_to_native_array(arrays[1].clone())?;
+    let output = shared_paths(left.as_ref(), right.as_ref())?.into_coord_type(CoordType::Separated);
+    Ok(output.into_array_ref().into())
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::*;
+    use geo::line_string;
+    use geoarrow::array::GeometryArray;
+    use geoarrow::trait_::ArrayAccessor;
+
+    use crate::udf::native::register_native;
+
+    #[tokio::test]
+    async fn test() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_SharedPaths(ST_GeomFromText('LINESTRING(0 0, 4 0)'), ST_GeomFromText('LINESTRING(3 0, 1 0, 1 1)'));")
+            .await
+            .unwrap();
+        let batches = out.collect().await.unwrap();
+        let column = batches.first().unwrap().columns().first().unwrap().clone();
+        let geom_arr = GeometryArray::try_from(column.as_ref()).unwrap();
+        let expected = geo::GeometryCollection::from(vec![
+            geo::Geometry::from(geo::MultiLineString::new(vec![])),
+            geo::Geometry::from(geo::MultiLineString::new(vec![
+                line_string![(x: 1., y: 0.), (x: 3., y: 0.)],
+            ])),
+        ]);
+        assert_eq!(
+            geom_arr.value_as_geo(0),
+            geo::Geometry::GeometryCollection(expected)
+        );
+    }
+}
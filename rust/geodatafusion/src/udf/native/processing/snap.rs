@@ -0,0 +1,119 @@
+use std::any::Any;
+use std::sync::OnceLock;
+
+use arrow::array::AsArray;
+use arrow::datatypes::Float64Type;
+use arrow_array::Array;
+use arrow_schema::DataType;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
+use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
+use geoarrow::algorithm::geo::snap;
+use geoarrow::array::CoordType;
+use geoarrow::ArrayBase;
+
+use crate::data_types::{any_geometry_pair_input, parse_to_native_array, GEOMETRY_TYPE};
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug)]
+pub(super) struct Snap {
+    signature: Signature,
+}
+
+impl Snap {
+    pub fn new() -> Self {
+        Self {
+            signature: any_geometry_pair_input(&[DataType::Float64]),
+        }
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Snap {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_snap"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(GEOMETRY_TYPE.into())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(snap_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Snaps the vertices and segments of a geometry to the vertices of a reference geometry. The snap distance tolerance is used to control where snapping is performed. Vertices within the tolerance of a reference vertex are moved onto it, and reference vertices within the tolerance of a segment are inserted into it. The result may not be valid even if the input is.",
+                "ST_Snap(input, reference, tolerance)",
+            )
+            .with_argument("input", "geometry")
+            .with_argument("reference", "geometry")
+            .with_argument("tolerance", "float")
+            .build()
+        }))
+    }
+}
+
+fn snap_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(&args[..2])?;
+    let array = parse_to_native_array(arrays[0].clone())?;
+    let reference = parse_to_native_array(arrays[1].clone())?;
+    let tolerance = match &args[2] {
+        ColumnarValue::Scalar(tolerance) => {
+            let tolerance = tolerance.to_scalar()?.into_inner();
+            let tolerance = tolerance.as_primitive::<Float64Type>();
+            if tolerance.is_null(0) {
+                BroadcastablePrimitive::Array(vec![None::<f64>; array.len()].into())
+            } else {
+                tolerance.value(0).into()
+            }
+        }
+        ColumnarValue::Array(tolerance) => {
+            BroadcastablePrimitive::Array(tolerance.as_primitive().clone())
+        }
+    };
+    let output =
+        snap(array.as_ref(), reference.as_ref(), &tolerance)?.into_coord_type(CoordType::Separated);
+    Ok(output.into_array_ref().into())
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::*;
+    use geo::line_string;
+    use geoarrow::array::GeometryArray;
+    use geoarrow::trait_::ArrayAccessor;
+
+    use crate::udf::native::register_native;
+
+    #[tokio::test]
+    async fn test() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_Snap(ST_GeomFromText('LINESTRING(0 0, 10 0.2)'), ST_GeomFromText('LINESTRING(10 0, 5 0.1)'), 0.5);")
+            .await
+            .unwrap();
+        let batches = out.collect().await.unwrap();
+        let column = batches.first().unwrap().columns().first().unwrap().clone();
+        let geom_arr = GeometryArray::try_from(column.as_ref()).unwrap();
+        let expected = line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)];
+        assert_eq!(
+            geom_arr.value_as_geo(0),
+            geo::Geometry::LineString(expected)
+        );
+    }
+}
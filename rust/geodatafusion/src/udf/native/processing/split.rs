@@ -0,0 +1,102 @@
+use std::any::Any;
+use std::sync::OnceLock;
+
+use arrow_schema::DataType;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
+use geoarrow::algorithm::geo::split;
+use geoarrow::array::CoordType;
+use geoarrow::ArrayBase;
+
+use crate::data_types::{any_geometry_pair_input, parse_to_native_array, GEOMETRY_TYPE};
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug)]
+pub(super) struct Split {
+    signature: Signature,
+}
+
+impl Split {
+    pub fn new() -> Self {
+        Self {
+            signature: any_geometry_pair_input(&[]),
+        }
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Split {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_split"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(GEOMETRY_TYPE.into())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(split_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Splits a geometry by another geometry, returning a collection of geometries. Lines are split by points, lines, multilines, polygons or multipolygons. Polygons are split by lines, multilines, polygons or multipolygons, where only blade lines that cross the polygon split it. Geometries that the blade doesn't touch are returned unchanged in the collection.",
+                "ST_Split(geom, blade)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("blade", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn split_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let array = parse_to_native_array(arrays[0].clone())?;
+    let blade = parse_to_native_array(arrays[1].clone())?;
+    let output = split(array.as_ref(), blade.as_ref())?.into_coord_type(CoordType::Separated);
+    Ok(output.into_array_ref().into())
+}
+
+#[cfg(test)]
+mod test {
+    use datafusion::prelude::*;
+    use geo::line_string;
+    use geoarrow::array::GeometryArray;
+    use geoarrow::trait_::ArrayAccessor;
+
+    use crate::udf::native::register_native;
+
+    #[tokio::test]
+    async fn test() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_Split(ST_GeomFromText('LINESTRING(0 0, 4 0)'), ST_GeomFromText('LINESTRING(1 -1, 1 1)'));")
+            .await
+            .unwrap();
+        let batches = out.collect().await.unwrap();
+        let column = batches.first().unwrap().columns().first().unwrap().clone();
+        let geom_arr = GeometryArray::try_from(column.as_ref()).unwrap();
+        let expected = geo::GeometryCollection::from(vec![
+            geo::Geometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]),
+            geo::Geometry::from(line_string![(x: 1., y: 0.), (x: 4., y: 0.)]),
+        ]);
+        assert_eq!(
+            geom_arr.value_as_geo(0),
+            geo::Geometry::GeometryCollection(expected)
+        );
+    }
+}
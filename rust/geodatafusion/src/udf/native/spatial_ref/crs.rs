@@ -0,0 +1,91 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow::array::StringArray;
+use arrow_array::Array;
+use arrow_schema::DataType;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+
+use crate::data_types::{any_single_geometry_type_input, GEOMETRY_TYPES};
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug)]
+pub(super) struct Crs {
+    signature: Signature,
+}
+
+impl Crs {
+    pub fn new() -> Self {
+        // The second argument is the CRS of the input, added by the CrsAnalyzerRule.
+        let mut signatures = vec![any_single_geometry_type_input().type_signature];
+        signatures.extend(
+            GEOMETRY_TYPES
+                .iter()
+                .map(|t| TypeSignature::Exact(vec![t.to_data_type(), DataType::Utf8])),
+        );
+        Self {
+            signature: Signature::one_of(signatures, Volatility::Immutable),
+        }
+    }
+}
+
+static DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Crs {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_crs"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(crs_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the CRS of the geometry as stored in its GeoArrow metadata, such as an authority code, PROJJSON or WKT, or null if its CRS is unknown.",
+                "ST_CRS(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn crs_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let crs = match args.get(1) {
+        Some(ColumnarValue::Scalar(ScalarValue::Utf8(crs))) => crs.clone(),
+        _ => None,
+    };
+    let array = ColumnarValue::values_to_arrays(&args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let output = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                crs.as_deref()
+            } else {
+                None
+            }
+        })
+        .collect::<StringArray>();
+    Ok(ColumnarValue::Array(Arc::new(output)))
+}
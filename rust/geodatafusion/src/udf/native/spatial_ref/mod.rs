@@ -0,0 +1,24 @@
+//! Spatial reference system functions.
+//!
+//! The CRS of a geometry column is stored in its field metadata, which UDFs can't see when they
+//! are invoked. [`CrsAnalyzerRule`] resolves the CRS of the inputs of `ST_SRID` and `ST_CRS` when
+//! the query is planned, and `ST_SetSRID` sets the CRS of its output through
+//! [`geometry_metadata`](crate::metadata::geometry_metadata).
+
+mod crs;
+mod srid;
+
+use std::sync::Arc;
+
+use datafusion::prelude::SessionContext;
+
+use crate::metadata::CrsAnalyzerRule;
+
+/// Register all provided spatial reference system functions, and the [`CrsAnalyzerRule`] they
+/// rely on.
+pub fn register_udfs(ctx: &SessionContext) {
+    ctx.register_udf(crs::Crs::new().into());
+    ctx.register_udf(srid::SetSrid::new().into());
+    ctx.register_udf(srid::Srid::new().into());
+    ctx.add_analyzer_rule(Arc::new(CrsAnalyzerRule::new()));
+}
@@ -0,0 +1,142 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow::array::Int32Array;
+use arrow_array::Array;
+use arrow_schema::DataType;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+use datafusion::scalar::ScalarValue;
+
+use crate::data_types::{any_single_geometry_type_input, GEOMETRY_TYPES};
+use crate::error::GeoDataFusionResult;
+
+#[derive(Debug)]
+pub(super) struct Srid {
+    signature: Signature,
+}
+
+impl Srid {
+    pub fn new() -> Self {
+        // The second argument is the SRID of the input, added by the CrsAnalyzerRule.
+        let mut signatures = vec![any_single_geometry_type_input().type_signature];
+        signatures.extend(
+            GEOMETRY_TYPES
+                .iter()
+                .map(|t| TypeSignature::Exact(vec![t.to_data_type(), DataType::Int32])),
+        );
+        Self {
+            signature: Signature::one_of(signatures, Volatility::Immutable),
+        }
+    }
+}
+
+static SRID_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for Srid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_srid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(srid_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(SRID_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the spatial reference identifier of the geometry, or 0 if its CRS is unknown.",
+                "ST_SRID(geometry)",
+            )
+            .with_argument("g1", "geometry")
+            .build()
+        }))
+    }
+}
+
+fn srid_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let srid = match args.get(1) {
+        Some(ColumnarValue::Scalar(ScalarValue::Int32(Some(srid)))) => *srid,
+        _ => 0,
+    };
+    let array = ColumnarValue::values_to_arrays(&args[..1])?
+        .into_iter()
+        .next()
+        .unwrap();
+    let output = (0..array.len())
+        .map(|i| array.is_valid(i).then_some(srid))
+        .collect::<Int32Array>();
+    Ok(ColumnarValue::Array(Arc::new(output)))
+}
+
+#[derive(Debug)]
+pub(super) struct SetSrid {
+    signature: Signature,
+}
+
+impl SetSrid {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                GEOMETRY_TYPES
+                    .iter()
+                    .map(|t| TypeSignature::Exact(vec![t.to_data_type(), DataType::Int64]))
+                    .collect(),
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+static SET_SRID_DOCUMENTATION: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for SetSrid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_setsrid"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        // The geometries are unchanged, the SRID is attached to the output field metadata.
+        Ok(args[0].clone())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(SET_SRID_DOCUMENTATION.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Sets the SRID of a geometry to the given EPSG code, without transforming its coordinates. An SRID of 0 removes the CRS.",
+                "ST_SetSRID(geometry, 4326)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("srid", "integer literal")
+            .build()
+        }))
+    }
+}
@@ -0,0 +1,76 @@
+//! Web mercator tiles
+
+mod quadkey;
+mod tile_envelope;
+mod tile_id;
+
+use arrow::array::AsArray;
+use arrow::datatypes::Int64Type;
+use arrow_array::Array;
+use arrow_schema::DataType;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::{ColumnarValue, Signature, TypeSignature, Volatility};
+use datafusion::prelude::SessionContext;
+use geo_traits::{CoordTrait, RectTrait};
+use geoarrow::algorithm::native::BoundingRectArray;
+use geoarrow::algorithm::tile::{Tile, MAX_ZOOM};
+use geoarrow::trait_::ArrayAccessor;
+
+use crate::data_types::{parse_to_native_array, GEOMETRY_TYPES};
+use crate::error::GeoDataFusionResult;
+
+/// Register all provided tile functions
+pub fn register_udfs(ctx: &SessionContext) {
+    ctx.register_udf(quadkey::QuadKey::new().into());
+    ctx.register_udf(tile_envelope::TileEnvelope::new().into());
+    ctx.register_udf(tile_id::TileId::new().into());
+}
+
+fn check_zoom(zoom: i64) -> GeoDataFusionResult<u8> {
+    match u8::try_from(zoom) {
+        Ok(zoom) if zoom <= MAX_ZOOM => Ok(zoom),
+        _ => Err(DataFusionError::Execution(format!(
+            "Tile zoom level must be between 0 and {MAX_ZOOM}, got {zoom}"
+        ))
+        .into()),
+    }
+}
+
+fn geometry_and_zoom_input() -> Signature {
+    Signature::one_of(
+        GEOMETRY_TYPES
+            .iter()
+            .map(|t| TypeSignature::Exact(vec![t.to_data_type(), DataType::Int64]))
+            .collect(),
+        Volatility::Immutable,
+    )
+}
+
+/// The smallest tile, at the given zoom level or below, that contains the bounding box of each
+/// geometry, or `None` where the geometry or zoom level is null, or the geometry is empty.
+fn covering_tiles(args: &[ColumnarValue]) -> GeoDataFusionResult<Vec<Option<Tile>>> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let native_array = parse_to_native_array(arrays[0].clone())?;
+    let rects = native_array.as_ref().bounding_rect()?;
+    let zooms = arrays[1].as_primitive::<Int64Type>();
+
+    let mut tiles = Vec::with_capacity(rects.len());
+    for (i, rect) in rects.iter().enumerate() {
+        let tile = match rect {
+            Some(rect) if zooms.is_valid(i) => {
+                let zoom = check_zoom(zooms.value(i))?;
+                let rect = geo::Rect::new(
+                    geo::coord! { x: rect.min().x(), y: rect.min().y() },
+                    geo::coord! { x: rect.max().x(), y: rect.max().y() },
+                );
+                let finite = [rect.min(), rect.max()]
+                    .iter()
+                    .all(|coord| coord.x.is_finite() && coord.y.is_finite());
+                finite.then(|| Tile::covering(&rect, zoom)).transpose()?
+            }
+            _ => None,
+        };
+        tiles.push(tile);
+    }
+    Ok(tiles)
+}
@@ -0,0 +1,97 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow::array::StringBuilder;
+use arrow_schema::DataType;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::tile::{covering_tiles, geometry_and_zoom_input};
+
+#[derive(Debug)]
+pub(super) struct QuadKey {
+    signature: Signature,
+}
+
+impl QuadKey {
+    pub fn new() -> Self {
+        Self {
+            signature: geometry_and_zoom_input(),
+        }
+    }
+}
+
+static QUADKEY_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for QuadKey {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_quadkey"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(quadkey_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(QUADKEY_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the quadkey of the smallest web mercator tile, at the given zoom level or below, that contains the whole bounding box of a geometry, as returned by ST_Tile. A quadkey has one digit per zoom level, so the quadkeys of the tiles within a tile share its quadkey as a prefix.",
+                "ST_QuadKey(geometry, zoom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zoom", "integer, the maximum zoom level between 0 and 30")
+            .with_related_udf("st_tile")
+            .build()
+        }))
+    }
+}
+
+fn quadkey_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let tiles = covering_tiles(args)?;
+    let mut builder = StringBuilder::with_capacity(tiles.len(), 0);
+    for tile in tiles {
+        builder.append_option(tile.map(|tile| tile.quadkey()));
+    }
+    Ok(ColumnarValue::Array(Arc::new(builder.finish())))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::AsArray;
+    use datafusion::prelude::*;
+
+    use crate::udf::native::register_native;
+
+    #[tokio::test]
+    async fn test_quadkey() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_QuadKey(ST_GeomFromText('POINT(-122.4194 37.7749)'), 3), ST_QuadKey(ST_GeomFromText('LINESTRING(-1 1, 1 2)'), 3);")
+            .await
+            .unwrap();
+        let batches = out.collect().await.unwrap();
+        let batch = batches.first().unwrap();
+
+        let quadkey = batch.column(0).as_string::<i32>();
+        assert_eq!(quadkey.value(0), "023");
+        // Across the prime meridian, only the tile at zoom level 0 contains the line
+        let quadkey = batch.column(1).as_string::<i32>();
+        assert_eq!(quadkey.value(0), "");
+    }
+}
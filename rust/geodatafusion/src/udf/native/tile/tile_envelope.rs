@@ -0,0 +1,168 @@
+use std::any::Any;
+use std::sync::OnceLock;
+
+use arrow::array::AsArray;
+use arrow::datatypes::Int64Type;
+use arrow_schema::DataType;
+use datafusion::error::DataFusionError;
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, TypeSignature, Volatility,
+};
+use geoarrow::algorithm::tile::Tile;
+use geoarrow::array::{CoordType, GeometryBuilder};
+use geoarrow::ArrayBase;
+
+use crate::data_types::GEOMETRY_TYPE;
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::tile::check_zoom;
+
+#[derive(Debug)]
+pub(super) struct TileEnvelope {
+    signature: Signature,
+}
+
+impl TileEnvelope {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::one_of(
+                vec![
+                    TypeSignature::Exact(vec![DataType::Int64, DataType::Int64, DataType::Int64]),
+                    TypeSignature::Exact(vec![DataType::Utf8]),
+                ],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+static TILE_ENVELOPE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileEnvelope {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_tileenvelope"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(GEOMETRY_TYPE.into())
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(tile_envelope_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_ENVELOPE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the bounds of a web mercator tile as a polygon in longitude and latitude. The tile is given either by its zoom level, column and row counted from the north-west, or by its quadkey as ST_TileEnvelope(quadkey).",
+                "ST_TileEnvelope(z, x, y)",
+            )
+            .with_argument("z", "integer, the zoom level between 0 and 30")
+            .with_argument("x", "integer, the tile column")
+            .with_argument("y", "integer, the tile row")
+            .with_related_udf("st_tile")
+            .build()
+        }))
+    }
+}
+
+fn tile(z: i64, x: i64, y: i64) -> GeoDataFusionResult<Tile> {
+    let zoom = check_zoom(z)?;
+    match (u32::try_from(x), u32::try_from(y)) {
+        (Ok(x), Ok(y)) => Ok(Tile::try_new(zoom, x, y)?),
+        _ => Err(DataFusionError::Execution(format!(
+            "Tile {z}/{x}/{y} is out of range; x and y must be between 0 and {} at zoom {z}",
+            (1u64 << zoom) - 1
+        ))
+        .into()),
+    }
+}
+
+fn tile_envelope_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let len = arrays[0].len();
+
+    let mut tiles = Vec::with_capacity(len);
+    if let [quadkeys] = arrays.as_slice() {
+        for quadkey in quadkeys.as_string::<i32>().iter() {
+            tiles.push(quadkey.map(Tile::from_quadkey).transpose()?);
+        }
+    } else {
+        let z = arrays[0].as_primitive::<Int64Type>();
+        let x = arrays[1].as_primitive::<Int64Type>();
+        let y = arrays[2].as_primitive::<Int64Type>();
+        for ((z, x), y) in z.iter().zip(x.iter()).zip(y.iter()) {
+            let tile = match (z, x, y) {
+                (Some(z), Some(x), Some(y)) => Some(tile(z, x, y)?),
+                _ => None,
+            };
+            tiles.push(tile);
+        }
+    }
+
+    let mut builder =
+        GeometryBuilder::new_with_options(CoordType::Separated, Default::default(), false);
+    for tile in tiles {
+        if let Some(tile) = tile {
+            builder.push_polygon(Some(&tile.bounds().to_polygon()))?;
+        } else {
+            builder.push_null();
+        }
+    }
+
+    Ok(builder.finish().into_array_ref().into())
+}
+
+#[cfg(test)]
+mod test {
+    use approx::relative_eq;
+    use datafusion::prelude::*;
+    use geo_traits::{CoordTrait, RectTrait};
+    use geoarrow::algorithm::native::BoundingRectArray;
+    use geoarrow::algorithm::tile::MAX_LATITUDE;
+    use geoarrow::array::GeometryArray;
+    use geoarrow::trait_::ArrayAccessor;
+    use geoarrow::NativeArray;
+
+    use crate::udf::native::register_native;
+
+    #[tokio::test]
+    async fn test_tile_envelope() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_TileEnvelope(1, 1, 0), ST_TileEnvelope('1');")
+            .await
+            .unwrap();
+        let batches = out.collect().await.unwrap();
+        let batch = batches.first().unwrap();
+
+        for column in batch.columns() {
+            let geom_arr = GeometryArray::try_from(column.as_ref()).unwrap();
+            let rects = geom_arr.as_ref().bounding_rect().unwrap();
+            let rect = rects.value(0);
+            assert!(relative_eq!(rect.min().x(), 0.));
+            assert!(relative_eq!(rect.min().y(), 0.));
+            assert!(relative_eq!(rect.max().x(), 180.));
+            assert!(relative_eq!(rect.max().y(), MAX_LATITUDE));
+        }
+
+        assert!(ctx
+            .sql("SELECT ST_TileEnvelope(2, 4, 0);")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+use arrow::array::{Int64Builder, StructArray};
+use arrow_array::ArrayRef;
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Field, Fields};
+use datafusion::logical_expr::scalar_doc_sections::DOC_SECTION_OTHER;
+use datafusion::logical_expr::{ColumnarValue, Documentation, ScalarUDFImpl, Signature};
+use geoarrow::algorithm::tile::Tile;
+
+use crate::error::GeoDataFusionResult;
+use crate::udf::native::tile::{covering_tiles, geometry_and_zoom_input};
+
+fn tile_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("z", DataType::Int64, false),
+        Field::new("x", DataType::Int64, false),
+        Field::new("y", DataType::Int64, false),
+    ])
+}
+
+#[derive(Debug)]
+pub(super) struct TileId {
+    signature: Signature,
+}
+
+impl TileId {
+    pub fn new() -> Self {
+        Self {
+            signature: geometry_and_zoom_input(),
+        }
+    }
+}
+
+static TILE_DOC: OnceLock<Documentation> = OnceLock::new();
+
+impl ScalarUDFImpl for TileId {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "st_tile"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> datafusion::error::Result<DataType> {
+        Ok(DataType::Struct(tile_fields()))
+    }
+
+    fn invoke(&self, args: &[ColumnarValue]) -> datafusion::error::Result<ColumnarValue> {
+        Ok(tile_impl(args)?)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        Some(TILE_DOC.get_or_init(|| {
+            Documentation::builder(
+                DOC_SECTION_OTHER,
+                "Returns the smallest web mercator tile, at the given zoom level or below, that contains the whole bounding box of a geometry, as a struct of the zoom level z, column x and row y counted from the north-west. The geometry is in longitude and latitude. A geometry across tile edges is in a tile at a lower zoom level, down to the single tile at zoom level 0. Latitudes beyond the bounds of web mercator are clamped to the edge tiles.",
+                "ST_Tile(geometry, zoom)",
+            )
+            .with_argument("geom", "geometry")
+            .with_argument("zoom", "integer, the maximum zoom level between 0 and 30")
+            .with_related_udf("st_tileenvelope")
+            .with_related_udf("st_quadkey")
+            .build()
+        }))
+    }
+}
+
+fn tile_impl(args: &[ColumnarValue]) -> GeoDataFusionResult<ColumnarValue> {
+    let tiles = covering_tiles(args)?;
+
+    let mut z = Int64Builder::with_capacity(tiles.len());
+    let mut x = Int64Builder::with_capacity(tiles.len());
+    let mut y = Int64Builder::with_capacity(tiles.len());
+    for tile in &tiles {
+        let tile = tile.unwrap_or(Tile { z: 0, x: 0, y: 0 });
+        z.append_value(tile.z.into());
+        x.append_value(tile.x.into());
+        y.append_value(tile.y.into());
+    }
+    let nulls = NullBuffer::from_iter(tiles.iter().map(Option::is_some));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(z.finish()),
+        Arc::new(x.finish()),
+        Arc::new(y.finish()),
+    ];
+    let output = StructArray::try_new(tile_fields(), columns, Some(nulls))?;
+    Ok(ColumnarValue::Array(Arc::new(output)))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::AsArray;
+    use arrow::datatypes::Int64Type;
+    use datafusion::prelude::*;
+
+    use crate::udf::native::register_native;
+
+    #[tokio::test]
+    async fn test_tile() {
+        let ctx = SessionContext::new();
+        register_native(&ctx);
+
+        let out = ctx
+            .sql("SELECT ST_Tile(ST_GeomFromText('POINT(-122.4194 37.7749)'), 10), ST_Tile(ST_GeomFromText('LINESTRING(80 10, 100 20)'), 10);")
+            .await
+            .unwrap();
+        let batches = out.collect().await.unwrap();
+        let batch = batches.first().unwrap();
+
+        let tile = batch.column(0).as_struct();
+        assert_eq!(tile.column(0).as_primitive::<Int64Type>().value(0), 10);
+        assert_eq!(tile.column(1).as_primitive::<Int64Type>().value(0), 163);
+        assert_eq!(tile.column(2).as_primitive::<Int64Type>().value(0), 395);
+
+        // The line crosses the edges of the tiles above zoom level 1
+        let tile = batch.column(1).as_struct();
+        assert_eq!(tile.column(0).as_primitive::<Int64Type>().value(0), 1);
+        assert_eq!(tile.column(1).as_primitive::<Int64Type>().value(0), 1);
+        assert_eq!(tile.column(2).as_primitive::<Int64Type>().value(0), 0);
+    }
+}